@@ -0,0 +1,117 @@
+//! Widget benchmarks
+//!
+//! Rendering and input paths that grow with the data: table rendering and
+//! navigation at 1k, 10k and 100k rows, typing into a text area with long
+//! lines, image-to-cell conversion and the fuzzy matcher. Run with
+//! `cargo bench`, or `cargo bench -- table` for one group; criterion keeps
+//! the previous run and reports regressions against it.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{backend::TestBackend, Terminal};
+
+use tui_image_viewer::widgets::finder::fuzzy_match;
+use tui_image_viewer::widgets::table::InteractiveTable;
+use tui_image_viewer::widgets::text_area::TextArea;
+
+/// Terminal size the render benchmarks draw into
+const WIDTH: u16 = 160;
+const HEIGHT: u16 = 48;
+
+fn terminal() -> Terminal<TestBackend> {
+    Terminal::new(TestBackend::new(WIDTH, HEIGHT)).unwrap()
+}
+
+fn synthetic_table(rows: usize) -> InteractiveTable {
+    InteractiveTable::builder()
+        .headers(["Id", "Name", "City", "Score", "Status"])
+        .rows((0..rows).map(|i| {
+            [
+                i.to_string(),
+                format!("User {}", i),
+                ["London", "Tokyo", "Paris", "Lagos", "Lima"][i % 5].to_string(),
+                (i * 37 % 100).to_string(),
+                if i % 3 == 0 { "Inactive" } else { "Active" }.to_string(),
+            ]
+        }))
+        .build()
+}
+
+fn bench_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("table");
+    for rows in [1_000, 10_000, 100_000] {
+        let mut table = synthetic_table(rows);
+        let mut terminal = terminal();
+        group.throughput(Throughput::Elements(rows as u64));
+        group.bench_with_input(BenchmarkId::new("render", rows), &rows, |b, _| {
+            b.iter(|| {
+                terminal.draw(|f| table.render(f, f.area())).unwrap();
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("next_row_and_render", rows), &rows, |b, _| {
+            b.iter(|| {
+                table.next_row();
+                terminal.draw(|f| table.render(f, f.area())).unwrap();
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_text_input(c: &mut Criterion) {
+    let mut group = c.benchmark_group("text_input");
+    for length in [1_000, 10_000, 100_000] {
+        let line = "lorem ipsum dolor sit amet ".repeat(length / 27 + 1);
+        let mut area = TextArea::new("Bench").with_text(&line[..length]);
+        let mut terminal = terminal();
+        group.bench_with_input(BenchmarkId::new("type_and_render", length), &length, |b, _| {
+            b.iter(|| {
+                area.handle_key(KeyEvent::from(KeyCode::Char('x')));
+                area.handle_key(KeyEvent::from(KeyCode::Backspace));
+                terminal.draw(|f| area.render(f, f.area(), true)).unwrap();
+            });
+        });
+    }
+    group.finish();
+}
+
+#[cfg(feature = "image")]
+fn bench_image(c: &mut Criterion) {
+    use image::{DynamicImage, Rgb, RgbImage};
+    use ratatui::layout::Rect;
+    use tui_image_viewer::widgets::image::{ImageQuality, ImageWidget};
+
+    let gradient = RgbImage::from_fn(1920, 1080, |x, y| Rgb([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8]));
+    let img = DynamicImage::ImageRgb8(gradient);
+    let mut group = c.benchmark_group("image_to_cells");
+    for quality in [ImageQuality::Low, ImageQuality::High] {
+        let widget = ImageWidget::with_quality(quality);
+        for area in [Rect::new(0, 0, 80, 24), Rect::new(0, 0, WIDTH, HEIGHT)] {
+            let id = format!("{:?}/{}x{}", quality, area.width, area.height);
+            group.bench_function(id, |b| {
+                b.iter(|| widget.image_to_ascii(black_box(&img), area.width as usize, area.height as usize));
+            });
+        }
+    }
+    group.finish();
+}
+
+#[cfg(not(feature = "image"))]
+fn bench_image(_c: &mut Criterion) {}
+
+fn bench_fuzzy(c: &mut Criterion) {
+    let candidates: Vec<String> = (0..10_000)
+        .map(|i| format!("src/module_{}/widgets/component_{}_view.rs", i % 97, i))
+        .collect();
+    let mut group = c.benchmark_group("fuzzy_match");
+    group.throughput(Throughput::Elements(candidates.len() as u64));
+    for query in ["w", "cmpview", "module_42/component_4242"] {
+        group.bench_with_input(BenchmarkId::from_parameter(query), query, |b, query| {
+            b.iter(|| candidates.iter().filter_map(|candidate| fuzzy_match(black_box(query), candidate)).count());
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_table, bench_text_input, bench_image, bench_fuzzy);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+//! CSV given to `interactive_table --csv`: parsing, drawing the table at
+//! a small and a large size, moving through it and exporting it as CSV,
+//! JSON and Markdown.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ratatui::{backend::TestBackend, Terminal};
+use tui_image_viewer::widgets::export::{export, ExportFormat};
+use tui_image_viewer::widgets::table::{InteractiveTable, TableData};
+
+fuzz_target!(|text: &str| {
+    let Ok(data) = TableData::from_csv(text) else { return };
+    for format in ExportFormat::ALL {
+        let _ = export(&data, format);
+    }
+
+    let mut table = InteractiveTable::new(data);
+    for (width, height) in [(20, 5), (120, 40)] {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| table.render(f, f.area())).unwrap();
+        table.next_row();
+        table.last_row();
+        terminal.draw(|f| table.render(f, f.area())).unwrap();
+    }
+});
@@ -0,0 +1,27 @@
+//! JSON read back from disk: the saved session, including migrating older
+//! formats, and a settings form built from a schema and values.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ratatui::{backend::TestBackend, Terminal};
+use serde_json::Value;
+use tui_image_viewer::app::PersistedState;
+use tui_image_viewer::widgets::schema::SchemaForm;
+
+fuzz_target!(|text: &str| {
+    if let Ok(state) = PersistedState::from_json(text) {
+        // A migrated session must load again unchanged
+        let saved = serde_json::to_string(&state).unwrap();
+        assert_eq!(PersistedState::from_json(&saved).unwrap(), state);
+    }
+
+    let Ok(value) = serde_json::from_str::<Value>(text) else { return };
+    let values = value.get("values").cloned().unwrap_or(Value::Null);
+    let Ok(form) = SchemaForm::from_schema("Fuzz", &value, &values) else { return };
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| form.form.render(f, f.area())).unwrap();
+    let _ = form.values();
+    let mut target = values;
+    let _ = form.apply(&mut target);
+});
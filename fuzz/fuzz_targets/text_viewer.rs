@@ -0,0 +1,26 @@
+//! Bytes of a text file opened from the file browser, such as Markdown or
+//! captured logs: lossy decoding, ANSI styling, link detection and drawing
+//! while scrolling.
+
+#![no_main]
+
+use std::path::Path;
+
+use crossterm::event::KeyCode;
+use libfuzzer_sys::fuzz_target;
+use ratatui::{backend::TestBackend, Terminal};
+use tui_image_viewer::widgets::ansi::ansi_to_text;
+use tui_image_viewer::widgets::text_viewer::TextViewer;
+
+fuzz_target!(|bytes: &[u8]| {
+    let content = String::from_utf8_lossy(bytes);
+    let _ = ansi_to_text(&content);
+
+    let mut viewer = TextViewer::new(Path::new("fuzz.md"), &content);
+    let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+    for key in [KeyCode::Down, KeyCode::PageDown, KeyCode::End, KeyCode::Home] {
+        viewer.handle_key(key);
+        terminal.draw(|f| viewer.render(f, f.area())).unwrap();
+    }
+    let _ = viewer.selected_text();
+});
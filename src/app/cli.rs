@@ -0,0 +1,200 @@
+//! Command-line module
+//!
+//! Flags every binary accepts, parsed with clap. `--config` and `--theme`
+//! change which config `CommonArgs::config` loads, `--log-level` sets how much goes to
+//! the redacting log file, `--no-mouse` leaves the mouse to the terminal
+//! for selecting text, `--demo` plays the binary's scripted demo where it
+//! has one, and `--completions <shell>` prints a completion script for the
+//! binary.
+//!
+//! `exit` turns the result of a binary's `run` into its exit code and
+//! prints a failure for people rather than as a `Debug` dump.
+
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{error::ErrorKind, Args, Parser, ValueEnum};
+use clap_complete::Shell;
+use crossterm::{
+    cursor,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+
+use crate::error::{DemoError, Exit};
+use crate::widgets::redact;
+
+use super::config::{Config, ConfigHandle, Overrides};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn filter(self) -> log::LevelFilter {
+        match self {
+            LogLevel::Off => log::LevelFilter::Off,
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+/// Flags shared by all binaries; flatten into a binary's own parser
+#[derive(Debug, Clone, Default, Args)]
+pub struct CommonArgs {
+    /// Theme from the config file, by name
+    #[arg(long, global = true, value_name = "NAME")]
+    pub theme: Option<String>,
+    /// Config file to load and save instead of the default one
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    /// Most detailed messages written to the log file
+    #[arg(long, global = true, value_enum, default_value_t = LogLevel::Info)]
+    pub log_level: LogLevel,
+    /// Leave the mouse to the terminal, e.g. to select and copy text
+    #[arg(long, global = true)]
+    pub no_mouse: bool,
+    /// Play a scripted demo, for screen recordings; any key takes over
+    #[arg(long, global = true)]
+    pub demo: bool,
+    /// Print a completion script for this binary and exit
+    #[arg(long, value_enum, value_name = "SHELL")]
+    pub completions: Option<Shell>,
+}
+
+impl CommonArgs {
+    /// Config file and theme given with `--config` and `--theme`
+    pub fn overrides(&self) -> Overrides {
+        Overrides {
+            path: self.config.clone(),
+            theme: self.theme.clone(),
+        }
+    }
+
+    /// The binary's config, loaded once and shared by its screens
+    pub fn config(&self) -> ConfigHandle {
+        ConfigHandle::load_with(self.overrides())
+    }
+
+    /// Capture the mouse unless `--no-mouse` was given
+    pub fn capture_mouse(&self) -> io::Result<()> {
+        if !self.no_mouse {
+            execute!(io::stdout(), EnableMouseCapture)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parser for binaries with no flags of their own
+#[derive(Debug, Parser)]
+#[command(version)]
+pub struct Cli {
+    #[command(flatten)]
+    pub common: CommonArgs,
+}
+
+/// Parse the command line of a binary with no flags of its own
+pub fn parse() -> CommonArgs {
+    parse_with(|cli: &Cli| &cli.common).common
+}
+
+/// Parse the command line into `C` and act on its common flags: print
+/// completions and exit, start logging, and check that the chosen theme
+/// exists. Exits with a usage error for an unknown theme.
+pub fn parse_with<C: Parser>(common: impl Fn(&C) -> &CommonArgs) -> C {
+    let cli = C::parse();
+    let args = common(&cli);
+
+    if let Some(shell) = args.completions {
+        let mut command = C::command();
+        let name = std::env::args()
+            .next()
+            .and_then(|arg0| Path::new(&arg0).file_stem().map(|stem| stem.to_string_lossy().to_string()))
+            .unwrap_or_else(|| command.get_name().to_string());
+        clap_complete::generate(shell, &mut command, name, &mut io::stdout());
+        std::process::exit(0);
+    }
+
+    redact::init_logging(args.log_level.filter());
+    if let Some(theme) = &args.theme {
+        let file = Overrides {
+            path: args.config.clone(),
+            theme: None,
+        };
+        let themes = Config::load_with(&file).map(|config| config.themes).unwrap_or_else(|_| Config::default().themes);
+        if !themes.iter().any(|known| known.name.eq_ignore_ascii_case(theme)) {
+            let names: Vec<&str> = themes.iter().map(|known| known.name.as_str()).collect();
+            C::command()
+                .error(ErrorKind::InvalidValue, format!("no theme named '{}'; known themes: {}", theme, names.join(", ")))
+                .exit();
+        }
+    }
+    cli
+}
+
+/// Fail before entering raw mode when stdin or stdout is not a terminal
+pub fn require_terminal() -> Result<(), DemoError> {
+    if io::stdin().is_terminal() && io::stdout().is_terminal() {
+        Ok(())
+    } else {
+        Err(DemoError::Unsupported("stdin and stdout must both be a terminal".to_string()))
+    }
+}
+
+/// Exit code for the result of a binary's `run`. A failure restores the
+/// terminal, logs the full error, and prints the message with its hint and
+/// where the log file is.
+pub fn exit<E: Into<DemoError>>(result: Result<(), E>) -> ExitCode {
+    let Err(error) = result else {
+        return ExitCode::SUCCESS;
+    };
+    let error = error.into();
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, cursor::Show);
+
+    let code = error.exit_code();
+    if code == Exit::Aborted {
+        eprintln!("Cancelled");
+    } else {
+        log::error!("{:?}", error);
+        eprintln!("Error: {}", error);
+        eprintln!("Hint: {}", error.hint());
+        if let Some(path) = redact::log_path() {
+            eprintln!("Details are logged to {}", path.display());
+        }
+    }
+    ExitCode::from(code as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_common_args() {
+        let cli = Cli::try_parse_from(["dashboard", "--theme", "solarized", "--log-level", "debug", "--no-mouse", "--demo"]).unwrap();
+        assert_eq!(cli.common.theme.as_deref(), Some("solarized"));
+        assert_eq!(cli.common.log_level, LogLevel::Debug);
+        assert!(cli.common.no_mouse);
+        assert!(cli.common.demo);
+
+        let cli = Cli::try_parse_from(["dashboard"]).unwrap();
+        assert_eq!(cli.common.log_level, LogLevel::Info);
+        assert!(Cli::try_parse_from(["dashboard", "--log-level", "loud"]).is_err());
+        Cli::command().debug_assert();
+    }
+}
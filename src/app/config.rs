@@ -1,13 +1,75 @@
+use chrono::Weekday;
 use serde::{Deserialize, Serialize};
-use std::error::Error;
+use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::error::DemoError;
+use crate::widgets::alert::AlertConfig;
+use crate::widgets::clipboard::ClipboardHistoryConfig;
+use crate::widgets::keymap::KeymapProfile;
+use crate::widgets::lru::{self, CacheConfig};
+use crate::widgets::pomodoro::PomodoroConfig;
+use crate::widgets::recents::RecentFiles;
+use crate::widgets::redact::{self, RedactConfig};
+use crate::widgets::weather::WeatherConfig;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub theme_index: usize,
     pub default_tab: usize,
     pub auto_save: bool,
+    /// First day of the week in calendar views
+    #[serde(default = "default_week_start")]
+    pub week_start: Weekday,
+    /// Command run in the process runner pane
+    #[serde(default = "default_run_command")]
+    pub run_command: String,
+    /// Directories pinned in the file browser's locations list
+    #[serde(default)]
+    pub bookmarks: Vec<PathBuf>,
+    /// Refresh views automatically when files change on disk
+    #[serde(default = "default_watch_files")]
+    pub watch_files: bool,
+    /// Key binding preset for lists, tables and text inputs
+    #[serde(default)]
+    pub keymap: KeymapProfile,
+    /// Skip decorative animations such as confetti
+    #[serde(default)]
+    pub reduce_motion: bool,
+    /// IANA timezones shown by the world clock, e.g. `Europe/Berlin`
+    #[serde(default = "default_timezones")]
+    pub timezones: Vec<String>,
+    /// RSS/Atom feed URLs for the feed reader
+    #[serde(default = "default_feeds")]
+    pub feeds: Vec<String>,
+    /// Hidden column headers per table name
+    #[serde(default)]
+    pub hidden_columns: HashMap<String, Vec<String>>,
+    /// Weather tile endpoint, key and location
+    #[serde(default)]
+    pub weather: WeatherConfig,
+    /// Pomodoro phase lengths, edited from the pomodoro screen
+    #[serde(default)]
+    pub pomodoro: PomodoroConfig,
+    /// Bell, screen flash and desktop notifications for finished timers and tasks
+    #[serde(default)]
+    pub alerts: AlertConfig,
+    /// Clipboard history retention, exclusions and encryption
+    #[serde(default)]
+    pub clipboard: ClipboardHistoryConfig,
+    /// Masking of tokens and, optionally, emails in logs, notifications and history
+    #[serde(default)]
+    pub redaction: RedactConfig,
+    /// Byte limits for the image render and thumbnail caches
+    #[serde(default)]
+    pub caches: CacheConfig,
+    /// Images, videos, journals and CSV files listed on the main menu
+    #[serde(default)]
+    pub recent_files: RecentFiles,
     pub themes: Vec<Theme>,
 }
 
@@ -20,12 +82,66 @@ pub struct Theme {
     pub text_color: String,
 }
 
+/// Config file and theme chosen on the command line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overrides {
+    /// Load and save this file instead of the one in the config directory
+    pub path: Option<PathBuf>,
+    /// Use the theme with this name whatever the file says
+    pub theme: Option<String>,
+}
+
+impl Overrides {
+    fn config_path(&self) -> Result<PathBuf, DemoError> {
+        match &self.path {
+            Some(path) => Ok(path.clone()),
+            None => Config::config_path(),
+        }
+    }
+}
+
+fn default_week_start() -> Weekday {
+    Weekday::Mon
+}
+
+fn default_run_command() -> String {
+    "cargo check".to_string()
+}
+
+fn default_watch_files() -> bool {
+    true
+}
+
+fn default_timezones() -> Vec<String> {
+    ["UTC", "America/New_York", "Europe/London", "Asia/Tokyo"].map(String::from).to_vec()
+}
+
+fn default_feeds() -> Vec<String> {
+    ["https://blog.rust-lang.org/feed.xml", "https://this-week-in-rust.org/atom.xml"].map(String::from).to_vec()
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             theme_index: 0,
             default_tab: 0,
             auto_save: true,
+            week_start: default_week_start(),
+            run_command: default_run_command(),
+            bookmarks: Vec::new(),
+            watch_files: default_watch_files(),
+            keymap: KeymapProfile::default(),
+            reduce_motion: false,
+            timezones: default_timezones(),
+            feeds: default_feeds(),
+            hidden_columns: HashMap::new(),
+            weather: WeatherConfig::default(),
+            pomodoro: PomodoroConfig::default(),
+            alerts: AlertConfig::default(),
+            clipboard: ClipboardHistoryConfig::default(),
+            redaction: RedactConfig::default(),
+            caches: CacheConfig::default(),
+            recent_files: RecentFiles::default(),
             themes: vec![
                 Theme {
                     name: "Default".into(),
@@ -48,36 +164,274 @@ impl Default for Config {
 
 impl Config {
     /// Load configuration from file or create default
-    pub fn load() -> Result<Self, Box<dyn Error>> {
-        let config_path = Self::config_path()?;
-        if config_path.exists() {
+    pub fn load() -> Result<Self, DemoError> {
+        Self::load_with(&Overrides::default())
+    }
+
+    /// `load` from the file and with the theme given on the command line
+    pub fn load_with(overrides: &Overrides) -> Result<Self, DemoError> {
+        let config_path = overrides.config_path()?;
+        let mut config: Self = if config_path.exists() {
             let content = fs::read_to_string(&config_path)?;
-            Ok(toml::from_str(&content)?)
+            toml::from_str(&content)?
         } else {
-            Ok(Self::default())
+            Self::default()
+        };
+        if let Some(name) = &overrides.theme {
+            config.theme_index = config
+                .theme_position(name)
+                .ok_or_else(|| DemoError::Config(format!("No theme named '{}'", name)))?;
         }
+        Ok(config)
     }
 
     /// Save configuration to file
-    pub fn save(&self) -> Result<(), Box<dyn Error>> {
-        let config_path = Self::config_path()?;
+    pub fn save(&self) -> Result<(), DemoError> {
+        self.save_to(&Self::config_path()?)
+    }
+
+    /// Save configuration to `config_path` instead of the config directory
+    pub fn save_to(&self, config_path: &Path) -> Result<(), DemoError> {
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent)?;
         }
         let content = toml::to_string_pretty(self)?;
-        fs::write(&config_path, content)?;
+        fs::write(config_path, content)?;
         Ok(())
     }
 
-    fn config_path() -> Result<PathBuf, Box<dyn Error>> {
-        let mut path = dirs::config_dir().ok_or("Could not find config directory")?;
+    fn config_path() -> Result<PathBuf, DemoError> {
+        let mut path = dirs::config_dir().ok_or_else(|| DemoError::Config("Could not find config directory".to_string()))?;
         path.push("ratatui-rust-example");
         path.push("config.toml");
         Ok(path)
     }
 
+    /// Index of the theme called `name`, ignoring case
+    pub fn theme_position(&self, name: &str) -> Option<usize> {
+        self.themes.iter().position(|theme| theme.name.eq_ignore_ascii_case(name))
+    }
+
     /// Get the current theme
     pub fn current_theme(&self) -> &Theme {
         &self.themes[self.theme_index]
     }
+
+    /// JSON Schema of the editable settings, for generated settings forms.
+    /// Themes are edited in the TOML file.
+    pub fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["run_command"],
+            "properties": {
+                "theme_index": {
+                    "type": "integer",
+                    "title": "Theme",
+                    "minimum": 0,
+                    "maximum": self.themes.len().saturating_sub(1)
+                },
+                "default_tab": { "type": "integer", "title": "Default tab", "minimum": 0 },
+                "auto_save": { "type": "boolean", "title": "Auto save" },
+                "week_start": {
+                    "title": "Week starts on",
+                    "enum": ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+                },
+                "run_command": { "type": "string", "title": "Run command", "minLength": 1 },
+                "bookmarks": {
+                    "type": "array",
+                    "title": "Bookmarks (comma-separated)",
+                    "items": { "type": "string" }
+                },
+                "watch_files": { "type": "boolean", "title": "Watch files" },
+                "keymap": { "title": "Keymap", "enum": KeymapProfile::ALL },
+                "reduce_motion": { "type": "boolean", "title": "Reduce motion" },
+                "timezones": {
+                    "type": "array",
+                    "title": "World clock timezones (comma-separated)",
+                    "items": { "type": "string" }
+                },
+                "feeds": {
+                    "type": "array",
+                    "title": "Feeds (comma-separated URLs)",
+                    "items": { "type": "string" }
+                },
+                "themes": { "type": "array", "items": { "type": "object" } }
+            }
+        })
+    }
+}
+
+/// The config of a running app, shared by its screens instead of each
+/// loading its own copy. Clones point at the same config; `update` tells
+/// every `ConfigWatcher` so widgets can restyle, and applies the redaction
+/// and cache settings to the whole process.
+#[derive(Debug, Clone)]
+pub struct ConfigHandle {
+    config: Arc<RwLock<Config>>,
+    watchers: Arc<Mutex<Vec<Sender<Config>>>>,
+    /// Where the config is loaded from and saved to
+    overrides: Overrides,
+}
+
+/// Receives the config each time a `ConfigHandle` is updated
+#[derive(Debug)]
+pub struct ConfigWatcher(Receiver<Config>);
+
+impl ConfigWatcher {
+    /// The newest config since the last call, if it changed
+    pub fn changed(&self) -> Option<Config> {
+        self.0.try_iter().last()
+    }
+}
+
+impl Default for ConfigHandle {
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
+
+impl ConfigHandle {
+    pub fn new(config: Config) -> Self {
+        apply(&config);
+        Self {
+            config: Arc::new(RwLock::new(config)),
+            watchers: Arc::default(),
+            overrides: Overrides::default(),
+        }
+    }
+
+    /// `Config::load`, falling back to the defaults
+    pub fn load() -> Self {
+        Self::load_with(Overrides::default())
+    }
+
+    /// `Config::load_with`, falling back to the defaults; saves go to the
+    /// overridden file too
+    pub fn load_with(overrides: Overrides) -> Self {
+        let config = Config::load_with(&overrides).unwrap_or_default();
+        Self {
+            overrides,
+            ..Self::new(config)
+        }
+    }
+
+    /// The config as saved on disk, e.g. by another process sharing the file
+    pub fn load_saved(&self) -> Result<Config, DemoError> {
+        Config::load_with(&self.overrides)
+    }
+
+    /// Copy of the current config
+    pub fn get(&self) -> Config {
+        self.read(Config::clone)
+    }
+
+    pub fn read<R>(&self, f: impl FnOnce(&Config) -> R) -> R {
+        f(&self.config.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Change the config in place and notify the watchers
+    pub fn update(&self, f: impl FnOnce(&mut Config)) {
+        let config = {
+            let mut config = self.config.write().unwrap_or_else(|e| e.into_inner());
+            f(&mut config);
+            config.clone()
+        };
+        apply(&config);
+        if let Ok(mut watchers) = self.watchers.lock() {
+            watchers.retain(|watcher| watcher.send(config.clone()).is_ok());
+        }
+    }
+
+    /// Write the current config to the file it was loaded from
+    pub fn save(&self) -> Result<(), DemoError> {
+        let path = self.overrides.config_path()?;
+        self.read(|config| config.save_to(&path))
+    }
+
+    /// `update` followed by `save`
+    pub fn update_and_save(&self, f: impl FnOnce(&mut Config)) -> Result<(), DemoError> {
+        self.update(f);
+        self.save()
+    }
+
+    /// Add `path` to the recent files and save, when it is a kind the main
+    /// menu lists
+    pub fn remember_file(&self, path: &Path) -> Result<(), DemoError> {
+        let mut added = false;
+        self.update(|config| added = config.recent_files.push(path));
+        if added {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    pub fn watch(&self) -> ConfigWatcher {
+        let (sender, receiver) = mpsc::channel();
+        if let Ok(mut watchers) = self.watchers.lock() {
+            watchers.push(sender);
+        }
+        ConfigWatcher(receiver)
+    }
+}
+
+/// Settings the process keeps outside the handle, for code without one
+fn apply(config: &Config) {
+    redact::configure(&config.redaction);
+    lru::configure(&config.caches);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::schema::SchemaForm;
+
+    #[test]
+    fn test_schema_form_round_trip() {
+        let config = Config::default();
+        let mut value = serde_json::to_value(&config).unwrap();
+        let mut form = SchemaForm::from_schema("Settings", &config.schema(), &value).unwrap();
+        assert!(!form.fields.iter().any(|field| field.key == "themes"));
+
+        let keymap = form.form.fields.iter().position(|field| field.label == "Keymap").unwrap();
+        form.form.fields[keymap].value = "vim".to_string();
+        form.apply(&mut value).unwrap();
+        let edited: Config = serde_json::from_value(value).unwrap();
+        assert_eq!(edited.keymap, KeymapProfile::Vim);
+        assert_eq!(edited.week_start, config.week_start);
+        assert_eq!(edited.themes.len(), config.themes.len());
+    }
+
+    #[test]
+    fn test_handle_notifies_watchers() {
+        let handle = ConfigHandle::new(Config::default());
+        let screen = handle.clone();
+        let watcher = handle.watch();
+        assert!(watcher.changed().is_none());
+
+        handle.update(|config| config.theme_index = 1);
+        handle.update(|config| config.theme_index = 2);
+        assert_eq!(screen.read(|config| config.theme_index), 2);
+        assert_eq!(watcher.changed().map(|config| config.theme_index), Some(2));
+        assert!(watcher.changed().is_none());
+
+        drop(watcher);
+        handle.update(|config| config.auto_save = !config.auto_save);
+        assert!(handle.watchers.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_handle_saves_to_the_overridden_file() {
+        let path = std::env::temp_dir().join(format!("config_test_{}.toml", std::process::id()));
+        let overrides = Overrides {
+            path: Some(path.clone()),
+            theme: Some("solarized".to_string()),
+        };
+        let handle = ConfigHandle::load_with(overrides.clone());
+        assert_eq!(handle.read(|config| config.theme_index), 1);
+
+        handle.update_and_save(|config| config.week_start = Weekday::Sun).unwrap();
+        let saved = Config::load_with(&Overrides { theme: None, ..overrides }).unwrap();
+        assert_eq!(saved.week_start, Weekday::Sun);
+        let _ = fs::remove_file(&path);
+    }
 }
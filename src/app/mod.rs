@@ -3,18 +3,22 @@
 //! Contains the main application state, configuration management,
 //! and clipboard integration functionality.
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cli;
 pub mod config;
+pub mod state;
 
-use once_cell::sync::Lazy;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
-use std::sync::Mutex;
 
-pub use config::Config;
+use crate::error::DemoError;
+use crate::widgets::bus::{Message, Subscriber};
+use crate::widgets::clipboard::{self, ClipboardStore};
+use crate::widgets::redact::redact;
 
-/// Maximum number of clipboard history entries
-const MAX_CLIPBOARD_HISTORY: usize = 50;
+pub use config::{Config, ConfigHandle, ConfigWatcher};
+pub use state::PersistedState;
 
 /// Input modes for the application
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -35,8 +39,8 @@ impl Default for InputMode {
     }
 }
 
-/// Application state
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Application state; sessions are saved as a `PersistedState`
+#[derive(Debug, Clone)]
 pub struct App {
     /// Current input mode
     pub input_mode: InputMode,
@@ -48,7 +52,7 @@ pub struct App {
     pub emoji_input: String,
     /// Hyperlink input content
     pub hyperlink_input: String,
-    /// Current emoji category index
+    /// Current emoji category index, into `emoji::categories()`
     pub emoji_category_index: usize,
     /// Current emoji index within category
     pub emoji_index: usize,
@@ -56,14 +60,12 @@ pub struct App {
     pub clipboard_history: VecDeque<String>,
     /// Selected clipboard history index
     pub clipboard_history_index: usize,
-    /// Application configuration
-    pub config: Config,
+    /// History file the clipboard history is loaded from and appended to
+    pub clipboard_store: ClipboardStore,
+    /// Application configuration, shared with the screens
+    pub config: ConfigHandle,
     /// Whether the application should quit
     pub should_quit: bool,
-    /// Current tab index
-    pub current_tab: usize,
-    /// Active tab index
-    pub active_tab: usize,
 }
 
 impl Default for App {
@@ -73,8 +75,10 @@ impl Default for App {
 }
 
 impl App {
-    /// Create a new application instance
+    /// Create a new application instance, restoring the saved clipboard history
     pub fn new() -> Self {
+        let config = ConfigHandle::default();
+        let clipboard_store = ClipboardStore::new(config.read(|config| config.clipboard.clone()));
         Self {
             input_mode: InputMode::Normal,
             selected_input: 0,
@@ -83,18 +87,17 @@ impl App {
             hyperlink_input: String::new(),
             emoji_category_index: 0,
             emoji_index: 0,
-            clipboard_history: VecDeque::new(),
+            clipboard_history: clipboard_store.load(),
             clipboard_history_index: 0,
-            config: Config::default(),
+            clipboard_store,
+            config,
             should_quit: false,
-            current_tab: 0,
-            active_tab: 0,
         }
     }
 
     /// Get the primary color from config
     pub fn primary_color(&self) -> Color {
-        match self.config.theme_index {
+        match self.config.read(|config| config.theme_index) {
             0 => Color::Cyan,
             1 => Color::Green,
             2 => Color::Yellow,
@@ -105,69 +108,63 @@ impl App {
         }
     }
 
-    /// Quit the application
-    pub fn quit(&mut self) {
-        self.should_quit = true;
+    /// The part of the app saved between sessions
+    pub fn persisted_state(&self) -> PersistedState {
+        PersistedState {
+            selected_input: self.selected_input,
+            text_input: self.text_input.clone(),
+            emoji_input: self.emoji_input.clone(),
+            hyperlink_input: self.hyperlink_input.clone(),
+            emoji_category_index: self.emoji_category_index,
+            emoji_index: self.emoji_index,
+            ..PersistedState::default()
+        }
     }
 
-    /// Switch to next tab
-    pub fn next_tab(&mut self) {
-        self.current_tab = (self.current_tab + 1) % 3;
+    /// Continue a saved session
+    pub fn restore(&mut self, state: PersistedState) {
+        self.selected_input = state.selected_input;
+        self.text_input = state.text_input;
+        self.emoji_input = state.emoji_input;
+        self.hyperlink_input = state.hyperlink_input;
+        self.emoji_category_index = state.emoji_category_index;
+        self.emoji_index = state.emoji_index;
     }
 
-    /// Switch to previous tab
-    pub fn prev_tab(&mut self) {
-        self.current_tab = if self.current_tab == 0 {
-            2
-        } else {
-            self.current_tab - 1
-        };
+    /// Quit the application
+    pub fn quit(&mut self) {
+        self.should_quit = true;
     }
 
-    /// Copy content to clipboard and add to history
-    pub fn copy_to_clipboard(&mut self, content: &str) {
+    /// Copy content to clipboard and add to history. The entry is kept in
+    /// the history even when the system clipboard is unavailable.
+    pub fn copy_to_clipboard(&mut self, content: &str) -> Result<(), DemoError> {
         if content.is_empty() {
-            return;
+            return Ok(());
         }
+        self.record_copy(content);
 
-        // Add to clipboard history
-        self.clipboard_history.push_front(content.to_string());
+        // Copy to system clipboard
+        clipboard::copy_text(content)
+    }
+
+    /// Add a copy to the clipboard history, masking secrets; the system
+    /// clipboard gets the real content
+    fn record_copy(&mut self, content: &str) {
+        let entry = redact(content);
+        self.clipboard_store.append(&entry);
+        self.clipboard_history.push_front(entry);
 
         // Limit history size
-        if self.clipboard_history.len() > MAX_CLIPBOARD_HISTORY {
-            self.clipboard_history.pop_back();
-        }
+        self.clipboard_history.truncate(self.clipboard_store.config.max_entries);
 
         // Reset history index
         self.clipboard_history_index = 0;
-
-        // Copy to system clipboard
-        if let Err(e) = self.copy_to_system_clipboard(content) {
-            log::error!("Failed to copy to clipboard: {e:?}");
-        }
-    }
-
-    /// Copy to system clipboard
-    fn copy_to_system_clipboard(&self, content: &str) -> Result<(), Box<dyn std::error::Error>> {
-        use arboard::Clipboard;
-        let mut clipboard = Clipboard::new()?;
-        clipboard.set_text(content)?;
-        Ok(())
     }
 
     /// Paste from clipboard
-    pub fn paste_from_clipboard(&self) -> String {
-        self.get_system_clipboard().unwrap_or_else(|e| {
-            log::error!("Failed to get clipboard content: {e:?}");
-            String::new()
-        })
-    }
-
-    /// Get system clipboard content
-    fn get_system_clipboard(&self) -> Result<String, Box<dyn std::error::Error>> {
-        use arboard::Clipboard;
-        let mut clipboard = Clipboard::new()?;
-        Ok(clipboard.get_text()?)
+    pub fn paste_from_clipboard(&self) -> Result<String, DemoError> {
+        clipboard::paste_text()
     }
 
     /// Get clipboard history item at index
@@ -182,7 +179,7 @@ impl App {
 
     /// Get theme colors
     pub fn theme_colors(&self) -> (Color, Color, Color) {
-        match self.config.theme_index {
+        match self.config.read(|config| config.theme_index) {
             0 => (Color::Cyan, Color::Blue, Color::Gray),
             1 => (Color::Green, Color::LightGreen, Color::DarkGray),
             2 => (Color::Yellow, Color::LightYellow, Color::Gray),
@@ -195,80 +192,28 @@ impl App {
 
     /// Switch to next theme
     pub fn next_theme(&mut self) {
-        self.config.theme_index = (self.config.theme_index + 1) % 6;
-    }
-
-    /// Add table_state field and methods
-    pub fn table_state() -> &'static Mutex<ratatui::widgets::TableState> {
-        static TABLE_STATE: Lazy<Mutex<ratatui::widgets::TableState>> =
-            Lazy::new(|| Mutex::new(ratatui::widgets::TableState::default()));
-        &TABLE_STATE
+        self.config.update(|config| config.theme_index = (config.theme_index + 1) % 6);
     }
 }
 
-/// Available emoji categories for the picker
-pub const EMOJI_CATEGORIES: &[&str] = &[
-    "smileys",
-    "animals",
-    "food",
-    "travel",
-    "activities",
-    "nature",
-    "objects",
-    "symbols",
-];
-
-/// Emoji mappings by category
-pub const EMOJI_MAP: &[(&str, &[&str])] = &[
-    (
-        "smileys",
-        &["😀", "😃", "😄", "😁", "😆", "😅", "🤣", "😂", "🙂", "🙃"],
-    ),
-    (
-        "animals",
-        &["🐶", "🐱", "🐭", "🐹", "🐰", "🦊", "🐻", "🐼", "🐨", "🐯"],
-    ),
-    (
-        "food",
-        &["🍎", "🍊", "🍋", "🍌", "🍉", "🍇", "🍓", "🫐", "🍈", "🍒"],
-    ),
-    (
-        "travel",
-        &["🚗", "🚕", "🚙", "🚌", "🚎", "🏎️", "🚓", "🚑", "🚒", "🚐"],
-    ),
-    (
-        "activities",
-        &["⚽", "🏀", "🏈", "⚾", "🥎", "🎾", "🏐", "🏉", "🥏", "🎱"],
-    ),
-    (
-        "nature",
-        &["🌲", "🌳", "🌴", "🌵", "🌶️", "🌷", "🌸", "🌹", "🌺", "🌻"],
-    ),
-    (
-        "objects",
-        &["💎", "🔔", "🎵", "🎶", "💝", "🎁", "📱", "💻", "⌚", "📷"],
-    ),
-    (
-        "symbols",
-        &["❤️", "💙", "💚", "💛", "🧡", "💜", "🖤", "🤍", "🤎", "💔"],
-    ),
-];
-
-// Config static for global access (remove duplicate imports)
-static CONFIG: Lazy<Mutex<Option<Config>>> = Lazy::new(|| Mutex::new(None));
-
-// Then access it like this:
-pub fn get_config() -> Config {
-    CONFIG.lock().unwrap().clone().unwrap_or_default()
-}
-
-pub fn set_config(config: Config) {
-    let mut guard = CONFIG.lock().unwrap();
-    *guard = Some(config);
+/// Copies made by widgets join the clipboard history, and a theme applied
+/// elsewhere becomes the current one
+impl Subscriber for App {
+    fn on_message(&mut self, message: &Message) {
+        match message {
+            Message::Copied(text) if !text.is_empty() => self.record_copy(text),
+            Message::ThemeChanged(name) => {
+                if let Some(index) = self.config.read(|config| config.theme_position(name)) {
+                    self.config.update(|config| config.theme_index = index);
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 // src/app/mod.rs
-pub fn run() -> Result<(), Box<dyn std::error::Error>> {
+pub fn run() -> Result<(), DemoError> {
     println!("App logic goes here!");
     Ok(())
 }
@@ -1,9 +1,8 @@
 use std::io;
 use std::collections::VecDeque;
-use std::fs;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -14,20 +13,37 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{
         Block, Borders, List, ListItem, Paragraph, Tabs,
-        BarChart, Table, Row, Cell, Clear, Gauge,
+        BarChart, Table, Row, Cell,
     },
     Frame, Terminal,
 };
+use serde::{Deserialize, Serialize};
+use std::process::ExitCode;
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::campaign::{create_campaign_files, CampaignRequest};
+use tui_image_viewer::widgets::confetti::Confetti;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::draft::DraftStore;
+use tui_image_viewer::widgets::form::{ConfirmDialog, FormAction};
+use tui_image_viewer::widgets::history::{HistoryAction, InputHistory};
+use tui_image_viewer::widgets::line_edit::handle_edit_key;
+use tui_image_viewer::widgets::selection::column_of_char;
+use tui_image_viewer::widgets::state_machine::{State, StateMachine, Transitions};
+use tui_image_viewer::widgets::progress::{TaskHandle, TaskManager, TaskOutcome};
 
 const HISTORY_SIZE: usize = 10;
 
-#[derive(PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone)]
 enum InputMode {
     Navigation,
     Editing,
     Generating, // For loading states
 }
 
+impl State for InputMode {}
+
 #[derive(PartialEq, Copy, Clone)]
 enum InputField {
     Name,
@@ -44,10 +60,40 @@ enum SelectOption {
     Other,
 }
 
+impl SelectOption {
+    const ALL: [SelectOption; 4] = [SelectOption::Yes, SelectOption::No, SelectOption::Maybe, SelectOption::Other];
+
+    fn label(self) -> &'static str {
+        match self {
+            SelectOption::Yes => "Yes",
+            SelectOption::No => "No",
+            SelectOption::Maybe => "Maybe",
+            SelectOption::Other => "Other",
+        }
+    }
+}
+
+/// In-progress entries autosaved so they survive a crash or an early quit
+#[derive(Serialize, Deserialize)]
+struct FormDraft {
+    name: String,
+    email: String,
+    selection: String,
+}
+
+impl FormDraft {
+    fn is_empty(&self) -> bool {
+        self.name.is_empty() && self.email.is_empty()
+    }
+}
+
 struct App {
     name: String,
     email: String,
-    input_mode: InputMode,
+    // Cursor positions in characters
+    name_cursor: usize,
+    email_cursor: usize,
+    input_mode: StateMachine<InputMode>,
     active_field: InputField,
     selected_option: SelectOption,
     history: VecDeque<String>,
@@ -60,15 +106,20 @@ struct App {
     table_headers: Vec<String>,
     selected_table_row: usize,
     selected_table_col: usize,
-    loading_progress: u16,
-    show_loading: bool,
+    tasks: TaskManager<String>,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
     campaign_generated: bool,
     show_image_placeholder: bool,
-    last_generation_time: Option<Instant>,
-    // Command history for shell-like experience
-    command_history: VecDeque<String>,
-    history_index: Option<usize>,
-    current_input_backup: String,
+    // Persistent per-field history with Up/Down recall and Ctrl+R search
+    name_history: InputHistory,
+    email_history: InputHistory,
+    drafts: DraftStore<FormDraft>,
+    /// Restore prompt for a draft left by the previous session
+    restore: Option<(ConfirmDialog, FormDraft)>,
+    /// Celebration after a submit or a generated campaign
+    confetti: Option<Confetti>,
+    reduce_motion: bool,
 }
 
 impl Default for App {
@@ -104,7 +155,9 @@ impl Default for App {
         Self {
             name: String::new(),
             email: String::new(),
-            input_mode: InputMode::Navigation,
+            name_cursor: 0,
+            email_cursor: 0,
+            input_mode: StateMachine::new(InputMode::Navigation),
             active_field: InputField::Name,
             selected_option: SelectOption::Yes,
             history,
@@ -117,19 +170,92 @@ impl Default for App {
             table_headers,
             selected_table_row: 0,
             selected_table_col: 0,
-            loading_progress: 0,
-            show_loading: false,
+            tasks: TaskManager::new(),
+            events: EventDispatcher::new(),
             campaign_generated: false,
             show_image_placeholder: true,
-            last_generation_time: None,
-            command_history: VecDeque::new(),
-            history_index: None,
-            current_input_backup: String::new(),
+            name_history: InputHistory::persistent("form_name"),
+            email_history: InputHistory::persistent("form_email"),
+            drafts: DraftStore::persistent("interactive_form"),
+            restore: None,
+            confetti: None,
+            reduce_motion: false,
         }
     }
 }
 
 impl App {
+    /// App with a restore prompt when the previous session left a draft
+    fn new(config: &ConfigHandle) -> Self {
+        let mut app = Self {
+            reduce_motion: config.read(|config| config.reduce_motion),
+            ..Self::default()
+        };
+        app.restore = app.drafts.load().filter(|draft| !draft.is_empty()).map(|draft| {
+            let message = format!(
+                "An unsaved form from your last session was found (Name: {}, Email: {}). Restore it?",
+                draft.name, draft.email
+            );
+            (ConfirmDialog::new("Restore draft", &message), draft)
+        });
+        app
+    }
+
+    fn draft(&self) -> FormDraft {
+        FormDraft {
+            name: self.name.clone(),
+            email: self.email.clone(),
+            selection: self.selected_option.label().to_string(),
+        }
+    }
+
+    fn restore_draft(&mut self, draft: FormDraft) {
+        self.name_cursor = draft.name.chars().count();
+        self.email_cursor = draft.email.chars().count();
+        self.name = draft.name;
+        self.email = draft.email;
+        if let Some(option) = SelectOption::ALL.into_iter().find(|option| option.label() == draft.selection) {
+            self.selected_option = option;
+        }
+        self.validate_name();
+        self.validate_email();
+        self.add_to_history("↺ Restored unsaved draft".to_string());
+    }
+
+    /// Keys for the restore prompt shown at launch
+    fn handle_restore_key(&mut self, key: KeyCode) {
+        let Some((dialog, _)) = &self.restore else {
+            return;
+        };
+        match dialog.handle_key(key) {
+            FormAction::Submit => {
+                if let Some((_, draft)) = self.restore.take() {
+                    self.restore_draft(draft);
+                }
+            }
+            FormAction::Cancel => {
+                self.restore = None;
+                self.drafts.discard();
+                self.add_to_history("Discarded unsaved draft".to_string());
+            }
+            FormAction::None => {}
+        }
+    }
+
+    /// Keep the draft file in step with the form; an empty form has no draft
+    fn autosave_draft(&mut self) {
+        if self.restore.is_some() {
+            // Don't overwrite the old draft before the user has decided
+            return;
+        }
+        let draft = self.draft();
+        if draft.is_empty() {
+            self.drafts.discard();
+        } else {
+            self.drafts.autosave(&draft);
+        }
+    }
+
     fn validate_email(&mut self) {
         // Enhanced email validation with detailed feedback
         if self.email.is_empty() {
@@ -186,79 +312,81 @@ impl App {
         }
     }
     
-    fn add_to_command_history(&mut self, command: String) {
-        if !command.trim().is_empty() && !self.command_history.contains(&command) {
-            self.command_history.push_front(command);
-            if self.command_history.len() > 20 {
-                self.command_history.pop_back();
+    fn active_text(&self) -> String {
+        match self.active_field {
+            InputField::Name => self.name.clone(),
+            InputField::Email => self.email.clone(),
+            _ => String::new(),
+        }
+    }
+
+    fn set_active_text(&mut self, text: String) {
+        match self.active_field {
+            InputField::Name => {
+                self.name_cursor = text.chars().count();
+                self.name = text;
+                self.validate_name();
+            }
+            InputField::Email => {
+                self.email_cursor = text.chars().count();
+                self.email = text;
+                self.validate_email();
             }
+            _ => {}
         }
-        self.history_index = None;
-        self.current_input_backup.clear();
     }
-    
-    fn scroll_command_history(&mut self, direction: i32) -> bool {
-        if self.command_history.is_empty() {
-            return false;
+
+    fn active_history(&mut self) -> Option<&mut InputHistory> {
+        match self.active_field {
+            InputField::Name => Some(&mut self.name_history),
+            InputField::Email => Some(&mut self.email_history),
+            _ => None,
         }
-        
-        // Backup current input on first scroll
-        if self.history_index.is_none() {
-            self.current_input_backup = match self.active_field {
-                InputField::Name => self.name.clone(),
-                InputField::Email => self.email.clone(),
-                _ => String::new(),
-            };
+    }
+
+    /// History whose reverse-search overlay is open
+    fn searching_history(&self) -> Option<&InputHistory> {
+        [&self.name_history, &self.email_history]
+            .into_iter()
+            .find(|history| history.is_searching())
+    }
+
+    fn add_to_command_history(&mut self) {
+        let text = self.active_text();
+        if let Some(history) = self.active_history() {
+            history.push(&text);
         }
-        
-        let new_index = match self.history_index {
-            None => {
-                if direction > 0 {
-                    Some(0)
-                } else {
-                    None
-                }
-            }
-            Some(current) => {
-                if direction > 0 && current + 1 < self.command_history.len() {
-                    Some(current + 1)
-                } else if direction < 0 && current > 0 {
-                    Some(current - 1)
-                } else if direction < 0 && current == 0 {
-                    None
-                } else {
-                    Some(current)
-                }
-            }
+    }
+
+    fn scroll_command_history(&mut self, direction: i32) -> bool {
+        let current = self.active_text();
+        let Some(history) = self.active_history() else {
+            return false;
         };
-        
-        if new_index != self.history_index {
-            self.history_index = new_index;
-            
-            let text = if let Some(idx) = new_index {
-                self.command_history[idx].clone()
-            } else {
-                self.current_input_backup.clone()
-            };
-            
-            match self.active_field {
-                InputField::Name => {
-                    self.name = text;
-                    self.validate_name();
-                }
-                InputField::Email => {
-                    self.email = text;
-                    self.validate_email();
-                }
-                _ => {}
+        let recalled = if direction > 0 {
+            history.older(&current)
+        } else {
+            history.newer()
+        };
+        match recalled {
+            Some(text) => {
+                self.set_active_text(text);
+                true
             }
-            
-            return true;
+            None => false,
         }
-        
-        false
     }
-    
+
+    fn handle_history_search_key(&mut self, key: event::KeyEvent) {
+        let Some(history) = self.active_history() else {
+            return;
+        };
+        if let HistoryAction::Accept(text) = history.handle_search_key(key) {
+            self.add_to_history(format!("↺ Recalled \"{}\"", text));
+            self.set_active_text(text);
+        }
+    }
+
     fn add_to_history(&mut self, entry: String) {
         self.history.push_front(entry);
         while self.history.len() > HISTORY_SIZE {
@@ -330,72 +458,88 @@ impl App {
     }
 
     fn start_campaign_generation(&mut self) {
-        self.input_mode = InputMode::Generating;
-        self.show_loading = true;
-        self.loading_progress = 0;
         self.campaign_generated = false;
-        self.last_generation_time = Some(Instant::now());
+
+        let campaign = CampaignRequest {
+            name: self.name.clone(),
+            email: self.email.clone(),
+            selection: self.selected_option.label().to_string(),
+            data: self.data.clone(),
+        };
+        self.tasks.spawn("Generating Campaign", move |handle| generate_campaign(&campaign, handle));
     }
 
-    fn update_loading(&mut self) {
-        if let Some(start_time) = self.last_generation_time {
-            let elapsed = start_time.elapsed();
-            let progress = (elapsed.as_millis() as f32 / 3000.0 * 100.0) as u16;
-            
-            if progress >= 100 {
-                self.loading_progress = 100;
-                self.show_loading = false;
-                self.campaign_generated = true;
-                self.input_mode = InputMode::Navigation;
-                self.create_campaign_files();
-                self.add_to_history("✓ Campaign files generated successfully!".to_string());
-            } else {
-                self.loading_progress = progress;
+    /// Apply background task progress and react to finished tasks
+    fn poll_tasks(&mut self) {
+        for outcome in self.tasks.poll() {
+            match outcome {
+                TaskOutcome::Finished { output, .. } => {
+                    self.campaign_generated = true;
+                    self.add_to_history(format!("✓ Campaign files generated in {}", output));
+                    self.confetti = Confetti::celebrate(self.reduce_motion);
+                }
+                TaskOutcome::Failed { name, error, .. } => {
+                    self.add_to_history(format!("✗ {} failed: {}", name, error));
+                }
+                TaskOutcome::Cancelled { name, .. } => {
+                    self.add_to_history(format!("✗ {} cancelled", name));
+                }
             }
         }
+
+        if self.input_mode.is(InputMode::Generating) && !self.tasks.is_busy() {
+            self.transition(InputMode::Navigation);
+        }
+        if self.confetti.as_ref().is_some_and(Confetti::is_finished) {
+            self.confetti = None;
+        }
     }
+}
 
-    fn create_campaign_files(&self) {
-        // Create campaign directory
-        let campaign_dir = format!("campaign_{}", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
-        let _ = fs::create_dir_all(&campaign_dir);
+impl Transitions<InputMode> for App {
+    fn machine(&mut self) -> &mut StateMachine<InputMode> {
+        &mut self.input_mode
+    }
 
-        // Create campaign.json
-        let campaign_data = serde_json::json!({
-            "name": self.name,
-            "email": self.email,
-            "selection": match self.selected_option {
-                SelectOption::Yes => "Yes",
-                SelectOption::No => "No", 
-                SelectOption::Maybe => "Maybe",
-                SelectOption::Other => "Other",
-            },
-            "created_at": chrono::Utc::now().to_rfc3339(),
-            "data": self.data
-        });
+    /// Only the name and email fields are edited, and a campaign is
+    /// generated from a valid form while no generation is running
+    fn guard(&self, from: InputMode, to: InputMode) -> bool {
+        match to {
+            InputMode::Editing => matches!(self.active_field, InputField::Name | InputField::Email),
+            InputMode::Generating => {
+                from == InputMode::Navigation && self.name_valid && self.email_valid && !self.tasks.is_busy()
+            }
+            InputMode::Navigation => true,
+        }
+    }
 
-        let campaign_file = format!("{}/campaign.json", campaign_dir);
-        let _ = fs::write(campaign_file, serde_json::to_string_pretty(&campaign_data).unwrap());
-
-        // Create README.md
-        let readme_content = format!(
-            "# Campaign: {}\n\n- Email: {}\n- Selection: {}\n- Generated: {}\n",
-            self.name,
-            self.email,
-            match self.selected_option {
-                SelectOption::Yes => "Yes",
-                SelectOption::No => "No",
-                SelectOption::Maybe => "Maybe", 
-                SelectOption::Other => "Other",
-            },
-            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
-        );
+    /// Leaving a field saves its input to the field's history
+    fn on_exit(&mut self, from: InputMode, _to: InputMode) {
+        if from == InputMode::Editing {
+            self.add_to_command_history();
+        }
+    }
 
-        let readme_file = format!("{}/README.md", campaign_dir);
-        let _ = fs::write(readme_file, readme_content);
+    fn on_enter(&mut self, to: InputMode, _from: InputMode) {
+        if to == InputMode::Generating {
+            self.start_campaign_generation();
+        }
     }
 }
 
+/// Write the campaign files, reporting progress through `handle`
+fn generate_campaign(campaign: &CampaignRequest, handle: &TaskHandle<String>) -> Result<String, String> {
+    // Pace the steps so the progress bars are visible in the demo
+    create_campaign_files(campaign, |ratio, message| {
+        if handle.is_cancelled() {
+            return Err("cancelled".to_string());
+        }
+        handle.report_with(ratio, message);
+        std::thread::sleep(Duration::from_millis(600));
+        Ok(())
+    })
+}
+
 fn ui(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -413,7 +557,7 @@ fn ui(f: &mut Frame, app: &App) {
         .split(f.area());
     
     // Title with enhanced instructions
-    let title_text = match app.input_mode {
+    let title_text = match app.input_mode.current() {
         InputMode::Navigation => {
             let mut instructions = vec!["Interactive Form - "];
             instructions.push("Tab/↑↓ navigate, Enter edit, Space generate");
@@ -431,12 +575,12 @@ fn ui(f: &mut Frame, app: &App) {
                 InputField::Table => "Table",
             };
             let mut instructions = format!("Editing {} - Type to input, Enter/Esc to stop", field_name);
-            if app.command_history.len() > 0 {
-                instructions.push_str(", ↑↓ for history");
+            if matches!(app.active_field, InputField::Name | InputField::Email) {
+                instructions.push_str(", ↑↓ history, Ctrl+R search history");
             }
             instructions
         },
-        InputMode::Generating => "Generating campaign files... Press Q/Esc to quit".to_string(),
+        InputMode::Generating => "Generating campaign files... Esc to cancel, Q to quit".to_string(),
     };
     
     let title = Paragraph::new(title_text.as_str())
@@ -445,7 +589,7 @@ fn ui(f: &mut Frame, app: &App) {
     f.render_widget(title, chunks[0]);
     
     // Name input
-    let name_style = if app.active_field == InputField::Name && app.input_mode == InputMode::Editing {
+    let name_style = if app.active_field == InputField::Name && app.input_mode.is(InputMode::Editing) {
         Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
     } else if app.active_field == InputField::Name {
         Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
@@ -488,7 +632,7 @@ fn ui(f: &mut Frame, app: &App) {
     f.render_widget(name_text, chunks[1]);
     
     // Email input
-    let email_style = if app.active_field == InputField::Email && app.input_mode == InputMode::Editing {
+    let email_style = if app.active_field == InputField::Email && app.input_mode.is(InputMode::Editing) {
         Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
     } else if app.active_field == InputField::Email {
         Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
@@ -601,24 +745,17 @@ fn ui(f: &mut Frame, app: &App) {
         } else {
             Style::default().fg(Color::Yellow)
         })
-        .block(Block::default().title("Actions & Status").borders(Borders::ALL));
+        .block(Block::default().title(app.events.status_title("Actions & Status")).borders(Borders::ALL));
     f.render_widget(actions, chunks[4]);
 
-    // Loading indicator
-    if app.show_loading {
+    // Background task progress
+    if app.tasks.is_busy() {
         let loading_area = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(0), Constraint::Length(3), Constraint::Min(0)].as_ref())
+            .constraints([Constraint::Min(0), Constraint::Length(app.tasks.panel_height()), Constraint::Min(0)].as_ref())
             .split(f.area())[1];
-        
-        let gauge = Gauge::default()
-            .block(Block::default().title("Generating Campaign").borders(Borders::ALL))
-            .gauge_style(Style::default().fg(Color::Cyan))
-            .percent(app.loading_progress)
-            .label(format!("{}%", app.loading_progress));
-        
-        f.render_widget(Clear, loading_area);
-        f.render_widget(gauge, loading_area);
+
+        app.tasks.render(f, loading_area);
     }
     
     // History
@@ -777,45 +914,76 @@ fn ui(f: &mut Frame, app: &App) {
     f.render_widget(image_display, viz_chunks[2]);
     
     // Cursor positioning for editing
-    if app.input_mode == InputMode::Editing {
+    if app.input_mode.is(InputMode::Editing) {
         match app.active_field {
             InputField::Name => {
                 f.set_cursor_position(ratatui::layout::Position::new(
-                    chunks[1].x + app.name.len() as u16 + 1, 
+                    chunks[1].x + column_of_char(&app.name, app.name_cursor) as u16 + 1,
                     chunks[1].y + 1
                 ));
             }
             InputField::Email => {
                 f.set_cursor_position(ratatui::layout::Position::new(
-                    chunks[2].x + app.email.len() as u16 + 1, 
+                    chunks[2].x + column_of_char(&app.email, app.email_cursor) as u16 + 1,
                     chunks[2].y + 1
                 ));
             }
             _ => {}
         }
     }
+
+    // Ctrl+R reverse history search
+    if let Some(history) = app.searching_history() {
+        let area = f.area();
+        history.render_search(f, area);
+    }
+
+    if let Some((dialog, _)) = &app.restore {
+        let area = f.area();
+        dialog.render(f, area);
+    }
+
+    if let Some(confetti) = &app.confetti {
+        let area = f.area();
+        confetti.render(f, area);
+    }
 }
 
 pub fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
+    config: &ConfigHandle,
 ) -> std::io::Result<()> {
-    let mut app = App::default();
+    let mut app = App::new(config);
     
     loop {
-        // Update loading progress if generating
-        if app.show_loading {
-            app.update_loading();
-        }
+        // Apply progress reported by background tasks
+        app.poll_tasks();
+        app.autosave_draft();
         
-        terminal.draw(|f| ui(f, &app))?;
+        let frame = terminal.draw(|f| {
+            ui(f, &app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
         
-        // Handle events with timeout for loading updates
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
+        // Handle events with timeout for loading updates; keys queued while
+        // drawing (fast typing, pastes) are handled before the next frame
+        for event in app.events.next_batch(Duration::from_millis(100))? {
+            if let Event::Key(key) = event {
+                if app.restore.is_some() {
+                    app.handle_restore_key(key.code);
+                    continue;
+                }
                 // FIXED: Removed KeyEventKind filtering that was blocking input
-                match app.input_mode {
+                match app.input_mode.current() {
                     InputMode::Navigation => match key.code {
-                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Char('q') | KeyCode::Esc => {
+                            // Quitting mid-entry keeps the latest draft for next launch
+                            if !app.draft().is_empty() {
+                                app.drafts.save(&app.draft());
+                            }
+                            return Ok(());
+                        }
                         KeyCode::Tab => {
                             app.active_field = match app.active_field {
                                 InputField::Name => InputField::Email,
@@ -882,28 +1050,21 @@ pub fn run_app<B: Backend>(
                         }
                         KeyCode::Enter => {
                             if app.active_field == InputField::Name || app.active_field == InputField::Email {
-                                app.input_mode = InputMode::Editing;
+                                app.transition(InputMode::Editing);
                             } else if app.active_field == InputField::Selection && app.name_valid && app.email_valid {
-                                let selection = match app.selected_option {
-                                    SelectOption::Yes => "Yes",
-                                    SelectOption::No => "No",
-                                    SelectOption::Maybe => "Maybe",
-                                    SelectOption::Other => "Other",
-                                };
-                                
                                 let entry = format!(
                                     "✓ Form submitted - Name: {}, Email: {}, Selection: {}",
-                                    app.name, app.email, selection
+                                    app.name, app.email, app.selected_option.label()
                                 );
                                 app.add_to_history(entry);
+                                app.drafts.discard();
+                                app.confetti = Confetti::celebrate(app.reduce_motion);
                             } else if app.active_field == InputField::Selection {
                                 app.add_to_history("✗ Please complete all fields correctly".to_string());
                             }
                         }
                         KeyCode::Char(' ') => {
-                            if app.name_valid && app.email_valid && !app.show_loading {
-                                app.start_campaign_generation();
-                            }
+                            app.transition(InputMode::Generating);
                         }
                         KeyCode::Char('c') => {
                             if key.modifiers.contains(KeyModifiers::CONTROL) {
@@ -927,18 +1088,17 @@ pub fn run_app<B: Backend>(
                         }
                         _ => {}
                     },
+                    InputMode::Editing if app.searching_history().is_some() => {
+                        app.handle_history_search_key(key);
+                    }
                     InputMode::Editing => match key.code {
                         KeyCode::Enter | KeyCode::Esc => {
-                            // Save current input to command history
-                            let current_text = match app.active_field {
-                                InputField::Name => app.name.clone(),
-                                InputField::Email => app.email.clone(),
-                                _ => String::new(),
-                            };
-                            if !current_text.is_empty() {
-                                app.add_to_command_history(current_text);
+                            app.transition(InputMode::Navigation);
+                        }
+                        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            if let Some(history) = app.active_history() {
+                                history.start_search();
                             }
-                            app.input_mode = InputMode::Navigation;
                         }
                         KeyCode::Up => {
                             if app.scroll_command_history(1) {
@@ -950,43 +1110,24 @@ pub fn run_app<B: Backend>(
                                 app.add_to_history("↓ Next command".to_string());
                             }
                         }
-                        KeyCode::Char(c) => {
-                            match app.active_field {
-                                InputField::Name => {
-                                    app.name.push(c);
-                                    app.validate_name();
-                                }
-                                InputField::Email => {
-                                    app.email.push(c);
-                                    app.validate_email();
-                                }
-                                _ => {}
+                        // Typing plus readline editing: Ctrl+Left/Right word navigation,
+                        // Ctrl+Backspace, Ctrl+U, Ctrl+K and Alt+D
+                        _ => match app.active_field {
+                            InputField::Name if handle_edit_key(&mut app.name, &mut app.name_cursor, key) => {
+                                app.validate_name();
                             }
-                        }
-                        KeyCode::Backspace => {
-                            match app.active_field {
-                                InputField::Name => {
-                                    if !app.name.is_empty() {
-                                        app.name.pop();
-                                        app.validate_name();
-                                    }
-                                }
-                                InputField::Email => {
-                                    if !app.email.is_empty() {
-                                        app.email.pop();
-                                        app.validate_email();
-                                    }
-                                }
-                                _ => {}
+                            InputField::Email if handle_edit_key(&mut app.email, &mut app.email_cursor, key) => {
+                                app.validate_email();
                             }
-                        }
-                        _ => {}
+                            _ => {}
+                        },
                     },
                     InputMode::Generating => {
-                        // Block input during generation except for quit
-                        if let KeyCode::Char('q') | KeyCode::Esc = key.code {
+                        // Only quit and task panel keys (↑↓ select, Esc cancel) during generation
+                        if let KeyCode::Char('q') = key.code {
                             return Ok(());
                         }
+                        app.tasks.handle_key(key.code);
                     }
                 }
             }
@@ -994,16 +1135,24 @@ pub fn run_app<B: Backend>(
     }
 }
 
-fn main() -> Result<(), io::Error> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Run app
-    let res = run_app(&mut terminal);
+    let res = run_app(&mut terminal, &args.config());
 
     // Restore terminal
     disable_raw_mode()?;
@@ -1014,9 +1163,19 @@ fn main() -> Result<(), io::Error> {
     )?;
     terminal.show_cursor()?;
 
-    if let Err(err) = res {
-        eprintln!("Error: {err:?}")
-    }
-
+    res?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let app = App::new(&ConfigHandle::default());
+        snapshot::assert_screen("interactive_form", |f| ui(f, &app));
+    }
+}
@@ -1,12 +1,19 @@
-use std::io::{self, Write};
-use std::thread;
-use std::time::Duration;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use crossterm::{
-    cursor,
     execute,
     style::{Color, Print, ResetColor, SetForegroundColor},
-    terminal::{Clear, ClearType},
 };
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use serde::Serialize;
+use tui_image_viewer::app::cli::{self, CommonArgs};
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::inline;
+use tui_image_viewer::widgets::links;
+use tui_image_viewer::widgets::prompt;
+use tui_image_viewer::widgets::redact;
+use tui_image_viewer::widgets::spinner::SpinnerStyle;
 
 #[derive(Debug, Clone)]
 struct UserData {
@@ -14,50 +21,160 @@ struct UserData {
     name: String,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Parse command line arguments
-    let args: Vec<String> = std::env::args().collect();
-    
-    if args.len() >= 3 && args[1] == "authenticate" && args[2] == "login" {
-        run_journal_flow()?;
-    } else {
-        show_usage();
+#[derive(Debug, Parser)]
+#[command(version, about = "Create a Journal account and an email campaign")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Account sign-in
+    Authenticate {
+        #[command(subcommand)]
+        action: AuthAction,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum AuthAction {
+    /// Create an account, then optionally an email campaign
+    Login(Options),
+}
+
+/// Answers given on the command line instead of at the prompts
+#[derive(Debug, Default, PartialEq, Args)]
+struct Options {
+    /// Account email; with --name, runs unattended and prints JSON
+    #[arg(long, value_parser = parse_email)]
+    email: Option<String>,
+    /// Account name; with --email, runs unattended and prints JSON
+    #[arg(long, value_parser = parse_name)]
+    name: Option<String>,
+    /// Generate the campaign without asking
+    #[arg(short, long)]
+    yes: bool,
+    /// Directory campaigns are created in
+    #[arg(long, value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+}
+
+impl Options {
+    /// With both email and name given there is nothing to ask, so the flow
+    /// runs unattended and reports what it created as JSON
+    fn unattended(&self) -> bool {
+        self.email.is_some() && self.name.is_some()
+    }
+
+    fn output_dir(&self) -> &Path {
+        self.output_dir.as_deref().unwrap_or(Path::new("./campaigns"))
+    }
+}
+
+/// What an unattended run created, printed as JSON on stdout
+#[derive(Debug, Serialize)]
+struct Report {
+    email: String,
+    name: String,
+    account_log: String,
+    campaign: Option<Campaign>,
+}
+
+#[derive(Debug, Serialize)]
+struct Campaign {
+    directory: String,
+    files: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse_with(|args: &Cli| &args.common);
+    // Loading the config applies its redaction settings to the account logs
+    let _config = args.common.config();
+    
+    match args.command {
+        Some(Command::Authenticate { action: AuthAction::Login(options) }) => {
+            if options.unattended() {
+                run_unattended(&options)?;
+            } else {
+                run_journal_flow(&options)?;
+            }
+        }
+        None => Cli::command().print_help()?,
     }
     
     Ok(())
 }
 
-fn show_usage() {
-    println!("Usage: cargo run --bin journal_cli authenticate login");
+fn parse_email(email: &str) -> Result<String, String> {
+    if validate_email(email) {
+        Ok(email.to_string())
+    } else {
+        Err("invalid email format (e.g., user@example.com)".to_string())
+    }
 }
 
-fn run_journal_flow() -> Result<(), Box<dyn std::error::Error>> {
-    // Clear screen for clean start
-    clear_screen()?;
-    
+fn parse_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.len() < 2 {
+        Err("must be at least 2 characters long".to_string())
+    } else {
+        Ok(name.to_string())
+    }
+}
+
+/// Create the account, and the campaign with `--yes`, without prompts,
+/// spinners or opening a file manager
+fn run_unattended(options: &Options) -> Result<(), DemoError> {
+    let user_data = UserData {
+        email: options.email.clone().unwrap_or_default(),
+        name: options.name.clone().unwrap_or_default(),
+    };
+    let account_log = simulate_web_service_call(&user_data)?;
+    let campaign = if options.yes {
+        Some(create_campaign_files(&user_data, options.output_dir())?)
+    } else {
+        None
+    };
+
+    let report = Report {
+        email: user_data.email,
+        name: user_data.name,
+        account_log,
+        campaign,
+    };
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+fn run_journal_flow(options: &Options) -> Result<(), DemoError> {
+    // The prompts read keys in raw mode
+    cli::require_terminal()?;
+
     // Show welcome header with image placeholder
     show_welcome_header()?;
     
     // Collect user information
-    let user_data = collect_user_info()?;
+    let user_data = collect_user_info(options)?;
     
     // Create account with loading animation
     create_account(&user_data)?;
     
     // Ask about email campaign
-    if prompt_campaign_generation()? {
-        generate_campaign(&user_data)?;
+    if options.yes || prompt_campaign_generation()? {
+        generate_campaign(&user_data, options.output_dir())?;
     }
     
     Ok(())
 }
 
-fn clear_screen() -> Result<(), Box<dyn std::error::Error>> {
-    execute!(io::stdout(), Clear(ClearType::All), cursor::MoveTo(0, 0))?;
-    Ok(())
-}
-
-fn show_welcome_header() -> Result<(), Box<dyn std::error::Error>> {
+fn show_welcome_header() -> Result<(), DemoError> {
     println!();
     execute!(
         io::stdout(),
@@ -74,103 +191,61 @@ fn show_welcome_header() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn collect_user_info() -> Result<UserData, Box<dyn std::error::Error>> {
+fn collect_user_info(options: &Options) -> Result<UserData, DemoError> {
     println!("Welcome to Journal, please provide your Email to create an account");
     
-    // Get email with validation
-    let email = get_validated_email()?;
-    
-    // Get name
-    let name = get_user_name()?;
+    // Get email with validation, unless given with --email
+    let email = match &options.email {
+        Some(email) => {
+            prompt::echo(&format!("Email: {}", email))?;
+            email.clone()
+        }
+        None => get_validated_email()?,
+    };
+    
+    // Get name, unless given with --name
+    let name = match &options.name {
+        Some(name) => {
+            prompt::echo(&format!("Name: {}", name))?;
+            name.clone()
+        }
+        None => get_user_name()?,
+    };
     
     Ok(UserData { email, name })
 }
 
-fn get_validated_email() -> Result<String, Box<dyn std::error::Error>> {
-    loop {
-        print!("Email: ");
-        io::stdout().flush()?;
-        
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let email = input.trim().to_string();
-        
-        if email.is_empty() {
-            execute!(
-                io::stdout(),
-                SetForegroundColor(Color::Red),
-                Print("❌ Email cannot be empty. Please try again.\n"),
-                ResetColor
-            )?;
-            continue;
-        }
-        
-        if validate_email(&email) {
-            // Echo back the entered email
-            execute!(
-                io::stdout(),
-                SetForegroundColor(Color::Green),
-                Print(&format!("Email: {}\n", email)),
-                ResetColor
-            )?;
-            return Ok(email);
-        } else {
-            execute!(
-                io::stdout(),
-                SetForegroundColor(Color::Red),
-                Print("❌ Invalid email format. Please enter a valid email (e.g., user@example.com)\n"),
-                ResetColor
-            )?;
-        }
-    }
+fn get_validated_email() -> Result<String, DemoError> {
+    prompt::Text::new("Email")
+        .validate(|email| {
+            if validate_email(email) {
+                Ok(())
+            } else {
+                Err("Invalid email format. Please enter a valid email (e.g., user@example.com)".to_string())
+            }
+        })
+        .interact()
 }
 
-fn get_user_name() -> Result<String, Box<dyn std::error::Error>> {
-    loop {
-        print!("Name: ");
-        io::stdout().flush()?;
-        
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let name = input.trim().to_string();
-        
-        if name.is_empty() {
-            execute!(
-                io::stdout(),
-                SetForegroundColor(Color::Red),
-                Print("❌ Name cannot be empty. Please try again.\n"),
-                ResetColor
-            )?;
-            continue;
-        }
-        
-        if name.len() < 2 {
-            execute!(
-                io::stdout(),
-                SetForegroundColor(Color::Red),
-                Print("❌ Name must be at least 2 characters long.\n"),
-                ResetColor
-            )?;
-            continue;
-        }
-        
-        // Echo back the entered name
-        execute!(
-            io::stdout(),
-            SetForegroundColor(Color::Green),
-            Print(&format!("Name: {}\n", name)),
-            ResetColor
-        )?;
-        
-        return Ok(name);
-    }
+fn get_user_name() -> Result<String, DemoError> {
+    prompt::Text::new("Name")
+        .validate(|name| {
+            if name.len() < 2 {
+                Err("Name must be at least 2 characters long.".to_string())
+            } else {
+                Ok(())
+            }
+        })
+        .interact()
 }
 
-fn create_account(user_data: &UserData) -> Result<(), Box<dyn std::error::Error>> {
+fn create_account(user_data: &UserData) -> Result<(), DemoError> {
     println!("Thank you, Journal is creating an account for you. Standby...");
     
-    // Show loading animation
-    show_loading_animation("Creating your account", 3)?;
+    // Send data to the web service while the spinner runs
+    inline::spinner(SpinnerStyle::Braille, "Creating your account", || {
+        simulate_web_service_call(user_data)
+    })??;
     
     execute!(
         io::stdout(),
@@ -179,56 +254,18 @@ fn create_account(user_data: &UserData) -> Result<(), Box<dyn std::error::Error>
         ResetColor
     )?;
     
-    // Simulate sending data to web service
-    simulate_web_service_call(user_data)?;
-    
     Ok(())
 }
 
-fn prompt_campaign_generation() -> Result<bool, Box<dyn std::error::Error>> {
-    println!("Would you like to generate an Email campaign?");
-    
-    loop {
-        print!("Yes / No: ");
-        io::stdout().flush()?;
-        
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let choice = input.trim().to_lowercase();
-        
-        match choice.as_str() {
-            "yes" | "y" => {
-                execute!(
-                    io::stdout(),
-                    SetForegroundColor(Color::Green),
-                    Print("→ Yes\n"),
-                    ResetColor
-                )?;
-                return Ok(true);
-            }
-            "no" | "n" => {
-                execute!(
-                    io::stdout(),
-                    SetForegroundColor(Color::Yellow),
-                    Print("→ No\n"),
-                    ResetColor
-                )?;
-                return Ok(false);
-            }
-            _ => {
-                execute!(
-                    io::stdout(),
-                    SetForegroundColor(Color::Red),
-                    Print("❌ Please enter 'Yes' or 'No'\n"),
-                    ResetColor
-                )?;
-            }
-        }
-    }
+fn prompt_campaign_generation() -> Result<bool, DemoError> {
+    prompt::confirm("Would you like to generate an Email campaign?", true)
 }
 
-fn generate_campaign(user_data: &UserData) -> Result<(), Box<dyn std::error::Error>> {
-    show_loading_animation("Generating email campaign", 2)?;
+fn generate_campaign(user_data: &UserData, output_dir: &Path) -> Result<(), DemoError> {
+    // Create campaign files
+    let campaign = inline::spinner(SpinnerStyle::Braille, "Generating email campaign", || {
+        create_campaign_files(user_data, output_dir)
+    })??;
     
     execute!(
         io::stdout(),
@@ -237,42 +274,8 @@ fn generate_campaign(user_data: &UserData) -> Result<(), Box<dyn std::error::Err
         ResetColor
     )?;
     
-    // Create campaign files
-    let campaign_path = create_campaign_files(user_data)?;
-    
     // Open file explorer
-    open_file_explorer(&campaign_path)?;
-    
-    Ok(())
-}
-
-fn show_loading_animation(message: &str, duration_secs: u64) -> Result<(), Box<dyn std::error::Error>> {
-    let frames = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-    let total_iterations = duration_secs * 10; // 100ms per frame
-    
-    for i in 0..total_iterations {
-        let frame_idx = (i as usize) % frames.len();
-        let frame = frames[frame_idx];
-        
-        execute!(
-            io::stdout(),
-            cursor::SavePosition,
-            SetForegroundColor(Color::Cyan),
-            Print(frame),
-            SetForegroundColor(Color::White),
-            Print(&format!(" {}", message)),
-            ResetColor
-        )?;
-        
-        io::stdout().flush()?;
-        thread::sleep(Duration::from_millis(100));
-        
-        execute!(
-            io::stdout(),
-            cursor::RestorePosition,
-            Clear(ClearType::FromCursorDown)
-        )?;
-    }
+    open_file_explorer(&campaign.directory)?;
     
     Ok(())
 }
@@ -281,7 +284,8 @@ fn validate_email(email: &str) -> bool {
     email.contains('@') && email.contains('.') && email.len() > 5
 }
 
-fn simulate_web_service_call(user_data: &UserData) -> Result<(), Box<dyn std::error::Error>> {
+/// Log the account request and return the log file's path
+fn simulate_web_service_call(user_data: &UserData) -> Result<String, DemoError> {
     // Create logs directory
     std::fs::create_dir_all("./logs")?;
     
@@ -301,21 +305,21 @@ fn simulate_web_service_call(user_data: &UserData) -> Result<(), Box<dyn std::er
         user_data.name
     );
     
-    // Write to log file
+    // Write to log file, masking secrets (and emails, if configured)
     let log_file = format!("./logs/api_call_{}.json", chrono::Utc::now().timestamp());
-    std::fs::write(log_file, payload)?;
+    std::fs::write(&log_file, redact::redact(&payload))?;
     
-    Ok(())
+    Ok(std::fs::canonicalize(&log_file)?.to_string_lossy().to_string())
 }
 
-fn create_campaign_files(user_data: &UserData) -> Result<String, Box<dyn std::error::Error>> {
+fn create_campaign_files(user_data: &UserData, output_dir: &Path) -> Result<Campaign, DemoError> {
     use std::fs;
     
     // Create campaign directory
     let safe_email = user_data.email.replace('@', "_at_").replace('.', "_");
     let campaign_name = format!("journal_campaign_{}", safe_email);
     let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-    let campaign_dir = format!("./campaigns/{}_{}", campaign_name, timestamp);
+    let campaign_dir = output_dir.join(format!("{}_{}", campaign_name, timestamp)).to_string_lossy().to_string();
     
     // Create directory structure
     fs::create_dir_all(&campaign_dir)?;
@@ -403,10 +407,18 @@ Generated by Journal CLI
     
     fs::write(format!("{}/README.md", campaign_dir), readme)?;
     
-    Ok(std::fs::canonicalize(&campaign_dir)?.to_string_lossy().to_string())
+    let directory = fs::canonicalize(&campaign_dir)?;
+    let files = ["campaign.json", "templates/welcome_email.html", "README.md"]
+        .iter()
+        .map(|file| directory.join(file).to_string_lossy().to_string())
+        .collect();
+    Ok(Campaign {
+        directory: directory.to_string_lossy().to_string(),
+        files,
+    })
 }
 
-fn open_file_explorer(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+fn open_file_explorer(path: &str) -> Result<(), DemoError> {
     execute!(
         io::stdout(),
         SetForegroundColor(Color::Cyan),
@@ -414,27 +426,9 @@ fn open_file_explorer(path: &str) -> Result<(), Box<dyn std::error::Error>> {
         ResetColor
     )?;
     
-    // Platform-specific file explorer opening
-    #[cfg(target_os = "windows")]
-    {
-        std::process::Command::new("explorer")
-            .arg(path)
-            .spawn()?;
-    }
-    
-    #[cfg(target_os = "macos")]
-    {
-        std::process::Command::new("open")
-            .arg(path)
-            .spawn()?;
-    }
-    
-    #[cfg(target_os = "linux")]
-    {
-        std::process::Command::new("xdg-open")
-            .arg(path)
-            .spawn()?;
-    }
+    links::open_path(std::path::Path::new(path))
+        .and_then(links::Launch::wait)
+        .map_err(io::Error::other)?;
     
     execute!(
         io::stdout(),
@@ -458,4 +452,28 @@ mod tests {
         assert!(!validate_email("@domain.com"));
         assert!(!validate_email("user@"));
     }
+    
+    #[test]
+    fn test_parse_options() {
+        let options = |args: &[&str]| match Cli::try_parse_from(args) {
+            Ok(Cli { command: Some(Command::Authenticate { action: AuthAction::Login(options) }), .. }) => Ok(options),
+            Ok(_) => panic!("expected authenticate login"),
+            Err(e) => Err(e),
+        };
+        let login = ["journal_cli", "authenticate", "login"];
+        
+        let parsed = options(&[&login[..], &["--email", "ada@example.com", "--name=Ada", "--yes", "--output-dir", "out"]].concat()).unwrap();
+        assert!(parsed.unattended());
+        assert!(parsed.yes);
+        assert_eq!(parsed.output_dir(), Path::new("out"));
+        
+        let parsed = options(&[&login[..], &["--name", "Ada", "--no-mouse"]].concat()).unwrap();
+        assert!(!parsed.unattended());
+        assert_eq!(parsed.output_dir(), Path::new("./campaigns"));
+        
+        assert!(options(&[&login[..], &["--email", "not-an-email"]].concat()).is_err());
+        assert!(options(&[&login[..], &["--email"]].concat()).is_err());
+        assert!(options(&[&login[..], &["--verbose"]].concat()).is_err());
+        Cli::command().debug_assert();
+    }
 }
@@ -115,3 +115,16 @@ fn main() -> Result<(), io::Error> {
     println!("Final input was: '{}'", app.input);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let app = App::default();
+        snapshot::assert_screen("keyboard_test", |f| ui(f, &app));
+    }
+}
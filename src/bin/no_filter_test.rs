@@ -188,3 +188,16 @@ fn main() -> Result<(), io::Error> {
              app.name, app.email, app.selection);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let app = App::default();
+        snapshot::assert_screen("no_filter_test", |f| ui(f, &app));
+    }
+}
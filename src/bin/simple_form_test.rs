@@ -315,3 +315,16 @@ fn main() -> Result<(), io::Error> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let app = App::default();
+        snapshot::assert_screen("simple_form_test", |f| ui(f, &app));
+    }
+}
@@ -0,0 +1,154 @@
+//! Error module
+//!
+//! Crate-wide error type. Library users can match on what kind of failure
+//! happened, and the examples show `hint()` next to the message so the user
+//! knows what to try next. Binaries exit with `exit_code()` so scripts can
+//! tell failures apart.
+
+use std::io;
+
+use thiserror::Error;
+
+#[cfg(feature = "image")]
+use crate::widgets::image::ImageError;
+
+#[derive(Debug, Error)]
+pub enum DemoError {
+    /// Starting a shell on a pseudo-terminal
+    #[error("Terminal error: {0}")]
+    Terminal(String),
+    #[error("Clipboard error: {0}")]
+    Clipboard(String),
+    #[cfg(feature = "image")]
+    #[error(transparent)]
+    Image(#[from] ImageError),
+    /// Probing or decoding video, usually through ffmpeg
+    #[error("Video error: {0}")]
+    Video(String),
+    /// Decoding or playing an audio clip
+    #[error("Audio error: {0}")]
+    Audio(String),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    /// Reading, parsing or saving the config file
+    #[error("Config error: {0}")]
+    Config(String),
+    #[error("HTTP error: {0}")]
+    Http(String),
+    /// Not running in an interactive terminal, e.g. with output piped
+    #[error("Terminal not supported: {0}")]
+    Unsupported(String),
+}
+
+/// Process exit codes, following the BSD `sysexits.h` values where one fits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    Failure = 1,
+    /// Bad command-line arguments; clap exits with this too
+    Usage = 2,
+    NotFound = 66,
+    Unsupported = 69,
+    Config = 78,
+    /// Cancelled at a prompt, as for Ctrl+C
+    Aborted = 130,
+}
+
+impl DemoError {
+    /// What the user can try to fix the failure
+    pub fn hint(&self) -> &'static str {
+        match self {
+            DemoError::Terminal(_) => "Check that a shell is installed and $SHELL points to it",
+            DemoError::Clipboard(_) => "The clipboard needs a desktop session; on Linux install xclip or wl-clipboard",
+            #[cfg(feature = "image")]
+            DemoError::Image(_) => "Check that the file is a PNG, JPEG, GIF or BMP image and is not truncated",
+            DemoError::Video(_) => "Install ffmpeg and make sure ffmpeg and ffprobe are on PATH",
+            DemoError::Audio(_) => "Check that a sound device is available and the file is a WAV, MP3, FLAC or Ogg clip",
+            DemoError::Io(_) => "Check that the path exists and that you may read and write it",
+            DemoError::Config(_) => "Fix the config file, or delete it to start again from defaults",
+            DemoError::Http(_) => "Check the network connection and the URL",
+            DemoError::Unsupported(_) => "Run it directly in a terminal window, without piping or redirecting",
+        }
+    }
+
+    pub fn exit_code(&self) -> Exit {
+        match self {
+            DemoError::Config(_) => Exit::Config,
+            DemoError::Unsupported(_) => Exit::Unsupported,
+            DemoError::Io(err) => match err.kind() {
+                io::ErrorKind::NotFound => Exit::NotFound,
+                io::ErrorKind::Interrupted => Exit::Aborted,
+                _ => Exit::Failure,
+            },
+            _ => Exit::Failure,
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::ImageError> for DemoError {
+    fn from(err: image::ImageError) -> Self {
+        DemoError::Image(err.into())
+    }
+}
+
+/// Data files are JSON, so a JSON error is a failed read or write
+impl From<serde_json::Error> for DemoError {
+    fn from(err: serde_json::Error) -> Self {
+        DemoError::Io(err.into())
+    }
+}
+
+impl From<toml::de::Error> for DemoError {
+    fn from(err: toml::de::Error) -> Self {
+        DemoError::Config(err.to_string())
+    }
+}
+
+impl From<toml::ser::Error> for DemoError {
+    fn from(err: toml::ser::Error) -> Self {
+        DemoError::Config(err.to_string())
+    }
+}
+
+#[cfg(feature = "clipboard")]
+impl From<arboard::Error> for DemoError {
+    fn from(err: arboard::Error) -> Self {
+        DemoError::Clipboard(err.to_string())
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<ureq::Error> for DemoError {
+    fn from(err: ureq::Error) -> Self {
+        DemoError::Http(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions_pick_variant() {
+        let err: DemoError = io::Error::new(io::ErrorKind::NotFound, "missing").into();
+        assert!(matches!(err, DemoError::Io(_)));
+        assert_eq!(err.to_string(), "IO error: missing");
+
+        let err: DemoError = toml::from_str::<toml::Table>("key = ").unwrap_err().into();
+        assert!(matches!(err, DemoError::Config(_)));
+        assert!(err.hint().contains("config file"));
+
+        let err: DemoError = serde_json::from_str::<u32>("nope").unwrap_err().into();
+        assert!(matches!(err, DemoError::Io(_)));
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let io_error = |kind| DemoError::from(io::Error::new(kind, "test"));
+        assert_eq!(io_error(io::ErrorKind::NotFound).exit_code(), Exit::NotFound);
+        assert_eq!(io_error(io::ErrorKind::Interrupted).exit_code(), Exit::Aborted);
+        assert_eq!(io_error(io::ErrorKind::PermissionDenied).exit_code(), Exit::Failure);
+        assert_eq!(DemoError::Config("bad".to_string()).exit_code() as u8, 78);
+        assert_eq!(DemoError::Unsupported("piped".to_string()).exit_code() as u8, 69);
+    }
+}
@@ -0,0 +1,136 @@
+//! Event module
+//!
+//! The key, mouse and resize events the widgets handle, and where an
+//! `EventDispatcher` reads them from. Native builds use crossterm's types
+//! as they are, so the binaries pass terminal events straight through; the
+//! browser build has the same types without a terminal behind them and
+//! gets its events pushed in from xterm.js.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::io;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+#[cfg(target_arch = "wasm32")]
+mod keys;
+#[cfg(target_arch = "wasm32")]
+pub use keys::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+/// Where events come from: the terminal, or a queue filled by the host
+pub trait EventSource: Debug {
+    /// Whether an event is ready within `timeout`
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool>;
+
+    /// The next event, waiting for it if the source can wait
+    fn read(&mut self) -> io::Result<Event>;
+
+    /// Current size in columns and rows
+    fn size(&self) -> io::Result<(u16, u16)>;
+}
+
+/// The source a new `EventDispatcher` reads from: the terminal in native
+/// builds, a `QueuedEvents` in the browser
+impl Default for Box<dyn EventSource> {
+    fn default() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        return Box::new(TerminalEvents);
+        #[cfg(target_arch = "wasm32")]
+        return Box::new(QueuedEvents::default());
+    }
+}
+
+/// Events of the terminal the process runs in
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TerminalEvents;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl EventSource for TerminalEvents {
+    fn poll(&mut self, timeout: Duration) -> io::Result<bool> {
+        crossterm::event::poll(timeout)
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        crossterm::event::read()
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        crossterm::terminal::size()
+    }
+}
+
+/// Events pushed by the host, e.g. from xterm.js callbacks. Clones share
+/// the queue, so the host keeps one to push into. It cannot wait, so
+/// `read` on an empty queue is an error.
+#[derive(Debug, Clone)]
+pub struct QueuedEvents {
+    queue: Rc<RefCell<VecDeque<Event>>>,
+    size: Rc<RefCell<(u16, u16)>>,
+}
+
+impl Default for QueuedEvents {
+    fn default() -> Self {
+        Self {
+            queue: Rc::default(),
+            size: Rc::new(RefCell::new((80, 24))),
+        }
+    }
+}
+
+impl QueuedEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, event: Event) {
+        if let Event::Resize(columns, rows) = event {
+            *self.size.borrow_mut() = (columns, rows);
+        }
+        self.queue.borrow_mut().push_back(event);
+    }
+}
+
+impl EventSource for QueuedEvents {
+    fn poll(&mut self, _timeout: Duration) -> io::Result<bool> {
+        Ok(!self.queue.borrow().is_empty())
+    }
+
+    fn read(&mut self) -> io::Result<Event> {
+        self.queue
+            .borrow_mut()
+            .pop_front()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::WouldBlock, "no queued events"))
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok(*self.size.borrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queued_events_share_the_queue() {
+        let host = QueuedEvents::new();
+        let mut source = host.clone();
+        assert!(!source.poll(Duration::ZERO).unwrap());
+        assert!(source.read().is_err());
+
+        host.push(Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)));
+        host.push(Event::Resize(100, 30));
+        assert!(source.poll(Duration::ZERO).unwrap());
+        assert_eq!(source.read().unwrap(), Event::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)));
+        assert_eq!(source.size().unwrap(), (100, 30));
+    }
+}
@@ -3,7 +3,7 @@
 //! Displays a sequence of images as ASCII art animation
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -21,6 +21,9 @@ use std::{
     path::Path,
     time::{Duration, Instant},
 };
+use std::process::ExitCode;
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
 
 struct AnimationApp {
     frame_files: Vec<String>,
@@ -69,7 +72,7 @@ impl AnimationApp {
         }
     }
 
-    fn load_current_frame(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn load_current_frame(&mut self) -> Result<(), DemoError> {
         if self.frame_files.is_empty() {
             self.ascii_art = "No frames found in the specified directory.\n\nMake sure you have extracted frames using:\nffmpeg -i assets/sample.mp4 -vf \"fps=10\" frames/output_%03d.jpg".to_string();
             return Ok(());
@@ -213,7 +216,13 @@ impl AnimationApp {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Create frames directory if it doesn't exist
     let frames_dir = "frames";
     if !Path::new(frames_dir).exists() {
@@ -224,9 +233,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -3,7 +3,7 @@
 //! A simplified image viewer that works without FFmpeg dependencies
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -17,6 +17,9 @@ use ratatui::{
     Frame, Terminal,
 };
 use std::io;
+use std::process::ExitCode;
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
 
 struct ImageViewerApp {
     image_path: String,
@@ -35,7 +38,7 @@ impl ImageViewerApp {
         }
     }
 
-    fn load_image(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+    fn load_image(&mut self) -> Result<(), DemoError> {
         // Load the image
         let img = image::open(&self.image_path)?;
 
@@ -131,11 +134,19 @@ impl ImageViewerApp {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -0,0 +1,358 @@
+//! Campaign manager example
+//!
+//! Lists the campaigns generated by the journal CLIs under `./campaigns` and
+//! shows the selected campaign's send sequence on a timeline.
+
+use crossterm::{
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use chrono::{Local, Weekday};
+use std::process::ExitCode;
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::calendar::{Calendar, DatePicker, DatePickerAction};
+use tui_image_viewer::widgets::progress::{TaskManager, TaskOutcome};
+use tui_image_viewer::widgets::spinner::{Skeleton, Spinner, SpinnerStyle};
+use tui_image_viewer::widgets::timeline::Timeline;
+use tui_image_viewer::widgets::watcher::FileWatcher;
+
+const CAMPAIGNS_DIR: &str = "./campaigns";
+
+#[derive(PartialEq)]
+enum Focus {
+    List,
+    Timeline,
+}
+
+struct CampaignApp {
+    campaigns: Vec<PathBuf>,
+    list_state: ListState,
+    timeline: Option<Timeline>,
+    focus: Focus,
+    status_message: String,
+    should_quit: bool,
+    scan: TaskManager<Vec<PathBuf>>,
+    skeleton: Skeleton,
+    spinner: Spinner,
+    date_picker: Option<DatePicker>,
+    week_start: Weekday,
+    /// Rescans campaigns when anything under the campaigns directory changes
+    watcher: FileWatcher,
+    config: ConfigHandle,
+}
+
+impl CampaignApp {
+    fn new(config: ConfigHandle) -> Self {
+        let (week_start, watch_files) = config.read(|config| (config.week_start, config.watch_files));
+        let mut app = Self {
+            campaigns: Vec::new(),
+            list_state: ListState::default(),
+            timeline: None,
+            focus: Focus::List,
+            status_message: "↑↓: select campaign | Enter/Tab: focus timeline | w: auto-refresh | q: Quit".to_string(),
+            should_quit: false,
+            scan: TaskManager::new(),
+            skeleton: Skeleton::new(),
+            spinner: Spinner::new(SpinnerStyle::Braille, "Scanning campaigns"),
+            date_picker: None,
+            week_start,
+            watcher: FileWatcher::new(watch_files),
+            config,
+        };
+        app.scan.spawn("Scanning campaigns", |_| Ok(find_campaigns(Path::new(CAMPAIGNS_DIR))));
+        if Path::new(CAMPAIGNS_DIR).is_dir() {
+            app.watcher.watch(Path::new(CAMPAIGNS_DIR), true);
+        }
+        app
+    }
+
+    /// Rescan after debounced changes, keeping the selected campaign
+    fn poll_watcher(&mut self) {
+        if self.watcher.poll().is_none() || self.scan.is_busy() {
+            return;
+        }
+        let selected = self.list_state.selected().and_then(|i| self.campaigns.get(i)).cloned();
+        self.campaigns = find_campaigns(Path::new(CAMPAIGNS_DIR));
+        let index = selected
+            .and_then(|selected| self.campaigns.iter().position(|path| *path == selected))
+            .or(if self.campaigns.is_empty() { None } else { Some(0) });
+        self.list_state.select(index);
+        match index {
+            Some(_) => self.load_selected(),
+            None => self.timeline = None,
+        }
+    }
+
+    fn toggle_watch(&mut self) {
+        let mut watch_files = false;
+        let saved = self.config.update_and_save(|config| {
+            config.watch_files = !config.watch_files;
+            watch_files = config.watch_files;
+        });
+        self.watcher.set_enabled(watch_files);
+        self.status_message = if watch_files {
+            "Auto-refresh on file changes enabled".to_string()
+        } else {
+            "Auto-refresh on file changes disabled".to_string()
+        };
+        if let Err(e) = saved {
+            self.status_message = format!("Failed to save settings: {} ({})", e, e.hint());
+        }
+    }
+
+    /// Install the campaign list once the background scan finishes
+    fn poll_scan(&mut self) {
+        for outcome in self.scan.poll() {
+            match outcome {
+                TaskOutcome::Finished { output, .. } => {
+                    self.campaigns = output;
+                    if !self.campaigns.is_empty() {
+                        self.list_state.select(Some(0));
+                        self.load_selected();
+                    }
+                }
+                TaskOutcome::Failed { error, .. } => {
+                    self.status_message = format!("Failed to scan campaigns: {}", error);
+                }
+                TaskOutcome::Cancelled { .. } => {}
+            }
+        }
+    }
+
+    fn load_selected(&mut self) {
+        let Some(dir) = self.list_state.selected().and_then(|i| self.campaigns.get(i)) else {
+            return;
+        };
+
+        match fs::read_to_string(dir.join("campaign.json"))
+            .map_err(|e| e.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+        {
+            Ok(json) => {
+                self.timeline = Some(Timeline::from_campaign(&json));
+            }
+            Err(e) => {
+                self.timeline = None;
+                self.status_message = format!("Failed to read {}: {}", dir.display(), e);
+            }
+        }
+    }
+
+    fn select_offset(&mut self, offset: isize) {
+        if self.campaigns.is_empty() {
+            return;
+        }
+        let len = self.campaigns.len() as isize;
+        let current = self.list_state.selected().unwrap_or(0) as isize;
+        self.list_state.select(Some((current + offset).rem_euclid(len) as usize));
+        self.load_selected();
+    }
+
+    fn on_key(&mut self, key: KeyCode) {
+        if let Some(picker) = self.date_picker.as_mut() {
+            match picker.handle_key(key) {
+                DatePickerAction::Picked(date) => {
+                    if let Some(timeline) = self.timeline.as_mut() {
+                        timeline.jump_to(date.and_time(chrono::NaiveTime::MIN).and_utc());
+                    }
+                    self.date_picker = None;
+                }
+                DatePickerAction::Cancelled => self.date_picker = None,
+                DatePickerAction::None => {}
+            }
+            return;
+        }
+
+        match key {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('w') => self.toggle_watch(),
+            KeyCode::Esc if self.focus == Focus::Timeline => self.focus = Focus::List,
+            KeyCode::Esc => self.should_quit = true,
+            KeyCode::Tab | KeyCode::Enter => {
+                self.focus = match self.focus {
+                    Focus::List => Focus::Timeline,
+                    Focus::Timeline => Focus::List,
+                };
+                self.status_message = match self.focus {
+                    Focus::List => "↑↓: select campaign | Enter/Tab: focus timeline | w: auto-refresh | q: Quit",
+                    Focus::Timeline => "←→: scroll | ↑↓: step | +/-: zoom | t: jump to now | g: go to date | Tab/Esc: back",
+                }
+                .to_string();
+            }
+            _ => match self.focus {
+                Focus::List => match key {
+                    KeyCode::Down => self.select_offset(1),
+                    KeyCode::Up => self.select_offset(-1),
+                    _ => {}
+                },
+                Focus::Timeline if key == KeyCode::Char('g') && self.timeline.is_some() => {
+                    let calendar = Calendar::new(Local::now().date_naive()).with_week_start(self.week_start);
+                    self.date_picker = Some(DatePicker::new(calendar));
+                }
+                Focus::Timeline => {
+                    if let Some(timeline) = self.timeline.as_mut() {
+                        timeline.handle_key(key);
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Collect campaign directories that contain a `campaign.json`
+fn find_campaigns(root: &Path) -> Vec<PathBuf> {
+    let mut campaigns: Vec<PathBuf> = fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.join("campaign.json").is_file())
+                .collect()
+        })
+        .unwrap_or_default();
+    campaigns.sort();
+    campaigns
+}
+
+fn ui(f: &mut Frame, app: &mut CampaignApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(8),
+            Constraint::Min(8),
+            Constraint::Length(3),
+        ])
+        .split(f.area());
+
+    let focus_style = |focused: bool| {
+        if focused {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default()
+        }
+    };
+
+    // Skeleton placeholders while the campaign list is still loading
+    if app.scan.is_busy() {
+        app.skeleton.render_list(f, chunks[0], "Campaigns");
+        app.skeleton.render_table(f, chunks[1], "Timeline", 3);
+        app.spinner.render(f, chunks[2].inner(ratatui::layout::Margin::new(1, 1)));
+        return;
+    }
+
+    // Campaign list
+    let items: Vec<ListItem> = app
+        .campaigns
+        .iter()
+        .map(|path| ListItem::new(path.file_name().unwrap_or_default().to_string_lossy().to_string()))
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Campaigns ({})", app.campaigns.len()))
+                .border_style(focus_style(app.focus == Focus::List)),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
+    f.render_stateful_widget(list, chunks[0], &mut app.list_state);
+
+    // Timeline
+    match &app.timeline {
+        Some(timeline) => timeline.render(f, chunks[1]),
+        None => {
+            let empty = Paragraph::new(format!("No campaigns found in {}", CAMPAIGNS_DIR))
+                .block(Block::default().borders(Borders::ALL).title("Timeline"))
+                .style(Style::default().fg(Color::Gray));
+            f.render_widget(empty, chunks[1]);
+        }
+    }
+
+    let status = Paragraph::new(app.status_message.as_str())
+        .block(Block::default().borders(Borders::ALL).title("Status"))
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(status, chunks[2]);
+
+    if let Some(picker) = &app.date_picker {
+        let area = f.area();
+        picker.render(f, area);
+    }
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = CampaignApp::new(args.config());
+    let tick_rate = Duration::from_millis(80);
+
+    loop {
+        app.poll_scan();
+        app.poll_watcher();
+        terminal.draw(|f| ui(f, &mut app))?;
+
+        if event::poll(tick_rate)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.on_key(key.code);
+                }
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = CampaignApp::new(ConfigHandle::default());
+        snapshot::assert_screen("campaign_manager", |f| ui(f, &mut app));
+    }
+}
@@ -3,10 +3,12 @@
 //! Features:
 //! - Histogram showing data distribution
 //! - Bar chart with sample data
+//! - Scatter plot with zoom/pan and a nearest-point readout
 //! - Toggle between different chart views
+//! - Save the current chart as a PNG or copy it to the clipboard
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -20,14 +22,24 @@ use ratatui::{
     Terminal, Frame,
 };
 use std::{
-    error::Error,
     io,
+    path::Path,
     time::Duration,
 };
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::chart_image::{ChartData, ChartImage};
+use tui_image_viewer::widgets::scatter::{create_demo_scatter, ScatterPlot};
+
+const VIEW_COUNT: usize = 3;
 
 struct App {
     should_quit: bool,
     current_view: usize,
+    scatter: ScatterPlot,
+    status_message: Option<String>,
 }
 
 impl App {
@@ -35,16 +47,43 @@ impl App {
         Self {
             should_quit: false,
             current_view: 0,
+            scatter: create_demo_scatter(),
+            status_message: None,
         }
     }
 
+    /// Data of the current view for image export
+    fn chart_data(&self) -> ChartData {
+        match self.current_view {
+            0 => ChartData::Bars(bar_data().iter().map(|&(label, value)| (label.to_string(), value as f64)).collect()),
+            1 => ChartData::Lines(vec![line_data()]),
+            _ => ChartData::Points(self.scatter.points.clone()),
+        }
+    }
+
+    /// Write the current chart to `chart-<timestamp>.png`
+    fn save_image(&mut self) {
+        let path = format!("chart-{}.png", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+        self.status_message = Some(match ChartImage::default().save(&self.chart_data(), Path::new(&path)) {
+            Ok(()) => format!("Saved chart to {}", path),
+            Err(e) => format!("Failed to save chart: {}", e),
+        });
+    }
+
+    fn copy_image(&mut self) {
+        self.status_message = Some(match ChartImage::default().copy_to_clipboard(&self.chart_data()) {
+            Ok(()) => "Copied chart image to clipboard".to_string(),
+            Err(e) => format!("Failed to copy chart: {}", e),
+        });
+    }
+
     fn next_view(&mut self) {
-        self.current_view = (self.current_view + 1) % 2;
+        self.current_view = (self.current_view + 1) % VIEW_COUNT;
     }
 
     fn previous_view(&mut self) {
         if self.current_view == 0 {
-            self.current_view = 1;
+            self.current_view = VIEW_COUNT - 1;
         } else {
             self.current_view -= 1;
         }
@@ -65,6 +104,7 @@ fn ui(f: &mut Frame, app: &App) {
     let title = match app.current_view {
         0 => "Bar Chart Demo",
         1 => "Line Chart (Histogram) Demo",
+        2 => "Scatter Plot Demo",
         _ => "Charts Demo",
     };
     
@@ -77,18 +117,24 @@ fn ui(f: &mut Frame, app: &App) {
     match app.current_view {
         0 => render_bar_chart(f, chunks[1]),
         1 => render_line_chart(f, chunks[1]),
+        2 => app.scatter.render(f, chunks[1]),
         _ => {}
     }
 
-    // Instructions
-    let instructions = Paragraph::new("Tab: Switch view | q: Quit")
-        .block(Block::default().borders(Borders::ALL).title("Controls"))
+    // Instructions, with the scatter readout in place of the hint line
+    let instructions = if app.current_view == 2 {
+        format!("{} | Arrows: cursor | +/-: zoom | wasd: pan | 0: reset | i/y: PNG | Tab: view | q: Quit", app.scatter.readout())
+    } else {
+        "Tab: Switch view | i: Save PNG | y: Copy image | q: Quit".to_string()
+    };
+    let instructions = Paragraph::new(instructions)
+        .block(Block::default().borders(Borders::ALL).title(app.status_message.as_deref().unwrap_or("Controls")))
         .style(Style::default().fg(Color::Cyan));
     f.render_widget(instructions, chunks[2]);
 }
 
-fn render_bar_chart(f: &mut Frame, area: Rect) {
-    let data = vec![
+fn bar_data() -> Vec<(&'static str, u64)> {
+    vec![
         ("Jan", 20),
         ("Feb", 25),
         ("Mar", 30),
@@ -101,7 +147,11 @@ fn render_bar_chart(f: &mut Frame, area: Rect) {
         ("Oct", 29),
         ("Nov", 26),
         ("Dec", 31),
-    ];
+    ]
+}
+
+fn render_bar_chart(f: &mut Frame, area: Rect) {
+    let data = bar_data();
 
     let bar_chart = BarChart::default()
         .block(Block::default().borders(Borders::ALL).title("Monthly Sales"))
@@ -114,9 +164,9 @@ fn render_bar_chart(f: &mut Frame, area: Rect) {
     f.render_widget(bar_chart, area);
 }
 
-fn render_line_chart(f: &mut Frame, area: Rect) {
-    // Sample histogram data - frequency distribution
-    let data: Vec<(f64, f64)> = vec![
+/// Sample histogram data - frequency distribution
+fn line_data() -> Vec<(f64, f64)> {
+    vec![
         (0.0, 2.0),
         (1.0, 5.0),
         (2.0, 8.0),
@@ -130,7 +180,11 @@ fn render_line_chart(f: &mut Frame, area: Rect) {
         (10.0, 8.0),
         (11.0, 5.0),
         (12.0, 2.0),
-    ];
+    ]
+}
+
+fn render_line_chart(f: &mut Frame, area: Rect) {
+    let data = line_data();
 
     let datasets = vec![Dataset::default()
         .name("Distribution")
@@ -167,11 +221,19 @@ fn render_line_chart(f: &mut Frame, area: Rect) {
     f.render_widget(chart, area);
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -195,6 +257,11 @@ fn main() -> Result<(), Box<dyn Error>> {
                         KeyCode::BackTab => {
                             app.previous_view();
                         }
+                        KeyCode::Char('i') => app.save_image(),
+                        KeyCode::Char('y') => app.copy_image(),
+                        code if app.current_view == 2 => {
+                            app.scatter.handle_key(code);
+                        }
                         _ => {}
                     }
                 }
@@ -216,4 +283,17 @@ fn main() -> Result<(), Box<dyn Error>> {
     terminal.show_cursor()?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let app = App::new();
+        snapshot::assert_screen("charts_demo", |f| ui(f, &app));
+    }
+}
@@ -0,0 +1,166 @@
+//! Config editor example
+//!
+//! Edits the crate's own settings in a form generated from `Config::schema`,
+//! with a widget per field and validation on save, instead of editing the
+//! TOML file by hand.
+
+use crossterm::{
+    event::{DisableMouseCapture, Event},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame, Terminal,
+};
+use std::{io};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::{Config, ConfigHandle};
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::form::FormAction;
+use tui_image_viewer::widgets::schema::SchemaForm;
+
+struct ConfigEditorApp {
+    config: ConfigHandle,
+    form: SchemaForm,
+    status_message: String,
+    should_quit: bool,
+    events: EventDispatcher,
+}
+
+impl ConfigEditorApp {
+    fn new(config: ConfigHandle) -> Result<Self, DemoError> {
+        let status_message = match config.load_saved() {
+            Ok(_) => "Tab/↑↓: field | ←→: choose | Enter: save | Esc: quit".to_string(),
+            Err(e) => format!("Could not read config, editing defaults: {} ({})", e, e.hint()),
+        };
+        let form = Self::form_for(&config.get())?;
+        Ok(Self {
+            config,
+            form,
+            status_message,
+            should_quit: false,
+            events: EventDispatcher::new(),
+        })
+    }
+
+    fn form_for(config: &Config) -> Result<SchemaForm, DemoError> {
+        let values = serde_json::to_value(config)?;
+        SchemaForm::from_schema("⚙️ Settings", &config.schema(), &values).map_err(DemoError::Config)
+    }
+
+    /// Apply the form to the loaded config and write it to disk
+    fn save(&mut self) -> Result<(), DemoError> {
+        let mut values = serde_json::to_value(self.config.get())?;
+        self.form.apply(&mut values).map_err(DemoError::Config)?;
+        let edited: Config = serde_json::from_value(values).map_err(|e| DemoError::Config(e.to_string()))?;
+        self.config.update_and_save(|config| *config = edited)
+    }
+
+    fn on_key(&mut self, key: crossterm::event::KeyEvent) {
+        match self.form.form.handle_key_event(key) {
+            FormAction::Submit => {
+                self.status_message = match self.save() {
+                    Ok(()) => "✓ Settings saved".to_string(),
+                    Err(e) => format!("❌ Failed to save settings: {} ({})", e, e.hint()),
+                };
+            }
+            FormAction::Cancel => self.should_quit = true,
+            FormAction::None => {}
+        }
+    }
+}
+
+fn ui(f: &mut Frame, app: &ConfigEditorApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(f.area());
+
+    let themes: Vec<String> = app.config.read(|config| {
+        config.themes.iter().enumerate().map(|(i, theme)| format!("{}: {}", i, theme.name)).collect()
+    });
+    let background = Paragraph::new(format!(
+        "Themes: {}\nTheme colors and other nested settings are kept as they are.",
+        themes.join(", ")
+    ))
+    .wrap(Wrap { trim: false })
+    .style(Style::default().fg(Color::DarkGray))
+    .block(Block::default().borders(Borders::ALL).title("Config Editor"));
+    f.render_widget(background, chunks[0]);
+    app.form.form.render(f, chunks[0]);
+
+    let status = Paragraph::new(app.status_message.as_str())
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(app.events.status_title("Status")));
+    f.render_widget(status, chunks[1]);
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+    let mut app = ConfigEditorApp::new(args.config())?;
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    loop {
+        let frame = terminal.draw(|f| {
+            ui(f, &app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        // Keys queued while drawing (fast typing, pastes) are handled before the next frame
+        for event in app.events.read_batch()? {
+            if let Event::Key(key) = event {
+                if !app.should_quit {
+                    app.on_key(key);
+                }
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let app = ConfigEditorApp::new(ConfigHandle::default()).unwrap();
+        snapshot::assert_screen("config_editor", |f| ui(f, &app));
+    }
+}
@@ -1,7 +1,18 @@
 //! Dashboard example showing multiple widgets
+//!
+//! The overview has a gauge cluster of system load, memory use and a
+//! simulated progress value; 'g' switches between bar and radial gauges.
+//! A clock and the progress KPI are shown as large segment digits, and the
+//! details tab has a world clock for the configured timezones and a weather
+//! tile refreshed in the background ('r' refreshes now).
+//!
+//! 't' starts a guided tour of the current tab.
+//!
+//! Built with the `terminal` feature, a third tab hosts a shell in an
+//! embedded terminal pane.
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -10,10 +21,43 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Tabs},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Tabs, Wrap},
     Frame, Terminal,
 };
-use std::io;
+use std::{fs, io, time::Duration};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::gauges::{GaugeCluster, GaugeSpec, GaugeStyle};
+use tui_image_viewer::widgets::segment::SegmentDisplay;
+use tui_image_viewer::widgets::tour::{Tour, TourStep};
+use tui_image_viewer::widgets::weather::WeatherTile;
+use tui_image_viewer::widgets::world_clock::WorldClock;
+
+#[cfg(feature = "terminal")]
+use tui_image_viewer::widgets::terminal::TerminalPane;
+
+/// Index of the embedded terminal tab
+#[cfg(feature = "terminal")]
+const TERMINAL_TAB: usize = 2;
+
+const OVERVIEW_TOUR: &[TourStep] = &[
+    TourStep::new("tabs", "Tab bar", "This is the tab bar — press Tab to switch, Shift+Tab to go back"),
+    TourStep::new("controls", "Controls", "The keys this dashboard understands, in case you forget"),
+    TourStep::new("gauges", "Gauges", "System load, memory use and progress; press g to switch between bar and radial gauges"),
+    TourStep::new("summary", "Summary", "A short description of what this tab shows"),
+    TourStep::new("clock", "Clock", "The local time, drawn in segment digits in the theme color"),
+    TourStep::new("kpi", "Progress", "The simulated progress value, which goes up with every key press"),
+];
+
+const DETAILS_TOUR: &[TourStep] = &[
+    TourStep::new("tabs", "Tab bar", "This is the tab bar — press Tab to switch, Shift+Tab to go back"),
+    TourStep::new("details", "Details", "A list of items belonging to the dashboard"),
+    TourStep::new("world_clock", "World clock", "The time in each timezone from the config file"),
+    TourStep::new("weather", "Weather", "Current weather, refreshed in the background; press r to refresh now"),
+];
 
 struct DashboardApp {
     tabs: Vec<String>,
@@ -21,12 +65,28 @@ struct DashboardApp {
     should_quit: bool,
     items: Vec<String>,
     progress: u16,
+    gauge_style: GaugeStyle,
+    /// Theme primary color for the segment displays
+    accent: Color,
+    world_clock: WorldClock,
+    weather: WeatherTile,
+    tour: Tour,
+    #[cfg(feature = "terminal")]
+    terminal: Option<TerminalPane>,
+    #[cfg(feature = "terminal")]
+    terminal_error: Option<String>,
 }
 
 impl DashboardApp {
-    fn new() -> Self {
+    fn new(config: &ConfigHandle) -> Self {
+        #[allow(unused_mut)]
+        let mut tabs = vec!["Overview".to_string(), "Details".to_string()];
+        #[cfg(feature = "terminal")]
+        tabs.push("Terminal".to_string());
+        let config = config.get();
+
         Self {
-            tabs: vec!["Overview".to_string(), "Details".to_string()],
+            tabs,
             tab_index: 0,
             should_quit: false,
             items: vec![
@@ -35,32 +95,108 @@ impl DashboardApp {
                 "Item 3".to_string(),
             ],
             progress: 45,
+            gauge_style: GaugeStyle::Bar,
+            accent: config.current_theme().primary_color.parse().unwrap_or(Color::Cyan),
+            world_clock: WorldClock::new(&config.timezones),
+            weather: WeatherTile::new(config.weather.clone()),
+            tour: Tour::new(),
+            #[cfg(feature = "terminal")]
+            terminal: None,
+            #[cfg(feature = "terminal")]
+            terminal_error: None,
+        }
+    }
+
+    /// Start the shell the first time the terminal tab is shown
+    #[cfg(feature = "terminal")]
+    fn ensure_terminal(&mut self) {
+        if self.terminal.as_ref().is_some_and(|pane| pane.is_alive()) {
+            return;
+        }
+        let cwd = std::env::current_dir().unwrap_or_default();
+        match TerminalPane::spawn_shell(&cwd, 24, 80) {
+            Ok(pane) => self.terminal = Some(pane),
+            Err(e) => self.terminal_error = Some(format!("Failed to start shell: {} ({})", e, e.hint())),
         }
     }
 
-    fn on_key(&mut self, key: KeyCode) {
-        match key {
+    fn select_tab(&mut self, index: usize) {
+        self.tab_index = index;
+        #[cfg(feature = "terminal")]
+        if self.tab_index == TERMINAL_TAB {
+            self.ensure_terminal();
+        }
+    }
+
+    /// Tour of the regions on the current tab; the terminal tab sends 't'
+    /// to the shell, so it has none
+    fn tour_steps(&self) -> &'static [TourStep] {
+        match self.tab_index {
+            0 => OVERVIEW_TOUR,
+            1 => DETAILS_TOUR,
+            _ => &[],
+        }
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if self.tour.handle_key(key) {
+            return;
+        }
+
+        // The shell gets every key except F2, which leaves the terminal tab
+        #[cfg(feature = "terminal")]
+        if self.tab_index == TERMINAL_TAB && key.code != KeyCode::F(2) {
+            if let Some(pane) = self.terminal.as_mut() {
+                pane.handle_key(key);
+            }
+            return;
+        }
+
+        match key.code {
             KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Tab => {
-                self.tab_index = (self.tab_index + 1) % self.tabs.len();
+            KeyCode::Char('g') => self.gauge_style = self.gauge_style.toggle(),
+            KeyCode::Char('r') => self.weather.refresh(),
+            KeyCode::Char('t') => {
+                let steps = self.tour_steps();
+                self.tour.start(steps);
+            },
+            KeyCode::Tab | KeyCode::F(2) => {
+                self.select_tab((self.tab_index + 1) % self.tabs.len());
             },
             KeyCode::BackTab => {
                 if self.tab_index > 0 {
-                    self.tab_index -= 1;
+                    self.select_tab(self.tab_index - 1);
                 } else {
-                    self.tab_index = self.tabs.len() - 1;
+                    self.select_tab(self.tabs.len() - 1);
                 }
             },
             _ => {}
         }
     }
 
+    /// Load per core and memory use from /proc where available, plus the simulated progress
+    fn system_gauges(&self) -> Vec<GaugeSpec> {
+        let mut gauges = Vec::new();
+        let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get()) as f64;
+        if let Some(load) = fs::read_to_string("/proc/loadavg")
+            .ok()
+            .and_then(|content| content.split_whitespace().next()?.parse::<f64>().ok())
+        {
+            gauges.push(GaugeSpec::new("Load", (load / cores * 100.0).round()).range(0.0, 200.0).thresholds(80.0, 150.0));
+        }
+        if let Some(memory) = fs::read_to_string("/proc/meminfo").ok().and_then(|content| memory_used_percent(&content)) {
+            gauges.push(GaugeSpec::new("Memory", memory).thresholds(75.0, 90.0));
+        }
+        gauges.push(GaugeSpec::new("Progress", self.progress as f64).thresholds(101.0, 101.0));
+        gauges
+    }
+
     fn update_progress(&mut self) {
         // Simulate progress updates
         self.progress = (self.progress + 1) % 101;
     }
 
-    fn render(&self, frame: &mut Frame) {
+    fn render(&mut self, frame: &mut Frame) {
         let chunks = Layout::default()
             .constraints([Constraint::Length(3), Constraint::Min(0)])
             .split(frame.area());
@@ -76,16 +212,29 @@ impl DashboardApp {
             .select(self.tab_index)
             .block(Block::default().borders(Borders::ALL).title("Tabs"));
         frame.render_widget(tabs, chunks[0]);
+        self.tour.mark("tabs", chunks[0]);
 
         // Render content based on selected tab
         match self.tab_index {
             0 => self.render_overview(frame, chunks[1]),
             1 => self.render_details(frame, chunks[1]),
+            #[cfg(feature = "terminal")]
+            TERMINAL_TAB => match self.terminal.as_mut() {
+                Some(pane) => pane.render(frame, chunks[1], true),
+                None => {
+                    let message = self.terminal_error.clone().unwrap_or_default();
+                    let error = Paragraph::new(message)
+                        .block(Block::default().borders(Borders::ALL).title("Terminal"))
+                        .style(Style::default().fg(Color::Red));
+                    frame.render_widget(error, chunks[1]);
+                }
+            },
             _ => {}
         }
+        self.tour.render(frame, frame.area());
     }
 
-    fn render_overview(&self, frame: &mut Frame, area: Rect) {
+    fn render_overview(&mut self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -97,23 +246,58 @@ impl DashboardApp {
 
         // Instructions
         self.render_instructions(frame, chunks[0]);
+        self.tour.mark("controls", chunks[0]);
         
-        // Render gauge
-        let gauge = Gauge::default()
-            .block(Block::default().borders(Borders::ALL).title("Progress"))
-            .gauge_style(Style::default().fg(Color::Green))
-            .ratio(self.progress as f64 / 100.0);
-        frame.render_widget(gauge, chunks[1]);
+        // Render gauge cluster
+        let cluster = GaugeCluster {
+            style: self.gauge_style,
+            ..GaugeCluster::new("System", self.system_gauges())
+        };
+        cluster.render(frame, chunks[1]);
+        self.tour.mark("gauges", chunks[1]);
+
+        let tiles = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(35), Constraint::Percentage(25)])
+            .split(chunks[2]);
 
         // Render paragraph
         let text = Text::from("This is the overview tab. It shows a high-level dashboard with key metrics and status information.");
         let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
             .block(Block::default().borders(Borders::ALL).title("Summary"))
             .style(Style::default().fg(Color::White));
-        frame.render_widget(paragraph, chunks[2]);
+        frame.render_widget(paragraph, tiles[0]);
+        self.tour.mark("summary", tiles[0]);
+
+        // Clock and KPI tiles
+        let accent = Style::default().fg(self.accent);
+        let clock = SegmentDisplay::new(&chrono::Local::now().format("%H:%M:%S").to_string())
+            .style(accent)
+            .block(Block::default().borders(Borders::ALL).title("Clock"));
+        frame.render_widget(clock, tiles[1]);
+        self.tour.mark("clock", tiles[1]);
+        let kpi = SegmentDisplay::new(&self.progress.to_string())
+            .style(accent)
+            .block(Block::default().borders(Borders::ALL).title("Progress %"));
+        frame.render_widget(kpi, tiles[2]);
+        self.tour.mark("kpi", tiles[2]);
     }
 
-    fn render_details(&self, frame: &mut Frame, area: Rect) {
+    fn render_details(&mut self, frame: &mut Frame, area: Rect) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        let tiles = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(columns[1]);
+        self.world_clock.render(frame, tiles[0], chrono::Utc::now());
+        self.weather.render(frame, tiles[1]);
+        self.tour.mark("world_clock", tiles[0]);
+        self.tour.mark("weather", tiles[1]);
+
         // Render list
         let items: Vec<ListItem> = self
             .items
@@ -125,38 +309,72 @@ impl DashboardApp {
             .block(Block::default().borders(Borders::ALL).title("Details"))
             .style(Style::default().fg(Color::White))
             .highlight_style(Style::default().add_modifier(Modifier::BOLD));
-        frame.render_widget(list, area);
+        frame.render_widget(list, columns[0]);
+        self.tour.mark("details", columns[0]);
     }
 
     fn render_instructions(&self, frame: &mut Frame, area: Rect) {
-        let instructions = Paragraph::new("Tab: Switch tabs | Shift+Tab: Previous tab | q: Quit")
+        let instructions = Paragraph::new(if cfg!(feature = "terminal") {
+            "Tab: Switch tabs | Shift+Tab: Previous tab | g: Gauge style | r: Refresh weather | t: Tour | F2: Leave terminal tab | q: Quit"
+        } else {
+            "Tab: Switch tabs | Shift+Tab: Previous tab | g: Gauge style | r: Refresh weather | t: Tour | q: Quit"
+        })
             .block(Block::default().borders(Borders::ALL).title("Controls"))
             .style(Style::default().fg(Color::Yellow));
         frame.render_widget(instructions, area);
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Used memory from /proc/meminfo, as a percentage of the total
+fn memory_used_percent(meminfo: &str) -> Option<f64> {
+    let field = |name: &str| {
+        meminfo
+            .lines()
+            .find(|line| line.starts_with(name))?
+            .split_whitespace()
+            .nth(1)?
+            .parse::<f64>()
+            .ok()
+    };
+    let total = field("MemTotal:")?;
+    let available = field("MemAvailable:")?;
+    (total > 0.0).then(|| ((total - available) / total * 1000.0).round() / 10.0)
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app
-    let mut app = DashboardApp::new();
+    let mut app = DashboardApp::new(&args.config());
 
     // Main loop
     loop {
+        app.weather.poll();
         terminal.draw(|f| app.render(f))?;
 
-        if let Event::Key(key) = event::read()? {
-            app.on_key(key.code);
+        // Poll so the terminal pane keeps redrawing while the shell prints
+        if event::poll(Duration::from_millis(50))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.on_key(key);
+                    app.update_progress(); // Update progress on each key press
+                }
+            }
         }
 
-        app.update_progress(); // Update progress in each iteration
-
         if app.should_quit {
             break;
         }
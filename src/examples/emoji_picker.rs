@@ -3,7 +3,7 @@
 //! Demonstrates the emoji picker functionality with categories and navigation.
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -11,150 +11,45 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Style},
-    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame, Terminal,
 };
 use std::io;
-
-const EMOJI_CATEGORIES: &[&str] = &["smileys", "animals", "food", "travel"];
-
-const EMOJI_MAP: &[(&str, &[(&str, &str)])] = &[
-    (
-        "smileys",
-        &[
-            ("😀", "grinning face"),
-            ("😃", "grinning face with big eyes"),
-            ("😄", "grinning face with smiling eyes"),
-            ("😁", "beaming face with smiling eyes"),
-            ("😆", "grinning squinting face"),
-            ("😊", "smiling face with smiling eyes"),
-            ("😇", "smiling face with halo"),
-            ("🙂", "slightly smiling face"),
-            ("🙃", "upside-down face"),
-            ("😉", "winking face"),
-        ],
-    ),
-    (
-        "animals",
-        &[
-            ("🐶", "dog face"),
-            ("🐱", "cat face"),
-            ("🐭", "mouse face"),
-            ("🐹", "hamster face"),
-            ("🐰", "rabbit face"),
-            ("🦊", "fox face"),
-            ("🐻", "bear face"),
-            ("🐼", "panda face"),
-            ("🐨", "koala"),
-            ("🐯", "tiger face"),
-        ],
-    ),
-    (
-        "food",
-        &[
-            ("🍎", "red apple"),
-            ("🍊", "tangerine"),
-            ("🍋", "lemon"),
-            ("🍌", "banana"),
-            ("🍉", "watermelon"),
-            ("🍇", "grapes"),
-            ("🍓", "strawberry"),
-            ("🥝", "kiwi fruit"),
-            ("🍒", "cherries"),
-            ("🥭", "mango"),
-        ],
-    ),
-    (
-        "travel",
-        &[
-            ("🚗", "car"),
-            ("🚕", "taxi"),
-            ("🚙", "sport utility vehicle"),
-            ("🚌", "bus"),
-            ("🚎", "trolleybus"),
-            ("🏎️", "racing car"),
-            ("🚓", "police car"),
-            ("🚑", "ambulance"),
-            ("🚒", "fire engine"),
-            ("🚐", "minibus"),
-        ],
-    ),
-];
+use std::process::ExitCode;
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::component::Component;
+use tui_image_viewer::widgets::emoji::EmojiPicker;
 
 struct EmojiPickerApp {
     should_quit: bool,
-    category_index: usize,
-    emoji_index: usize,
-    selected_emoji: String,
+    picker: EmojiPicker,
 }
 
 impl EmojiPickerApp {
     fn new() -> Self {
-        Self {
-            should_quit: false,
-            category_index: 0,
-            emoji_index: 0,
-            selected_emoji: String::new(),
-        }
+        let mut picker = EmojiPicker::new();
+        picker.set_focused(true);
+        Self { should_quit: false, picker }
     }
 
-    fn on_key(&mut self, key: KeyCode) {
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
+    fn on_event(&mut self, event: &Event) {
+        if self.picker.handle_event(event) {
+            return;
+        }
+        if let Event::Key(key) = event {
+            if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
                 self.should_quit = true;
             }
-            KeyCode::Left => {
-                if self.category_index > 0 {
-                    self.category_index -= 1;
-                    self.emoji_index = 0;
-                }
-            }
-            KeyCode::Right => {
-                if self.category_index < EMOJI_CATEGORIES.len() - 1 {
-                    self.category_index += 1;
-                    self.emoji_index = 0;
-                }
-            }
-            KeyCode::Up => {
-                if self.emoji_index >= 5 {
-                    self.emoji_index -= 5;
-                }
-            }
-            KeyCode::Down => {
-                if let Some(&category) = EMOJI_CATEGORIES.get(self.category_index) {
-                    if let Some((_, emoji_data)) =
-                        EMOJI_MAP.iter().find(|(cat, _)| cat == &category)
-                    {
-                        if self.emoji_index + 5 < emoji_data.len() {
-                            self.emoji_index += 5;
-                        }
-                    }
-                }
-            }
-            KeyCode::Enter => {
-                if let Some(&category) = EMOJI_CATEGORIES.get(self.category_index) {
-                    if let Some((_, emoji_data)) =
-                        EMOJI_MAP.iter().find(|(cat, _)| cat == &category)
-                    {
-                        if let Some((emoji, _)) = emoji_data.get(self.emoji_index) {
-                            self.selected_emoji = emoji.to_string();
-                        }
-                    }
-                }
-            }
-            _ => {}
         }
     }
 
-    fn render(&self, frame: &mut Frame) {
+    fn render(&mut self, frame: &mut Frame) -> Result<(), DemoError> {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(3), // Title
-                Constraint::Length(3), // Categories
-                Constraint::Min(8),    // Emojis
-                Constraint::Length(3), // Description
+                Constraint::Min(14),   // Picker
                 Constraint::Length(3), // Selected
             ])
             .split(frame.area());
@@ -166,77 +61,12 @@ impl EmojiPickerApp {
             .style(Style::default().fg(Color::Cyan));
         frame.render_widget(title, chunks[0]);
 
-        // Categories
-        let category_items: Vec<Span> = EMOJI_CATEGORIES
-            .iter()
-            .enumerate()
-            .map(|(i, &cat)| {
-                if i == self.category_index {
-                    Span::styled(
-                        format!("[{cat}]"),
-                        Style::default().fg(Color::Black).bg(Color::Cyan),
-                    )
-                } else {
-                    Span::styled(format!(" {cat} "), Style::default().fg(Color::Gray))
-                }
-            })
-            .collect();
-
-        let categories = Paragraph::new(Line::from(category_items))
-            .block(Block::default().borders(Borders::ALL).title("Categories"))
-            .alignment(Alignment::Center);
-        frame.render_widget(categories, chunks[1]);
-
-        // Emojis grid
-        if let Some(&category) = EMOJI_CATEGORIES.get(self.category_index) {
-            if let Some((_, emoji_data)) = EMOJI_MAP.iter().find(|(cat, _)| cat == &category) {
-                let emoji_count = emoji_data.len();
-                let cols = 5;
-                let rows = (emoji_count + cols - 1) / cols;
-
-                let mut emoji_grid = Vec::new();
-
-                for row in 0..rows {
-                    let mut line = Vec::new();
-                    for col in 0..cols {
-                        let index = row * cols + col;
-                        if index < emoji_count {
-                            let (emoji, _) = emoji_data[index];
-                            let style = if index == self.emoji_index {
-                                Style::default().fg(Color::Black).bg(Color::Cyan)
-                            } else {
-                                Style::default().fg(Color::White)
-                            };
-                            line.push(Span::styled(format!(" {emoji} "), style));
-                        } else {
-                            line.push(Span::raw("   "));
-                        }
-                    }
-                    emoji_grid.push(Line::from(line));
-                }
-
-                let emoji_list = Paragraph::new(emoji_grid)
-                    .block(Block::default().borders(Borders::ALL).title("Emojis"))
-                    .alignment(Alignment::Left);
-                frame.render_widget(emoji_list, chunks[2]);
-
-                // Description
-                if let Some((emoji, description)) = emoji_data.get(self.emoji_index) {
-                    let desc_text = format!("{emoji} - {description}");
-                    let desc = Paragraph::new(desc_text)
-                        .block(Block::default().borders(Borders::ALL).title("Current"))
-                        .alignment(Alignment::Center)
-                        .style(Style::default().fg(Color::Yellow));
-                    frame.render_widget(desc, chunks[3]);
-                }
-            }
-        }
+        Component::render(&mut self.picker, frame, chunks[1])?;
 
         // Selected emoji
-        let selected_text = if self.selected_emoji.is_empty() {
-            "No emoji selected. Press Enter to select.".to_string()
-        } else {
-            format!("Selected: {}", self.selected_emoji)
+        let selected_text = match self.picker.selected() {
+            Some(emoji) => format!("Selected: {}", emoji),
+            None => "No emoji selected. Press Enter to select.".to_string(),
         };
 
         let selected = Paragraph::new(selected_text)
@@ -247,15 +77,24 @@ impl EmojiPickerApp {
             )
             .alignment(Alignment::Center)
             .style(Style::default().fg(Color::Green));
-        frame.render_widget(selected, chunks[4]);
+        frame.render_widget(selected, chunks[2]);
+        Ok(())
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -264,11 +103,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Main loop
     loop {
-        terminal.draw(|f| app.render(f))?;
+        let mut rendered = Ok(());
+        terminal.draw(|f| rendered = app.render(f))?;
+        rendered?;
 
-        if let Event::Key(key) = event::read()? {
-            app.on_key(key.code);
-        }
+        app.on_event(&event::read()?);
 
         if app.should_quit {
             break;
@@ -284,7 +123,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     )?;
     terminal.show_cursor()?;
 
-    println!("Final selected emoji: {}", app.selected_emoji);
+    println!("Final selected emoji: {}", app.picker.selected().unwrap_or(""));
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = EmojiPickerApp::new();
+        snapshot::assert_screen("emoji_picker", |f| app.render(f).unwrap());
+    }
+}
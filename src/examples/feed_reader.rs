@@ -0,0 +1,353 @@
+//! Feed reader example
+//!
+//! RSS/Atom feeds from the `feeds` config list, fetched in the background on
+//! startup and with 'r'. Feeds show their unread counts, the article list
+//! marks unread entries, and the selected article is rendered as wrapped
+//! text. 'o' opens the article in the browser.
+
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use std::{io, time::Duration};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::feeds::{Article, FeedReader};
+use tui_image_viewer::widgets::links;
+use tui_image_viewer::widgets::spinner::{Spinner, SpinnerStyle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pane {
+    Feeds,
+    Articles,
+    Body,
+}
+
+struct FeedReaderApp {
+    reader: FeedReader,
+    pane: Pane,
+    feeds: ListState,
+    articles: ListState,
+    scroll: u16,
+    spinner: Spinner,
+    status_message: String,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    /// Articles opened in the browser, until the launcher exits
+    launches: links::Launches,
+    should_quit: bool,
+}
+
+impl FeedReaderApp {
+    fn new(config: &ConfigHandle) -> Self {
+        let mut reader = FeedReader::new(&config.read(|config| config.feeds.clone()));
+        reader.refresh();
+        let mut feeds = ListState::default();
+        feeds.select((!reader.channels.is_empty()).then_some(0));
+        Self {
+            reader,
+            pane: Pane::Feeds,
+            feeds,
+            articles: ListState::default(),
+            scroll: 0,
+            spinner: Spinner::new(SpinnerStyle::Braille, ""),
+            status_message: String::new(),
+            events: EventDispatcher::new(),
+            launches: links::Launches::new(),
+            should_quit: false,
+        }
+    }
+
+    fn channel_index(&self) -> Option<usize> {
+        self.feeds.selected().filter(|&index| index < self.reader.channels.len())
+    }
+
+    fn article(&self) -> Option<&Article> {
+        let channel = &self.reader.channels[self.channel_index()?];
+        channel.articles.get(self.articles.selected()?)
+    }
+
+    /// Select an article, mark it read and show its body from the top
+    fn select_article(&mut self, index: usize) {
+        self.articles.select(Some(index));
+        self.scroll = 0;
+        if let Some(id) = self.article().map(|article| article.id.clone()) {
+            self.reader.set_read(&id, true);
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        match self.pane {
+            Pane::Feeds => {
+                let len = self.reader.channels.len();
+                if len > 0 {
+                    let current = self.feeds.selected().unwrap_or(0) as isize;
+                    self.feeds.select(Some((current + delta).clamp(0, len as isize - 1) as usize));
+                    self.articles.select(None);
+                }
+            }
+            Pane::Articles => {
+                let Some(channel) = self.channel_index() else {
+                    return;
+                };
+                let len = self.reader.channels[channel].articles.len();
+                if len > 0 {
+                    let next = match self.articles.selected() {
+                        Some(current) => (current as isize + delta).clamp(0, len as isize - 1) as usize,
+                        None => 0,
+                    };
+                    self.select_article(next);
+                }
+            }
+            Pane::Body => self.scroll = (self.scroll as isize + delta).max(0) as u16,
+        }
+    }
+
+    fn open_in_browser(&mut self) {
+        let Some(article) = self.article() else {
+            return;
+        };
+        self.status_message = match article.link.clone() {
+            Some(link) => match self.launches.open_url(&link) {
+                Ok(()) => format!("Opened {}", link),
+                Err(e) => format!("❌ {}", e),
+            },
+            None => "This article has no link".to_string(),
+        };
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('r') => {
+                self.reader.refresh();
+                self.status_message = "Refreshing feeds...".to_string();
+            }
+            KeyCode::Char('o') => self.open_in_browser(),
+            KeyCode::Char('u') => {
+                if let Some(id) = self.article().map(|article| article.id.clone()) {
+                    self.reader.set_read(&id, false);
+                }
+            }
+            KeyCode::Char('a') => {
+                if let Some(channel) = self.channel_index() {
+                    self.reader.mark_all_read(channel);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::PageUp => self.move_selection(-10),
+            KeyCode::PageDown => self.move_selection(10),
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => match self.pane {
+                Pane::Feeds => {
+                    self.pane = Pane::Articles;
+                    // Open the newest article when entering a feed
+                    if self.articles.selected().is_none() {
+                        self.move_selection(0);
+                    }
+                }
+                Pane::Articles | Pane::Body => self.pane = Pane::Body,
+            },
+            KeyCode::Left | KeyCode::Esc | KeyCode::Char('h') => {
+                self.pane = match self.pane {
+                    Pane::Body => Pane::Articles,
+                    Pane::Articles | Pane::Feeds => Pane::Feeds,
+                };
+            }
+            KeyCode::Tab => {
+                self.pane = match self.pane {
+                    Pane::Feeds => Pane::Articles,
+                    Pane::Articles => Pane::Body,
+                    Pane::Body => Pane::Feeds,
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+fn pane_block(title: String, focused: bool) -> Block<'static> {
+    let border = if focused { Color::Cyan } else { Color::DarkGray };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border))
+        .title(title)
+}
+
+fn render_feeds(f: &mut Frame, app: &mut FeedReaderApp, area: Rect) {
+    let items: Vec<ListItem> = app
+        .reader
+        .channels
+        .iter()
+        .map(|channel| {
+            let unread = app.reader.unread_count(channel);
+            let (badge, color) = if channel.loading {
+                (app.spinner.current_frame().to_string(), Color::Yellow)
+            } else if channel.error.is_some() {
+                ("!".to_string(), Color::Red)
+            } else {
+                (unread.to_string(), if unread > 0 { Color::Cyan } else { Color::DarkGray })
+            };
+            let style = if unread > 0 { Style::default().add_modifier(Modifier::BOLD) } else { Style::default() };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:>3} ", badge), Style::default().fg(color)),
+                Span::styled(channel.title.clone(), style),
+            ]))
+        })
+        .collect();
+    let title = format!("Feeds ({} unread)", app.reader.total_unread());
+    let list = List::new(items)
+        .block(pane_block(title, app.pane == Pane::Feeds))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    f.render_stateful_widget(list, area, &mut app.feeds);
+}
+
+fn render_articles(f: &mut Frame, app: &mut FeedReaderApp, area: Rect) {
+    let focused = app.pane == Pane::Articles;
+    let Some(channel) = app.channel_index().map(|index| &app.reader.channels[index]) else {
+        f.render_widget(Paragraph::new("No feeds configured").block(pane_block("Articles".to_string(), focused)), area);
+        return;
+    };
+    if let (Some(error), true) = (&channel.error, channel.articles.is_empty()) {
+        let message = Paragraph::new(format!("Failed to fetch {}: {}", channel.url, error))
+            .style(Style::default().fg(Color::Red))
+            .block(pane_block("Articles".to_string(), focused));
+        f.render_widget(message, area);
+        return;
+    }
+    let items: Vec<ListItem> = channel
+        .articles
+        .iter()
+        .map(|article| {
+            let read = app.reader.is_read(article);
+            let date = article.published.map(|date| date.format("%Y-%m-%d ").to_string()).unwrap_or_default();
+            let (marker, style) = if read {
+                ("  ", Style::default().fg(Color::Gray))
+            } else {
+                ("● ", Style::default().add_modifier(Modifier::BOLD))
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, Style::default().fg(Color::Cyan)),
+                Span::styled(date, Style::default().fg(Color::DarkGray)),
+                Span::styled(article.title.clone(), style),
+            ]))
+        })
+        .collect();
+    let title = format!("{} ({})", channel.title, channel.articles.len());
+    let list = List::new(items)
+        .block(pane_block(title, focused))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    f.render_stateful_widget(list, area, &mut app.articles);
+}
+
+fn render_body(f: &mut Frame, app: &FeedReaderApp, area: Rect) {
+    let block = pane_block("Article".to_string(), app.pane == Pane::Body);
+    let Some(article) = app.article() else {
+        let hint = Paragraph::new("Select an article").style(Style::default().fg(Color::DarkGray)).block(block);
+        f.render_widget(hint, area);
+        return;
+    };
+    let mut lines = vec![
+        Line::styled(article.title.clone(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Line::styled(article.link.clone().unwrap_or_default(), Style::default().fg(Color::DarkGray)),
+        Line::raw(""),
+    ];
+    let width = block.inner(area).width as usize;
+    lines.extend(article.text(width).lines().map(|line| Line::raw(line.to_string())));
+    f.render_widget(Paragraph::new(lines).scroll((app.scroll, 0)).block(block), area);
+}
+
+fn ui(f: &mut Frame, app: &mut FeedReaderApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(f.area());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(35), Constraint::Percentage(40)])
+        .split(chunks[0]);
+
+    render_feeds(f, app, columns[0]);
+    render_articles(f, app, columns[1]);
+    render_body(f, app, columns[2]);
+
+    let help = if app.status_message.is_empty() {
+        "↑↓: move | Enter/→: open | ←/Esc: back | Tab: pane | o: browser | u: unread | a: all read | r: refresh | q: quit"
+            .to_string()
+    } else {
+        app.status_message.clone()
+    };
+    let status = Paragraph::new(help)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(app.events.status_title("Feed Reader")));
+    f.render_widget(status, chunks[1]);
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = FeedReaderApp::new(&args.config());
+
+    loop {
+        if app.reader.poll() && !app.reader.is_loading() && app.status_message.starts_with("Refreshing") {
+            app.status_message.clear();
+        }
+        for failure in app.launches.failures() {
+            app.status_message = format!("❌ {}", failure);
+        }
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        match app.events.next(Duration::from_millis(100))? {
+            Some(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                app.status_message.clear();
+                app.on_key(key);
+            }
+            _ => {}
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
@@ -0,0 +1,386 @@
+//! Games example
+//!
+//! The "fun" corner of the demos: Snake and Tetris drawn on a canvas and
+//! driven by a fixed tick loop whose speed follows the game. High scores are
+//! kept per game in the data directory and shown in the menu.
+
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::games::{draw_board, HighScores};
+use tui_image_viewer::widgets::snake::{Heading, Snake};
+use tui_image_viewer::widgets::tetris::{self, piece_cells, Tetris};
+
+const GAMES: [(&str, &str); 2] = [
+    ("snake", "Eat, grow and stay off the walls"),
+    ("tetris", "Clear lines before the well fills up"),
+];
+
+const SNAKE_SIZE: (u16, u16) = (24, 16);
+
+enum Game {
+    Snake(Snake),
+    Tetris(Tetris),
+}
+
+impl Game {
+    fn start(name: &str) -> Self {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(1, |elapsed| elapsed.as_nanos() as u64);
+        match name {
+            "tetris" => Game::Tetris(Tetris::new(seed)),
+            _ => Game::Snake(Snake::new(SNAKE_SIZE.0, SNAKE_SIZE.1, seed)),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Game::Snake(_) => "snake",
+            Game::Tetris(_) => "tetris",
+        }
+    }
+
+    fn score(&self) -> u32 {
+        match self {
+            Game::Snake(snake) => snake.score,
+            Game::Tetris(tetris) => tetris.score,
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        match self {
+            Game::Snake(snake) => snake.game_over,
+            Game::Tetris(tetris) => tetris.game_over,
+        }
+    }
+
+    fn tick_interval(&self) -> Duration {
+        Duration::from_millis(match self {
+            Game::Snake(snake) => snake.tick_millis(),
+            Game::Tetris(tetris) => tetris.tick_millis(),
+        })
+    }
+
+    fn tick(&mut self) {
+        match self {
+            Game::Snake(snake) => snake.step(),
+            Game::Tetris(tetris) => {
+                tetris.tick();
+            }
+        }
+    }
+
+    fn on_key(&mut self, key: KeyCode) {
+        match self {
+            Game::Snake(snake) => match key {
+                KeyCode::Up | KeyCode::Char('w') => snake.turn(Heading::Up),
+                KeyCode::Down | KeyCode::Char('s') => snake.turn(Heading::Down),
+                KeyCode::Left | KeyCode::Char('a') => snake.turn(Heading::Left),
+                KeyCode::Right | KeyCode::Char('d') => snake.turn(Heading::Right),
+                _ => {}
+            },
+            Game::Tetris(tetris) => match key {
+                KeyCode::Left | KeyCode::Char('a') => {
+                    tetris.shift(-1);
+                }
+                KeyCode::Right | KeyCode::Char('d') => {
+                    tetris.shift(1);
+                }
+                KeyCode::Up | KeyCode::Char('w') => {
+                    tetris.rotate();
+                }
+                KeyCode::Down | KeyCode::Char('s') => tetris.soft_drop(),
+                KeyCode::Char(' ') => tetris.hard_drop(),
+                _ => {}
+            },
+        }
+    }
+}
+
+struct GamesApp {
+    menu: ListState,
+    game: Option<Game>,
+    paused: bool,
+    last_tick: Instant,
+    high_scores: HighScores,
+    /// Set once the current game ended and its score was recorded
+    new_best: Option<bool>,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    should_quit: bool,
+}
+
+impl GamesApp {
+    fn new() -> Self {
+        let mut menu = ListState::default();
+        menu.select(Some(0));
+        Self {
+            menu,
+            game: None,
+            paused: false,
+            last_tick: Instant::now(),
+            high_scores: HighScores::load(),
+            new_best: None,
+            events: EventDispatcher::new(),
+            should_quit: false,
+        }
+    }
+
+    fn start(&mut self, name: &str) {
+        self.game = Some(Game::start(name));
+        self.paused = false;
+        self.new_best = None;
+        self.last_tick = Instant::now();
+    }
+
+    /// Advance the game on its own clock and record the score when it ends
+    fn update(&mut self) {
+        let Some(game) = self.game.as_mut() else {
+            return;
+        };
+        if self.paused || self.new_best.is_some() {
+            return;
+        }
+        if self.last_tick.elapsed() >= game.tick_interval() {
+            game.tick();
+            self.last_tick = Instant::now();
+        }
+        if game.is_over() {
+            self.new_best = Some(self.high_scores.record(game.name(), game.score()));
+        }
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        let Some(game) = self.game.as_mut() else {
+            let selected = self.menu.selected().unwrap_or(0);
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+                KeyCode::Up | KeyCode::Char('k') => self.menu.select(Some(selected.saturating_sub(1))),
+                KeyCode::Down | KeyCode::Char('j') => self.menu.select(Some((selected + 1).min(GAMES.len() - 1))),
+                KeyCode::Enter => self.start(GAMES[selected].0),
+                _ => {}
+            }
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                // Leaving a running game still records its score
+                if self.new_best.is_none() {
+                    self.high_scores.record(game.name(), game.score());
+                }
+                self.game = None;
+            }
+            KeyCode::Char('p') if self.new_best.is_none() => self.paused = !self.paused,
+            KeyCode::Char('r') if self.new_best.is_some() => {
+                let name = game.name();
+                self.start(name);
+            }
+            code if !self.paused && self.new_best.is_none() => game.on_key(code),
+            _ => {}
+        }
+    }
+}
+
+fn render_menu(f: &mut Frame, app: &mut GamesApp, area: Rect) {
+    let items: Vec<ListItem> = GAMES
+        .iter()
+        .map(|(name, description)| {
+            let best = app.high_scores.best(name).map_or_else(|| "no score yet".to_string(), |best| format!("best {}", best));
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(format!("{:<8}", capitalize(name)), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    Span::styled(best, Style::default().fg(Color::Yellow)),
+                ]),
+                Line::styled(description.to_string(), Style::default().fg(Color::Gray)),
+            ])
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("🎮 Fun"))
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("▶ ");
+    f.render_stateful_widget(list, area, &mut app.menu);
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+/// Score, level and high score table beside the board
+fn render_sidebar(f: &mut Frame, app: &GamesApp, game: &Game, area: Rect) {
+    let mut lines = vec![
+        Line::from(vec![Span::raw("Score  "), Span::styled(game.score().to_string(), Style::default().add_modifier(Modifier::BOLD))]),
+    ];
+    if let Game::Tetris(tetris) = game {
+        lines.push(Line::raw(format!("Lines  {}", tetris.lines)));
+        lines.push(Line::raw(format!("Level  {}", tetris.level())));
+        lines.push(Line::from(vec![
+            Span::raw("Next   "),
+            Span::styled(format!("{:?}", tetris.next), Style::default().fg(tetris.next.color())),
+        ]));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::styled("High scores", Style::default().fg(Color::Yellow)));
+    for (rank, score) in app.high_scores.top(game.name()).iter().enumerate() {
+        lines.push(Line::raw(format!("{}. {}", rank + 1, score)));
+    }
+    f.render_widget(Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(capitalize(game.name()))), area);
+}
+
+fn render_game(f: &mut Frame, app: &GamesApp, game: &Game, area: Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(22)])
+        .split(area);
+    match game {
+        Game::Snake(snake) => {
+            let (head, tail) = (vec![snake.body[0]], snake.body.iter().skip(1).copied().collect());
+            draw_board(
+                f,
+                columns[0],
+                "Snake",
+                (snake.width, snake.height),
+                &[(vec![snake.food], Color::Red), (tail, Color::Green), (head, Color::LightGreen)],
+            );
+        }
+        Game::Tetris(tetris) => {
+            let mut layers = tetris.locked_cells();
+            layers.push((piece_cells(&tetris.ghost()), Color::DarkGray));
+            layers.push((piece_cells(&tetris.piece), tetris.piece.kind.color()));
+            draw_board(f, columns[0], "Tetris", (tetris::WIDTH, tetris::HEIGHT), &layers);
+        }
+    }
+    render_sidebar(f, app, game, columns[1]);
+
+    let message = match (app.new_best, app.paused) {
+        (Some(true), _) => Some(format!("New high score: {}!  r: play again | Esc: menu", game.score())),
+        (Some(false), _) => Some(format!("Game over, score {}  r: play again | Esc: menu", game.score())),
+        (None, true) => Some("Paused  p: resume".to_string()),
+        (None, false) => None,
+    };
+    if let Some(message) = message {
+        let width = (message.chars().count() as u16 + 4).min(area.width);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + area.height / 2,
+            width,
+            height: 3.min(area.height),
+        };
+        f.render_widget(Clear, popup);
+        let popup_widget = Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL));
+        f.render_widget(popup_widget, popup);
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut GamesApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(f.area());
+
+    let help = match &app.game {
+        None => "↑↓: choose | Enter: play | q: quit",
+        Some(Game::Snake(_)) => "←↑↓→/WASD: steer | p: pause | Esc: menu",
+        Some(Game::Tetris(_)) => "←→: move | ↑: rotate | ↓: soft drop | Space: hard drop | p: pause | Esc: menu",
+    };
+    match app.game.take() {
+        Some(game) => {
+            render_game(f, app, &game, chunks[0]);
+            app.game = Some(game);
+        }
+        None => render_menu(f, app, chunks[0]),
+    }
+
+    let status = Paragraph::new(help)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(app.events.status_title("Games")));
+    f.render_widget(status, chunks[1]);
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = GamesApp::new();
+
+    loop {
+        app.update();
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        // Short polls keep the tick loop close to the game's speed
+        if let Some(Event::Key(key)) = app.events.next(Duration::from_millis(16))? {
+            if key.kind == KeyEventKind::Press {
+                app.on_key(key);
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = GamesApp::new();
+        snapshot::assert_screen("games", |f| ui(f, &mut app));
+    }
+}
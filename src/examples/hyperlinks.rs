@@ -1,6 +1,7 @@
 //! Terminal hyperlinks example
 //!
 //! Demonstrates clickable hyperlinks in the terminal using OSC 8 escape sequences.
+//! 's' shows the selected link as a QR code for opening it on a phone.
 
 use crossterm::{
     event::{self, Event, KeyCode},
@@ -16,17 +17,20 @@ use ratatui::{
     Frame, Terminal,
 };
 use std::io;
+use std::process::ExitCode;
 
-// OSC 8 sequence for hyperlinks
-fn hyperlink(text: &str, url: &str) -> String {
-    format!("\x1B]8;;{}\x1B\\{}\x1B]8;;\x1B\\", url, text)
-}
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::links;
+use tui_image_viewer::widgets::qr::QrCodeView;
 
 struct HyperlinkApp {
     should_quit: bool,
     selected_link: usize,
     links: Vec<(String, String)>, // (Text, URL)
     status_message: String,
+    qr_code: Option<QrCodeView>,
+    launches: links::Launches,
 }
 
 impl HyperlinkApp {
@@ -53,7 +57,9 @@ impl HyperlinkApp {
                     "https://github.com/yourusername/ratatui-rust-example".to_string(),
                 ),
             ],
-            status_message: String::from("Use ↑/↓ to select, Enter to open in browser, q to quit"),
+            status_message: String::from("Use ↑/↓ to select, Enter to open in browser, s to share as QR code, q to quit"),
+            qr_code: None,
+            launches: links::Launches::new(),
         }
     }
 
@@ -75,38 +81,28 @@ impl HyperlinkApp {
 
     fn open_selected_link(&mut self) {
         if let Some((text, url)) = self.links.get(self.selected_link) {
-            self.status_message = format!("Opening: {} ({})", text, url);
-
-            // On Unix-like systems, we could use:
-            // std::process::Command::new("xdg-open").arg(url).spawn().ok();
-
-            // On Windows:
-            #[cfg(target_os = "windows")]
-            {
-                std::process::Command::new("cmd")
-                    .args(&["/C", "start", "", &url])
-                    .spawn()
-                    .ok();
-            }
-
-            // On macOS:
-            #[cfg(target_os = "macos")]
-            {
-                std::process::Command::new("open").arg(&url).spawn().ok();
-            }
+            self.status_message = match self.launches.open_url(url) {
+                Ok(()) => format!("Opening: {} ({})", text, url),
+                Err(e) => format!("Failed to open {}: {}", text, e),
+            };
+        }
+    }
 
-            // On other Unix-like systems:
-            #[cfg(all(unix, not(target_os = "macos")))]
-            {
-                std::process::Command::new("xdg-open")
-                    .arg(&url)
-                    .spawn()
-                    .ok();
+    /// Show the selected link as a QR code
+    fn show_qr_code(&mut self) {
+        if let Some((_, url)) = self.links.get(self.selected_link) {
+            match QrCodeView::new(url) {
+                Ok(view) => self.qr_code = Some(view),
+                Err(e) => self.status_message = format!("Failed to encode {}: {}", url, e),
             }
         }
     }
 
     fn on_key(&mut self, key: KeyCode) {
+        // Any key closes the QR code
+        if self.qr_code.take().is_some() {
+            return;
+        }
         match key {
             KeyCode::Char('q') => {
                 self.should_quit = true;
@@ -120,6 +116,7 @@ impl HyperlinkApp {
             KeyCode::Enter => {
                 self.open_selected_link();
             }
+            KeyCode::Char('s') => self.show_qr_code(),
             _ => {}
         }
     }
@@ -175,7 +172,7 @@ impl HyperlinkApp {
                 };
 
                 let content = format!("➤ {}", text);
-                let hyperlinked_text = hyperlink(&content, url);
+                let hyperlinked_text = links::link_text(&content, url);
 
                 ListItem::new(hyperlinked_text).style(style)
             })
@@ -195,6 +192,11 @@ impl HyperlinkApp {
             .block(Block::default().borders(Borders::ALL).title("Status"))
             .style(Style::default().fg(Color::Yellow));
         frame.render_widget(status, chunks[3]);
+
+        if let Some(qr_code) = &self.qr_code {
+            let area = frame.area();
+            qr_code.render(frame, area);
+        }
     }
 
     #[allow(dead_code)]    #[allow(dead_code)]
@@ -215,7 +217,13 @@ impl HyperlinkApp {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    cli::parse();
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
@@ -225,6 +233,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut app = HyperlinkApp::new();
 
     loop {
+        for failure in app.launches.failures() {
+            app.status_message = format!("❌ {}", failure);
+        }
         terminal.draw(|f| app.render(f))?;
 
         // Fix: Use proper event polling
@@ -242,3 +253,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let app = HyperlinkApp::new();
+        snapshot::assert_screen("hyperlinks", |f| app.render(f));
+    }
+}
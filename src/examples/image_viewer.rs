@@ -1,25 +1,45 @@
 //! Standalone image viewer example
 //!
 //! This example demonstrates the image rendering capabilities
-//! including high-resolution terminal rendering via viuer and ASCII art conversion.
+//! including high-resolution terminal rendering via viuer and ASCII art conversion,
+//! with an EXIF side panel ('i') and saving a copy without EXIF ('x').
 
+use clap::Parser;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use image::GenericImageView;
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Style},
     text::Line,
     widgets::{Block, Borders, Paragraph},
     Frame, Terminal,
 };
 use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use tui_image_viewer::app::cli::{self, CommonArgs};
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::console::{self, Session};
+use tui_image_viewer::widgets::exif::{self, ExifInfo};
+use tui_image_viewer::widgets::finder::{FileFinder, FileKind, FinderAction};
+use tui_image_viewer::widgets::progress::{TaskHandle, TaskManager, TaskOutcome};
+use tui_image_viewer::widgets::watcher::FileWatcher;
 use viuer::Config;
 
+/// Image decoded off the UI thread, with its ASCII fallback
+struct LoadedImage {
+    path: String,
+    image: image::DynamicImage,
+    ascii: Vec<String>,
+    exif: Option<ExifInfo>,
+}
+
 struct ImageViewerApp {
     should_quit: bool,
     status_message: String,
@@ -27,53 +47,69 @@ struct ImageViewerApp {
     ascii_image: Vec<String>, // Store ASCII representation
     use_high_res: bool,       // Toggle between high-res and ASCII
     image_buffer: Option<image::DynamicImage>, // Store the loaded image
+    exif: Option<ExifInfo>,   // Metadata of the loaded image
+    show_exif: bool,          // Toggle the EXIF side panel
     last_terminal_size: (u16, u16), // Track terminal size for image redrawing
     needs_redraw: bool,       // Flag to indicate if image needs redrawing
+    tasks: TaskManager<LoadedImage>, // Background image decoding
+    finder: FileFinder,       // Ctrl+T / 'o' file picker
+    watcher: FileWatcher,     // Reloads the image when it changes on disk
+    settings: ConfigHandle,
+    session: Session,         // SSH or headless sessions stay in ASCII mode
 }
 
 impl ImageViewerApp {
-    fn new() -> Self {
+    fn new(settings: ConfigHandle) -> Self {
+        let watch_files = settings.read(|config| config.watch_files);
         Self {
             should_quit: false,
-            status_message: "Press 'o' to open image, 'h' to toggle high-res mode, 'q' to quit".to_string(),
+            status_message: "Press 'o' to open image, 'h' to toggle high-res mode, 'i' for EXIF, 'w' to toggle auto-reload, 'q' to quit".to_string(),
             current_image_path: None,
             ascii_image: Vec::new(),
             use_high_res: false,
             image_buffer: None,
+            exif: None,
+            show_exif: false,
             last_terminal_size: (0, 0),
             needs_redraw: false,
+            tasks: TaskManager::new(),
+            finder: FileFinder::in_current_dir(),
+            watcher: FileWatcher::new(watch_files),
+            settings,
+            session: console::session(),
         }
     }
 
-    fn load_image(&mut self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let img = image::open(path)?;
-        self.current_image_path = Some(path.to_string());
-        let (width, height) = img.dimensions();
-        
-        // Store the original image for high-res rendering
-        self.image_buffer = Some(img.clone());
-        
-        // Always generate ASCII representation for fallback
-        let resized_img = img.resize(80, 40, image::imageops::FilterType::Nearest);
-        
-        self.ascii_image = Vec::new();
-        for y in 0..resized_img.height() {
-            let mut line = String::new();
-            for x in 0..resized_img.width() {
-                let pixel = resized_img.get_pixel(x, y);
-                let brightness =
-                    0.3 * pixel[0] as f32 + 0.59 * pixel[1] as f32 + 0.11 * pixel[2] as f32;
-                let ascii_char = match brightness as u8 {
-                    0..=50 => ' ',
-                    51..=100 => '.',
-                    101..=150 => '*',
-                    151..=200 => '#',
-                    _ => '@',
-                };
-                line.push(ascii_char);
+    /// Start decoding an image on a background task
+    fn load_image(&mut self, path: &str) {
+        let path = path.to_string();
+        self.status_message = format!("Loading {}...", path);
+        self.tasks.spawn(&format!("Loading {}", path), move |handle| decode_image(&path, handle));
+    }
+
+    /// Apply background task progress and install finished images
+    fn poll_tasks(&mut self) {
+        for outcome in self.tasks.poll() {
+            match outcome {
+                TaskOutcome::Finished { output, .. } => self.install_image(output),
+                TaskOutcome::Failed { error, .. } => {
+                    self.status_message = format!("Error loading image: {}", error);
+                }
+                TaskOutcome::Cancelled { name, .. } => {
+                    self.status_message = format!("{} cancelled", name);
+                }
             }
-            self.ascii_image.push(line);
         }
+    }
+
+    fn install_image(&mut self, loaded: LoadedImage) {
+        let (width, height) = loaded.image.dimensions();
+        let path = loaded.path;
+        self.watch_image(Path::new(&path));
+        self.current_image_path = Some(path.clone());
+        self.image_buffer = Some(loaded.image);
+        self.ascii_image = loaded.ascii;
+        self.exif = loaded.exif;
 
         if self.use_high_res {
             self.status_message = format!("Loaded image in high-res mode: {} ({}x{})", path, width, height);
@@ -81,21 +117,80 @@ impl ImageViewerApp {
         } else {
             self.status_message = format!("Loaded image in ASCII mode: {} ({}x{})", path, width, height);
         }
+        if let Err(e) = self.settings.remember_file(Path::new(&path)) {
+            self.status_message = format!("Failed to save recent files: {} ({})", e, e.hint());
+        }
+    }
 
-        Ok(())
+    /// Watch the image's directory; editors often replace files rather than write in place
+    fn watch_image(&mut self, path: &Path) {
+        self.watcher.unwatch_all();
+        if let Some(dir) = path.canonicalize().ok().and_then(|path| path.parent().map(Path::to_path_buf)) {
+            self.watcher.watch(&dir, false);
+        }
+    }
+
+    /// Reload the current image after debounced changes to it on disk
+    fn poll_watcher(&mut self) {
+        let Some(changed) = self.watcher.poll() else {
+            return;
+        };
+        let Some(current) = self.current_image_path.clone() else {
+            return;
+        };
+        let current_path: Option<PathBuf> = Path::new(&current).canonicalize().ok();
+        if current_path.is_some_and(|current_path| changed.contains(&current_path)) && !self.tasks.is_busy() {
+            self.load_image(&current);
+        }
+    }
+
+    /// Save `<name>-noexif.<ext>` next to the current image
+    fn save_without_exif(&mut self) {
+        let (Some(path), Some(image)) = (&self.current_image_path, &self.image_buffer) else {
+            self.status_message = "No image loaded".to_string();
+            return;
+        };
+        self.status_message = match exif::save_without_exif(Path::new(path), image) {
+            Ok(copy) => format!("Saved copy without EXIF to {}", copy.display()),
+            Err(e) => format!("Failed to save copy: {}", e),
+        };
+    }
+
+    fn toggle_watch(&mut self) {
+        let mut watch_files = false;
+        let saved = self.settings.update_and_save(|config| {
+            config.watch_files = !config.watch_files;
+            watch_files = config.watch_files;
+        });
+        self.watcher.set_enabled(watch_files);
+        self.status_message = if watch_files {
+            "Auto-reload on file changes enabled".to_string()
+        } else {
+            "Auto-reload on file changes disabled".to_string()
+        };
+        if let Err(e) = saved {
+            self.status_message = format!("Failed to save settings: {} ({})", e, e.hint());
+        }
     }
 
     fn on_key(&mut self, key: KeyCode) {
+        // Task panel keys (↑↓ select, Esc cancel) take priority while loading
+        if self.tasks.handle_key(key) {
+            return;
+        }
+
         match key {
             KeyCode::Char('q') => {
                 self.should_quit = true;
             }
             KeyCode::Char('o') => {
-                // In a real app, you would open a file dialog here
-                // For this example, we'll just hardcode a path
-                if let Err(e) = self.load_image("sample.jpg") {
-                    self.status_message = format!("Error loading image: {}", e);
-                }
+                self.finder.open();
+            }
+            KeyCode::Char('w') => self.toggle_watch(),
+            KeyCode::Char('i') => self.show_exif = !self.show_exif,
+            KeyCode::Char('x') => self.save_without_exif(),
+            KeyCode::Char('h') if self.session.ascii_images() => {
+                self.status_message = "High-resolution mode needs a local graphical terminal".to_string();
             }
             KeyCode::Char('h') => {
                 // Toggle high-resolution mode
@@ -115,7 +210,16 @@ impl ImageViewerApp {
         }
     }
 
-    fn render(&self, frame: &mut Frame) {
+    /// Load a file picked in the finder if it is an image
+    fn open_file(&mut self, path: &Path, kind: FileKind) {
+        if kind == FileKind::Image {
+            self.load_image(&path.to_string_lossy());
+        } else {
+            self.status_message = format!("Not an image: {}", path.display());
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -137,6 +241,17 @@ impl ImageViewerApp {
             .style(Style::default().fg(Color::Cyan));
         frame.render_widget(title, chunks[0]);
 
+        // Collapsible EXIF panel on the right of the content area
+        let mut chunks = chunks.to_vec();
+        if self.show_exif {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(20), Constraint::Length(34)])
+                .split(chunks[1]);
+            exif::render_panel(frame, columns[1], self.exif.as_ref());
+            chunks[1] = columns[0];
+        }
+
         // Content area
         if self.ascii_image.is_empty() {
             // No image loaded yet - show placeholder
@@ -174,6 +289,7 @@ impl ImageViewerApp {
                     Line::from(""),
                     Line::from("  Controls:"),
                     Line::from("  - Press 'h' to switch to ASCII mode"),
+                    Line::from("  - Press 'i' to show EXIF, 'x' to save a copy without it"),
                     Line::from("  - Press 'q' to quit"),
                     Line::from(""),
                 ];
@@ -208,23 +324,101 @@ impl ImageViewerApp {
         }
 
         // Status bar
+        let mut status_block = Block::default().borders(Borders::ALL).title("Status");
+        if let Some(indicator) = self.session.indicator() {
+            status_block = status_block.title_bottom(Line::from(indicator).alignment(Alignment::Right));
+        }
         let status = Paragraph::new(self.status_message.as_str())
-            .block(Block::default().borders(Borders::ALL).title("Status"))
+            .block(status_block)
             .style(Style::default().fg(Color::Yellow));
         frame.render_widget(status, chunks[2]);
+
+        // Loading progress overlays the bottom of the content area
+        if self.tasks.is_busy() {
+            let height = self.tasks.panel_height().min(chunks[1].height);
+            let area = ratatui::layout::Rect {
+                y: chunks[1].y + chunks[1].height - height,
+                height,
+                ..chunks[1]
+            };
+            self.tasks.render(frame, area);
+        }
+
+        let area = frame.area();
+        self.finder.render(frame, area);
+    }
+}
+
+/// Decode an image and build its ASCII representation
+fn decode_image(path: &str, handle: &TaskHandle<LoadedImage>) -> Result<LoadedImage, String> {
+    handle.report_with(0.1, "decoding");
+    let img = image::open(path).map_err(|e| e.to_string())?;
+
+    // Always generate ASCII representation for fallback
+    handle.report_with(0.5, "converting to ASCII");
+    let resized_img = img.resize(80, 40, image::imageops::FilterType::Nearest);
+
+    let mut ascii = Vec::new();
+    for y in 0..resized_img.height() {
+        if handle.is_cancelled() {
+            return Err("cancelled".to_string());
+        }
+        let mut line = String::new();
+        for x in 0..resized_img.width() {
+            let pixel = resized_img.get_pixel(x, y);
+            let brightness =
+                0.3 * pixel[0] as f32 + 0.59 * pixel[1] as f32 + 0.11 * pixel[2] as f32;
+            let ascii_char = match brightness as u8 {
+                0..=50 => ' ',
+                51..=100 => '.',
+                101..=150 => '*',
+                151..=200 => '#',
+                _ => '@',
+            };
+            line.push(ascii_char);
+        }
+        ascii.push(line);
+        handle.report(0.5 + 0.5 * (y + 1) as f64 / resized_img.height() as f64);
     }
+
+    Ok(LoadedImage {
+        path: path.to_string(),
+        image: img,
+        ascii,
+        exif: ExifInfo::read(Path::new(path)),
+    })
+}
+
+#[derive(Debug, Parser)]
+#[command(version, about = "View images in the terminal")]
+struct Cli {
+    /// Image to open at start
+    image: Option<String>,
+    #[command(flatten)]
+    common: CommonArgs,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse_with(|args: &Cli| &args.common);
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.common.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app
-    let mut app = ImageViewerApp::new();
+    let mut app = ImageViewerApp::new(args.common.config());
+    if let Some(path) = &args.image {
+        app.load_image(path);
+    }
 
     // Main loop
     loop {
@@ -239,13 +433,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         }
 
+        // Apply progress reported by background tasks
+        app.poll_tasks();
+        app.finder.poll();
+        app.poll_watcher();
+
         // Draw the UI
         terminal.draw(|f| app.render(f))?;
 
         // Handle events
         if crossterm::event::poll(std::time::Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
-                app.on_key(key.code);
+                if app.finder.visible {
+                    if let FinderAction::Open(path, kind) = app.finder.handle_key(key) {
+                        app.open_file(&path, kind);
+                    }
+                } else if FileFinder::is_shortcut(&key) {
+                    app.finder.open();
+                } else {
+                    app.on_key(key.code);
+                }
             }
         }
 
@@ -262,7 +469,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             config.width = Some((terminal_size.width / 2) as u32);
             config.height = Some((terminal_size.height / 2) as u32);
             config.truecolor = true;
-            config.use_iterm = true;
+            // Probing for an unsupported protocol garbles the Windows console
+            let graphics = console::graphics();
+            config.use_kitty = graphics.kitty;
+            config.use_iterm = graphics.iterm;
 
             // Display the image from memory if possible
             let mut viuer_error: Option<String> = None;
@@ -280,7 +490,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             // Return to alternate screen mode
             enable_raw_mode()?;
-            execute!(terminal.backend_mut(), EnableMouseCapture)?;
+            args.common.capture_mouse()?;
 
             // Now it's safe to mutate app
             if let Some(msg) = viuer_error {
@@ -318,7 +528,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         config.width = None;      // Auto-detect for full screen
         config.height = None;     // Auto-detect for full screen
         config.truecolor = true;  // Use true color
-        config.use_iterm = true;  // Try iTerm protocol
+        let graphics = console::graphics();
+        config.use_kitty = graphics.kitty;
+        config.use_iterm = graphics.iterm;
         
         // Display the image directly from memory
         if let Err(e) = viuer::print(&image_buffer.unwrap(), &config) {
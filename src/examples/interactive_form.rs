@@ -12,7 +12,9 @@ use ratatui::{
     },
     Frame, Terminal,
 };
-use unicode_width::UnicodeWidthStr;
+
+use tui_image_viewer::widgets::line_edit::handle_edit_key;
+use tui_image_viewer::widgets::selection::column_of_char;
 
 const HISTORY_SIZE: usize = 10;
 
@@ -41,6 +43,9 @@ struct App {
     // Input fields
     name: String,
     email: String,
+    // Cursor positions in characters
+    name_cursor: usize,
+    email_cursor: usize,
     
     // Current state
     input_mode: InputMode,
@@ -77,6 +82,8 @@ impl Default for App {
         Self {
             name: String::new(),
             email: String::new(),
+            name_cursor: 0,
+            email_cursor: 0,
             input_mode: InputMode::Navigation,
             active_field: InputField::Name,
             selected_option: SelectOption::Yes,
@@ -377,16 +384,14 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
     if app.input_mode == InputMode::Editing {
         match app.active_field {
             InputField::Name => {
-                // Position cursor at end of text
                 f.set_cursor_position(ratatui::layout::Position::new(
-                    chunks[1].x + app.name.width() as u16 + 1,
+                    chunks[1].x + column_of_char(&app.name, app.name_cursor) as u16 + 1,
                     chunks[1].y + 1,
                 ));
             }
             InputField::Email => {
-                // Position cursor at end of text
                 f.set_cursor_position(ratatui::layout::Position::new(
-                    chunks[2].x + app.email.width() as u16 + 1,
+                    chunks[2].x + column_of_char(&app.email, app.email_cursor) as u16 + 1,
                     chunks[2].y + 1,
                 ));
             }
@@ -445,6 +450,8 @@ pub fn run_app<B: Backend>(
 
                                 app.name = String::new();
                                 app.email = String::new();
+                                app.name_cursor = 0;
+                                app.email_cursor = 0;
                                 app.name_valid = false;
                                 app.email_valid = false;
                             } else if app.name_valid && app.email_valid {
@@ -464,6 +471,8 @@ pub fn run_app<B: Backend>(
 
                                 app.name = String::new();
                                 app.email = String::new();
+                                app.name_cursor = 0;
+                                app.email_cursor = 0;
                                 app.name_valid = false;
                                 app.email_valid = false;
                             } else {
@@ -509,40 +518,21 @@ pub fn run_app<B: Backend>(
                     KeyCode::Enter | KeyCode::Esc => {
                         app.input_mode = InputMode::Navigation;
                     }
-                    KeyCode::Char(c) => {
-                        match app.active_field {
-                            InputField::Name => {
-                                app.name.push(c);
+                    // Typing plus readline editing: Ctrl+Left/Right word navigation,
+                    // Ctrl+Backspace, Ctrl+U, Ctrl+K and Alt+D
+                    _ => match app.active_field {
+                        InputField::Name => {
+                            if handle_edit_key(&mut app.name, &mut app.name_cursor, key) {
                                 app.validate_name();
                             }
-                            InputField::Email => {
-                                app.email.push(c);
-                                app.validate_email();
-                            }
-                            _ => {}
                         }
-                    }
-                    KeyCode::Backspace => {
-                        match app.active_field {
-                            InputField::Name => {
-                                app.name.pop();
-                                app.validate_name();
-                            }
-                            InputField::Email => {
-                                app.email.pop();
+                        InputField::Email => {
+                            if handle_edit_key(&mut app.email, &mut app.email_cursor, key) {
                                 app.validate_email();
                             }
-                            _ => {}
                         }
-                    }
-                    // Support for Ctrl+Left/Right for word navigation
-                    KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        // Word navigation left implementation would go here
-                    }
-                    KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        // Word navigation right implementation would go here
-                    }
-                    _ => {}
+                        _ => {}
+                    },
                 },
             }
         }
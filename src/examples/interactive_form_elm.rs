@@ -0,0 +1,344 @@
+//! Interactive form in the Elm style
+//!
+//! The name, email and selection form of `interactive_form` written as a
+//! `Program`: the model is `Form`, keys become `Msg`s in `on_event`, every
+//! change happens in `update`, and campaign generation is a `Cmd` whose
+//! result comes back as `Msg::Generated`. Compare with the imperative
+//! `src/bin/interactive_form.rs` to pick a structure for your own screens.
+
+use std::collections::VecDeque;
+use std::io;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame, Terminal,
+};
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::campaign::{create_campaign_files, CampaignRequest};
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::elm::{Cmd, Program, Runtime};
+use tui_image_viewer::widgets::line_edit::handle_edit_key;
+
+const HISTORY_SIZE: usize = 10;
+
+const OPTIONS: [&str; 4] = ["Yes", "No", "Maybe", "Other"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Name,
+    Email,
+    Selection,
+}
+
+impl Field {
+    fn next(self) -> Self {
+        match self {
+            Field::Name => Field::Email,
+            Field::Email => Field::Selection,
+            Field::Selection => Field::Name,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            Field::Name => Field::Selection,
+            Field::Email => Field::Name,
+            Field::Selection => Field::Email,
+        }
+    }
+}
+
+enum Msg {
+    FocusNext,
+    FocusPrevious,
+    StartEditing,
+    StopEditing,
+    /// An editing key for the focused text field
+    Edit(KeyEvent),
+    /// Move the selection by this many options
+    Select(isize),
+    Submit,
+    Generate,
+    /// Campaign directory, or why generation failed
+    Generated(Result<String, String>),
+    Quit,
+}
+
+/// The model
+struct Form {
+    name: String,
+    email: String,
+    // Cursor positions in characters
+    name_cursor: usize,
+    email_cursor: usize,
+    field: Field,
+    editing: bool,
+    selected: usize,
+    generating: bool,
+    history: VecDeque<String>,
+}
+
+impl Form {
+    fn new() -> Self {
+        Self {
+            name: String::new(),
+            email: String::new(),
+            name_cursor: 0,
+            email_cursor: 0,
+            field: Field::Name,
+            editing: false,
+            selected: 0,
+            generating: false,
+            history: VecDeque::from(["Welcome to the Elm-style form".to_string()]),
+        }
+    }
+
+    fn name_valid(&self) -> bool {
+        self.name.trim().chars().count() >= 2
+    }
+
+    fn email_problem(&self) -> Option<&'static str> {
+        let email = &self.email;
+        let valid = regex::Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").unwrap();
+        if !email.contains('@') {
+            Some("Missing @ symbol")
+        } else if !email.contains('.') {
+            Some("Missing domain (.com, .org, etc.)")
+        } else if email.starts_with('@') {
+            Some("Cannot start with @")
+        } else if email.ends_with('.') {
+            Some("Cannot end with .")
+        } else if email.matches('@').count() > 1 {
+            Some("Too many @ symbols")
+        } else if !valid.is_match(email) {
+            Some("Invalid email format")
+        } else {
+            None
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.name_valid() && self.email_problem().is_none()
+    }
+
+    fn log(&mut self, entry: String) {
+        self.history.push_front(entry);
+        self.history.truncate(HISTORY_SIZE);
+    }
+}
+
+impl Program for Form {
+    type Msg = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::FocusNext => self.field = self.field.next(),
+            Msg::FocusPrevious => self.field = self.field.previous(),
+            Msg::StartEditing => self.editing = self.field != Field::Selection,
+            Msg::StopEditing => self.editing = false,
+            Msg::Edit(key) => match self.field {
+                Field::Name => {
+                    handle_edit_key(&mut self.name, &mut self.name_cursor, key);
+                }
+                Field::Email => {
+                    handle_edit_key(&mut self.email, &mut self.email_cursor, key);
+                }
+                Field::Selection => {}
+            },
+            Msg::Select(step) => {
+                self.selected = self.selected.saturating_add_signed(step).min(OPTIONS.len() - 1);
+            }
+            Msg::Submit if self.is_valid() => {
+                let entry = format!(
+                    "✓ Form submitted - Name: {}, Email: {}, Selection: {}",
+                    self.name, self.email, OPTIONS[self.selected]
+                );
+                self.log(entry);
+            }
+            Msg::Submit => self.log("✗ Please complete all fields correctly".to_string()),
+            Msg::Generate if self.is_valid() && !self.generating => {
+                self.generating = true;
+                self.log("Generating campaign files...".to_string());
+                let campaign = CampaignRequest {
+                    name: self.name.clone(),
+                    email: self.email.clone(),
+                    selection: OPTIONS[self.selected].to_string(),
+                    ..CampaignRequest::default()
+                };
+                return Cmd::perform(move || Msg::Generated(create_campaign_files(&campaign, |_, _| Ok(()))));
+            }
+            Msg::Generate => {}
+            Msg::Generated(result) => {
+                self.generating = false;
+                self.log(match result {
+                    Ok(dir) => format!("✓ Campaign files generated in {}", dir),
+                    Err(e) => format!("✗ Generating Campaign failed: {}", e),
+                });
+            }
+            Msg::Quit => return Cmd::Quit,
+        }
+        Cmd::None
+    }
+
+    fn on_event(&self, event: Event) -> Option<Msg> {
+        let Event::Key(key) = event else { return None };
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if self.editing {
+            return match key.code {
+                KeyCode::Enter | KeyCode::Esc => Some(Msg::StopEditing),
+                _ => Some(Msg::Edit(key)),
+            };
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => Some(Msg::Quit),
+            KeyCode::Tab | KeyCode::Down => Some(Msg::FocusNext),
+            KeyCode::BackTab | KeyCode::Up => Some(Msg::FocusPrevious),
+            KeyCode::Left if self.field == Field::Selection => Some(Msg::Select(-1)),
+            KeyCode::Right if self.field == Field::Selection => Some(Msg::Select(1)),
+            KeyCode::Enter if self.field == Field::Selection => Some(Msg::Submit),
+            KeyCode::Enter => Some(Msg::StartEditing),
+            KeyCode::Char(' ') => Some(Msg::Generate),
+            _ => None,
+        }
+    }
+
+    fn view(&self, f: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(3), // Title
+                Constraint::Length(3), // Name
+                Constraint::Length(3), // Email
+                Constraint::Length(3), // Selection
+                Constraint::Min(4),    // History
+            ])
+            .split(f.area());
+
+        let title = if self.generating {
+            "Generating campaign files... Q to quit".to_string()
+        } else if self.editing {
+            "Editing - Type to input, Enter/Esc to stop".to_string()
+        } else {
+            "Elm-style Form - Tab/↑↓ navigate, Enter edit, Space generate, Q quit".to_string()
+        };
+        let title = Paragraph::new(title)
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL));
+        f.render_widget(title, chunks[0]);
+
+        let name_check = match (self.name.is_empty(), self.name_valid()) {
+            (true, _) => None,
+            (false, true) => Some((true, " ✓".to_string())),
+            (false, false) => Some((false, " ✗ Name must be at least 2 characters".to_string())),
+        };
+        let email_check = match (self.email.is_empty(), self.email_problem()) {
+            (true, _) => None,
+            (false, None) => Some((true, " ✓ Valid email".to_string())),
+            (false, Some(problem)) => Some((false, format!(" ✗ {}", problem))),
+        };
+        f.render_widget(self.input(Field::Name, "Name", &self.name, name_check), chunks[1]);
+        f.render_widget(self.input(Field::Email, "Email", &self.email, email_check), chunks[2]);
+
+        let options: Vec<Span> = OPTIONS
+            .iter()
+            .enumerate()
+            .map(|(i, option)| {
+                let style = if i == self.selected {
+                    Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Span::styled(format!(" {} ", option), style)
+            })
+            .collect();
+        let selection = Paragraph::new(Line::from(options)).block(self.field_block(Field::Selection, "Selection (←→, Enter submit)"));
+        f.render_widget(selection, chunks[3]);
+
+        let history: Vec<ListItem> = self.history.iter().map(|entry| ListItem::new(entry.as_str())).collect();
+        let history = List::new(history).block(Block::default().title("History").borders(Borders::ALL));
+        f.render_widget(history, chunks[4]);
+    }
+}
+
+impl Form {
+    fn field_block(&self, field: Field, title: &str) -> Block<'static> {
+        let title_style = match (self.field == field, self.editing) {
+            (true, true) => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            (true, false) => Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            (false, _) => Style::default(),
+        };
+        Block::default()
+            .title(Span::styled(title.to_string(), title_style))
+            .borders(Borders::ALL)
+            .border_style(if self.field == field { Style::default().fg(Color::Cyan) } else { Style::default() })
+    }
+
+    /// A text field followed by whether it is valid and why
+    fn input<'a>(&self, field: Field, title: &str, value: &'a str, check: Option<(bool, String)>) -> Paragraph<'a> {
+        let mut spans = vec![Span::raw(value)];
+        if let Some((valid, text)) = check {
+            let color = if valid { Color::Green } else { Color::Red };
+            spans.push(Span::styled(text, Style::default().fg(color).add_modifier(Modifier::BOLD)));
+        }
+        Paragraph::new(Line::from(spans)).block(self.field_block(field, title))
+    }
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut runtime = Runtime::new(Form::new());
+    let res = runtime.run(&mut terminal, &mut EventDispatcher::new(), Duration::from_millis(100));
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let form = Form::new();
+        snapshot::assert_screen("interactive_form_elm", |f| form.view(f));
+    }
+}
@@ -4,49 +4,231 @@
 //! - Arrow key navigation (up/down for rows, left/right for columns)
 //! - Shift + Arrow keys for multi-selection
 //! - Visual highlighting of selected row and column
+//! - Vim keys (hjkl, gg/G, dd, yy/p) or emacs chords from the configured keymap
+//! - Macros: Alt+Q <register> records edits, Alt+@ <count> <register> replays them
+//!   (a digit register after a `"`)
+//! - Column chooser popup on `v`; hidden columns are remembered in the config
+//! - Pivot summary on `P`: `g` group column, `a` aggregated column, `f` count/sum/avg
+//! - Export on `e`: table, selection or pivot as CSV, JSON or Markdown, copied
+//!   to the clipboard or written to a file
+//! - Reports: `r` saves the current screen as HTML in theme colors, `R` as plain text
+//!
+//! Pass a CSV file to explore it instead of the demo data:
+//! `cargo run --bin interactive_table -- data.csv`
 
+use clap::Parser;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
+    buffer::Buffer,
     layout::{Constraint, Direction, Layout},
     style::{Color, Style},
     widgets::{Block, Borders, Paragraph},
     Terminal, Frame,
 };
 use std::{
-    error::Error,
-    io,
+    fs, io,
+    path::PathBuf,
     time::{Duration, Instant},
 };
+use std::process::ExitCode;
 
-use tui_image_viewer::widgets::table::{create_demo_table, InteractiveTable};
+use tui_image_viewer::app::cli::{self, CommonArgs};
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::bus::{Message, MessageBus, StatusLine};
+use tui_image_viewer::widgets::clipboard::{self, ClipboardStore};
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::export::{export, ExportAction, ExportDialog};
+use tui_image_viewer::widgets::keymap::{KeyResult, Keymap, KeymapProfile};
+use tui_image_viewer::widgets::pivot::PivotView;
+use tui_image_viewer::widgets::report::{self, ReportTheme};
+use tui_image_viewer::widgets::table::{create_demo_table, InteractiveTable, TableData};
+
+/// Key for this table's column choice in the config
+const TABLE_NAME: &str = "interactive_table";
 
 struct App {
     table: InteractiveTable,
+    /// Summary shown instead of the table while set
+    pivot: Option<PivotView>,
+    export: Option<ExportDialog>,
+    /// Row moves, copies and export results published by the table and
+    /// dialogs, handed out once per loop
+    bus: MessageBus,
+    /// Latest message, shown with the instructions
+    status: StatusLine,
+    /// Exported copies join the clipboard history
+    clipboard_store: ClipboardStore,
+    /// Report requested with `r` (HTML) or `R` (text), written after the next draw
+    pending_report: Option<bool>,
+    keymap: Keymap,
+    /// Loaded once and shared, so saves never overwrite newer settings
+    config: ConfigHandle,
+    events: EventDispatcher,
     should_quit: bool,
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(mut table: InteractiveTable, handle: ConfigHandle) -> Self {
+        let config = handle.get();
+        if let Some(hidden) = config.hidden_columns.get(TABLE_NAME) {
+            table.set_hidden_headers(hidden);
+        }
+        let bus = MessageBus::new();
+        table.publish_to(bus.publisher());
         Self {
-            table: create_demo_table(),
+            table,
+            pivot: None,
+            export: None,
+            bus,
+            status: StatusLine::new(),
+            clipboard_store: ClipboardStore::new(config.clipboard.clone()),
+            pending_report: None,
+            keymap: Keymap::new(config.keymap),
+            config: handle,
+            events: EventDispatcher::new().with_keymap(config.keymap),
             should_quit: false,
         }
     }
 
-    fn handle_key_event(&mut self, key: KeyCode, modifiers: KeyModifiers) {
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
-                self.should_quit = true;
+    /// Remember the visible columns for the next run
+    fn save_columns(&self) {
+        let hidden = self.table.hidden_headers();
+        let saved = self.config.update_and_save(|config| {
+            config.hidden_columns.insert(TABLE_NAME.to_string(), hidden);
+        });
+        if let Err(e) = saved {
+            eprintln!("Failed to save column choice: {}", e);
+        }
+    }
+
+    /// Group by the selected column, aggregating the next numeric-looking one
+    fn open_pivot(&mut self) {
+        let group = self.table.selected_column;
+        let numeric = |i: &usize| {
+            self.table.data.rows.iter().any(|row| row.get(*i).is_some_and(|value| value.trim().parse::<f64>().is_ok()))
+        };
+        let value = (0..self.table.data.headers.len()).find(|i| *i != group && numeric(i)).unwrap_or(group);
+        self.pivot = Some(PivotView::new(group, value));
+    }
+
+    fn open_export(&mut self) {
+        let has_selection = self.pivot.is_none() && self.table.get_selection_count() > 0;
+        self.export = Some(ExportDialog::new(has_selection));
+    }
+
+    fn handle_export_key(&mut self, key: KeyEvent) {
+        let Some(dialog) = &mut self.export else {
+            return;
+        };
+        let action = dialog.handle_key(key);
+        let (format, selection_only) = (dialog.format, dialog.selection_only);
+        let data = match &self.pivot {
+            Some(pivot) => pivot.to_table_data(&self.table.data),
+            None => self.table.export_data(selection_only),
+        };
+        let result = match action {
+            ExportAction::None => return,
+            ExportAction::Cancel => {
+                self.export = None;
+                return;
+            }
+            ExportAction::Copy => {
+                let text = export(&data, format);
+                clipboard::copy_text(&text)
+                    .map(|_| {
+                        self.bus.publish(Message::Copied(text));
+                        format!("Copied {} rows as {}", data.rows.len(), format.name())
+                    })
+                    .map_err(|e| e.to_string())
+            }
+            ExportAction::Write(path) => std::fs::write(&path, export(&data, format))
+                .map(|_| format!("Wrote {} rows to {}", data.rows.len(), path.display()))
+                .map_err(|e| e.to_string()),
+        };
+        self.bus.publish(Message::Status(match result {
+            Ok(message) => message,
+            Err(e) => format!("Export failed: {}", e),
+        }));
+        self.export = None;
+    }
+
+    /// Save the drawn screen as `report-<time>.html` or `.txt` in the current directory
+    fn write_report(&mut self, buffer: &Buffer, html: bool) {
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let (path, content) = if html {
+            let theme = self.config.read(|config| {
+                config
+                    .themes
+                    .get(config.theme_index)
+                    .map(|theme| ReportTheme {
+                        primary: theme.primary_color.clone(),
+                        secondary: theme.secondary_color.clone(),
+                        background: theme.background_color.clone(),
+                        text: theme.text_color.clone(),
+                    })
+                    .unwrap_or_default()
+            });
+            (format!("report-{}.html", stamp), report::to_html(buffer, "Interactive Table", &theme))
+        } else {
+            (format!("report-{}.txt", stamp), report::to_text(buffer))
+        };
+        self.bus.publish(Message::Status(match fs::write(&path, content) {
+            Ok(()) => format!("Saved report to {}", path),
+            Err(e) => format!("Report failed: {}", e),
+        }));
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) {
+        if self.export.is_some() {
+            self.handle_export_key(key);
+            return;
+        }
+        if let Some(pivot) = &mut self.pivot {
+            match key.code {
+                KeyCode::Char('P') | KeyCode::Esc => self.pivot = None,
+                KeyCode::Char('e') => self.open_export(),
+                KeyCode::Char('r') => self.pending_report = Some(true),
+                KeyCode::Char('R') => self.pending_report = Some(false),
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Char(c) => {
+                    pivot.handle_key(c, self.table.data.headers.len());
+                }
+                _ => {}
             }
-            _ => {
-                let shift_pressed = modifiers.contains(KeyModifiers::SHIFT);
-                self.table.handle_key_with_shift(key, shift_pressed);
+            return;
+        }
+        if self.table.column_chooser_open() {
+            // The popup takes raw keys so vim/emacs bindings don't move the table
+            self.table.handle_key(key.code);
+            if !self.table.column_chooser_open() {
+                self.save_columns();
+            }
+            return;
+        }
+        match self.keymap.resolve(key) {
+            KeyResult::Action(action) => {
+                self.table.handle_action(action);
             }
+            KeyResult::Pending => {}
+            KeyResult::Unbound(key) => match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    self.should_quit = true;
+                }
+                KeyCode::Char('P') => self.open_pivot(),
+                KeyCode::Char('e') => self.open_export(),
+                KeyCode::Char('r') => self.pending_report = Some(true),
+                KeyCode::Char('R') => self.pending_report = Some(false),
+                _ => {
+                    let shift_pressed = key.modifiers.contains(KeyModifiers::SHIFT);
+                    self.table.handle_key_with_shift(key.code, shift_pressed);
+                }
+            },
         }
     }
 }
@@ -60,48 +242,110 @@ fn ui(f: &mut Frame, app: &mut App) {
         ])
         .split(f.area());
 
-    // Render the interactive table
-    app.table.render(f, chunks[0]);
+    // Render the interactive table or its pivot summary
+    match &app.pivot {
+        Some(pivot) => pivot.render(f, chunks[0], &app.table.data),
+        None => app.table.render(f, chunks[0]),
+    }
 
     // Instructions
     let selection_count = app.table.get_selection_count();
-    let instructions = format!(
-        "Navigation: ↑↓ rows, ←→ columns | Shift+Arrow: multi-select ({} selected) | c: clear | q: quit",
+    if app.pivot.is_some() {
+        let help = Paragraph::new("Pivot: g group column | a aggregated column | f count/sum/avg | e: export | r/R: report | P/Esc: back to table | q: quit")
+            .block(Block::default().borders(Borders::ALL).title(app.events.status_title("Instructions")))
+            .style(Style::default().fg(Color::Yellow));
+        f.render_widget(help, chunks[1]);
+        render_export(f, app);
+        return;
+    }
+
+    let mut instructions = format!(
+        "Navigation: ↑↓ rows, ←→ columns | Shift+Arrow: multi-select ({} selected) | c: clear | v: columns | P: pivot | e: export | r/R: report | Alt+Q/Alt+@: record/replay macro | q: quit",
         selection_count
     );
+    match app.keymap.profile {
+        KeymapProfile::Vim => instructions.push_str(" | vim: hjkl, gg/G, dd, yy/p"),
+        KeymapProfile::Emacs => instructions.push_str(" | emacs: C-n/C-p, C-f/C-b, M-</M->, C-y"),
+        KeymapProfile::Default => {}
+    }
     
+    let title = app.status.text().unwrap_or("Instructions");
     let instructions_widget = Paragraph::new(instructions)
-        .block(Block::default().borders(Borders::ALL).title("Instructions"))
+        .block(Block::default().borders(Borders::ALL).title(app.events.status_title(title)))
         .style(Style::default().fg(Color::Yellow));
     
     f.render_widget(instructions_widget, chunks[1]);
+    render_export(f, app);
+}
+
+fn render_export(f: &mut Frame, app: &App) {
+    if let Some(dialog) = &app.export {
+        let area = f.area();
+        dialog.render(f, area);
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Browse and edit a table")]
+struct Cli {
+    /// CSV file to open instead of the demo table
+    csv: Option<PathBuf>,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse_with(|args: &Cli| &args.common);
+    let table = match &args.csv {
+        Some(path) => InteractiveTable::new(
+            TableData::from_csv(&fs::read_to_string(path)?)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        ),
+        None => create_demo_table(),
+    };
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.common.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app and run it
-    let mut app = App::new();
+    let mut app = App::new(table, args.common.config());
+    if let Some(path) = &args.csv {
+        if let Err(e) = app.config.remember_file(path) {
+            eprintln!("Failed to save recent files: {}", e);
+        }
+    }
     let tick_rate = Duration::from_millis(100);
     let mut last_tick = Instant::now();
 
     loop {
-        terminal.draw(|f| ui(f, &mut app))?;
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+        if let Some(html) = app.pending_report.take() {
+            app.write_report(frame.buffer, html);
+        }
 
         let timeout = tick_rate.saturating_sub(last_tick.elapsed());
-        if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    app.handle_key_event(key.code, key.modifiers);
-                }
+        if let Some(Event::Key(key)) = app.events.next(timeout)? {
+            if key.kind == KeyEventKind::Press {
+                app.handle_key_event(key);
             }
         }
 
+        app.bus.dispatch(&mut [&mut app.status, &mut app.clipboard_store]);
+
         if last_tick.elapsed() >= tick_rate {
             last_tick = Instant::now();
         }
@@ -121,4 +365,17 @@ fn main() -> Result<(), Box<dyn Error>> {
     terminal.show_cursor()?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = App::new(create_demo_table(), ConfigHandle::default());
+        snapshot::assert_screen("interactive_table", |f| ui(f, &mut app));
+    }
+}
@@ -0,0 +1,232 @@
+//! Journal calendar example
+//!
+//! Month view for navigating journal activity: days with campaigns or
+//! account log entries are marked, and the selected day's entries are
+//! listed beside the calendar.
+
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame, Terminal,
+};
+use std::{fs, io, path::Path, time::Duration};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::calendar::Calendar;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::humanize::time_ago;
+use tui_image_viewer::widgets::timeline::parse_timestamp;
+
+struct JournalEntry {
+    time: DateTime<Utc>,
+    title: String,
+}
+
+impl JournalEntry {
+    fn local_date(&self) -> NaiveDate {
+        self.time.with_timezone(&Local).date_naive()
+    }
+}
+
+struct JournalApp {
+    calendar: Calendar,
+    entries: Vec<JournalEntry>,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    should_quit: bool,
+}
+
+impl JournalApp {
+    fn new(config: &ConfigHandle) -> Self {
+        let mut entries = load_entries(Path::new("./campaigns"), Path::new("./logs"));
+        entries.sort_by_key(|entry| entry.time);
+
+        let calendar = Calendar::new(Local::now().date_naive())
+            .with_week_start(config.read(|config| config.week_start))
+            .with_events(entries.iter().map(JournalEntry::local_date));
+
+        Self {
+            calendar,
+            entries,
+            events: EventDispatcher::new(),
+            should_quit: false,
+        }
+    }
+
+    fn entries_for_selected(&self) -> impl Iterator<Item = &JournalEntry> {
+        let selected = self.calendar.selected;
+        self.entries.iter().filter(move |entry| entry.local_date() == selected)
+    }
+
+    /// Jump to the nearest day with entries in the given direction
+    fn jump_to_entry(&mut self, forward: bool) {
+        let selected = self.calendar.selected;
+        let target = if forward {
+            self.entries.iter().map(JournalEntry::local_date).find(|date| *date > selected)
+        } else {
+            self.entries.iter().rev().map(JournalEntry::local_date).find(|date| *date < selected)
+        };
+        if let Some(date) = target {
+            self.calendar.selected = date;
+        }
+    }
+
+    fn on_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('n') => self.jump_to_entry(true),
+            KeyCode::Char('p') => self.jump_to_entry(false),
+            _ => {
+                self.calendar.handle_key(key);
+            }
+        }
+    }
+}
+
+/// Read journal activity from campaign folders and account API logs
+fn load_entries(campaigns_dir: &Path, logs_dir: &Path) -> Vec<JournalEntry> {
+    let mut entries = Vec::new();
+
+    let read_json = |path: &Path| -> Option<serde_json::Value> {
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+    };
+
+    if let Ok(dirs) = fs::read_dir(campaigns_dir) {
+        for dir in dirs.filter_map(|entry| entry.ok()) {
+            let Some(json) = read_json(&dir.path().join("campaign.json")) else {
+                continue;
+            };
+            let Some(time) = json["created_at"].as_str().and_then(parse_timestamp) else {
+                continue;
+            };
+            let name = json["campaign_name"].as_str().unwrap_or("Campaign");
+            let user = json["user"]["email"].as_str().unwrap_or_default();
+            entries.push(JournalEntry {
+                time,
+                title: format!("📧 {} {}", name, user),
+            });
+        }
+    }
+
+    if let Ok(logs) = fs::read_dir(logs_dir) {
+        for log in logs.filter_map(|entry| entry.ok()) {
+            let Some(json) = read_json(&log.path()) else {
+                continue;
+            };
+            let Some(time) = json["timestamp"].as_str().and_then(parse_timestamp) else {
+                continue;
+            };
+            let action = json["action"].as_str().unwrap_or("event").replace('_', " ");
+            let user = json["user_data"]["email"].as_str().unwrap_or_default();
+            entries.push(JournalEntry {
+                time,
+                title: format!("👤 {} {}", action, user),
+            });
+        }
+    }
+
+    entries
+}
+
+fn ui(f: &mut Frame, app: &JournalApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(10), Constraint::Length(3)])
+        .split(f.area());
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(32), Constraint::Min(20)])
+        .split(chunks[0]);
+
+    let calendar_area = ratatui::layout::Rect {
+        height: app.calendar.height().min(body[0].height),
+        ..body[0]
+    };
+    app.calendar.render(f, calendar_area);
+
+    let items: Vec<ListItem> = app
+        .entries_for_selected()
+        .map(|entry| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    entry.time.with_timezone(&Local).format("%H:%M  ").to_string(),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(entry.title.clone()),
+                Span::styled(format!("  {}", time_ago(&entry.time)), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+    let title = app.calendar.selected.format("%A, %d %B %Y").to_string();
+    let list = if items.is_empty() {
+        List::new(vec![ListItem::new("No journal entries").style(Style::default().fg(Color::DarkGray))])
+    } else {
+        List::new(items)
+    };
+    f.render_widget(list.block(Block::default().borders(Borders::ALL).title(title)), body[1]);
+
+    let help = Paragraph::new("←→↑↓: day/week | PgUp/PgDn: month | t: today | n/p: next/previous entry | q: Quit")
+        .block(Block::default().borders(Borders::ALL).title(app.events.status_title("Controls")))
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(help, chunks[1]);
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = JournalApp::new(&args.config());
+
+    loop {
+        let frame = terminal.draw(|f| {
+            ui(f, &app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        if let Some(Event::Key(key)) = app.events.next(Duration::from_millis(250))? {
+            if key.kind == KeyEventKind::Press {
+                app.on_key(key.code);
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
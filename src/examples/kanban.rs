@@ -0,0 +1,460 @@
+//! Kanban board example
+//!
+//! Todo/Doing/Done columns with cards that can be moved with the keyboard or
+//! dragged with the mouse. Cards are edited in a form dialog and the board is
+//! saved to JSON on every change.
+
+use crossterm::{
+    event::{
+        DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame, Terminal,
+};
+use serde::{Deserialize, Serialize};
+use std::{fs, io, path::PathBuf, time::Duration};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::form::{Condition, FormAction, FormDialog, FormField};
+
+/// Rows taken by a single card, including its border
+const CARD_HEIGHT: u16 = 4;
+
+const COLUMN_NAMES: [&str; 3] = ["📋 Todo", "🚧 Doing", "✅ Done"];
+
+/// Choices for the card label; "Other" asks for a custom label
+const LABELS: [&str; 5] = ["None", "Bug", "Feature", "Docs", "Other"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Card {
+    title: String,
+    description: String,
+    /// Shown in the card border; empty for no label
+    #[serde(default)]
+    label: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Board {
+    columns: [Vec<Card>; 3],
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        let card = |title: &str, description: &str| Card {
+            title: title.to_string(),
+            description: description.to_string(),
+            label: String::new(),
+        };
+        Self {
+            columns: [
+                vec![
+                    card("Write docs", "Document the widget APIs"),
+                    card("Add tests", "Cover the table selection logic"),
+                ],
+                vec![card("Kanban demo", "Drag cards between columns")],
+                vec![card("Project setup", "Cargo workspace and CI")],
+            ],
+        }
+    }
+}
+
+impl Board {
+    fn path() -> Option<PathBuf> {
+        let mut path = dirs::data_dir()?;
+        path.push("ratatui-rust-example");
+        path.push("kanban.json");
+        Some(path)
+    }
+
+    fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<(), DemoError> {
+        let path = Self::path().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find data directory"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// Card being edited in the form dialog; `None` index means a new card
+struct Editing {
+    column: usize,
+    index: Option<usize>,
+    form: FormDialog,
+}
+
+struct KanbanApp {
+    board: Board,
+    focused_column: usize,
+    selected: [usize; 3],
+    editing: Option<Editing>,
+    /// Card picked up by the mouse, and the column it is hovering over
+    dragging: Option<(usize, usize)>,
+    drag_hover: Option<usize>,
+    /// Layout from the last draw, used for mouse hit testing
+    column_areas: Vec<Rect>,
+    offsets: [usize; 3],
+    status_message: String,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    should_quit: bool,
+}
+
+impl KanbanApp {
+    fn new() -> Self {
+        Self {
+            board: Board::load(),
+            focused_column: 0,
+            selected: [0; 3],
+            editing: None,
+            dragging: None,
+            drag_hover: None,
+            column_areas: Vec::new(),
+            offsets: [0; 3],
+            status_message: String::new(),
+            events: EventDispatcher::new(),
+            should_quit: false,
+        }
+    }
+
+    fn persist(&mut self) {
+        if let Err(e) = self.board.save() {
+            self.status_message = format!("❌ Failed to save board: {} ({})", e, e.hint());
+        }
+    }
+
+    fn clamp_selection(&mut self, column: usize) {
+        let len = self.board.columns[column].len();
+        self.selected[column] = self.selected[column].min(len.saturating_sub(1));
+    }
+
+    /// Move a card to another column (or position), selecting it there
+    fn move_card(&mut self, from: (usize, usize), to_column: usize, to_index: usize) {
+        if from.1 >= self.board.columns[from.0].len() {
+            return;
+        }
+        let card = self.board.columns[from.0].remove(from.1);
+        let to_index = to_index.min(self.board.columns[to_column].len());
+        self.board.columns[to_column].insert(to_index, card);
+        self.clamp_selection(from.0);
+        self.focused_column = to_column;
+        self.selected[to_column] = to_index;
+        self.persist();
+    }
+
+    fn open_form(&mut self, index: Option<usize>) {
+        let column = self.focused_column;
+        let card = index.and_then(|i| self.board.columns[column].get(i));
+        let (title, description, label) = card
+            .map(|card| (card.title.as_str(), card.description.as_str(), card.label.as_str()))
+            .unwrap_or_default();
+        let (choice, custom) = match label {
+            "" => ("None", ""),
+            label if LABELS.contains(&label) => (label, ""),
+            label => ("Other", label),
+        };
+        let other = || Condition::equals("Label", "Other");
+        let form = FormDialog::new(
+            if index.is_some() { "Edit Card" } else { "New Card" },
+            vec![
+                FormField::new("Title", title).required().max_len(60),
+                FormField::new("Description", description),
+                FormField::select("Label", &LABELS, choice),
+                FormField::new("Please specify", custom)
+                    .max_len(20)
+                    .visible_when(other())
+                    .required_when(other()),
+            ],
+        );
+        self.editing = Some(Editing { column, index, form });
+    }
+
+    fn submit_form(&mut self, editing: Editing) {
+        let card = Card {
+            title: editing.form.value("Title").unwrap_or_default().trim().to_string(),
+            description: editing.form.value("Description").unwrap_or_default().trim().to_string(),
+            label: match editing.form.value("Label") {
+                Some("Other") => editing.form.value("Please specify").unwrap_or_default().trim().to_string(),
+                Some("None") | None => String::new(),
+                Some(label) => label.to_string(),
+            },
+        };
+        let column = &mut self.board.columns[editing.column];
+        match editing.index {
+            Some(index) if index < column.len() => column[index] = card,
+            _ => {
+                column.push(card);
+                self.selected[editing.column] = column.len() - 1;
+            }
+        }
+        self.persist();
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if let Some(editing) = self.editing.as_mut() {
+            match editing.form.handle_key_event(key) {
+                FormAction::Submit => {
+                    if let Some(editing) = self.editing.take() {
+                        self.submit_form(editing);
+                    }
+                }
+                FormAction::Cancel => self.editing = None,
+                FormAction::None => {}
+            }
+            return;
+        }
+
+        let column = self.focused_column;
+        let index = self.selected[column];
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Left if shift && column > 0 => self.move_card((column, index), column - 1, usize::MAX),
+            KeyCode::Right if shift && column < 2 => self.move_card((column, index), column + 1, usize::MAX),
+            KeyCode::Up if shift && index > 0 => self.move_card((column, index), column, index - 1),
+            KeyCode::Down if shift => self.move_card((column, index), column, index + 1),
+            KeyCode::Left => self.focused_column = column.saturating_sub(1),
+            KeyCode::Right => self.focused_column = (column + 1).min(2),
+            KeyCode::Up => self.selected[column] = index.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected[column] = index + 1;
+                self.clamp_selection(column);
+            }
+            KeyCode::Char('n') => self.open_form(None),
+            KeyCode::Char('e') | KeyCode::Enter if !self.board.columns[column].is_empty() => {
+                self.open_form(Some(index))
+            }
+            KeyCode::Char('d') | KeyCode::Delete if !self.board.columns[column].is_empty() => {
+                let card = self.board.columns[column].remove(index);
+                self.status_message = format!("Deleted \"{}\"", card.title);
+                self.clamp_selection(column);
+                self.persist();
+            }
+            _ => {}
+        }
+    }
+
+    /// Column and card index under a screen position
+    fn hit_test(&self, x: u16, y: u16) -> Option<(usize, Option<usize>)> {
+        let column = self
+            .column_areas
+            .iter()
+            .position(|area| x >= area.x && x < area.x + area.width && y >= area.y && y < area.y + area.height)?;
+        let inner_y = self.column_areas[column].y + 1;
+        let index = self.offsets[column] + (y.saturating_sub(inner_y) / CARD_HEIGHT) as usize;
+        let card = (y >= inner_y && index < self.board.columns[column].len()).then_some(index);
+        Some((column, card))
+    }
+
+    fn on_mouse(&mut self, mouse: MouseEvent) {
+        if self.editing.is_some() {
+            return;
+        }
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some((column, card)) = self.hit_test(mouse.column, mouse.row) {
+                    self.focused_column = column;
+                    if let Some(index) = card {
+                        self.selected[column] = index;
+                        self.dragging = Some((column, index));
+                        self.drag_hover = Some(column);
+                    }
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging.is_some() => {
+                self.drag_hover = self.hit_test(mouse.column, mouse.row).map(|(column, _)| column);
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                if let Some(from) = self.dragging.take() {
+                    if let Some((column, card)) = self.hit_test(mouse.column, mouse.row) {
+                        let target = card.unwrap_or(usize::MAX);
+                        if (column, target) != from {
+                            self.move_card(from, column, target);
+                        }
+                    }
+                }
+                self.drag_hover = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn render_column(f: &mut Frame, app: &mut KanbanApp, column: usize, area: Rect) {
+    let focused = column == app.focused_column;
+    let hovered = app.drag_hover == Some(column) && app.dragging.map(|(from, _)| from) != Some(column);
+    let border_style = if hovered {
+        Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+    } else if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+    let cards = &app.board.columns[column];
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("{} ({})", COLUMN_NAMES[column], cards.len()))
+        .border_style(border_style);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Keep the selected card in view
+    let visible = (inner.height / CARD_HEIGHT).max(1) as usize;
+    let selected = app.selected[column];
+    let offset = &mut app.offsets[column];
+    if selected < *offset {
+        *offset = selected;
+    } else if selected >= *offset + visible {
+        *offset = selected + 1 - visible;
+    }
+
+    for (row, (index, card)) in cards.iter().enumerate().skip(*offset).take(visible).enumerate() {
+        let is_selected = focused && index == selected;
+        let is_dragged = app.dragging == Some((column, index));
+        let style = if is_dragged {
+            Style::default().fg(Color::DarkGray)
+        } else if is_selected {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        let card_area = Rect {
+            y: inner.y + row as u16 * CARD_HEIGHT,
+            height: CARD_HEIGHT,
+            ..inner
+        };
+        let body = Paragraph::new(vec![
+            Line::from(Span::styled(card.title.clone(), style.add_modifier(Modifier::BOLD))),
+            Line::from(Span::styled(card.description.clone(), Style::default().fg(Color::Gray))),
+        ])
+        .block(card_block(card, style));
+        f.render_widget(body, card_area);
+    }
+}
+
+fn card_block(card: &Card, style: Style) -> Block<'static> {
+    let block = Block::default().borders(Borders::ALL).border_style(style);
+    if card.label.is_empty() {
+        block
+    } else {
+        block.title(Span::styled(format!(" {} ", card.label), Style::default().fg(Color::Magenta)))
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut KanbanApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(8), Constraint::Length(3)])
+        .split(f.area());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 3); 3])
+        .split(chunks[0]);
+
+    app.column_areas = columns.to_vec();
+    for (column, area) in columns.iter().enumerate() {
+        render_column(f, app, column, *area);
+    }
+
+    let help = if app.status_message.is_empty() {
+        "←→↑↓: navigate | Shift+Arrows / drag: move card | n: new | e/Enter: edit | d: delete | q: quit".to_string()
+    } else {
+        app.status_message.clone()
+    };
+    let status = Paragraph::new(help)
+        .block(Block::default().borders(Borders::ALL).title(app.events.status_title("Kanban")))
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(status, chunks[1]);
+
+    if let Some(editing) = &app.editing {
+        let area = f.area();
+        editing.form.render(f, area);
+    }
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = KanbanApp::new();
+
+    loop {
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        // Keys queued while drawing (fast typing, pastes) are handled before the next frame
+        for event in app.events.next_batch(Duration::from_millis(250))? {
+            match event {
+                Event::Key(key) if key.kind == KeyEventKind::Press && !app.should_quit => {
+                    app.status_message.clear();
+                    app.on_key(key);
+                }
+                Event::Mouse(mouse) => app.on_mouse(mouse),
+                _ => {}
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = KanbanApp::new();
+        snapshot::assert_screen("kanban", |f| ui(f, &mut app));
+    }
+}
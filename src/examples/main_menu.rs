@@ -1,8 +1,14 @@
 //! Main Menu - Showcase all Ratatui features
 //! Navigate through all implemented examples
+//!
+//! `--demo` tours the menu, help, file finder, project search and text
+//! viewer on its own, then quits.
+//!
+//! Recently opened images, videos, journals and CSV files are listed under
+//! the details; keys 1-9 reopen them.
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{DisableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -15,11 +21,63 @@ use ratatui::{
     Terminal, Frame,
 };
 use std::{
-    error::Error,
     io,
-    process::Command,
+    path::Path,
     time::{Duration, Instant},
 };
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::alert::Alerter;
+use tui_image_viewer::widgets::autopilot::DemoStep;
+use tui_image_viewer::widgets::bus::{Message, MessageBus};
+use tui_image_viewer::widgets::clipboard::{self, ClipboardStore};
+use tui_image_viewer::widgets::console::{self, Session};
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::file_editor::FileEditor;
+use tui_image_viewer::widgets::finder::{FileFinder, FileKind, FinderAction};
+use tui_image_viewer::app::config::{ConfigHandle, ConfigWatcher};
+use tui_image_viewer::widgets::hex_viewer::{looks_binary, read_sample, HexViewer, SNIFF_LEN};
+use tui_image_viewer::widgets::keymap::{KeyResult, Keymap};
+use tui_image_viewer::widgets::links;
+use tui_image_viewer::widgets::recents::RecentKind;
+use tui_image_viewer::widgets::reflow::{Reflow, WrapMode};
+use tui_image_viewer::widgets::runner::{run_built_binary, ProcessRunner};
+use tui_image_viewer::widgets::screen_search::ScreenSearch;
+use tui_image_viewer::widgets::search::{ProjectSearch, SearchAction};
+use tui_image_viewer::widgets::text_viewer::TextViewer;
+use tui_image_viewer::widgets::toast::ToastManager;
+use tui_image_viewer::widgets::truncate::elide_path;
+
+/// Played with `--demo`; stays in the menu, since launched examples are
+/// built first and take an unknown time
+const DEMO: &[DemoStep] = &[
+    DemoStep::Pause(1000),
+    DemoStep::Key(KeyCode::Down),
+    DemoStep::Key(KeyCode::Down),
+    DemoStep::Key(KeyCode::Down),
+    DemoStep::Key(KeyCode::End),
+    DemoStep::Key(KeyCode::Home),
+    DemoStep::Key(KeyCode::Char('?')),
+    DemoStep::Pause(2500),
+    DemoStep::Key(KeyCode::Char('?')),
+    DemoStep::Ctrl('t'),
+    DemoStep::Type("readme"),
+    DemoStep::Pause(2000),
+    DemoStep::Key(KeyCode::Esc),
+    DemoStep::Ctrl('g'),
+    DemoStep::Type("DemoError"),
+    DemoStep::Pause(2000),
+    DemoStep::Key(KeyCode::Down),
+    DemoStep::Key(KeyCode::Enter),
+    DemoStep::Pause(1500),
+    DemoStep::Key(KeyCode::PageDown),
+    DemoStep::Pause(1500),
+    DemoStep::Key(KeyCode::Char('q')),
+    DemoStep::Pause(1000),
+    DemoStep::Key(KeyCode::Char('q')),
+];
 
 #[derive(Clone)]
 struct MenuItem {
@@ -35,10 +93,36 @@ struct MainMenuApp {
     should_quit: bool,
     show_help: bool,
     selected_index: usize,
+    finder: FileFinder,
+    search: ProjectSearch,
+    text_viewer: Option<TextViewer>,
+    /// Text file open for editing, replacing the viewer
+    editor: Option<FileEditor>,
+    /// Binary file picked in the finder
+    hex_viewer: Option<HexViewer>,
+    runner: Option<ProcessRunner>,
+    /// Binary and arguments to launch once the runner's build succeeds
+    pending_launch: Option<(String, Vec<String>)>,
+    keymap: Keymap,
+    /// Shared settings; the keymap follows changes through `config_changes`
+    config: ConfigHandle,
+    config_changes: ConfigWatcher,
+    /// Signals when a long build or command finishes in the background
+    alerts: Alerter,
+    /// Terminal events with macro recording (Alt+Q), replay (Alt+@) and F10 screenshots
+    events: EventDispatcher,
+    /// Links opened from the text viewer, until their launcher exits
+    launches: links::Launches,
+    toasts: ToastManager,
+    /// Opened files and copies, handed to the toasts and clipboard history
+    bus: MessageBus,
+    clipboard_store: ClipboardStore,
+    /// Shown in the controls bar when running over SSH or headless
+    session: Session,
 }
 
 impl MainMenuApp {
-    fn new() -> Self {
+    fn new(handle: ConfigHandle) -> Self {
         let items = vec![
             MenuItem {
                 name: "📥 Interactive Form".to_string(),
@@ -58,6 +142,66 @@ impl MainMenuApp {
                 binary_name: "charts_demo".to_string(),
                 status: "✅ Complete".to_string(),
             },
+            MenuItem {
+                name: "📅 Campaign Manager".to_string(),
+                description: "Campaign send sequences on a timeline".to_string(),
+                binary_name: "campaign_manager".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "🗓️ Journal Calendar".to_string(),
+                description: "Month view of journal activity".to_string(),
+                binary_name: "journal_calendar".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "🗂️ Kanban Board".to_string(),
+                description: "Todo/Doing/Done cards with drag and drop".to_string(),
+                binary_name: "kanban".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "📝 Todo List".to_string(),
+                description: "Tasks with due dates, priorities and filters".to_string(),
+                binary_name: "todo".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "⏱️ Timers".to_string(),
+                description: "Stopwatches and countdowns with laps".to_string(),
+                binary_name: "timers".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "📰 Feed Reader".to_string(),
+                description: "RSS/Atom feeds with unread counts".to_string(),
+                binary_name: "feed_reader".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "📈 Ticker".to_string(),
+                description: "Streaming prices with candlesticks and alerts".to_string(),
+                binary_name: "ticker".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "🍅 Pomodoro".to_string(),
+                description: "Focus and break cycles with a session chart".to_string(),
+                binary_name: "pomodoro".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "🎮 Fun: Games".to_string(),
+                description: "Snake and Tetris on a canvas with high scores".to_string(),
+                binary_name: "games".to_string(),
+                status: "✅ Complete".to_string(),
+            },
+            MenuItem {
+                name: "⚙️ Config Editor".to_string(),
+                description: "Settings form generated from the config schema".to_string(),
+                binary_name: "config_editor".to_string(),
+                status: "✅ Complete".to_string(),
+            },
             MenuItem {
                 name: "🔼 Interactive Table".to_string(),
                 description: "Row/column highlighting with Shift+Arrow".to_string(),
@@ -98,14 +242,34 @@ impl MainMenuApp {
 
         let mut list_state = ListState::default();
         list_state.select(Some(0));
+        let config = handle.get();
 
-        Self {
+        let mut app = Self {
             items,
             list_state,
             should_quit: false,
             show_help: false,
             selected_index: 0,
-        }
+            finder: FileFinder::in_current_dir(),
+            search: ProjectSearch::in_current_dir(),
+            text_viewer: None,
+            editor: None,
+            hex_viewer: None,
+            runner: None,
+            pending_launch: None,
+            keymap: Keymap::new(config.keymap),
+            config_changes: handle.watch(),
+            config: handle,
+            alerts: Alerter::new(config.alerts),
+            events: EventDispatcher::new().with_keymap(config.keymap),
+            launches: links::Launches::new(),
+            toasts: ToastManager::new(),
+            bus: MessageBus::new(),
+            clipboard_store: ClipboardStore::new(config.clipboard),
+            session: console::session(),
+        };
+        app.refresh_recent_files();
+        app
     }
 
     fn next(&mut self) {
@@ -138,31 +302,150 @@ impl MainMenuApp {
         self.selected_index = i;
     }
 
-    fn launch_selected(&self) -> Result<(), Box<dyn Error>> {
+    fn select(&mut self, index: usize) {
+        self.list_state.select(Some(index));
+        self.selected_index = index;
+    }
+
+    /// Switch to the next keymap profile and save it for every example
+    fn cycle_keymap(&mut self) {
+        let keymap = self.keymap.profile.next();
+        if let Err(e) = self.config.update_and_save(|config| config.keymap = keymap) {
+            eprintln!("Failed to save config: {}", e);
+        }
+    }
+
+    /// Pick up settings changed elsewhere in the app
+    fn apply_config_changes(&mut self) {
+        if let Some(config) = self.config_changes.changed() {
+            self.keymap = Keymap::new(config.keymap);
+            self.events.keymap = config.keymap;
+        }
+    }
+
+    /// Pick up files the launched examples opened and drop entries whose
+    /// file has gone
+    fn refresh_recent_files(&mut self) {
+        let mut recent = match self.config.load_saved() {
+            Ok(config) => config.recent_files,
+            Err(_) => self.config.read(|config| config.recent_files.clone()),
+        };
+        let pruned = recent.prune();
+        self.config.update(|config| config.recent_files = recent);
+        if pruned > 0 {
+            if let Err(e) = self.config.save() {
+                self.toasts.report(&e);
+            }
+        }
+    }
+
+    /// Put `path` at the top of the recent files
+    fn remember_file(&mut self, path: &Path) {
+        if let Err(e) = self.config.update_and_save(|config| {
+            config.recent_files.push(path);
+        }) {
+            self.toasts.report(&e);
+        }
+    }
+
+    /// Open the recent file at `index` the way it was first opened
+    fn reopen_recent(&mut self, index: usize) {
+        let Some(file) = self.config.read(|config| config.recent_files.get(index).cloned()) else {
+            return;
+        };
+        if !file.path.is_file() {
+            self.toasts.error(&format!("{} no longer exists", file.path.display()));
+            self.refresh_recent_files();
+            return;
+        }
+        let path_arg = file.path.to_string_lossy().to_string();
+        match file.kind {
+            RecentKind::Image => self.build_and_launch("image_viewer", vec![path_arg]),
+            RecentKind::Video => self.build_and_launch("video_player", vec![path_arg]),
+            RecentKind::Csv => self.build_and_launch("interactive_table", vec![path_arg]),
+            RecentKind::Journal => match TextViewer::open(&file.path) {
+                Ok(viewer) => self.text_viewer = Some(viewer.pager()),
+                Err(e) => {
+                    self.toasts.report(&DemoError::from(e));
+                    return;
+                }
+            },
+        }
+        self.remember_file(&file.path);
+    }
+
+    fn launch_selected(&mut self) {
         if let Some(item) = self.items.get(self.selected_index) {
-            // Restore terminal before launching
-            disable_raw_mode()?;
-            execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
-            
-            // Launch the selected example
-            let mut cmd = Command::new("cargo");
-            cmd.args(&["run", "--bin", &item.binary_name]);
-            
-            let status = cmd.status()?;
-            
-            // Re-setup terminal
-            enable_raw_mode()?;
-            execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
-            
-            if !status.success() {
-                eprintln!("Failed to run example: {}", item.binary_name);
+            let binary_name = item.binary_name.clone();
+            self.build_and_launch(&binary_name, Vec::new());
+        }
+    }
+
+    /// Build an example in the runner pane, launching it once the build succeeds
+    fn build_and_launch(&mut self, binary_name: &str, args: Vec<String>) {
+        let mut runner = ProcessRunner::cargo_build(binary_name);
+        runner.start();
+        self.runner = Some(runner);
+        self.pending_launch = Some((binary_name.to_string(), args));
+    }
+
+    /// Run the configured command in the runner pane
+    fn run_configured_command(&mut self) {
+        let command_line = self.config.read(|config| config.run_command.clone());
+        if let Some(mut runner) = ProcessRunner::from_command_line(&command_line, &command_line) {
+            runner.start();
+            self.runner = Some(runner);
+            self.pending_launch = None;
+        }
+    }
+
+    /// Open a file picked in the finder with the matching viewer; text opens
+    /// in the pager and binary files in the hex viewer
+    fn open_file(&mut self, path: &Path, kind: FileKind) -> Result<(), DemoError> {
+        self.remember_file(path);
+        let path_arg = path.to_string_lossy().to_string();
+        match kind {
+            FileKind::Image => self.build_and_launch("image_viewer", vec![path_arg]),
+            FileKind::Video => self.build_and_launch("video_player", vec![path_arg]),
+            FileKind::Other | FileKind::Audio if looks_binary(&read_sample(path, SNIFF_LEN)?) => {
+                self.hex_viewer = Some(HexViewer::open(path)?);
+                self.bus.publish(Message::FileOpened(path.to_path_buf()));
+            }
+            FileKind::Text | FileKind::Other | FileKind::Audio => {
+                self.text_viewer = Some(TextViewer::open(path)?.pager());
+                self.bus.publish(Message::FileOpened(path.to_path_buf()));
             }
         }
         Ok(())
     }
+
+    /// Open a search match in the text viewer with its line highlighted
+    fn open_match(&mut self, path: &Path, line: usize) -> Result<(), DemoError> {
+        let mut viewer = TextViewer::open(path)?;
+        viewer.jump_to(line);
+        self.text_viewer = Some(viewer);
+        Ok(())
+    }
+
+    /// Take the pending launch once its build has finished successfully
+    fn ready_to_launch(&mut self) -> Option<(String, Vec<String>)> {
+        let runner = self.runner.as_mut()?;
+        let was_running = runner.is_running();
+        runner.poll();
+        // Alert when a command finishes or a build fails; a successful build launches right away
+        if was_running && !runner.is_running() && !(runner.succeeded() && self.pending_launch.is_some()) {
+            self.alerts.alert(&runner.title, &runner.status_text());
+        }
+        if !runner.succeeded() {
+            return None;
+        }
+        let launch = self.pending_launch.take()?;
+        self.runner = None;
+        Some(launch)
+    }
 }
 
-fn ui(f: &mut Frame, app: &MainMenuApp) {
+fn ui(f: &mut Frame, app: &mut MainMenuApp) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -208,6 +491,30 @@ fn ui(f: &mut Frame, app: &MainMenuApp) {
 
     f.render_stateful_widget(list, main_chunks[0], &mut app.list_state.clone());
 
+    // Details with the recent files below
+    let recent = app.config.read(|config| config.recent_files.clone());
+    let side = Layout::vertical([Constraint::Min(6), Constraint::Length(recent.files.len().max(1) as u16 + 2)])
+        .split(main_chunks[1]);
+    let name_width = side[1].width.saturating_sub(7) as usize;
+    let recent_lines: Vec<Line> = if recent.is_empty() {
+        vec![Line::styled("Files you open are listed here", Style::default().fg(Color::DarkGray))]
+    } else {
+        recent
+            .files
+            .iter()
+            .enumerate()
+            .map(|(i, file)| {
+                Line::from(vec![
+                    Span::styled(format!("{} ", i + 1), Style::default().fg(Color::Yellow)),
+                    Span::raw(format!("{} ", file.kind.icon())),
+                    Span::raw(elide_path(&file.path.to_string_lossy(), name_width).into_owned()),
+                ])
+            })
+            .collect()
+    };
+    let recent_panel = Paragraph::new(recent_lines).block(Block::default().borders(Borders::ALL).title("Recent (1-9)"));
+    f.render_widget(recent_panel, side[1]);
+
     // Description panel
     if let Some(selected_item) = app.items.get(app.selected_index) {
         let description_text = format!(
@@ -218,26 +525,35 @@ fn ui(f: &mut Frame, app: &MainMenuApp) {
             selected_item.status
         );
 
-        let description = Paragraph::new(description_text)
+        let description_lines = Reflow::new(side[0].width.saturating_sub(2)).hyphenate(true).lines(&description_text, Style::default());
+        let description = Paragraph::new(description_lines)
             .block(Block::default().borders(Borders::ALL).title("Details"))
-            .style(Style::default().fg(Color::White))
-            .wrap(ratatui::widgets::Wrap { trim: true });
-        f.render_widget(description, main_chunks[1]);
+            .style(Style::default().fg(Color::White));
+        f.render_widget(description, side[0]);
     }
 
     // Instructions
     let instructions = if app.show_help {
-        "↑↓: Navigate | Enter: Launch | h: Hide help | q: Quit\n\
+        "↑↓: Navigate | Enter: Launch | h/?: Hide help | q: Quit\n\
          All examples demonstrate advanced Ratatui features:\n\
          • Interactive forms with validation\n\
          • Complex table navigation\n\
          • Charts and data visualization"
+            .to_string()
     } else {
-        "↑↓: Navigate | Enter: Launch example | h: Help | q: Quit"
+        format!(
+            "↑↓: Navigate | Enter: Launch example | 1-9: Reopen recent | Ctrl+T: Find file | Ctrl+G: Search in files | {}: Search screen | x: Run command | F2: Keymap ({}) | h/?: Help | q: Quit",
+            ScreenSearch::shortcut(app.keymap.profile).1,
+            app.keymap.profile.name()
+        )
     };
 
+    let mut controls = Block::default().borders(Borders::ALL).title(app.events.status_title("Controls"));
+    if let Some(indicator) = app.session.indicator() {
+        controls = controls.title_bottom(Line::from(indicator).alignment(Alignment::Right));
+    }
     let help = Paragraph::new(instructions)
-        .block(Block::default().borders(Borders::ALL).title("Controls"))
+        .block(controls)
         .style(Style::default().fg(Color::Yellow))
         .wrap(ratatui::widgets::Wrap { trim: true });
     f.render_widget(help, chunks[2]);
@@ -257,12 +573,30 @@ fn ui(f: &mut Frame, app: &MainMenuApp) {
             All components are modular and reusable!\n\n\
             Press 'h' to close this help.";
         
-        let help_popup = Paragraph::new(help_text)
+        let help_lines = Reflow::new(area.width.saturating_sub(2)).mode(WrapMode::Cjk).hyphenate(true).lines(help_text, Style::default());
+        let help_popup = Paragraph::new(help_lines)
             .block(Block::default().borders(Borders::ALL).title(" Help "))
-            .style(Style::default().fg(Color::White).bg(Color::Black))
-            .wrap(ratatui::widgets::Wrap { trim: true });
+            .style(Style::default().fg(Color::White).bg(Color::Black));
         f.render_widget(help_popup, area);
     }
+
+    let area = f.area();
+    if let Some(runner) = app.runner.as_mut() {
+        runner.render(f, centered_rect(90, 70, area));
+    }
+    if let Some(viewer) = app.text_viewer.as_mut() {
+        viewer.render(f, centered_rect(90, 90, area));
+    }
+    if let Some(editor) = app.editor.as_mut() {
+        editor.render(f, centered_rect(90, 90, area));
+    }
+    if let Some(hex) = app.hex_viewer.as_mut() {
+        hex.render(f, centered_rect(90, 90, area));
+    }
+    app.finder.render(f, area);
+    app.search.render(f, area);
+    app.toasts.render(f, area);
+    app.alerts.render(f);
 }
 
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -285,31 +619,154 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app
-    let mut app = MainMenuApp::new();
+    let mut app = MainMenuApp::new(args.config());
+    if args.demo {
+        app.events.play(DEMO);
+    }
     let tick_rate = Duration::from_millis(100);
     let mut last_tick = Instant::now();
 
     loop {
-        terminal.draw(|f| ui(f, &app))?;
+        app.finder.poll();
+        app.search.poll();
+        for failure in app.launches.failures() {
+            app.toasts.error(&failure);
+        }
+        app.bus.dispatch(&mut [&mut app.toasts, &mut app.clipboard_store]);
+        app.apply_config_changes();
+        app.toasts.prune();
+        if let Some(Err(e)) = app.editor.as_mut().map(FileEditor::tick) {
+            app.toasts.report(&e);
+        }
+        if let Some((binary_name, launch_args)) = app.ready_to_launch() {
+            let launch_args: Vec<&str> = launch_args.iter().map(String::as_str).collect();
+            run_built_binary(&binary_name, &launch_args)?;
+            app.refresh_recent_files();
+            if args.no_mouse {
+                execute!(terminal.backend_mut(), DisableMouseCapture)?;
+            }
+            terminal.clear()?;
+        }
+        app.alerts.ring(terminal.backend_mut())?;
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
 
         let timeout = tick_rate.saturating_sub(last_tick.elapsed());
-        if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    match key.code {
+        if let Some(event) = app.events.next(timeout)? {
+            if let (Event::Mouse(mouse), Some(viewer)) = (&event, app.text_viewer.as_mut()) {
+                if let Some(url) = viewer.handle_mouse(*mouse) {
+                    if let Err(e) = app.launches.open_url(&url) {
+                        app.toasts.error(&e);
+                    }
+                }
+            }
+            if let Event::Key(key) = event {
+                if key.kind == KeyEventKind::Press && app.finder.visible {
+                    if let FinderAction::Open(path, kind) = app.finder.handle_key(key) {
+                        if let Err(e) = app.open_file(&path, kind) {
+                            eprintln!("Error opening {}: {} ({})", path.display(), e, e.hint());
+                        }
+                    }
+                } else if key.kind == KeyEventKind::Press && app.search.visible {
+                    if let SearchAction::Open(path, line) = app.search.handle_key(key) {
+                        if let Err(e) = app.open_match(&path, line) {
+                            eprintln!("Error opening {}: {} ({})", path.display(), e, e.hint());
+                        }
+                    }
+                } else if key.kind == KeyEventKind::Press && FileFinder::is_shortcut(&key) {
+                    app.finder.open();
+                } else if key.kind == KeyEventKind::Press && ProjectSearch::is_shortcut(&key) {
+                    app.search.open();
+                } else if key.kind == KeyEventKind::Press && app.runner.is_some() {
+                    if key.code == KeyCode::Esc {
+                        app.runner = None;
+                        app.pending_launch = None;
+                    } else if let Some(runner) = app.runner.as_mut() {
+                        runner.handle_key(key.code);
+                    }
+                } else if key.kind == KeyEventKind::Press && app.editor.is_some() {
+                    if let Some(editor) = app.editor.as_mut() {
+                        match editor.handle_key(key) {
+                            Ok(true) => {}
+                            Ok(false) if key.code == KeyCode::Esc => {
+                                if let Err(e) = editor.close() {
+                                    app.toasts.report(&e);
+                                }
+                                app.editor = None;
+                            }
+                            Ok(false) => {}
+                            Err(e) => app.toasts.report(&e),
+                        }
+                    }
+                } else if key.kind == KeyEventKind::Press && app.hex_viewer.is_some() {
+                    let used = app.hex_viewer.as_mut().is_some_and(|hex| hex.handle_key(key));
+                    if !used && matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+                        app.hex_viewer = None;
+                    }
+                } else if key.kind == KeyEventKind::Press && app.text_viewer.is_some() {
+                    let searching = app.text_viewer.as_ref().is_some_and(|viewer| viewer.find.visible);
+                    if searching {
+                        if let Some(viewer) = app.text_viewer.as_mut() {
+                            viewer.handle_pager_key(key);
+                        }
+                    } else if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+                        app.text_viewer = None;
+                    } else if key.code == KeyCode::Char('e') {
+                        if let Some(viewer) = app.text_viewer.take() {
+                            match FileEditor::open(&viewer.path) {
+                                Ok(editor) => app.editor = Some(editor),
+                                Err(e) => {
+                                    app.toasts.report(&e);
+                                    app.text_viewer = Some(viewer);
+                                }
+                            }
+                        }
+                    } else if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                        if let Some(text) = app.text_viewer.as_ref().and_then(TextViewer::selected_text) {
+                            match clipboard::copy_text(&text) {
+                                Ok(()) => app.bus.publish(Message::Copied(text)),
+                                Err(e) => app.toasts.report(&e),
+                            }
+                        }
+                    } else if let Some(viewer) = app.text_viewer.as_mut() {
+                        if viewer.is_pager() {
+                            viewer.handle_pager_key(key);
+                        } else {
+                            viewer.handle_key(key.code);
+                        }
+                    }
+                } else if key.kind == KeyEventKind::Press {
+                    // Profile bindings (e.g. vim j/k, gg/G) resolve to the default keys
+                    let code = match app.keymap.resolve(key) {
+                        KeyResult::Action(action) => action.key().map(|key| key.code),
+                        KeyResult::Pending => None,
+                        KeyResult::Unbound(key) => Some(key.code),
+                    };
+                    match code.unwrap_or(KeyCode::Null) {
                         KeyCode::Char('q') => {
                             app.should_quit = true;
                         }
-                        KeyCode::Char('h') => {
+                        KeyCode::Char('h') | KeyCode::Char('?') => {
                             app.show_help = !app.show_help;
                         }
                         KeyCode::Down => {
@@ -318,10 +775,17 @@ fn main() -> Result<(), Box<dyn Error>> {
                         KeyCode::Up => {
                             app.previous();
                         }
+                        KeyCode::Home => app.select(0),
+                        KeyCode::End => app.select(app.items.len() - 1),
+                        KeyCode::F(2) => app.cycle_keymap(),
                         KeyCode::Enter => {
-                            if let Err(e) = app.launch_selected() {
-                                eprintln!("Error launching example: {}", e);
-                            }
+                            app.launch_selected();
+                        }
+                        KeyCode::Char('x') => {
+                            app.run_configured_command();
+                        }
+                        KeyCode::Char(digit @ '1'..='9') => {
+                            app.reopen_recent(digit as usize - '1' as usize);
                         }
                         _ => {}
                     }
@@ -0,0 +1,270 @@
+//! Pomodoro example
+//!
+//! Focus sessions and breaks counted down in large segment digits. Phase
+//! changes raise an alert and a toast, the last week of focus
+//! time is charted from the session history, and the phase lengths are
+//! edited in a settings form ('s') and saved to the config file.
+
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{BarChart, Block, Borders, Paragraph},
+    Frame, Terminal,
+};
+use std::{
+    io,
+    time::Duration,
+};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::alert::Alerter;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::form::{FormAction, FormDialog, FormField, Validator};
+use tui_image_viewer::widgets::pomodoro::{Phase, Pomodoro, PomodoroConfig};
+use tui_image_viewer::widgets::segment::SegmentDisplay;
+use tui_image_viewer::widgets::toast::ToastManager;
+
+/// Days shown in the focus chart
+const CHART_DAYS: u64 = 7;
+
+struct PomodoroApp {
+    pomodoro: Pomodoro,
+    config: ConfigHandle,
+    settings: Option<FormDialog>,
+    toasts: ToastManager,
+    alerts: Alerter,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    should_quit: bool,
+}
+
+impl PomodoroApp {
+    fn new(config: ConfigHandle) -> Self {
+        let (settings, alerts) = config.read(|config| (config.pomodoro.clone(), config.alerts.clone()));
+        Self {
+            pomodoro: Pomodoro::new(settings),
+            alerts: Alerter::new(alerts),
+            config,
+            settings: None,
+            toasts: ToastManager::persistent(),
+            events: EventDispatcher::new(),
+            should_quit: false,
+        }
+    }
+
+    fn announce(&mut self, phase: Phase) {
+        let message = match phase {
+            Phase::Work => "🍅 Break over, time to focus".to_string(),
+            Phase::ShortBreak => "☕ Focus session done, take a short break".to_string(),
+            Phase::LongBreak => "🌴 Cycle complete, take a long break".to_string(),
+        };
+        self.alerts.alert(phase.label(), &message);
+        self.toasts.success(&message);
+    }
+
+    fn tick(&mut self) {
+        if let Some(phase) = self.pomodoro.tick() {
+            self.announce(phase);
+        }
+        self.toasts.prune();
+    }
+
+    fn open_settings(&mut self) {
+        let config = &self.pomodoro.config;
+        let minutes = |label: &str, value: u64| {
+            FormField::new(label, &value.to_string())
+                .required()
+                .validator(Validator::Integer)
+                .validator(Validator::Min(1.0))
+                .validator(Validator::Max(180.0))
+        };
+        self.settings = Some(FormDialog::new(
+            "Pomodoro settings",
+            vec![
+                minutes("Focus minutes", config.work_minutes),
+                minutes("Short break minutes", config.short_break_minutes),
+                minutes("Long break minutes", config.long_break_minutes),
+                minutes("Sessions before long break", config.sessions_before_long_break as u64),
+                FormField::select("Auto start next phase", &["No", "Yes"], if config.auto_start { "Yes" } else { "No" }),
+            ],
+        ));
+    }
+
+    fn apply_settings(&mut self, form: &FormDialog) {
+        let number = |label: &str| form.value(label).and_then(|value| value.trim().parse::<u64>().ok());
+        let defaults = PomodoroConfig::default();
+        let settings = PomodoroConfig {
+            work_minutes: number("Focus minutes").unwrap_or(defaults.work_minutes),
+            short_break_minutes: number("Short break minutes").unwrap_or(defaults.short_break_minutes),
+            long_break_minutes: number("Long break minutes").unwrap_or(defaults.long_break_minutes),
+            sessions_before_long_break: number("Sessions before long break")
+                .map_or(defaults.sessions_before_long_break, |sessions| sessions as u32),
+            auto_start: form.value("Auto start next phase") == Some("Yes"),
+        };
+        self.pomodoro.set_config(settings.clone());
+        match self.config.update_and_save(|config| config.pomodoro = settings) {
+            Ok(()) => self.toasts.info("Settings saved"),
+            Err(e) => self.toasts.error(&format!("Failed to save settings: {}", e)),
+        }
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if let Some(form) = self.settings.as_mut() {
+            match form.handle_key_event(key) {
+                FormAction::Submit => {
+                    if let Some(form) = self.settings.take() {
+                        self.apply_settings(&form);
+                    }
+                }
+                FormAction::Cancel => self.settings = None,
+                FormAction::None => {}
+            }
+            return;
+        }
+        if self.toasts.center.handle_key(key.code) {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char(' ') => self.pomodoro.timer.toggle(),
+            KeyCode::Char('n') => {
+                let phase = self.pomodoro.skip();
+                self.toasts.info(&format!("Skipped to {}", phase.label().to_lowercase()));
+            }
+            KeyCode::Char('r') => self.pomodoro.reset(),
+            KeyCode::Char('s') => self.open_settings(),
+            KeyCode::Char('N') => self.toasts.center.toggle(),
+            _ => {}
+        }
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut PomodoroApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(9),
+            Constraint::Length(12),
+            Constraint::Length(3),
+        ])
+        .split(f.area());
+    let pomodoro = &app.pomodoro;
+    let phase = pomodoro.phase;
+    let color = phase.color();
+
+    // Phase, progress through the cycle and running state
+    let cycle = pomodoro.config.sessions_before_long_break.max(1);
+    let done = cycle - pomodoro.sessions_until_long_break();
+    let tomatoes: String = (0..cycle).map(|i| if i < done { "🍅" } else { "· " }).collect();
+    let state = if pomodoro.timer.is_running() { "running" } else { "paused" };
+    let header = Paragraph::new(Line::from(vec![
+        Span::styled(phase.label(), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        Span::raw(format!("  {}  ", tomatoes)),
+        Span::styled(state, Style::default().fg(Color::DarkGray)),
+    ]))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL).title("Pomodoro"));
+    f.render_widget(header, chunks[0]);
+
+    let remaining = pomodoro.timer.remaining().unwrap_or_default().as_secs();
+    let countdown = SegmentDisplay::new(&format!("{:02}:{:02}", remaining / 60, remaining % 60))
+        .style(Style::default().fg(color))
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(color)));
+    f.render_widget(countdown, chunks[1]);
+
+    // Focus minutes per day from the session history
+    let today = chrono::Local::now().date_naive();
+    let days: Vec<(String, u64)> = pomodoro
+        .focus_minutes(today, CHART_DAYS)
+        .into_iter()
+        .map(|(day, minutes)| (day.format("%a").to_string(), minutes))
+        .collect();
+    let bars: Vec<(&str, u64)> = days.iter().map(|(label, minutes)| (label.as_str(), *minutes)).collect();
+    let chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title("Focus minutes, last 7 days"))
+        .data(&bars)
+        .bar_width(5)
+        .bar_gap(2)
+        .bar_style(Style::default().fg(Phase::Work.color()))
+        .value_style(Style::default().fg(Color::Black).bg(Phase::Work.color()));
+    f.render_widget(chart, chunks[2]);
+
+    let status = Paragraph::new("Space: start/pause | n: skip phase | r: reset phase | s: settings | N: notifications | q: quit")
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "{} | 🔔 {}",
+            app.events.status_title("Controls"),
+            app.toasts.center.unread_count()
+        )))
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(status, chunks[3]);
+
+    let area = f.area();
+    if let Some(form) = &app.settings {
+        form.render(f, area);
+    }
+    app.toasts.render(f, area);
+    app.toasts.center.render(f, chunks[1]);
+    app.alerts.render(f);
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = PomodoroApp::new(args.config());
+
+    loop {
+        app.tick();
+        app.alerts.ring(terminal.backend_mut())?;
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        if let Some(Event::Key(key)) = app.events.next(Duration::from_millis(200))? {
+            if key.kind == KeyEventKind::Press {
+                app.on_key(key);
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
@@ -0,0 +1,249 @@
+//! Stress example
+//!
+//! Synthetic load for measuring performance by hand: a table with a large
+//! number of rows whose cells change and whose selection moves many times
+//! per tick, next to a log that streams the changes. The header shows how
+//! long each draw takes (last, average, p99 and worst of the recent
+//! frames), so a regression shows up as soon as the screen is opened with
+//! the same `--rows` and `--rate`. F12 adds the profiler overlay.
+
+use clap::Parser;
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame, Terminal,
+};
+use std::collections::VecDeque;
+use std::io;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use tui_image_viewer::app::cli::{self, CommonArgs};
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::table::InteractiveTable;
+
+/// Draw times kept for the statistics
+const FRAME_WINDOW: usize = 240;
+
+/// Log lines kept
+const LOG_SIZE: usize = 200;
+
+const CITIES: [&str; 5] = ["London", "Tokyo", "Paris", "Lagos", "Lima"];
+
+struct StressApp {
+    table: InteractiveTable,
+    /// Synthetic updates applied per tick
+    rate: usize,
+    paused: bool,
+    /// Counter driving which rows change, so runs are repeatable
+    step: usize,
+    log: VecDeque<String>,
+    draw_times: VecDeque<Duration>,
+    events: EventDispatcher,
+    should_quit: bool,
+}
+
+impl StressApp {
+    fn new(rows: usize, rate: usize) -> Self {
+        let table = InteractiveTable::builder()
+            .headers(["Id", "Name", "City", "Score", "Status"])
+            .rows((0..rows).map(|i| {
+                [
+                    i.to_string(),
+                    format!("User {}", i),
+                    CITIES[i % CITIES.len()].to_string(),
+                    (i * 37 % 100).to_string(),
+                    "Idle".to_string(),
+                ]
+            }))
+            .build();
+        Self {
+            table,
+            rate,
+            paused: false,
+            step: 0,
+            log: VecDeque::new(),
+            draw_times: VecDeque::new(),
+            events: EventDispatcher::new(),
+            should_quit: false,
+        }
+    }
+
+    /// Change `rate` cells and move the selection as often
+    fn tick(&mut self) {
+        if self.paused || self.table.data.rows.is_empty() {
+            return;
+        }
+        let rows = self.table.data.rows.len();
+        for _ in 0..self.rate {
+            self.step = self.step.wrapping_add(1);
+            let row = self.step.wrapping_mul(7919) % rows;
+            let score = (self.step * 31 % 100).to_string();
+            self.table.data.rows[row][3] = score.clone();
+            self.table.data.rows[row][4] = if self.step.is_multiple_of(2) { "Busy" } else { "Idle" }.to_string();
+            if self.step.is_multiple_of(3) {
+                self.table.next_row();
+            }
+            self.log.push_front(format!("#{} row {} score {}", self.step, row, score));
+        }
+        self.log.truncate(LOG_SIZE);
+    }
+
+    fn record_draw(&mut self, took: Duration) {
+        self.draw_times.push_back(took);
+        if self.draw_times.len() > FRAME_WINDOW {
+            self.draw_times.pop_front();
+        }
+    }
+
+    /// Last, average, p99 and worst draw time of the window
+    fn draw_stats(&self) -> Option<[Duration; 4]> {
+        let last = *self.draw_times.back()?;
+        let mut sorted: Vec<Duration> = self.draw_times.iter().copied().collect();
+        sorted.sort();
+        let average = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+        let p99 = sorted[(sorted.len() * 99 / 100).min(sorted.len() - 1)];
+        Some([last, average, p99, sorted[sorted.len() - 1]])
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::Char('+') => self.rate = (self.rate * 2).max(1),
+            KeyCode::Char('-') => self.rate /= 2,
+            KeyCode::Char('c') => self.draw_times.clear(),
+            _ => {
+                self.table.handle_key(key.code);
+            }
+        }
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut StressApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(5)])
+        .split(f.area());
+
+    let stats = match app.draw_stats() {
+        Some([last, average, p99, worst]) => format!(
+            "draw {:.2?} | avg {:.2?} | p99 {:.2?} | worst {:.2?}",
+            last, average, p99, worst
+        ),
+        None => "draw -".to_string(),
+    };
+    let header = format!(
+        "{} rows | {} updates/tick{} | {}",
+        app.table.data.rows.len(),
+        app.rate,
+        if app.paused { " (paused)" } else { "" },
+        stats
+    );
+    let title = app.events.status_title("Stress: Space pause, +/- rate, c reset stats, F12 profiler, q quit");
+    let header = Paragraph::new(header)
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(header, chunks[0]);
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(chunks[1]);
+    app.table.render(f, body[0]);
+
+    let log: Vec<ListItem> = app.log.iter().take(body[1].height as usize).map(|line| ListItem::new(line.as_str())).collect();
+    f.render_widget(List::new(log).block(Block::default().borders(Borders::ALL).title("Updates")), body[1]);
+}
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Synthetic load for measuring draw times")]
+struct Cli {
+    /// Rows in the table
+    #[arg(long, default_value_t = 100_000)]
+    rows: usize,
+    /// Cell updates per tick
+    #[arg(long, default_value_t = 50)]
+    rate: usize,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse_with(|args: &Cli| &args.common);
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.common.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = StressApp::new(args.rows, args.rate);
+    let tick_rate = Duration::from_millis(16);
+    let mut last_tick = Instant::now();
+
+    loop {
+        let started = Instant::now();
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+        app.record_draw(started.elapsed());
+
+        let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+        if let Some(Event::Key(key)) = app.events.next(timeout)? {
+            if key.kind == KeyEventKind::Press {
+                app.on_key(key);
+            }
+        }
+
+        if last_tick.elapsed() >= tick_rate {
+            app.tick();
+            last_tick = Instant::now();
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = StressApp::new(1_000, 50);
+        snapshot::assert_screen("stress", |f| ui(f, &mut app));
+    }
+}
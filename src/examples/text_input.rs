@@ -2,39 +2,49 @@
 //!
 //! Demonstrates text input fields with link detection and interaction.
 
-use arboard::Clipboard;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Position, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame, Terminal,
 };
-use regex::Regex;
 use std::io;
-use std::process::Command;
-
-#[derive(Clone, Debug)]
-struct Link {
-    text: String,
-    url: String,
-    start: usize,
-    end: usize,
-}
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::char_count;
+use tui_image_viewer::widgets::clipboard;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::emoji::ShortcodeCompleter;
+use tui_image_viewer::widgets::history::{HistoryAction, InputHistory};
+use tui_image_viewer::widgets::keymap::{Action, KeyResult, Keymap, KeymapProfile};
+use tui_image_viewer::widgets::line_edit::handle_edit_key;
+use tui_image_viewer::widgets::links;
+use tui_image_viewer::widgets::rich_text::{detect_links, link_style, Link, LinkRegions};
+use tui_image_viewer::widgets::selection::{byte_index, column_of_char};
+use tui_image_viewer::widgets::symbols::{SymbolAction, SymbolPicker};
+use tui_image_viewer::widgets::text_area::TextArea;
 
-#[derive(Clone)]
 struct TextField {
     content: String,
     cursor_position: usize,
     label: String,
     is_focused: bool,
     links: Vec<Link>,
+    /// Opt-in ↑↓ recall and Ctrl+R search of submitted values
+    history: Option<InputHistory>,
+    emoji: ShortcodeCompleter,
+    /// Shows the character count footer, red past this many characters
+    max_len: Option<usize>,
 }
 
 impl TextField {
@@ -45,79 +55,77 @@ impl TextField {
             label: label.to_string(),
             is_focused: false,
             links: Vec::new(),
+            history: None,
+            emoji: ShortcodeCompleter::new(),
+            max_len: None,
         }
     }
 
-    fn move_cursor_left(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
-        }
+    fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
     }
 
-    fn move_cursor_right(&mut self) {
-        if self.cursor_position < self.content.len() {
-            self.cursor_position += 1;
-        }
+    /// Remember submitted values in the shared history file `name`
+    fn with_history(mut self, name: &str) -> Self {
+        self.history = Some(InputHistory::persistent(name));
+        self
     }
 
-    fn move_cursor_to_start(&mut self) {
-        self.cursor_position = 0;
+    fn set_content(&mut self, text: String) {
+        self.cursor_position = text.chars().count();
+        self.content = text;
+        self.detect_links();
     }
 
-    fn move_cursor_to_end(&mut self) {
-        self.cursor_position = self.content.len();
+    /// Insert `text` after the character under the cursor, vim `p` style
+    fn paste_after(&mut self, text: &str) {
+        self.cursor_position = (self.cursor_position + 1).min(self.content.chars().count());
+        for c in text.chars() {
+            self.insert_char(c);
+        }
     }
 
-    fn insert_char(&mut self, c: char) {
-        self.content.insert(self.cursor_position, c);
-        self.cursor_position += 1;
-        self.detect_links();
+    /// ↑/↓ through the field's history; returns whether the text changed
+    fn recall(&mut self, older: bool) -> bool {
+        let Some(history) = &mut self.history else {
+            return false;
+        };
+        let recalled = if older { history.older(&self.content) } else { history.newer() };
+        match recalled {
+            Some(text) => {
+                self.set_content(text);
+                true
+            }
+            None => false,
+        }
     }
 
-    fn delete_char(&mut self) {
-        if self.cursor_position < self.content.len() {
-            self.content.remove(self.cursor_position);
+    /// Apply an editing key (including readline word operations)
+    fn edit(&mut self, key: event::KeyEvent) -> bool {
+        let edited = handle_edit_key(&mut self.content, &mut self.cursor_position, key);
+        if edited {
+            self.emoji.after_edit(&mut self.content, &mut self.cursor_position);
             self.detect_links();
         }
+        edited
     }
 
-    fn backspace(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
-            self.content.remove(self.cursor_position);
+    /// Keys for the `:shortcode:` suggestion popup while it is open
+    fn complete_emoji(&mut self, key: event::KeyEvent) -> bool {
+        let used = self.emoji.handle_key(&mut self.content, &mut self.cursor_position, key);
+        if used {
             self.detect_links();
         }
+        used
     }
 
-    fn detect_links(&mut self) {
-        self.links.clear();
-        
-        // URL regex pattern
-        let url_regex = Regex::new(r"https?://[^\s]+").unwrap();
-        
-        for mat in url_regex.find_iter(&self.content) {
-            self.links.push(Link {
-                text: mat.as_str().to_string(),
-                url: mat.as_str().to_string(),
-                start: mat.start(),
-                end: mat.end(),
-            });
-        }
-
-        // Email regex pattern
-        let email_regex = Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Z|a-z]{2,}\b").unwrap();
-        
-        for mat in email_regex.find_iter(&self.content) {
-            self.links.push(Link {
-                text: mat.as_str().to_string(),
-                url: format!("mailto:{}", mat.as_str()),
-                start: mat.start(),
-                end: mat.end(),
-            });
-        }
+    fn insert_char(&mut self, c: char) {
+        self.edit(event::KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+    }
 
-        // Sort links by start position
-        self.links.sort_by_key(|link| link.start);
+    fn detect_links(&mut self) {
+        self.links = detect_links(&self.content);
     }
 
     #[allow(dead_code)]    #[allow(dead_code)]
@@ -125,10 +133,13 @@ impl TextField {
         self.links.iter().find(|link| pos >= link.start && pos < link.end)
     }
 
-    fn render(&self, frame: &mut Frame, area: Rect) {
-        let block = Block::default()
+    fn render(&self, frame: &mut Frame, area: Rect, regions: &mut LinkRegions) {
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .title(self.label.clone());
+        if self.max_len.is_some() {
+            block = block.title_bottom(char_count::footer(&self.content, self.max_len));
+        }
 
         let block_style = if self.is_focused {
             Style::default().fg(Color::Cyan)
@@ -151,13 +162,8 @@ impl TextField {
             }
 
             // Add the link with special styling
-            spans.push(Span::styled(
-                &link.text,
-                Style::default()
-                    .fg(Color::Blue)
-                    .add_modifier(Modifier::UNDERLINED)
-                    .add_modifier(Modifier::BOLD),
-            ));
+            let hovered = regions.hovered.as_deref() == Some(link.url.as_str());
+            spans.push(Span::styled(&link.text, link_style(hovered).add_modifier(Modifier::BOLD)));
 
             last_end = link.end;
         }
@@ -220,22 +226,18 @@ impl TextField {
 
         frame.render_widget(block, area);
         frame.render_widget(text, inner_area);
+        regions.record(inner_area.x, inner_area.y, &self.content, &self.links, inner_area);
     }
 
-    fn paste_from_clipboard(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let mut clipboard = Clipboard::new()?;
-        if let Ok(text) = clipboard.get_text() {
-            for c in text.chars() {
-                self.insert_char(c);
-            }
+    fn paste_from_clipboard(&mut self) -> Result<(), DemoError> {
+        for c in clipboard::paste_text()?.chars() {
+            self.insert_char(c);
         }
         Ok(())
     }
 
-    fn copy_to_clipboard(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let mut clipboard = Clipboard::new()?;
-        clipboard.set_text(self.content.clone())?;
-        Ok(())
+    fn copy_to_clipboard(&self) -> Result<(), DemoError> {
+        clipboard::copy_text(&self.content)
     }
 }
 
@@ -245,15 +247,30 @@ struct InputApp {
     should_quit: bool,
     status_message: String,
     detected_links: Vec<String>,
+    /// Where links were drawn in the last frame, for click-to-open
+    link_regions: LinkRegions,
+    /// Multi-line notes, focused after the last single-line field
+    notes: TextArea,
+    notes_focused: bool,
+    /// Bindings from the configured profile for the single-line fields
+    keymap: Keymap,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    /// Text deleted or yanked with vim `dd`/`yy` or killed with emacs `C-k`
+    register: String,
+    /// Alt+U overlay inserting into whichever input is focused
+    symbols: SymbolPicker,
+    /// Opened links, until their launcher exits
+    launches: links::Launches,
 }
 
 impl InputApp {
-    fn new() -> Self {
+    fn new(config: &ConfigHandle) -> Self {
+        let keymap = config.read(|config| config.keymap);
         let mut fields = vec![
-            TextField::new("Username"),
-            TextField::new("Email"),
-            TextField::new("Website (try: https://example.com)"),
-            TextField::new("Notes (try adding URLs or emails)"),
+            TextField::new("Username").with_history("username").with_max_len(20),
+            TextField::new("Email").with_history("email"),
+            TextField::new("Website (try: https://example.com)").with_history("website"),
         ];
         fields[0].is_focused = true;
 
@@ -262,27 +279,51 @@ impl InputApp {
             active_field: 0,
             should_quit: false,
             status_message: String::from(
-                "Tab to switch fields, Ctrl+L to open links, Ctrl+C/V clipboard, q to quit"
+                "Tab to switch fields, ↑↓/Ctrl+R history (find/replace in notes), Ctrl+L or click to open links, Ctrl+C/V clipboard, Alt+U symbols, q to quit"
             ),
             detected_links: Vec::new(),
+            link_regions: LinkRegions::new(),
+            notes: TextArea::new("Notes (try adding URLs or emails, drag to select)"),
+            notes_focused: false,
+            keymap: Keymap::for_text(keymap),
+            events: EventDispatcher::new().with_keymap(keymap),
+            register: String::new(),
+            symbols: SymbolPicker::new(),
+            launches: links::Launches::new(),
         }
     }
 
-    fn next_field(&mut self) {
+    fn focus_field(&mut self, index: usize) {
         self.fields[self.active_field].is_focused = false;
-        self.active_field = (self.active_field + 1) % self.fields.len();
+        self.notes_focused = false;
+        self.active_field = index;
         self.fields[self.active_field].is_focused = true;
+    }
+
+    fn focus_notes(&mut self) {
+        self.fields[self.active_field].is_focused = false;
+        self.notes_focused = true;
+    }
+
+    fn next_field(&mut self) {
+        if self.notes_focused {
+            self.focus_field(0);
+        } else if self.active_field + 1 == self.fields.len() {
+            self.focus_notes();
+        } else {
+            self.focus_field(self.active_field + 1);
+        }
         self.update_detected_links();
     }
 
     fn previous_field(&mut self) {
-        self.fields[self.active_field].is_focused = false;
-        if self.active_field > 0 {
-            self.active_field -= 1;
+        if self.notes_focused {
+            self.focus_field(self.fields.len() - 1);
+        } else if self.active_field == 0 {
+            self.focus_notes();
         } else {
-            self.active_field = self.fields.len() - 1;
+            self.focus_field(self.active_field - 1);
         }
-        self.fields[self.active_field].is_focused = true;
         self.update_detected_links();
     }
 
@@ -293,27 +334,23 @@ impl InputApp {
                 self.detected_links.push(format!("{}: {}", field.label, link.url));
             }
         }
+        for link in detect_links(&self.notes.text()) {
+            self.detected_links.push(format!("Notes: {}", link.url));
+        }
     }
 
     fn open_links(&mut self) {
-        let field = &self.fields[self.active_field];
-        if field.links.is_empty() {
+        let found = if self.notes_focused {
+            detect_links(&self.notes.text())
+        } else {
+            self.fields[self.active_field].links.clone()
+        };
+        let Some(link) = found.first() else {
             self.status_message = "No links found in current field".to_string();
             return;
-        }
-
-        let link = &field.links[0]; // Open first link found
-        
-        #[cfg(target_os = "windows")]
-        let result = Command::new("cmd").args(&["/c", "start", &link.url]).spawn();
-        
-        #[cfg(target_os = "macos")]
-        let result = Command::new("open").arg(&link.url).spawn();
-        
-        #[cfg(target_os = "linux")]
-        let result = Command::new("xdg-open").arg(&link.url).spawn();
+        };
 
-        match result {
+        match self.launches.open_url(&link.url) {
             Ok(_) => self.status_message = format!("Opening: {}", link.url),
             Err(e) => self.status_message = format!("Failed to open link: {}", e),
         }
@@ -326,27 +363,161 @@ impl InputApp {
         
         // For demonstration, try to activate the field that was clicked
         if y >= 4 && y <= 6 && self.fields.len() > 0 {
-            self.fields[self.active_field].is_focused = false;
-            self.active_field = 0;
-            self.fields[self.active_field].is_focused = true;
+            self.focus_field(0);
         } else if y >= 7 && y <= 9 && self.fields.len() > 1 {
-            self.fields[self.active_field].is_focused = false;
-            self.active_field = 1;
-            self.fields[self.active_field].is_focused = true;
+            self.focus_field(1);
         }
         // Add more field mappings as needed
     }
 
     fn submit(&mut self) {
+        if let Some(field) = self.fields.iter().find(|field| char_count::exceeds(&field.content, field.max_len)) {
+            self.status_message = format!(
+                "{} is longer than {} characters",
+                field.label,
+                field.max_len.unwrap_or_default()
+            );
+            return;
+        }
         let mut values = Vec::new();
-        for field in &self.fields {
+        for field in &mut self.fields {
             values.push(format!("{}: {}", field.label, field.content));
+            if let Some(history) = &mut field.history {
+                history.push(&field.content);
+            }
         }
+        values.push(format!("Notes: {} lines", self.notes.lines.len()));
         self.status_message = format!("Submitted: {}", values.join(", "));
         self.update_detected_links();
     }
 
-    fn on_key(&mut self, key: event::KeyEvent) -> Result<(), Box<dyn std::error::Error>> {
+    fn on_notes_key(&mut self, key: event::KeyEvent) -> Result<(), DemoError> {
+        // The find/replace bar takes every key while it is open
+        if self.notes.find.visible {
+            if self.notes.handle_key(key) {
+                self.update_detected_links();
+            }
+            return Ok(());
+        }
+        match (key.code, key.modifiers) {
+            (KeyCode::Tab, _) => self.next_field(),
+            (KeyCode::BackTab, _) => self.previous_field(),
+            (KeyCode::Char('l'), KeyModifiers::CONTROL) => self.open_links(),
+            (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+                let text = self.notes.selected_text().unwrap_or_else(|| self.notes.text());
+                clipboard::copy_text(&text)?;
+                self.status_message = if self.notes.selection.is_empty() {
+                    "Copied notes to clipboard".to_string()
+                } else {
+                    "Copied selection to clipboard".to_string()
+                };
+            }
+            (KeyCode::Char('v'), KeyModifiers::CONTROL) => {
+                let text = clipboard::paste_text()?;
+                self.notes.insert_str(&text);
+                self.status_message = "Pasted from clipboard".to_string();
+                self.update_detected_links();
+            }
+            _ => {
+                if self.notes.handle_key(key) {
+                    self.update_detected_links();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Keys for the reverse history search of the active field
+    fn on_history_search_key(&mut self, key: event::KeyEvent) {
+        let field = &mut self.fields[self.active_field];
+        let Some(history) = &mut field.history else {
+            return;
+        };
+        if let HistoryAction::Accept(text) = history.handle_search_key(key) {
+            field.set_content(text);
+            self.update_detected_links();
+        }
+    }
+
+    /// Keys for the symbol picker; the chosen symbol goes to the focused input
+    fn on_symbols_key(&mut self, key: event::KeyEvent) {
+        if let SymbolAction::Insert(symbol) = self.symbols.handle_key(key) {
+            if self.notes_focused {
+                self.notes.insert_str(symbol.encode_utf8(&mut [0; 4]));
+            } else {
+                self.fields[self.active_field].insert_char(symbol);
+            }
+            self.update_detected_links();
+        }
+    }
+
+    fn on_key(&mut self, key: event::KeyEvent) -> Result<(), DemoError> {
+        if self.symbols.visible {
+            self.on_symbols_key(key);
+            return Ok(());
+        }
+        if SymbolPicker::is_shortcut(&key) {
+            self.symbols.open();
+            return Ok(());
+        }
+        if self.notes_focused {
+            return self.on_notes_key(key);
+        }
+        if self.fields[self.active_field].history.as_ref().is_some_and(InputHistory::is_searching) {
+            self.on_history_search_key(key);
+            return Ok(());
+        }
+        match self.keymap.resolve(key) {
+            KeyResult::Action(action) => self.on_field_action(action),
+            KeyResult::Pending => Ok(()),
+            KeyResult::Unbound(key) => self.on_field_key(key),
+        }
+    }
+
+    /// Keymap actions on the active field; navigation maps to the default keys
+    fn on_field_action(&mut self, action: Action) -> Result<(), DemoError> {
+        let field = &mut self.fields[self.active_field];
+        match action {
+            Action::DeleteLine => {
+                self.register = std::mem::take(&mut field.content);
+                field.set_content(String::new());
+            }
+            Action::YankLine => {
+                self.register = field.content.clone();
+                self.status_message = "Yanked field".to_string();
+                return Ok(());
+            }
+            Action::KillToEnd => {
+                let start = byte_index(&field.content, field.cursor_position);
+                self.register = field.content[start..].to_string();
+                field.edit(event::KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL));
+            }
+            Action::Paste if self.keymap.profile == KeymapProfile::Emacs => {
+                // Emacs yanks at the cursor rather than after it
+                for c in self.register.chars() {
+                    field.insert_char(c);
+                }
+            }
+            Action::Paste => field.paste_after(&self.register),
+            Action::InsertMode | Action::NormalMode => {
+                self.status_message = self.keymap.mode_label().unwrap_or_default().to_string();
+                return Ok(());
+            }
+            _ => {
+                if let Some(key) = action.key() {
+                    return self.on_field_key(key);
+                }
+            }
+        }
+        self.update_detected_links();
+        Ok(())
+    }
+
+    fn on_field_key(&mut self, key: event::KeyEvent) -> Result<(), DemoError> {
+        if self.fields[self.active_field].complete_emoji(key) {
+            self.update_detected_links();
+            return Ok(());
+        }
         match (key.code, key.modifiers) {
             (KeyCode::Char('q'), _) => {
                 self.should_quit = true;
@@ -363,6 +534,16 @@ impl InputApp {
             (KeyCode::Char('l'), KeyModifiers::CONTROL) => {
                 self.open_links();
             }
+            (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
+                if let Some(history) = &mut self.fields[self.active_field].history {
+                    history.start_search();
+                }
+            }
+            (KeyCode::Up | KeyCode::Down, _) => {
+                if self.fields[self.active_field].recall(key.code == KeyCode::Up) {
+                    self.update_detected_links();
+                }
+            }
             (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
                 self.fields[self.active_field].copy_to_clipboard()?;
                 self.status_message = "Copied to clipboard".to_string();
@@ -372,36 +553,31 @@ impl InputApp {
                 self.status_message = "Pasted from clipboard".to_string();
                 self.update_detected_links();
             }
-            (KeyCode::Char(c), _) => {
-                self.fields[self.active_field].insert_char(c);
-                self.update_detected_links();
-            }
-            (KeyCode::Left, _) => {
-                self.fields[self.active_field].move_cursor_left();
-            }
-            (KeyCode::Right, _) => {
-                self.fields[self.active_field].move_cursor_right();
-            }
-            (KeyCode::Home, _) => {
-                self.fields[self.active_field].move_cursor_to_start();
-            }
-            (KeyCode::End, _) => {
-                self.fields[self.active_field].move_cursor_to_end();
-            }
-            (KeyCode::Backspace, _) => {
-                self.fields[self.active_field].backspace();
-                self.update_detected_links();
-            }
-            (KeyCode::Delete, _) => {
-                self.fields[self.active_field].delete_char();
-                self.update_detected_links();
+            // Vim Normal mode: unbound letters are commands, not text
+            (KeyCode::Char(_), _) if !self.keymap.is_inserting() => {}
+            _ => {
+                if self.fields[self.active_field].edit(key) {
+                    self.update_detected_links();
+                }
             }
-            _ => {}
         }
         Ok(())
     }
 
     fn on_mouse(&mut self, mouse: MouseEvent) {
+        if let Some(url) = self.link_regions.handle_mouse(mouse) {
+            self.status_message = match self.launches.open_url(&url) {
+                Ok(()) => format!("Opening: {}", url),
+                Err(e) => format!("Failed to open link: {}", e),
+            };
+            return;
+        }
+        if self.notes.handle_mouse(mouse) {
+            if !self.notes_focused {
+                self.focus_notes();
+            }
+            return;
+        }
         match mouse.kind {
             MouseEventKind::Down(MouseButton::Left) => {
                 self.handle_mouse_click(mouse.column, mouse.row);
@@ -410,7 +586,7 @@ impl InputApp {
         }
     }
 
-    fn render(&self, frame: &mut Frame) {
+    fn render(&mut self, frame: &mut Frame) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -418,7 +594,7 @@ impl InputApp {
                 Constraint::Length(3), // Field 1
                 Constraint::Length(3), // Field 2
                 Constraint::Length(3), // Field 3
-                Constraint::Length(6), // Field 4 (notes, taller)
+                Constraint::Length(6), // Notes text area
                 Constraint::Min(3),    // Links panel
                 Constraint::Length(3), // Status
             ])
@@ -431,8 +607,20 @@ impl InputApp {
         frame.render_widget(title, chunks[0]);
 
         // Render fields
+        self.link_regions.clear();
         for (i, field) in self.fields.iter().enumerate() {
-            field.render(frame, chunks[i + 1]);
+            field.render(frame, chunks[i + 1], &mut self.link_regions);
+        }
+        self.notes.render(frame, chunks[4], self.notes_focused);
+        if !self.notes_focused {
+            let field = &self.fields[self.active_field];
+            let area = chunks[self.active_field + 1];
+            let at = Position::new(
+                area.x + 1 + column_of_char(&field.content, field.cursor_position) as u16,
+                area.y + 1,
+            );
+            let bounds = frame.area();
+            field.emoji.render(frame, &field.content, field.cursor_position, at, bounds);
         }
 
         // Links panel
@@ -447,38 +635,67 @@ impl InputApp {
         frame.render_widget(links_list, chunks[5]);
 
         // Status bar
+        let status_title = match self.keymap.mode_label() {
+            Some(mode) => format!("Status {}", mode),
+            None => "Status".to_string(),
+        };
         let status = Paragraph::new(self.status_message.as_str())
-            .block(Block::default().borders(Borders::ALL).title("Status"))
+            .block(Block::default().borders(Borders::ALL).title(self.events.status_title(&status_title)))
             .style(Style::default().fg(Color::Yellow));
         frame.render_widget(status, chunks[6]);
+
+        if let Some(history) = &self.fields[self.active_field].history {
+            let area = frame.area();
+            history.render_search(frame, area);
+        }
+        let area = frame.area();
+        self.symbols.render(frame, area);
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
     // Setup terminal
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app
-    let mut app = InputApp::new();
+    let mut app = InputApp::new(&args.config());
 
     // Main loop
     loop {
-        terminal.draw(|f| app.render(f))?;
-
-        match event::read()? {
-            Event::Key(key) => {
-                if let Err(e) = app.on_key(key) {
-                    app.status_message = format!("Error: {}", e);
+        for failure in app.launches.failures() {
+            app.status_message = format!("Failed to open link: {}", failure);
+        }
+        let frame = terminal.draw(|f| {
+            app.render(f);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        // Keys queued while drawing (fast typing, pastes) are handled before the next frame
+        for event in app.events.read_batch()? {
+            match event {
+                Event::Key(key) if !app.should_quit => {
+                    if let Err(e) = app.on_key(key) {
+                        app.status_message = format!("Error: {} ({})", e, e.hint());
+                    }
                 }
+                Event::Mouse(mouse) => {
+                    app.on_mouse(mouse);
+                }
+                _ => {}
             }
-            Event::Mouse(mouse) => {
-                app.on_mouse(mouse);
-            }
-            _ => {}
         }
 
         if app.should_quit {
@@ -497,3 +714,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = InputApp::new(&ConfigHandle::default());
+        snapshot::assert_screen("text_input", |f| app.render(f));
+    }
+}
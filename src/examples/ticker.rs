@@ -0,0 +1,305 @@
+//! Ticker example
+//!
+//! Simulated stock and crypto quotes streamed from a background thread into
+//! rolling series per symbol. The selected symbol is charted as a line or as
+//! candlesticks ('c'). Price alerts are added in a form ('a') and raise an
+//! alert and a toast when the price crosses them.
+
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use std::{
+    io,
+    sync::mpsc::Receiver,
+    time::Duration,
+};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::alert::Alerter;
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::form::{FormAction, FormDialog, FormField, Validator};
+use tui_image_viewer::widgets::ticker::{close_points, line_chart, Alert, CandleChart, Crossing, SimulatedFeed, Ticker};
+use tui_image_viewer::widgets::toast::ToastManager;
+
+const SYMBOLS: [(&str, f64); 5] = [("AAPL", 190.0), ("MSFT", 410.0), ("NVDA", 120.0), ("BTC", 64000.0), ("ETH", 3100.0)];
+
+/// Quotes folded into one candle
+const TICKS_PER_CANDLE: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChartKind {
+    Line,
+    Candles,
+}
+
+struct TickerApp {
+    ticker: Ticker,
+    quotes: Receiver<(String, f64)>,
+    list_state: ListState,
+    chart: ChartKind,
+    alert_form: Option<FormDialog>,
+    toasts: ToastManager,
+    alerts: Alerter,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    should_quit: bool,
+}
+
+impl TickerApp {
+    fn new(config: &ConfigHandle) -> Self {
+        let symbols: Vec<&str> = SYMBOLS.iter().map(|(symbol, _)| *symbol).collect();
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(1, |elapsed| elapsed.as_nanos() as u64);
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+        Self {
+            ticker: Ticker::new(&symbols, TICKS_PER_CANDLE),
+            quotes: SimulatedFeed::new(&SYMBOLS, seed).spawn(Duration::from_millis(300)),
+            list_state,
+            chart: ChartKind::Candles,
+            alert_form: None,
+            toasts: ToastManager::persistent(),
+            alerts: Alerter::new(config.read(|config| config.alerts.clone())),
+            events: EventDispatcher::new(),
+            should_quit: false,
+        }
+    }
+
+    fn selected(&self) -> usize {
+        self.list_state.selected().unwrap_or(0)
+    }
+
+    /// Apply streamed quotes and announce triggered alerts
+    fn tick(&mut self) {
+        while let Ok((symbol, price)) = self.quotes.try_recv() {
+            for alert in self.ticker.quote(&symbol, price) {
+                let message = format!(
+                    "🔔 {} {} {:.2} (now {:.2})",
+                    alert.symbol,
+                    alert.crossing.label().to_lowercase(),
+                    alert.price,
+                    price
+                );
+                self.alerts.alert("Price alert", &message);
+                self.toasts.warning(&message);
+            }
+        }
+        self.toasts.prune();
+    }
+
+    fn open_alert_form(&mut self) {
+        let series = &self.ticker.series[self.selected()];
+        let symbols: Vec<&str> = SYMBOLS.iter().map(|(symbol, _)| *symbol).collect();
+        let price = series.last().map(|price| format!("{:.2}", price)).unwrap_or_default();
+        self.alert_form = Some(FormDialog::new(
+            "New price alert",
+            vec![
+                FormField::select("Symbol", &symbols, &series.symbol),
+                FormField::select("When price moves", &Crossing::ALL, Crossing::Above.label()),
+                FormField::new("Price", &price).required().validator(Validator::Number).validator(Validator::Min(0.0)),
+            ],
+        ));
+    }
+
+    fn submit_alert(&mut self, form: &FormDialog) {
+        let symbol = form.value("Symbol").unwrap_or_default();
+        let crossing = if form.value("When price moves") == Some(Crossing::Below.label()) {
+            Crossing::Below
+        } else {
+            Crossing::Above
+        };
+        let Ok(price) = form.value("Price").unwrap_or_default().trim().parse::<f64>() else {
+            return;
+        };
+        self.ticker.alerts.push(Alert::new(symbol, crossing, price));
+        self.toasts.info(&format!("Alert set: {} {} {:.2}", symbol, crossing.label().to_lowercase(), price));
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if let Some(form) = self.alert_form.as_mut() {
+            match form.handle_key_event(key) {
+                FormAction::Submit => {
+                    if let Some(form) = self.alert_form.take() {
+                        self.submit_alert(&form);
+                    }
+                }
+                FormAction::Cancel => self.alert_form = None,
+                FormAction::None => {}
+            }
+            return;
+        }
+        if self.toasts.center.handle_key(key.code) {
+            return;
+        }
+
+        let len = self.ticker.series.len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.list_state.select(Some((self.selected() + len - 1) % len)),
+            KeyCode::Down | KeyCode::Char('j') => self.list_state.select(Some((self.selected() + 1) % len)),
+            KeyCode::Char('c') => {
+                self.chart = match self.chart {
+                    ChartKind::Line => ChartKind::Candles,
+                    ChartKind::Candles => ChartKind::Line,
+                };
+            }
+            KeyCode::Char('a') => self.open_alert_form(),
+            KeyCode::Char('x') => {
+                let symbol = self.ticker.series[self.selected()].symbol.clone();
+                self.ticker.alerts.retain(|alert| alert.symbol != symbol);
+            }
+            KeyCode::Char('N') => self.toasts.center.toggle(),
+            _ => {}
+        }
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut TickerApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(f.area());
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(32), Constraint::Min(0)])
+        .split(chunks[0]);
+    let sidebar = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(SYMBOLS.len() as u16 + 2), Constraint::Min(0)])
+        .split(body[0]);
+
+    // Watchlist with last price and change
+    let items: Vec<ListItem> = app
+        .ticker
+        .series
+        .iter()
+        .map(|series| {
+            let change = series.change_percent().unwrap_or(0.0);
+            let color = if change >= 0.0 { Color::Green } else { Color::Red };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<6}", series.symbol), Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{:>11.2} ", series.last().unwrap_or(0.0))),
+                Span::styled(format!("{:>+6.2}%", change), Style::default().fg(color)),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Watchlist"))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    f.render_stateful_widget(list, sidebar[0], &mut app.list_state);
+
+    // Alerts, triggered ones dimmed
+    let alerts: Vec<ListItem> = app
+        .ticker
+        .alerts
+        .iter()
+        .map(|alert| {
+            let style = if alert.triggered { Style::default().fg(Color::DarkGray) } else { Style::default() };
+            let marker = if alert.triggered { "✔" } else { "🔔" };
+            ListItem::new(format!(
+                "{} {} {} {:.2}",
+                marker,
+                alert.symbol,
+                alert.crossing.label().to_lowercase(),
+                alert.price
+            ))
+            .style(style)
+        })
+        .collect();
+    f.render_widget(List::new(alerts).block(Block::default().borders(Borders::ALL).title("Alerts")), sidebar[1]);
+
+    let series = &app.ticker.series[app.selected()];
+    let title = match app.chart {
+        ChartKind::Line => format!("{} · line", series.symbol),
+        ChartKind::Candles => format!("{} · candles of {} ticks", series.symbol, TICKS_PER_CANDLE),
+    };
+    let block = Block::default().borders(Borders::ALL).title(title);
+    match app.chart {
+        ChartKind::Line => {
+            let data = close_points(series);
+            f.render_widget(line_chart(series, &data, block), body[1]);
+        }
+        ChartKind::Candles => f.render_widget(CandleChart::new(series).block(block), body[1]),
+    }
+
+    let status = Paragraph::new("↑↓: symbol | c: line/candles | a: add alert | x: clear alerts | N: notifications | q: quit")
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "{} | 🔔 {}",
+            app.events.status_title("Ticker"),
+            app.toasts.center.unread_count()
+        )))
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(status, chunks[1]);
+
+    let area = f.area();
+    if let Some(form) = &app.alert_form {
+        form.render(f, area);
+    }
+    app.toasts.render(f, area);
+    app.toasts.center.render(f, chunks[0]);
+    app.alerts.render(f);
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = TickerApp::new(&args.config());
+
+    loop {
+        app.tick();
+        app.alerts.ring(terminal.backend_mut())?;
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        if let Some(Event::Key(key)) = app.events.next(Duration::from_millis(100))? {
+            if key.kind == KeyEventKind::Press {
+                app.on_key(key);
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
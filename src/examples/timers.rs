@@ -0,0 +1,357 @@
+//! Timers example
+//!
+//! Multiple concurrent stopwatches and countdowns with lap times. Completed
+//! countdowns raise an alert (bell, flash or desktop notification, as
+//! configured) and a toast.
+
+use crossterm::{
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use std::{
+    io,
+    time::Duration,
+};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::alert::Alerter;
+use tui_image_viewer::widgets::state_machine::{State, StateMachine, Transitions};
+use tui_image_viewer::widgets::timer::{format_duration, Timer, TimerKind};
+use tui_image_viewer::widgets::toast::ToastManager;
+
+const DEFAULT_COUNTDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Normal,
+    Renaming,
+}
+
+impl State for Mode {}
+
+struct TimersApp {
+    timers: Vec<Timer>,
+    list_state: ListState,
+    mode: StateMachine<Mode>,
+    name_input: String,
+    toasts: ToastManager,
+    alerts: Alerter,
+    should_quit: bool,
+}
+
+impl TimersApp {
+    fn new(config: &ConfigHandle) -> Self {
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+        Self {
+            timers: vec![
+                Timer::stopwatch("Stopwatch 1"),
+                Timer::countdown("Countdown 1", DEFAULT_COUNTDOWN),
+            ],
+            list_state,
+            mode: StateMachine::new(Mode::Normal),
+            name_input: String::new(),
+            toasts: ToastManager::persistent(),
+            alerts: Alerter::new(config.read(|config| config.alerts.clone())),
+            should_quit: false,
+        }
+    }
+
+    fn selected_mut(&mut self) -> Option<&mut Timer> {
+        self.list_state.selected().and_then(|i| self.timers.get_mut(i))
+    }
+
+    fn add(&mut self, timer: Timer) {
+        self.timers.push(timer);
+        self.list_state.select(Some(self.timers.len() - 1));
+    }
+
+    fn delete_selected(&mut self) {
+        let Some(index) = self.list_state.selected() else {
+            return;
+        };
+        if index < self.timers.len() {
+            let removed = self.timers.remove(index);
+            self.toasts.info(&format!("Removed {}", removed.name));
+        }
+        if self.timers.is_empty() {
+            self.list_state.select(None);
+        } else {
+            self.list_state.select(Some(index.min(self.timers.len() - 1)));
+        }
+    }
+
+    fn select_offset(&mut self, offset: isize) {
+        if self.timers.is_empty() {
+            return;
+        }
+        let len = self.timers.len() as isize;
+        let current = self.list_state.selected().unwrap_or(0) as isize;
+        self.list_state.select(Some((current + offset).rem_euclid(len) as usize));
+    }
+
+    /// Advance every timer, collecting completed countdowns
+    fn tick(&mut self) {
+        for timer in &mut self.timers {
+            if timer.tick() {
+                self.toasts.success(&format!("⏰ {} finished", timer.name));
+                self.alerts.alert("Timer finished", &timer.name);
+            }
+        }
+        self.toasts.prune();
+    }
+
+    fn on_key(&mut self, key: KeyCode) {
+        if self.toasts.center.handle_key(key) {
+            return;
+        }
+
+        match self.mode.current() {
+            Mode::Renaming => match key {
+                KeyCode::Enter => {
+                    let name = self.name_input.trim().to_string();
+                    if !name.is_empty() {
+                        if let Some(timer) = self.selected_mut() {
+                            timer.name = name;
+                        }
+                    }
+                    self.transition(Mode::Normal);
+                }
+                KeyCode::Esc => {
+                    self.transition(Mode::Normal);
+                }
+                KeyCode::Backspace => {
+                    self.name_input.pop();
+                }
+                KeyCode::Char(c) => self.name_input.push(c),
+                _ => {}
+            },
+            Mode::Normal => match key {
+                KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+                KeyCode::Char('N') => self.toasts.center.toggle(),
+                KeyCode::Up => self.select_offset(-1),
+                KeyCode::Down => self.select_offset(1),
+                KeyCode::Char('s') => {
+                    let name = format!("Stopwatch {}", self.timers.len() + 1);
+                    self.add(Timer::stopwatch(&name));
+                }
+                KeyCode::Char('c') => {
+                    let name = format!("Countdown {}", self.timers.len() + 1);
+                    self.add(Timer::countdown(&name, DEFAULT_COUNTDOWN));
+                }
+                KeyCode::Char('d') | KeyCode::Delete => self.delete_selected(),
+                KeyCode::Char('e') => {
+                    self.transition(Mode::Renaming);
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(timer) = self.selected_mut() {
+                        timer.toggle();
+                    }
+                }
+                KeyCode::Char('l') => {
+                    if let Some(timer) = self.selected_mut() {
+                        timer.lap();
+                    }
+                }
+                KeyCode::Char('r') => {
+                    if let Some(timer) = self.selected_mut() {
+                        timer.reset();
+                    }
+                }
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    if let Some(timer) = self.selected_mut() {
+                        timer.adjust(10);
+                    }
+                }
+                KeyCode::Char('-') => {
+                    if let Some(timer) = self.selected_mut() {
+                        timer.adjust(-10);
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+}
+
+impl Transitions<Mode> for TimersApp {
+    fn machine(&mut self) -> &mut StateMachine<Mode> {
+        &mut self.mode
+    }
+
+    /// Renaming needs a selected timer
+    fn guard(&self, _from: Mode, to: Mode) -> bool {
+        to != Mode::Renaming || self.list_state.selected().is_some_and(|i| i < self.timers.len())
+    }
+
+    fn on_exit(&mut self, from: Mode, _to: Mode) {
+        if from == Mode::Renaming {
+            self.name_input.clear();
+        }
+    }
+
+    /// The rename prompt starts from the current name
+    fn on_enter(&mut self, to: Mode, _from: Mode) {
+        if to == Mode::Renaming {
+            self.name_input = self.selected_mut().map(|timer| timer.name.clone()).unwrap_or_default();
+        }
+    }
+}
+
+fn timer_item(timer: &Timer) -> ListItem<'static> {
+    let (icon, color) = if timer.is_finished() {
+        ("✔", Color::Green)
+    } else if timer.is_running() {
+        ("▶", Color::Cyan)
+    } else {
+        ("⏸", Color::Gray)
+    };
+    let kind = match timer.kind {
+        TimerKind::Stopwatch => "stopwatch".to_string(),
+        TimerKind::Countdown(total) => format!("countdown of {}", format_duration(total)),
+    };
+
+    ListItem::new(Line::from(vec![
+        Span::styled(format!("{} ", icon), Style::default().fg(color)),
+        Span::styled(
+            format!("{:>12}  ", format_duration(timer.display_time())),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!("{:<20}", timer.name)),
+        Span::styled(kind, Style::default().fg(Color::DarkGray)),
+    ]))
+}
+
+fn ui(f: &mut Frame, app: &mut TimersApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(f.area());
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(chunks[0]);
+
+    // Timer list
+    let items: Vec<ListItem> = app.timers.iter().map(timer_item).collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Timers ({})", app.timers.len())),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("» ");
+    f.render_stateful_widget(list, body[0], &mut app.list_state);
+
+    // Laps of the selected timer, newest first
+    let laps: Vec<ListItem> = app
+        .list_state
+        .selected()
+        .and_then(|i| app.timers.get(i))
+        .map(|timer| {
+            let mut previous = Duration::ZERO;
+            let mut rows: Vec<ListItem> = timer
+                .laps
+                .iter()
+                .enumerate()
+                .map(|(i, &lap)| {
+                    let split = lap - previous;
+                    previous = lap;
+                    ListItem::new(format!(
+                        "#{:<3} {}  (+{})",
+                        i + 1,
+                        format_duration(lap),
+                        format_duration(split)
+                    ))
+                })
+                .collect();
+            rows.reverse();
+            rows
+        })
+        .unwrap_or_default();
+    let laps = List::new(laps).block(Block::default().borders(Borders::ALL).title("Laps"));
+    f.render_widget(laps, body[1]);
+
+    // Status bar doubles as the rename prompt
+    let status = match app.mode.current() {
+        Mode::Renaming => Paragraph::new(format!("{}█", app.name_input))
+            .block(Block::default().borders(Borders::ALL).title("Rename (Enter: save, Esc: cancel)"))
+            .style(Style::default().fg(Color::Cyan)),
+        Mode::Normal => Paragraph::new(
+            "Space: start/pause | l: lap | r: reset | s/c: add stopwatch/countdown | +/-: adjust | e: rename | d: delete | N: notifications | q: quit",
+        )
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Controls | 🔔 {}",
+            app.toasts.center.unread_count()
+        )))
+        .style(Style::default().fg(Color::Yellow)),
+    };
+    f.render_widget(status, chunks[1]);
+
+    let area = f.area();
+    app.toasts.render(f, area);
+    app.toasts.center.render(f, chunks[0]);
+    app.alerts.render(f);
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = TimersApp::new(&args.config());
+    let tick_rate = Duration::from_millis(100);
+
+    loop {
+        app.tick();
+        app.alerts.ring(terminal.backend_mut())?;
+        terminal.draw(|f| ui(f, &mut app))?;
+
+        if event::poll(tick_rate)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.on_key(key.code);
+                }
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
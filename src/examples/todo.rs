@@ -0,0 +1,712 @@
+//! Todo list example
+//!
+//! Tasks with notes, a due date and a priority, shown in a table that can be
+//! sorted by due date, priority or title and filtered to open, overdue, due
+//! today or done tasks. Tasks are edited in a dialog combining a title input,
+//! a notes text area, the date picker and a priority select, and the list is
+//! saved to JSON on every change. The status bar counts overdue tasks.
+//!
+//! `--demo` drafts a task in the editor, cancels it so the saved list is
+//! left alone, and cycles the sort orders and filters.
+
+use chrono::{Local, NaiveDate};
+use crossterm::{
+    event::{DisableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame, Terminal,
+};
+use serde::{Deserialize, Serialize};
+use std::{fs, io, path::PathBuf, time::Duration};
+use std::process::ExitCode;
+
+use tui_image_viewer::app::cli;
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::autopilot::DemoStep;
+use tui_image_viewer::widgets::calendar::{Calendar, DatePicker, DatePickerAction};
+use tui_image_viewer::widgets::dispatch::EventDispatcher;
+use tui_image_viewer::widgets::line_edit::handle_edit_key;
+use tui_image_viewer::widgets::text_area::TextArea;
+
+/// Played with `--demo`; Esc cancels the drafted task, so nothing is saved
+const DEMO: &[DemoStep] = &[
+    DemoStep::Pause(1000),
+    DemoStep::Key(KeyCode::Char('n')),
+    DemoStep::Type("Record the conference demo"),
+    DemoStep::Key(KeyCode::Tab),
+    DemoStep::Type("One take, no retakes"),
+    DemoStep::Key(KeyCode::Tab),
+    DemoStep::Key(KeyCode::Enter),
+    DemoStep::Key(KeyCode::Right),
+    DemoStep::Key(KeyCode::Right),
+    DemoStep::Key(KeyCode::Enter),
+    DemoStep::Key(KeyCode::Tab),
+    DemoStep::Key(KeyCode::Right),
+    DemoStep::Pause(2000),
+    DemoStep::Key(KeyCode::Esc),
+    DemoStep::Key(KeyCode::Char('s')),
+    DemoStep::Key(KeyCode::Char('s')),
+    DemoStep::Key(KeyCode::Char('s')),
+    DemoStep::Key(KeyCode::Char('f')),
+    DemoStep::Key(KeyCode::Char('f')),
+    DemoStep::Key(KeyCode::Char('f')),
+    DemoStep::Key(KeyCode::Char('f')),
+    DemoStep::Key(KeyCode::Char('f')),
+    DemoStep::Key(KeyCode::Down),
+    DemoStep::Key(KeyCode::Up),
+    DemoStep::Pause(1000),
+    DemoStep::Key(KeyCode::Char('q')),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    const ALL: [Priority; 3] = [Priority::Low, Priority::Medium, Priority::High];
+
+    fn label(self) -> &'static str {
+        match self {
+            Priority::Low => "Low",
+            Priority::Medium => "Medium",
+            Priority::High => "High",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Priority::Low => Color::Gray,
+            Priority::Medium => Color::Yellow,
+            Priority::High => Color::Red,
+        }
+    }
+
+    fn cycle(self, delta: isize) -> Self {
+        let index = Self::ALL.iter().position(|&priority| priority == self).unwrap_or(0) as isize;
+        Self::ALL[(index + delta).rem_euclid(Self::ALL.len() as isize) as usize]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Task {
+    title: String,
+    #[serde(default)]
+    notes: String,
+    due: Option<NaiveDate>,
+    priority: Priority,
+    #[serde(default)]
+    done: bool,
+}
+
+impl Task {
+    fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.done && self.due.is_some_and(|due| due < today)
+    }
+
+    fn is_due_today(&self, today: NaiveDate) -> bool {
+        !self.done && self.due == Some(today)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct TodoList {
+    tasks: Vec<Task>,
+}
+
+impl TodoList {
+    fn path() -> Option<PathBuf> {
+        let mut path = dirs::data_dir()?;
+        path.push("ratatui-rust-example");
+        path.push("todos.json");
+        Some(path)
+    }
+
+    fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<(), DemoError> {
+        let path = Self::path().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find data directory"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortKey {
+    Due,
+    Priority,
+    Title,
+}
+
+impl SortKey {
+    fn next(self) -> Self {
+        match self {
+            SortKey::Due => SortKey::Priority,
+            SortKey::Priority => SortKey::Title,
+            SortKey::Title => SortKey::Due,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortKey::Due => "due date",
+            SortKey::Priority => "priority",
+            SortKey::Title => "title",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Filter {
+    All,
+    Open,
+    Overdue,
+    DueToday,
+    Done,
+}
+
+impl Filter {
+    fn next(self) -> Self {
+        match self {
+            Filter::All => Filter::Open,
+            Filter::Open => Filter::Overdue,
+            Filter::Overdue => Filter::DueToday,
+            Filter::DueToday => Filter::Done,
+            Filter::Done => Filter::All,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Filter::All => "all",
+            Filter::Open => "open",
+            Filter::Overdue => "overdue",
+            Filter::DueToday => "due today",
+            Filter::Done => "done",
+        }
+    }
+
+    fn matches(self, task: &Task, today: NaiveDate) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Open => !task.done,
+            Filter::Overdue => task.is_overdue(today),
+            Filter::DueToday => task.is_due_today(today),
+            Filter::Done => task.done,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditorFocus {
+    Title,
+    Notes,
+    Due,
+    Priority,
+}
+
+impl EditorFocus {
+    const ORDER: [EditorFocus; 4] = [EditorFocus::Title, EditorFocus::Notes, EditorFocus::Due, EditorFocus::Priority];
+
+    fn cycle(self, delta: isize) -> Self {
+        let index = Self::ORDER.iter().position(|&focus| focus == self).unwrap_or(0) as isize;
+        Self::ORDER[(index + delta).rem_euclid(Self::ORDER.len() as isize) as usize]
+    }
+}
+
+/// What a key press in the editor asks the app to do
+enum EditorAction {
+    None,
+    Save,
+    Cancel,
+}
+
+/// Task dialog; `None` index means a new task
+struct Editor {
+    index: Option<usize>,
+    title: String,
+    title_cursor: usize,
+    notes: TextArea,
+    due: Option<NaiveDate>,
+    priority: Priority,
+    focus: EditorFocus,
+    picker: Option<DatePicker>,
+    week_start: chrono::Weekday,
+    error: Option<String>,
+}
+
+impl Editor {
+    fn new(index: Option<usize>, task: &Task, week_start: chrono::Weekday) -> Self {
+        Self {
+            index,
+            title: task.title.clone(),
+            title_cursor: task.title.chars().count(),
+            notes: TextArea::new("Notes").with_text(&task.notes),
+            due: task.due,
+            priority: task.priority,
+            focus: EditorFocus::Title,
+            picker: None,
+            week_start,
+            error: None,
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> EditorAction {
+        if let Some(picker) = self.picker.as_mut() {
+            match picker.handle_key(key.code) {
+                DatePickerAction::Picked(date) => {
+                    self.due = Some(date);
+                    self.picker = None;
+                }
+                DatePickerAction::Cancelled => self.picker = None,
+                DatePickerAction::None => {}
+            }
+            return EditorAction::None;
+        }
+
+        // The find/replace bar takes every key while it is open
+        if self.focus == EditorFocus::Notes && self.notes.find.visible {
+            self.notes.handle_key(key);
+            return EditorAction::None;
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return EditorAction::Cancel,
+            KeyCode::Char('s') if ctrl => return self.save(),
+            KeyCode::Tab => self.focus = self.focus.cycle(1),
+            KeyCode::BackTab => self.focus = self.focus.cycle(-1),
+            _ => match self.focus {
+                EditorFocus::Title => {
+                    if key.code == KeyCode::Enter {
+                        return self.save();
+                    }
+                    handle_edit_key(&mut self.title, &mut self.title_cursor, key);
+                }
+                EditorFocus::Notes => {
+                    self.notes.handle_key(key);
+                }
+                EditorFocus::Due => match key.code {
+                    KeyCode::Enter | KeyCode::Char(' ') => {
+                        let today = Local::now().date_naive();
+                        let calendar = Calendar::new(self.due.unwrap_or(today)).with_week_start(self.week_start);
+                        self.picker = Some(DatePicker::new(calendar));
+                    }
+                    KeyCode::Backspace | KeyCode::Delete => self.due = None,
+                    _ => {}
+                },
+                EditorFocus::Priority => match key.code {
+                    KeyCode::Left => self.priority = self.priority.cycle(-1),
+                    KeyCode::Right | KeyCode::Char(' ') => self.priority = self.priority.cycle(1),
+                    KeyCode::Enter => return self.save(),
+                    _ => {}
+                },
+            },
+        }
+        EditorAction::None
+    }
+
+    fn save(&mut self) -> EditorAction {
+        if self.title.trim().is_empty() {
+            self.error = Some("Title is required".to_string());
+            self.focus = EditorFocus::Title;
+            return EditorAction::None;
+        }
+        EditorAction::Save
+    }
+
+    /// Task with the edited values, keeping the done state of `task`
+    fn task(&self, task: Option<&Task>) -> Task {
+        Task {
+            title: self.title.trim().to_string(),
+            notes: self.notes.text().trim_end().to_string(),
+            due: self.due,
+            priority: self.priority,
+            done: task.is_some_and(|task| task.done),
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, area: Rect) {
+        let width = 64.min(area.width);
+        let height = 20.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        let title = if self.index.is_some() { "Edit task" } else { "New task" };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(popup);
+        f.render_widget(Clear, popup);
+        f.render_widget(block, popup);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+        let border = |focus: EditorFocus| {
+            let color = if self.focus == focus { Color::Cyan } else { Color::Gray };
+            Style::default().fg(color)
+        };
+
+        let mut title_text = self.title.clone();
+        if self.focus == EditorFocus::Title {
+            let byte = title_text.char_indices().nth(self.title_cursor).map_or(title_text.len(), |(i, _)| i);
+            title_text.insert(byte, '█');
+        }
+        let title_input = Paragraph::new(title_text)
+            .block(Block::default().borders(Borders::ALL).title("Title").border_style(border(EditorFocus::Title)));
+        f.render_widget(title_input, rows[0]);
+
+        self.notes.render(f, rows[1], self.focus == EditorFocus::Notes);
+
+        let fields = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[2]);
+        let due = self.due.map_or_else(|| "No due date".to_string(), |date| date.format("%a %Y-%m-%d").to_string());
+        let due_field = Paragraph::new(due).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Due (Enter: pick, Del: clear)")
+                .border_style(border(EditorFocus::Due)),
+        );
+        f.render_widget(due_field, fields[0]);
+        let priority = Paragraph::new(Line::from(vec![
+            Span::raw("◀ "),
+            Span::styled(self.priority.label(), Style::default().fg(self.priority.color()).add_modifier(Modifier::BOLD)),
+            Span::raw(" ▶"),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title("Priority").border_style(border(EditorFocus::Priority)));
+        f.render_widget(priority, fields[1]);
+
+        let hint = match &self.error {
+            Some(error) => Line::styled(error.clone(), Style::default().fg(Color::Red)),
+            None => Line::styled("Tab: next field | Ctrl+S: save | Ctrl+R: find/replace in notes | Esc: cancel", Style::default().fg(Color::DarkGray)),
+        };
+        f.render_widget(Paragraph::new(hint), rows[3]);
+
+        if let Some(picker) = &self.picker {
+            picker.render(f, area);
+        }
+    }
+}
+
+struct TodoApp {
+    list: TodoList,
+    table_state: TableState,
+    sort: SortKey,
+    filter: Filter,
+    editor: Option<Editor>,
+    week_start: chrono::Weekday,
+    status_message: String,
+    /// Terminal events with macro recording (Alt+Q) and replay (Alt+@)
+    events: EventDispatcher,
+    should_quit: bool,
+}
+
+impl TodoApp {
+    fn new(config: &ConfigHandle) -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+        Self {
+            list: TodoList::load(),
+            table_state,
+            sort: SortKey::Due,
+            filter: Filter::Open,
+            editor: None,
+            week_start: config.read(|config| config.week_start),
+            status_message: String::new(),
+            events: EventDispatcher::new(),
+            should_quit: false,
+        }
+    }
+
+    fn persist(&mut self) {
+        if let Err(e) = self.list.save() {
+            self.status_message = format!("❌ Failed to save tasks: {} ({})", e, e.hint());
+        }
+    }
+
+    /// Indexes of the tasks shown, filtered and sorted; tasks without a due
+    /// date sort after dated ones
+    fn visible(&self, today: NaiveDate) -> Vec<usize> {
+        let tasks = &self.list.tasks;
+        let mut visible: Vec<usize> = (0..tasks.len()).filter(|&i| self.filter.matches(&tasks[i], today)).collect();
+        visible.sort_by(|&a, &b| {
+            let (a, b) = (&tasks[a], &tasks[b]);
+            let by_due = (a.due.is_none(), a.due).cmp(&(b.due.is_none(), b.due));
+            match self.sort {
+                SortKey::Due => by_due.then(b.priority.cmp(&a.priority)),
+                SortKey::Priority => b.priority.cmp(&a.priority).then(by_due),
+                SortKey::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            }
+        });
+        visible
+    }
+
+    fn selected_task(&self) -> Option<usize> {
+        let visible = self.visible(Local::now().date_naive());
+        self.table_state.selected().and_then(|row| visible.get(row).copied())
+    }
+
+    fn clamp_selection(&mut self) {
+        let len = self.visible(Local::now().date_naive()).len();
+        self.table_state.select(if len == 0 { None } else { Some(self.table_state.selected().unwrap_or(0).min(len - 1)) });
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.visible(Local::now().date_naive()).len();
+        if len > 0 {
+            let current = self.table_state.selected().unwrap_or(0) as isize;
+            self.table_state.select(Some((current + delta).clamp(0, len as isize - 1) as usize));
+        }
+    }
+
+    fn open_editor(&mut self, index: Option<usize>) {
+        let blank = Task {
+            title: String::new(),
+            notes: String::new(),
+            due: None,
+            priority: Priority::Medium,
+            done: false,
+        };
+        let task = index.and_then(|index| self.list.tasks.get(index)).unwrap_or(&blank);
+        self.editor = Some(Editor::new(index, task, self.week_start));
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if let Some(editor) = self.editor.as_mut() {
+            match editor.handle_key(key) {
+                EditorAction::Save => {
+                    let task = editor.task(editor.index.and_then(|index| self.list.tasks.get(index)));
+                    match editor.index {
+                        Some(index) => self.list.tasks[index] = task,
+                        None => {
+                            self.status_message = format!("Added \"{}\"", task.title);
+                            self.list.tasks.push(task);
+                        }
+                    }
+                    self.editor = None;
+                    self.persist();
+                    self.clamp_selection();
+                }
+                EditorAction::Cancel => self.editor = None,
+                EditorAction::None => {}
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Char('n') => self.open_editor(None),
+            KeyCode::Char('e') | KeyCode::Enter => {
+                if let Some(index) = self.selected_task() {
+                    self.open_editor(Some(index));
+                }
+            }
+            KeyCode::Char(' ') | KeyCode::Char('x') => {
+                if let Some(index) = self.selected_task() {
+                    let task = &mut self.list.tasks[index];
+                    task.done = !task.done;
+                    self.persist();
+                    self.clamp_selection();
+                }
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if let Some(index) = self.selected_task() {
+                    let task = self.list.tasks.remove(index);
+                    self.status_message = format!("Deleted \"{}\"", task.title);
+                    self.persist();
+                    self.clamp_selection();
+                }
+            }
+            KeyCode::Char('s') => self.sort = self.sort.next(),
+            KeyCode::Char('f') => {
+                self.filter = self.filter.next();
+                self.clamp_selection();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn due_cell(task: &Task, today: NaiveDate) -> Cell<'static> {
+    let Some(due) = task.due else {
+        return Cell::from("");
+    };
+    let days = (due - today).num_days();
+    let (text, color) = if task.done {
+        (due.format("%Y-%m-%d").to_string(), Color::DarkGray)
+    } else if days < 0 {
+        (format!("{} ({}d overdue)", due.format("%Y-%m-%d"), -days), Color::Red)
+    } else if days == 0 {
+        ("Today".to_string(), Color::Yellow)
+    } else if days == 1 {
+        ("Tomorrow".to_string(), Color::White)
+    } else {
+        (due.format("%a %Y-%m-%d").to_string(), Color::White)
+    };
+    Cell::from(text).style(Style::default().fg(color))
+}
+
+fn ui(f: &mut Frame, app: &mut TodoApp) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(f.area());
+    let today = Local::now().date_naive();
+
+    let rows: Vec<Row> = app
+        .visible(today)
+        .into_iter()
+        .map(|index| {
+            let task = &app.list.tasks[index];
+            let title_style = if task.done {
+                Style::default().fg(Color::DarkGray).add_modifier(Modifier::CROSSED_OUT)
+            } else {
+                Style::default()
+            };
+            Row::new(vec![
+                Cell::from(if task.done { "✔" } else { "☐" }),
+                Cell::from(task.title.clone()).style(title_style),
+                Cell::from(task.priority.label()).style(Style::default().fg(task.priority.color())),
+                due_cell(task, today),
+                Cell::from(task.notes.lines().next().unwrap_or_default().to_string())
+                    .style(Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+    let header = Row::new(vec!["", "Title", "Priority", "Due", "Notes"])
+        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(2),
+            Constraint::Percentage(35),
+            Constraint::Length(8),
+            Constraint::Length(26),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(format!(
+        "Tasks · {} · sorted by {}",
+        app.filter.label(),
+        app.sort.label()
+    )))
+    .highlight_style(Style::default().bg(Color::DarkGray));
+    f.render_stateful_widget(table, chunks[0], &mut app.table_state);
+
+    // Status bar with open, overdue and due-today counts
+    let tasks = &app.list.tasks;
+    let open = tasks.iter().filter(|task| !task.done).count();
+    let overdue = tasks.iter().filter(|task| task.is_overdue(today)).count();
+    let due_today = tasks.iter().filter(|task| task.is_due_today(today)).count();
+    let counts = Line::from(vec![
+        Span::raw(format!("{} open · ", open)),
+        Span::styled(
+            format!("{} overdue", overdue),
+            if overdue > 0 { Style::default().fg(Color::Red).add_modifier(Modifier::BOLD) } else { Style::default() },
+        ),
+        Span::raw(format!(" · {} due today", due_today)),
+    ]);
+    let help = if app.status_message.is_empty() {
+        "↑↓: select | n: new | e/Enter: edit | Space: done | d: delete | s: sort | f: filter | q: quit".to_string()
+    } else {
+        app.status_message.clone()
+    };
+    let status = Paragraph::new(help)
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL).title(app.events.status_title("Todo")).title(counts.right_aligned()));
+    f.render_widget(status, chunks[1]);
+
+    if let Some(editor) = app.editor.as_mut() {
+        let area = f.area();
+        editor.render(f, area);
+    }
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse();
+
+    // Setup terminal
+    cli::require_terminal()?;
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut app = TodoApp::new(&args.config());
+    if args.demo {
+        app.events.play(DEMO);
+    }
+
+    loop {
+        let frame = terminal.draw(|f| {
+            ui(f, &mut app);
+            app.events.render(f);
+        })?;
+        app.events.frame_drawn(frame.buffer);
+
+        // Keys queued while drawing (fast typing, pastes) are handled before the next frame
+        for event in app.events.next_batch(Duration::from_millis(250))? {
+            if let Event::Key(key) = event {
+                if key.kind == KeyEventKind::Press && !app.should_quit {
+                    app.status_message.clear();
+                    app.on_key(key);
+                }
+            }
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    Ok(())
+}
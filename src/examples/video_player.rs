@@ -1,5 +1,6 @@
+use clap::Parser;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -12,62 +13,89 @@ use ratatui::{
     Frame, Terminal,
 };
 use std::{
+    collections::HashSet,
     fs, 
     io,
     path::{Path, PathBuf},
-};  // Remove Duration, Instant
-
-#[derive(Debug)]
-#[allow(dead_code)]  // Add this to suppress warnings
-enum PlayerError {
-    Io(io::Error),
-    Other(String),  // Keep this even if unused - might be useful later
-}
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
+use std::process::ExitCode;
+use tui_image_viewer::app::cli::{self, CommonArgs};
+use tui_image_viewer::app::config::ConfigHandle;
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::alert::Alerter;
+use tui_image_viewer::widgets::file_ops::{rename_in_place, restore_from_trash, FileOperation};
+use tui_image_viewer::widgets::file_preview::{probe_duration, FilePreview};
+use tui_image_viewer::widgets::finder::{FileFinder, FileKind, FinderAction};
+use tui_image_viewer::widgets::form::{ConfirmDialog, FormAction, FormDialog, FormField};
+use tui_image_viewer::widgets::humanize::format_clock;
+use tui_image_viewer::widgets::path_input::{PathInput, PathInputAction, RecentLocations};
+use tui_image_viewer::widgets::playback::PlaybackClock;
+use tui_image_viewer::widgets::progress::{TaskHandle, TaskManager, TaskOutcome};
+use tui_image_viewer::widgets::state_machine::{State, StateMachine, Transitions};
+#[cfg(feature = "terminal")]
+use tui_image_viewer::widgets::terminal::TerminalPane;
+use tui_image_viewer::widgets::toast::{Toast, ToastLevel, ToastManager};
+use tui_image_viewer::widgets::truncate::{elide_middle, elide_path, truncate_end};
+#[cfg(feature = "image")]
+use tui_image_viewer::widgets::video_thumbs::VideoThumbnails;
+use tui_image_viewer::widgets::watcher::FileWatcher;
 
-impl From<io::Error> for PlayerError {
-    fn from(err: io::Error) -> Self {
-        PlayerError::Io(err)
-    }
-}
+/// How long a trash operation can be undone from its toast
+const UNDO_GRACE: Duration = Duration::from_secs(10);
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum AppMode {
     FileBrowser,
     VideoPlayer,
     Help,
 }
 
+impl State for AppMode {
+    fn is_overlay(self) -> bool {
+        self == AppMode::Help
+    }
+}
+
 struct FileBrowser {
     current_dir: PathBuf,
     items: Vec<String>,
     selected: usize,  // Fix: Use 'selected' instead of 'selected_index'
     list_state: ListState,  // Fix: Add ListState
+    /// Entries marked with Space for bulk operations
+    marked: HashSet<PathBuf>,
 }
 
 impl FileBrowser {
-    fn new() -> Result<Self, PlayerError> {
+    fn new() -> Result<Self, DemoError> {
         let mut browser = Self {
             current_dir: std::env::current_dir()?,
             items: Vec::new(),
             selected: 0,
             list_state: ListState::default(),
+            marked: HashSet::new(),
         };
         browser.refresh_items()?;
         Ok(browser)
     }
 
-    fn refresh_items(&mut self) -> Result<(), PlayerError> {
+    fn refresh_items(&mut self) -> Result<(), DemoError> {
         self.items.clear();
+        // Marks survive a refresh of the same directory while the entries exist
+        let dir = self.current_dir.clone();
+        self.marked.retain(|path| path.parent() == Some(dir.as_path()) && path.exists());
         
         if let Some(_parent) = self.current_dir.parent() {  // Fix: Add underscore
             self.items.push("..".to_string());
         }
 
+        // Every visible entry is listed so it can be previewed; only videos play
         let entries = fs::read_dir(&self.current_dir)?;
         for entry in entries {
             let entry = entry?;
             let file_name = entry.file_name().to_string_lossy().to_string();
-            if entry.path().is_dir() || self.is_video_file(&entry.path()) {
+            if !file_name.starts_with('.') {
                 self.items.push(file_name);
             }
         }
@@ -81,13 +109,46 @@ impl FileBrowser {
     }
 
     fn is_video_file(&self, path: &Path) -> bool {
-        if let Some(ext) = path.extension() {
-            matches!(
-                ext.to_string_lossy().to_lowercase().as_str(),
-                "mp4" | "avi" | "mkv" | "mov" | "wmv" | "flv" | "webm"
-            )
+        is_video_path(path)
+    }
+
+    fn navigate_to(&mut self, dir: PathBuf) -> Result<(), DemoError> {
+        if !dir.is_dir() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Not a directory: {}", dir.display())).into());
+        }
+        let previous = std::mem::replace(&mut self.current_dir, dir);
+        self.selected = 0;
+        if let Err(e) = self.refresh_items() {
+            self.current_dir = previous;
+            self.refresh_items()?;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Path of the highlighted entry, if any
+    fn selected_path(&self) -> Option<PathBuf> {
+        self.items.get(self.selected).map(|item| self.current_dir.join(item))
+    }
+
+    /// Mark or unmark the highlighted entry and move to the next one
+    fn toggle_mark(&mut self) {
+        if let Some(path) = self.selected_path().filter(|path| !path.ends_with("..")) {
+            if !self.marked.remove(&path) {
+                self.marked.insert(path);
+            }
+        }
+        self.move_down();
+    }
+
+    /// Marked entries, or the highlighted one when nothing is marked
+    fn targets(&self) -> Vec<PathBuf> {
+        if self.marked.is_empty() {
+            self.selected_path().filter(|path| !path.ends_with("..")).into_iter().collect()
         } else {
-            false
+            let mut targets: Vec<PathBuf> = self.marked.iter().cloned().collect();
+            targets.sort();
+            targets
         }
     }
 
@@ -105,7 +166,7 @@ impl FileBrowser {
         }
     }
 
-    fn select_current(&mut self) -> Result<Option<PathBuf>, PlayerError> {
+    fn select_current(&mut self) -> Result<Option<PathBuf>, DemoError> {
         if self.items.is_empty() {
             return Ok(None);
         }
@@ -136,20 +197,71 @@ impl FileBrowser {
     }
 }
 
+/// Output of the player's background tasks
+enum TaskResult {
+    Indexed(Vec<PathBuf>),
+    /// Summary of a finished file operation
+    FileOp(String),
+    /// Paths moved to the trash, kept for undo
+    Trashed(Vec<PathBuf>),
+}
+
+/// What a submitted path prompt is used for
+enum PathPrompt {
+    /// Open the directory in the browser
+    Jump,
+    /// Copy the sources into the directory
+    Copy(Vec<PathBuf>),
+    /// Move the sources into the directory
+    Move(Vec<PathBuf>),
+}
+
 struct VideoPlayerApp {
-    mode: AppMode,
+    mode: StateMachine<AppMode>,
     should_quit: bool,
     status_message: String,
     error_message: Option<String>,
     file_browser: FileBrowser,
     current_video_path: Option<PathBuf>,  // Fix: Use PathBuf instead of String
-    is_playing: bool,
+    playback: PlaybackClock,
+    /// `ffprobe` finding the length of the video being played
+    duration_probe: Option<Receiver<Option<Duration>>>,
+    /// Where the seek bar was drawn, for mouse hovering and clicks
+    seek_bar_area: Rect,
+    /// Frames shown above the seek bar while seeking
+    #[cfg(feature = "image")]
+    thumbnails: VideoThumbnails,
+    tasks: TaskManager<TaskResult>,
+    indexed_videos: Vec<PathBuf>,
+    finder: FileFinder,
+    #[cfg(feature = "terminal")]
+    terminal: Option<TerminalPane>,
+    /// Metadata and preview of the highlighted entry
+    preview: FilePreview,
+    /// Ctrl+L path prompt, or the destination prompt for copy/move
+    path_input: Option<(PathInput, PathPrompt)>,
+    /// File operation waiting for confirmation
+    confirm: Option<(ConfirmDialog, FileOperation)>,
+    /// Rename dialog and the path being renamed
+    rename: Option<(FormDialog, PathBuf)>,
+    toasts: ToastManager,
+    /// Bell, flash or desktop notification for finished tasks and errors
+    alerts: Alerter,
+    /// Paths from the latest trash operation, restored by the Undo toast
+    last_trashed: Vec<PathBuf>,
+    /// Refreshes the listing when the current directory changes on disk
+    watcher: FileWatcher,
+    /// Bookmarks and recent locations popup, with its selection
+    locations: Option<ListState>,
+    config: ConfigHandle,
+    recent: RecentLocations,
 }
 
 impl VideoPlayerApp {
-    fn new() -> Self {
-        Self {
-            mode: AppMode::FileBrowser,
+    fn new(config: ConfigHandle) -> Self {
+        let (alerts, watch_files) = config.read(|config| (config.alerts.clone(), config.watch_files));
+        let mut app = Self {
+            mode: StateMachine::new(AppMode::FileBrowser),
             should_quit: false,
             status_message: "Navigate with arrows, Enter to select, 'q' to quit".to_string(),
             error_message: None,
@@ -158,38 +270,480 @@ impl VideoPlayerApp {
                 items: Vec::new(),
                 selected: 0,
                 list_state: ListState::default(),
+                marked: HashSet::new(),
             }),
             current_video_path: None,
-            is_playing: false,
+            playback: PlaybackClock::new(),
+            duration_probe: None,
+            seek_bar_area: Rect::default(),
+            #[cfg(feature = "image")]
+            thumbnails: VideoThumbnails::new(),
+            tasks: TaskManager::new(),
+            indexed_videos: Vec::new(),
+            finder: FileFinder::in_current_dir(),
+            #[cfg(feature = "terminal")]
+            terminal: None,
+            preview: FilePreview::new(),
+            path_input: None,
+            confirm: None,
+            rename: None,
+            toasts: ToastManager::new(),
+            alerts: Alerter::new(alerts),
+            last_trashed: Vec::new(),
+            locations: None,
+            watcher: FileWatcher::new(watch_files),
+            config,
+            recent: RecentLocations::persistent(),
+        };
+        let dir = app.file_browser.current_dir.clone();
+        app.watcher.watch(&dir, false);
+        app
+    }
+
+    /// Open the error popup and alert the user
+    fn show_error(&mut self, message: String) {
+        self.alerts.alert("Video player error", &message);
+        self.error_message = Some(message);
+    }
+
+    /// Change the browser directory and remember it as a recent location
+    fn go_to(&mut self, dir: PathBuf) {
+        match self.file_browser.navigate_to(dir) {
+            Ok(()) => self.entered_dir(),
+            Err(e) => self.show_error(format!("Error: {} ({})", e, e.hint())),
+        }
+    }
+
+    /// Record the new browser directory and watch it instead of the old one
+    fn entered_dir(&mut self) {
+        let dir = self.file_browser.current_dir.clone();
+        self.recent.push(&dir);
+        self.watcher.unwatch_all();
+        self.watcher.watch(&dir, false);
+    }
+
+    /// Refresh the listing and preview after debounced changes on disk
+    fn poll_watcher(&mut self) {
+        if self.watcher.poll().is_some() {
+            let _ = self.file_browser.refresh_items();
+            self.preview.clear();
+        }
+    }
+
+    fn toggle_watch(&mut self) {
+        let mut watch_files = false;
+        let saved = self.config.update_and_save(|config| {
+            config.watch_files = !config.watch_files;
+            watch_files = config.watch_files;
+        });
+        self.watcher.set_enabled(watch_files);
+        self.status_message = if watch_files {
+            "Watching for file changes".to_string()
+        } else {
+            "File watching disabled".to_string()
+        };
+        if let Err(e) = saved {
+            self.show_error(format!("Failed to save settings: {}", e));
+        }
+    }
+
+    /// Bookmarks first, then recent locations that are not bookmarked
+    fn location_entries(&self) -> Vec<(PathBuf, bool)> {
+        let bookmarks = self.config.read(|config| config.bookmarks.clone());
+        let recent: Vec<_> = self
+            .recent
+            .paths
+            .iter()
+            .filter(|path| !bookmarks.contains(path))
+            .map(|path| (path.clone(), false))
+            .collect();
+        bookmarks.into_iter().map(|path| (path, true)).chain(recent).collect()
+    }
+
+    fn toggle_bookmark(&mut self) {
+        let dir = self.file_browser.current_dir.clone();
+        let mut removed = false;
+        let saved = self.config.update_and_save(|config| {
+            if let Some(index) = config.bookmarks.iter().position(|path| *path == dir) {
+                config.bookmarks.remove(index);
+                removed = true;
+            } else {
+                config.bookmarks.push(dir.clone());
+            }
+        });
+        self.status_message = if removed {
+            format!("Removed bookmark: {}", dir.display())
+        } else {
+            format!("Bookmarked: {}", dir.display())
+        };
+        if let Err(e) = saved {
+            self.show_error(format!("Failed to save bookmarks: {}", e));
+        }
+    }
+
+    fn handle_path_input_key(&mut self, key: KeyEvent) {
+        let Some((input, _)) = self.path_input.as_mut() else {
+            return;
+        };
+        match input.handle_key_event(key) {
+            PathInputAction::Submit(path) => {
+                let Some((_, prompt)) = self.path_input.take() else {
+                    return;
+                };
+                match prompt {
+                    PathPrompt::Jump => self.go_to(path),
+                    PathPrompt::Copy(sources) => self.confirm_operation(FileOperation::Copy { sources, destination: path }),
+                    PathPrompt::Move(sources) => self.confirm_operation(FileOperation::Move { sources, destination: path }),
+                }
+            }
+            PathInputAction::Cancel => self.path_input = None,
+            PathInputAction::None => {}
+        }
+    }
+
+    /// Prompt for the destination of a copy or move of the current targets
+    fn prompt_destination(&mut self, moving: bool) {
+        let sources = self.file_browser.targets();
+        if sources.is_empty() {
+            return;
+        }
+        let (title, prompt) = if moving {
+            (format!("Move {} item(s) to", sources.len()), PathPrompt::Move(sources))
+        } else {
+            (format!("Copy {} item(s) to", sources.len()), PathPrompt::Copy(sources))
+        };
+        let input = PathInput::new(&self.file_browser.current_dir).with_title(&title);
+        self.path_input = Some((input, prompt));
+    }
+
+    fn confirm_operation(&mut self, operation: FileOperation) {
+        match &operation {
+            FileOperation::Copy { destination, .. } | FileOperation::Move { destination, .. }
+                if !destination.is_dir() =>
+            {
+                self.show_error(format!("Not a directory: {}", destination.display()));
+                return;
+            }
+            _ => {}
+        }
+        let dialog = ConfirmDialog::new("Confirm", &format!("{}?", operation.describe()));
+        self.confirm = Some((dialog, operation));
+    }
+
+    fn handle_confirm_key(&mut self, key: KeyCode) {
+        let Some((dialog, _)) = &self.confirm else {
+            return;
+        };
+        match dialog.handle_key(key) {
+            FormAction::Submit => {
+                if let Some((_, operation)) = self.confirm.take() {
+                    self.start_file_operation(operation);
+                }
+            }
+            FormAction::Cancel => self.confirm = None,
+            FormAction::None => {}
+        }
+    }
+
+    /// Run a confirmed operation on a background task with a progress bar
+    fn start_file_operation(&mut self, operation: FileOperation) {
+        let name = operation.describe();
+        self.status_message = format!("{}...", name);
+        let task_name = name.clone();
+        self.tasks.spawn(&task_name, move |handle| {
+            let total = operation.sources().len();
+            let done = operation
+                .run(|files_done, files_total, path| {
+                    let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                    handle.report_with(files_done as f64 / files_total.max(1) as f64, file_name);
+                    !handle.is_cancelled()
+                })
+                .map_err(|e| e.to_string())?;
+            if let FileOperation::Trash { sources } = &operation {
+                return Ok(TaskResult::Trashed(sources[..done].to_vec()));
+            }
+            if done < total {
+                Ok(TaskResult::FileOp(format!("{}: stopped after {} of {}", name, done, total)))
+            } else {
+                Ok(TaskResult::FileOp(format!("{}: done", name)))
+            }
+        });
+    }
+
+    /// Restore the latest trashed files while their Undo toast is visible
+    fn undo_trash(&mut self) {
+        if self.toasts.take_action('u').is_none() {
+            return;
+        }
+        let paths = std::mem::take(&mut self.last_trashed);
+        match restore_from_trash(&paths) {
+            Ok(count) => self.toasts.success(&format!("Restored {} item(s) from trash", count)),
+            Err(e) => self.toasts.error(&format!("Undo failed: {}", e)),
+        }
+        let _ = self.file_browser.refresh_items();
+    }
+
+    fn open_rename(&mut self) {
+        let Some(path) = self.file_browser.selected_path().filter(|path| !path.ends_with("..")) else {
+            return;
+        };
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let dialog = FormDialog::new("Rename", vec![FormField::new("Name", &name).required()]);
+        self.rename = Some((dialog, path));
+    }
+
+    fn handle_rename_key(&mut self, key: KeyEvent) {
+        let Some((dialog, _)) = self.rename.as_mut() else {
+            return;
+        };
+        match dialog.handle_key_event(key) {
+            FormAction::Submit => {
+                let Some((dialog, path)) = self.rename.take() else {
+                    return;
+                };
+                let name = dialog.value("Name").unwrap_or_default().trim().to_string();
+                match rename_in_place(&path, &name) {
+                    Ok(target) => self.status_message = format!("Renamed to {}", target.display()),
+                    Err(e) => self.show_error(format!("Rename failed: {}", e)),
+                }
+                let _ = self.file_browser.refresh_items();
+            }
+            FormAction::Cancel => self.rename = None,
+            FormAction::None => {}
+        }
+    }
+
+    fn handle_locations_key(&mut self, key: KeyCode) {
+        let entries = self.location_entries();
+        let Some(state) = self.locations.as_mut() else {
+            return;
+        };
+        let selected = state.selected().unwrap_or(0);
+        match key {
+            KeyCode::Esc | KeyCode::Char('g') => self.locations = None,
+            KeyCode::Up if selected > 0 => state.select(Some(selected - 1)),
+            KeyCode::Down if selected + 1 < entries.len() => state.select(Some(selected + 1)),
+            KeyCode::Enter => {
+                if let Some((path, _)) = entries.get(selected) {
+                    self.locations = None;
+                    self.go_to(path.clone());
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some((path, true)) = entries.get(selected) {
+                    let saved = self.config.update_and_save(|config| config.bookmarks.retain(|bookmark| bookmark != path));
+                    state.select(Some(selected.min(entries.len().saturating_sub(2))));
+                    if let Err(e) = saved {
+                        self.show_error(format!("Failed to save bookmarks: {}", e));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Index every video below the current directory on a background task
+    fn start_indexing(&mut self) {
+        let root = self.file_browser.current_dir.clone();
+        self.status_message = format!("Indexing videos in {}...", root.display());
+        self.tasks.spawn("Indexing videos", move |handle| {
+            index_videos(&root, handle).map(TaskResult::Indexed)
+        });
+    }
+
+    /// Advance the playback clock, pick up the video's length and fetch the
+    /// thumbnail of the seek target; call once per tick
+    fn poll_player(&mut self) {
+        if let Some(Ok(duration)) = self.duration_probe.as_ref().map(Receiver::try_recv) {
+            self.duration_probe = None;
+            self.playback.duration = duration;
+            #[cfg(feature = "image")]
+            if let Some(path) = &self.current_video_path {
+                self.thumbnails.set_video(path, duration);
+            }
+        }
+        self.playback.tick();
+        #[cfg(feature = "image")]
+        {
+            self.thumbnails.poll();
+            if let Some(target) = self.playback.seek_target {
+                self.thumbnails.request(target);
+            }
+        }
+    }
+
+    /// Hovering the seek bar previews that position and a click jumps there
+    fn on_mouse(&mut self, mouse: MouseEvent) {
+        if self.mode.current() != AppMode::VideoPlayer {
+            return;
+        }
+        let bar = self.seek_bar_area;
+        let on_bar = mouse.row == bar.y && mouse.column >= bar.x && mouse.column < bar.right();
+        let target = on_bar
+            .then(|| self.playback.at_ratio((mouse.column - bar.x) as f64 / bar.width.max(1) as f64))
+            .flatten();
+        match mouse.kind {
+            MouseEventKind::Moved => self.playback.hover(target),
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(target) = target {
+                    self.playback.seek(target);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Apply background task progress and collect finished results
+    fn poll_tasks(&mut self) {
+        for outcome in self.tasks.poll() {
+            match outcome {
+                TaskOutcome::Finished { output: TaskResult::Indexed(videos), .. } => {
+                    self.status_message = format!("Indexed {} video files", videos.len());
+                    self.alerts.alert("Indexing finished", &self.status_message);
+                    self.indexed_videos = videos;
+                }
+                TaskOutcome::Finished { output: TaskResult::FileOp(summary), .. } => {
+                    self.alerts.alert("File operation finished", &summary);
+                    self.status_message = summary;
+                    self.file_browser.marked.clear();
+                    let _ = self.file_browser.refresh_items();
+                }
+                TaskOutcome::Finished { output: TaskResult::Trashed(paths), .. } => {
+                    let message = format!("Moved {} item(s) to trash", paths.len());
+                    self.toasts.push(
+                        Toast::new(ToastLevel::Info, &message)
+                            .with_action('u', "Undo")
+                            .with_ttl(UNDO_GRACE),
+                    );
+                    self.last_trashed = paths;
+                    let _ = self.file_browser.refresh_items();
+                }
+                // A failed or cancelled file operation may have changed some files
+                TaskOutcome::Failed { name, error, .. } => {
+                    self.show_error(format!("{} failed: {}", name, error));
+                    let _ = self.file_browser.refresh_items();
+                }
+                TaskOutcome::Cancelled { name, .. } => {
+                    self.status_message = format!("{} cancelled", name);
+                    let _ = self.file_browser.refresh_items();
+                }
+            }
+        }
+    }
+
+    /// Play a video picked in the finder or passed on the command line
+    fn open_video(&mut self, path: PathBuf) {
+        if FileKind::from_path(&path) == FileKind::Video {
+            self.current_video_path = Some(path);
+            self.transition(AppMode::VideoPlayer);
+        } else {
+            self.show_error(format!("Not a video file: {}", path.display()));
+        }
+    }
+
+    /// Open a shell in the browser's current directory
+    #[cfg(feature = "terminal")]
+    fn open_terminal_here(&mut self) {
+        let (cols, rows) = crossterm::terminal::size().unwrap_or((80, 24));
+        match TerminalPane::spawn_shell(&self.file_browser.current_dir, rows, cols) {
+            Ok(pane) => self.terminal = Some(pane),
+            Err(e) => self.show_error(format!("Failed to start shell: {} ({})", e, e.hint())),
         }
     }
 
     fn on_key(&mut self, key: KeyCode, modifiers: KeyModifiers) {
-        match self.mode {
+        if self.path_input.is_some() {
+            self.handle_path_input_key(KeyEvent::new(key, modifiers));
+            return;
+        }
+        if self.locations.is_some() {
+            self.handle_locations_key(key);
+            return;
+        }
+        if self.confirm.is_some() {
+            self.handle_confirm_key(key);
+            return;
+        }
+        if self.rename.is_some() {
+            self.handle_rename_key(KeyEvent::new(key, modifiers));
+            return;
+        }
+
+        // Task panel keys (↑↓ select, Esc cancel) take priority while tasks run
+        if self.tasks.handle_key(key) {
+            return;
+        }
+
+        match self.mode.current() {
             AppMode::FileBrowser => self.handle_browser_key(key, modifiers),
             AppMode::VideoPlayer => self.handle_player_key(key, modifiers),
             AppMode::Help => self.handle_help_key(key, modifiers),
         }
     }
 
-    fn handle_browser_key(&mut self, key: KeyCode, _modifiers: KeyModifiers) {
+    fn handle_browser_key(&mut self, key: KeyCode, modifiers: KeyModifiers) {
+        // An audio clip in the preview takes Enter, p and s for its transport
+        #[cfg(feature = "audio")]
+        match self.preview.handle_audio_key(key) {
+            Ok(true) => return,
+            Ok(false) => {}
+            Err(e) => {
+                self.toasts.report(&e);
+                return;
+            }
+        }
         match key {
+            KeyCode::Char('l') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.path_input = Some((PathInput::new(&self.file_browser.current_dir), PathPrompt::Jump));
+            }
+            KeyCode::Char(' ') => self.file_browser.toggle_mark(),
+            KeyCode::Char('c') => self.prompt_destination(false),
+            KeyCode::Char('m') => self.prompt_destination(true),
+            KeyCode::Char('r') => self.open_rename(),
+            KeyCode::Char('d') | KeyCode::Delete => {
+                let sources = self.file_browser.targets();
+                if !sources.is_empty() {
+                    self.confirm_operation(FileOperation::Trash { sources });
+                }
+            }
+            KeyCode::Char('D') => {
+                let sources = self.file_browser.targets();
+                if !sources.is_empty() {
+                    self.confirm_operation(FileOperation::Delete { sources });
+                }
+            }
+            KeyCode::Char('u') => self.undo_trash(),
+            KeyCode::Char('w') => self.toggle_watch(),
+            KeyCode::Char('b') => self.toggle_bookmark(),
+            KeyCode::Char('g') => {
+                let mut state = ListState::default();
+                state.select(Some(0));
+                self.locations = Some(state);
+            }
             KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('h') => self.mode = AppMode::Help,
+            KeyCode::Char('h') => {
+                self.transition(AppMode::Help);
+            }
+            KeyCode::Char('i') if !self.tasks.is_busy() => self.start_indexing(),
+            #[cfg(feature = "terminal")]
+            KeyCode::Char('t') => self.open_terminal_here(),
             KeyCode::Up => self.file_browser.move_up(),
             KeyCode::Down => self.file_browser.move_down(),
             KeyCode::Enter => {
+                let previous_dir = self.file_browser.current_dir.clone();
                 match self.file_browser.select_current() {
                     Ok(Some(path)) => {
-                        self.current_video_path = Some(path.clone());
-                        self.status_message = format!("Selected: {}", path.display());
-                        self.mode = AppMode::VideoPlayer;
+                        self.current_video_path = Some(path);
+                        self.transition(AppMode::VideoPlayer);
                     }
                     Ok(None) => {
                         // Directory navigation handled in select_current
+                        if self.file_browser.current_dir != previous_dir {
+                            self.entered_dir();
+                        }
                     }
                     Err(e) => {
-                        self.error_message = Some(format!("Error: {:?}", e));
+                        self.show_error(format!("Error: {} ({})", e, e.hint()));
                     }
                 }
             }
@@ -200,12 +754,25 @@ impl VideoPlayerApp {
     fn handle_player_key(&mut self, key: KeyCode, _modifiers: KeyModifiers) {
         match key {
             KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('b') => self.mode = AppMode::FileBrowser,
-            KeyCode::Char('h') => self.mode = AppMode::Help,
+            KeyCode::Char('b') => {
+                self.transition(AppMode::FileBrowser);
+            }
+            KeyCode::Char('h') => {
+                self.transition(AppMode::Help);
+            }
             KeyCode::Char(' ') => {
-                self.is_playing = !self.is_playing;
-                self.status_message = if self.is_playing { "Playing..." } else { "Paused" }.to_string();
+                self.playback.toggle();
+                self.status_message = if self.playback.playing { "Playing..." } else { "Paused" }.to_string();
             }
+            KeyCode::Left => self.playback.scrub(false),
+            KeyCode::Right => self.playback.scrub(true),
+            KeyCode::Enter => self.playback.commit_seek(),
+            KeyCode::Esc => self.playback.cancel_seek(),
+            KeyCode::Char('[') => self.playback.change_speed(false),
+            KeyCode::Char(']') => self.playback.change_speed(true),
+            KeyCode::Backspace => self.playback.reset_speed(),
+            KeyCode::Char('l') => self.playback.mark_repeat(),
+            KeyCode::Char('L') => self.playback.toggle_loop(),
             _ => {}
         }
     }
@@ -213,21 +780,60 @@ impl VideoPlayerApp {
     fn handle_help_key(&mut self, key: KeyCode, _modifiers: KeyModifiers) {
         match key {
             KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Esc | KeyCode::Char('h') => self.mode = AppMode::FileBrowser,
+            // Back to the browser or the player, whichever opened help
+            KeyCode::Esc | KeyCode::Char('h') => {
+                self.back();
+            }
             _ => {}
         }
     }
 
     fn render(&mut self, frame: &mut Frame) {
-        match self.mode {
+        #[cfg(feature = "terminal")]
+        if let Some(pane) = self.terminal.as_mut() {
+            let area = frame.area();
+            pane.render(frame, area, true);
+            return;
+        }
+
+        match self.mode.current() {
             AppMode::FileBrowser => self.render_file_browser(frame),
             AppMode::VideoPlayer => self.render_video_player(frame),
             AppMode::Help => self.render_help(frame),
         }
 
+        if self.tasks.is_busy() {
+            let size = frame.area();
+            let height = self.tasks.panel_height().min(size.height);
+            let area = Rect {
+                y: size.height.saturating_sub(height + 3),
+                height,
+                ..size
+            };
+            self.tasks.render(frame, area);
+        }
+
+        if self.locations.is_some() {
+            self.render_locations(frame);
+        }
+        let area = frame.area();
+        if let Some((input, _)) = &self.path_input {
+            input.render(frame, area);
+        }
+        if let Some((dialog, _)) = &self.rename {
+            dialog.render(frame, area);
+        }
+        if let Some((dialog, _)) = &self.confirm {
+            dialog.render(frame, area);
+        }
+
         if let Some(ref error) = self.error_message {
             self.render_error_popup(frame, error);
         }
+
+        self.toasts.render(frame, area);
+        self.finder.render(frame, area);
+        self.alerts.render(frame);
     }
 
     fn render_file_browser(&mut self, frame: &mut Frame) {
@@ -248,34 +854,66 @@ impl VideoPlayerApp {
             .style(Style::default().fg(Color::Cyan));
         frame.render_widget(title, chunks[0]);
 
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[1]);
+
         // File list - Fix: Store items in a local variable first
+        let name_width = panes[0].width.saturating_sub(2) as usize;
         let display_items = self.file_browser.items.iter().map(|item| {
             let path = self.file_browser.current_dir.join(item);
+            if self.file_browser.marked.contains(&path) {
+                return ListItem::new(format!("● {}", elide_middle(item, name_width.saturating_sub(2))))
+                    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+            }
             let style = if path.is_dir() {
                 Style::default().fg(Color::Blue)
-            } else {
+            } else if is_video_path(&path) {
                 Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::Gray)
             };
-            ListItem::new(item.clone()).style(style)
+            ListItem::new(elide_middle(item, name_width).into_owned()).style(style)
         }).collect::<Vec<_>>();
 
+        let list_title = match self.file_browser.marked.len() {
+            0 => "Files".to_string(),
+            count => format!("Files ({} marked)", count),
+        };
         let list = List::new(display_items)
-            .block(Block::default().borders(Borders::ALL).title("Files"))
+            .block(Block::default().borders(Borders::ALL).title(list_title))
             .highlight_style(Style::default().bg(Color::Blue).add_modifier(Modifier::BOLD));
 
-        frame.render_stateful_widget(list, chunks[1], &mut self.file_browser.list_state);
+        frame.render_stateful_widget(list, panes[0], &mut self.file_browser.list_state);
+
+        // Preview follows the highlighted entry; ".." is not previewed
+        match self.file_browser.selected_path() {
+            Some(path) if !path.ends_with("..") => self.preview.set_path(&path),
+            _ => self.preview.clear(),
+        }
+        if let Err(e) = self.preview.render(frame, panes[1]) {
+            self.toasts.report(&e);
+        }
 
         // Status
-        let current_dir = format!("Current: {}", self.file_browser.current_dir.display());
+        let status_width = chunks[2].width.saturating_sub(2) as usize;
+        let indexed = match self.indexed_videos.len() {
+            0 => String::new(),
+            count => format!(" | {} videos indexed", count),
+        };
+        let dir = self.file_browser.current_dir.display().to_string();
+        let dir_width = status_width.saturating_sub("Current: ".len() + indexed.len());
+        let current_dir = format!("Current: {}{}", elide_path(&dir, dir_width), indexed);
         let status = Paragraph::new(vec![
             Line::from(current_dir),
-            Line::from(self.status_message.clone()),
+            Line::from(truncate_end(&self.status_message, status_width).into_owned()),
         ])
         .block(Block::default().borders(Borders::ALL).title("Status"));
         frame.render_widget(status, chunks[2]);
     }
 
-    fn render_video_player(&self, frame: &mut Frame) {
+    fn render_video_player(&mut self, frame: &mut Frame) {
         let size = frame.area();  // Fix: Use frame.area()
 
         let chunks = Layout::default()
@@ -307,16 +945,35 @@ impl VideoPlayerApp {
             .wrap(Wrap { trim: true });
         frame.render_widget(video_placeholder, chunks[1]);
 
-        // Controls
-        let controls_text = vec![
-            Line::from(format!("Status: {}", if self.is_playing { "Playing" } else { "Paused" })),
-            Line::from("Controls: [Space] Play/Pause, [b] Back to browser, [q] Quit, [h] Help"),
-        ];
+        // Controls: status, seek bar and key help
+        let controls_block = Block::default().borders(Borders::ALL).title("Controls");
+        let rows = Layout::vertical([Constraint::Length(1); 3]).split(controls_block.inner(chunks[2]));
+        frame.render_widget(controls_block, chunks[2]);
+        let mut status = format!("Status: {}", if self.playback.playing { "Playing" } else { "Paused" });
+        if let Some(target) = self.playback.seek_target {
+            status.push_str(&format!(" | Seek to {} (Enter: jump, Esc: cancel)", format_clock(target)));
+        }
+        let modes = self.playback.mode_label();
+        if !modes.is_empty() {
+            status.push_str(" | ");
+            status.push_str(&modes);
+        }
+        frame.render_widget(Paragraph::new(status), rows[0]);
+        self.seek_bar_area = rows[1];
+        frame.render_widget(self.playback.seek_bar(), rows[1]);
+        frame.render_widget(
+            Paragraph::new("Controls: [Space] Play/Pause, [←/→] Seek, [[/]] Speed, [l] A-B, [L] Loop, [b] Back, [q] Quit, [h] Help")
+                .wrap(Wrap { trim: true }),
+            rows[2],
+        );
 
-        let controls = Paragraph::new(controls_text)
-            .block(Block::default().borders(Borders::ALL).title("Controls"))
-            .wrap(Wrap { trim: true });
-        frame.render_widget(controls, chunks[2]);
+        // Frame at the seek target, over the bottom of the video area
+        #[cfg(feature = "image")]
+        if let Some(target) = self.playback.seek_target {
+            let bar = self.seek_bar_area;
+            let x = bar.x + (self.playback.ratio_of(target) * bar.width as f64) as u16;
+            self.thumbnails.render(frame, chunks[1], x, chunks[1].bottom(), target);
+        }
     }
 
     fn render_help(&self, frame: &mut Frame) {
@@ -328,11 +985,31 @@ impl VideoPlayerApp {
             Line::from("File Browser:"),
             Line::from("  ↑/↓ - Navigate files"),
             Line::from("  Enter - Select file/directory"),
+            #[cfg(feature = "audio")]
+            Line::from("  Enter/p / s - Play or pause / stop the previewed audio clip"),
+            Line::from("  i - Index videos below this directory"),
+            Line::from("  Ctrl+L - Go to path (Tab completes)"),
+            Line::from("  b - Bookmark/unbookmark this directory"),
+            Line::from("  g - Bookmarks and recent locations"),
+            Line::from("  Space - Mark/unmark for bulk operations"),
+            Line::from("  c / m - Copy / move marked (or highlighted) items"),
+            Line::from("  r - Rename highlighted item"),
+            Line::from("  d / Delete - Move marked (or highlighted) items to trash"),
+            Line::from("  u - Undo the last trash while its toast is shown"),
+            Line::from("  D - Permanently delete marked (or highlighted) items"),
+            Line::from("  w - Toggle auto-refresh on file changes"),
+            #[cfg(feature = "terminal")]
+            Line::from("  t - Open terminal here (F2 closes it)"),
             Line::from("  h - Show this help"),
             Line::from("  q - Quit"),
             Line::from(""),
             Line::from("Video Player:"),
             Line::from("  Space - Play/Pause"),
+            Line::from("  ←/→ - Seek 5s, previewing the frame (Enter jumps now, Esc cancels)"),
+            Line::from("  Mouse over the seek bar - Preview that frame; click to jump"),
+            Line::from("  [ / ] - Slower / faster (0.25x to 2x, muted away from 1x); Backspace - Normal speed"),
+            Line::from("  l - Mark A, then B to repeat the range; again to clear"),
+            Line::from("  L - Loop the whole video"),
             Line::from("  b - Back to file browser"),
             Line::from("  h - Show this help"),
             Line::from("  q - Quit"),
@@ -350,6 +1027,39 @@ impl VideoPlayerApp {
         frame.render_widget(help, area);
     }
 
+    fn render_locations(&mut self, frame: &mut Frame) {
+        let entries = self.location_entries();
+        let area = centered_rect(70, 60, frame.area());
+        // Borders and the icon
+        let path_width = area.width.saturating_sub(4) as usize;
+        let items: Vec<ListItem> = if entries.is_empty() {
+            vec![ListItem::new("No bookmarks or recent locations yet").style(Style::default().fg(Color::DarkGray))]
+        } else {
+            entries
+                .iter()
+                .map(|(path, bookmarked)| {
+                    let (icon, color) = if *bookmarked { ("★", Color::Yellow) } else { ("↺", Color::Gray) };
+                    let path = path.display().to_string();
+                    ListItem::new(format!("{} {}", icon, elide_path(&path, path_width))).style(Style::default().fg(color))
+                })
+                .collect()
+        };
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Locations ")
+                    .title_bottom(" Enter: go | d: remove bookmark | Esc: close "),
+            )
+            .highlight_style(Style::default().bg(Color::Blue).add_modifier(Modifier::BOLD));
+
+        frame.render_widget(Clear, area);
+        if let Some(state) = self.locations.as_mut() {
+            frame.render_stateful_widget(list, area, state);
+        }
+    }
+
     fn render_error_popup(&self, frame: &mut Frame, error: &str) {
         let size = frame.area();  // Fix: Use frame.area()
         let area = centered_rect(60, 20, size);
@@ -365,6 +1075,82 @@ impl VideoPlayerApp {
     }
 }
 
+impl Transitions<AppMode> for VideoPlayerApp {
+    fn machine(&mut self) -> &mut StateMachine<AppMode> {
+        &mut self.mode
+    }
+
+    /// The player needs a video to show
+    fn guard(&self, _from: AppMode, to: AppMode) -> bool {
+        to != AppMode::VideoPlayer || self.current_video_path.is_some()
+    }
+
+    /// Playback stops when going back to the browser, not for help
+    fn on_exit(&mut self, from: AppMode, to: AppMode) {
+        if from == AppMode::VideoPlayer && to == AppMode::FileBrowser {
+            self.playback.stop();
+            self.playback.cancel_seek();
+        }
+    }
+
+    fn on_enter(&mut self, to: AppMode, from: AppMode) {
+        match (to, &self.current_video_path) {
+            (AppMode::VideoPlayer, Some(path)) if from != AppMode::Help => {
+                self.status_message = format!("Selected: {}", path.display());
+                self.playback = PlaybackClock::new();
+                let (sender, receiver) = mpsc::channel();
+                probe_duration(path, move |duration| {
+                    let _ = sender.send(duration);
+                });
+                self.duration_probe = Some(receiver);
+                if let Err(e) = self.config.remember_file(path) {
+                    self.show_error(format!("Failed to save recent files: {}", e));
+                }
+            }
+            (AppMode::FileBrowser, _) => {
+                self.status_message = "Navigate with arrows, Enter to select, 'q' to quit".to_string();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Recursively collect video files, reporting progress per directory
+fn index_videos(root: &Path, handle: &TaskHandle<TaskResult>) -> Result<Vec<PathBuf>, String> {
+    let mut pending = vec![root.to_path_buf()];
+    let mut visited = 0usize;
+    let mut videos = Vec::new();
+
+    while let Some(dir) = pending.pop() {
+        if handle.is_cancelled() {
+            return Err("cancelled".to_string());
+        }
+
+        // Unreadable directories are skipped rather than failing the index
+        if let Ok(entries) = fs::read_dir(&dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if is_video_path(&path) {
+                    videos.push(path);
+                }
+            }
+        }
+
+        visited += 1;
+        let ratio = visited as f64 / (visited + pending.len()) as f64;
+        handle.report_with(ratio, format!("{} dirs, {} videos", visited, videos.len()));
+    }
+
+    videos.sort();
+    Ok(videos)
+}
+
+fn is_video_path(path: &Path) -> bool {
+    FileKind::from_path(path) == FileKind::Video
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -385,20 +1171,76 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+#[derive(Debug, Parser)]
+#[command(version, about = "Browse and preview videos and other files")]
+struct Cli {
+    /// Video to open at start
+    video: Option<PathBuf>,
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+fn main() -> ExitCode {
+    cli::exit(run())
+}
+
+fn run() -> Result<(), DemoError> {
+    let args = cli::parse_with(|args: &Cli| &args.common);
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.common.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = VideoPlayerApp::new();
+    let mut app = VideoPlayerApp::new(args.common.config());
+    if let Some(path) = args.video.clone() {
+        app.open_video(path);
+    }
 
     loop {
+        app.poll_tasks();
+        app.finder.poll();
+        if let Err(e) = app.preview.poll() {
+            app.toasts.report(&e);
+        }
+        app.toasts.prune();
+        app.poll_watcher();
+        app.poll_player();
+        #[cfg(feature = "terminal")]
+        if app.terminal.as_ref().is_some_and(|pane| !pane.is_alive()) {
+            app.terminal = None;
+        }
+        app.alerts.ring(terminal.backend_mut())?;
         terminal.draw(|f| app.render(f))?;
 
-        if let Event::Key(key) = event::read()? {
-            app.on_key(key.code, key.modifiers);
+        if event::poll(Duration::from_millis(100))? {
+            let event = event::read()?;
+            if let Event::Mouse(mouse) = event {
+                app.on_mouse(mouse);
+            }
+            if let Event::Key(key) = event {
+                #[cfg(feature = "terminal")]
+                if let Some(pane) = app.terminal.as_mut() {
+                    if key.code == KeyCode::F(2) {
+                        app.terminal = None;
+                    } else {
+                        pane.handle_key(key);
+                    }
+                    continue;
+                }
+
+                if app.finder.visible {
+                    if let FinderAction::Open(path, _) = app.finder.handle_key(key) {
+                        app.open_video(path);
+                    }
+                } else if FileFinder::is_shortcut(&key) {
+                    app.finder.open();
+                } else {
+                    app.on_key(key.code, key.modifiers);
+                }
+            }
         }
 
         if app.should_quit {
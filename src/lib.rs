@@ -1,7 +1,16 @@
 pub mod app;
+pub mod error;
+pub mod event;
+pub mod platform;
+pub mod snapshot;
+#[cfg(feature = "image")]
 pub mod ui;
+pub mod web;
 pub mod widgets;
 
 // Re-export commonly used components
-pub use widgets::table::{InteractiveTable, TableData};
-pub use widgets::table::{SelectComponent, SelectOption, SelectType};
+pub use error::DemoError;
+pub use widgets::component::Component;
+pub use widgets::emoji::EmojiPicker;
+pub use widgets::table::{InteractiveTable, InteractiveTableBuilder, TableData};
+pub use widgets::table::{SelectComponent, SelectComponentBuilder, SelectOption, SelectType};
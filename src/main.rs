@@ -1,23 +1,29 @@
 use std::io;
+use std::process::ExitCode;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers},
+    event::{self, DisableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph, Table, Row, Cell},
-    Terminal,
+    widgets::{Block, Borders, Paragraph, Table, TableState, Row, Cell},
+    Frame, Terminal,
 };
+use tui_image_viewer::app::cli::{self, CommonArgs};
+use tui_image_viewer::error::DemoError;
+use tui_image_viewer::widgets::component::{Stateful, StatefulScreen};
+use tui_image_viewer::widgets::runner::{run_built_binary, ProcessRunner};
+use tui_image_viewer::widgets::state_machine::{State, StateMachine, Transitions};
 
 // For clipboard functionality
 struct Clipboard;
 
 impl Clipboard {
-    fn new() -> Result<Self, Box<dyn std::error::Error>> {
+    fn new() -> Result<Self, DemoError> {
         Ok(Self)
     }
     
@@ -30,7 +36,7 @@ impl Clipboard {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum Mode {
     Table,
     Image,
@@ -38,49 +44,142 @@ enum Mode {
     Form,
 }
 
+impl Mode {
+    /// Mode switched to with the number keys 1 to 4
+    fn from_digit(c: char) -> Option<Self> {
+        match c {
+            '1' => Some(Mode::Table),
+            '2' => Some(Mode::Image),
+            '3' => Some(Mode::Input),
+            '4' => Some(Mode::Form),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Mode::Table => "Table",
+            Mode::Image => "Image",
+            Mode::Input => "Input",
+            Mode::Form => "Form",
+        }
+    }
+}
+
+impl State for Mode {}
+
+/// Table mode's feature overview; the selection lives in `App::features`
+struct FeatureTable {
+    rows: Vec<[&'static str; 3]>,
+}
+
+impl FeatureTable {
+    fn new() -> Self {
+        Self {
+            rows: vec![
+                ["Table", "Widget", "Sorting, Selection"],
+                ["Image", "High-Res", "Kitty/Sixel"],
+                ["Input", "Text/Emoji", "Clipboard, Links"],
+            ],
+        }
+    }
+}
+
+impl StatefulScreen for FeatureTable {
+    type State = TableState;
+
+    fn handle_key(&self, key: KeyEvent, state: &mut TableState) -> bool {
+        let last = self.rows.len().saturating_sub(1);
+        let selected = state.selected();
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => state.select(Some(selected.map_or(0, |i| (i + 1).min(last)))),
+            KeyCode::Up | KeyCode::Char('k') => state.select(Some(selected.map_or(0, |i| i.saturating_sub(1)))),
+            _ => return false,
+        }
+        true
+    }
+
+    fn render(&self, f: &mut Frame, area: Rect, state: &mut TableState) {
+        let header = Row::new(["Feature", "Type", "Supports"].map(|title| {
+            Cell::from(Span::styled(title, Style::default().add_modifier(Modifier::BOLD)))
+        }));
+        let rows = self.rows.iter().map(|row| Row::new(row.map(Cell::from)));
+
+        let column_widths = [
+            Constraint::Length(10),
+            Constraint::Length(15),
+            Constraint::Length(25),
+        ];
+
+        let table = Table::new(rows, column_widths)
+            .header(header)
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL).title("Feature Table (↑↓ to select)"));
+        f.render_stateful_widget(table, area, state);
+    }
+}
+
 struct App {
-    mode: Mode,
+    mode: StateMachine<Mode>,
+    features: Stateful<FeatureTable>,
     status: String,
     input: String,
     clipboard: Clipboard,
     emoji_picker: Vec<&'static str>,
     emoji_index: usize,
     link: String,
+    form_build: Option<ProcessRunner>,
 }
 
 impl App {
-    fn new() -> Result<Self, Box<dyn std::error::Error>> {
+    fn new() -> Result<Self, DemoError> {
         Ok(Self {
-            mode: Mode::Table,
+            mode: StateMachine::new(Mode::Table),
+            features: Stateful::new(FeatureTable::new()),
             status: "Press 1:Table 2:Image 3:Input 4:Form | q:Quit".to_string(),
             input: String::new(),
             clipboard: Clipboard::new()?,
             emoji_picker: vec!["😀", "😂", "🥳", "🚀", "❤️", "👍", "🔥", "🎉"],
             emoji_index: 0,
             link: "https://ratatui.rs".to_string(),
+            form_build: None,
         })
     }
 
     fn on_key(&mut self, key: KeyEvent) {
-        match self.mode {
-            Mode::Table => match key.code {
-                KeyCode::Char('2') => self.mode = Mode::Image,
-                KeyCode::Char('3') => self.mode = Mode::Input,
-                KeyCode::Char('4') => self.mode = Mode::Form,
-                KeyCode::Char('q') => self.status = "quit".to_string(),
-                _ => {}
-            },
-            Mode::Image => match key.code {
-                KeyCode::Char('1') => self.mode = Mode::Table,
-                KeyCode::Char('3') => self.mode = Mode::Input,
-                KeyCode::Char('4') => self.mode = Mode::Form,
-                KeyCode::Char('q') => self.status = "quit".to_string(),
-                _ => {}
-            },
+        // Build output pane keys while the interactive form is compiling
+        if let Some(runner) = self.form_build.as_mut() {
+            if key.code == KeyCode::Esc {
+                self.form_build = None;
+                self.status = "Form launch cancelled".to_string();
+            } else {
+                runner.handle_key(key.code);
+            }
+            return;
+        }
+
+        // Number keys switch modes everywhere; the current mode's own digit
+        // is typed in Input mode
+        if let KeyCode::Char(c) = key.code {
+            if Mode::from_digit(c).is_some_and(|mode| self.transition(mode)) {
+                return;
+            }
+        }
+
+        match self.mode.current() {
+            Mode::Table => {
+                if key.code == KeyCode::Char('q') {
+                    self.status = "quit".to_string();
+                } else {
+                    self.features.handle_key(key);
+                }
+            }
+            Mode::Image => {
+                if key.code == KeyCode::Char('q') {
+                    self.status = "quit".to_string();
+                }
+            }
             Mode::Input => match key.code {
-                KeyCode::Char('1') => self.mode = Mode::Table,
-                KeyCode::Char('2') => self.mode = Mode::Image,
-                KeyCode::Char('4') => self.mode = Mode::Form,
                 KeyCode::Char('q') => self.status = "quit".to_string(),
                 KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                     self.clipboard.set_text(self.input.clone());
@@ -108,13 +207,13 @@ impl App {
                 _ => {}
             },
             Mode::Form => match key.code {
-                KeyCode::Char('1') => self.mode = Mode::Table,
-                KeyCode::Char('2') => self.mode = Mode::Image,
-                KeyCode::Char('3') => self.mode = Mode::Input,
                 KeyCode::Char('q') => self.status = "quit".to_string(),
                 KeyCode::Enter => {
-                    // Launch interactive form
-                    self.status = "Launching interactive form...".to_string();
+                    // Build the interactive form; it launches once the build succeeds
+                    let mut runner = ProcessRunner::cargo_build("interactive_form");
+                    runner.start();
+                    self.form_build = Some(runner);
+                    self.status = "Building interactive form...".to_string();
                 },
                 _ => {}
             },
@@ -122,7 +221,17 @@ impl App {
     }
 }
 
-fn ui(f: &mut ratatui::Frame, app: &App) {
+impl Transitions<Mode> for App {
+    fn machine(&mut self) -> &mut StateMachine<Mode> {
+        &mut self.mode
+    }
+
+    fn on_enter(&mut self, to: Mode, _from: Mode) {
+        self.status = format!("{} mode | 1:Table 2:Image 3:Input 4:Form | q:Quit", to.name());
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut App) {
     let size = f.area();
     
     let chunks = Layout::default()
@@ -142,41 +251,8 @@ fn ui(f: &mut ratatui::Frame, app: &App) {
     f.render_widget(title, chunks[0]);
     
     // Content based on mode
-    match app.mode {
-        Mode::Table => {
-            let rows = vec![
-                Row::new(vec![
-                    Cell::from(Span::styled("Feature", Style::default().add_modifier(Modifier::BOLD))),
-                    Cell::from(Span::styled("Type", Style::default().add_modifier(Modifier::BOLD))),
-                    Cell::from(Span::styled("Supports", Style::default().add_modifier(Modifier::BOLD))),
-                ]),
-                Row::new(vec![
-                    Cell::from("Table"),
-                    Cell::from("Widget"),
-                    Cell::from("Sorting, Selection"),
-                ]),
-                Row::new(vec![
-                    Cell::from("Image"),
-                    Cell::from("High-Res"),
-                    Cell::from("Kitty/Sixel"),
-                ]),
-                Row::new(vec![
-                    Cell::from("Input"),
-                    Cell::from("Text/Emoji"),
-                    Cell::from("Clipboard, Links"),
-                ]),
-            ];
-            
-            let column_widths = [
-                Constraint::Length(10),
-                Constraint::Length(15),
-                Constraint::Length(25),
-            ];
-            
-            let table = Table::new(rows, column_widths)
-                .block(Block::default().borders(Borders::ALL).title("Feature Table"));
-            f.render_widget(table, chunks[1]);
-        },
+    match app.mode.current() {
+        Mode::Table => app.features.render(f, chunks[1]),
         Mode::Image => {
             let content = Paragraph::new("Press 'h' to show the image in high resolution using viuer.\n\nMake sure your terminal supports Kitty or Sixel graphics protocols for best results.\n\nPress 1:Table 3:Input 4:Form q:Quit")
                 .block(Block::default().borders(Borders::ALL).title("Image Mode"));
@@ -210,6 +286,9 @@ fn ui(f: &mut ratatui::Frame, app: &App) {
             let content = Paragraph::new("Press Enter to open the interactive form\n\nThis will open a separate screen with a complete form interface.\n\nPress 1:Table 2:Image 3:Input q:Quit")
                 .block(Block::default().borders(Borders::ALL).title("Interactive Form Mode"));
             f.render_widget(content, chunks[1]);
+            if let Some(runner) = app.form_build.as_mut() {
+                runner.render(f, chunks[1]);
+            }
         }
     }
     
@@ -220,11 +299,13 @@ fn ui(f: &mut ratatui::Frame, app: &App) {
     f.render_widget(status, chunks[2]);
 }
 
-fn run_app() -> Result<(), Box<dyn std::error::Error>> {
+fn run_app(args: &CommonArgs) -> Result<(), DemoError> {
     // Terminal initialization
+    cli::require_terminal()?;
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    args.capture_mouse()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -232,41 +313,31 @@ fn run_app() -> Result<(), Box<dyn std::error::Error>> {
     let mut app = App::new()?;
 
     loop {
+        // Hand the terminal to the interactive form once its build succeeds
+        if let Some(runner) = app.form_build.as_mut() {
+            runner.poll();
+            if runner.succeeded() {
+                app.form_build = None;
+                let status = run_built_binary("interactive_form", &[])?;
+                if args.no_mouse {
+                    execute!(terminal.backend_mut(), DisableMouseCapture)?;
+                }
+                app.status = if status.success() {
+                    "Interactive form closed".to_string()
+                } else {
+                    "Interactive form exited with error".to_string()
+                };
+                terminal.clear()?;
+            }
+        }
+
         // Draw UI
-        terminal.draw(|f| ui(f, &app))?;
+        terminal.draw(|f| ui(f, &mut app))?;
 
         // Handle input
         if event::poll(std::time::Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 app.on_key(key);
-                
-                // Special handling for Form mode and Enter key
-                if app.mode == Mode::Form && key.code == KeyCode::Enter {
-                    // Save terminal state
-                    disable_raw_mode()?;
-                    execute!(
-                        terminal.backend_mut(),
-                        LeaveAlternateScreen,
-                        DisableMouseCapture
-                    )?;
-                    
-                    // Launch the interactive form as a separate process
-                    let status = std::process::Command::new("cargo")
-                        .args(["run", "--bin", "interactive_form"])
-                        .status()?;
-                        
-                    if !status.success() {
-                        eprintln!("Interactive form exited with error");
-                    }
-                    
-                    // Restore terminal state
-                    enable_raw_mode()?;
-                    execute!(
-                        terminal.backend_mut(),
-                        EnterAlternateScreen, 
-                        EnableMouseCapture
-                    )?;
-                }
             }
         }
 
@@ -288,6 +359,20 @@ fn run_app() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    run_app()
+fn main() -> ExitCode {
+    let args = cli::parse();
+    cli::exit(run_app(&args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_image_viewer::snapshot;
+
+    #[test]
+    fn test_snapshots() {
+        snapshot::isolate();
+        let mut app = App::new().unwrap();
+        snapshot::assert_screen("main", |f| ui(f, &mut app));
+    }
 }
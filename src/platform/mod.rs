@@ -0,0 +1,56 @@
+//! Platform module
+//!
+//! The process, launcher and background work the widgets need, behind one
+//! trait so the same widgets build for the terminal and for the browser.
+//! Widgets call `current()` instead of `std::process` and `std::thread`:
+//! natively that runs programs and work on background threads, in the
+//! browser it opens URLs in a new tab, runs work in place since there are no
+//! threads, and reports programs as unavailable.
+
+use std::sync::mpsc::Sender;
+
+use crate::widgets::runner::OutputLine;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod native;
+#[cfg(target_arch = "wasm32")]
+pub mod web;
+
+/// Callback receiving the outcome of background work
+pub type Done<T> = Box<dyn FnOnce(Result<T, String>) + Send>;
+
+/// A program started by `Platform::spawn`
+pub trait Process: Send {
+    /// `Some(code)` once the program has exited, with `code` `None` when it
+    /// was terminated by a signal; `None` while it is still running
+    fn try_wait(&mut self) -> Result<Option<Option<i32>>, String>;
+
+    /// Stop the program and wait for it
+    fn kill(&mut self);
+}
+
+pub trait Platform: Send + Sync {
+    /// Open a URL or path with whatever the platform uses for it, off the
+    /// calling thread, and pass how the launcher exited to `done`
+    fn open(&self, target: &str, done: Done<()>);
+
+    /// Run `program` to completion off the calling thread and pass its
+    /// stdout to `done`. A failed run is passed as an error with stderr.
+    fn run_tool(&self, program: &str, args: &[String], done: Done<Vec<u8>>);
+
+    /// Run `work` off the calling thread. The browser has no threads, so
+    /// there it runs before this returns; hand results back via a channel
+    /// either way.
+    fn background(&self, work: Box<dyn FnOnce() + Send>);
+
+    /// Start `program`, sending each line it prints to `output`
+    fn spawn(&self, program: &str, args: &[String], output: Sender<OutputLine>) -> Result<Box<dyn Process>, String>;
+}
+
+/// The platform this build runs on
+pub fn current() -> &'static dyn Platform {
+    #[cfg(not(target_arch = "wasm32"))]
+    return &native::NativePlatform;
+    #[cfg(target_arch = "wasm32")]
+    return &web::WebPlatform;
+}
@@ -0,0 +1,166 @@
+//! Native platform: `std::process` with pipes read on background threads
+
+use std::io::{self, BufRead, BufReader, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::Sender;
+use std::thread;
+
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use super::{Done, Platform, Process};
+use crate::widgets::runner::{OutputLine, OutputStream};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NativePlatform;
+
+impl Platform for NativePlatform {
+    /// Runs the launcher on a thread and reports how it exited, so a missing
+    /// handler shows up as an error rather than nothing happening
+    fn open(&self, target: &str, done: Done<()>) {
+        let mut command = launcher(target);
+        let program = command.get_program().to_string_lossy().into_owned();
+        let target = target.to_string();
+        thread::spawn(move || {
+            let output = command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::piped()).output();
+            let result = match output {
+                Ok(output) if output.status.success() => Ok(()),
+                Ok(output) => {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    let reason = stderr.lines().next().map(str::to_string).unwrap_or_else(|| output.status.to_string());
+                    Err(format!("{} could not open {}: {}", program, target, reason))
+                }
+                Err(e) => Err(format!("failed to run {} for {}: {}", program, target, e)),
+            };
+            done(result);
+        });
+    }
+
+    fn run_tool(&self, program: &str, args: &[String], done: Done<Vec<u8>>) {
+        let mut command = Command::new(program);
+        command.args(args);
+        let program = program.to_string();
+        thread::spawn(move || {
+            let result = match command.output() {
+                Ok(output) if output.status.success() => Ok(output.stdout),
+                Ok(output) => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+                Err(e) => Err(format!("{} unavailable: {}", program, e)),
+            };
+            done(result);
+        });
+    }
+
+    fn background(&self, work: Box<dyn FnOnce() + Send>) {
+        thread::spawn(work);
+    }
+
+    fn spawn(&self, program: &str, args: &[String], output: Sender<OutputLine>) -> Result<Box<dyn Process>, String> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("{}: {}", program, e))?;
+        if let Some(stdout) = child.stdout.take() {
+            forward_lines(stdout, OutputStream::Stdout, output.clone());
+        }
+        if let Some(stderr) = child.stderr.take() {
+            forward_lines(stderr, OutputStream::Stderr, output);
+        }
+        Ok(Box::new(NativeProcess(child)))
+    }
+}
+
+fn launcher(target: &str) -> Command {
+    // `cmd /C start` would parse `&` and `^` in URLs, so go through the URL
+    // handler, which opens URLs, files and folders alike
+    #[cfg(target_os = "windows")]
+    let command = {
+        let mut command = Command::new("rundll32");
+        command.args(["url.dll,FileProtocolHandler", target]);
+        command
+    };
+
+    #[cfg(target_os = "macos")]
+    let command = {
+        let mut command = Command::new("open");
+        command.arg(target);
+        command
+    };
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let command = {
+        let mut command = Command::new("xdg-open");
+        command.arg(target);
+        command
+    };
+
+    command
+}
+
+struct NativeProcess(Child);
+
+impl Process for NativeProcess {
+    fn try_wait(&mut self) -> Result<Option<Option<i32>>, String> {
+        self.0
+            .try_wait()
+            .map(|status| status.map(|status| status.code()))
+            .map_err(|e| e.to_string())
+    }
+
+    fn kill(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Read `pipe` line by line on a background thread
+fn forward_lines(pipe: impl Read + Send + 'static, stream: OutputStream, sender: Sender<OutputLine>) {
+    thread::spawn(move || {
+        for text in BufReader::new(pipe).lines().map_while(Result::ok) {
+            if sender.send(OutputLine { stream, text }).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Hand the terminal to an interactive binary built by a `ProcessRunner`.
+/// Uses `cargo run --quiet` so an up-to-date build prints nothing.
+pub fn run_built_binary(binary_name: &str, args: &[&str]) -> io::Result<ExitStatus> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+
+    let status = Command::new("cargo")
+        .args(["run", "--quiet", "--bin", binary_name, "--"])
+        .args(args)
+        .status();
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_run_tool_reports_a_missing_program() {
+        let (sender, receiver) = mpsc::channel();
+        NativePlatform.run_tool(
+            "definitely-not-a-real-program",
+            &[],
+            Box::new(move |result| {
+                let _ = sender.send(result);
+            }),
+        );
+        let error = receiver.recv().unwrap().unwrap_err();
+        assert!(error.starts_with("definitely-not-a-real-program unavailable"));
+    }
+}
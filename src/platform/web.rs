@@ -0,0 +1,37 @@
+//! Browser platform: URLs open in a new tab, there are no processes
+
+use std::sync::mpsc::Sender;
+
+use super::{Done, Platform, Process};
+use crate::widgets::runner::OutputLine;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WebPlatform;
+
+fn unavailable(program: &str) -> String {
+    format!("{} cannot run in the browser", program)
+}
+
+impl Platform for WebPlatform {
+    fn open(&self, target: &str, done: Done<()>) {
+        let result = match web_sys::window().map(|window| window.open_with_url_and_target(target, "_blank")) {
+            None => Err("no browser window".to_string()),
+            Some(Ok(Some(_))) => Ok(()),
+            Some(Ok(None)) => Err(format!("the browser blocked opening {}", target)),
+            Some(Err(_)) => Err(format!("failed to open {}", target)),
+        };
+        done(result);
+    }
+
+    fn run_tool(&self, program: &str, _args: &[String], done: Done<Vec<u8>>) {
+        done(Err(unavailable(program)));
+    }
+
+    fn background(&self, work: Box<dyn FnOnce() + Send>) {
+        work();
+    }
+
+    fn spawn(&self, program: &str, _args: &[String], _output: Sender<OutputLine>) -> Result<Box<dyn Process>, String> {
+        Err(unavailable(program))
+    }
+}
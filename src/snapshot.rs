@@ -0,0 +1,149 @@
+//! Golden-file snapshots of example screens
+//!
+//! A screen's test draws it into a `TestBackend` at each of `SIZES` and
+//! compares the result with `snapshots/<name>-<width>x<height>.snap`: the
+//! text of the screen followed by every run of styled cells, so both layout
+//! and theme changes show up as reviewable diffs. A missing or different
+//! snapshot fails the test, with the output written next to it as
+//! `.snap.new`. Run with `UPDATE_SNAPSHOTS=1` to record new screens or
+//! accept changed ones.
+//!
+//! `isolate` points the config and data directories at an empty directory
+//! so snapshots don't depend on the user's settings or saved files. Screens
+//! showing the current time, live data or media files (dashboard, calendar,
+//! todo due dates, timers, pomodoro, ticker, feeds, ASCII art, image and
+//! video viewers) and the main menu, whose banner depends on the session,
+//! are not snapshotted.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Once;
+
+use ratatui::{
+    backend::TestBackend,
+    buffer::{Buffer, Cell},
+    style::Style,
+    Frame, Terminal,
+};
+
+use crate::widgets::report;
+
+/// Canonical terminal sizes: narrow, the classic 80x24 and a large window
+pub const SIZES: [(u16, u16); 3] = [(60, 20), (80, 24), (140, 40)];
+
+/// Use an empty config and data directory for the rest of the process
+pub fn isolate() {
+    static ISOLATE: Once = Once::new();
+    ISOLATE.call_once(|| {
+        let home = std::env::temp_dir().join(format!("ratatui-snapshots-{}", std::process::id()));
+        let _ = fs::create_dir_all(&home);
+        std::env::set_var("XDG_CONFIG_HOME", home.join("config"));
+        std::env::set_var("XDG_DATA_HOME", home.join("data"));
+    });
+}
+
+/// Draw once into a `width` x `height` test terminal
+pub fn render(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).expect("test backend");
+    terminal.draw(draw).expect("draw to test backend");
+    terminal.backend().buffer().clone()
+}
+
+/// Text of the buffer, then one line per run of same-styled cells that
+/// differ from the default style
+pub fn to_snapshot(buffer: &Buffer) -> String {
+    let mut snapshot = report::to_text(buffer);
+    snapshot.push_str("--- styles ---\n");
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        let mut run: Option<(u16, Style)> = None;
+        for x in area.left()..=area.right() {
+            let style = buffer.cell((x, y)).map(Cell::style);
+            if let Some((start, current)) = run {
+                if style == Some(current) {
+                    continue;
+                }
+                if current != Style::default() {
+                    let _ = writeln!(snapshot, "{}:{}-{} {:?}", y, start, x - 1, current);
+                }
+            }
+            run = style.map(|style| (x, style));
+        }
+    }
+    snapshot
+}
+
+/// Compare `buffer` with the golden file `snapshots/<name>.snap`
+pub fn assert_snapshot(name: &str, buffer: &Buffer) {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("snapshots");
+    let path = dir.join(format!("{}.snap", name));
+    let actual = to_snapshot(buffer);
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+
+    let new_path = path.with_extension("snap.new");
+    match fs::read_to_string(&path) {
+        Ok(expected) if expected == actual => {}
+        Err(_) if !update => {
+            let _ = fs::create_dir_all(&dir);
+            let _ = fs::write(&new_path, &actual);
+            panic!(
+                "snapshot {} is missing; review {} and rerun with UPDATE_SNAPSHOTS=1 to record it",
+                name,
+                new_path.display()
+            );
+        }
+        Ok(expected) if !update => {
+            let _ = fs::write(&new_path, &actual);
+            let line = expected
+                .lines()
+                .zip(actual.lines())
+                .position(|(expected, actual)| expected != actual)
+                .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+            panic!(
+                "snapshot {} changed at line {}; review {} and rerun with UPDATE_SNAPSHOTS=1 to accept it",
+                name,
+                line + 1,
+                new_path.display()
+            );
+        }
+        _ => {
+            fs::create_dir_all(&dir).expect("create snapshots directory");
+            fs::write(&path, actual).expect("write snapshot");
+            let _ = fs::remove_file(&new_path);
+        }
+    }
+}
+
+/// Snapshot a screen at every size in `SIZES`, as `<name>-<width>x<height>`
+pub fn assert_screen(name: &str, mut draw: impl FnMut(&mut Frame)) {
+    isolate();
+    for (width, height) in SIZES {
+        let buffer = render(width, height, &mut draw);
+        assert_snapshot(&format!("{}-{}x{}", name, width, height), &buffer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{
+        style::{Color, Modifier},
+        widgets::{Block, Borders, Paragraph},
+    };
+
+    #[test]
+    fn test_snapshot_format() {
+        let buffer = render(12, 3, |f| {
+            let block = Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan));
+            let text = Paragraph::new("hi").style(Style::default().add_modifier(Modifier::BOLD)).block(block);
+            f.render_widget(text, f.area());
+        });
+        let snapshot = to_snapshot(&buffer);
+        let (text, styles) = snapshot.split_once("--- styles ---\n").unwrap();
+        assert_eq!(text, "┌──────────┐\n│hi        │\n└──────────┘\n");
+        assert!(styles.starts_with("0:0-11 "));
+        assert!(styles.contains("1:1-10 "));
+        assert_eq!(styles.lines().count(), 5);
+    }
+}
@@ -1,17 +1,17 @@
+pub mod tabs;
+
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    text::Line,
-    widgets::{Block, Borders, Paragraph, Tabs},
+    layout::{Constraint, Direction, Layout},
     Frame,
 };
 
 use crate::app::App;
-use crate::widgets::image::{ImageWidget, ImageQuality};
-use crate::widgets::input::InputWidget;
+use crate::widgets::toast::ToastManager;
+use tabs::TabManager;
 
-/// Main UI rendering function
-pub fn ui(frame: &mut Frame, app: &mut App) {
+/// Main UI rendering function; failures of the active screen are shown as
+/// error toasts
+pub fn ui(frame: &mut Frame, app: &mut App, tabs: &mut TabManager, toasts: &mut ToastManager) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -20,44 +20,17 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         ])
         .split(frame.area());
 
-    // Get theme colors - use app.active_tab if it exists, otherwise default to 0
-    let active_tab = app.active_tab; // Direct access if it's usize
-    let primary_color = app.primary_color();
-
     // Render tabs
-    let titles = ["Table", "Image", "User Input"];
-    let tab_titles: Vec<Line> = titles.iter().map(|t| Line::from(*t)).collect();
-    let tabs = Tabs::new(tab_titles)
-        .block(Block::default().borders(Borders::ALL))
-        .select(active_tab)
-        .highlight_style(Style::default().fg(Color::Black).bg(primary_color));
-    frame.render_widget(tabs, chunks[0]);
-
-    // Render content based on active tab
-    match active_tab {
-        0 => render_table(frame, chunks[1]),
-        1 => render_image(frame, chunks[1]),
-        2 => render_input(frame, chunks[1]),
-        _ => {}
+    tabs.render_bar(frame, chunks[0], app.primary_color());
+
+    // Render the active tab's screen
+    if let Some(screen) = tabs.active_screen_mut() {
+        if let Err(e) = screen.update() {
+            toasts.report(&e);
+        }
+        if let Err(e) = screen.render(frame, chunks[1]) {
+            toasts.report(&e);
+        }
     }
-}
-
-/// Render table widget
-fn render_table(frame: &mut Frame, area: Rect) {
-    let paragraph = Paragraph::new("Table view - Coming soon!")
-        .block(Block::default().borders(Borders::ALL).title("Table"))
-        .style(Style::default().fg(Color::White));
-    frame.render_widget(paragraph, area);
-}
-
-/// Render image widget  
-fn render_image(frame: &mut Frame, area: Rect) {
-    let mut widget = ImageWidget::with_quality(ImageQuality::Medium);
-    let _ = widget.render(frame, area);
-}
-
-/// Render input widget
-fn render_input(frame: &mut Frame, area: Rect) {
-    let input_widget = InputWidget::new();
-    let _ = input_widget.render(frame, area);
+    toasts.render(frame, frame.area());
 }
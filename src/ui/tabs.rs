@@ -0,0 +1,321 @@
+//! Tab manager module
+//!
+//! Dynamic tab bar where each tab hosts its own `Screen`, so switching tabs
+//! keeps table selection, loaded images and input state intact.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Tabs},
+    Frame,
+};
+
+use crate::error::DemoError;
+use crate::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use crate::widgets::component::Component;
+use crate::widgets::image::{ImageQuality, ImageWidget};
+use crate::widgets::input::InputWidget;
+use crate::widgets::table::{create_demo_table, InteractiveTable};
+
+/// Content hosted by a tab, owning that tab's state
+pub enum Screen {
+    Table(InteractiveTable),
+    Image(ImageWidget),
+    Input(InputWidget),
+}
+
+impl Screen {
+    pub fn table() -> Self {
+        Self::Table(create_demo_table())
+    }
+
+    pub fn image() -> Self {
+        Self::Image(ImageWidget::with_quality(ImageQuality::Medium))
+    }
+
+    pub fn input() -> Self {
+        Self::Input(InputWidget::new())
+    }
+
+    /// Default tab title for this kind of screen
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Table(_) => "Table",
+            Self::Image(_) => "Image",
+            Self::Input(_) => "User Input",
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.component().handle_event(&Event::Key(key))
+    }
+
+    fn component(&mut self) -> &mut dyn Component {
+        match self {
+            Self::Table(table) => table,
+            Self::Image(widget) => widget,
+            Self::Input(widget) => widget,
+        }
+    }
+
+    /// Pick up background work, e.g. a finished image decode
+    pub fn update(&mut self) -> Result<(), DemoError> {
+        self.component().update()
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError> {
+        self.component().render(frame, area)
+    }
+}
+
+pub struct Tab {
+    pub title: String,
+    pub screen: Screen,
+}
+
+/// Pending tab-bar prompt
+#[derive(Debug, Clone, PartialEq)]
+enum TabPrompt {
+    /// Choosing the screen for a new tab
+    NewTab,
+    Rename(String),
+}
+
+pub struct TabManager {
+    pub tabs: Vec<Tab>,
+    pub active: usize,
+    prompt: Option<TabPrompt>,
+}
+
+impl Default for TabManager {
+    fn default() -> Self {
+        let mut manager = Self::new();
+        manager.open(Screen::table());
+        manager.open(Screen::image());
+        manager.open(Screen::input());
+        manager.active = 0;
+        manager
+    }
+}
+
+impl TabManager {
+    /// Empty manager; open at least one tab before rendering
+    pub fn new() -> Self {
+        Self {
+            tabs: Vec::new(),
+            active: 0,
+            prompt: None,
+        }
+    }
+
+    /// Open `screen` in a new tab after the active one and switch to it
+    pub fn open(&mut self, screen: Screen) -> usize {
+        let tab = Tab {
+            title: screen.name().to_string(),
+            screen,
+        };
+        let index = if self.tabs.is_empty() { 0 } else { self.active + 1 };
+        self.tabs.insert(index, tab);
+        self.active = index;
+        index
+    }
+
+    /// Close a tab; the last remaining tab cannot be closed
+    pub fn close(&mut self, index: usize) -> bool {
+        if self.tabs.len() <= 1 || index >= self.tabs.len() {
+            return false;
+        }
+        self.tabs.remove(index);
+        if self.active > index || self.active == self.tabs.len() {
+            self.active -= 1;
+        }
+        true
+    }
+
+    pub fn rename(&mut self, index: usize, title: &str) {
+        let title = title.trim();
+        if let Some(tab) = self.tabs.get_mut(index) {
+            if !title.is_empty() {
+                tab.title = title.to_string();
+            }
+        }
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.active = index;
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.tabs.is_empty() {
+            self.active = (self.active + 1) % self.tabs.len();
+        }
+    }
+
+    pub fn prev(&mut self) {
+        if !self.tabs.is_empty() {
+            self.active = (self.active + self.tabs.len() - 1) % self.tabs.len();
+        }
+    }
+
+    /// Move the active tab left (negative) or right (positive) in the bar
+    pub fn move_active(&mut self, delta: isize) {
+        let target = self.active as isize + delta;
+        if target < 0 || target >= self.tabs.len() as isize {
+            return;
+        }
+        self.tabs.swap(self.active, target as usize);
+        self.active = target as usize;
+    }
+
+    pub fn active_tab(&self) -> Option<&Tab> {
+        self.tabs.get(self.active)
+    }
+
+    pub fn active_screen_mut(&mut self) -> Option<&mut Screen> {
+        self.tabs.get_mut(self.active).map(|tab| &mut tab.screen)
+    }
+
+    pub fn is_prompting(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    /// Tab management keys, falling through to the active screen.
+    /// Ctrl+1..9 switch, Ctrl+N new, Ctrl+W close, F2 rename,
+    /// Ctrl+PgUp/PgDn previous/next, Alt+←/→ reorder.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if let Some(prompt) = self.prompt.take() {
+            self.handle_prompt_key(prompt, key.code);
+            return true;
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        match key.code {
+            // Many terminals cannot send Ctrl+digit, so Alt+digit works too
+            KeyCode::Char(c @ '1'..='9') if ctrl || alt => {
+                self.select(c as usize - '1' as usize);
+            }
+            KeyCode::Char('n') if ctrl => self.prompt = Some(TabPrompt::NewTab),
+            KeyCode::Char('w') if ctrl => {
+                self.close(self.active);
+            }
+            KeyCode::F(2) => {
+                let title = self.active_tab().map(|tab| tab.title.clone()).unwrap_or_default();
+                self.prompt = Some(TabPrompt::Rename(title));
+            }
+            KeyCode::PageDown if ctrl => self.next(),
+            KeyCode::PageUp if ctrl => self.prev(),
+            KeyCode::Right if alt => self.move_active(1),
+            KeyCode::Left if alt => self.move_active(-1),
+            _ => {
+                return self
+                    .active_screen_mut()
+                    .is_some_and(|screen| screen.handle_key(key));
+            }
+        }
+        true
+    }
+
+    fn handle_prompt_key(&mut self, prompt: TabPrompt, key: KeyCode) {
+        match prompt {
+            TabPrompt::NewTab => match key {
+                KeyCode::Char('1') => {
+                    self.open(Screen::table());
+                }
+                KeyCode::Char('2') => {
+                    self.open(Screen::image());
+                }
+                KeyCode::Char('3') => {
+                    self.open(Screen::input());
+                }
+                KeyCode::Esc => {}
+                _ => self.prompt = Some(TabPrompt::NewTab),
+            },
+            TabPrompt::Rename(mut title) => match key {
+                KeyCode::Enter => self.rename(self.active, &title),
+                KeyCode::Esc => {}
+                KeyCode::Backspace => {
+                    title.pop();
+                    self.prompt = Some(TabPrompt::Rename(title));
+                }
+                KeyCode::Char(c) => {
+                    title.push(c);
+                    self.prompt = Some(TabPrompt::Rename(title));
+                }
+                _ => self.prompt = Some(TabPrompt::Rename(title)),
+            },
+        }
+    }
+
+    /// Render the tab bar; the prompt, if any, replaces the bar's title
+    pub fn render_bar(&self, frame: &mut Frame, area: Rect, highlight: Color) {
+        let titles: Vec<Line> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| Line::from(format!("{} {}", i + 1, tab.title)))
+            .collect();
+
+        let title = match &self.prompt {
+            Some(TabPrompt::NewTab) => " New tab: 1 Table | 2 Image | 3 User Input | Esc cancel ".to_string(),
+            Some(TabPrompt::Rename(title)) => format!(" Rename tab: {}_ ", title),
+            None => " Ctrl+N new | Ctrl+W close | F2 rename | Alt+←/→ move ".to_string(),
+        };
+
+        let tabs = Tabs::new(titles)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .select(self.active)
+            .highlight_style(Style::default().fg(Color::Black).bg(highlight));
+        frame.render_widget(tabs, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles(manager: &TabManager) -> Vec<&str> {
+        manager.tabs.iter().map(|tab| tab.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_open_close_keeps_active_in_range() {
+        let mut manager = TabManager::default();
+        assert_eq!(titles(&manager), vec!["Table", "Image", "User Input"]);
+
+        manager.select(1);
+        assert_eq!(manager.open(Screen::table()), 2);
+        assert_eq!(titles(&manager), vec!["Table", "Image", "Table", "User Input"]);
+
+        manager.select(3);
+        assert!(manager.close(3));
+        assert_eq!(manager.active, 2);
+        assert!(manager.close(0));
+        assert_eq!(manager.active, 1);
+
+        manager.close(0);
+        assert!(!manager.close(0), "the last tab stays open");
+    }
+
+    #[test]
+    fn test_reorder_and_rename_by_keys() {
+        let mut manager = TabManager::default();
+        manager.handle_key(KeyEvent::new(KeyCode::Right, KeyModifiers::ALT));
+        assert_eq!(titles(&manager), vec!["Image", "Table", "User Input"]);
+        assert_eq!(manager.active, 1);
+
+        manager.handle_key(KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE));
+        for _ in 0.."Table".len() {
+            manager.handle_key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        }
+        manager.handle_key(KeyEvent::new(KeyCode::Char('P'), KeyModifiers::NONE));
+        manager.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(manager.tabs[1].title, "P");
+
+        manager.handle_key(KeyEvent::new(KeyCode::Char('3'), KeyModifiers::CONTROL));
+        assert_eq!(manager.active, 2);
+    }
+}
@@ -0,0 +1,141 @@
+//! Ratatui backend that renders to ANSI text for xterm.js
+
+use std::fmt::Write;
+use std::io;
+
+use ratatui::{
+    backend::{Backend, ClearType, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+};
+
+use crate::widgets::report::sgr;
+
+/// Backend collecting the escape sequences of each draw; pass
+/// `take_output` to xterm.js `Terminal.write` after every frame
+#[derive(Debug)]
+pub struct XtermBackend {
+    size: Size,
+    cursor: Position,
+    output: String,
+}
+
+impl XtermBackend {
+    pub fn new(columns: u16, rows: u16) -> Self {
+        Self {
+            size: Size::new(columns, rows),
+            cursor: Position::ORIGIN,
+            output: String::new(),
+        }
+    }
+
+    /// Follow a resize of the xterm.js terminal; call `Terminal::autoresize`
+    /// or draw afterwards so ratatui picks it up
+    pub fn resize(&mut self, columns: u16, rows: u16) {
+        self.size = Size::new(columns, rows);
+    }
+
+    /// Escape sequences written since the last call
+    pub fn take_output(&mut self) -> String {
+        std::mem::take(&mut self.output)
+    }
+
+    fn move_to(&mut self, position: Position) {
+        let _ = write!(self.output, "\x1b[{};{}H", position.y + 1, position.x + 1);
+    }
+}
+
+impl Backend for XtermBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let mut next: Option<Position> = None;
+        let mut style = String::new();
+        for (x, y, cell) in content {
+            let position = Position::new(x, y);
+            if next != Some(position) {
+                self.move_to(position);
+            }
+            let codes = sgr(cell);
+            if codes != style {
+                self.output.push_str(&codes);
+                style = codes;
+            }
+            self.output.push_str(cell.symbol());
+            next = Some(Position::new(x + 1, y));
+        }
+        if !style.is_empty() {
+            self.output.push_str("\x1b[0m");
+        }
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.output.push_str("\x1b[?25l");
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.output.push_str("\x1b[?25h");
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.cursor = position.into();
+        self.move_to(self.cursor);
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.output.push_str("\x1b[0m\x1b[2J");
+        Ok(())
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        let code = match clear_type {
+            ClearType::All => "\x1b[2J",
+            ClearType::AfterCursor => "\x1b[0J",
+            ClearType::BeforeCursor => "\x1b[1J",
+            ClearType::CurrentLine => "\x1b[2K",
+            ClearType::UntilNewLine => "\x1b[0K",
+        };
+        self.output.push_str(code);
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: self.size,
+            pixels: Size::default(),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{style::Stylize, widgets::Paragraph, Terminal};
+
+    #[test]
+    fn test_draw_writes_only_changed_cells() {
+        let mut terminal = Terminal::new(XtermBackend::new(6, 2)).unwrap();
+        terminal.draw(|frame| frame.render_widget(Paragraph::new("hi".red()), frame.area())).unwrap();
+        assert_eq!(terminal.backend_mut().take_output(), "\x1b[1;1H\x1b[0;31mhi\x1b[0m\x1b[?25l");
+
+        terminal.draw(|frame| frame.render_widget(Paragraph::new("ho".red()), frame.area())).unwrap();
+        assert_eq!(terminal.backend_mut().take_output(), "\x1b[1;2H\x1b[0;31mo\x1b[0m\x1b[?25l");
+    }
+}
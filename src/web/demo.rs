@@ -0,0 +1,219 @@
+//! Widget gallery for the browser: the table, select, emoji picker and form
+//! in tabs, drawn through `XtermBackend`. Alt+1..4 switch tabs.
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Tabs},
+    Frame, Terminal,
+};
+
+use crate::event::{Event, EventSource, KeyCode, KeyModifiers, QueuedEvents};
+use crate::widgets::component::{key_press, Component};
+use crate::widgets::emoji::EmojiPicker;
+use crate::widgets::form::{FormAction, FormDialog, FormField, Validator};
+use crate::widgets::table::{create_demo_table, InteractiveTable, SelectComponent, SelectOption, SelectType};
+
+use super::backend::XtermBackend;
+
+const TITLES: [&str; 4] = ["Table", "Select", "Emoji", "Form"];
+
+fn demo_form() -> FormDialog {
+    FormDialog::new(
+        "Sign up",
+        vec![
+            FormField::new("Name", "").required(),
+            FormField::new("Age", "").validator(Validator::Integer),
+            FormField::select("Plan", &["Free", "Pro", "Team"], "Free"),
+        ],
+    )
+}
+
+pub struct Demo {
+    terminal: Terminal<XtermBackend>,
+    /// Shared with the host, which pushes keys and resizes into it
+    events: QueuedEvents,
+    active: usize,
+    table: InteractiveTable,
+    select: SelectComponent,
+    emoji: EmojiPicker,
+    form: FormDialog,
+    status: String,
+}
+
+impl Demo {
+    pub fn new(columns: u16, rows: u16) -> Self {
+        let terminal = Terminal::new(XtermBackend::new(columns, rows)).expect("XtermBackend never fails");
+        let select = SelectComponent::builder()
+            .title("Favorite language")
+            .options([
+                SelectOption::new("Rust", "rust"),
+                SelectOption::new("TypeScript", "ts"),
+                SelectOption::new("Go", "go"),
+                SelectOption::disabled("COBOL", "cobol"),
+            ])
+            .select_type(SelectType::RadioGroup)
+            .build();
+        let mut demo = Self {
+            terminal,
+            events: QueuedEvents::new(),
+            active: 0,
+            table: create_demo_table(),
+            select,
+            emoji: EmojiPicker::new(),
+            form: demo_form(),
+            status: "Alt+1..4: switch tabs".to_string(),
+        };
+        demo.select.set_focused(true);
+        demo.emoji.set_focused(true);
+        demo
+    }
+
+    /// Queue the host pushes events into
+    pub fn events(&self) -> QueuedEvents {
+        self.events.clone()
+    }
+
+    /// Handle the queued events and draw; returns the ANSI output for xterm.js
+    pub fn frame(&mut self) -> String {
+        while let Ok(event) = self.events.read() {
+            self.handle_event(&event);
+        }
+        let Self {
+            terminal,
+            active,
+            table,
+            select,
+            emoji,
+            form,
+            status,
+            ..
+        } = self;
+        let _ = terminal.draw(|frame| {
+            let [bar, body, footer] =
+                Layout::vertical([Constraint::Length(3), Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+            render_tabs(frame, bar, *active);
+            let _ = match *active {
+                0 => Component::render(table, frame, body),
+                1 => Component::render(select, frame, body),
+                2 => Component::render(emoji, frame, body),
+                _ => {
+                    form.render(frame, body);
+                    Ok(())
+                }
+            };
+            frame.render_widget(Paragraph::new(status.as_str()).style(Style::default().fg(Color::DarkGray)), footer);
+        });
+        self.terminal.backend_mut().take_output()
+    }
+
+    fn handle_event(&mut self, event: &Event) {
+        if let Event::Resize(columns, rows) = *event {
+            self.terminal.backend_mut().resize(columns, rows);
+            return;
+        }
+        let Some(key) = key_press(event) else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c @ '1'..='4') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.active = c as usize - '1' as usize;
+            }
+            _ if self.active == 3 => match self.form.handle_key_event(key) {
+                FormAction::Submit => {
+                    self.status = format!("Signed up {}", self.form.value("Name").unwrap_or_default());
+                    self.form = demo_form();
+                }
+                FormAction::Cancel => self.form = demo_form(),
+                FormAction::None => {}
+            },
+            _ => {
+                let component: &mut dyn Component = match self.active {
+                    0 => &mut self.table,
+                    1 => &mut self.select,
+                    _ => &mut self.emoji,
+                };
+                component.handle_event(event);
+            }
+        }
+    }
+}
+
+fn render_tabs(frame: &mut Frame, area: Rect, active: usize) {
+    let titles: Vec<Line> = TITLES.iter().enumerate().map(|(i, title)| Line::from(format!("{} {}", i + 1, title))).collect();
+    let tabs = Tabs::new(titles)
+        .block(Block::default().borders(Borders::ALL).title(" Ratatui in the browser "))
+        .select(active)
+        .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    frame.render_widget(tabs, area);
+}
+
+/// `Demo` for JavaScript; see `web/index.html` for the xterm.js side
+#[cfg(target_arch = "wasm32")]
+mod bindings {
+    use wasm_bindgen::prelude::*;
+
+    use super::Demo;
+    use crate::event::{Event, QueuedEvents};
+    use crate::web::input::key_event;
+
+    #[wasm_bindgen]
+    pub struct WebDemo {
+        demo: Demo,
+        events: QueuedEvents,
+    }
+
+    #[wasm_bindgen]
+    impl WebDemo {
+        #[wasm_bindgen(constructor)]
+        pub fn new(columns: u16, rows: u16) -> Self {
+            let demo = Demo::new(columns, rows);
+            let events = demo.events();
+            Self { demo, events }
+        }
+
+        /// From xterm.js `onKey`: `domEvent.key` and its modifier flags
+        pub fn key(&self, key: &str, ctrl: bool, alt: bool, shift: bool) {
+            if let Some(key) = key_event(key, ctrl, alt, shift) {
+                self.events.push(Event::Key(key));
+            }
+        }
+
+        /// From xterm.js `onResize`
+        pub fn resize(&self, columns: u16, rows: u16) {
+            self.events.push(Event::Resize(columns, rows));
+        }
+
+        /// ANSI output for xterm.js `write`
+        pub fn frame(&mut self) -> String {
+            self.demo.frame()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KeyEvent;
+
+    #[test]
+    fn test_demo_switches_tabs_and_submits_the_form() {
+        let mut demo = Demo::new(80, 24);
+        let events = demo.events();
+        assert!(demo.frame().contains("Ratatui"));
+
+        events.push(Event::Key(KeyEvent::new(KeyCode::Char('4'), KeyModifiers::ALT)));
+        for c in "Ada".chars() {
+            events.push(Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)));
+        }
+        events.push(Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        demo.frame();
+        assert_eq!(demo.active, 3);
+        assert_eq!(demo.status, "Signed up Ada");
+
+        events.push(Event::Resize(100, 30));
+        demo.frame();
+        assert_eq!(demo.terminal.size().unwrap(), ratatui::layout::Size::new(100, 30));
+    }
+}
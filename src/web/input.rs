@@ -0,0 +1,56 @@
+//! Browser keyboard events to the widgets' key events
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Key event for a DOM `KeyboardEvent.key` name such as `ArrowUp` or `a`,
+/// as passed along by xterm.js `onKey`; `None` for keys the widgets have no
+/// code for, e.g. a lone `Shift`
+pub fn key_event(key: &str, ctrl: bool, alt: bool, shift: bool) -> Option<KeyEvent> {
+    let mut modifiers = KeyModifiers::NONE;
+    modifiers.set(KeyModifiers::CONTROL, ctrl);
+    modifiers.set(KeyModifiers::ALT, alt);
+    modifiers.set(KeyModifiers::SHIFT, shift);
+
+    let code = match key {
+        "Enter" => KeyCode::Enter,
+        "Escape" => KeyCode::Esc,
+        "Backspace" => KeyCode::Backspace,
+        "Tab" if shift => KeyCode::BackTab,
+        "Tab" => KeyCode::Tab,
+        "ArrowUp" => KeyCode::Up,
+        "ArrowDown" => KeyCode::Down,
+        "ArrowLeft" => KeyCode::Left,
+        "ArrowRight" => KeyCode::Right,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Delete" => KeyCode::Delete,
+        "Insert" => KeyCode::Insert,
+        _ => {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                (Some('F'), Some(_)) => KeyCode::F(key[1..].parse().ok()?),
+                _ => return None,
+            }
+        }
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_event() {
+        assert_eq!(key_event("ArrowUp", false, false, false), Some(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)));
+        assert_eq!(key_event("Tab", false, false, true), Some(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)));
+        assert_eq!(key_event("c", true, false, false), Some(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+        assert_eq!(key_event("F10", false, false, false), Some(KeyEvent::new(KeyCode::F(10), KeyModifiers::NONE)));
+        assert_eq!(key_event("é", false, false, false), Some(KeyEvent::new(KeyCode::Char('é'), KeyModifiers::NONE)));
+        assert_eq!(key_event("Shift", false, false, true), None);
+        assert_eq!(key_event("Fn", false, false, false), None);
+    }
+}
@@ -0,0 +1,13 @@
+//! Web module
+//!
+//! Runs the widgets in the browser on top of xterm.js: a ratatui backend
+//! that renders to ANSI text, the mapping from DOM key names to key events,
+//! and a widget gallery exported to JavaScript as `WebDemo`. Everything but
+//! the export builds natively too, so it is tested with the rest.
+
+pub mod backend;
+mod demo;
+pub mod input;
+
+pub use backend::XtermBackend;
+pub use demo::Demo;
@@ -0,0 +1,146 @@
+//! Alert module
+//!
+//! Gets the user's attention when a timer ends, a long task completes or an
+//! error dialog opens: rings the terminal bell, flashes the screen by
+//! inverting its colors for a moment, and sends a desktop notification
+//! through `notify-rust` when built with the `desktop-notifications` feature.
+//! Which of these are used is set in the app config.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    style::{Modifier, Style},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+/// How long the screen stays inverted
+const FLASH_DURATION: Duration = Duration::from_millis(150);
+
+/// Alert channels, stored in the app config
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertConfig {
+    pub bell: bool,
+    pub flash: bool,
+    /// Needs the `desktop-notifications` feature; ignored without it
+    pub desktop: bool,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            bell: true,
+            flash: true,
+            desktop: false,
+        }
+    }
+}
+
+pub struct Alerter {
+    pub config: AlertConfig,
+    bell_pending: bool,
+    flash_until: Option<Instant>,
+}
+
+impl Alerter {
+    pub fn new(config: AlertConfig) -> Self {
+        Self {
+            config,
+            bell_pending: false,
+            flash_until: None,
+        }
+    }
+
+    /// Alert through every channel enabled in the config
+    pub fn alert(&mut self, summary: &str, body: &str) {
+        if self.config.bell {
+            self.bell();
+        }
+        if self.config.flash {
+            self.flash();
+        }
+        if self.config.desktop {
+            notify_desktop(summary, body);
+        }
+    }
+
+    /// Ring the bell on the next `ring`
+    pub fn bell(&mut self) {
+        self.bell_pending = true;
+    }
+
+    pub fn flash(&mut self) {
+        self.flash_until = Some(Instant::now() + FLASH_DURATION);
+    }
+
+    pub fn is_flashing(&self) -> bool {
+        self.flash_until.is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Write a pending bell to the terminal; call once per loop
+    pub fn ring<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        if !self.bell_pending {
+            return Ok(());
+        }
+        self.bell_pending = false;
+        out.write_all(b"\x07")?;
+        out.flush()
+    }
+
+    /// Invert the whole frame while a flash is showing; call last when drawing
+    pub fn render(&self, frame: &mut Frame) {
+        if self.is_flashing() {
+            let area = frame.area();
+            frame.buffer_mut().set_style(area, Style::default().add_modifier(Modifier::REVERSED));
+        }
+    }
+}
+
+/// Show a desktop notification from a background thread, so a slow
+/// notification daemon never stalls the UI
+#[cfg(feature = "desktop-notifications")]
+pub fn notify_desktop(summary: &str, body: &str) {
+    let (summary, body) = (super::redact::redact(summary), super::redact::redact(body));
+    crate::platform::current().background(Box::new(move || {
+        let shown = notify_rust::Notification::new()
+            .appname("ratatui-rust-example")
+            .summary(&summary)
+            .body(&body)
+            .show();
+        if let Err(e) = shown {
+            log::warn!("Failed to show desktop notification: {}", e);
+        }
+    }));
+}
+
+#[cfg(not(feature = "desktop-notifications"))]
+pub fn notify_desktop(_summary: &str, _body: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alert_uses_enabled_channels() {
+        let mut alerter = Alerter::new(AlertConfig::default());
+        alerter.alert("Timer", "Tea is ready");
+        assert!(alerter.is_flashing());
+
+        let mut out = Vec::new();
+        alerter.ring(&mut out).unwrap();
+        alerter.ring(&mut out).unwrap();
+        assert_eq!(out, b"\x07");
+
+        let mut quiet = Alerter::new(AlertConfig {
+            bell: false,
+            flash: false,
+            desktop: false,
+        });
+        quiet.alert("Build", "failed");
+        quiet.ring(&mut out).unwrap();
+        assert_eq!(out, b"\x07");
+        assert!(!quiet.is_flashing());
+    }
+}
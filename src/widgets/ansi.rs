@@ -0,0 +1,201 @@
+//! ANSI escape sequence module
+//!
+//! Converts text containing ANSI SGR color codes (captured command output,
+//! log files) into styled ratatui `Text`. Other escape sequences are dropped
+//! so raw control bytes never reach the screen.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+/// Convert multi-line ANSI text into styled `Text`; styles carry across lines
+pub fn ansi_to_text(input: &str) -> Text<'static> {
+    let mut style = Style::default();
+    let lines: Vec<Line<'static>> = input.lines().map(|line| parse_line(line, &mut style)).collect();
+    Text::from(lines)
+}
+
+/// Convert a single line, starting from `base` style
+pub fn ansi_line(input: &str, base: Style) -> Line<'static> {
+    let mut style = base;
+    parse_line(input, &mut style)
+}
+
+/// Remove all escape sequences, keeping only the visible text
+pub fn strip_ansi(input: &str) -> String {
+    ansi_line(input, Style::default())
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+fn parse_line(input: &str, style: &mut Style) -> Line<'static> {
+    // A carriage return redraws the line, as progress output does
+    let input = input.rsplit('\r').find(|part| !part.is_empty()).unwrap_or("");
+
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            if !c.is_control() || c == '\t' {
+                current.push(c);
+            }
+            continue;
+        }
+
+        match chars.next() {
+            // CSI: parameters up to a final byte in the @..~ range
+            Some('[') => {
+                let mut params = String::new();
+                let mut terminator = None;
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        terminator = Some(c);
+                        break;
+                    }
+                    params.push(c);
+                }
+                if terminator == Some('m') {
+                    if !current.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut current), *style));
+                    }
+                    apply_sgr(&params, style);
+                }
+            }
+            // OSC: ends with BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !current.is_empty() {
+        spans.push(Span::styled(current, *style));
+    }
+    Line::from(spans)
+}
+
+/// Apply a `;`-separated list of SGR parameters to `style`
+fn apply_sgr(params: &str, style: &mut Style) {
+    let codes: Vec<u16> = params
+        .split(';')
+        .map(|code| code.parse().unwrap_or(0))
+        .collect();
+    let mut i = 0;
+
+    while i < codes.len() {
+        match codes[i] {
+            0 => *style = Style::default(),
+            1 => *style = style.add_modifier(Modifier::BOLD),
+            2 => *style = style.add_modifier(Modifier::DIM),
+            3 => *style = style.add_modifier(Modifier::ITALIC),
+            4 => *style = style.add_modifier(Modifier::UNDERLINED),
+            5 => *style = style.add_modifier(Modifier::SLOW_BLINK),
+            7 => *style = style.add_modifier(Modifier::REVERSED),
+            9 => *style = style.add_modifier(Modifier::CROSSED_OUT),
+            22 => *style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => *style = style.remove_modifier(Modifier::ITALIC),
+            24 => *style = style.remove_modifier(Modifier::UNDERLINED),
+            25 => *style = style.remove_modifier(Modifier::SLOW_BLINK),
+            27 => *style = style.remove_modifier(Modifier::REVERSED),
+            29 => *style = style.remove_modifier(Modifier::CROSSED_OUT),
+            code @ 30..=37 => *style = style.fg(basic_color(code - 30)),
+            code @ 90..=97 => *style = style.fg(bright_color(code - 90)),
+            39 => style.fg = None,
+            code @ 40..=47 => *style = style.bg(basic_color(code - 40)),
+            code @ 100..=107 => *style = style.bg(bright_color(code - 100)),
+            49 => style.bg = None,
+            code @ (38 | 48) => {
+                let (color, consumed) = extended_color(&codes[i + 1..]);
+                if let Some(color) = color {
+                    *style = if code == 38 { style.fg(color) } else { style.bg(color) };
+                }
+                i += consumed;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+/// Parse `5;n` or `2;r;g;b` after a 38/48 code, returning the parameters consumed
+fn extended_color(rest: &[u16]) -> (Option<Color>, usize) {
+    match rest {
+        [5, n, ..] => (Some(Color::Indexed(*n as u8)), 2),
+        [2, r, g, b, ..] => (Some(Color::Rgb(*r as u8, *g as u8, *b as u8)), 4),
+        _ => (None, rest.len()),
+    }
+}
+
+fn basic_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::Gray,
+    }
+}
+
+fn bright_color(index: u16) -> Color {
+    match index {
+        0 => Color::DarkGray,
+        1 => Color::LightRed,
+        2 => Color::LightGreen,
+        3 => Color::LightYellow,
+        4 => Color::LightBlue,
+        5 => Color::LightMagenta,
+        6 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_colors_and_reset() {
+        let line = ansi_line("\x1b[1;32mCompiling\x1b[0m foo", Style::default());
+        assert_eq!(line.spans.len(), 2);
+        assert_eq!(line.spans[0].content, "Compiling");
+        assert_eq!(line.spans[0].style, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD));
+        assert_eq!(line.spans[1].content, " foo");
+        assert_eq!(line.spans[1].style, Style::default());
+    }
+
+    #[test]
+    fn test_extended_colors() {
+        let line = ansi_line("\x1b[38;5;208mA\x1b[48;2;1;2;3mB", Style::default());
+        assert_eq!(line.spans[0].style.fg, Some(Color::Indexed(208)));
+        assert_eq!(line.spans[1].style.bg, Some(Color::Rgb(1, 2, 3)));
+    }
+
+    #[test]
+    fn test_non_sgr_sequences_are_dropped() {
+        assert_eq!(strip_ansi("\x1b[2Kdone\x1b]8;;http://x\x07link\x1b]8;;\x07"), "donelink");
+        assert_eq!(strip_ansi("10%\r50%\r100%"), "100%");
+    }
+
+    #[test]
+    fn test_style_carries_across_lines() {
+        let text = ansi_to_text("\x1b[31mred\nstill red\x1b[0m");
+        assert_eq!(text.lines[1].spans[0].style.fg, Some(Color::Red));
+    }
+}
@@ -0,0 +1,210 @@
+//! Audio module
+//!
+//! Plays short audio clips with rodio, for previewing them in the file
+//! browser. The output device is opened only when playback starts and is
+//! released on stop, so moving through a directory of clips stays cheap.
+//! `render` draws the mini transport: state, elapsed time and a seek bar,
+//! which shows the clip's waveform once it has been computed.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use rodio::{Decoder, OutputStream, Sink, Source};
+
+use super::humanize::{format_clock, format_size};
+use super::waveform::{render_waveform, Waveform};
+use crate::error::DemoError;
+use crate::event::KeyCode;
+
+/// Largest file played in the preview
+pub const CLIP_MAX_BYTES: u64 = 20 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Stopped,
+    Playing,
+    Paused,
+}
+
+pub struct AudioClip {
+    pub path: PathBuf,
+    /// Length of the clip, when the format reports it
+    pub duration: Option<Duration>,
+    /// Output stream and sink while the clip is loaded for playback
+    output: Option<(OutputStream, Sink)>,
+    /// Peaks for the seek bar, once computed
+    pub waveform: Option<Waveform>,
+}
+
+fn decode(path: &Path) -> Result<Decoder<BufReader<File>>, DemoError> {
+    Decoder::new(BufReader::new(File::open(path)?)).map_err(|e| DemoError::Audio(e.to_string()))
+}
+
+impl AudioClip {
+    /// Check that `path` is a clip small enough to play and read its length
+    pub fn open(path: &Path) -> Result<Self, DemoError> {
+        let size = path.metadata()?.len();
+        if size > CLIP_MAX_BYTES {
+            return Err(DemoError::Audio(format!(
+                "{} is too large to preview; clips up to {} play here",
+                format_size(size),
+                format_size(CLIP_MAX_BYTES)
+            )));
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            duration: decode(path)?.total_duration(),
+            output: None,
+            waveform: None,
+        })
+    }
+
+    pub fn state(&self) -> Transport {
+        match &self.output {
+            Some((_, sink)) if !sink.empty() && sink.is_paused() => Transport::Paused,
+            Some((_, sink)) if !sink.empty() => Transport::Playing,
+            _ => Transport::Stopped,
+        }
+    }
+
+    /// Play from the start when stopped, otherwise pause or resume
+    pub fn toggle(&mut self) -> Result<(), DemoError> {
+        if let (Some((_, sink)), Transport::Playing | Transport::Paused) = (&self.output, self.state()) {
+            if sink.is_paused() {
+                sink.play();
+            } else {
+                sink.pause();
+            }
+            return Ok(());
+        }
+        self.play()
+    }
+
+    fn play(&mut self) -> Result<(), DemoError> {
+        let (stream, handle) = OutputStream::try_default().map_err(|e| DemoError::Audio(e.to_string()))?;
+        let sink = Sink::try_new(&handle).map_err(|e| DemoError::Audio(e.to_string()))?;
+        sink.append(decode(&self.path)?);
+        self.output = Some((stream, sink));
+        Ok(())
+    }
+
+    /// Stop and release the output device
+    pub fn stop(&mut self) {
+        self.output = None;
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match (&self.output, self.state()) {
+            (Some((_, sink)), Transport::Playing | Transport::Paused) => sink.get_pos(),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Enter or `p` plays and pauses, `s` stops; returns whether the key
+    /// was used
+    pub fn handle_key(&mut self, key: KeyCode) -> Result<bool, DemoError> {
+        match key {
+            KeyCode::Enter | KeyCode::Char('p') => self.toggle()?,
+            KeyCode::Char('s') => self.stop(),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Share of the clip played, when its length is known
+    pub fn progress(&self) -> Option<f64> {
+        let duration = self.duration.filter(|duration| !duration.is_zero())?;
+        Some((self.elapsed().as_secs_f64() / duration.as_secs_f64()).min(1.0))
+    }
+
+    /// Transport state and elapsed time against the length
+    pub fn status_line(&self) -> Line<'static> {
+        let (icon, label, color) = match self.state() {
+            Transport::Playing => ("▶", "Playing", Color::Green),
+            Transport::Paused => ("⏸", "Paused", Color::Yellow),
+            Transport::Stopped => ("■", "Stopped", Color::Gray),
+        };
+        let total = self.duration.map_or_else(|| "--:--:--".to_string(), format_clock);
+        Line::from(vec![
+            Span::styled(format!("{} {}", icon, label), Style::default().fg(color)),
+            Span::raw(format!("  {} / {}", format_clock(self.elapsed()), total)),
+        ])
+    }
+
+    /// Status line on top, key help at the bottom and the seek bar between:
+    /// the waveform once it is known, a plain bar until then
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if area.height < 3 {
+            frame.render_widget(Paragraph::new(self.status_line()), area);
+            return;
+        }
+        let status = Rect { height: 1, ..area };
+        let help = Rect { y: area.bottom() - 1, height: 1, ..area };
+        let seek = Rect { y: area.y + 1, height: area.height - 2, ..area };
+        frame.render_widget(Paragraph::new(self.status_line()), status);
+        frame.render_widget(
+            Paragraph::new("Enter/p: play/pause | s: stop").style(Style::default().fg(Color::DarkGray)),
+            help,
+        );
+        match &self.waveform {
+            Some(waveform) => render_waveform(frame, seek, waveform, self.progress()),
+            None => {
+                let width = seek.width as usize;
+                let filled = (self.progress().unwrap_or(0.0) * width as f64) as usize;
+                let bar = Line::from(vec![
+                    Span::styled("━".repeat(filled), Style::default().fg(Color::Cyan)),
+                    Span::styled("─".repeat(width - filled), Style::default().fg(Color::DarkGray)),
+                ]);
+                frame.render_widget(Paragraph::new(bar), Rect { height: 1, ..seek });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A silent 16-bit mono WAV file of `seconds` at 8 kHz
+    fn silent_wav(seconds: u32) -> Vec<u8> {
+        let rate = 8000u32;
+        let data_len = rate * seconds * 2;
+        let mut wav = Vec::new();
+        wav.extend(b"RIFF");
+        wav.extend((36 + data_len).to_le_bytes());
+        wav.extend(b"WAVEfmt ");
+        wav.extend(16u32.to_le_bytes());
+        wav.extend(1u16.to_le_bytes());
+        wav.extend(1u16.to_le_bytes());
+        wav.extend(rate.to_le_bytes());
+        wav.extend((rate * 2).to_le_bytes());
+        wav.extend(2u16.to_le_bytes());
+        wav.extend(16u16.to_le_bytes());
+        wav.extend(b"data");
+        wav.extend(data_len.to_le_bytes());
+        wav.resize(wav.len() + data_len as usize, 0);
+        wav
+    }
+
+    #[test]
+    fn test_clip_reads_duration_without_playing() {
+        let path = std::env::temp_dir().join(format!("audio_test_{}.wav", std::process::id()));
+        std::fs::write(&path, silent_wav(2)).unwrap();
+        let clip = AudioClip::open(&path).unwrap();
+        assert_eq!(clip.duration, Some(Duration::from_secs(2)));
+        assert_eq!(clip.state(), Transport::Stopped);
+        assert_eq!(clip.elapsed(), Duration::ZERO);
+        let text: String = clip.status_line().spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(text, "■ Stopped  00:00:00 / 00:00:02");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+//! Autopilot module
+//!
+//! Scripted key presses for `--demo`. A screen lists its demo as
+//! `DemoStep`s and hands them to `EventDispatcher::play`, which delivers the
+//! keys with pauses between them as if someone were typing, for unattended
+//! screen recordings and conference demos. A real key press hands control
+//! back to the user.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Pause before each scripted key, long enough for viewers to follow
+const KEY_DELAY: Duration = Duration::from_millis(700);
+
+/// Pause between the characters of typed text
+const TYPE_DELAY: Duration = Duration::from_millis(90);
+
+/// One instruction of a demo script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DemoStep {
+    Key(KeyCode),
+    /// Control and a letter, e.g. `Ctrl('t')`
+    Ctrl(char),
+    /// Type the text a character at a time
+    Type(&'static str),
+    /// Wait this many milliseconds before the next key
+    Pause(u64),
+}
+
+/// Keys of a demo script waiting to be played, each with the delay before it
+#[derive(Debug)]
+pub struct Autopilot {
+    keys: VecDeque<(Duration, KeyEvent)>,
+    /// When the next key is due
+    due: Instant,
+}
+
+impl Autopilot {
+    pub fn new(steps: &[DemoStep]) -> Self {
+        let keys = expand(steps);
+        let delay = keys.front().map_or(Duration::ZERO, |(delay, _)| *delay);
+        Self {
+            keys,
+            due: Instant::now() + delay,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Time left until the next key is due
+    pub fn until_next(&self, now: Instant) -> Duration {
+        self.due.saturating_duration_since(now)
+    }
+
+    /// The next key if it is due at `now`; the one after it is timed from `now`
+    pub fn next_due(&mut self, now: Instant) -> Option<KeyEvent> {
+        if now < self.due {
+            return None;
+        }
+        let (_, key) = self.keys.pop_front()?;
+        if let Some((delay, _)) = self.keys.front() {
+            self.due = now + *delay;
+        }
+        Some(key)
+    }
+}
+
+/// Key presses of `steps`, with pauses added to the delay of the key after them
+fn expand(steps: &[DemoStep]) -> VecDeque<(Duration, KeyEvent)> {
+    let mut keys = VecDeque::new();
+    let mut pause = Duration::ZERO;
+    for step in steps {
+        match *step {
+            DemoStep::Key(code) => {
+                keys.push_back((KEY_DELAY + pause, KeyEvent::new(code, KeyModifiers::NONE)));
+                pause = Duration::ZERO;
+            }
+            DemoStep::Ctrl(c) => {
+                keys.push_back((KEY_DELAY + pause, KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)));
+                pause = Duration::ZERO;
+            }
+            DemoStep::Type(text) => {
+                for (i, c) in text.chars().enumerate() {
+                    let delay = if i == 0 { KEY_DELAY + pause } else { TYPE_DELAY };
+                    keys.push_back((delay, KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)));
+                    pause = Duration::ZERO;
+                }
+            }
+            DemoStep::Pause(millis) => pause += Duration::from_millis(millis),
+        }
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autopilot_timing() {
+        let steps = [
+            DemoStep::Key(KeyCode::Down),
+            DemoStep::Pause(1000),
+            DemoStep::Type("hi"),
+            DemoStep::Ctrl('t'),
+        ];
+        let keys = expand(&steps);
+        let delays: Vec<Duration> = keys.iter().map(|(delay, _)| *delay).collect();
+        assert_eq!(delays, vec![KEY_DELAY, KEY_DELAY + Duration::from_secs(1), TYPE_DELAY, KEY_DELAY]);
+        assert_eq!(keys[3].1, KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL));
+
+        let mut autopilot = Autopilot::new(&steps);
+        let start = Instant::now();
+        assert_eq!(autopilot.next_due(start), None);
+        let now = start + KEY_DELAY;
+        assert_eq!(autopilot.next_due(now).map(|key| key.code), Some(KeyCode::Down));
+        assert_eq!(autopilot.until_next(now), KEY_DELAY + Duration::from_secs(1));
+        assert_eq!(autopilot.next_due(now), None);
+        assert!(!autopilot.is_finished());
+    }
+}
@@ -0,0 +1,141 @@
+//! Message bus module
+//!
+//! Typed messages components publish instead of reaching into shared app
+//! fields. A widget given a `Publisher` sends what happened to it, e.g. a
+//! new selection or a copied text, and the screen hands everything queued
+//! to its subscribers once per loop: the clipboard history, toasts and the
+//! status line react to any widget without knowing which one it was.
+//! Publishers are channels, so background threads can publish too.
+
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+/// Something that happened in a component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    /// A list or table moved its selection to `index`
+    SelectionChanged { source: &'static str, index: usize },
+    FileOpened(PathBuf),
+    /// The theme with this name was applied
+    ThemeChanged(String),
+    /// Text put on the clipboard
+    Copied(String),
+    /// Outcome of an action, for the status line
+    Status(String),
+}
+
+/// Reacts to published messages
+pub trait Subscriber {
+    fn on_message(&mut self, message: &Message);
+}
+
+/// Sending end of a `MessageBus`, handed to the components that publish
+#[derive(Debug, Clone)]
+pub struct Publisher(Sender<Message>);
+
+impl Publisher {
+    /// Queue `message`; it is dropped when the bus is gone
+    pub fn publish(&self, message: Message) {
+        let _ = self.0.send(message);
+    }
+}
+
+#[derive(Debug)]
+pub struct MessageBus {
+    sender: Sender<Message>,
+    receiver: Receiver<Message>,
+}
+
+impl Default for MessageBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageBus {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self { sender, receiver }
+    }
+
+    pub fn publisher(&self) -> Publisher {
+        Publisher(self.sender.clone())
+    }
+
+    pub fn publish(&self, message: Message) {
+        let _ = self.sender.send(message);
+    }
+
+    /// Hand every queued message to each subscriber in order; returns how
+    /// many messages there were
+    pub fn dispatch(&self, subscribers: &mut [&mut dyn Subscriber]) -> usize {
+        let mut count = 0;
+        for message in self.receiver.try_iter() {
+            for subscriber in subscribers.iter_mut() {
+                subscriber.on_message(&message);
+            }
+            count += 1;
+        }
+        count
+    }
+}
+
+/// Status bar text following the latest message
+#[derive(Debug, Clone, Default)]
+pub struct StatusLine {
+    text: Option<String>,
+}
+
+impl StatusLine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn set(&mut self, text: impl Into<String>) {
+        self.text = Some(text.into());
+    }
+}
+
+impl Subscriber for StatusLine {
+    fn on_message(&mut self, message: &Message) {
+        self.text = Some(match message {
+            Message::SelectionChanged { source, index } => format!("{} row {}", source, index + 1),
+            Message::FileOpened(path) => format!("Opened {}", path.display()),
+            Message::ThemeChanged(name) => format!("Theme: {}", name),
+            Message::Copied(text) => format!("Copied {} characters", text.chars().count()),
+            Message::Status(text) => text.clone(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatch_to_subscribers() {
+        let bus = MessageBus::new();
+        let publisher = bus.publisher();
+        std::thread::spawn(move || publisher.publish(Message::FileOpened(PathBuf::from("notes.md"))))
+            .join()
+            .unwrap();
+        bus.publish(Message::Copied("héllo".to_string()));
+
+        let mut status = StatusLine::new();
+        let mut seen = Vec::new();
+        struct Log<'a>(&'a mut Vec<Message>);
+        impl Subscriber for Log<'_> {
+            fn on_message(&mut self, message: &Message) {
+                self.0.push(message.clone());
+            }
+        }
+        assert_eq!(bus.dispatch(&mut [&mut status, &mut Log(&mut seen)]), 2);
+        assert_eq!(seen[0], Message::FileOpened(PathBuf::from("notes.md")));
+        assert_eq!(status.text(), Some("Copied 5 characters"));
+        assert_eq!(bus.dispatch(&mut [&mut status]), 0);
+    }
+}
@@ -0,0 +1,287 @@
+//! Calendar widget module
+//!
+//! Month view with day selection, event markers under days that have
+//! entries and a configurable first day of the week. `DatePicker` wraps the
+//! calendar in a popup for picking a single date.
+
+use std::collections::HashSet;
+
+use chrono::{Datelike, Duration, Local, Months, NaiveDate, Weekday};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::event::KeyCode;
+
+/// Width of a single day cell
+const CELL_WIDTH: usize = 4;
+
+pub struct Calendar {
+    pub selected: NaiveDate,
+    pub week_start: Weekday,
+    /// Days that get an event marker
+    pub events: HashSet<NaiveDate>,
+    today: NaiveDate,
+}
+
+impl Calendar {
+    pub fn new(selected: NaiveDate) -> Self {
+        Self {
+            selected,
+            week_start: Weekday::Mon,
+            events: HashSet::new(),
+            today: Local::now().date_naive(),
+        }
+    }
+
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn with_events(mut self, events: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.events = events.into_iter().collect();
+        self
+    }
+
+    pub fn with_today(mut self, today: NaiveDate) -> Self {
+        self.today = today;
+        self
+    }
+
+    pub fn move_days(&mut self, days: i64) {
+        if let Some(date) = self.selected.checked_add_signed(Duration::days(days)) {
+            self.selected = date;
+        }
+    }
+
+    /// Move by whole months, clamping the day to the target month's length
+    pub fn move_months(&mut self, months: i32) {
+        let shifted = if months >= 0 {
+            self.selected.checked_add_months(Months::new(months as u32))
+        } else {
+            self.selected.checked_sub_months(Months::new(months.unsigned_abs()))
+        };
+        if let Some(date) = shifted {
+            self.selected = date;
+        }
+    }
+
+    pub fn select_today(&mut self) {
+        self.selected = self.today;
+    }
+
+    /// Weekday names in display order
+    pub fn weekday_order(&self) -> [Weekday; 7] {
+        let mut day = self.week_start;
+        std::array::from_fn(|_| {
+            let current = day;
+            day = day.succ();
+            current
+        })
+    }
+
+    /// Weeks of the selected month, with `None` padding outside the month
+    pub fn month_grid(&self) -> Vec<[Option<NaiveDate>; 7]> {
+        let first = self.selected.with_day(1).unwrap_or(self.selected);
+        let offset = (first.weekday().num_days_from_monday() + 7
+            - self.week_start.num_days_from_monday())
+            % 7;
+
+        let mut weeks = Vec::new();
+        let mut week = [None; 7];
+        let mut column = offset as usize;
+        let mut day = first;
+        while day.month() == first.month() {
+            week[column] = Some(day);
+            column += 1;
+            if column == 7 {
+                weeks.push(week);
+                week = [None; 7];
+                column = 0;
+            }
+            match day.succ_opt() {
+                Some(next) => day = next,
+                None => break,
+            }
+        }
+        if column > 0 {
+            weeks.push(week);
+        }
+        weeks
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Left => self.move_days(-1),
+            KeyCode::Right => self.move_days(1),
+            KeyCode::Up => self.move_days(-7),
+            KeyCode::Down => self.move_days(7),
+            KeyCode::PageUp => self.move_months(-1),
+            KeyCode::PageDown => self.move_months(1),
+            KeyCode::Char('t') => self.select_today(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Rows needed to draw the widget, including borders
+    pub fn height(&self) -> u16 {
+        self.month_grid().len() as u16 * 2 + 3
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = vec![Line::from(
+            self.weekday_order()
+                .iter()
+                .map(|day| {
+                    let name = &day.to_string()[..2];
+                    let color = if matches!(day, Weekday::Sat | Weekday::Sun) {
+                        Color::Magenta
+                    } else {
+                        Color::Yellow
+                    };
+                    Span::styled(format!("{:>width$}", name, width = CELL_WIDTH), Style::default().fg(color))
+                })
+                .collect::<Vec<_>>(),
+        )];
+
+        for week in self.month_grid() {
+            let mut days = Vec::new();
+            let mut markers = Vec::new();
+            for date in week {
+                let Some(date) = date else {
+                    days.push(Span::raw(" ".repeat(CELL_WIDTH)));
+                    markers.push(Span::raw(" ".repeat(CELL_WIDTH)));
+                    continue;
+                };
+
+                let mut style = Style::default();
+                if date == self.today {
+                    style = style.fg(Color::Cyan).add_modifier(Modifier::BOLD);
+                }
+                if date == self.selected {
+                    style = style.bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD);
+                }
+                days.push(Span::raw("  "));
+                days.push(Span::styled(format!("{:>2}", date.day()), style));
+
+                let marker = if self.events.contains(&date) { "•" } else { " " };
+                markers.push(Span::styled(
+                    format!("{:>width$}", marker, width = CELL_WIDTH),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            lines.push(Line::from(days));
+            lines.push(Line::from(markers));
+        }
+
+        let title = self.selected.format(" %B %Y ").to_string();
+        let paragraph = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Result of a key press in the date picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePickerAction {
+    None,
+    Picked(NaiveDate),
+    Cancelled,
+}
+
+/// Calendar shown as a centered popup for picking a date
+pub struct DatePicker {
+    pub calendar: Calendar,
+}
+
+impl DatePicker {
+    pub fn new(calendar: Calendar) -> Self {
+        Self { calendar }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> DatePickerAction {
+        match key {
+            KeyCode::Enter => DatePickerAction::Picked(self.calendar.selected),
+            KeyCode::Esc => DatePickerAction::Cancelled,
+            _ => {
+                self.calendar.handle_key(key);
+                DatePickerAction::None
+            }
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = ((CELL_WIDTH * 7) as u16 + 3).min(area.width);
+        let height = (self.calendar.height() + 1).min(area.height);
+        if height < 2 {
+            // No room for the calendar above the hint line
+            return;
+        }
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+        self.calendar.render(frame, Rect { height: height - 1, ..popup });
+        let hint = Paragraph::new("Enter: pick | Esc: cancel").style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(hint, Rect { y: popup.y + height - 1, height: 1, ..popup });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_month_grid_respects_week_start() {
+        // 1 October 2026 is a Thursday
+        let monday = Calendar::new(date(2026, 10, 16));
+        assert_eq!(monday.month_grid()[0][3], Some(date(2026, 10, 1)));
+
+        let sunday = Calendar::new(date(2026, 10, 16)).with_week_start(Weekday::Sun);
+        assert_eq!(sunday.month_grid()[0][4], Some(date(2026, 10, 1)));
+        assert_eq!(sunday.weekday_order()[0], Weekday::Sun);
+
+        let days: usize = monday.month_grid().iter().flatten().flatten().count();
+        assert_eq!(days, 31);
+    }
+
+    #[test]
+    fn test_month_navigation_clamps_day() {
+        let mut calendar = Calendar::new(date(2026, 1, 31));
+        calendar.move_months(1);
+        assert_eq!(calendar.selected, date(2026, 2, 28));
+        calendar.move_months(-2);
+        assert_eq!(calendar.selected, date(2025, 12, 28));
+    }
+
+    #[test]
+    fn test_picker_returns_selected_date() {
+        let mut picker = DatePicker::new(Calendar::new(date(2026, 10, 16)));
+        assert_eq!(picker.handle_key(KeyCode::Right), DatePickerAction::None);
+        assert_eq!(picker.handle_key(KeyCode::Enter), DatePickerAction::Picked(date(2026, 10, 17)));
+        assert_eq!(picker.handle_key(KeyCode::Esc), DatePickerAction::Cancelled);
+    }
+
+    #[test]
+    fn test_picker_renders_into_empty_area() {
+        let picker = DatePicker::new(Calendar::new(date(2026, 10, 16)));
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 0)).unwrap();
+        terminal.draw(|f| picker.render(f, f.area())).unwrap();
+
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 1)).unwrap();
+        terminal.draw(|f| picker.render(f, f.area())).unwrap();
+    }
+}
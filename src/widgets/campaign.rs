@@ -0,0 +1,90 @@
+//! Campaign files module
+//!
+//! Writes a campaign from the interactive forms as `campaign.json` and
+//! `README.md` in a new `campaign_<time>` directory. Callers pass a step
+//! callback to report progress or cancel between files.
+
+use std::fs;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Snapshot of the form values a campaign is generated from
+#[derive(Debug, Clone, Default)]
+pub struct CampaignRequest {
+    pub name: String,
+    pub email: String,
+    pub selection: String,
+    /// Table rows included in `campaign.json`
+    pub data: Vec<(String, u64)>,
+}
+
+impl CampaignRequest {
+    fn json(&self, now: DateTime<Utc>) -> Value {
+        serde_json::json!({
+            "name": self.name,
+            "email": self.email,
+            "selection": self.selection,
+            "created_at": now.to_rfc3339(),
+            "data": self.data,
+        })
+    }
+
+    fn readme(&self, now: DateTime<Utc>) -> String {
+        format!(
+            "# Campaign: {}\n\n- Email: {}\n- Selection: {}\n- Generated: {}\n",
+            self.name,
+            self.email,
+            self.selection,
+            now.format("%Y-%m-%d %H:%M:%S UTC")
+        )
+    }
+}
+
+/// Write the campaign files and return their directory. `step` is called
+/// with the progress ratio and a message before each part; an error from
+/// it stops the generation.
+pub fn create_campaign_files(
+    campaign: &CampaignRequest,
+    mut step: impl FnMut(f64, &str) -> Result<(), String>,
+) -> Result<String, String> {
+    let now = Utc::now();
+
+    step(0.1, "creating directory")?;
+    let campaign_dir = format!("campaign_{}", now.format("%Y%m%d_%H%M%S"));
+    fs::create_dir_all(&campaign_dir).map_err(|e| e.to_string())?;
+
+    step(0.4, "writing campaign.json")?;
+    let json = serde_json::to_string_pretty(&campaign.json(now)).map_err(|e| e.to_string())?;
+    fs::write(format!("{}/campaign.json", campaign_dir), json).map_err(|e| e.to_string())?;
+
+    step(0.7, "writing README.md")?;
+    fs::write(format!("{}/README.md", campaign_dir), campaign.readme(now)).map_err(|e| e.to_string())?;
+    step(1.0, "done")?;
+
+    Ok(campaign_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_campaign_contents() {
+        let campaign = CampaignRequest {
+            name: "Launch".to_string(),
+            email: "ada@example.com".to_string(),
+            selection: "Option 2".to_string(),
+            data: vec![("Mon".to_string(), 3)],
+        };
+        let now = DateTime::parse_from_rfc3339("2026-10-16T12:00:00Z").unwrap().with_timezone(&Utc);
+
+        let json = campaign.json(now);
+        assert_eq!(json["selection"], "Option 2");
+        assert_eq!(json["data"][0][1], 3);
+        assert_eq!(
+            campaign.readme(now),
+            "# Campaign: Launch\n\n- Email: ada@example.com\n- Selection: Option 2\n- Generated: 2026-10-16 12:00:00 UTC\n"
+        );
+    }
+}
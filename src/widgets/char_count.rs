@@ -0,0 +1,76 @@
+//! Character count module
+//!
+//! Live footer for input fields: grapheme count, byte length, display width
+//! and, with a maximum length, the characters remaining. The footer turns
+//! red once the limit is exceeded.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Measurements of an input's text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextStats {
+    /// User-perceived characters, so `é` and `👍🏽` count once
+    pub graphemes: usize,
+    pub bytes: usize,
+    /// Terminal columns the text occupies
+    pub width: usize,
+}
+
+impl TextStats {
+    pub fn of(text: &str) -> Self {
+        Self {
+            graphemes: text.graphemes(true).count(),
+            bytes: text.len(),
+            width: Line::raw(text).width(),
+        }
+    }
+}
+
+/// Whether `text` is longer than `max_len` graphemes
+pub fn exceeds(text: &str, max_len: Option<usize>) -> bool {
+    max_len.is_some_and(|max| TextStats::of(text).graphemes > max)
+}
+
+/// Footer line such as `12 chars · 14 bytes · width 12 · 8 left`
+pub fn footer(text: &str, max_len: Option<usize>) -> Line<'static> {
+    let stats = TextStats::of(text);
+    let mut summary = format!("{} chars · {} bytes · width {}", stats.graphemes, stats.bytes, stats.width);
+    let style = match max_len {
+        Some(max) if stats.graphemes > max => {
+            summary.push_str(&format!(" · {} over limit of {}", stats.graphemes - max, max));
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        }
+        Some(max) => {
+            summary.push_str(&format!(" · {} left", max - stats.graphemes));
+            Style::default().fg(Color::DarkGray)
+        }
+        None => Style::default().fg(Color::DarkGray),
+    };
+    Line::from(Span::styled(format!(" {} ", summary), style))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_and_limit() {
+        let stats = TextStats::of("cafe\u{301} 👍🏽");
+        assert_eq!(stats.graphemes, 6);
+        assert_eq!(stats.bytes, 15);
+
+        assert!(!exceeds("hello", Some(5)));
+        assert!(exceeds("hello!", Some(5)));
+        assert!(!exceeds("hello!", None));
+
+        let line = footer("abc", Some(5));
+        assert!(line.to_string().contains("2 left"));
+        let line = footer("abcdefg", Some(5));
+        assert!(line.to_string().contains("2 over limit of 5"));
+        assert_eq!(line.spans[0].style.fg, Some(Color::Red));
+    }
+}
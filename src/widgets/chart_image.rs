@@ -0,0 +1,332 @@
+//! Chart image module
+//!
+//! Rasterizes chart data into a high-resolution PNG with the image crate,
+//! drawing the plot itself rather than a screenshot of terminal cells. Bar,
+//! line, scatter and heatmap charts get gridlines and numeric axis ticks; the
+//! image can be saved or copied to the clipboard.
+
+#[cfg(feature = "clipboard")]
+use std::borrow::Cow;
+use std::path::Path;
+
+use image::{Rgb, RgbImage};
+
+/// Data for one of the supported chart kinds
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartData {
+    /// Labelled values drawn as bars from zero
+    Bars(Vec<(String, f64)>),
+    /// One polyline per series
+    Lines(Vec<Vec<(f64, f64)>>),
+    Points(Vec<(f64, f64)>),
+    /// Rows of values, colored from low (blue) to high (red)
+    Heatmap(Vec<Vec<f64>>),
+}
+
+/// Margin around the plot area, in pixels
+const MARGIN: u32 = 90;
+
+/// Ticks drawn on each axis
+const TICKS: usize = 5;
+
+/// 3×5 glyphs for tick labels, one row per `u8` with the low 3 bits used
+fn glyph(c: char) -> Option<[u8; 5]> {
+    Some(match c {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        _ => return None,
+    })
+}
+
+fn tick_label(value: f64) -> String {
+    if value.fract().abs() < 1e-9 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+/// Rendering settings for chart images
+#[derive(Debug, Clone)]
+pub struct ChartImage {
+    pub width: u32,
+    pub height: u32,
+    pub background: Rgb<u8>,
+    pub axis: Rgb<u8>,
+    pub grid: Rgb<u8>,
+    /// Series colors, reused in order
+    pub colors: Vec<Rgb<u8>>,
+}
+
+impl Default for ChartImage {
+    fn default() -> Self {
+        Self {
+            width: 1600,
+            height: 900,
+            background: Rgb([255, 255, 255]),
+            axis: Rgb([40, 40, 40]),
+            grid: Rgb([225, 225, 225]),
+            colors: vec![Rgb([38, 139, 210]), Rgb([220, 50, 47]), Rgb([133, 153, 0]), Rgb([181, 137, 0])],
+        }
+    }
+}
+
+/// Pixel mapping of the data ranges onto the plot area
+struct Plot {
+    left: f64,
+    top: f64,
+    width: f64,
+    height: f64,
+    x: (f64, f64),
+    y: (f64, f64),
+}
+
+impl Plot {
+    fn px(&self, x: f64) -> f64 {
+        self.left + (x - self.x.0) / (self.x.1 - self.x.0) * self.width
+    }
+
+    fn py(&self, y: f64) -> f64 {
+        self.top + self.height - (y - self.y.0) / (self.y.1 - self.y.0) * self.height
+    }
+}
+
+/// `(min, max)` of `values`, widened when empty or flat
+fn range(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)));
+    if !min.is_finite() || !max.is_finite() {
+        (0.0, 1.0)
+    } else if (max - min).abs() < f64::EPSILON {
+        (min - 1.0, max + 1.0)
+    } else {
+        (min, max)
+    }
+}
+
+fn heat_color(ratio: f64) -> Rgb<u8> {
+    let ratio = ratio.clamp(0.0, 1.0);
+    Rgb([(255.0 * ratio) as u8, (80.0 * (1.0 - (2.0 * ratio - 1.0).abs())) as u8, (255.0 * (1.0 - ratio)) as u8])
+}
+
+impl ChartImage {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            ..Self::default()
+        }
+    }
+
+    fn color(&self, index: usize) -> Rgb<u8> {
+        self.colors.get(index % self.colors.len().max(1)).copied().unwrap_or(self.axis)
+    }
+
+    pub fn render(&self, data: &ChartData) -> RgbImage {
+        let mut image = RgbImage::from_pixel(self.width, self.height, self.background);
+        let plot_range = match data {
+            ChartData::Bars(bars) => {
+                let (min, max) = range(bars.iter().map(|(_, v)| *v).chain([0.0]));
+                ((0.0, bars.len().max(1) as f64), (min.min(0.0), max))
+            }
+            ChartData::Lines(series) => {
+                let points = || series.iter().flatten();
+                (range(points().map(|p| p.0)), range(points().map(|p| p.1)))
+            }
+            ChartData::Points(points) => (range(points.iter().map(|p| p.0)), range(points.iter().map(|p| p.1))),
+            ChartData::Heatmap(rows) => (
+                (0.0, rows.iter().map(Vec::len).max().unwrap_or(1).max(1) as f64),
+                (0.0, rows.len().max(1) as f64),
+            ),
+        };
+        let plot = Plot {
+            left: MARGIN as f64,
+            top: (MARGIN / 2) as f64,
+            width: self.width.saturating_sub(MARGIN + MARGIN / 2).max(1) as f64,
+            height: self.height.saturating_sub(MARGIN + MARGIN / 2).max(1) as f64,
+            x: plot_range.0,
+            y: plot_range.1,
+        };
+
+        if !matches!(data, ChartData::Heatmap(_)) {
+            self.draw_grid(&mut image, &plot, !matches!(data, ChartData::Bars(_)));
+        }
+        match data {
+            ChartData::Bars(bars) => {
+                let slot = plot.width / bars.len().max(1) as f64;
+                for (i, (_, value)) in bars.iter().enumerate() {
+                    let x0 = plot.left + slot * (i as f64 + 0.15);
+                    let x1 = plot.left + slot * (i as f64 + 0.85);
+                    let (y0, y1) = (plot.py(value.max(0.0)), plot.py(value.min(0.0)));
+                    fill_rect(&mut image, x0, y0, x1, y1, self.color(0));
+                }
+            }
+            ChartData::Lines(series) => {
+                for (i, points) in series.iter().enumerate() {
+                    for pair in points.windows(2) {
+                        let (a, b) = (pair[0], pair[1]);
+                        draw_line(&mut image, (plot.px(a.0), plot.py(a.1)), (plot.px(b.0), plot.py(b.1)), 3.0, self.color(i));
+                    }
+                }
+            }
+            ChartData::Points(points) => {
+                for &(x, y) in points {
+                    fill_circle(&mut image, plot.px(x), plot.py(y), 6.0, self.color(0));
+                }
+            }
+            ChartData::Heatmap(rows) => {
+                let (min, max) = range(rows.iter().flatten().copied());
+                for (row, values) in rows.iter().enumerate() {
+                    for (col, value) in values.iter().enumerate() {
+                        let color = heat_color((value - min) / (max - min));
+                        let top = plot.py(rows.len() as f64 - row as f64);
+                        let bottom = plot.py(rows.len() as f64 - row as f64 - 1.0);
+                        fill_rect(&mut image, plot.px(col as f64), top, plot.px(col as f64 + 1.0), bottom, color);
+                    }
+                }
+            }
+        }
+        self.draw_axes(&mut image, &plot);
+        image
+    }
+
+    /// Horizontal gridlines with y labels, plus vertical ones with x labels
+    fn draw_grid(&self, image: &mut RgbImage, plot: &Plot, vertical: bool) {
+        for i in 0..=TICKS {
+            let ratio = i as f64 / TICKS as f64;
+            let y_value = plot.y.0 + (plot.y.1 - plot.y.0) * ratio;
+            let y = plot.py(y_value);
+            draw_line(image, (plot.left, y), (plot.left + plot.width, y), 1.0, self.grid);
+            let label = tick_label(y_value);
+            draw_text(image, &label, plot.left - 12.0 - text_width(&label), y - 7.5, self.axis);
+            if vertical {
+                let x_value = plot.x.0 + (plot.x.1 - plot.x.0) * ratio;
+                let x = plot.px(x_value);
+                draw_line(image, (x, plot.top), (x, plot.top + plot.height), 1.0, self.grid);
+                let label = tick_label(x_value);
+                draw_text(image, &label, x - text_width(&label) / 2.0, plot.top + plot.height + 14.0, self.axis);
+            }
+        }
+    }
+
+    fn draw_axes(&self, image: &mut RgbImage, plot: &Plot) {
+        let bottom = plot.top + plot.height;
+        draw_line(image, (plot.left, plot.top), (plot.left, bottom), 2.0, self.axis);
+        draw_line(image, (plot.left, bottom), (plot.left + plot.width, bottom), 2.0, self.axis);
+    }
+
+    pub fn save(&self, data: &ChartData, path: &Path) -> Result<(), String> {
+        self.render(data).save(path).map_err(|e| e.to_string())
+    }
+
+    #[cfg(feature = "clipboard")]
+    pub fn copy_to_clipboard(&self, data: &ChartData) -> Result<(), String> {
+        let image = image::DynamicImage::ImageRgb8(self.render(data)).to_rgba8();
+        let (width, height) = image.dimensions();
+        let image_data = arboard::ImageData {
+            width: width as usize,
+            height: height as usize,
+            bytes: Cow::Owned(image.into_raw()),
+        };
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_image(image_data))
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Width in pixels of `text` drawn with `draw_text`
+fn text_width(text: &str) -> f64 {
+    text.chars().count() as f64 * 4.0 * 3.0
+}
+
+/// Tick label in the 3×5 font scaled by three
+fn draw_text(image: &mut RgbImage, text: &str, x: f64, y: f64, color: Rgb<u8>) {
+    const SCALE: f64 = 3.0;
+    for (i, c) in text.chars().enumerate() {
+        let Some(rows) = glyph(c) else {
+            continue;
+        };
+        let left = x + i as f64 * 4.0 * SCALE;
+        for (row, bits) in rows.iter().enumerate() {
+            for col in 0..3 {
+                if bits & (0b100 >> col) != 0 {
+                    let px = left + col as f64 * SCALE;
+                    let py = y + row as f64 * SCALE;
+                    fill_rect(image, px, py, px + SCALE, py + SCALE, color);
+                }
+            }
+        }
+    }
+}
+
+fn put(image: &mut RgbImage, x: i64, y: i64, color: Rgb<u8>) {
+    if x >= 0 && y >= 0 && (x as u32) < image.width() && (y as u32) < image.height() {
+        image.put_pixel(x as u32, y as u32, color);
+    }
+}
+
+fn fill_rect(image: &mut RgbImage, x0: f64, y0: f64, x1: f64, y1: f64, color: Rgb<u8>) {
+    let (left, right) = (x0.min(x1).round() as i64, x0.max(x1).round() as i64);
+    let (top, bottom) = (y0.min(y1).round() as i64, y0.max(y1).round() as i64);
+    for y in top..bottom {
+        for x in left..right {
+            put(image, x, y, color);
+        }
+    }
+}
+
+fn fill_circle(image: &mut RgbImage, cx: f64, cy: f64, radius: f64, color: Rgb<u8>) {
+    let r = radius.ceil() as i64;
+    for dy in -r..=r {
+        for dx in -r..=r {
+            if (dx * dx + dy * dy) as f64 <= radius * radius {
+                put(image, cx.round() as i64 + dx, cy.round() as i64 + dy, color);
+            }
+        }
+    }
+}
+
+/// Line of the given thickness, stamped as circles along its length
+fn draw_line(image: &mut RgbImage, from: (f64, f64), to: (f64, f64), thickness: f64, color: Rgb<u8>) {
+    let length = ((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)).sqrt();
+    let steps = length.ceil().max(1.0) as usize;
+    for step in 0..=steps {
+        let t = step as f64 / steps as f64;
+        let (x, y) = (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t);
+        if thickness <= 1.0 {
+            put(image, x.round() as i64, y.round() as i64, color);
+        } else {
+            fill_circle(image, x, y, thickness / 2.0, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_bars_and_heatmap() {
+        let chart = ChartImage::new(400, 300);
+        let image = chart.render(&ChartData::Bars(vec![("a".to_string(), 1.0), ("b".to_string(), 2.0)]));
+        assert_eq!(image.dimensions(), (400, 300));
+        // The taller bar reaches the top of the plot area
+        let plot_top = MARGIN / 2;
+        let bar_x = MARGIN + (400 - MARGIN - MARGIN / 2) * 3 / 4;
+        assert_eq!(*image.get_pixel(bar_x, plot_top + 2), chart.colors[0]);
+        assert_eq!(*image.get_pixel(5, 5), chart.background);
+
+        let heatmap = chart.render(&ChartData::Heatmap(vec![vec![0.0, 1.0]]));
+        let right = *heatmap.get_pixel(400 - MARGIN / 2 - 5, 150);
+        assert_eq!(right, heat_color(1.0));
+    }
+}
@@ -0,0 +1,117 @@
+//! Component module
+//!
+//! Common trait for the stateful widgets a screen hosts, so a host can route
+//! events to, update, draw and focus them without knowing their type.
+//! `update` and `render` return failures instead of dropping them, so the
+//! host can show them to the user, e.g. as an error toast.
+//!
+//! Screens that draw borrowed data implement `StatefulScreen` instead: like
+//! ratatui's `StatefulWidget`, the widget state such as the selected row is
+//! passed in, and the app owns it as a `Stateful` field. Each table or list
+//! then keeps its own selection rather than sharing a global one.
+
+use ratatui::{layout::Rect, Frame};
+
+use crate::error::DemoError;
+use crate::event::{Event, KeyEvent, KeyEventKind};
+
+pub trait Component {
+    /// Handle a terminal event; returns whether the component used it
+    fn handle_event(&mut self, _event: &Event) -> bool {
+        false
+    }
+
+    /// Pick up background work such as a finished decode; call once per tick
+    fn update(&mut self) -> Result<(), DemoError> {
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError>;
+
+    /// Whether the component draws itself as the one receiving keys
+    fn is_focused(&self) -> bool {
+        false
+    }
+
+    /// Components without a focused look ignore this
+    fn set_focused(&mut self, _focused: bool) {}
+}
+
+/// A screen whose widget state, e.g. a `TableState`, is owned by its host
+pub trait StatefulScreen {
+    type State: Default;
+
+    /// Handle a key press; returns whether the screen used it
+    fn handle_key(&self, _key: KeyEvent, _state: &mut Self::State) -> bool {
+        false
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect, state: &mut Self::State);
+}
+
+/// A `StatefulScreen` together with its own state, to keep as an app field
+#[derive(Debug, Clone, Default)]
+pub struct Stateful<S: StatefulScreen> {
+    pub screen: S,
+    pub state: S::State,
+}
+
+impl<S: StatefulScreen> Stateful<S> {
+    pub fn new(screen: S) -> Self {
+        Self {
+            screen,
+            state: S::State::default(),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.screen.handle_key(key, &mut self.state)
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.screen.render(frame, area, &mut self.state);
+    }
+}
+
+/// The key of a key press event; releases and repeats are ignored
+pub fn key_press(event: &Event) -> Option<KeyEvent> {
+    match event {
+        Event::Key(key) if key.kind == KeyEventKind::Press => Some(*key),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KeyCode;
+    use ratatui::widgets::TableState;
+
+    struct Rows(usize);
+
+    impl StatefulScreen for Rows {
+        type State = TableState;
+
+        fn handle_key(&self, key: KeyEvent, state: &mut TableState) -> bool {
+            match key.code {
+                KeyCode::Down => state.select(Some(state.selected().map_or(0, |i| (i + 1).min(self.0 - 1)))),
+                _ => return false,
+            }
+            true
+        }
+
+        fn render(&self, _frame: &mut Frame, _area: Rect, _state: &mut TableState) {}
+    }
+
+    #[test]
+    fn test_screens_keep_their_own_state() {
+        let mut first = Stateful::new(Rows(3));
+        let mut second = Stateful::new(Rows(3));
+        for _ in 0..5 {
+            first.handle_key(KeyEvent::from(KeyCode::Down));
+        }
+        assert!(!second.handle_key(KeyEvent::from(KeyCode::Up)));
+        assert_eq!(first.state.selected(), Some(2));
+        assert_eq!(second.state.selected(), None);
+    }
+}
@@ -0,0 +1,133 @@
+//! Confetti module
+//!
+//! Short celebration overlay of colored glyphs falling over the screen,
+//! animated from its start time like the spinners. Drawn on top of the
+//! existing cells so the screen stays visible, and never started when the
+//! reduce-motion setting is on.
+
+use std::time::{Duration, Instant};
+
+use ratatui::{layout::Rect, style::Color, Frame};
+
+const GLYPHS: [&str; 8] = ["*", "+", "•", "◆", "▲", "●", "✦", "~"];
+const COLORS: [Color; 6] = [Color::Red, Color::Yellow, Color::Green, Color::Cyan, Color::Magenta, Color::LightBlue];
+
+/// Particles per celebration
+const PARTICLES: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Particle {
+    /// Horizontal start as a fraction of the width
+    x: f32,
+    /// Seconds before the particle starts falling
+    delay: f32,
+    /// Rows per second
+    speed: f32,
+    /// Amplitude of the sideways sway, in columns
+    sway: f32,
+    glyph: &'static str,
+    color: Color,
+}
+
+pub struct Confetti {
+    started: Instant,
+    duration: Duration,
+    particles: Vec<Particle>,
+}
+
+/// xorshift step, enough for scattering confetti without a rand dependency
+fn next_random(state: &mut u64) -> f32 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    (*state >> 40) as f32 / (1u64 << 24) as f32
+}
+
+impl Confetti {
+    /// Confetti scattered from `seed`, lasting `duration`
+    pub fn new(seed: u64, duration: Duration) -> Self {
+        let mut state = seed.max(1);
+        let particles = (0..PARTICLES)
+            .map(|_| Particle {
+                x: next_random(&mut state),
+                delay: next_random(&mut state) * duration.as_secs_f32() * 0.4,
+                speed: 8.0 + next_random(&mut state) * 16.0,
+                sway: next_random(&mut state) * 3.0,
+                glyph: GLYPHS[(next_random(&mut state) * GLYPHS.len() as f32) as usize % GLYPHS.len()],
+                color: COLORS[(next_random(&mut state) * COLORS.len() as f32) as usize % COLORS.len()],
+            })
+            .collect();
+        Self {
+            started: Instant::now(),
+            duration,
+            particles,
+        }
+    }
+
+    /// A two-second celebration, or `None` when motion should be reduced
+    pub fn celebrate(reduce_motion: bool) -> Option<Self> {
+        if reduce_motion {
+            return None;
+        }
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(1, |time| time.as_nanos() as u64);
+        Some(Self::new(seed, Duration::from_secs(2)))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.started.elapsed() >= self.duration
+    }
+
+    /// Cell, glyph and color of each particle inside `area` after `elapsed`
+    fn positions(&self, elapsed: Duration, area: Rect) -> Vec<(u16, u16, &'static str, Color)> {
+        if elapsed >= self.duration || area.width == 0 {
+            return Vec::new();
+        }
+        let t = elapsed.as_secs_f32();
+        self.particles
+            .iter()
+            .filter_map(|particle| {
+                let falling = t - particle.delay;
+                if falling < 0.0 {
+                    return None;
+                }
+                let row = (falling * particle.speed) as u16;
+                let column = particle.x * area.width as f32 + particle.sway * (falling * 4.0).sin();
+                let column = column.clamp(0.0, (area.width - 1) as f32) as u16;
+                (row < area.height).then_some((area.x + column, area.y + row, particle.glyph, particle.color))
+            })
+            .collect()
+    }
+
+    /// Draw the particles over whatever is already in `area`
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let buffer = frame.buffer_mut();
+        for (x, y, glyph, color) in self.positions(self.started.elapsed(), area) {
+            if let Some(cell) = buffer.cell_mut((x, y)) {
+                cell.set_symbol(glyph).set_fg(color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_particles_fall_within_area() {
+        assert!(Confetti::celebrate(true).is_none());
+
+        let confetti = Confetti::new(42, Duration::from_secs(2));
+        let area = Rect::new(5, 2, 40, 20);
+        let early = confetti.positions(Duration::from_millis(300), area);
+        let later = confetti.positions(Duration::from_millis(700), area);
+        assert!(!later.is_empty());
+        for (x, y, ..) in early.iter().chain(&later) {
+            assert!(area.x <= *x && *x < area.right());
+            assert!(area.y <= *y && *y < area.bottom());
+        }
+        assert!(confetti.positions(Duration::from_secs(2), area).is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+//! Console module
+//!
+//! Which terminal hosts the app, for the features that differ on Windows.
+//! The legacy console host (conhost) draws no inline images and prints OSC 8
+//! hyperlinks as garbage, and Windows Terminal renders Sixel but not the
+//! Kitty or iTerm image protocols. Probing for an unsupported protocol
+//! writes escape sequences the console echoes, or waits for a reply that
+//! never comes.
+//!
+//! Over SSH or without a display the app degrades: images are drawn as
+//! ASCII, copies go to the local terminal through OSC 52, and links are not
+//! handed to `xdg-open`, which would open them on the remote machine or fail.
+
+/// Terminal the app runs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleHost {
+    WindowsTerminal,
+    /// The legacy Windows console, e.g. a plain `cmd.exe` window
+    Conhost,
+    /// Any other terminal, including third-party terminals on Windows
+    Other,
+}
+
+/// Image protocols worth trying in the current terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphicsSupport {
+    pub kitty: bool,
+    pub iterm: bool,
+    pub sixel: bool,
+}
+
+impl ConsoleHost {
+    pub fn graphics(self) -> GraphicsSupport {
+        match self {
+            ConsoleHost::WindowsTerminal => GraphicsSupport { kitty: false, iterm: false, sixel: true },
+            ConsoleHost::Conhost => GraphicsSupport { kitty: false, iterm: false, sixel: false },
+            ConsoleHost::Other => GraphicsSupport { kitty: true, iterm: true, sixel: true },
+        }
+    }
+
+    /// Whether OSC 8 hyperlinks can be printed without corrupting the screen
+    pub fn hyperlinks_safe(self) -> bool {
+        self != ConsoleHost::Conhost
+    }
+}
+
+/// Detect the terminal from the environment
+pub fn host() -> ConsoleHost {
+    detect(cfg!(windows), |name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+}
+
+fn detect(windows: bool, var: impl Fn(&str) -> Option<String>) -> ConsoleHost {
+    if !windows {
+        ConsoleHost::Other
+    } else if var("WT_SESSION").is_some() {
+        ConsoleHost::WindowsTerminal
+    } else if var("TERM_PROGRAM").is_some() || var("TERM").is_some() {
+        // WezTerm, VS Code, mintty and the like announce themselves
+        ConsoleHost::Other
+    } else {
+        ConsoleHost::Conhost
+    }
+}
+
+/// What is reachable from this session
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Session {
+    /// Connected over SSH, so the desktop is on another machine
+    pub ssh: bool,
+    /// No X11 or Wayland display on a system that needs one
+    pub no_display: bool,
+    /// `TERM=dumb`, e.g. an editor's shell buffer
+    pub dumb_terminal: bool,
+}
+
+impl Session {
+    pub fn is_degraded(&self) -> bool {
+        self.ssh || self.no_display || self.dumb_terminal
+    }
+
+    /// Draw images as ASCII rather than through a graphics protocol
+    pub fn ascii_images(&self) -> bool {
+        self.is_degraded()
+    }
+
+    /// Copy through OSC 52 to the terminal the user sits at
+    pub fn osc52_clipboard(&self) -> bool {
+        self.ssh || self.no_display
+    }
+
+    /// Whether there is a local desktop to open links and files on
+    pub fn can_launch(&self) -> bool {
+        !self.ssh && !self.no_display
+    }
+
+    /// Status bar note on the reduced features, `None` when there are none
+    pub fn indicator(&self) -> Option<String> {
+        let cause = if self.ssh {
+            "SSH"
+        } else if self.no_display {
+            "No display"
+        } else if self.dumb_terminal {
+            "Dumb terminal"
+        } else {
+            return None;
+        };
+        let mut limits = vec!["ASCII images"];
+        if self.osc52_clipboard() {
+            limits.push("OSC 52 copy, no paste");
+        }
+        if !self.can_launch() {
+            limits.push("links not opened");
+        }
+        Some(format!("{}: {}", cause, limits.join(", ")))
+    }
+}
+
+/// Detect the session from the environment
+pub fn session() -> Session {
+    detect_session(cfg!(all(unix, not(target_os = "macos"))), |name| {
+        std::env::var(name).ok().filter(|value| !value.is_empty())
+    })
+}
+
+fn detect_session(needs_display: bool, var: impl Fn(&str) -> Option<String>) -> Session {
+    Session {
+        ssh: ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"].iter().any(|name| var(name).is_some()),
+        no_display: needs_display && var("DISPLAY").is_none() && var("WAYLAND_DISPLAY").is_none(),
+        dumb_terminal: var("TERM").as_deref() == Some("dumb"),
+    }
+}
+
+/// Image protocols for this terminal and session; none when degraded
+pub fn graphics() -> GraphicsSupport {
+    if session().ascii_images() {
+        GraphicsSupport { kitty: false, iterm: false, sixel: false }
+    } else {
+        host().graphics()
+    }
+}
+
+/// Escape sequence asking the terminal to put `text` on its clipboard
+pub fn osc52(text: &str) -> String {
+    format!("\x1B]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, byte)| n | ((*byte as u32) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_host() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+        };
+        assert_eq!(detect(false, env(&[])), ConsoleHost::Other);
+        assert_eq!(detect(true, env(&[])), ConsoleHost::Conhost);
+        assert_eq!(detect(true, env(&[("WT_SESSION", "1d0c")])), ConsoleHost::WindowsTerminal);
+        assert_eq!(detect(true, env(&[("TERM_PROGRAM", "WezTerm")])), ConsoleHost::Other);
+
+        assert!(!ConsoleHost::Conhost.graphics().sixel);
+        assert!(!ConsoleHost::WindowsTerminal.graphics().kitty);
+        assert!(!ConsoleHost::Conhost.hyperlinks_safe());
+    }
+
+    #[test]
+    fn test_degraded_session() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string())
+        };
+        let desktop = detect_session(true, env(&[("DISPLAY", ":0")]));
+        assert!(!desktop.is_degraded());
+        assert_eq!(desktop.indicator(), None);
+
+        let ssh = detect_session(true, env(&[("DISPLAY", ":0"), ("SSH_CONNECTION", "10.0.0.2 5122 10.0.0.1 22")]));
+        assert!(ssh.osc52_clipboard() && !ssh.can_launch());
+        assert_eq!(ssh.indicator().as_deref(), Some("SSH: ASCII images, OSC 52 copy, no paste, links not opened"));
+
+        let dumb = detect_session(false, env(&[("TERM", "dumb")]));
+        assert!(dumb.ascii_images() && dumb.can_launch() && !dumb.osc52_clipboard());
+
+        assert_eq!(base64(b"hi!?"), "aGkhPw==");
+        assert_eq!(osc52("hello"), "\x1B]52;c;aGVsbG8=\x07");
+    }
+}
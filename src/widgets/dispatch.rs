@@ -0,0 +1,437 @@
+//! Event dispatch module
+//!
+//! Central source of terminal events for the example loops. Key presses go
+//! through a macro recorder first: Alt+Q and a letter record the following
+//! keys into that register until Alt+Q is pressed again, and Alt+@ with an
+//! optional count and a letter replays it, e.g. Alt+@ 3 a. Digits there
+//! are the count, so a digit register is named after a `"`, as in
+//! Alt+@ 3 " 1. Alt+@ @ repeats the last replayed register. F10 saves a screenshot of the last drawn
+//! frame as text, ANSI text, SVG and HTML, Ctrl+F (Alt+S with the emacs
+//! keymap) opens a search over the screen's content and F12 toggles the profiler overlay. A demo script
+//! given to `play` runs ahead of the terminal until a key is pressed.
+
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use ratatui::{buffer::Buffer, Frame};
+
+use crate::event::{Event, EventSource, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use super::autopilot::{Autopilot, DemoStep};
+use super::keymap::KeymapProfile;
+use super::profiler::Profiler;
+use super::redact::redact_buffer;
+use super::report::{self, ReportTheme};
+use super::screen_search::ScreenSearch;
+
+/// Upper bound on keys queued by a single replay
+const MAX_REPLAY_KEYS: usize = 10_000;
+
+/// Events taken in one batch at most, so a huge paste still redraws now and then
+const MAX_BATCH: usize = 512;
+
+/// Macro command waiting for its register or count
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pending {
+    Record,
+    Replay(usize),
+    /// After `"`: the next key names the register, even a digit
+    Register(usize),
+}
+
+#[derive(Debug, Default)]
+pub struct MacroRecorder {
+    registers: HashMap<char, Vec<KeyEvent>>,
+    /// Register being recorded and the keys so far
+    recording: Option<(char, Vec<KeyEvent>)>,
+    pending: Option<Pending>,
+    last_replayed: Option<char>,
+}
+
+fn is_record_key(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::ALT) && matches!(key.code, KeyCode::Char('q') | KeyCode::Char('Q'))
+}
+
+fn is_replay_key(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::ALT) && key.code == KeyCode::Char('@')
+}
+
+impl MacroRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register being recorded
+    pub fn recording(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    pub fn register(&self, name: char) -> Option<&[KeyEvent]> {
+        self.registers.get(&name).map(Vec::as_slice)
+    }
+
+    /// Short indicator for a status bar, e.g. `recording @a`
+    pub fn status(&self) -> Option<String> {
+        match (self.pending, self.recording()) {
+            (Some(Pending::Record), _) => Some("record macro: press a register letter or digit".to_string()),
+            (Some(Pending::Replay(0)), _) => Some("replay macro: count and register".to_string()),
+            (Some(Pending::Replay(count) | Pending::Register(count)), _) => {
+                Some(format!("replay macro {}×: register", count))
+            }
+            (None, Some(register)) => Some(format!("recording @{}", register)),
+            (None, None) => None,
+        }
+    }
+
+    /// Feed a pressed key; returns the keys the screen should handle. Macro
+    /// commands are consumed and never recorded, so a macro cannot replay
+    /// itself.
+    pub fn process(&mut self, key: KeyEvent) -> Vec<KeyEvent> {
+        if let Some(pending) = self.pending.take() {
+            return self.finish_command(pending, key);
+        }
+        if is_record_key(&key) {
+            match self.recording.take() {
+                Some((register, keys)) => {
+                    self.registers.insert(register, keys);
+                }
+                None => self.pending = Some(Pending::Record),
+            }
+            return Vec::new();
+        }
+        if is_replay_key(&key) {
+            self.pending = Some(Pending::Replay(0));
+            return Vec::new();
+        }
+        if let Some((_, keys)) = &mut self.recording {
+            keys.push(key);
+        }
+        vec![key]
+    }
+
+    fn finish_command(&mut self, pending: Pending, key: KeyEvent) -> Vec<KeyEvent> {
+        let KeyCode::Char(c) = key.code else {
+            // Esc or any other key cancels the command
+            return Vec::new();
+        };
+        match pending {
+            Pending::Record if c.is_ascii_alphanumeric() => {
+                self.recording = Some((c, Vec::new()));
+            }
+            Pending::Replay(count) if c.is_ascii_digit() => {
+                let digit = c.to_digit(10).unwrap_or(0) as usize;
+                self.pending = Some(Pending::Replay((count * 10 + digit).min(MAX_REPLAY_KEYS)));
+            }
+            Pending::Replay(count) if c == '"' => self.pending = Some(Pending::Register(count)),
+            Pending::Replay(count) | Pending::Register(count) => {
+                let register = match c {
+                    '@' if matches!(pending, Pending::Replay(_)) => self.last_replayed,
+                    c if c.is_ascii_alphanumeric() => Some(c),
+                    _ => None,
+                };
+                if let Some(keys) = register.and_then(|register| self.registers.get(&register)) {
+                    self.last_replayed = register;
+                    return keys
+                        .iter()
+                        .copied()
+                        .cycle()
+                        .take((keys.len() * count.max(1)).min(MAX_REPLAY_KEYS))
+                        .collect();
+                }
+            }
+            Pending::Record => {}
+        }
+        Vec::new()
+    }
+}
+
+/// Reads terminal events, expanding macro replays into queued key presses
+#[derive(Debug, Default)]
+pub struct EventDispatcher {
+    /// The terminal by default; see `with_source`
+    source: Box<dyn EventSource>,
+    pub macros: MacroRecorder,
+    queue: VecDeque<Event>,
+    /// Colors for SVG and HTML screenshots
+    pub screenshot_theme: ReportTheme,
+    /// Last frame passed to `frame_drawn`
+    last_frame: Option<Buffer>,
+    /// Outcome of the last screenshot, shown until the next key
+    screenshot_message: Option<String>,
+    /// Ctrl+F search bar
+    pub search: ScreenSearch,
+    /// Host's keymap, whose bindings the search shortcut stays clear of
+    pub keymap: KeymapProfile,
+    /// F12 frame rate and events-per-frame overlay
+    pub profiler: Profiler,
+    /// Demo script being played
+    autopilot: Option<Autopilot>,
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dispatcher reading from `source` instead of the terminal, e.g. a
+    /// `QueuedEvents` fed by the browser
+    pub fn with_source(source: Box<dyn EventSource>) -> Self {
+        Self {
+            source,
+            ..Self::default()
+        }
+    }
+
+    /// Pick the search shortcut that does not clash with `profile`
+    pub fn with_keymap(mut self, profile: KeymapProfile) -> Self {
+        self.keymap = profile;
+        self
+    }
+
+    /// Play `steps` as key presses until they run out or a key is pressed
+    pub fn play(&mut self, steps: &[DemoStep]) {
+        self.autopilot = Some(Autopilot::new(steps));
+    }
+
+    /// Whether a demo script is playing
+    pub fn is_playing(&self) -> bool {
+        self.autopilot.is_some()
+    }
+
+    /// Next event within `timeout`: queued replay keys first, then the
+    /// demo script, then the terminal. Returns `None` on timeout or when a key was consumed by a
+    /// macro command, so the caller can redraw.
+    pub fn next(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let event = self.next_event(timeout)?;
+        if event.is_some() {
+            self.profiler.record_events(1);
+        }
+        Ok(event)
+    }
+
+    fn next_event(&mut self, mut timeout: Duration) -> io::Result<Option<Event>> {
+        if let Some(event) = self.queue.pop_front() {
+            return Ok(Some(event));
+        }
+        if let Some(autopilot) = self.autopilot.as_mut() {
+            let now = Instant::now();
+            if let Some(key) = autopilot.next_due(now) {
+                if autopilot.is_finished() {
+                    self.autopilot = None;
+                }
+                return Ok(self.route(Event::Key(key)));
+            }
+            timeout = timeout.min(autopilot.until_next(now));
+        }
+        if !self.source.poll(timeout)? {
+            return Ok(None);
+        }
+        let event = self.source.read()?;
+        if matches!(event, Event::Key(key) if key.kind == KeyEventKind::Press) {
+            // Someone took over the demo
+            self.autopilot = None;
+        }
+        Ok(self.route(event))
+    }
+
+    /// Handle the dispatcher's own shortcuts and macro keys in `event`
+    fn route(&mut self, event: Event) -> Option<Event> {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press && key.code == KeyCode::F(10) => {
+                self.screenshot_message = Some(self.save_screenshot());
+                None
+            }
+            Event::Key(key) if key.kind == KeyEventKind::Press && Profiler::is_shortcut(&key) => {
+                self.profiler.toggle();
+                None
+            }
+            Event::Key(key) if key.kind == KeyEventKind::Press && ScreenSearch::is_shortcut(&key, self.keymap) => {
+                if self.search.visible {
+                    self.search.close();
+                } else {
+                    self.search.open();
+                }
+                None
+            }
+            Event::Key(key) if key.kind == KeyEventKind::Press && self.search.visible => {
+                self.search.handle_key(key);
+                None
+            }
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                self.screenshot_message = None;
+                self.queue.extend(self.macros.process(key).into_iter().map(Event::Key));
+                self.queue.pop_front()
+            }
+            other => Some(other),
+        }
+    }
+
+    /// Every event that is already waiting, after waiting up to `timeout`
+    /// for the first. Handling the whole batch before redrawing keeps fast
+    /// typing and unbracketed pastes from lagging a frame per key.
+    pub fn next_batch(&mut self, timeout: Duration) -> io::Result<Vec<Event>> {
+        let mut events: Vec<Event> = self.next(timeout)?.into_iter().collect();
+        if !events.is_empty() {
+            self.drain_waiting(&mut events)?;
+        }
+        Ok(events)
+    }
+
+    /// `read` followed by every event that is already waiting
+    pub fn read_batch(&mut self) -> io::Result<Vec<Event>> {
+        let mut events = vec![self.read()?];
+        self.drain_waiting(&mut events)?;
+        Ok(events)
+    }
+
+    /// Append the events that are already waiting. Stops early at a key
+    /// consumed by a macro command or the search bar, since the keys after
+    /// it may depend on the redrawn screen.
+    fn drain_waiting(&mut self, events: &mut Vec<Event>) -> io::Result<()> {
+        while events.len() < MAX_BATCH {
+            match self.next(Duration::ZERO)? {
+                Some(event) => events.push(event),
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// Draw the search bar and profiler over the screen; call last when drawing
+    pub fn render(&mut self, frame: &mut Frame) {
+        self.search.render(frame);
+        self.profiler.render(frame);
+    }
+
+    /// Keep the frame just drawn for F10, e.g. `events.frame_drawn(terminal.draw(..)?.buffer)`
+    pub fn frame_drawn(&mut self, buffer: &Buffer) {
+        self.profiler.record_frame();
+        match &mut self.last_frame {
+            Some(frame) if frame.area == buffer.area => frame.content.clone_from(&buffer.content),
+            frame => *frame = Some(buffer.clone()),
+        }
+    }
+
+    /// Write the last frame to `screenshot-<time>.*` in the current directory
+    fn save_screenshot(&self) -> String {
+        let Some(mut frame) = self.last_frame.clone() else {
+            return "No frame to capture yet".to_string();
+        };
+        redact_buffer(&mut frame);
+        let stem = PathBuf::from(format!("screenshot-{}", chrono::Local::now().format("%Y%m%d-%H%M%S")));
+        match report::write_screenshot(&frame, &stem, &self.screenshot_theme) {
+            Ok(_) => format!("Screenshot saved to {}.{{txt,ans,svg,html}}", stem.display()),
+            Err(e) => format!("Screenshot failed: {}", e),
+        }
+    }
+
+    /// `title` with the macro, demo or screenshot status appended, for a
+    /// screen's status block
+    pub fn status_title(&self, title: &str) -> String {
+        let demo = self.is_playing().then(|| "demo: press any key to take over".to_string());
+        match self.macros.status().or(demo).or_else(|| self.screenshot_message.clone()) {
+            Some(status) => format!("{} ● {}", title, status),
+            None => title.to_string(),
+        }
+    }
+
+    /// Wait for the next event, for loops without a tick. Keys typed into
+    /// the search bar come back as a resize to the current size, so the
+    /// caller redraws the bar and its highlights.
+    pub fn read(&mut self) -> io::Result<Event> {
+        loop {
+            let searching = self.search.visible;
+            if let Some(event) = self.next(Duration::from_secs(60))? {
+                return Ok(event);
+            }
+            if searching || self.search.visible {
+                let (columns, rows) = self.source.size()?;
+                return Ok(Event::Resize(columns, rows));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::QueuedEvents;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn alt(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    #[test]
+    fn test_record_and_replay_with_count() {
+        let mut macros = MacroRecorder::new();
+        assert!(macros.process(alt('q')).is_empty());
+        assert!(macros.process(key('a')).is_empty());
+        assert_eq!(macros.recording(), Some('a'));
+        assert_eq!(macros.process(key('j')), vec![key('j')]);
+        assert_eq!(macros.process(key('x')), vec![key('x')]);
+        assert!(macros.process(alt('q')).is_empty());
+        assert_eq!(macros.recording(), None);
+        assert_eq!(macros.register('a'), Some(&[key('j'), key('x')][..]));
+
+        for k in [alt('@'), key('3')] {
+            assert!(macros.process(k).is_empty());
+        }
+        assert_eq!(macros.process(key('a')).len(), 6);
+        macros.process(alt('@'));
+        assert_eq!(macros.process(key('@')), vec![key('j'), key('x')]);
+        macros.process(alt('@'));
+        assert!(macros.process(key('z')).is_empty());
+    }
+
+    #[test]
+    fn test_search_shortcut_follows_keymap() {
+        let ctrl = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+        let mut emacs = EventDispatcher::new().with_keymap(KeymapProfile::Emacs);
+        assert_eq!(emacs.route(ctrl('f')), Some(ctrl('f')));
+        assert!(!emacs.search.visible);
+        assert_eq!(emacs.route(Event::Key(alt('s'))), None);
+        assert!(emacs.search.visible);
+
+        let mut default = EventDispatcher::new();
+        assert_eq!(default.route(ctrl('f')), None);
+        assert!(default.search.visible);
+    }
+
+    #[test]
+    fn test_digit_register_replays_after_quote() {
+        let mut macros = MacroRecorder::new();
+        macros.process(alt('q'));
+        macros.process(key('1'));
+        assert_eq!(macros.recording(), Some('1'));
+        macros.process(key('j'));
+        macros.process(alt('q'));
+        assert_eq!(macros.register('1'), Some(&[key('j')][..]));
+
+        for k in [alt('@'), key('2'), key('"')] {
+            assert!(macros.process(k).is_empty());
+        }
+        assert_eq!(macros.process(key('1')), vec![key('j'), key('j')]);
+        macros.process(alt('@'));
+        macros.process(key('"'));
+        assert_eq!(macros.process(key('1')), vec![key('j')]);
+        macros.process(alt('@'));
+        assert_eq!(macros.process(key('@')), vec![key('j')]);
+    }
+
+    #[test]
+    fn test_dispatcher_reads_from_queued_source() {
+        let events = QueuedEvents::new();
+        let mut dispatcher = EventDispatcher::with_source(Box::new(events.clone()));
+        assert_eq!(dispatcher.next(Duration::ZERO).unwrap(), None);
+
+        events.push(Event::Key(key('j')));
+        events.push(Event::Resize(90, 20));
+        assert_eq!(dispatcher.next_batch(Duration::ZERO).unwrap(), vec![Event::Key(key('j')), Event::Resize(90, 20)]);
+    }
+}
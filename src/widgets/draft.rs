@@ -0,0 +1,124 @@
+//! Draft autosave module
+//!
+//! Periodically writes in-progress form state to `drafts/<name>.json` in the
+//! data directory, so a crash or an early quit can be recovered from on the
+//! next launch. Forms discard the draft once it is submitted or emptied.
+
+use std::fs;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Minimum time between two draft writes
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(2);
+
+pub struct DraftStore<T> {
+    path: Option<PathBuf>,
+    /// Serialized form of the last write, to skip unchanged drafts
+    saved: Option<String>,
+    last_save: Option<Instant>,
+    _draft: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> DraftStore<T> {
+    /// Draft backed by `drafts/<name>.json` in the data directory
+    pub fn persistent(name: &str) -> Self {
+        let path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("drafts");
+            path.push(format!("{}.json", name));
+            path
+        });
+        Self::at(path)
+    }
+
+    /// Draft stored at `path`; `None` keeps nothing
+    pub fn at(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            saved: None,
+            last_save: None,
+            _draft: PhantomData,
+        }
+    }
+
+    /// Draft left by a previous session, if any
+    pub fn load(&self) -> Option<T> {
+        let content = fs::read_to_string(self.path.as_ref()?).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Write `draft` when it changed and the autosave interval has passed;
+    /// call this on every tick of the event loop
+    pub fn autosave(&mut self, draft: &T) {
+        if self.last_save.is_some_and(|last| last.elapsed() < AUTOSAVE_INTERVAL) {
+            return;
+        }
+        self.save(draft);
+    }
+
+    /// Write `draft` now if it changed since the last write
+    pub fn save(&mut self, draft: &T) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let content = match serde_json::to_string_pretty(draft) {
+            Ok(content) => content,
+            Err(e) => {
+                log::error!("Failed to serialize form draft: {}", e);
+                return;
+            }
+        };
+        self.last_save = Some(Instant::now());
+        if self.saved.as_deref() == Some(content.as_str()) {
+            return;
+        }
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(path, &content));
+        match result {
+            Ok(()) => self.saved = Some(content),
+            Err(e) => log::error!("Failed to save form draft: {}", e),
+        }
+    }
+
+    /// Remove the draft file, e.g. after a successful submit
+    pub fn discard(&mut self) {
+        if let Some(path) = &self.path {
+            if path.exists() {
+                if let Err(e) = fs::remove_file(path) {
+                    log::error!("Failed to remove form draft: {}", e);
+                }
+            }
+        }
+        self.saved = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load_and_discard() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("drafts").join("form.json");
+        let mut store: DraftStore<Vec<String>> = DraftStore::at(Some(path.clone()));
+        assert_eq!(store.load(), None);
+
+        store.autosave(&vec!["Ada".to_string()]);
+        // Within the interval further changes wait for the next autosave
+        store.autosave(&vec!["Ada L".to_string()]);
+        assert_eq!(DraftStore::<Vec<String>>::at(Some(path.clone())).load(), Some(vec!["Ada".to_string()]));
+
+        store.save(&vec!["Ada L".to_string()]);
+        assert_eq!(store.load(), Some(vec!["Ada L".to_string()]));
+
+        store.discard();
+        assert!(!path.exists());
+        assert_eq!(store.load(), None);
+    }
+}
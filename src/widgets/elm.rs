@@ -0,0 +1,215 @@
+//! Elm architecture module
+//!
+//! An alternative to the imperative app structs of the examples: a screen
+//! is a `Program` with a model, a `Msg` enum, an `update` that applies one
+//! message and returns a `Cmd` for side effects, and a `view` that only
+//! reads the model. Terminal events become messages in `on_event`, and
+//! `Cmd::perform` runs slow work off the loop and feeds its result back as
+//! a message, so every state change goes through `update`.
+
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+use ratatui::{backend::Backend, Frame, Terminal};
+
+use crate::error::DemoError;
+use crate::event::Event;
+use crate::platform;
+
+use super::dispatch::EventDispatcher;
+
+/// Side effect requested by `update`
+pub enum Cmd<M> {
+    None,
+    /// Stop the program after this update
+    Quit,
+    Batch(Vec<Cmd<M>>),
+    /// Run on a background thread; the message it returns goes to `update`
+    Perform(Box<dyn FnOnce() -> M + Send>),
+}
+
+impl<M> Cmd<M> {
+    pub fn perform(task: impl FnOnce() -> M + Send + 'static) -> Self {
+        Cmd::Perform(Box::new(task))
+    }
+}
+
+/// A screen in the Elm style; the implementing type is the model
+pub trait Program {
+    type Msg: Send + 'static;
+
+    /// Command to run when the program starts
+    fn init(&mut self) -> Cmd<Self::Msg> {
+        Cmd::None
+    }
+
+    fn update(&mut self, msg: Self::Msg) -> Cmd<Self::Msg>;
+
+    fn view(&self, frame: &mut Frame);
+
+    /// Message for a terminal event, if the program cares about it
+    fn on_event(&self, event: Event) -> Option<Self::Msg>;
+
+    /// Message sent every tick, e.g. to advance an animation
+    fn on_tick(&self) -> Option<Self::Msg> {
+        None
+    }
+}
+
+/// Runs a `Program`: queues messages, applies them and executes commands
+pub struct Runtime<P: Program> {
+    pub program: P,
+    queue: VecDeque<P::Msg>,
+    /// Results of `Cmd::Perform` coming back from their threads
+    sender: Sender<P::Msg>,
+    receiver: Receiver<P::Msg>,
+    quit: bool,
+}
+
+impl<P: Program> Runtime<P> {
+    pub fn new(mut program: P) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let init = program.init();
+        let mut runtime = Self {
+            program,
+            queue: VecDeque::new(),
+            sender,
+            receiver,
+            quit: false,
+        };
+        runtime.execute(init);
+        runtime
+    }
+
+    pub fn send(&mut self, msg: P::Msg) {
+        self.queue.push_back(msg);
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Apply queued messages and finished commands until none are left;
+    /// returns how many were applied
+    pub fn process(&mut self) -> usize {
+        let mut count = 0;
+        while !self.quit {
+            let Some(msg) = self.queue.pop_front().or_else(|| self.receiver.try_recv().ok()) else {
+                break;
+            };
+            let cmd = self.program.update(msg);
+            self.execute(cmd);
+            count += 1;
+        }
+        count
+    }
+
+    fn execute(&mut self, cmd: Cmd<P::Msg>) {
+        match cmd {
+            Cmd::None => {}
+            Cmd::Quit => self.quit = true,
+            Cmd::Batch(cmds) => cmds.into_iter().for_each(|cmd| self.execute(cmd)),
+            Cmd::Perform(task) => {
+                let sender = self.sender.clone();
+                platform::current().background(Box::new(move || {
+                    let _ = sender.send(task());
+                }));
+            }
+        }
+    }
+
+    /// Draw, read events and update until a `Cmd::Quit`
+    pub fn run<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        events: &mut EventDispatcher,
+        tick_rate: Duration,
+    ) -> Result<(), DemoError> {
+        let mut last_tick = Instant::now();
+        while !self.quit {
+            let frame = terminal.draw(|f| {
+                self.program.view(f);
+                events.render(f);
+            })?;
+            events.frame_drawn(frame.buffer);
+
+            let timeout = tick_rate.saturating_sub(last_tick.elapsed());
+            if let Some(msg) = events.next(timeout)?.and_then(|event| self.program.on_event(event)) {
+                self.send(msg);
+            }
+            if last_tick.elapsed() >= tick_rate {
+                if let Some(msg) = self.program.on_tick() {
+                    self.send(msg);
+                }
+                last_tick = Instant::now();
+            }
+            self.process();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyCode, KeyEvent};
+
+    struct Counter {
+        count: i32,
+    }
+
+    enum Msg {
+        Add(i32),
+        Double,
+        Quit,
+    }
+
+    impl Program for Counter {
+        type Msg = Msg;
+
+        fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+            match msg {
+                Msg::Add(n) => self.count += n,
+                Msg::Double => {
+                    let count = self.count;
+                    return Cmd::perform(move || Msg::Add(count));
+                }
+                Msg::Quit => return Cmd::Quit,
+            }
+            Cmd::None
+        }
+
+        fn view(&self, _frame: &mut Frame) {}
+
+        fn on_event(&self, event: Event) -> Option<Msg> {
+            match event {
+                Event::Key(key) if key.code == KeyCode::Char('q') => Some(Msg::Quit),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_and_commands() {
+        let mut runtime = Runtime::new(Counter { count: 0 });
+        runtime.send(Msg::Add(2));
+        runtime.send(Msg::Double);
+        assert_eq!(runtime.process(), 2);
+
+        // The doubled value arrives from the command's thread
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while runtime.program.count != 4 && Instant::now() < deadline {
+            runtime.process();
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(runtime.program.count, 4);
+
+        let quit = runtime.program.on_event(Event::Key(KeyEvent::from(KeyCode::Char('q'))));
+        runtime.send(quit.unwrap());
+        runtime.send(Msg::Add(1));
+        runtime.process();
+        assert!(runtime.should_quit());
+        assert_eq!(runtime.program.count, 4);
+    }
+}
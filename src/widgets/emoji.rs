@@ -0,0 +1,474 @@
+//! Emoji module
+//!
+//! Shortcode database and `:shortcode:` expansion for text inputs. Typing
+//! `:rocket:` and a space replaces the shortcode with 🚀, and while a
+//! shortcode is being typed `ShortcodeCompleter` lists matches in a popup
+//! (↑↓ select, Tab/Enter complete, Esc dismiss). `EmojiPicker` browses the
+//! emoji by category in a grid. The emoji, their names and picker
+//! categories come from a compressed dataset decoded at first use.
+
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+use once_cell::sync::Lazy;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::error::DemoError;
+use crate::event::{Event, KeyCode, KeyEvent};
+
+use super::component::{key_press, Component};
+use super::selection::byte_index;
+
+/// Suggestions listed in the popup
+const MAX_SUGGESTIONS: usize = 8;
+
+/// Emoji per row of the picker grid
+const PICKER_COLUMNS: usize = 5;
+
+/// Emoji dataset, a gzip-compressed TSV of `category, emoji, shortcodes,
+/// name` rows with the shortcodes (GitHub/Slack names) separated by spaces
+const EMOJI_DATA: &[u8] = include_bytes!("../../assets/emoji.tsv.gz");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmojiEntry {
+    pub category: String,
+    pub emoji: String,
+    pub shortcodes: Vec<String>,
+    pub name: String,
+}
+
+/// Decoded on first use rather than at startup
+static EMOJIS: Lazy<Vec<EmojiEntry>> = Lazy::new(|| {
+    decode(EMOJI_DATA).unwrap_or_else(|e| {
+        log::error!("Failed to load emoji data: {}", e);
+        Vec::new()
+    })
+});
+
+fn decode(data: &[u8]) -> Result<Vec<EmojiEntry>, String> {
+    let mut bytes = Vec::new();
+    GzDecoder::new(data).read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+    let text = String::from_utf8(bytes).map_err(|e| e.to_string())?;
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| match line.split('\t').collect::<Vec<_>>()[..] {
+            [category, emoji, shortcodes, name] => Ok(EmojiEntry {
+                category: category.to_string(),
+                emoji: emoji.to_string(),
+                shortcodes: shortcodes.split_whitespace().map(str::to_string).collect(),
+                name: name.to_string(),
+            }),
+            _ => Err(format!("line {}: expected 4 fields", index + 1)),
+        })
+        .collect()
+}
+
+/// Every emoji, grouped by category
+pub fn emojis() -> &'static [EmojiEntry] {
+    &EMOJIS
+}
+
+/// Category names in dataset order
+pub fn categories() -> Vec<&'static str> {
+    let mut categories: Vec<&'static str> = Vec::new();
+    for entry in emojis() {
+        if !categories.contains(&entry.category.as_str()) {
+            categories.push(&entry.category);
+        }
+    }
+    categories
+}
+
+pub fn in_category(category: &str) -> Vec<&'static EmojiEntry> {
+    emojis().iter().filter(|entry| entry.category == category).collect()
+}
+
+/// Every shortcode with its emoji
+fn shortcodes() -> impl Iterator<Item = (&'static str, &'static str)> {
+    emojis()
+        .iter()
+        .flat_map(|entry| entry.shortcodes.iter().map(move |code| (code.as_str(), entry.emoji.as_str())))
+}
+
+/// Emoji for an exact shortcode
+pub fn lookup(shortcode: &str) -> Option<&'static str> {
+    shortcodes().find(|(code, _)| *code == shortcode).map(|(_, emoji)| emoji)
+}
+
+/// Shortcodes starting with `query`, then those containing it
+pub fn search(query: &str) -> Vec<(&'static str, &'static str)> {
+    let query = query.to_lowercase();
+    let prefixed = shortcodes().filter(|(code, _)| code.starts_with(&query));
+    let containing = shortcodes().filter(|(code, _)| !code.starts_with(&query) && code.contains(&query));
+    prefixed.chain(containing).take(MAX_SUGGESTIONS).collect()
+}
+
+fn is_shortcode_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-')
+}
+
+/// Start of the shortcode name ending at character `end`, if it follows a
+/// colon at the start of the text or after whitespace (so `10:30` and URLs
+/// are left alone). Returns the colon's character index.
+fn shortcode_start(chars: &[char], end: usize) -> Option<usize> {
+    let mut start = end;
+    while start > 0 && is_shortcode_char(chars[start - 1]) {
+        start -= 1;
+    }
+    let colon = start.checked_sub(1)?;
+    let opens = chars[colon] == ':' && (colon == 0 || chars[colon - 1].is_whitespace());
+    (opens && start < end).then_some(colon)
+}
+
+/// Colon position and name of the shortcode being typed before `cursor`
+pub fn partial_at(text: &str, cursor: usize) -> Option<(usize, String)> {
+    let chars: Vec<char> = text.chars().collect();
+    let cursor = cursor.min(chars.len());
+    let colon = shortcode_start(&chars, cursor)?;
+    Some((colon, chars[colon + 1..cursor].iter().collect()))
+}
+
+/// Replace `text`'s characters `from..to` with `emoji`, moving the cursor
+/// from `to` by the change in length
+fn replace_chars(text: &mut String, cursor: &mut usize, from: usize, to: usize, emoji: &str) {
+    let range = byte_index(text, from)..byte_index(text, to);
+    text.replace_range(range, emoji);
+    *cursor = *cursor + emoji.chars().count() + from - to;
+}
+
+/// Expand a `:shortcode:` that was just closed by a space before `cursor`
+pub fn expand_before_cursor(text: &mut String, cursor: &mut usize) -> bool {
+    let chars: Vec<char> = text.chars().collect();
+    let end = (*cursor).min(chars.len());
+    if end < 2 || !chars[end - 1].is_whitespace() || chars[end - 2] != ':' {
+        return false;
+    }
+    let Some(colon) = shortcode_start(&chars, end - 2) else {
+        return false;
+    };
+    let name: String = chars[colon + 1..end - 2].iter().collect();
+    match lookup(&name) {
+        Some(emoji) => {
+            replace_chars(text, cursor, colon, end - 1, emoji);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Suggestion popup state for one text input
+#[derive(Debug, Default)]
+pub struct ShortcodeCompleter {
+    selected: usize,
+    /// Colon position whose suggestions were dismissed with Esc
+    dismissed: Option<usize>,
+}
+
+impl ShortcodeCompleter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matching shortcodes for the text being typed before `cursor`
+    pub fn suggestions(&self, text: &str, cursor: usize) -> Vec<(&'static str, &'static str)> {
+        match partial_at(text, cursor) {
+            Some((colon, _)) if self.dismissed == Some(colon) => Vec::new(),
+            Some((_, partial)) => search(&partial),
+            None => Vec::new(),
+        }
+    }
+
+    /// Keys for the open popup; returns whether the key was used
+    pub fn handle_key(&mut self, text: &mut String, cursor: &mut usize, key: KeyEvent) -> bool {
+        let suggestions = self.suggestions(text, *cursor);
+        let Some((colon, _)) = partial_at(text, *cursor).filter(|_| !suggestions.is_empty()) else {
+            return false;
+        };
+        let count = suggestions.len();
+        match key.code {
+            KeyCode::Up => self.selected = (self.selected.min(count - 1) + count - 1) % count,
+            KeyCode::Down => self.selected = (self.selected + 1) % count,
+            KeyCode::Tab | KeyCode::Enter => {
+                let (_, emoji) = suggestions[self.selected.min(count - 1)];
+                let end = *cursor;
+                replace_chars(text, cursor, colon, end, emoji);
+                self.selected = 0;
+            }
+            KeyCode::Esc => self.dismissed = Some(colon),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Call after each edit: expands a completed shortcode and resets the
+    /// popup selection. Returns whether the text changed.
+    pub fn after_edit(&mut self, text: &mut String, cursor: &mut usize) -> bool {
+        self.selected = 0;
+        if partial_at(text, *cursor).map(|(colon, _)| colon) != self.dismissed {
+            self.dismissed = None;
+        }
+        expand_before_cursor(text, cursor)
+    }
+
+    /// Draw the suggestions under the screen cell `at` (the text cursor),
+    /// or above it when there is no room, clipped to `bounds`
+    pub fn render(&self, frame: &mut Frame, text: &str, cursor: usize, at: Position, bounds: Rect) {
+        let suggestions = self.suggestions(text, cursor);
+        if suggestions.is_empty() {
+            return;
+        }
+        let height = (suggestions.len() as u16 + 2).min(bounds.height);
+        let width = 32.min(bounds.width);
+        let y = if at.y + 1 + height <= bounds.bottom() {
+            at.y + 1
+        } else {
+            at.y.saturating_sub(height).max(bounds.y)
+        };
+        let x = at.x.min(bounds.right().saturating_sub(width)).max(bounds.x);
+        let popup = Rect::new(x, y, width, height);
+
+        let items: Vec<ListItem> = suggestions
+            .iter()
+            .map(|(code, emoji)| {
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{} ", emoji)),
+                    Span::styled(format!(":{}:", code), Style::default().fg(Color::Cyan)),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title_bottom("Tab: insert | Esc: dismiss")
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+        let mut state = ListState::default().with_selected(Some(self.selected.min(suggestions.len() - 1)));
+        frame.render_widget(Clear, popup);
+        frame.render_stateful_widget(list, popup, &mut state);
+    }
+}
+
+/// Category tabs over a grid of emoji: ←→ switch category, ↑↓ move a row,
+/// Enter picks the highlighted emoji
+#[derive(Debug)]
+pub struct EmojiPicker {
+    categories: Vec<&'static str>,
+    category_index: usize,
+    emoji_index: usize,
+    selected: Option<&'static str>,
+    focused: bool,
+}
+
+impl Default for EmojiPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmojiPicker {
+    pub fn new() -> Self {
+        Self {
+            categories: categories(),
+            category_index: 0,
+            emoji_index: 0,
+            selected: None,
+            focused: false,
+        }
+    }
+
+    /// Emoji in the current category
+    pub fn emojis(&self) -> Vec<&'static EmojiEntry> {
+        self.categories
+            .get(self.category_index)
+            .map(|category| in_category(category))
+            .unwrap_or_default()
+    }
+
+    /// Highlighted emoji
+    pub fn current(&self) -> Option<&'static EmojiEntry> {
+        self.emojis().get(self.emoji_index).copied()
+    }
+
+    /// Last emoji picked with Enter
+    pub fn selected(&self) -> Option<&'static str> {
+        self.selected
+    }
+
+    fn select_category(&mut self, index: usize) {
+        self.category_index = index;
+        self.emoji_index = 0;
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Left if self.category_index > 0 => self.select_category(self.category_index - 1),
+            KeyCode::Right if self.category_index + 1 < self.categories.len() => {
+                self.select_category(self.category_index + 1)
+            }
+            KeyCode::Up if self.emoji_index >= PICKER_COLUMNS => self.emoji_index -= PICKER_COLUMNS,
+            KeyCode::Down if self.emoji_index + PICKER_COLUMNS < self.emojis().len() => {
+                self.emoji_index += PICKER_COLUMNS
+            }
+            KeyCode::Enter => match self.current() {
+                Some(entry) => self.selected = Some(entry.emoji.as_str()),
+                None => return false,
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    /// Draw the category bar, the grid and the highlighted emoji's name
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(3), Constraint::Length(3)])
+            .split(area);
+        let border = Style::default().fg(if self.focused { Color::Cyan } else { Color::Reset });
+
+        let tabs: Vec<Span> = self
+            .categories
+            .iter()
+            .enumerate()
+            .map(|(i, category)| {
+                if i == self.category_index {
+                    Span::styled(format!("[{category}]"), Style::default().fg(Color::Black).bg(Color::Cyan))
+                } else {
+                    Span::styled(format!(" {category} "), Style::default().fg(Color::Gray))
+                }
+            })
+            .collect();
+        let tabs = Paragraph::new(Line::from(tabs))
+            .block(Block::default().borders(Borders::ALL).title("Categories").border_style(border))
+            .alignment(Alignment::Center);
+        frame.render_widget(tabs, chunks[0]);
+
+        // Scrolled to keep the highlighted row visible
+        let emojis = self.emojis();
+        let grid: Vec<Line> = emojis
+            .chunks(PICKER_COLUMNS)
+            .enumerate()
+            .map(|(row, entries)| {
+                let cells: Vec<Span> = entries
+                    .iter()
+                    .enumerate()
+                    .map(|(col, entry)| {
+                        let style = if row * PICKER_COLUMNS + col == self.emoji_index {
+                            Style::default().fg(Color::Black).bg(Color::Cyan)
+                        } else {
+                            Style::default().fg(Color::White)
+                        };
+                        Span::styled(format!(" {} ", entry.emoji), style)
+                    })
+                    .collect();
+                Line::from(cells)
+            })
+            .collect();
+        let visible_rows = chunks[1].height.saturating_sub(2) as usize;
+        let scroll = (self.emoji_index / PICKER_COLUMNS + 1).saturating_sub(visible_rows);
+        let grid = Paragraph::new(grid)
+            .block(Block::default().borders(Borders::ALL).title("Emojis").border_style(border))
+            .scroll((scroll as u16, 0));
+        frame.render_widget(grid, chunks[1]);
+
+        if let Some(entry) = self.current() {
+            let current = Paragraph::new(format!("{} - {}", entry.emoji, entry.name))
+                .block(Block::default().borders(Borders::ALL).title("Current").border_style(border))
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Yellow));
+            frame.render_widget(current, chunks[2]);
+        }
+    }
+}
+
+impl Component for EmojiPicker {
+    fn handle_event(&mut self, event: &Event) -> bool {
+        key_press(event).is_some_and(|key| self.handle_key(key.code))
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError> {
+        EmojiPicker::render(self, frame, area);
+        Ok(())
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_and_complete_shortcodes() {
+        let mut text = "launch :rocket: ".to_string();
+        let mut cursor = text.chars().count();
+        assert!(expand_before_cursor(&mut text, &mut cursor));
+        assert_eq!(text, "launch 🚀 ");
+        assert_eq!(cursor, 9);
+
+        let mut time = "at 10:30: ".to_string();
+        let mut cursor = time.chars().count();
+        assert!(!expand_before_cursor(&mut time, &mut cursor));
+
+        assert_eq!(partial_at("hi :roc", 7), Some((3, "roc".to_string())));
+        assert_eq!(partial_at("http://x", 8), None);
+        assert_eq!(search("rock").first(), Some(&("rocket", "🚀")));
+
+        let mut completer = ShortcodeCompleter::new();
+        let mut text = "go :tad".to_string();
+        let mut cursor = 7;
+        let tab = KeyEvent::new(KeyCode::Tab, crate::event::KeyModifiers::NONE);
+        assert!(completer.handle_key(&mut text, &mut cursor, tab));
+        assert_eq!(text, "go 🎉");
+        assert_eq!(cursor, 4);
+        assert!(!completer.handle_key(&mut text, &mut cursor, tab));
+    }
+
+    #[test]
+    fn test_emoji_data_is_valid() {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let entries = decode(EMOJI_DATA).unwrap();
+        assert!(!entries.is_empty());
+        let mut seen = std::collections::HashSet::new();
+        for entry in &entries {
+            assert_eq!(entry.emoji.graphemes(true).count(), 1, "{:?}", entry);
+            assert!(!entry.category.is_empty() && !entry.name.is_empty(), "{:?}", entry);
+            for code in &entry.shortcodes {
+                assert!(code.chars().all(is_shortcode_char), "{:?}", entry);
+                assert!(seen.insert(code.clone()), "duplicate shortcode {}", code);
+            }
+        }
+        assert_eq!(lookup("+1"), lookup("thumbsup"));
+        assert_eq!(categories().first(), Some(&"smileys"));
+        assert!(in_category("food").iter().any(|entry| entry.emoji == "🍕"));
+    }
+
+    #[test]
+    fn test_picker_navigation() {
+        let mut picker = EmojiPicker::new();
+        assert!(!picker.handle_key(KeyCode::Up));
+        assert!(picker.handle_key(KeyCode::Down));
+        let entry = picker.current().unwrap();
+        assert_eq!(entry, in_category("smileys")[PICKER_COLUMNS]);
+        assert!(picker.handle_event(&Event::Key(KeyEvent::from(KeyCode::Enter))));
+        assert_eq!(picker.selected(), Some(entry.emoji.as_str()));
+
+        assert!(picker.handle_key(KeyCode::Right));
+        assert_eq!(picker.current().map(|entry| entry.category.as_str()), categories().get(1).copied());
+    }
+}
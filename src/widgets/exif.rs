@@ -0,0 +1,206 @@
+//! EXIF metadata module
+//!
+//! Reads camera, exposure, GPS and timestamp details from an image with
+//! `kamadak-exif`, renders them as a side panel, and saves copies of images
+//! with the metadata removed.
+
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use exif::{In, Tag, Value};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// The EXIF details shown in the panel
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExifInfo {
+    /// Make and model, e.g. `Canon EOS 80D`
+    pub camera: Option<String>,
+    pub lens: Option<String>,
+    /// Exposure time, aperture, ISO and focal length as `(label, value)`
+    pub exposure: Vec<(&'static str, String)>,
+    /// Latitude and longitude in decimal degrees
+    pub gps: Option<(f64, f64)>,
+    pub altitude: Option<String>,
+    pub taken: Option<String>,
+}
+
+/// Degrees from EXIF degrees/minutes/seconds, negative for `S` and `W`
+pub fn dms_to_degrees(dms: &[f64], reference: &str) -> Option<f64> {
+    let [degrees, minutes, seconds] = dms else {
+        return None;
+    };
+    let value = degrees + minutes / 60.0 + seconds / 3600.0;
+    Some(if matches!(reference.trim(), "S" | "W") { -value } else { value })
+}
+
+impl ExifInfo {
+    /// Metadata of the image at `path`; `None` when it has no EXIF
+    pub fn read(path: &Path) -> Option<Self> {
+        let file = File::open(path).ok()?;
+        let exif = exif::Reader::new().read_from_container(&mut BufReader::new(file)).ok()?;
+        Some(Self::from_exif(&exif))
+    }
+
+    pub fn from_exif(exif: &exif::Exif) -> Self {
+        let text = |tag: Tag| {
+            exif.get_field(tag, In::PRIMARY)
+                .map(|field| field.display_value().with_unit(exif).to_string().trim_matches('"').trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let rationals = |tag: Tag| match exif.get_field(tag, In::PRIMARY).map(|field| &field.value) {
+            Some(Value::Rational(values)) => values.iter().map(|value| value.to_f64()).collect(),
+            _ => Vec::new(),
+        };
+        let coordinate = |value: Tag, reference: Tag| dms_to_degrees(&rationals(value), &text(reference).unwrap_or_default());
+
+        let camera = match (text(Tag::Make), text(Tag::Model)) {
+            (Some(make), Some(model)) if model.starts_with(&make) => Some(model),
+            (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+            (make, model) => make.or(model),
+        };
+        let exposure = [
+            ("Exposure", Tag::ExposureTime),
+            ("Aperture", Tag::FNumber),
+            ("ISO", Tag::PhotographicSensitivity),
+            ("Focal length", Tag::FocalLength),
+        ]
+        .into_iter()
+        .filter_map(|(label, tag)| text(tag).map(|value| (label, value)))
+        .collect();
+
+        Self {
+            camera,
+            lens: text(Tag::LensModel),
+            exposure,
+            gps: coordinate(Tag::GPSLatitude, Tag::GPSLatitudeRef)
+                .zip(coordinate(Tag::GPSLongitude, Tag::GPSLongitudeRef)),
+            altitude: text(Tag::GPSAltitude),
+            taken: text(Tag::DateTimeOriginal).or_else(|| text(Tag::DateTime)),
+        }
+    }
+
+    /// Panel lines grouped under Camera, Exposure, Location and Taken
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let heading = |title: &str| Line::styled(title.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+        let entry = |label: &str, value: &str| {
+            Line::from(vec![
+                Span::styled(format!(" {}: ", label), Style::default().fg(Color::DarkGray)),
+                Span::raw(value.to_string()),
+            ])
+        };
+        let mut lines = vec![heading("Camera")];
+        lines.push(entry("Model", self.camera.as_deref().unwrap_or("unknown")));
+        if let Some(lens) = &self.lens {
+            lines.push(entry("Lens", lens));
+        }
+        if !self.exposure.is_empty() {
+            lines.push(Line::raw(""));
+            lines.push(heading("Exposure"));
+            lines.extend(self.exposure.iter().map(|(label, value)| entry(label, value)));
+        }
+        if let Some((latitude, longitude)) = self.gps {
+            lines.push(Line::raw(""));
+            lines.push(heading("Location"));
+            lines.push(entry("Lat", &format!("{:.5}", latitude)));
+            lines.push(entry("Lon", &format!("{:.5}", longitude)));
+            if let Some(altitude) = &self.altitude {
+                lines.push(entry("Alt", altitude));
+            }
+        }
+        if let Some(taken) = &self.taken {
+            lines.push(Line::raw(""));
+            lines.push(heading("Taken"));
+            lines.push(entry("Date", taken));
+        }
+        lines
+    }
+}
+
+/// Side panel with the EXIF details of the current image
+pub fn render_panel(frame: &mut Frame, area: Rect, info: Option<&ExifInfo>) {
+    let lines = match info {
+        Some(info) => info.lines(),
+        None => vec![Line::styled("No EXIF metadata", Style::default().fg(Color::DarkGray))],
+    };
+    let panel = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title("EXIF"));
+    frame.render_widget(panel, area);
+}
+
+/// JPEG bytes without the EXIF APP1 segments, leaving the image data
+/// untouched; `None` if `bytes` is not a JPEG
+pub fn strip_jpeg_exif(bytes: &[u8]) -> Option<Vec<u8>> {
+    if !bytes.starts_with(&[0xFF, 0xD8]) {
+        return None;
+    }
+    let mut output = vec![0xFF, 0xD8];
+    let mut pos = 2;
+    while pos + 4 <= bytes.len() && bytes[pos] == 0xFF {
+        let marker = bytes[pos + 1];
+        // Start of scan: the rest is entropy-coded image data
+        if marker == 0xDA {
+            break;
+        }
+        let length = u16::from_be_bytes([bytes[pos + 2], bytes[pos + 3]]) as usize;
+        let end = (pos + 2 + length).clamp(pos + 4, bytes.len());
+        let is_exif = marker == 0xE1 && bytes[pos + 4..end].starts_with(b"Exif\0\0");
+        if !is_exif {
+            output.extend_from_slice(&bytes[pos..end]);
+        }
+        pos = end;
+    }
+    output.extend_from_slice(&bytes[pos.min(bytes.len())..]);
+    Some(output)
+}
+
+/// `photo.jpg` -> `photo-noexif.jpg`
+pub fn stripped_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+    let name = match path.extension() {
+        Some(extension) => format!("{}-noexif.{}", stem, extension.to_string_lossy()),
+        None => format!("{}-noexif", stem),
+    };
+    path.with_file_name(name)
+}
+
+/// Save a copy of `path` without EXIF next to it and return the copy's path.
+/// JPEGs are copied losslessly; other formats are re-encoded, which drops
+/// their metadata.
+pub fn save_without_exif(path: &Path, image: &image::DynamicImage) -> Result<PathBuf, String> {
+    let destination = stripped_path(path);
+    let bytes = fs::read(path).map_err(|e| e.to_string())?;
+    match strip_jpeg_exif(&bytes) {
+        Some(stripped) => fs::write(&destination, stripped).map_err(|e| e.to_string())?,
+        None => image.save(&destination).map_err(|e| e.to_string())?,
+    }
+    Ok(destination)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gps_and_jpeg_stripping() {
+        assert_eq!(dms_to_degrees(&[10.0, 30.0, 0.0], "N"), Some(10.5));
+        assert_eq!(dms_to_degrees(&[1.0, 15.0, 0.0], "W"), Some(-1.25));
+        assert_eq!(dms_to_degrees(&[1.0], "N"), None);
+
+        let app0 = [0xFF, 0xE0, 0x00, 0x04, 0xAA, 0xBB];
+        let exif = [0xFF, 0xE1, 0x00, 0x08, b'E', b'x', b'i', b'f', 0, 0];
+        let scan = [0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9];
+        let jpeg = [&[0xFF, 0xD8][..], &app0, &exif, &scan].concat();
+        assert_eq!(strip_jpeg_exif(&jpeg), Some([&[0xFF, 0xD8][..], &app0, &scan].concat()));
+        assert_eq!(strip_jpeg_exif(b"\x89PNG"), None);
+
+        assert_eq!(stripped_path(Path::new("dir/photo.jpg")), Path::new("dir/photo-noexif.jpg"));
+    }
+}
@@ -0,0 +1,237 @@
+//! Table export module
+//!
+//! Serializes `TableData` as CSV, JSON (an array of objects keyed by header)
+//! or a GitHub-flavored Markdown table, and an export popup that picks the
+//! format and scope and either copies to the clipboard or asks for a path.
+
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use serde_json::{Map, Value};
+
+use crate::event::{KeyCode, KeyEvent};
+
+use super::path_input::{PathInput, PathInputAction};
+use super::table::TableData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [ExportFormat::Csv, ExportFormat::Json, ExportFormat::Markdown];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Markdown => "Markdown",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            ExportFormat::Csv => ExportFormat::Json,
+            ExportFormat::Json => ExportFormat::Markdown,
+            ExportFormat::Markdown => ExportFormat::Csv,
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', "<br>")
+}
+
+pub fn to_csv(data: &TableData) -> String {
+    std::iter::once(&data.headers)
+        .chain(&data.rows)
+        .map(|row| row.iter().map(|value| csv_field(value)).collect::<Vec<_>>().join(","))
+        .map(|line| line + "\n")
+        .collect()
+}
+
+/// Array of objects keyed by header, in column order
+pub fn to_json(data: &TableData) -> String {
+    let rows: Vec<Value> = data
+        .rows
+        .iter()
+        .map(|row| {
+            let object: Map<String, Value> = data
+                .headers
+                .iter()
+                .zip(row)
+                .map(|(header, value)| (header.clone(), Value::String(value.clone())))
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+    serde_json::to_string_pretty(&rows).unwrap_or_default()
+}
+
+pub fn to_markdown(data: &TableData) -> String {
+    let row = |cells: &[String]| format!("| {} |\n", cells.iter().map(|cell| markdown_cell(cell)).collect::<Vec<_>>().join(" | "));
+    let mut markdown = row(&data.headers);
+    markdown.push_str(&format!("|{}\n", " --- |".repeat(data.headers.len())));
+    for cells in &data.rows {
+        markdown.push_str(&row(cells));
+    }
+    markdown
+}
+
+pub fn export(data: &TableData, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Csv => to_csv(data),
+        ExportFormat::Json => to_json(data),
+        ExportFormat::Markdown => to_markdown(data),
+    }
+}
+
+/// What the caller should do after a key in the export popup
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportAction {
+    None,
+    Cancel,
+    /// Copy the export to the clipboard
+    Copy,
+    /// Write the export to this file
+    Write(PathBuf),
+}
+
+/// Popup choosing format and scope; `c` copies, `w` asks for a path
+pub struct ExportDialog {
+    pub format: ExportFormat,
+    /// Export only the selected cells instead of the whole table
+    pub selection_only: bool,
+    /// Whether there is a selection to export
+    pub has_selection: bool,
+    path: Option<PathInput>,
+}
+
+impl ExportDialog {
+    pub fn new(has_selection: bool) -> Self {
+        Self {
+            format: ExportFormat::Csv,
+            selection_only: has_selection,
+            has_selection,
+            path: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ExportAction {
+        if let Some(path) = &mut self.path {
+            match path.handle_key_event(key) {
+                PathInputAction::Submit(path) => return ExportAction::Write(path),
+                PathInputAction::Cancel => self.path = None,
+                PathInputAction::None => {}
+            }
+            return ExportAction::None;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return ExportAction::Cancel,
+            KeyCode::Tab | KeyCode::Char('f') => self.format = self.format.next(),
+            KeyCode::Char('s') if self.has_selection => self.selection_only = !self.selection_only,
+            KeyCode::Char('c') => return ExportAction::Copy,
+            KeyCode::Char('w') | KeyCode::Enter => {
+                let start = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+                self.path = Some(self.path_prompt(&start));
+            }
+            _ => {}
+        }
+        ExportAction::None
+    }
+
+    /// Path prompt pre-filled with `table.<ext>` in `dir`
+    fn path_prompt(&self, dir: &Path) -> PathInput {
+        let mut input = PathInput::new(dir).with_title(&format!("Export {} to", self.format.name()));
+        input.value.push_str(&format!("table.{}", self.format.extension()));
+        input
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if let Some(path) = &self.path {
+            path.render(frame, area);
+            return;
+        }
+        let width = 50.min(area.width);
+        let height = 7.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        let chosen = Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD);
+        let formats: Vec<Span> = ExportFormat::ALL
+            .iter()
+            .flat_map(|&format| {
+                let style = if format == self.format { chosen } else { Style::default() };
+                [Span::styled(format!(" {} ", format.name()), style), Span::raw(" ")]
+            })
+            .collect();
+        let scope = match (self.has_selection, self.selection_only) {
+            (false, _) => "Whole table (no selection)",
+            (true, true) => "Selected cells",
+            (true, false) => "Whole table",
+        };
+        let lines = vec![
+            Line::from([vec![Span::raw("Format: ")], formats].concat()),
+            Line::from(vec![Span::raw("Scope:  "), Span::styled(scope, Style::default().fg(Color::Yellow))]),
+            Line::raw(""),
+            Line::styled(
+                "Tab: format | s: scope | c: copy | w: write file | Esc: close",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Export ")
+            .border_style(Style::default().fg(Color::Cyan));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exporters() {
+        let data = TableData {
+            headers: vec!["Name".to_string(), "Note".to_string()],
+            rows: vec![vec!["Ada".to_string(), "a|b, \"c\"".to_string()]],
+        };
+        assert_eq!(to_csv(&data), "Name,Note\nAda,\"a|b, \"\"c\"\"\"\n");
+        assert_eq!(to_markdown(&data), "| Name | Note |\n| --- | --- |\n| Ada | a\\|b, \"c\" |\n");
+
+        let json: Value = serde_json::from_str(&to_json(&data)).unwrap();
+        assert_eq!(json, serde_json::json!([{ "Name": "Ada", "Note": "a|b, \"c\"" }]));
+        // Keys keep the column order
+        assert!(to_json(&data).find("Name") < to_json(&data).find("Note"));
+    }
+}
@@ -0,0 +1,263 @@
+//! Feed reader module
+//!
+//! RSS and Atom feeds fetched on background threads, with read state kept
+//! per article id in the data directory. Article bodies are HTML and are
+//! converted to wrapped plain text for display.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::platform;
+
+/// One entry of a feed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Article {
+    /// Feed-provided id, falling back to the link
+    pub id: String,
+    pub title: String,
+    pub link: Option<String>,
+    pub published: Option<DateTime<Utc>>,
+    /// Full content when the feed has it, otherwise the summary
+    pub html: String,
+}
+
+impl Article {
+    /// Body as plain text wrapped at `width` columns
+    pub fn text(&self, width: usize) -> String {
+        html_to_text(&self.html, width)
+    }
+}
+
+/// A configured feed and its latest fetch result
+#[derive(Debug, Clone, PartialEq)]
+pub struct Channel {
+    pub url: String,
+    /// Feed title once fetched, the URL until then
+    pub title: String,
+    pub articles: Vec<Article>,
+    pub error: Option<String>,
+    pub loading: bool,
+}
+
+impl Channel {
+    fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            title: url.to_string(),
+            articles: Vec::new(),
+            error: None,
+            loading: false,
+        }
+    }
+}
+
+/// Wrapped plain text for an HTML fragment; paragraphs, lists and links
+/// keep their structure, with link targets listed as footnotes
+pub fn html_to_text(html: &str, width: usize) -> String {
+    html2text::from_read(html.as_bytes(), width.max(20))
+}
+
+/// Parse an RSS or Atom document, newest articles first
+pub fn parse_feed(url: &str, content: impl Read) -> Result<Channel, String> {
+    let feed = feed_rs::parser::parse(content).map_err(|e| e.to_string())?;
+    let mut articles: Vec<Article> = feed
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let link = entry.links.first().map(|link| link.href.clone());
+            let html = entry
+                .content
+                .and_then(|content| content.body)
+                .or(entry.summary.map(|summary| summary.content))
+                .unwrap_or_default();
+            Article {
+                id: if entry.id.is_empty() { link.clone().unwrap_or_default() } else { entry.id },
+                title: entry.title.map_or_else(|| "(untitled)".to_string(), |title| title.content),
+                link,
+                published: entry.published.or(entry.updated),
+                html,
+            }
+        })
+        .collect();
+    articles.sort_by_key(|article| std::cmp::Reverse(article.published));
+    Ok(Channel {
+        title: feed.title.map_or_else(|| url.to_string(), |title| title.content),
+        articles,
+        ..Channel::new(url)
+    })
+}
+
+fn fetch(url: &str) -> Result<Channel, String> {
+    let response = ureq::get(url)
+        .timeout(Duration::from_secs(15))
+        .call()
+        .map_err(|e| e.to_string())?;
+    parse_feed(url, response.into_reader())
+}
+
+pub struct FeedReader {
+    pub channels: Vec<Channel>,
+    read: HashSet<String>,
+    read_path: Option<PathBuf>,
+    sender: Sender<(usize, Result<Channel, String>)>,
+    receiver: Receiver<(usize, Result<Channel, String>)>,
+}
+
+impl FeedReader {
+    /// Reader for `urls`, with read state in `feeds-read.json` in the data directory
+    pub fn new(urls: &[String]) -> Self {
+        let read_path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("feeds-read.json");
+            path
+        });
+        Self::with_read_path(urls, read_path)
+    }
+
+    pub fn with_read_path(urls: &[String], read_path: Option<PathBuf>) -> Self {
+        let read = read_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            channels: urls.iter().map(|url| Channel::new(url.trim())).collect(),
+            read,
+            read_path,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Fetch every feed that is not already loading
+    pub fn refresh(&mut self) {
+        for (index, channel) in self.channels.iter_mut().enumerate() {
+            if channel.loading {
+                continue;
+            }
+            channel.loading = true;
+            let sender = self.sender.clone();
+            let url = channel.url.clone();
+            platform::current().background(Box::new(move || {
+                let _ = sender.send((index, fetch(&url)));
+            }));
+        }
+    }
+
+    /// Install finished fetches; returns whether anything changed
+    pub fn poll(&mut self) -> bool {
+        let mut changed = false;
+        while let Ok((index, result)) = self.receiver.try_recv() {
+            if index < self.channels.len() {
+                self.install(index, result);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    fn install(&mut self, index: usize, result: Result<Channel, String>) {
+        let channel = &mut self.channels[index];
+        match result {
+            Ok(fetched) => *channel = fetched,
+            Err(error) => {
+                // Keep the previous articles so a failed refresh does not empty the list
+                channel.error = Some(error);
+                channel.loading = false;
+            }
+        }
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.channels.iter().any(|channel| channel.loading)
+    }
+
+    pub fn is_read(&self, article: &Article) -> bool {
+        self.read.contains(&article.id)
+    }
+
+    pub fn unread_count(&self, channel: &Channel) -> usize {
+        channel.articles.iter().filter(|article| !self.is_read(article)).count()
+    }
+
+    pub fn total_unread(&self) -> usize {
+        self.channels.iter().map(|channel| self.unread_count(channel)).sum()
+    }
+
+    pub fn set_read(&mut self, id: &str, read: bool) {
+        let changed = if read { self.read.insert(id.to_string()) } else { self.read.remove(id) };
+        if changed {
+            self.save_read();
+        }
+    }
+
+    /// Mark every article of a channel as read
+    pub fn mark_all_read(&mut self, index: usize) {
+        let Some(channel) = self.channels.get(index) else {
+            return;
+        };
+        self.read.extend(channel.articles.iter().map(|article| article.id.clone()));
+        self.save_read();
+    }
+
+    fn save_read(&self) {
+        let Some(path) = &self.read_path else {
+            return;
+        };
+        let result = serde_json::to_string(&self.read)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                path.parent().map_or(Ok(()), fs::create_dir_all).map_err(|e| e.to_string())?;
+                fs::write(path, content).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::error!("Failed to save read articles: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>Example News</title>
+  <item><guid>a</guid><title>Older</title><link>https://example.com/a</link>
+    <pubDate>Mon, 01 Jul 2024 10:00:00 GMT</pubDate><description>&lt;p&gt;First &lt;b&gt;post&lt;/b&gt;&lt;/p&gt;</description></item>
+  <item><guid>b</guid><title>Newer</title><link>https://example.com/b</link>
+    <pubDate>Tue, 02 Jul 2024 10:00:00 GMT</pubDate><description>Second</description></item>
+</channel></rss>"#;
+
+    #[test]
+    fn test_parse_feed_and_read_state() {
+        let channel = parse_feed("https://example.com/feed", RSS.as_bytes()).unwrap();
+        assert_eq!(channel.title, "Example News");
+        let titles: Vec<&str> = channel.articles.iter().map(|article| article.title.as_str()).collect();
+        assert_eq!(titles, vec!["Newer", "Older"]);
+        assert_eq!(channel.articles[1].link.as_deref(), Some("https://example.com/a"));
+        assert!(channel.articles[1].text(40).contains("First"));
+        assert!(!channel.articles[1].text(40).contains("<p>"));
+        assert!(parse_feed("https://example.com/feed", "not a feed".as_bytes()).is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("read.json");
+        let urls = vec!["https://example.com/feed".to_string()];
+        let mut reader = FeedReader::with_read_path(&urls, Some(path.clone()));
+        reader.install(0, Ok(channel));
+        assert_eq!(reader.total_unread(), 2);
+        reader.set_read("a", true);
+
+        let mut reopened = FeedReader::with_read_path(&urls, Some(path));
+        reopened.install(0, Ok(reader.channels[0].clone()));
+        assert_eq!(reopened.unread_count(&reopened.channels[0]), 1);
+        reopened.mark_all_read(0);
+        assert_eq!(reopened.total_unread(), 0);
+    }
+}
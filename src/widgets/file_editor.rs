@@ -0,0 +1,287 @@
+//! File editor module
+//!
+//! A `TextArea` backed by a file on disk. Edits are written back once typing
+//! has been idle for the auto-save delay, and the file's directory is
+//! watched so a change made by another program is noticed. Without unsaved
+//! edits the buffer just reloads; with them, a dialog asks whether to
+//! reload, keep the buffer or merge the two with conflict markers, so
+//! nothing is overwritten silently.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::selection::TextPos;
+use super::text_area::TextArea;
+use super::watcher::FileWatcher;
+use crate::error::DemoError;
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Idle time after the last edit before the file is saved
+pub const AUTOSAVE_DELAY: Duration = Duration::from_secs(2);
+
+pub struct FileEditor {
+    pub path: PathBuf,
+    pub area: TextArea,
+    /// Content last read from or written to the file
+    saved: String,
+    last_edit: Option<Instant>,
+    autosave_delay: Duration,
+    watcher: FileWatcher,
+    /// Content on disk that changed while the buffer had unsaved edits
+    conflict: Option<String>,
+    /// Outcome of the last save or reload, shown in the border
+    pub status: String,
+}
+
+impl FileEditor {
+    pub fn open(path: &Path) -> Result<Self, DemoError> {
+        let path = fs::canonicalize(path)?;
+        let saved = read_text(&path)?;
+        let title = format!(" ✏ {} ", path.display());
+        let mut watcher = FileWatcher::new(true);
+        // Watch the directory: editors that save by renaming replace the file
+        if let Some(dir) = path.parent() {
+            watcher.watch(dir, false);
+        }
+        Ok(Self {
+            area: TextArea::new(&title).with_text(&saved),
+            path,
+            saved,
+            last_edit: None,
+            autosave_delay: AUTOSAVE_DELAY,
+            watcher,
+            conflict: None,
+            status: String::new(),
+        })
+    }
+
+    pub fn with_autosave_delay(mut self, delay: Duration) -> Self {
+        self.autosave_delay = delay;
+        self
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.area.text() != self.saved
+    }
+
+    pub fn has_conflict(&self) -> bool {
+        self.conflict.is_some()
+    }
+
+    pub fn save(&mut self) -> Result<(), DemoError> {
+        let text = self.area.text();
+        fs::write(&self.path, &text)?;
+        self.saved = text;
+        self.last_edit = None;
+        self.status = format!("Saved {}", chrono::Local::now().format("%H:%M:%S"));
+        Ok(())
+    }
+
+    /// Pick up changes on disk and auto-save idle edits; call once per tick
+    pub fn tick(&mut self) -> Result<(), DemoError> {
+        if let Some(changed) = self.watcher.poll() {
+            if changed.iter().any(|path| path == &self.path) {
+                self.check_disk()?;
+            }
+        }
+        let idle = self.last_edit.is_some_and(|last| last.elapsed() >= self.autosave_delay);
+        if idle && self.conflict.is_none() {
+            if self.is_dirty() {
+                self.save()?;
+            } else {
+                self.last_edit = None;
+            }
+        }
+        Ok(())
+    }
+
+    /// Compare the file with what was last saved. Our own saves and deleted
+    /// files are not changes worth acting on.
+    pub fn check_disk(&mut self) -> Result<(), DemoError> {
+        let disk = match read_text(&self.path) {
+            Ok(disk) => disk,
+            Err(_) if !self.path.exists() => {
+                self.status = "Deleted on disk; saving will recreate it".to_string();
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        if disk == self.saved || disk == self.area.text() {
+            self.saved = disk;
+            return Ok(());
+        }
+        if self.is_dirty() {
+            self.conflict = Some(disk);
+        } else {
+            self.reload(disk);
+            self.status = "Reloaded: changed on disk".to_string();
+        }
+        Ok(())
+    }
+
+    /// Replace the buffer with `text`, keeping the cursor where it still fits
+    fn reload(&mut self, text: String) {
+        let cursor = self.area.cursor;
+        self.area.set_text(&text);
+        let row = cursor.row.min(self.area.lines.len() - 1);
+        let col = cursor.col.min(self.area.lines[row].chars().count());
+        self.area.cursor = TextPos::new(row, col);
+        self.saved = text;
+        self.last_edit = None;
+    }
+
+    /// Returns whether the key was used; Esc outside the find bar is left to
+    /// the host, which closes the editor
+    pub fn handle_key(&mut self, key: KeyEvent) -> Result<bool, DemoError> {
+        if let Some(disk) = self.conflict.take() {
+            match key.code {
+                KeyCode::Char('r') => {
+                    self.reload(disk);
+                    self.status = "Reloaded: local edits dropped".to_string();
+                }
+                KeyCode::Char('k') => {
+                    self.saved = disk;
+                    self.save()?;
+                }
+                KeyCode::Char('m') => {
+                    let merged = merge_with_markers(&self.area.text(), &disk);
+                    let cursor = self.area.cursor;
+                    self.area.set_text(&merged);
+                    self.area.cursor = TextPos::new(cursor.row.min(self.area.lines.len() - 1), 0);
+                    self.saved = disk;
+                    // Wait for an edit before saving the markers
+                    self.last_edit = None;
+                    self.status = "Merged: resolve the conflict markers".to_string();
+                }
+                _ => self.conflict = Some(disk),
+            }
+            return Ok(true);
+        }
+        if key.code == KeyCode::Char('s') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.save()?;
+            return Ok(true);
+        }
+        let used = self.area.handle_key(key);
+        if used {
+            self.last_edit = Some(Instant::now());
+        }
+        Ok(used)
+    }
+
+    /// Save unsaved edits before the editor goes away, unless that would
+    /// overwrite someone else's change
+    pub fn close(&mut self) -> Result<(), DemoError> {
+        if self.is_dirty() && self.conflict.is_none() {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(Clear, area);
+        self.area.render(frame, area, self.conflict.is_none());
+        let state = if self.is_dirty() { "● modified" } else { "saved" };
+        let footer = format!(" {} | {} | Ctrl+S: save | Ctrl+R: find | Esc: close ", state, self.status);
+        let footer_area = Rect { x: area.x + 1, y: area.bottom().saturating_sub(1), width: area.width.saturating_sub(2), height: 1 };
+        if !self.area.find.visible && area.height > 2 {
+            frame.render_widget(Paragraph::new(footer).style(Style::default().fg(Color::Gray)), footer_area);
+        }
+
+        if self.conflict.is_some() {
+            let name = self.path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let lines = vec![
+                Line::raw(format!("{} changed on disk while you have unsaved edits.", name)),
+                Line::raw(""),
+                Line::raw("r: reload from disk (drop my edits)"),
+                Line::raw("k: keep mine (overwrite the file)"),
+                Line::raw("m: merge with conflict markers"),
+            ];
+            let width = (lines[0].width() as u16 + 4).min(area.width);
+            let dialog = Rect {
+                x: area.x + (area.width - width) / 2,
+                y: area.y + area.height.saturating_sub(7) / 2,
+                width,
+                height: 7.min(area.height),
+            };
+            frame.render_widget(Clear, dialog);
+            frame.render_widget(
+                Paragraph::new(lines).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" File changed ")
+                        .border_style(Style::default().fg(Color::Yellow)),
+                ),
+                dialog,
+            );
+        }
+    }
+}
+
+fn read_text(path: &Path) -> Result<String, DemoError> {
+    Ok(String::from_utf8_lossy(&fs::read(path)?).into_owned())
+}
+
+/// `mine` with the lines that differ from `theirs` wrapped in git-style
+/// conflict markers; the lines both start and end with are kept once
+pub fn merge_with_markers(mine: &str, theirs: &str) -> String {
+    let mine: Vec<&str> = mine.split('\n').collect();
+    let theirs: Vec<&str> = theirs.split('\n').collect();
+    let prefix = mine.iter().zip(&theirs).take_while(|(a, b)| a == b).count();
+    let max_suffix = mine.len().min(theirs.len()) - prefix;
+    let suffix = mine.iter().rev().zip(theirs.iter().rev()).take(max_suffix).take_while(|(a, b)| a == b).count();
+
+    let mut merged: Vec<&str> = mine[..prefix].to_vec();
+    merged.push("<<<<<<< mine");
+    merged.extend(&mine[prefix..mine.len() - suffix]);
+    merged.push("=======");
+    merged.extend(&theirs[prefix..theirs.len() - suffix]);
+    merged.push(">>>>>>> on disk");
+    merged.extend(&mine[mine.len() - suffix..]);
+    merged.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autosave_and_conflicts() {
+        let dir = std::env::temp_dir().join(format!("file_editor_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("notes.txt");
+        fs::write(&path, "one\ntwo\nthree").unwrap();
+
+        let mut editor = FileEditor::open(&path).unwrap().with_autosave_delay(Duration::ZERO);
+        editor.handle_key(KeyEvent::from(KeyCode::Char('!'))).unwrap();
+        assert!(editor.is_dirty());
+        editor.tick().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "!one\ntwo\nthree");
+
+        // Clean buffer: reload quietly
+        fs::write(&path, "!one\ntwo\nthree\nfour").unwrap();
+        editor.check_disk().unwrap();
+        assert!(!editor.has_conflict());
+        assert_eq!(editor.area.lines.len(), 4);
+
+        // Unsaved edits: ask, and never auto-save over the change
+        editor.handle_key(KeyEvent::from(KeyCode::Char('?'))).unwrap();
+        fs::write(&path, "!one\nTWO\nthree\nfour").unwrap();
+        editor.check_disk().unwrap();
+        assert!(editor.has_conflict());
+        editor.tick().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "!one\nTWO\nthree\nfour");
+        editor.handle_key(KeyEvent::from(KeyCode::Char('m'))).unwrap();
+        assert_eq!(editor.area.text(), "<<<<<<< mine\n!?one\ntwo\n=======\n!one\nTWO\n>>>>>>> on disk\nthree\nfour");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
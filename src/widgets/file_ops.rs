@@ -0,0 +1,211 @@
+//! File operations module
+//!
+//! Recursive copy, move and delete of a set of paths, reporting progress per
+//! file so the work can run on a background task. Deletions can go through
+//! the OS trash and be restored afterwards.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileOperation {
+    Copy { sources: Vec<PathBuf>, destination: PathBuf },
+    Move { sources: Vec<PathBuf>, destination: PathBuf },
+    Delete { sources: Vec<PathBuf> },
+    /// Move to the OS trash instead of deleting permanently
+    Trash { sources: Vec<PathBuf> },
+}
+
+impl FileOperation {
+    pub fn sources(&self) -> &[PathBuf] {
+        match self {
+            Self::Copy { sources, .. }
+            | Self::Move { sources, .. }
+            | Self::Delete { sources }
+            | Self::Trash { sources } => sources,
+        }
+    }
+
+    /// Short description such as `Copy 3 items to /tmp`
+    pub fn describe(&self) -> String {
+        let count = self.sources().len();
+        let items = if count == 1 {
+            self.sources()[0]
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| "1 item".to_string())
+        } else {
+            format!("{} items", count)
+        };
+        match self {
+            Self::Copy { destination, .. } => format!("Copy {} to {}", items, destination.display()),
+            Self::Move { destination, .. } => format!("Move {} to {}", items, destination.display()),
+            Self::Delete { .. } => format!("Permanently delete {}", items),
+            Self::Trash { .. } => format!("Move {} to trash", items),
+        }
+    }
+
+    /// Perform the operation. `progress` is called before each file with the
+    /// files done so far and the total; returning `false` stops early.
+    /// Returns the number of top-level sources processed.
+    pub fn run(&self, mut progress: impl FnMut(usize, usize, &Path) -> bool) -> io::Result<usize> {
+        let total: usize = match self {
+            Self::Delete { sources } | Self::Trash { sources } => sources.len(),
+            _ => self.sources().iter().map(|source| count_files(source)).sum(),
+        };
+        let mut done = 0;
+        let mut step = |path: &Path, files: usize| {
+            let keep_going = progress(done, total, path);
+            done += files;
+            keep_going
+        };
+
+        for (i, source) in self.sources().iter().enumerate() {
+            let completed = match self {
+                Self::Copy { destination, .. } => {
+                    copy_recursive(source, &target_in(destination, source)?, &mut |path: &Path| step(path, 1))?
+                }
+                Self::Move { destination, .. } => {
+                    let target = target_in(destination, source)?;
+                    let files = count_files(source);
+                    // A rename is instant on the same filesystem; otherwise copy then delete
+                    if fs::rename(source, &target).is_ok() {
+                        step(source, files)
+                    } else {
+                        copy_recursive(source, &target, &mut |path: &Path| step(path, 1))? && remove_path(source).map(|_| true)?
+                    }
+                }
+                Self::Delete { .. } => step(source, 1) && remove_path(source).map(|_| true)?,
+                Self::Trash { .. } => {
+                    step(source, 1) && trash::delete(source).map(|_| true).map_err(|e| io::Error::other(e.to_string()))?
+                }
+            };
+            if !completed {
+                return Ok(i);
+            }
+        }
+        Ok(self.sources().len())
+    }
+}
+
+/// Rename `path` within its directory, refusing to overwrite
+pub fn rename_in_place(path: &Path, new_name: &str) -> io::Result<PathBuf> {
+    if new_name.is_empty() || new_name.contains(['/', '\\']) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid file name"));
+    }
+    let target = path.with_file_name(new_name);
+    if target.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", target.display())));
+    }
+    fs::rename(path, &target)?;
+    Ok(target)
+}
+
+/// Restore trashed files to their original locations, returning how many
+/// were found. When a path was trashed more than once the newest copy wins.
+#[cfg(any(windows, all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android"))))]
+pub fn restore_from_trash(paths: &[PathBuf]) -> Result<usize, String> {
+    let mut items = trash::os_limited::list().map_err(|e| e.to_string())?;
+    items.sort_by_key(|item| std::cmp::Reverse(item.time_deleted));
+
+    let mut restore = Vec::new();
+    for path in paths {
+        if let Some(index) = items.iter().position(|item| item.original_path() == *path) {
+            restore.push(items.remove(index));
+        }
+    }
+    let count = restore.len();
+    if count > 0 {
+        trash::os_limited::restore_all(restore).map_err(|e| e.to_string())?;
+    }
+    Ok(count)
+}
+
+#[cfg(not(any(windows, all(unix, not(target_os = "macos"), not(target_os = "ios"), not(target_os = "android")))))]
+pub fn restore_from_trash(_paths: &[PathBuf]) -> Result<usize, String> {
+    Err("Restoring from the trash is not supported on this platform".to_string())
+}
+
+/// Path `source` would get inside `destination`, refusing to overwrite
+fn target_in(destination: &Path, source: &Path) -> io::Result<PathBuf> {
+    let name = source
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("{} has no file name", source.display())))?;
+    let target = destination.join(name);
+    if target.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", target.display())));
+    }
+    if target.starts_with(source) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "cannot copy a directory into itself"));
+    }
+    Ok(target)
+}
+
+fn count_files(path: &Path) -> usize {
+    if path.is_dir() && !path.is_symlink() {
+        fs::read_dir(path)
+            .map(|entries| entries.flatten().map(|entry| count_files(&entry.path())).sum())
+            .unwrap_or(0)
+    } else {
+        1
+    }
+}
+
+/// Copy a file or directory tree; returns `false` if `step` asked to stop
+fn copy_recursive(source: &Path, target: &Path, step: &mut impl FnMut(&Path) -> bool) -> io::Result<bool> {
+    if source.is_dir() && !source.is_symlink() {
+        fs::create_dir_all(target)?;
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            if !copy_recursive(&entry.path(), &target.join(entry.file_name()), step)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    } else {
+        if !step(source) {
+            return Ok(false);
+        }
+        fs::copy(source, target)?;
+        Ok(true)
+    }
+}
+
+fn remove_path(path: &Path) -> io::Result<()> {
+    if path.is_dir() && !path.is_symlink() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_move_delete() {
+        let root = std::env::temp_dir().join(format!("file_ops_test_{}", std::process::id()));
+        let source = root.join("src");
+        let destination = root.join("dest");
+        fs::create_dir_all(source.join("nested")).unwrap();
+        fs::create_dir_all(&destination).unwrap();
+        fs::write(source.join("a.txt"), "a").unwrap();
+        fs::write(source.join("nested/b.txt"), "b").unwrap();
+
+        let copy = FileOperation::Copy { sources: vec![source.clone()], destination: destination.clone() };
+        let mut seen = 0;
+        assert_eq!(copy.run(|_, total, _| { seen += 1; assert_eq!(total, 2); true }).unwrap(), 1);
+        assert_eq!(seen, 2);
+        assert_eq!(fs::read_to_string(destination.join("src/nested/b.txt")).unwrap(), "b");
+        assert!(copy.run(|_, _, _| true).is_err(), "existing targets are not overwritten");
+
+        let renamed = rename_in_place(&destination.join("src"), "copy").unwrap();
+        let delete = FileOperation::Delete { sources: vec![renamed.clone()] };
+        delete.run(|_, _, _| true).unwrap();
+        assert!(!renamed.exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,508 @@
+//! File preview module
+//!
+//! Metadata (size, modified time, permissions) and an inline preview of the
+//! selected file: an ASCII thumbnail for images, the first lines of text
+//! files, stream details from `ffprobe` for videos and a hex dump of the
+//! start of binary files. With the `pdf` feature, PDFs show their first
+//! page, and with `audio` short clips play with a small transport over
+//! their waveform. Decoded images are
+//! kept in an LRU cache, so moving back to an image shows it right away.
+
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+#[cfg(feature = "image")]
+use image::DynamicImage;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::error::DemoError;
+#[cfg(feature = "audio")]
+use crate::event::KeyCode;
+use crate::platform;
+
+use super::ansi::ansi_line;
+#[cfg(feature = "audio")]
+use super::audio::AudioClip;
+#[cfg(feature = "image")]
+use super::component::Component;
+use super::finder::FileKind;
+use super::hex_viewer::{bytes_per_row_for, hex_row, looks_binary, read_sample, SNIFF_LEN};
+use super::humanize::{format_clock, format_size, time_ago};
+#[cfg(feature = "image")]
+use super::image::{open_image, Decoder, ImageQuality, ImageState, ImageWidget};
+#[cfg(feature = "image")]
+use super::lru;
+#[cfg(any(feature = "image", feature = "audio"))]
+use super::lru::LruCache;
+#[cfg(feature = "pdf")]
+use super::pdf;
+#[cfg(feature = "audio")]
+use super::waveform::{Waveform, BUCKETS};
+
+/// Lines read from the start of a text file
+const TEXT_PREVIEW_LINES: usize = 200;
+
+/// Bytes of a binary file shown as a hex dump
+const HEX_PREVIEW_BYTES: usize = 1024;
+
+/// Waveforms kept for audio clips; a few kilobytes each
+#[cfg(feature = "audio")]
+const WAVEFORM_CACHE_BYTES: usize = 1024 * 1024;
+
+/// A decoded image or waveform is reused only while the file is unchanged
+#[cfg(any(feature = "image", feature = "audio"))]
+type ThumbnailKey = (PathBuf, Option<DateTime<Local>>);
+
+/// Stream details from `ffprobe`, or why it failed
+type ProbeResult = Result<Vec<(String, String)>, String>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileMetadata {
+    pub size: u64,
+    pub modified: Option<DateTime<Local>>,
+    pub permissions: String,
+    pub is_dir: bool,
+}
+
+impl FileMetadata {
+    pub fn read(path: &Path) -> std::io::Result<Self> {
+        let metadata = fs::metadata(path)?;
+        Ok(Self {
+            size: metadata.len(),
+            modified: metadata.modified().ok().map(DateTime::<Local>::from),
+            permissions: format_permissions(&metadata),
+            is_dir: metadata.is_dir(),
+        })
+    }
+}
+
+pub enum PreviewContent {
+    Empty,
+    Directory(usize),
+    Text(Vec<String>),
+    /// Start of a binary file
+    Hex(Vec<u8>),
+    #[cfg(feature = "audio")]
+    Audio(Box<AudioClip>),
+    #[cfg(feature = "image")]
+    Image(Box<ImageWidget>),
+    /// `ffprobe` key/value pairs, or `None` while it is still running
+    Video(Option<Vec<(String, String)>>),
+    Unavailable(String),
+}
+
+pub struct FilePreview {
+    pub path: Option<PathBuf>,
+    pub metadata: Option<FileMetadata>,
+    pub content: PreviewContent,
+    probe: Option<Receiver<ProbeResult>>,
+    #[cfg(feature = "image")]
+    thumbnails: LruCache<ThumbnailKey, DynamicImage>,
+    #[cfg(feature = "audio")]
+    waveforms: LruCache<ThumbnailKey, Waveform>,
+    /// Waveform of the previewed clip while it is computed
+    #[cfg(feature = "audio")]
+    waveform_job: Option<Receiver<Result<Waveform, String>>>,
+}
+
+impl Default for FilePreview {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FilePreview {
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            metadata: None,
+            content: PreviewContent::Empty,
+            probe: None,
+            #[cfg(feature = "image")]
+            thumbnails: LruCache::new("thumbnails", lru::config().thumbnail_bytes),
+            #[cfg(feature = "audio")]
+            waveforms: LruCache::new("waveforms", WAVEFORM_CACHE_BYTES),
+            #[cfg(feature = "audio")]
+            waveform_job: None,
+        }
+    }
+
+    /// Preview `path`, doing nothing if it is already shown
+    pub fn set_path(&mut self, path: &Path) {
+        if self.path.as_deref() == Some(path) {
+            return;
+        }
+        self.path = Some(path.to_path_buf());
+        self.metadata = FileMetadata::read(path).ok();
+        self.probe = None;
+        #[cfg(feature = "audio")]
+        {
+            self.waveform_job = None;
+        }
+        self.content = self.load_content(path);
+    }
+
+    pub fn clear(&mut self) {
+        self.path = None;
+        self.metadata = None;
+        self.probe = None;
+        #[cfg(feature = "audio")]
+        {
+            self.waveform_job = None;
+        }
+        self.content = PreviewContent::Empty;
+    }
+
+    #[cfg(any(feature = "image", feature = "audio"))]
+    fn thumbnail_key(&self) -> Option<ThumbnailKey> {
+        let path = self.path.clone()?;
+        Some((path, self.metadata.as_ref().and_then(|metadata| metadata.modified)))
+    }
+
+    fn load_content(&mut self, path: &Path) -> PreviewContent {
+        if path.is_dir() {
+            return match fs::read_dir(path) {
+                Ok(entries) => PreviewContent::Directory(entries.count()),
+                Err(e) => PreviewContent::Unavailable(e.to_string()),
+            };
+        }
+
+        #[cfg(feature = "pdf")]
+        if pdf::is_pdf(path) {
+            return self.image_content(path, pdf::render_first_page);
+        }
+
+        match FileKind::from_path(path) {
+            #[cfg(feature = "image")]
+            FileKind::Image => self.image_content(path, open_image),
+            #[cfg(not(feature = "image"))]
+            FileKind::Image => PreviewContent::Unavailable("Built without the image feature".to_string()),
+            FileKind::Video => {
+                // ffprobe can take a moment on large files, so run it off the UI thread
+                let (sender, receiver) = mpsc::channel();
+                probe_video(path, move |result| {
+                    let _ = sender.send(result);
+                });
+                self.probe = Some(receiver);
+                PreviewContent::Video(None)
+            }
+            #[cfg(feature = "audio")]
+            FileKind::Audio => self.audio_content(path),
+            #[cfg(not(feature = "audio"))]
+            FileKind::Audio => PreviewContent::Unavailable("Built without the audio feature".to_string()),
+            FileKind::Text => match read_head(path, TEXT_PREVIEW_LINES) {
+                Ok(lines) => PreviewContent::Text(lines),
+                Err(e) => PreviewContent::Unavailable(e.to_string()),
+            },
+            // Unknown extensions are often text, such as a Makefile
+            FileKind::Other => match read_sample(path, SNIFF_LEN) {
+                Ok(mut sample) if looks_binary(&sample) => {
+                    sample.truncate(HEX_PREVIEW_BYTES);
+                    PreviewContent::Hex(sample)
+                }
+                Ok(_) => match read_head(path, TEXT_PREVIEW_LINES) {
+                    Ok(lines) => PreviewContent::Text(lines),
+                    Err(e) => PreviewContent::Unavailable(e.to_string()),
+                },
+                Err(e) => PreviewContent::Unavailable(e.to_string()),
+            },
+        }
+    }
+
+    /// Image preview of `path`, decoded off the UI thread unless cached; the
+    /// widget shows a spinner meanwhile
+    #[cfg(feature = "image")]
+    fn image_content(&mut self, path: &Path, decoder: Decoder) -> PreviewContent {
+        let mut widget = ImageWidget::with_quality(ImageQuality::Low).with_decoder(decoder);
+        let cached = self.thumbnail_key().and_then(|key| self.thumbnails.get(&key).cloned());
+        match cached {
+            Some(img) => widget.show_image(path, img),
+            None => widget.load_async(path),
+        }
+        PreviewContent::Image(Box::new(widget))
+    }
+
+    /// Audio clip preview; its waveform comes from the cache or is computed
+    /// off the UI thread, as decoding the whole clip takes a moment
+    #[cfg(feature = "audio")]
+    fn audio_content(&mut self, path: &Path) -> PreviewContent {
+        let mut clip = match AudioClip::open(path) {
+            Ok(clip) => clip,
+            Err(e) => return PreviewContent::Unavailable(e.to_string()),
+        };
+        clip.waveform = self.thumbnail_key().and_then(|key| self.waveforms.get(&key).cloned());
+        if clip.waveform.is_none() {
+            let (sender, receiver) = mpsc::channel();
+            let path = path.to_path_buf();
+            platform::current().background(Box::new(move || {
+                let _ = sender.send(Waveform::load(&path, BUCKETS).map_err(|e| e.to_string()));
+            }));
+            self.waveform_job = Some(receiver);
+        }
+        PreviewContent::Audio(Box::new(clip))
+    }
+
+    /// Cache a finished waveform and hand it to the clip's seek bar. A
+    /// failure only leaves the plain bar, so it is logged rather than shown.
+    #[cfg(feature = "audio")]
+    fn poll_waveform(&mut self) {
+        let Some(Ok(result)) = self.waveform_job.as_ref().map(Receiver::try_recv) else {
+            return;
+        };
+        self.waveform_job = None;
+        match result {
+            Ok(waveform) => {
+                if let Some(key) = self.thumbnail_key() {
+                    self.waveforms.insert(key, waveform.clone());
+                }
+                if let PreviewContent::Audio(clip) = &mut self.content {
+                    clip.waveform = Some(waveform);
+                }
+            }
+            Err(e) => log::warn!("No waveform for {:?}: {}", self.path, e),
+        }
+    }
+
+    /// Transport keys for an audio clip preview; returns whether the key
+    /// was used
+    #[cfg(feature = "audio")]
+    pub fn handle_audio_key(&mut self, key: KeyCode) -> Result<bool, DemoError> {
+        match &mut self.content {
+            PreviewContent::Audio(clip) => clip.handle_key(key),
+            _ => Ok(false),
+        }
+    }
+
+    /// Pick up a finished image decode or `ffprobe` run; call once per tick.
+    /// A failed decode or probe is returned as well as shown in the preview.
+    pub fn poll(&mut self) -> Result<(), DemoError> {
+        #[cfg(feature = "image")]
+        self.poll_image()?;
+        #[cfg(feature = "audio")]
+        self.poll_waveform();
+        let Some(receiver) = &self.probe else {
+            return Ok(());
+        };
+        if let Ok(result) = receiver.try_recv() {
+            self.probe = None;
+            match result {
+                Ok(fields) => self.content = PreviewContent::Video(Some(fields)),
+                Err(e) => {
+                    self.content = PreviewContent::Unavailable(e.clone());
+                    return Err(DemoError::Video(e));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Cache the decoded image once its background decode finishes
+    #[cfg(feature = "image")]
+    fn poll_image(&mut self) -> Result<(), DemoError> {
+        let PreviewContent::Image(widget) = &mut self.content else {
+            return Ok(());
+        };
+        let was_loading = matches!(widget.state(), ImageState::Loading { .. });
+        let updated = Component::update(widget.as_mut());
+        if was_loading && matches!(widget.state(), ImageState::Loaded) {
+            if let (Some(img), Some(key)) = (widget.image().cloned(), self.thumbnail_key()) {
+                self.thumbnails.insert(key, img);
+            }
+        }
+        updated
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError> {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Min(0)])
+            .split(area);
+
+        let label = Style::default().fg(Color::Gray);
+        let metadata_lines = match &self.metadata {
+            Some(metadata) => vec![
+                Line::from(vec![
+                    Span::styled("Size:     ", label),
+                    Span::raw(if metadata.is_dir { "-".to_string() } else { format_size(metadata.size) }),
+                ]),
+                Line::from(vec![
+                    Span::styled("Modified: ", label),
+                    Span::raw(
+                        metadata
+                            .modified
+                            .map(|time| format!("{} ({})", time.format("%Y-%m-%d %H:%M"), time_ago(&time)))
+                            .unwrap_or_else(|| "-".to_string()),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::styled("Mode:     ", label),
+                    Span::raw(metadata.permissions.clone()),
+                ]),
+            ],
+            None => vec![Line::styled("No file selected", label)],
+        };
+        let title = self
+            .path
+            .as_ref()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Info".to_string());
+        frame.render_widget(
+            Paragraph::new(metadata_lines).block(Block::default().borders(Borders::ALL).title(title)),
+            chunks[0],
+        );
+
+        let preview_block = Block::default().borders(Borders::ALL).title("Preview");
+        let dim = Style::default().fg(Color::DarkGray);
+        let lines: Vec<Line> = match &mut self.content {
+            #[cfg(feature = "image")]
+            PreviewContent::Image(widget) => return Component::render(widget.as_mut(), frame, chunks[1]),
+            PreviewContent::Empty => Vec::new(),
+            PreviewContent::Directory(count) => vec![Line::styled(format!("Directory with {} entries", count), dim)],
+            PreviewContent::Text(lines) => lines.iter().map(|line| ansi_line(line, Style::default())).collect(),
+            #[cfg(feature = "audio")]
+            PreviewContent::Audio(clip) => {
+                let inner = preview_block.inner(chunks[1]);
+                frame.render_widget(preview_block, chunks[1]);
+                clip.render(frame, inner);
+                return Ok(());
+            }
+            PreviewContent::Hex(bytes) => {
+                let per_row = bytes_per_row_for(chunks[1].width.saturating_sub(2));
+                bytes
+                    .chunks(per_row)
+                    .enumerate()
+                    .map(|(i, row)| hex_row((i * per_row) as u64, row, per_row, None))
+                    .collect()
+            }
+            PreviewContent::Video(None) => vec![Line::styled("Probing with ffprobe...", dim)],
+            PreviewContent::Video(Some(fields)) => fields
+                .iter()
+                .map(|(key, value)| {
+                    Line::from(vec![
+                        Span::styled(format!("{:<14}", key), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                        Span::raw(value.clone()),
+                    ])
+                })
+                .collect(),
+            PreviewContent::Unavailable(reason) => vec![Line::styled(reason.clone(), dim)],
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(preview_block).wrap(Wrap { trim: false }),
+            chunks[1],
+        );
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn format_permissions(metadata: &fs::Metadata) -> String {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = metadata.permissions().mode();
+    let mut text = String::with_capacity(10);
+    text.push(if metadata.is_dir() { 'd' } else { '-' });
+    for shift in [6, 3, 0] {
+        let bits = (mode >> shift) & 0o7;
+        text.push(if bits & 0o4 != 0 { 'r' } else { '-' });
+        text.push(if bits & 0o2 != 0 { 'w' } else { '-' });
+        text.push(if bits & 0o1 != 0 { 'x' } else { '-' });
+    }
+    format!("{} ({:o})", text, mode & 0o777)
+}
+
+#[cfg(not(unix))]
+fn format_permissions(metadata: &fs::Metadata) -> String {
+    if metadata.permissions().readonly() {
+        "read-only".to_string()
+    } else {
+        "read-write".to_string()
+    }
+}
+
+fn read_head(path: &Path, count: usize) -> std::io::Result<Vec<String>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut lines = Vec::new();
+    for line in reader.split(b'\n').take(count) {
+        let line = line?;
+        lines.push(String::from_utf8_lossy(&line).trim_end_matches('\r').replace('\t', "    "));
+    }
+    Ok(lines)
+}
+
+/// Probe the length of the video at `path` with `ffprobe` in the
+/// background and pass it to `done`, `None` when it is unknown
+pub fn probe_duration(path: &Path, done: impl FnOnce(Option<Duration>) + Send + 'static) {
+    let mut args: Vec<String> =
+        ["-v", "error", "-show_entries", "format=duration", "-of", "default=noprint_wrappers=1:nokey=1"]
+            .map(String::from)
+            .into();
+    args.push(path.to_string_lossy().into_owned());
+    platform::current().run_tool(
+        "ffprobe",
+        &args,
+        Box::new(move |output| {
+            let seconds = output.ok().and_then(|stdout| String::from_utf8_lossy(&stdout).trim().parse().ok());
+            done(seconds.and_then(|seconds| Duration::try_from_secs_f64(seconds).ok()))
+        }),
+    );
+}
+
+/// Run `ffprobe` in the background and pass container and stream details
+/// as key/value pairs to `done`
+fn probe_video(path: &Path, done: impl FnOnce(ProbeResult) + Send + 'static) {
+    let mut args: Vec<String> = [
+        "-v",
+        "error",
+        "-show_entries",
+        "format=format_long_name,duration,bit_rate:stream=codec_type,codec_name,width,height,r_frame_rate",
+        "-of",
+        "default=noprint_wrappers=1",
+    ]
+    .map(String::from)
+    .into();
+    args.push(path.to_string_lossy().into_owned());
+    platform::current().run_tool(
+        "ffprobe",
+        &args,
+        Box::new(move |output| done(output.map(|stdout| parse_probe_output(&String::from_utf8_lossy(&stdout))))),
+    );
+}
+
+fn parse_probe_output(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .filter(|(_, value)| !value.is_empty() && *value != "N/A")
+        .map(|(key, value)| {
+            let seconds = value.parse().ok().and_then(|seconds| Duration::try_from_secs_f64(seconds).ok());
+            match (key, seconds) {
+                ("duration", Some(duration)) => (key.to_string(), format_clock(duration)),
+                _ => (key.to_string(), value.to_string()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_probe_output() {
+        let fields = parse_probe_output("codec_name=h264\ncodec_type=video\nwidth=1920\nbit_rate=N/A\nduration=12.5\n");
+        assert_eq!(fields.len(), 4);
+        assert_eq!(fields[2], ("width".to_string(), "1920".to_string()));
+        assert_eq!(fields[3], ("duration".to_string(), "00:00:12".to_string()));
+    }
+}
@@ -0,0 +1,235 @@
+//! Find and replace module
+//!
+//! Bar at the bottom of a `TextArea` for finding text and replacing it. The
+//! query is literal unless regex mode is on, in which case the replacement
+//! can use `$1`-style groups. Matching is smart-case like the other search
+//! bars, and matches never span lines. The bar only holds the query and
+//! which match is current; the editor applies the actions it returns.
+//! Read-only views use `find_only`, which has no replace field.
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use regex::{NoExpand, Regex};
+
+use super::line_edit::edit_at_end;
+use super::search::build_pattern;
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// A match within one line, in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextMatch {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum BarField {
+    #[default]
+    Find,
+    Replace,
+}
+
+/// What a key in the bar asks the editor to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindAction {
+    None,
+    /// The query changed; go to the first match from the cursor
+    Search,
+    Next,
+    Previous,
+    ReplaceOne,
+    ReplaceAll,
+    Close,
+}
+
+#[derive(Debug, Default)]
+pub struct FindReplace {
+    pub visible: bool,
+    pub query: String,
+    pub replacement: String,
+    /// Treat the query as a regular expression
+    pub regex: bool,
+    /// No replace field, for read-only views
+    find_only: bool,
+    focus: BarField,
+    /// Index of the current match
+    pub current: usize,
+}
+
+impl FindReplace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn find_only() -> Self {
+        Self { find_only: true, ..Self::default() }
+    }
+
+    pub fn is_shortcut(key: &KeyEvent) -> bool {
+        key.code == KeyCode::Char('r') && key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Show the bar with the find field focused, keeping the previous query
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.focus = BarField::Find;
+    }
+
+    /// Pattern for the query; `Ok(None)` while it is empty
+    pub fn pattern(&self) -> Result<Option<Regex>, String> {
+        match (self.query.is_empty(), self.regex) {
+            (true, _) => Ok(None),
+            (false, true) => build_pattern(&self.query).map(Some),
+            (false, false) => build_pattern(&regex::escape(&self.query)).map(Some),
+        }
+    }
+
+    /// Every non-empty match in `lines`, top to bottom
+    pub fn matches(&self, lines: &[String]) -> Vec<TextMatch> {
+        let Ok(Some(regex)) = self.pattern() else {
+            return Vec::new();
+        };
+        lines
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                regex
+                    .find_iter(line)
+                    .filter(|found| !found.is_empty())
+                    .map(move |found| TextMatch { row, start: found.start(), end: found.end() })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Text that replaces `found` in `line`, with groups expanded in regex
+    /// mode
+    pub fn replacement_for(&self, line: &str, found: TextMatch) -> String {
+        let regex = match self.pattern() {
+            Ok(Some(regex)) if self.regex => regex,
+            _ => return self.replacement.clone(),
+        };
+        let mut expanded = String::new();
+        if let Some(captures) = regex.captures_iter(line).find(|captures| captures.get(0).is_some_and(|m| m.start() == found.start)) {
+            captures.expand(&self.replacement, &mut expanded);
+        }
+        expanded
+    }
+
+    /// `line` with every match replaced, or `None` when nothing matched
+    pub fn replace_all_in(&self, line: &str) -> Option<String> {
+        let regex = self.pattern().ok().flatten()?;
+        if !regex.is_match(line) {
+            return None;
+        }
+        let replaced = if self.regex {
+            regex.replace_all(line, self.replacement.as_str())
+        } else {
+            regex.replace_all(line, NoExpand(&self.replacement))
+        };
+        Some(replaced.into_owned())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> FindAction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Esc => {
+                self.visible = false;
+                FindAction::Close
+            }
+            _ if Self::is_shortcut(&key) => {
+                self.visible = false;
+                FindAction::Close
+            }
+            KeyCode::Tab | KeyCode::BackTab if self.find_only => FindAction::None,
+            KeyCode::Char('a') if ctrl && self.find_only => FindAction::None,
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.focus = match self.focus {
+                    BarField::Find => BarField::Replace,
+                    BarField::Replace => BarField::Find,
+                };
+                FindAction::None
+            }
+            KeyCode::Char('x') if alt => {
+                self.regex = !self.regex;
+                FindAction::Search
+            }
+            KeyCode::Char('a') if ctrl => FindAction::ReplaceAll,
+            KeyCode::Enter if key.modifiers.contains(KeyModifiers::SHIFT) => FindAction::Previous,
+            KeyCode::Enter if self.focus == BarField::Replace => FindAction::ReplaceOne,
+            KeyCode::Enter | KeyCode::Down => FindAction::Next,
+            KeyCode::Up => FindAction::Previous,
+            _ => {
+                let (field, search) = match self.focus {
+                    BarField::Find => (&mut self.query, FindAction::Search),
+                    BarField::Replace => (&mut self.replacement, FindAction::None),
+                };
+                if edit_at_end(field, key) {
+                    search
+                } else {
+                    FindAction::None
+                }
+            }
+        }
+    }
+
+    /// The bar's line: both fields, the match counter and the regex mode
+    pub fn line(&self, match_count: usize) -> Line<'static> {
+        let field = |label: &str, text: &str, focused: bool| {
+            let style = if focused {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            let cursor = if focused { "▏" } else { "" };
+            Span::styled(format!("{}{}{}", label, text, cursor), style)
+        };
+        let count = match (self.pattern(), match_count) {
+            (Err(_), _) => Span::styled("invalid regex", Style::default().fg(Color::Red)),
+            (Ok(None), _) => Span::raw(""),
+            (Ok(Some(_)), 0) => Span::styled("no matches", Style::default().fg(Color::Red)),
+            (Ok(Some(_)), total) => Span::styled(format!("{}/{}", self.current + 1, total), Style::default().fg(Color::Cyan)),
+        };
+        let mut spans = vec![field(" 🔍 ", &self.query, self.focus == BarField::Find), Span::raw("  ")];
+        if !self.find_only {
+            spans.extend([field("↪ ", &self.replacement, self.focus == BarField::Replace), Span::raw("  ")]);
+        }
+        spans.extend([count, Span::styled(if self.regex { "  .* on" } else { "  .* off" }, Style::default().fg(Color::DarkGray))]);
+        Line::from(spans)
+    }
+}
+
+/// Style of matches other than the current one
+pub fn match_style() -> Style {
+    Style::default().fg(Color::Black).bg(Color::Yellow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_and_replace() {
+        let lines = vec!["Call 555-1234 or 555-9876".to_string(), "no numbers".to_string()];
+        let mut bar = FindReplace::new();
+        bar.query = "555".to_string();
+        assert_eq!(bar.matches(&lines).len(), 2);
+
+        bar.query = r"(\d{3})-(\d{4})".to_string();
+        assert!(bar.matches(&lines).is_empty());
+        bar.regex = true;
+        let matches = bar.matches(&lines);
+        assert_eq!(matches[1], TextMatch { row: 0, start: 17, end: 25 });
+        bar.replacement = "$2-$1".to_string();
+        assert_eq!(bar.replacement_for(&lines[0], matches[1]), "9876-555");
+        assert_eq!(bar.replace_all_in(&lines[0]).as_deref(), Some("Call 1234-555 or 9876-555"));
+        assert_eq!(bar.replace_all_in(&lines[1]), None);
+
+        bar.query = "(".to_string();
+        assert!(bar.pattern().is_err());
+    }
+}
@@ -0,0 +1,381 @@
+//! Fuzzy file finder module
+//!
+//! Overlay that indexes a directory tree on a background task and
+//! fuzzy-matches file paths as the user types. Hosts decide how to open the
+//! picked file based on its `FileKind`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::line_edit::edit_at_end;
+use super::progress::{TaskHandle, TaskManager, TaskOutcome};
+use super::spinner::{Spinner, SpinnerStyle};
+
+/// Maximum number of matches listed
+const MAX_RESULTS: usize = 200;
+
+/// Directories never descended into while indexing
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+/// Broad file categories used to pick a viewer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    Image,
+    Video,
+    Audio,
+    Text,
+    Other,
+}
+
+impl FileKind {
+    pub fn from_path(path: &Path) -> Self {
+        let ext = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        match ext.as_str() {
+            "png" | "jpg" | "jpeg" | "gif" | "bmp" | "webp" | "ico" | "tiff" => FileKind::Image,
+            "mp4" | "avi" | "mkv" | "mov" | "wmv" | "flv" | "webm" => FileKind::Video,
+            "wav" | "mp3" | "flac" | "ogg" => FileKind::Audio,
+            "rs" | "toml" | "md" | "txt" | "json" | "yaml" | "yml" | "html" | "css" | "js" | "ts" | "py" | "sh"
+            | "lock" | "log" | "csv" => FileKind::Text,
+            _ => FileKind::Other,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            FileKind::Image => "🖼️",
+            FileKind::Video => "🎬",
+            FileKind::Audio => "🎵",
+            FileKind::Text => "📄",
+            FileKind::Other => "📦",
+        }
+    }
+}
+
+/// Result of a key press in the finder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FinderAction {
+    None,
+    Open(PathBuf, FileKind),
+    Close,
+}
+
+/// Score a subsequence match of `query` in `candidate`, case-insensitively.
+/// Returns the score and the matched character positions, or `None` when
+/// the query is not a subsequence. The best-scoring alignment wins, so a
+/// later run at a word start beats an earlier scattered match.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let n = candidate_chars.len();
+    let matches = |q: char, c: char| c.to_lowercase().eq(std::iter::once(q));
+    // Word starts are worth more, and skipped characters cost one each
+    let char_score = |index: usize| {
+        let word_start = index == 0 || matches!(candidate_chars[index - 1], '/' | '\\' | '_' | '-' | '.' | ' ');
+        10 + if word_start { 20 } else { 0 }
+    };
+
+    // best[i]: score of the best alignment of the query so far whose last
+    // character is at `i`; back[j][i] is where the previous character went
+    let mut best: Vec<Option<i64>> = Vec::new();
+    let mut back: Vec<Vec<usize>> = Vec::with_capacity(query.len());
+    for (j, &q) in query.iter().enumerate() {
+        let mut row = vec![None; n];
+        let mut from = vec![0; n];
+        // Best `best[p] + p + 1` over p < i, for a gap of `i - p - 1`
+        let mut reach: Option<(i64, usize)> = None;
+        for i in 0..n {
+            if i > 0 && j > 0 {
+                if let Some(score) = best[i - 1] {
+                    if reach.is_none_or(|(value, _)| score + i as i64 > value) {
+                        reach = Some((score + i as i64, i - 1));
+                    }
+                }
+            }
+            if !matches(q, candidate_chars[i]) {
+                continue;
+            }
+            let candidates = if j == 0 {
+                [Some((-(i as i64), 0)), None]
+            } else {
+                // Consecutive characters are worth more
+                let consecutive = (i > 0).then(|| best[i - 1].map(|score| (score + 15, i - 1))).flatten();
+                [reach.map(|(value, p)| (value - i as i64, p)), consecutive]
+            };
+            if let Some((score, p)) = candidates.into_iter().flatten().max_by_key(|&(score, _)| score) {
+                row[i] = Some(score + char_score(i));
+                from[i] = p;
+            }
+        }
+        best = row;
+        back.push(from);
+    }
+
+    let (mut score, mut index) = match query.len() {
+        0 => (0, 0),
+        _ => best.iter().enumerate().filter_map(|(i, score)| score.map(|score| (score, i))).max()?,
+    };
+    let mut positions = vec![0; query.len()];
+    for j in (0..query.len()).rev() {
+        positions[j] = index;
+        index = back[j][index];
+    }
+
+    // Prefer shorter paths among otherwise equal matches
+    score -= n as i64 / 10;
+    Some((score, positions))
+}
+
+/// Recursively collect files below `root`, skipping hidden and build directories
+fn index_files(root: &Path, handle: &TaskHandle<Vec<PathBuf>>) -> Result<Vec<PathBuf>, String> {
+    let mut pending = vec![root.to_path_buf()];
+    let mut files = Vec::new();
+
+    while let Some(dir) = pending.pop() {
+        if handle.is_cancelled() {
+            return Err("cancelled".to_string());
+        }
+
+        if let Ok(entries) = fs::read_dir(&dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name.starts_with('.') {
+                    continue;
+                }
+                if path.is_dir() {
+                    if !SKIPPED_DIRS.contains(&name.as_str()) {
+                        pending.push(path);
+                    }
+                } else if let Ok(relative) = path.strip_prefix(root) {
+                    files.push(relative.to_path_buf());
+                }
+            }
+        }
+        handle.report_with(0.0, format!("{} files", files.len()));
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+pub struct FileFinder {
+    pub visible: bool,
+    pub query: String,
+    root: PathBuf,
+    files: Vec<PathBuf>,
+    /// Indices into `files` with their match positions, best first
+    matches: Vec<(usize, Vec<usize>)>,
+    state: ListState,
+    indexer: TaskManager<Vec<PathBuf>>,
+    indexed: bool,
+    spinner: Spinner,
+}
+
+impl FileFinder {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            visible: false,
+            query: String::new(),
+            root: root.into(),
+            files: Vec::new(),
+            matches: Vec::new(),
+            state: ListState::default(),
+            indexer: TaskManager::new(),
+            indexed: false,
+            spinner: Spinner::new(SpinnerStyle::Braille, "Indexing files"),
+        }
+    }
+
+    /// Finder rooted at the process's working directory
+    pub fn in_current_dir() -> Self {
+        Self::new(std::env::current_dir().unwrap_or_default())
+    }
+
+    /// Whether a key event is the Ctrl+T shortcut that opens the finder
+    pub fn is_shortcut(key: &KeyEvent) -> bool {
+        key.code == KeyCode::Char('t') && key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Show the overlay, starting the background index on first use
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.query.clear();
+        if !self.indexed && !self.indexer.is_busy() {
+            let root = self.root.clone();
+            self.indexer.spawn("Indexing files", move |handle| index_files(&root, handle));
+        }
+        self.refresh();
+    }
+
+    pub fn is_indexing(&self) -> bool {
+        self.indexer.is_busy()
+    }
+
+    /// Install the file index once indexing finishes; call once per tick
+    pub fn poll(&mut self) {
+        for outcome in self.indexer.poll() {
+            if let TaskOutcome::Finished { output, .. } = outcome {
+                self.files = output;
+                self.indexed = true;
+                self.refresh();
+            }
+        }
+    }
+
+    fn refresh(&mut self) {
+        let mut scored: Vec<(i64, usize, Vec<usize>)> = self
+            .files
+            .iter()
+            .enumerate()
+            .filter_map(|(i, path)| {
+                fuzzy_match(&self.query, &path.to_string_lossy()).map(|(score, positions)| (score, i, positions))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        self.matches = scored
+            .into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_, i, positions)| (i, positions))
+            .collect();
+        self.state.select(if self.matches.is_empty() { None } else { Some(0) });
+    }
+
+    fn select_offset(&mut self, offset: isize) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let len = self.matches.len() as isize;
+        let current = self.state.selected().unwrap_or(0) as isize;
+        self.state.select(Some((current + offset).rem_euclid(len) as usize));
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> FinderAction {
+        match key.code {
+            KeyCode::Esc => {
+                self.visible = false;
+                return FinderAction::Close;
+            }
+            KeyCode::Enter => {
+                let picked = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.matches.get(i))
+                    .map(|(index, _)| self.root.join(&self.files[*index]));
+                if let Some(path) = picked {
+                    self.visible = false;
+                    let kind = FileKind::from_path(&path);
+                    return FinderAction::Open(path, kind);
+                }
+            }
+            KeyCode::Up => self.select_offset(-1),
+            KeyCode::Down | KeyCode::Tab => self.select_offset(1),
+            _ if edit_at_end(&mut self.query, key) => self.refresh(),
+            _ => {}
+        }
+        FinderAction::None
+    }
+
+    /// Render as a centered popup over `area`
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+        let width = (area.width * 7 / 10).max(30).min(area.width);
+        let height = (area.height * 7 / 10).max(8).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let input_area = Rect { height: 3, ..popup };
+        let list_area = Rect {
+            y: popup.y + 3,
+            height: popup.height.saturating_sub(3),
+            ..popup
+        };
+
+        let input = Paragraph::new(format!("{}█", self.query)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🔍 Find file (Ctrl+T)")
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(input, input_area);
+
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|(index, positions)| {
+                let path = &self.files[*index];
+                let mut spans = vec![Span::raw(format!("{} ", FileKind::from_path(path).icon()))];
+                spans.extend(path.to_string_lossy().chars().enumerate().map(|(i, c)| {
+                    if positions.contains(&i) {
+                        Span::styled(c.to_string(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                    } else {
+                        Span::raw(c.to_string())
+                    }
+                }));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let title = if self.indexer.is_busy() {
+            format!("{} {} ", self.spinner.current_frame(), self.spinner.label)
+        } else {
+            format!("{} / {} files ", self.matches.len(), self.files.len())
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .title_bottom("↑↓: select | Enter: open | Esc: close"),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        frame.render_stateful_widget(list, list_area, &mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_positions() {
+        let (_, positions) = fuzzy_match("mrs", "src/main.rs").unwrap();
+        assert_eq!(positions, vec![4, 9, 10]);
+        assert!(fuzzy_match("xyz", "src/main.rs").is_none());
+        assert!(fuzzy_match("", "anything").is_some());
+    }
+
+    #[test]
+    fn test_fuzzy_match_prefers_word_starts() {
+        let (boundary, _) = fuzzy_match("tab", "widgets/table.rs").unwrap();
+        let (scattered, _) = fuzzy_match("tab", "widgets/timeline_abc.rs").unwrap();
+        assert!(boundary > scattered);
+    }
+
+    #[test]
+    fn test_file_kind() {
+        assert_eq!(FileKind::from_path(Path::new("a/photo.JPG")), FileKind::Image);
+        assert_eq!(FileKind::from_path(Path::new("clip.mkv")), FileKind::Video);
+        assert_eq!(FileKind::from_path(Path::new("Cargo.toml")), FileKind::Text);
+        assert_eq!(FileKind::from_path(Path::new("binary")), FileKind::Other);
+    }
+}
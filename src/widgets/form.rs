@@ -0,0 +1,502 @@
+//! Form dialog module
+//!
+//! Modal popup with labelled text and select fields, Tab/Shift+Tab focus
+//! cycling and submit/cancel actions, plus a yes/no confirmation popup.
+//! Fields can be shown or required depending on other values, and the form
+//! re-lays out as those rules change. Validators check values on submit. Text fields expand `:shortcode:` emoji
+//! as they are typed, and fields with a maximum length show a live character
+//! count under the input.
+
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use regex::Regex;
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::char_count;
+use super::emoji::ShortcodeCompleter;
+use super::line_edit::edit_at_end;
+use super::selection::column_of_char;
+
+/// Result of a key press in the form dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormAction {
+    None,
+    Submit,
+    Cancel,
+}
+
+/// Rule on another field's value, by label
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    Equals(String, String),
+    NotEmpty(String),
+}
+
+impl Condition {
+    pub fn equals(label: &str, value: &str) -> Self {
+        Condition::Equals(label.to_string(), value.to_string())
+    }
+
+    pub fn not_empty(label: &str) -> Self {
+        Condition::NotEmpty(label.to_string())
+    }
+
+    fn holds(&self, fields: &[FormField]) -> bool {
+        let value = |label: &str| {
+            fields
+                .iter()
+                .find(|field| field.label == label)
+                .map_or("", |field| field.value.as_str())
+        };
+        match self {
+            Condition::Equals(label, expected) => value(label) == expected,
+            Condition::NotEmpty(label) => !value(label).trim().is_empty(),
+        }
+    }
+}
+
+/// Check on a non-empty field value, run on submit
+#[derive(Debug, Clone)]
+pub enum Validator {
+    Integer,
+    Number,
+    Min(f64),
+    Max(f64),
+    MinLength(usize),
+    /// Whole value must match
+    Pattern(Regex),
+}
+
+impl Validator {
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        let number = || value.parse::<f64>().map_err(|_| "must be a number".to_string());
+        match self {
+            Validator::Integer => value.parse::<i64>().map(|_| ()).map_err(|_| "must be a whole number".to_string()),
+            Validator::Number => number().map(|_| ()),
+            Validator::Min(min) if number()? < *min => Err(format!("must be at least {}", min)),
+            Validator::Max(max) if number()? > *max => Err(format!("must be at most {}", max)),
+            Validator::MinLength(min) if char_count::TextStats::of(value).graphemes < *min => {
+                Err(format!("must be at least {} characters", min))
+            }
+            Validator::Pattern(regex) if !regex.find(value).is_some_and(|m| m.start() == 0 && m.end() == value.len()) => {
+                Err(format!("must match {}", regex.as_str()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label: String,
+    pub value: String,
+    pub required: bool,
+    /// Limit in characters, shown and enforced by the counter footer
+    pub max_len: Option<usize>,
+    /// Choices cycled with ←→; empty for a text field
+    pub options: Vec<String>,
+    /// Field is hidden unless this holds
+    pub visible_when: Option<Condition>,
+    /// Field is required while this holds
+    pub required_when: Option<Condition>,
+    pub validators: Vec<Validator>,
+}
+
+impl FormField {
+    pub fn new(label: &str, value: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            value: value.to_string(),
+            required: false,
+            max_len: None,
+            options: Vec::new(),
+            visible_when: None,
+            required_when: None,
+            validators: Vec::new(),
+        }
+    }
+
+    /// Select field; `value` falls back to the first option when not listed
+    pub fn select(label: &str, options: &[&str], value: &str) -> Self {
+        let value = if options.contains(&value) {
+            value
+        } else {
+            options.first().copied().unwrap_or_default()
+        };
+        Self {
+            options: options.iter().map(|option| option.to_string()).collect(),
+            ..Self::new(label, value)
+        }
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    pub fn visible_when(mut self, condition: Condition) -> Self {
+        self.visible_when = Some(condition);
+        self
+    }
+
+    pub fn required_when(mut self, condition: Condition) -> Self {
+        self.required_when = Some(condition);
+        self
+    }
+
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    pub fn is_select(&self) -> bool {
+        !self.options.is_empty()
+    }
+
+    /// Step a select field's value by `delta` options, clamped to the ends
+    fn cycle(&mut self, delta: isize) {
+        let current = self.options.iter().position(|option| *option == self.value).unwrap_or(0);
+        let next = (current as isize + delta).clamp(0, self.options.len() as isize - 1) as usize;
+        self.value = self.options[next].clone();
+    }
+}
+
+pub struct FormDialog {
+    pub title: String,
+    pub fields: Vec<FormField>,
+    /// Index into `fields`; always a visible field
+    pub focused: usize,
+    pub error: Option<String>,
+    emoji: ShortcodeCompleter,
+}
+
+impl FormDialog {
+    pub fn new(title: &str, fields: Vec<FormField>) -> Self {
+        let mut form = Self {
+            title: title.to_string(),
+            fields,
+            focused: 0,
+            error: None,
+            emoji: ShortcodeCompleter::new(),
+        };
+        form.focused = form.visible_fields().first().copied().unwrap_or(0);
+        form
+    }
+
+    /// Value of the field with the given label; `None` while it is hidden
+    pub fn value(&self, label: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.label == label && self.is_visible(field))
+            .map(|field| field.value.as_str())
+    }
+
+    pub fn is_visible(&self, field: &FormField) -> bool {
+        field.visible_when.as_ref().is_none_or(|condition| condition.holds(&self.fields))
+    }
+
+    pub fn is_required(&self, field: &FormField) -> bool {
+        field.required || field.required_when.as_ref().is_some_and(|condition| condition.holds(&self.fields))
+    }
+
+    /// Indexes of the fields currently shown, in order
+    pub fn visible_fields(&self) -> Vec<usize> {
+        (0..self.fields.len()).filter(|&i| self.is_visible(&self.fields[i])).collect()
+    }
+
+    fn validate(&mut self) -> bool {
+        let visible: Vec<&FormField> = self.visible_fields().into_iter().map(|i| &self.fields[i]).collect();
+        if let Some(field) = visible.iter().find(|field| self.is_required(field) && field.value.trim().is_empty()) {
+            self.error = Some(format!("{} is required", field.label));
+            return false;
+        }
+        if let Some(field) = visible.iter().find(|field| char_count::exceeds(&field.value, field.max_len)) {
+            self.error = Some(format!("{} is longer than {} characters", field.label, field.max_len.unwrap_or_default()));
+            return false;
+        }
+        let invalid = visible.iter().filter(|field| !field.value.trim().is_empty()).find_map(|field| {
+            field
+                .validators
+                .iter()
+                .find_map(|validator| validator.check(&field.value).err())
+                .map(|message| format!("{} {}", field.label, message))
+        });
+        self.error = invalid;
+        self.error.is_none()
+    }
+
+    /// Move focus by `delta` visible fields, wrapping around
+    fn move_focus(&mut self, delta: isize) {
+        let visible = self.visible_fields();
+        if visible.is_empty() {
+            return;
+        }
+        let current = visible.iter().position(|&i| i == self.focused).unwrap_or(0);
+        let next = (current as isize + delta).rem_euclid(visible.len() as isize) as usize;
+        self.focused = visible[next];
+    }
+
+    /// Keep focus on a visible field after rules re-evaluate
+    fn refocus(&mut self) {
+        let visible = self.visible_fields();
+        if !visible.contains(&self.focused) {
+            self.focused = visible
+                .iter()
+                .copied()
+                .rev()
+                .find(|&i| i < self.focused)
+                .or(visible.first().copied())
+                .unwrap_or(0);
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> FormAction {
+        self.handle_key_event(KeyEvent::new(key, KeyModifiers::NONE))
+    }
+
+    /// Like `handle_key`, with modifiers for the word editing shortcuts
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> FormAction {
+        if let Some(field) = self.fields.get_mut(self.focused).filter(|field| !field.is_select()) {
+            let mut cursor = field.value.chars().count();
+            if self.emoji.handle_key(&mut field.value, &mut cursor, key) {
+                return FormAction::None;
+            }
+        }
+        match key.code {
+            KeyCode::Esc => return FormAction::Cancel,
+            KeyCode::Enter => {
+                if self.validate() {
+                    return FormAction::Submit;
+                }
+            }
+            KeyCode::Tab | KeyCode::Down => self.move_focus(1),
+            KeyCode::BackTab | KeyCode::Up => self.move_focus(-1),
+            KeyCode::Left | KeyCode::Right if self.fields.get(self.focused).is_some_and(FormField::is_select) => {
+                let delta = if key.code == KeyCode::Left { -1 } else { 1 };
+                self.fields[self.focused].cycle(delta);
+            }
+            _ => {
+                if let Some(field) = self.fields.get_mut(self.focused).filter(|field| !field.is_select()) {
+                    if edit_at_end(&mut field.value, key) {
+                        let mut cursor = field.value.chars().count();
+                        self.emoji.after_edit(&mut field.value, &mut cursor);
+                    }
+                }
+            }
+        }
+        self.refocus();
+        FormAction::None
+    }
+
+    /// Render as a centered popup over `area`
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let visible = self.visible_fields();
+        let width = 60.min(area.width);
+        let height = (visible.len() as u16 * 3 + 4).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.title.clone())
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(popup);
+        frame.render_widget(Clear, popup);
+        frame.render_widget(block, popup);
+
+        for (row, &i) in visible.iter().enumerate() {
+            let field = &self.fields[i];
+            let field_area = Rect {
+                y: inner.y + row as u16 * 3,
+                height: 3,
+                ..inner
+            };
+            if field_area.y + field_area.height > inner.y + inner.height {
+                break;
+            }
+            let focused = i == self.focused;
+            let label = if self.is_required(field) {
+                format!("{} *", field.label)
+            } else {
+                field.label.clone()
+            };
+            let style = if focused {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            let text = if field.is_select() {
+                format!("◀ {} ▶", field.value)
+            } else if focused {
+                format!("{}█", field.value)
+            } else {
+                field.value.clone()
+            };
+            let mut block = Block::default().borders(Borders::ALL).title(label).border_style(style);
+            if field.max_len.is_some() {
+                block = block.title_bottom(char_count::footer(&field.value, field.max_len));
+            }
+            frame.render_widget(Paragraph::new(text).block(block), field_area);
+        }
+
+        let footer = match &self.error {
+            Some(error) => Line::from(Span::styled(
+                format!("❌ {}", error),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            None => Line::from(Span::styled(
+                "Tab: next field | ←→: choose | Enter: save | Esc: cancel",
+                Style::default().fg(Color::DarkGray),
+            )),
+        };
+        let footer_area = Rect {
+            y: inner.y + inner.height.saturating_sub(1),
+            height: 1,
+            ..inner
+        };
+        frame.render_widget(Paragraph::new(footer), footer_area);
+
+        let focused_row = visible.iter().position(|&i| i == self.focused);
+        if let Some((row, field)) = focused_row.zip(self.fields.get(self.focused)).filter(|(_, field)| !field.is_select()) {
+            let cursor = field.value.chars().count();
+            let at = Position::new(
+                inner.x + 1 + column_of_char(&field.value, cursor) as u16,
+                inner.y + row as u16 * 3 + 1,
+            );
+            self.emoji.render(frame, &field.value, cursor, at, area);
+        }
+    }
+}
+
+/// Yes/no popup; `y` or Enter submits, `n` or Esc cancels
+pub struct ConfirmDialog {
+    pub title: String,
+    pub message: String,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: &str, message: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn handle_key(&self, key: KeyCode) -> FormAction {
+        match key {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => FormAction::Submit,
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => FormAction::Cancel,
+            _ => FormAction::None,
+        }
+    }
+
+    /// Render as a centered popup over `area`
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = 60.min(area.width);
+        let text_width = width.saturating_sub(2).max(1) as usize;
+        let message_rows = self
+            .message
+            .lines()
+            .map(|line| line.chars().count().div_ceil(text_width).max(1) as u16)
+            .sum::<u16>();
+        let height = (message_rows + 4).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let mut lines: Vec<Line> = self.message.lines().map(|line| Line::from(line.to_string())).collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "y/Enter: confirm | n/Esc: cancel",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.title.clone())
+            .border_style(Style::default().fg(Color::Yellow));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }).block(block), popup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_field_blocks_submit() {
+        let mut form = FormDialog::new("Card", vec![FormField::new("Title", "").required(), FormField::new("Notes", "")]);
+        assert_eq!(form.handle_key(KeyCode::Enter), FormAction::None);
+        assert!(form.error.is_some());
+
+        form.handle_key(KeyCode::Char('A'));
+        assert_eq!(form.handle_key(KeyCode::Enter), FormAction::Submit);
+        assert_eq!(form.value("Title"), Some("A"));
+    }
+
+    #[test]
+    fn test_confirm_keys() {
+        let dialog = ConfirmDialog::new("Delete", "Delete 2 items?");
+        assert_eq!(dialog.handle_key(KeyCode::Char('y')), FormAction::Submit);
+        assert_eq!(dialog.handle_key(KeyCode::Esc), FormAction::Cancel);
+        assert_eq!(dialog.handle_key(KeyCode::Char('x')), FormAction::None);
+    }
+
+    #[test]
+    fn test_focus_wraps() {
+        let mut form = FormDialog::new("Card", vec![FormField::new("A", ""), FormField::new("B", "")]);
+        form.handle_key(KeyCode::BackTab);
+        assert_eq!(form.focused, 1);
+        form.handle_key(KeyCode::Tab);
+        assert_eq!(form.focused, 0);
+    }
+
+    #[test]
+    fn test_conditional_field_follows_select() {
+        let other = || Condition::equals("Label", "Other");
+        let mut form = FormDialog::new(
+            "Card",
+            vec![
+                FormField::select("Label", &["None", "Bug", "Other"], "Bug"),
+                FormField::new("Please specify", "").visible_when(other()).required_when(other()),
+            ],
+        );
+        assert_eq!(form.visible_fields(), vec![0]);
+        assert_eq!(form.handle_key(KeyCode::Enter), FormAction::Submit);
+
+        form.handle_key(KeyCode::Right);
+        assert_eq!(form.value("Label"), Some("Other"));
+        assert_eq!(form.visible_fields(), vec![0, 1]);
+        assert_eq!(form.handle_key(KeyCode::Enter), FormAction::None);
+
+        form.handle_key(KeyCode::Tab);
+        form.handle_key(KeyCode::Char('X'));
+        assert_eq!(form.handle_key(KeyCode::Enter), FormAction::Submit);
+
+        // Hiding the focused field moves focus back to the select
+        form.fields[0].value = "None".to_string();
+        form.handle_key(KeyCode::Null);
+        assert_eq!(form.focused, 0);
+        assert_eq!(form.value("Please specify"), None);
+    }
+}
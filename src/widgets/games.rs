@@ -0,0 +1,153 @@
+//! Mini-game support module
+//!
+//! High scores per game kept in the data directory, and a canvas board that
+//! draws cell-based games with two terminal columns per cell so the cells
+//! come out roughly square.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use ratatui::{
+    layout::Rect,
+    style::Color,
+    symbols::Marker,
+    widgets::{
+        canvas::{Canvas, Points},
+        Block, Borders,
+    },
+    Frame,
+};
+
+/// Scores kept per game
+pub const MAX_SCORES: usize = 5;
+
+pub struct HighScores {
+    scores: HashMap<String, Vec<u32>>,
+    path: Option<PathBuf>,
+}
+
+impl HighScores {
+    /// Scores from `high_scores.json` in the data directory
+    pub fn load() -> Self {
+        let path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("high_scores.json");
+            path
+        });
+        Self::with_path(path)
+    }
+
+    pub fn with_path(path: Option<PathBuf>) -> Self {
+        let scores = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { scores, path }
+    }
+
+    /// Best scores of a game, highest first
+    pub fn top(&self, game: &str) -> &[u32] {
+        self.scores.get(game).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn best(&self, game: &str) -> Option<u32> {
+        self.top(game).first().copied()
+    }
+
+    /// Add a finished game's score; returns whether it is a new best
+    pub fn record(&mut self, game: &str, score: u32) -> bool {
+        if score == 0 {
+            return false;
+        }
+        let is_best = self.best(game).is_none_or(|best| score > best);
+        let scores = self.scores.entry(game.to_string()).or_default();
+        scores.push(score);
+        scores.sort_unstable_by(|a, b| b.cmp(a));
+        scores.truncate(MAX_SCORES);
+        self.save();
+        is_best
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = serde_json::to_string_pretty(&self.scores)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                path.parent().map_or(Ok(()), fs::create_dir_all).map_err(|e| e.to_string())?;
+                fs::write(path, content).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::error!("Failed to save high scores: {}", e);
+        }
+    }
+}
+
+/// Area of a bordered board of `width`×`height` cells centered in `area`
+pub fn board_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let outer_width = (width * 2 + 2).min(area.width);
+    let outer_height = (height + 2).min(area.height);
+    Rect {
+        x: area.x + (area.width - outer_width) / 2,
+        y: area.y + (area.height - outer_height) / 2,
+        width: outer_width,
+        height: outer_height,
+    }
+}
+
+/// Draw layers of cells, given as `(column, row)` with row 0 at the top, on
+/// a board drawn by `board_rect`
+pub fn draw_board(frame: &mut Frame, area: Rect, title: &str, size: (u16, u16), layers: &[(Vec<(u16, u16)>, Color)]) {
+    let (width, height) = size;
+    let rect = board_rect(area, width, height);
+    // One canvas unit per terminal column and row, so every point lands in its own cell
+    let points: Vec<(Vec<(f64, f64)>, Color)> = layers
+        .iter()
+        .map(|(cells, color)| {
+            let coords = cells
+                .iter()
+                .flat_map(|&(x, y)| {
+                    let row = (height - 1 - y) as f64;
+                    [(x as f64 * 2.0, row), (x as f64 * 2.0 + 1.0, row)]
+                })
+                .collect();
+            (coords, *color)
+        })
+        .collect();
+    let canvas = Canvas::default()
+        .block(Block::default().borders(Borders::ALL).title(title.to_string()))
+        .marker(Marker::Block)
+        .x_bounds([0.0, (width * 2 - 1) as f64])
+        .y_bounds([0.0, (height - 1) as f64])
+        .paint(move |ctx| {
+            for (coords, color) in &points {
+                ctx.draw(&Points { coords, color: *color });
+            }
+        });
+    frame.render_widget(canvas, rect);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_high_scores_keep_best_five() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scores.json");
+        let mut scores = HighScores::with_path(Some(path.clone()));
+        assert!(scores.record("snake", 10));
+        assert!(!scores.record("snake", 5));
+        assert!(!scores.record("snake", 0));
+        for score in [7, 3, 12, 1] {
+            scores.record("snake", score);
+        }
+
+        let reopened = HighScores::with_path(Some(path));
+        assert_eq!(reopened.top("snake"), &[12, 10, 7, 5, 3]);
+        assert_eq!(reopened.best("tetris"), None);
+    }
+}
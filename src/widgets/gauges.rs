@@ -0,0 +1,279 @@
+//! Gauge cluster module
+//!
+//! A group of related gauges, each with its own range and warning and
+//! critical thresholds. The track shows the green/yellow/red bands and the
+//! value is colored by the band it falls in. Gauges are drawn as horizontal
+//! bars or as braille arcs.
+
+use std::f64::consts::PI;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        canvas::{Canvas, Points},
+        Block, Borders, Paragraph,
+    },
+    Frame,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GaugeStyle {
+    Bar,
+    Radial,
+}
+
+impl GaugeStyle {
+    pub fn toggle(self) -> Self {
+        match self {
+            GaugeStyle::Bar => GaugeStyle::Radial,
+            GaugeStyle::Radial => GaugeStyle::Bar,
+        }
+    }
+}
+
+/// Threshold band of a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Normal,
+    Warning,
+    Critical,
+}
+
+impl Level {
+    pub fn color(self) -> Color {
+        match self {
+            Level::Normal => Color::Green,
+            Level::Warning => Color::Yellow,
+            Level::Critical => Color::Red,
+        }
+    }
+}
+
+/// One gauge of a cluster
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaugeSpec {
+    pub label: String,
+    pub value: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Values from here are yellow
+    pub warning: f64,
+    /// Values from here are red
+    pub critical: f64,
+    pub unit: String,
+}
+
+impl GaugeSpec {
+    /// Percentage gauge, yellow from 70 and red from 90
+    pub fn new(label: &str, value: f64) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+            min: 0.0,
+            max: 100.0,
+            warning: 70.0,
+            critical: 90.0,
+            unit: "%".to_string(),
+        }
+    }
+
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    pub fn thresholds(mut self, warning: f64, critical: f64) -> Self {
+        self.warning = warning;
+        self.critical = critical;
+        self
+    }
+
+    pub fn unit(mut self, unit: &str) -> Self {
+        self.unit = unit.to_string();
+        self
+    }
+
+    /// Position of `value` between min and max, clamped to 0..=1
+    fn ratio_of(&self, value: f64) -> f64 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    pub fn ratio(&self) -> f64 {
+        self.ratio_of(self.value)
+    }
+
+    pub fn level_of(&self, value: f64) -> Level {
+        if value >= self.critical {
+            Level::Critical
+        } else if value >= self.warning {
+            Level::Warning
+        } else {
+            Level::Normal
+        }
+    }
+
+    pub fn level(&self) -> Level {
+        self.level_of(self.value)
+    }
+
+    /// Value with its unit, e.g. `42.5%`
+    pub fn value_text(&self) -> String {
+        if self.value.fract() == 0.0 {
+            format!("{}{}", self.value, self.unit)
+        } else {
+            format!("{:.1}{}", self.value, self.unit)
+        }
+    }
+
+    /// Bar `width` cells wide: filled cells in the value's color, the rest
+    /// of the track dimmed in the color of its band
+    pub fn bar_line(&self, width: u16) -> Line<'static> {
+        let filled = (self.ratio() * width as f64).round() as u16;
+        let spans = (0..width)
+            .map(|i| {
+                let cell_value = self.min + (self.max - self.min) * (i as f64 + 0.5) / width as f64;
+                if i < filled {
+                    Span::styled("█", Style::default().fg(self.level().color()))
+                } else {
+                    Span::styled("░", Style::default().fg(self.level_of(cell_value).color()).add_modifier(Modifier::DIM))
+                }
+            })
+            .collect::<Vec<_>>();
+        Line::from(spans)
+    }
+}
+
+/// Angle for a ratio on a 270° arc opening downwards
+fn arc_angle(ratio: f64) -> f64 {
+    1.25 * PI - ratio * 1.5 * PI
+}
+
+/// Points of the arc between two ratios at `radius`
+fn arc_points(from: f64, to: f64, radius: f64) -> Vec<(f64, f64)> {
+    let steps = ((to - from) * 120.0).ceil().max(1.0) as usize;
+    (0..=steps)
+        .map(|step| {
+            let angle = arc_angle(from + (to - from) * step as f64 / steps as f64);
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+pub struct GaugeCluster {
+    pub title: String,
+    pub gauges: Vec<GaugeSpec>,
+    pub style: GaugeStyle,
+}
+
+impl GaugeCluster {
+    pub fn new(title: &str, gauges: Vec<GaugeSpec>) -> Self {
+        Self {
+            title: title.to_string(),
+            gauges,
+            style: GaugeStyle::Bar,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default().borders(Borders::ALL).title(self.title.clone());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        if self.gauges.is_empty() {
+            return;
+        }
+        match self.style {
+            GaugeStyle::Bar => self.render_bars(frame, inner),
+            GaugeStyle::Radial => self.render_radial(frame, inner),
+        }
+    }
+
+    /// One row per gauge: label, banded bar and value
+    fn render_bars(&self, frame: &mut Frame, area: Rect) {
+        let label_width = self.gauges.iter().map(|gauge| gauge.label.chars().count()).max().unwrap_or(0) as u16 + 1;
+        let bar_width = area.width.saturating_sub(label_width + 9);
+        let lines: Vec<Line> = self
+            .gauges
+            .iter()
+            .flat_map(|gauge| {
+                let mut spans = vec![Span::styled(
+                    format!("{:width$}", gauge.label, width = label_width as usize),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )];
+                spans.extend(gauge.bar_line(bar_width).spans);
+                spans.push(Span::styled(
+                    format!(" {:>8}", gauge.value_text()),
+                    Style::default().fg(gauge.level().color()),
+                ));
+                [Line::from(spans), Line::raw("")]
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    /// Gauges in columns, each a braille arc with the value in the middle
+    fn render_radial(&self, frame: &mut Frame, area: Rect) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, self.gauges.len() as u32); self.gauges.len()])
+            .split(area);
+        for (gauge, column) in self.gauges.iter().zip(columns.iter()) {
+            let bands = [
+                (0.0, gauge.ratio_of(gauge.warning), Level::Normal),
+                (gauge.ratio_of(gauge.warning), gauge.ratio_of(gauge.critical), Level::Warning),
+                (gauge.ratio_of(gauge.critical), 1.0, Level::Critical),
+            ];
+            // Canvas units per terminal column, for centering text
+            let cell = 2.4 / column.width.max(1) as f64;
+            let value_text = gauge.value_text();
+            let canvas = Canvas::default()
+                .marker(Marker::Braille)
+                .x_bounds([-1.2, 1.2])
+                .y_bounds([-1.2, 1.2])
+                .paint(move |ctx| {
+                    for (from, to, level) in bands {
+                        if to > from {
+                            ctx.draw(&Points { coords: &arc_points(from, to, 1.1), color: level.color() });
+                        }
+                    }
+                    for radius in [0.8, 0.85, 0.9] {
+                        ctx.draw(&Points { coords: &arc_points(0.0, gauge.ratio(), radius), color: gauge.level().color() });
+                    }
+                    ctx.print(
+                        -cell * value_text.chars().count() as f64 / 2.0,
+                        0.0,
+                        Span::styled(value_text.clone(), Style::default().fg(gauge.level().color()).add_modifier(Modifier::BOLD)),
+                    );
+                    ctx.print(-cell * gauge.label.chars().count() as f64 / 2.0, -0.5, gauge.label.clone());
+                });
+            frame.render_widget(canvas, *column);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_and_banded_bar() {
+        let gauge = GaugeSpec::new("CPU", 75.0);
+        assert_eq!(gauge.level(), Level::Warning);
+        assert_eq!(GaugeSpec::new("CPU", 95.0).level(), Level::Critical);
+        assert_eq!(gauge.clone().range(0.0, 150.0).ratio(), 0.5);
+
+        let bar = gauge.bar_line(10);
+        assert_eq!(bar.spans.len(), 10);
+        // Filled up to the value in yellow, then the red band of the track
+        assert_eq!(bar.spans[7].content, "█");
+        assert_eq!(bar.spans[7].style.fg, Some(Color::Yellow));
+        assert_eq!(bar.spans[8].content, "░");
+        assert_eq!(bar.spans[9].style.fg, Some(Color::Red));
+    }
+}
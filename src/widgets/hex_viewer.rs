@@ -0,0 +1,268 @@
+//! Hex viewer module
+//!
+//! Hex dump of a binary file: the offset, sixteen bytes in hex and the same
+//! bytes as ASCII, with bytes coloured by kind (null, printable, whitespace,
+//! other). Only the rows on screen are read, so large files open at once.
+//! `:` jumps to an offset given in decimal or as `0x…` hex.
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::humanize::format_size;
+use super::line_edit::edit_at_end;
+use crate::event::{KeyCode, KeyEvent};
+
+pub const BYTES_PER_ROW: usize = 16;
+
+/// Bytes read from the start of a file to decide whether it is binary
+pub const SNIFF_LEN: usize = 8192;
+
+/// Whether `sample`, the start of a file, is binary rather than text: it
+/// has a null byte or invalid UTF-8. A character cut off at the end of the
+/// sample does not count.
+pub fn looks_binary(sample: &[u8]) -> bool {
+    sample.contains(&0) || std::str::from_utf8(sample).is_err_and(|e| e.error_len().is_some())
+}
+
+/// The first `len` bytes of `path`
+pub fn read_sample(path: &Path, len: usize) -> io::Result<Vec<u8>> {
+    let mut sample = Vec::with_capacity(len);
+    File::open(path)?.take(len as u64).read_to_end(&mut sample)?;
+    Ok(sample)
+}
+
+/// Parse an offset typed as decimal or `0x` hex
+pub fn parse_offset(text: &str) -> Option<u64> {
+    let text = text.trim();
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+fn byte_style(byte: u8) -> Style {
+    match byte {
+        0 => Style::default().fg(Color::DarkGray),
+        b'\t' | b'\n' | b'\r' | b' ' => Style::default().fg(Color::Green),
+        0x21..=0x7e => Style::default().fg(Color::Cyan),
+        _ => Style::default().fg(Color::Yellow),
+    }
+}
+
+/// One dump row for `bytes` starting at `offset`; the hex column is padded
+/// so a short last row keeps the ASCII column aligned. `marked` is the
+/// index of a byte to highlight.
+pub fn hex_row(offset: u64, bytes: &[u8], per_row: usize, marked: Option<usize>) -> Line<'static> {
+    let mark = |style: Style, i: usize| {
+        if marked == Some(i) {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    };
+    let mut spans = vec![Span::styled(format!("{:08x}  ", offset), Style::default().fg(Color::DarkGray))];
+    for i in 0..per_row {
+        if i == per_row / 2 && per_row > 4 {
+            spans.push(Span::raw(" "));
+        }
+        match bytes.get(i) {
+            Some(&byte) => spans.push(Span::styled(format!("{:02x}", byte), mark(byte_style(byte), i))),
+            None => spans.push(Span::raw("  ")),
+        }
+        spans.push(Span::raw(" "));
+    }
+    spans.push(Span::styled("│", Style::default().fg(Color::DarkGray)));
+    for (i, &byte) in bytes.iter().enumerate() {
+        let shown = if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' };
+        spans.push(Span::styled(shown.to_string(), mark(byte_style(byte), i)));
+    }
+    spans.push(Span::styled("│", Style::default().fg(Color::DarkGray)));
+    Line::from(spans)
+}
+
+/// Bytes per row that fit in `width` columns: 16, 8 or 4
+pub fn bytes_per_row_for(width: u16) -> usize {
+    match width {
+        77.. => 16,
+        44.. => 8,
+        _ => 4,
+    }
+}
+
+pub struct HexViewer {
+    pub path: PathBuf,
+    file: File,
+    len: u64,
+    /// First row on screen
+    pub row: u64,
+    /// Rows visible at the last render, used for paging
+    page_rows: usize,
+    /// Bytes on screen, starting at `window_start`
+    window: Vec<u8>,
+    window_start: Option<u64>,
+    /// Byte picked by the last go-to, highlighted
+    pub marked: Option<u64>,
+    /// Go-to-offset prompt while it is open
+    pub goto: Option<String>,
+    message: Option<String>,
+}
+
+impl HexViewer {
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            len,
+            row: 0,
+            page_rows: 16,
+            window: Vec::new(),
+            window_start: None,
+            marked: None,
+            goto: None,
+            message: None,
+        })
+    }
+
+    fn total_rows(&self) -> u64 {
+        self.len.div_ceil(BYTES_PER_ROW as u64)
+    }
+
+    fn max_row(&self) -> u64 {
+        self.total_rows().saturating_sub(self.page_rows as u64)
+    }
+
+    pub fn scroll_by(&mut self, delta: i64) {
+        self.row = self.row.saturating_add_signed(delta).min(self.max_row());
+    }
+
+    /// Scroll `offset` to the top of the view and highlight it
+    pub fn go_to(&mut self, offset: u64) {
+        if offset >= self.len {
+            self.message = Some(format!("Offset 0x{:x} is past the end (0x{:x})", offset, self.len));
+            return;
+        }
+        self.marked = Some(offset);
+        self.row = (offset / BYTES_PER_ROW as u64).min(self.max_row());
+        self.message = None;
+    }
+
+    /// Returns whether the key was used; Esc and `q` outside the prompt are
+    /// left to the host, which closes the viewer
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if let Some(input) = self.goto.as_mut() {
+            match key.code {
+                KeyCode::Esc => self.goto = None,
+                KeyCode::Enter => {
+                    let input = self.goto.take().unwrap_or_default();
+                    match parse_offset(&input) {
+                        Some(offset) => self.go_to(offset),
+                        None => self.message = Some(format!("Not an offset: {}", input)),
+                    }
+                }
+                _ => {
+                    edit_at_end(input, key);
+                }
+            }
+            return true;
+        }
+        let page = self.page_rows as i64;
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_by(1),
+            KeyCode::PageUp | KeyCode::Char('b') => self.scroll_by(-page),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by(page),
+            KeyCode::Home | KeyCode::Char('g') => self.row = 0,
+            KeyCode::End | KeyCode::Char('G') => self.row = self.max_row(),
+            KeyCode::Char(':') => self.goto = Some(String::new()),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Read the rows on screen unless they are already loaded
+    fn load_window(&mut self) -> io::Result<()> {
+        let start = self.row * BYTES_PER_ROW as u64;
+        let wanted = (self.page_rows * BYTES_PER_ROW) as u64;
+        if self.window_start == Some(start) && self.window.len() as u64 >= wanted.min(self.len - start) {
+            return Ok(());
+        }
+        self.window.clear();
+        self.file.seek(SeekFrom::Start(start))?;
+        (&mut self.file).take(wanted).read_to_end(&mut self.window)?;
+        self.window_start = Some(start);
+        Ok(())
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let footer = match &self.goto {
+            Some(input) => format!(" Go to offset: {}▏ (decimal or 0x hex) | Enter: go | Esc: cancel ", input),
+            None => {
+                let offset = self.row * BYTES_PER_ROW as u64;
+                let percent = ((offset + (self.page_rows * BYTES_PER_ROW) as u64).min(self.len) * 100)
+                    .checked_div(self.len)
+                    .unwrap_or(100);
+                format!(
+                    " 0x{:08x} / 0x{:08x} {}% | ↑↓ PgUp/PgDn: scroll | g/G: start/end | :: go to offset | q: close ",
+                    offset, self.len, percent
+                )
+            }
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" ⬢ {} ({}) ", self.path.display(), format_size(self.len)))
+            .title_bottom(footer);
+        let inner = block.inner(area);
+        self.page_rows = inner.height.max(1) as usize;
+        self.row = self.row.min(self.max_row());
+
+        let mut lines = Vec::new();
+        if let Some(message) = &self.message {
+            lines.push(Line::styled(message.clone(), Style::default().fg(Color::Red)));
+        }
+        match self.load_window() {
+            Ok(()) => {
+                let start = self.row * BYTES_PER_ROW as u64;
+                for (i, chunk) in self.window.chunks(BYTES_PER_ROW).enumerate() {
+                    let offset = start + (i * BYTES_PER_ROW) as u64;
+                    let marked = self.marked.and_then(|marked| marked.checked_sub(offset)).filter(|&at| at < BYTES_PER_ROW as u64);
+                    lines.push(hex_row(offset, chunk, BYTES_PER_ROW, marked.map(|at| at as usize)));
+                }
+            }
+            Err(e) => lines.push(Line::styled(format!("Failed to read: {}", e), Style::default().fg(Color::Red))),
+        }
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_dump() {
+        let line = hex_row(0x10, b"Hi\0\xff", 8, None);
+        let text: String = line.spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(text, "00000010  48 69 00 ff              │Hi..│");
+
+        assert!(looks_binary(b"\x7fELF\x02\x01\x01\0"));
+        assert!(!looks_binary("naïve text".as_bytes()));
+        // A multi-byte character cut off by the sample is still text
+        assert!(!looks_binary(&"é".as_bytes()[..1]));
+        assert_eq!(parse_offset("0x1F0"), Some(0x1f0));
+        assert_eq!(parse_offset("496"), Some(496));
+        assert_eq!(parse_offset("zz"), None);
+    }
+}
@@ -0,0 +1,310 @@
+//! Input history module
+//!
+//! Readline-style history for input fields that opt in: Up/Down recall that
+//! keeps the unsent draft, de-duplicated entries saved to the data directory,
+//! and a Ctrl+R reverse-search overlay.
+
+use std::fs;
+use std::path::PathBuf;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::line_edit::edit_at_end;
+use super::redact::redact;
+
+/// Entries kept per history file
+const MAX_ENTRIES: usize = 500;
+
+/// Matches listed in the reverse-search overlay
+const MAX_SHOWN: usize = 10;
+
+/// Result of a key press in the reverse-search overlay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryAction {
+    None,
+    /// Use this entry as the field's text
+    Accept(String),
+    Cancel,
+}
+
+#[derive(Default)]
+struct ReverseSearch {
+    query: String,
+    /// Index into the current matches, newest first
+    selected: usize,
+}
+
+/// Entries of one input field, newest first
+#[derive(Default)]
+pub struct InputHistory {
+    pub entries: Vec<String>,
+    path: Option<PathBuf>,
+    /// Entry shown by Up/Down recall, `None` while editing the draft
+    index: Option<usize>,
+    /// Text typed before recall started, restored by Down past the newest entry
+    draft: String,
+    search: Option<ReverseSearch>,
+}
+
+impl InputHistory {
+    /// In-memory history that is not saved between sessions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// History backed by `history/<name>.json` in the data directory, so
+    /// fields sharing a name share their entries
+    pub fn persistent(name: &str) -> Self {
+        let path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("history");
+            path.push(format!("{}.json", name));
+            path
+        });
+        let entries = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            entries,
+            path,
+            ..Self::default()
+        }
+    }
+
+    /// Record a submitted entry, moving an existing copy to the front
+    pub fn push(&mut self, entry: &str) {
+        self.reset();
+        if entry.trim().is_empty() {
+            return;
+        }
+        let entry = redact(entry);
+        self.entries.retain(|existing| *existing != entry);
+        self.entries.insert(0, entry);
+        self.entries.truncate(MAX_ENTRIES);
+        self.save();
+    }
+
+    /// Stop recalling and forget the draft
+    pub fn reset(&mut self) {
+        self.index = None;
+        self.draft.clear();
+    }
+
+    /// Older entry for Up; `current` is kept as the draft when recall starts
+    pub fn older(&mut self, current: &str) -> Option<String> {
+        let index = match self.index {
+            None if !self.entries.is_empty() => {
+                self.draft = current.to_string();
+                0
+            }
+            Some(index) if index + 1 < self.entries.len() => index + 1,
+            _ => return None,
+        };
+        self.index = Some(index);
+        Some(self.entries[index].clone())
+    }
+
+    /// Newer entry for Down, ending with the draft
+    pub fn newer(&mut self) -> Option<String> {
+        match self.index? {
+            0 => {
+                self.index = None;
+                Some(std::mem::take(&mut self.draft))
+            }
+            index => {
+                self.index = Some(index - 1);
+                Some(self.entries[index - 1].clone())
+            }
+        }
+    }
+
+    /// Entries containing `query`, ignoring case, newest first
+    pub fn matches(&self, query: &str) -> Vec<&str> {
+        let query = query.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|entry| entry.to_lowercase().contains(&query))
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn start_search(&mut self) {
+        self.search = Some(ReverseSearch::default());
+    }
+
+    pub fn is_searching(&self) -> bool {
+        self.search.is_some()
+    }
+
+    /// Ctrl+R or ↑ steps to older matches, ↓ to newer ones; typing narrows
+    /// the query
+    pub fn handle_search_key(&mut self, key: KeyEvent) -> HistoryAction {
+        let Some(search) = &self.search else {
+            return HistoryAction::None;
+        };
+        let count = self.matches(&search.query).len();
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let Some(search) = &mut self.search else {
+            return HistoryAction::None;
+        };
+        match key.code {
+            KeyCode::Char('r') if ctrl => search.selected = (search.selected + 1).min(count.saturating_sub(1)),
+            KeyCode::Up => search.selected = (search.selected + 1).min(count.saturating_sub(1)),
+            KeyCode::Down => search.selected = search.selected.saturating_sub(1),
+            KeyCode::Esc => {
+                self.search = None;
+                return HistoryAction::Cancel;
+            }
+            KeyCode::Enter => {
+                let query = std::mem::take(&mut search.query);
+                let selected = search.selected;
+                let entry = self.matches(&query).get(selected).map(|entry| entry.to_string());
+                self.search = None;
+                self.reset();
+                return entry.map_or(HistoryAction::Cancel, HistoryAction::Accept);
+            }
+            _ => {
+                if edit_at_end(&mut search.query, key) {
+                    search.selected = 0;
+                }
+            }
+        }
+        HistoryAction::None
+    }
+
+    /// Draw the reverse-search overlay near the bottom of `area`
+    pub fn render_search(&self, frame: &mut Frame, area: Rect) {
+        let Some(search) = &self.search else {
+            return;
+        };
+        let matches = self.matches(&search.query);
+        let shown = matches.len().clamp(1, MAX_SHOWN) as u16;
+        let width = (area.width * 7 / 10).max(30).min(area.width);
+        let height = (shown + 5).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) * 3 / 4,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let input_area = Rect { height: 3, ..popup };
+        let list_area = Rect {
+            y: popup.y + 3,
+            height: popup.height.saturating_sub(3),
+            ..popup
+        };
+
+        let input = Paragraph::new(format!("{}█", search.query)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("(reverse-i-search)")
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(input, input_area);
+
+        let highlight = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|entry| ListItem::new(highlight_match(entry, &search.query, highlight)))
+            .collect();
+        let title = if matches.is_empty() {
+            "No matching history ".to_string()
+        } else {
+            format!("{} of {} entries ", matches.len(), self.entries.len())
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .title_bottom("Ctrl+R/↑: older | ↓: newer | Enter: use | Esc: cancel"),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        let selected = (!matches.is_empty()).then_some(search.selected);
+        let mut state = ListState::default().with_selected(selected);
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                let content = serde_json::to_string_pretty(&self.entries)?;
+                fs::write(path, content)
+            });
+        if let Err(e) = result {
+            log::error!("Failed to save input history: {}", e);
+        }
+    }
+}
+
+/// `entry` with the first case-insensitive occurrence of `query` styled
+fn highlight_match(entry: &str, query: &str, style: Style) -> Line<'static> {
+    let found = (!query.is_empty())
+        .then(|| entry.to_lowercase().find(&query.to_lowercase()))
+        .flatten()
+        // Lowercasing can change byte lengths; only trust offsets that still line up
+        .filter(|&start| entry.is_char_boundary(start) && entry.is_char_boundary(start + query.len()));
+    match found {
+        Some(start) => {
+            let end = start + query.len();
+            Line::from(vec![
+                Span::raw(entry[..start].to_string()),
+                Span::styled(entry[start..end].to_string(), style),
+                Span::raw(entry[end..].to_string()),
+            ])
+        }
+        None => Line::raw(entry.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_recall_dedup_and_reverse_search() {
+        let mut history = InputHistory::new();
+        for entry in ["alice", "bob", "  ", "alice", "alfred"] {
+            history.push(entry);
+        }
+        assert_eq!(history.entries, vec!["alfred", "alice", "bob"]);
+
+        assert_eq!(history.older("dra").as_deref(), Some("alfred"));
+        assert_eq!(history.older("ignored").as_deref(), Some("alice"));
+        assert_eq!(history.newer().as_deref(), Some("alfred"));
+        assert_eq!(history.newer().as_deref(), Some("dra"));
+        assert_eq!(history.newer(), None);
+
+        history.start_search();
+        for c in "AL".chars() {
+            history.handle_search_key(key(KeyCode::Char(c), KeyModifiers::SHIFT));
+        }
+        history.handle_search_key(key(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        assert_eq!(
+            history.handle_search_key(key(KeyCode::Enter, KeyModifiers::NONE)),
+            HistoryAction::Accept("alice".to_string())
+        );
+        assert!(!history.is_searching());
+    }
+}
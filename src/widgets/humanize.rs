@@ -0,0 +1,165 @@
+//! Human-readable formatting module
+//!
+//! Sizes with binary units (`1.4 GiB`), durations as a clock (`00:04:32`)
+//! and times relative to now (`3 minutes ago`, `in 2 days`). The words and
+//! number format come from a `Locale`; `English` is used unless a screen
+//! passes its own, which is where a translation plugs in.
+
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Units of a relative time, from the smallest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Words and number format of the formatted text
+pub trait Locale {
+    /// `count` of `unit`, such as `3 minutes`
+    fn amount(&self, count: u64, unit: TimeUnit) -> String;
+    /// A past `amount`, such as `3 minutes ago`
+    fn ago(&self, amount: &str) -> String;
+    /// A future `amount`, such as `in 3 minutes`
+    fn later(&self, amount: &str) -> String;
+    /// Less than a minute either way
+    fn just_now(&self) -> String;
+    /// A number with one decimal
+    fn decimal(&self, value: f64) -> String {
+        format!("{:.1}", value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl Locale for English {
+    fn amount(&self, count: u64, unit: TimeUnit) -> String {
+        let name = match unit {
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Month => "month",
+            TimeUnit::Year => "year",
+        };
+        format!("{} {}{}", count, name, if count == 1 { "" } else { "s" })
+    }
+
+    fn ago(&self, amount: &str) -> String {
+        format!("{} ago", amount)
+    }
+
+    fn later(&self, amount: &str) -> String {
+        format!("in {}", amount)
+    }
+
+    fn just_now(&self) -> String {
+        "just now".to_string()
+    }
+}
+
+/// Format a byte count with binary units, e.g. `1.5 MiB`
+pub fn format_size(bytes: u64) -> String {
+    format_size_in(&English, bytes)
+}
+
+pub fn format_size_in(locale: &dyn Locale, bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{} {}", locale.decimal(size), UNITS[unit])
+    }
+}
+
+/// Format a duration as `HH:MM:SS`; hours go past 99 rather than wrap
+pub fn format_clock(duration: Duration) -> String {
+    let total = duration.as_secs();
+    format!("{:02}:{:02}:{:02}", total / 3600, (total % 3600) / 60, total % 60)
+}
+
+/// How long ago `time` was, or how far ahead it is
+pub fn time_ago<Tz: TimeZone>(time: &DateTime<Tz>) -> String {
+    relative_time(Utc::now().signed_duration_since(time.with_timezone(&Utc)))
+}
+
+/// `elapsed` as a relative time; negative values are in the future
+pub fn relative_time(elapsed: chrono::Duration) -> String {
+    relative_time_in(&English, elapsed)
+}
+
+pub fn relative_time_in(locale: &dyn Locale, elapsed: chrono::Duration) -> String {
+    const STEPS: [(u64, TimeUnit); 6] = [
+        (60, TimeUnit::Minute),
+        (3_600, TimeUnit::Hour),
+        (86_400, TimeUnit::Day),
+        (7 * 86_400, TimeUnit::Week),
+        (30 * 86_400, TimeUnit::Month),
+        (365 * 86_400, TimeUnit::Year),
+    ];
+    let seconds = elapsed.num_seconds().unsigned_abs();
+    if seconds < 60 {
+        return locale.just_now();
+    }
+    // Largest unit that fits; weeks stop at a month, months at a year
+    let (size, unit) = STEPS.iter().rev().find(|(size, _)| seconds >= *size).copied().unwrap_or(STEPS[0]);
+    let amount = locale.amount(seconds / size, unit);
+    if elapsed.num_seconds() >= 0 {
+        locale.ago(&amount)
+    } else {
+        locale.later(&amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_human_formats() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024 * 1024), "5.0 GiB");
+        assert_eq!(format_clock(Duration::from_secs(272)), "00:04:32");
+        assert_eq!(format_clock(Duration::from_secs(100 * 3600)), "100:00:00");
+
+        assert_eq!(relative_time(chrono::Duration::seconds(20)), "just now");
+        assert_eq!(relative_time(chrono::Duration::seconds(200)), "3 minutes ago");
+        assert_eq!(relative_time(chrono::Duration::hours(-1)), "in 1 hour");
+        assert_eq!(relative_time(chrono::Duration::days(45)), "1 month ago");
+
+        struct Comma;
+        impl Locale for Comma {
+            fn amount(&self, count: u64, _unit: TimeUnit) -> String {
+                count.to_string()
+            }
+            fn ago(&self, amount: &str) -> String {
+                format!("vor {}", amount)
+            }
+            fn later(&self, amount: &str) -> String {
+                format!("in {}", amount)
+            }
+            fn just_now(&self) -> String {
+                "gerade eben".to_string()
+            }
+            fn decimal(&self, value: f64) -> String {
+                format!("{:.1}", value).replace('.', ",")
+            }
+        }
+        assert_eq!(format_size_in(&Comma, 1536), "1,5 KiB");
+        assert_eq!(relative_time_in(&Comma, chrono::Duration::seconds(5)), "gerade eben");
+    }
+}
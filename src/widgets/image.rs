@@ -2,52 +2,46 @@
 //! 
 //! Provides high-resolution image rendering and MP4 video frame extraction/playback
 //! Features ASCII art conversion, frame caching, and cross-platform video support.
+//! Images can be decoded on a background thread, with loading and error states.
+//! Converted frames are cached per target area and quality, so redrawing or
+//! switching back to an image tab does not scale the image again.
 
 use std::io::{self, Result as IoResult};
-use std::path::Path;
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Instant, SystemTime};
 use image::{DynamicImage, imageops::FilterType};
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Wrap},
 };
+use thiserror::Error;
+
+use crate::error::DemoError;
+use crate::event::{Event, KeyCode};
+use crate::platform;
+
+use super::component::{key_press, Component};
+use super::lru::{self, LruCache};
+use super::spinner::{Spinner, SpinnerStyle};
 
 /// Error types for image operations
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum ImageError {
-    Io(io::Error),
-    ImageError(image::ImageError),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Image error: {0}")]
+    ImageError(#[from] image::ImageError),
+    #[error("Unsupported format: {0}")]
     UnsupportedFormat(String),
+    #[error("FFmpeg error: {0}")]
     FfmpegError(String),
+    #[error("Failed to load {}: {message}", path.display())]
+    Load { path: PathBuf, message: String },
 }
 
-impl From<io::Error> for ImageError {
-    fn from(err: io::Error) -> Self {
-        ImageError::Io(err)
-    }
-}
-
-impl From<image::ImageError> for ImageError {
-    fn from(err: image::ImageError) -> Self {
-        ImageError::ImageError(err)
-    }
-}
-
-impl std::fmt::Display for ImageError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ImageError::Io(err) => write!(f, "IO error: {err}"),
-            ImageError::ImageError(err) => write!(f, "Image error: {err}"),
-            ImageError::FfmpegError(msg) => write!(f, "FFmpeg error: {msg}"),
-            ImageError::UnsupportedFormat(msg) => write!(f, "Unsupported format: {msg}"),
-        }
-    }
-}
-
-impl std::error::Error for ImageError {}
-
 /// Image quality settings
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ImageQuality {
     Low,
     Medium,
@@ -72,9 +66,48 @@ impl ImageQuality {
     }
 }
 
+/// What the widget is currently showing
+pub enum ImageState {
+    Empty,
+    /// Decoding `path` in the background
+    Loading { path: PathBuf, spinner: Spinner },
+    Loaded,
+    /// Loading `path` failed; `r` retries
+    Error { path: PathBuf, message: String },
+}
+
+/// What a converted frame was made from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    path: Option<PathBuf>,
+    area: Rect,
+    quality: ImageQuality,
+}
+
+/// Turns a file into an image on the background thread of `load_async`
+pub type Decoder = fn(&Path) -> Result<DynamicImage, String>;
+
+/// Decode an image file of any format the `image` crate knows
+pub fn open_image(path: &Path) -> Result<DynamicImage, String> {
+    image::open(path).map_err(|e| e.to_string())
+}
+
+/// Last modification time of `path`, if it can be read
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
 /// Image rendering widget
 pub struct ImageWidget {
+    state: ImageState,
+    /// Result of the background decode started by `load_async`
+    pending: Option<Receiver<Result<DynamicImage, String>>>,
+    /// Used by `load_async`, and to reload or retry the file
+    decoder: Decoder,
     current_image: Option<DynamicImage>,
+    /// File of the current image and its modification time when loaded
+    source: Option<(PathBuf, Option<SystemTime>)>,
+    render_cache: LruCache<CacheKey, Vec<String>>,
     frame_cache: Vec<DynamicImage>,
     current_frame: usize,
     quality: ImageQuality,
@@ -99,7 +132,12 @@ impl Default for ImageWidget {
 impl ImageWidget {
     pub fn new() -> Self {
         Self {
+            state: ImageState::Empty,
+            pending: None,
+            decoder: open_image,
             current_image: None,
+            source: None,
+            render_cache: LruCache::new("image render", lru::config().image_render_bytes),
             frame_cache: Vec::new(),
             current_frame: 0,
             last_frame_time: Instant::now(),
@@ -118,24 +156,170 @@ impl ImageWidget {
         }
     }
 
+    /// Decode files with `decoder` instead of as image files, e.g. to
+    /// render a document page
+    pub fn with_decoder(mut self, decoder: Decoder) -> Self {
+        self.decoder = decoder;
+        self
+    }
+
+    pub fn state(&self) -> &ImageState {
+        &self.state
+    }
+
+    /// Decode `path` on the calling thread; a failure also moves the widget
+    /// to the error state
     pub fn load_image<P: AsRef<Path>>(&mut self, path: P) -> Result<(), ImageError> {
-        let img = image::open(path)?;
+        self.pending = None;
+        self.source = Some((path.as_ref().to_path_buf(), modified_time(path.as_ref())));
+        match image::open(path.as_ref()) {
+            Ok(img) => {
+                self.install(img);
+                Ok(())
+            }
+            Err(e) => {
+                self.fail(path.as_ref(), e.to_string());
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Decode `path` on a background thread; `poll` picks up the result
+    pub fn load_async<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref().to_path_buf();
+        self.source = Some((path.clone(), modified_time(&path)));
+        let (sender, receiver) = mpsc::channel();
+        let (decode_path, decoder) = (path.clone(), self.decoder);
+        platform::current().background(Box::new(move || {
+            let _ = sender.send(decoder(&decode_path));
+        }));
+        self.pending = Some(receiver);
+        let label = format!("Loading {}", path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned()));
+        self.state = ImageState::Loading {
+            path,
+            spinner: Spinner::new(SpinnerStyle::Braille, &label),
+        };
+    }
+
+    /// Show an image that was already decoded, e.g. from a cache
+    pub fn show_image<P: AsRef<Path>>(&mut self, path: P, img: DynamicImage) {
+        self.pending = None;
+        self.source = Some((path.as_ref().to_path_buf(), modified_time(path.as_ref())));
+        self.install(img);
+    }
+
+    /// The decoded image, once loaded
+    pub fn image(&self) -> Option<&DynamicImage> {
+        self.current_image.as_ref()
+    }
+
+    /// Install a finished background decode, or start reloading the image
+    /// when its file changed on disk; returns true when the state changed
+    pub fn poll(&mut self) -> bool {
+        let Some(receiver) = &self.pending else {
+            return self.reload_if_changed();
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return false,
+            Err(TryRecvError::Disconnected) => Err("decoder thread stopped".to_string()),
+        };
+        self.pending = None;
+        let path = match &self.state {
+            ImageState::Loading { path, .. } => path.clone(),
+            _ => PathBuf::new(),
+        };
+        match result {
+            Ok(img) => self.install(img),
+            Err(message) => self.fail(&path, message),
+        }
+        true
+    }
+
+    fn reload_if_changed(&mut self) -> bool {
+        let Some((path, modified)) = &self.source else {
+            return false;
+        };
+        if !matches!(self.state, ImageState::Loaded) || modified_time(path) == *modified {
+            return false;
+        }
+        let path = path.clone();
+        self.load_async(path);
+        true
+    }
+
+    /// Load the failed image again
+    pub fn retry(&mut self) -> bool {
+        let ImageState::Error { path, .. } = &self.state else {
+            return false;
+        };
+        let path = path.clone();
+        self.load_async(path);
+        true
+    }
+
+    /// `r` retries after an error
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        key == KeyCode::Char('r') && self.retry()
+    }
+
+    fn install(&mut self, img: DynamicImage) {
         self.current_image = Some(img);
+        self.render_cache.clear();
+        self.state = ImageState::Loaded;
         self.frame_cache.clear();
         self.is_playing = false;
         self.current_frame = 0;
         self.total_frames = 1; // Single image has 1 frame
         self.frames_rendered = 0;
-        Ok(())
+    }
+
+    fn fail(&mut self, path: &Path, message: String) {
+        self.current_image = None;
+        self.state = ImageState::Error {
+            path: path.to_path_buf(),
+            message,
+        };
     }
 
     pub fn render(&mut self, frame: &mut Frame, area: Rect) -> IoResult<()> {
+        let block = Block::default().borders(Borders::ALL).title("Image");
+        match &self.state {
+            ImageState::Loading { spinner, .. } => {
+                let inner = block.inner(area);
+                frame.render_widget(block, area);
+                let row = Rect { y: inner.y + inner.height / 2, height: 1.min(inner.height), ..inner };
+                frame.render_widget(Paragraph::new(spinner.line()).alignment(Alignment::Center), row);
+                return Ok(());
+            }
+            ImageState::Error { path, message } => {
+                let lines = vec![
+                    Line::styled(format!("Failed to load {}", path.display()), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                    Line::styled(message.clone(), Style::default().fg(Color::Red)),
+                    Line::raw(""),
+                    Line::styled("Press r to retry", Style::default().fg(Color::Gray)),
+                ];
+                let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(block.border_style(Style::default().fg(Color::Red)));
+                frame.render_widget(paragraph, area);
+                return Ok(());
+            }
+            ImageState::Empty | ImageState::Loaded => {}
+        }
         if let Some(img) = &self.current_image {
-            let ascii_art = self.image_to_ascii(img, area.width as usize, area.height as usize);
-            
-            let lines: Vec<Line> = ascii_art.into_iter()
-                .map(|line| Line::from(line))
-                .collect();
+            let key = CacheKey {
+                path: self.source.as_ref().map(|(path, _)| path.clone()),
+                area,
+                quality: self.quality,
+            };
+            let ascii_art = match self.render_cache.get(&key) {
+                Some(lines) => lines.clone(),
+                None => {
+                    let lines = self.image_to_ascii(img, area.width as usize, area.height as usize);
+                    self.render_cache.insert(key, lines.clone());
+                    lines
+                }
+            };
+            let lines: Vec<Line> = ascii_art.into_iter().map(Line::raw).collect();
 
             // Add frame info to title for video playback info
             let title = if self.total_frames > 1 {
@@ -195,7 +379,8 @@ impl ImageWidget {
         }
     }
 
-    fn image_to_ascii(&self, img: &DynamicImage, width: usize, height: usize) -> Vec<String> {
+    /// `img` scaled to `width` x `height` cells, one character per cell
+    pub fn image_to_ascii(&self, img: &DynamicImage, width: usize, height: usize) -> Vec<String> {
         let ascii_chars = self.quality.get_ascii_chars();
         let filter = self.quality.get_filter();
         
@@ -221,6 +406,32 @@ impl ImageWidget {
     }
 }
 
+impl Component for ImageWidget {
+    fn handle_event(&mut self, event: &Event) -> bool {
+        key_press(event).is_some_and(|key| self.handle_key(key.code))
+    }
+
+    /// Fails once when a background decode fails; the widget keeps showing
+    /// the error until it is retried
+    fn update(&mut self) -> Result<(), DemoError> {
+        if !self.poll() {
+            return Ok(());
+        }
+        match &self.state {
+            ImageState::Error { path, message } => Err(ImageError::Load {
+                path: path.clone(),
+                message: message.clone(),
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError> {
+        Ok(ImageWidget::render(self, frame, area)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,6 +460,41 @@ mod tests {
         assert!(!playing);
     }
 
+    #[test]
+    fn test_async_load_error_and_retry() {
+        let mut widget = ImageWidget::new();
+        widget.load_async("does-not-exist.png");
+        assert!(matches!(widget.state(), ImageState::Loading { .. }));
+        let start = Instant::now();
+        let mut update = Ok(());
+        while matches!(widget.state(), ImageState::Loading { .. }) && start.elapsed().as_secs() < 5 {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            update = Component::update(&mut widget);
+        }
+        assert!(matches!(widget.state(), ImageState::Error { path, .. } if path == Path::new("does-not-exist.png")));
+        assert!(matches!(update, Err(DemoError::Image(ImageError::Load { .. }))));
+
+        assert!(widget.handle_key(KeyCode::Char('r')));
+        assert!(matches!(widget.state(), ImageState::Loading { .. }));
+        assert!(!ImageWidget::new().retry());
+    }
+
+    #[test]
+    fn test_render_cache_reuses_conversions() {
+        let mut widget = ImageWidget::new();
+        widget.install(DynamicImage::new_luma8(4, 4));
+        let mut terminal = Terminal::new(backend::TestBackend::new(20, 6)).unwrap();
+        terminal.draw(|f| widget.render(f, f.area()).unwrap()).unwrap();
+        terminal.draw(|f| widget.render(f, f.area()).unwrap()).unwrap();
+        terminal.backend_mut().resize(30, 8);
+        terminal.draw(|f| widget.render(f, f.area()).unwrap()).unwrap();
+        let stats = widget.render_cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 2));
+
+        widget.install(DynamicImage::new_luma8(4, 4));
+        assert!(widget.render_cache.is_empty());
+    }
+
     #[test]
     fn test_frame_rate_setting() {
         let mut widget = ImageWidget::new();
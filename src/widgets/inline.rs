@@ -0,0 +1,76 @@
+//! Inline viewport module
+//!
+//! Small widgets drawn in a few lines below the cursor rather than on a
+//! cleared or alternate screen, for CLIs that print prompts and results as
+//! they go. The last frame a widget draws stays in the normal scrollback.
+
+use std::io::{self, Stdout};
+use std::thread;
+
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::Position,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Terminal, TerminalOptions, Viewport,
+};
+
+use super::spinner::{Spinner, SpinnerStyle};
+
+pub type InlineTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// Terminal drawing into `height` lines starting at the cursor
+pub fn terminal(height: u16) -> io::Result<InlineTerminal> {
+    Terminal::with_options(
+        CrosstermBackend::new(io::stdout()),
+        TerminalOptions {
+            viewport: Viewport::Inline(height),
+        },
+    )
+}
+
+/// Leave the last frame in the scrollback and move the cursor below it
+pub fn finish(mut terminal: InlineTerminal) -> io::Result<()> {
+    let area = terminal.get_frame().area();
+    terminal.set_cursor_position(Position::new(0, area.bottom().saturating_sub(1)))?;
+    terminal.show_cursor()?;
+    println!();
+    Ok(())
+}
+
+/// Blank the viewport and put the cursor at its top, for output that
+/// replaces the widget
+pub fn clear(mut terminal: InlineTerminal) -> io::Result<()> {
+    let area = terminal.get_frame().area();
+    terminal.clear()?;
+    terminal.set_cursor_position(Position::new(area.x, area.y))?;
+    terminal.show_cursor()
+}
+
+/// Run `work` on a worker thread while a one-line spinner animates, then
+/// replace the spinner with a check mark and the message
+pub fn spinner<T: Send>(style: SpinnerStyle, message: &str, work: impl FnOnce() -> T + Send) -> io::Result<T> {
+    let mut terminal = terminal(1)?;
+    let spinner = Spinner::new(style, message);
+
+    let result = thread::scope(|scope| -> io::Result<T> {
+        let worker = scope.spawn(work);
+        while !worker.is_finished() {
+            terminal.draw(|frame| spinner.render(frame, frame.area()))?;
+            thread::sleep(style.interval());
+        }
+        Ok(worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+    })?;
+
+    terminal.draw(|frame| {
+        let line = Line::from(vec![
+            Span::styled("✓", Style::default().fg(Color::Green)),
+            Span::raw(" "),
+            Span::raw(message.to_string()),
+        ]);
+        frame.render_widget(Paragraph::new(line), frame.area());
+    })?;
+    finish(terminal)?;
+    Ok(result)
+}
@@ -7,6 +7,11 @@ use ratatui::{
     layout::Rect,
     Frame,
 };
+use thiserror::Error;
+
+use crate::error::DemoError;
+
+use super::component::Component;
 
 /// Input modes for the application
 #[derive(Debug, Clone, PartialEq)]
@@ -18,31 +23,16 @@ pub enum InputMode {
 }
 
 /// Error types for input operations
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum InputError {
-    Io(io::Error),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Invalid format: {0}")]
     InvalidFormat(String),
+    #[error("Clipboard error: {0}")]
     ClipboardError(String),
 }
 
-impl From<io::Error> for InputError {
-    fn from(err: io::Error) -> Self {
-        InputError::Io(err)
-    }
-}
-
-impl std::fmt::Display for InputError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InputError::Io(err) => write!(f, "IO error: {err}"),
-            InputError::InvalidFormat(msg) => write!(f, "Invalid format: {msg}"),
-            InputError::ClipboardError(msg) => write!(f, "Clipboard error: {msg}"),
-        }
-    }
-}
-
-impl std::error::Error for InputError {}
-
 /// Clipboard manager for handling copy/paste operations
 pub struct ClipboardManager {
     clipboard_content: String,
@@ -136,14 +126,29 @@ impl InputWidget {
     }
 
     pub fn render(&self, frame: &mut Frame, area: Rect) -> IoResult<()> {
+        let border = if self.focused { Color::Cyan } else { Color::Reset };
         let paragraph = Paragraph::new("Input Widget")
-            .block(Block::default().borders(Borders::ALL))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(border)))
             .style(Style::default().fg(Color::White));
         frame.render_widget(paragraph, area);
         Ok(())
     }
 }
 
+impl Component for InputWidget {
+    fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError> {
+        Ok(InputWidget::render(self, frame, area)?)
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.set_focus(focused);
+    }
+}
+
 /// Validate if a string is a valid URL
 pub fn validate_url(url: &str) -> bool {
     if url.is_empty() {
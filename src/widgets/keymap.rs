@@ -0,0 +1,336 @@
+//! Keymap module
+//!
+//! Central key bindings. A `Keymap` built from the user's `KeymapProfile`
+//! (default, vim or emacs) resolves keys, including sequences like `gg`, to
+//! `Action`s. Keys a profile does not bind pass through unchanged, so
+//! profiles coexist with each widget's default keys. Most actions translate
+//! back to a default key with `Action::key`, which lets existing key
+//! handlers consume them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Binding preset selected in the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeymapProfile {
+    /// Arrow keys and readline shortcuts only
+    #[default]
+    Default,
+    /// Modal vim emulation: Normal mode navigates, Insert mode types
+    Vim,
+    /// Emacs control and meta chords, active while typing
+    Emacs,
+}
+
+impl KeymapProfile {
+    pub const ALL: [KeymapProfile; 3] = [KeymapProfile::Default, KeymapProfile::Vim, KeymapProfile::Emacs];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeymapProfile::Default => "default",
+            KeymapProfile::Vim => "vim",
+            KeymapProfile::Emacs => "emacs",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|profile| profile == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// What a bound key asks the focused widget to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Start of the line in text, first row in lists
+    First,
+    /// End of the line in text, last row in lists
+    Last,
+    WordLeft,
+    WordRight,
+    PageUp,
+    PageDown,
+    DeleteChar,
+    /// Delete from the cursor to the end of the line (`C-k`)
+    KillToEnd,
+    /// Delete the line or row into the register (`dd`)
+    DeleteLine,
+    /// Copy the line or row into the register (`yy`)
+    YankLine,
+    /// Insert the register after the cursor (`p`, `C-y`)
+    Paste,
+    InsertMode,
+    NormalMode,
+}
+
+impl Action {
+    /// The default key with the same effect, for handlers that take raw keys
+    pub fn key(&self) -> Option<KeyEvent> {
+        let (code, modifiers) = match self {
+            Action::Up => (KeyCode::Up, KeyModifiers::NONE),
+            Action::Down => (KeyCode::Down, KeyModifiers::NONE),
+            Action::Left => (KeyCode::Left, KeyModifiers::NONE),
+            Action::Right => (KeyCode::Right, KeyModifiers::NONE),
+            Action::First => (KeyCode::Home, KeyModifiers::NONE),
+            Action::Last => (KeyCode::End, KeyModifiers::NONE),
+            Action::WordLeft => (KeyCode::Left, KeyModifiers::CONTROL),
+            Action::WordRight => (KeyCode::Right, KeyModifiers::CONTROL),
+            Action::PageUp => (KeyCode::PageUp, KeyModifiers::NONE),
+            Action::PageDown => (KeyCode::PageDown, KeyModifiers::NONE),
+            Action::DeleteChar => (KeyCode::Delete, KeyModifiers::NONE),
+            Action::KillToEnd => (KeyCode::Char('k'), KeyModifiers::CONTROL),
+            _ => return None,
+        };
+        Some(KeyEvent::new(code, modifiers))
+    }
+}
+
+/// Whether keys type text or run commands in modal profiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMode {
+    Normal,
+    Insert,
+}
+
+/// Result of feeding one key to the keymap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyResult {
+    Action(Action),
+    /// Waiting for the rest of a sequence such as `gg`
+    Pending,
+    /// Not bound by the profile; handle it as usual
+    Unbound(KeyEvent),
+}
+
+/// A key with the modifiers that matter for matching
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct KeyPress {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl From<KeyEvent> for KeyPress {
+    fn from(key: KeyEvent) -> Self {
+        // Shift is already part of an uppercase character
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers.difference(KeyModifiers::SHIFT),
+            _ => key.modifiers,
+        };
+        Self { code: key.code, modifiers }
+    }
+}
+
+struct Binding {
+    /// Mode the binding is active in; `None` for every mode
+    mode: Option<KeyMode>,
+    keys: Vec<KeyPress>,
+    action: Action,
+}
+
+fn ch(c: char) -> KeyPress {
+    KeyPress { code: KeyCode::Char(c), modifiers: KeyModifiers::NONE }
+}
+
+fn ctrl(c: char) -> KeyPress {
+    KeyPress { code: KeyCode::Char(c), modifiers: KeyModifiers::CONTROL }
+}
+
+fn alt(c: char) -> KeyPress {
+    KeyPress { code: KeyCode::Char(c), modifiers: KeyModifiers::ALT }
+}
+
+fn code(code: KeyCode) -> KeyPress {
+    KeyPress { code, modifiers: KeyModifiers::NONE }
+}
+
+fn bindings(profile: KeymapProfile) -> Vec<Binding> {
+    let normal = |keys: Vec<KeyPress>, action| Binding { mode: Some(KeyMode::Normal), keys, action };
+    let any = |key: KeyPress, action| Binding { mode: None, keys: vec![key], action };
+    match profile {
+        KeymapProfile::Default => Vec::new(),
+        KeymapProfile::Vim => vec![
+            normal(vec![ch('h')], Action::Left),
+            normal(vec![ch('j')], Action::Down),
+            normal(vec![ch('k')], Action::Up),
+            normal(vec![ch('l')], Action::Right),
+            normal(vec![ch('g'), ch('g')], Action::First),
+            normal(vec![ch('G')], Action::Last),
+            normal(vec![ch('0')], Action::First),
+            normal(vec![ch('$')], Action::Last),
+            normal(vec![ch('w')], Action::WordRight),
+            normal(vec![ch('b')], Action::WordLeft),
+            normal(vec![ctrl('u')], Action::PageUp),
+            normal(vec![ctrl('d')], Action::PageDown),
+            normal(vec![ch('x')], Action::DeleteChar),
+            normal(vec![ch('d'), ch('d')], Action::DeleteLine),
+            normal(vec![ch('y'), ch('y')], Action::YankLine),
+            normal(vec![ch('p')], Action::Paste),
+            normal(vec![ch('i')], Action::InsertMode),
+            Binding { mode: Some(KeyMode::Insert), keys: vec![code(KeyCode::Esc)], action: Action::NormalMode },
+        ],
+        KeymapProfile::Emacs => vec![
+            any(ctrl('p'), Action::Up),
+            any(ctrl('n'), Action::Down),
+            any(ctrl('b'), Action::Left),
+            any(ctrl('f'), Action::Right),
+            any(ctrl('a'), Action::First),
+            any(ctrl('e'), Action::Last),
+            any(alt('<'), Action::First),
+            any(alt('>'), Action::Last),
+            any(alt('b'), Action::WordLeft),
+            any(alt('f'), Action::WordRight),
+            any(ctrl('d'), Action::DeleteChar),
+            any(ctrl('k'), Action::KillToEnd),
+            any(ctrl('y'), Action::Paste),
+        ],
+    }
+}
+
+pub struct Keymap {
+    pub profile: KeymapProfile,
+    mode: KeyMode,
+    bindings: Vec<Binding>,
+    pending: Vec<KeyPress>,
+    /// Whether the host edits text, enabling Insert mode
+    text: bool,
+}
+
+impl Keymap {
+    /// Keymap for list and table views, which stay in Normal mode
+    pub fn new(profile: KeymapProfile) -> Self {
+        let bindings = bindings(profile)
+            .into_iter()
+            .filter(|binding| !matches!(binding.action, Action::InsertMode | Action::NormalMode))
+            .collect();
+        Self {
+            profile,
+            mode: KeyMode::Normal,
+            bindings,
+            pending: Vec::new(),
+            text: false,
+        }
+    }
+
+    /// Keymap for text inputs, starting in Insert mode so typing works
+    pub fn for_text(profile: KeymapProfile) -> Self {
+        Self {
+            profile,
+            mode: KeyMode::Insert,
+            bindings: bindings(profile),
+            pending: Vec::new(),
+            text: true,
+        }
+    }
+
+    pub fn mode(&self) -> KeyMode {
+        self.mode
+    }
+
+    /// Whether unbound character keys should type text
+    pub fn is_inserting(&self) -> bool {
+        self.mode == KeyMode::Insert
+    }
+
+    /// Mode indicator for modal text profiles, e.g. `-- NORMAL --`
+    pub fn mode_label(&self) -> Option<&'static str> {
+        if !self.text || self.profile != KeymapProfile::Vim {
+            return None;
+        }
+        Some(match self.mode {
+            KeyMode::Normal => "-- NORMAL --",
+            KeyMode::Insert => "-- INSERT --",
+        })
+    }
+
+    pub fn resolve(&mut self, key: KeyEvent) -> KeyResult {
+        self.pending.push(KeyPress::from(key));
+        let mut found = None;
+        let mut prefix = false;
+        for binding in self.bindings.iter().filter(|binding| binding.mode.is_none_or(|mode| mode == self.mode)) {
+            if binding.keys == self.pending {
+                found = Some(binding.action);
+            } else if binding.keys.starts_with(&self.pending) {
+                prefix = true;
+            }
+        }
+
+        if let Some(action) = found {
+            self.pending.clear();
+            match action {
+                Action::InsertMode => self.mode = KeyMode::Insert,
+                Action::NormalMode => self.mode = KeyMode::Normal,
+                _ => {}
+            }
+            return KeyResult::Action(action);
+        }
+        if prefix {
+            return KeyResult::Pending;
+        }
+        // A broken sequence like `gj` drops its start and retries the last key
+        let restart = self.pending.len() > 1;
+        self.pending.clear();
+        if restart {
+            self.resolve(key)
+        } else {
+            KeyResult::Unbound(key)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_vim_sequences_and_modes() {
+        let mut keymap = Keymap::for_text(KeymapProfile::Vim);
+        assert_eq!(keymap.resolve(key('j')), KeyResult::Unbound(key('j')));
+        assert_eq!(
+            keymap.resolve(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
+            KeyResult::Action(Action::NormalMode)
+        );
+        assert_eq!(keymap.resolve(key('j')), KeyResult::Action(Action::Down));
+        assert_eq!(keymap.resolve(key('g')), KeyResult::Pending);
+        assert_eq!(keymap.resolve(key('g')), KeyResult::Action(Action::First));
+        assert_eq!(keymap.resolve(key('d')), KeyResult::Pending);
+        assert_eq!(keymap.resolve(key('k')), KeyResult::Action(Action::Up));
+        assert_eq!(
+            keymap.resolve(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            KeyResult::Action(Action::Last)
+        );
+        let arrow = KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT);
+        assert_eq!(keymap.resolve(arrow), KeyResult::Unbound(arrow));
+
+        let mut list = Keymap::new(KeymapProfile::Vim);
+        assert_eq!(list.resolve(key('i')), KeyResult::Unbound(key('i')));
+        assert_eq!(list.resolve(key('j')), KeyResult::Action(Action::Down));
+        assert_eq!(Keymap::new(KeymapProfile::Default).resolve(key('j')), KeyResult::Unbound(key('j')));
+    }
+
+    #[test]
+    fn test_emacs_profile() {
+        let mut keymap = Keymap::for_text(KeymapProfile::Emacs);
+        let chord = |c, modifiers| KeyEvent::new(KeyCode::Char(c), modifiers);
+        assert_eq!(keymap.resolve(chord('a', KeyModifiers::CONTROL)), KeyResult::Action(Action::First));
+        assert_eq!(keymap.resolve(chord('f', KeyModifiers::ALT)), KeyResult::Action(Action::WordRight));
+        assert_eq!(
+            keymap.resolve(chord('>', KeyModifiers::ALT | KeyModifiers::SHIFT)),
+            KeyResult::Action(Action::Last)
+        );
+        // Typing is never modal
+        assert_eq!(keymap.resolve(key('j')), KeyResult::Unbound(key('j')));
+        assert!(keymap.is_inserting());
+        assert_eq!(keymap.mode_label(), None);
+        assert_eq!(KeymapProfile::Vim.next(), KeymapProfile::Emacs);
+    }
+}
@@ -0,0 +1,133 @@
+//! Line editing module
+//!
+//! Readline-style editing of a single-line `String` with a character cursor,
+//! shared by the input widgets: word motion with Ctrl+←/→ (or Alt+B/F),
+//! Ctrl+Backspace/Ctrl+W and Alt+D to delete words, Ctrl+U and Ctrl+K to
+//! clear to the start or end of the line.
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::selection::byte_index;
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Start of the word before `cursor`, skipping separators first
+pub fn prev_word(text: &str, cursor: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pos = cursor.min(chars.len());
+    while pos > 0 && !is_word_char(chars[pos - 1]) {
+        pos -= 1;
+    }
+    while pos > 0 && is_word_char(chars[pos - 1]) {
+        pos -= 1;
+    }
+    pos
+}
+
+/// End of the word after `cursor`, skipping separators first
+pub fn next_word(text: &str, cursor: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pos = cursor.min(chars.len());
+    while pos < chars.len() && !is_word_char(chars[pos]) {
+        pos += 1;
+    }
+    while pos < chars.len() && is_word_char(chars[pos]) {
+        pos += 1;
+    }
+    pos
+}
+
+/// Remove the characters `from..to` of `text`
+fn remove_chars(text: &mut String, from: usize, to: usize) {
+    let (start, end) = (byte_index(text, from), byte_index(text, to));
+    text.replace_range(start..end, "");
+}
+
+/// Apply an editing key to `text` with `cursor` counted in characters.
+/// Returns whether the key was an editing key.
+pub fn handle_edit_key(text: &mut String, cursor: &mut usize, key: KeyEvent) -> bool {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+    let len = text.chars().count();
+    *cursor = (*cursor).min(len);
+
+    match key.code {
+        KeyCode::Left if ctrl => *cursor = prev_word(text, *cursor),
+        KeyCode::Right if ctrl => *cursor = next_word(text, *cursor),
+        KeyCode::Char('b') if alt => *cursor = prev_word(text, *cursor),
+        KeyCode::Char('f') if alt => *cursor = next_word(text, *cursor),
+        KeyCode::Backspace if ctrl || alt => {
+            let start = prev_word(text, *cursor);
+            remove_chars(text, start, *cursor);
+            *cursor = start;
+        }
+        // Many terminals send Ctrl+Backspace as Ctrl+H
+        KeyCode::Char('w') | KeyCode::Char('h') if ctrl => {
+            let start = prev_word(text, *cursor);
+            remove_chars(text, start, *cursor);
+            *cursor = start;
+        }
+        KeyCode::Char('d') if alt => {
+            let end = next_word(text, *cursor);
+            remove_chars(text, *cursor, end);
+        }
+        KeyCode::Char('u') if ctrl => {
+            remove_chars(text, 0, *cursor);
+            *cursor = 0;
+        }
+        KeyCode::Char('k') if ctrl => remove_chars(text, *cursor, len),
+        KeyCode::Char(_) if ctrl || alt => return false,
+        KeyCode::Char(c) => {
+            text.insert(byte_index(text, *cursor), c);
+            *cursor += 1;
+        }
+        KeyCode::Backspace if *cursor > 0 => {
+            remove_chars(text, *cursor - 1, *cursor);
+            *cursor -= 1;
+        }
+        KeyCode::Delete if *cursor < len => remove_chars(text, *cursor, *cursor + 1),
+        KeyCode::Backspace | KeyCode::Delete => {}
+        KeyCode::Left => *cursor = cursor.saturating_sub(1),
+        KeyCode::Right => *cursor = (*cursor + 1).min(len),
+        KeyCode::Home => *cursor = 0,
+        KeyCode::End => *cursor = len,
+        _ => return false,
+    }
+    true
+}
+
+/// Edit `text` as if the cursor were at its end, for inputs without one
+pub fn edit_at_end(text: &mut String, key: KeyEvent) -> bool {
+    if matches!(key.code, KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End) {
+        return false;
+    }
+    let mut cursor = text.chars().count();
+    handle_edit_key(text, &mut cursor, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(text: &str, cursor: usize, code: KeyCode, modifiers: KeyModifiers) -> (String, usize) {
+        let (mut text, mut cursor) = (text.to_string(), cursor);
+        handle_edit_key(&mut text, &mut cursor, KeyEvent::new(code, modifiers));
+        (text, cursor)
+    }
+
+    #[test]
+    fn test_word_operations() {
+        assert_eq!(prev_word("hello big world", 15), 10);
+        assert_eq!(prev_word("hello big  world", 11), 6);
+        assert_eq!(next_word("hello big world", 5), 9);
+
+        let ctrl = KeyModifiers::CONTROL;
+        assert_eq!(edit("hello big world", 9, KeyCode::Backspace, ctrl), ("hello  world".to_string(), 6));
+        assert_eq!(edit("hello big world", 5, KeyCode::Char('d'), KeyModifiers::ALT), ("hello world".to_string(), 5));
+        assert_eq!(edit("héllo world", 5, KeyCode::Char('u'), ctrl), (" world".to_string(), 0));
+        assert_eq!(edit("héllo world", 5, KeyCode::Char('k'), ctrl), ("héllo".to_string(), 5));
+        assert_eq!(edit("héllo", 2, KeyCode::Char('x'), KeyModifiers::NONE), ("héxllo".to_string(), 3));
+    }
+}
@@ -0,0 +1,179 @@
+//! Link opening module
+//!
+//! One place for turning URLs and paths into something the user can open:
+//! validation, OSC 8 terminal hyperlinks where the terminal understands them,
+//! and the platform launcher (`xdg-open`, `open` or the Windows URL handler)
+//! otherwise. Launchers run in the background; `Launches` collects their
+//! failures so the UI can show them instead of losing them.
+
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+use url::Url;
+
+use super::console;
+use crate::platform;
+
+/// URL schemes that may be handed to the platform launcher
+const ALLOWED_SCHEMES: &[&str] = &["http", "https", "mailto", "file"];
+
+/// Parse `input` as a URL with an allowed scheme
+pub fn validate_url(input: &str) -> Result<Url, String> {
+    let url = Url::parse(input.trim()).map_err(|e| format!("invalid URL {}: {}", input, e))?;
+    if !ALLOWED_SCHEMES.contains(&url.scheme()) {
+        return Err(format!("refusing to open {} links", url.scheme()));
+    }
+    if matches!(url.scheme(), "http" | "https") && url.host_str().is_none_or(str::is_empty) {
+        return Err(format!("invalid URL {}: missing host", input));
+    }
+    Ok(url)
+}
+
+/// Wrap `text` in an OSC 8 escape sequence pointing at `url`
+pub fn hyperlink(text: &str, url: &str) -> String {
+    format!("\x1B]8;;{}\x1B\\{}\x1B]8;;\x1B\\", url, text)
+}
+
+/// Best-effort check for a terminal that renders OSC 8 hyperlinks
+pub fn supports_hyperlinks() -> bool {
+    let var = |name: &str| std::env::var(name).unwrap_or_default();
+    if !var("FORCE_HYPERLINK").is_empty() {
+        return var("FORCE_HYPERLINK") != "0";
+    }
+    if !console::host().hyperlinks_safe() || console::session().dumb_terminal {
+        return false;
+    }
+    matches!(var("TERM_PROGRAM").as_str(), "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper")
+        || !var("WT_SESSION").is_empty()
+        || !var("KITTY_WINDOW_ID").is_empty()
+        || var("VTE_VERSION").parse::<u32>().is_ok_and(|version| version >= 5000)
+        || ["kitty", "alacritty", "foot", "wezterm"].iter().any(|term| var("TERM").contains(term))
+}
+
+/// `text` as a clickable hyperlink when supported, plain text otherwise
+pub fn link_text(text: &str, url: &str) -> String {
+    if supports_hyperlinks() {
+        hyperlink(text, url)
+    } else {
+        text.to_string()
+    }
+}
+
+/// A platform launcher running in the background
+pub struct Launch {
+    target: String,
+    result: Receiver<Result<(), String>>,
+}
+
+impl Launch {
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// The launcher's outcome once it has exited, `None` while it runs
+    pub fn poll(&self) -> Option<Result<(), String>> {
+        match self.result.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(format!("launcher for {} stopped", self.target))),
+        }
+    }
+
+    /// Block until the launcher exits, for command-line tools
+    pub fn wait(self) -> Result<(), String> {
+        self.result
+            .recv()
+            .unwrap_or_else(|_| Err(format!("launcher for {} stopped", self.target)))
+    }
+}
+
+/// Validate `url` and open it with the platform launcher
+pub fn open_url(url: &str) -> Result<Launch, String> {
+    let url = validate_url(url)?;
+    check_desktop(url.as_str())?;
+    Ok(launch(url.as_str()))
+}
+
+/// Open a local file or directory with the platform launcher
+pub fn open_path(path: &Path) -> Result<Launch, String> {
+    if !path.exists() {
+        return Err(format!("{} does not exist", path.display()));
+    }
+    check_desktop(&path.to_string_lossy())?;
+    Ok(launch(&path.to_string_lossy()))
+}
+
+/// Over SSH or without a display the launcher would open `target` on the
+/// wrong machine or not at all, so refuse before trying
+fn check_desktop(target: &str) -> Result<(), String> {
+    if console::session().can_launch() {
+        Ok(())
+    } else {
+        Err(format!("no desktop to open {} on (SSH or no display)", target))
+    }
+}
+
+/// Launchers started from one screen, polled each tick for failures
+#[derive(Default)]
+pub struct Launches {
+    pending: Vec<Launch>,
+}
+
+impl Launches {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open_url(&mut self, url: &str) -> Result<(), String> {
+        self.pending.push(open_url(url)?);
+        Ok(())
+    }
+
+    pub fn open_path(&mut self, path: &Path) -> Result<(), String> {
+        self.pending.push(open_path(path)?);
+        Ok(())
+    }
+
+    /// Errors of the launchers that exited since the last call
+    pub fn failures(&mut self) -> Vec<String> {
+        let mut failures = Vec::new();
+        self.pending.retain(|launch| match launch.poll() {
+            None => true,
+            Some(Ok(())) => false,
+            Some(Err(e)) => {
+                failures.push(e);
+                false
+            }
+        });
+        failures
+    }
+}
+
+/// Open `target` through the platform, collecting how the launcher exited
+fn launch(target: &str) -> Launch {
+    let (sender, result) = mpsc::channel();
+    platform::current().open(
+        target,
+        Box::new(move |outcome| {
+            let _ = sender.send(outcome);
+        }),
+    );
+    Launch {
+        target: target.to_string(),
+        result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_url() {
+        assert!(validate_url("https://www.rust-lang.org").is_ok());
+        assert!(validate_url("mailto:someone@example.com").is_ok());
+        assert!(validate_url("javascript:alert(1)").is_err());
+        assert!(validate_url("not a url").is_err());
+        assert!(validate_url("http://").is_err());
+    }
+}
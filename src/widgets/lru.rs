@@ -0,0 +1,249 @@
+//! LRU cache module
+//!
+//! Least-recently-used cache bounded by the size of its values in bytes
+//! rather than by an entry count, so a few large images cannot hold on to
+//! far more memory than a screenful of small ones. Each cache counts hits,
+//! misses and evictions under its name; the F12 profiler shows the totals.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::{Mutex, RwLock};
+
+#[cfg(feature = "image")]
+use image::DynamicImage;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+/// Approximate heap size of a cached value
+pub trait ByteSize {
+    fn byte_size(&self) -> usize;
+}
+
+#[cfg(feature = "image")]
+impl ByteSize for DynamicImage {
+    fn byte_size(&self) -> usize {
+        self.as_bytes().len()
+    }
+}
+
+impl ByteSize for Vec<String> {
+    fn byte_size(&self) -> usize {
+        self.iter().map(|line| line.len() + std::mem::size_of::<String>()).sum()
+    }
+}
+
+/// Byte limits for the shared caches, stored in the app config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Image frames converted to text, per image widget
+    pub image_render_bytes: usize,
+    /// Decoded images shown in the file preview
+    pub thumbnail_bytes: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            image_render_bytes: 4 * 1024 * 1024,
+            thumbnail_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub entries: usize,
+    pub bytes: usize,
+}
+
+impl CacheStats {
+    /// Share of lookups that were hits, from 0.0 to 1.0
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+static CONFIG: Lazy<RwLock<CacheConfig>> = Lazy::new(|| RwLock::new(CacheConfig::default()));
+
+/// Totals per cache name, across every live cache with that name
+static STATS: Lazy<Mutex<BTreeMap<&'static str, CacheStats>>> = Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// Set the limits used by caches created from now on; called by
+/// `ConfigHandle` whenever the config changes
+pub fn configure(config: &CacheConfig) {
+    if let Ok(mut current) = CONFIG.write() {
+        *current = *config;
+    }
+}
+
+pub fn config() -> CacheConfig {
+    CONFIG.read().map(|config| *config).unwrap_or_default()
+}
+
+/// Totals for every named cache, sorted by name
+pub fn stats() -> Vec<(&'static str, CacheStats)> {
+    STATS
+        .lock()
+        .map(|stats| stats.iter().map(|(name, stats)| (*name, *stats)).collect())
+        .unwrap_or_default()
+}
+
+fn record(name: &'static str, update: impl FnOnce(&mut CacheStats)) {
+    if let Ok(mut stats) = STATS.lock() {
+        update(stats.entry(name).or_default());
+    }
+}
+
+#[derive(Debug)]
+pub struct LruCache<K, V> {
+    name: &'static str,
+    limit: usize,
+    /// Values with the tick they were last used at
+    entries: HashMap<K, (V, u64)>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+impl<K: Eq + Hash + Clone, V: ByteSize> LruCache<K, V> {
+    /// Empty cache holding at most `limit` bytes of values
+    pub fn new(name: &'static str, limit: usize) -> Self {
+        Self {
+            name,
+            limit,
+            entries: HashMap::new(),
+            tick: 0,
+            stats: CacheStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Cached value for `key`, marking it as most recently used
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        self.tick += 1;
+        let hit = match self.entries.get_mut(key) {
+            Some((_, used)) => {
+                *used = self.tick;
+                true
+            }
+            None => false,
+        };
+        self.update(|stats| {
+            if hit {
+                stats.hits += 1;
+            } else {
+                stats.misses += 1;
+            }
+        });
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    /// Cache `value`, evicting the least recently used values until the
+    /// cache fits its limit. A value larger than the whole limit is not kept.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.remove(&key);
+        let size = value.byte_size();
+        if size > self.limit {
+            return;
+        }
+        while self.stats.bytes + size > self.limit {
+            let Some(oldest) = self.entries.iter().min_by_key(|(_, (_, used))| *used).map(|(key, _)| key.clone()) else {
+                break;
+            };
+            self.remove(&oldest);
+            self.update(|stats| stats.evictions += 1);
+        }
+        self.tick += 1;
+        self.entries.insert(key, (value, self.tick));
+        self.update(|stats| {
+            stats.entries += 1;
+            stats.bytes += size;
+        });
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, _) = self.entries.remove(key)?;
+        let size = value.byte_size();
+        self.update(|stats| {
+            stats.entries -= 1;
+            stats.bytes -= size;
+        });
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        let (entries, bytes) = (self.stats.entries, self.stats.bytes);
+        self.entries.clear();
+        self.update(|stats| {
+            stats.entries -= entries;
+            stats.bytes -= bytes;
+        });
+    }
+
+    /// Apply the same change to this cache's stats and the named totals
+    fn update(&mut self, change: impl Fn(&mut CacheStats)) {
+        change(&mut self.stats);
+        record(self.name, change);
+    }
+}
+
+impl<K, V> Drop for LruCache<K, V> {
+    fn drop(&mut self) {
+        let (entries, bytes) = (self.stats.entries, self.stats.bytes);
+        record(self.name, |stats| {
+            stats.entries = stats.entries.saturating_sub(entries);
+            stats.bytes = stats.bytes.saturating_sub(bytes);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        vec![text.to_string()]
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used_by_bytes() {
+        let entry = lines("aaaa").byte_size();
+        let mut cache = LruCache::new("test", entry * 2);
+        cache.insert(1, lines("aaaa"));
+        cache.insert(2, lines("bbbb"));
+        assert_eq!(cache.get(&1), Some(&lines("aaaa")));
+        cache.insert(3, lines("cccc"));
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some(&lines("aaaa")));
+        assert_eq!(cache.get(&3), Some(&lines("cccc")));
+
+        cache.insert(4, vec!["too large".repeat(10)]);
+        assert_eq!(cache.len(), 2);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (3, 1, 1));
+        assert_eq!(stats.bytes, entry * 2);
+        assert_eq!(stats.hit_rate(), 0.75);
+        cache.clear();
+        assert_eq!(cache.stats().bytes, 0);
+    }
+}
@@ -1,7 +1,97 @@
+pub mod alert;
+pub mod ansi;
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod autopilot;
+pub mod bus;
+pub mod calendar;
+pub mod campaign;
+#[cfg(feature = "image")]
+pub mod chart_image;
+pub mod char_count;
 pub mod clipboard;
+pub mod component;
+pub mod confetti;
+pub mod console;
+pub mod dispatch;
+pub mod draft;
+pub mod elm;
+pub mod emoji;
+#[cfg(feature = "image")]
+pub mod exif;
+pub mod export;
+#[cfg(feature = "http")]
+pub mod feeds;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file_editor;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod file_ops;
+pub mod file_preview;
+pub mod find_replace;
+pub mod finder;
+pub mod form;
+pub mod games;
+pub mod gauges;
+pub mod hex_viewer;
+pub mod history;
+pub mod humanize;
+#[cfg(feature = "image")]
 pub mod image;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod inline;
 pub mod input;
+pub mod keymap;
+pub mod line_edit;
+pub mod links;
+pub mod lru;
+pub mod notifications;
+pub mod path_input;
+#[cfg(feature = "pdf")]
+pub mod pdf;
+pub mod pivot;
+pub mod playback;
+pub mod pomodoro;
+pub mod profiler;
+pub mod progress;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod prompt;
+pub mod qr;
+pub mod recents;
+pub mod redact;
+pub mod reflow;
+pub mod report;
+pub mod rich_text;
+pub mod runner;
+pub mod scatter;
+pub mod schema;
+pub mod screen_search;
+pub mod search;
+pub mod segment;
+pub mod selection;
+pub mod snake;
+pub mod spinner;
+pub mod state_machine;
+pub mod symbols;
 pub mod table;
+#[cfg(feature = "terminal")]
+pub mod terminal;
+pub mod tetris;
+pub mod text_area;
+pub mod text_viewer;
+pub mod ticker;
+pub mod timeline;
+pub mod timer;
+pub mod toast;
+pub mod tour;
+pub mod truncate;
+#[cfg(feature = "image")]
+pub mod video_thumbs;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watcher;
+#[cfg(feature = "audio")]
+pub mod waveform;
+pub mod weather;
+pub mod world_clock;
 
 // Re-export for easier access
-pub use table::{InteractiveTable, TableData, SelectComponent, SelectOption, SelectType, create_demo_table};
+pub use table::{InteractiveTable, TableData, CellRenderer, ColumnSpec, SelectComponent, SelectOption, SelectType, create_demo_table};
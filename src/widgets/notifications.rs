@@ -0,0 +1,249 @@
+//! Notification center module
+//!
+//! History of past toasts with timestamps, severity and read state, shown in
+//! a side panel and optionally persisted to JSON across sessions.
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::event::KeyCode;
+
+use super::humanize::time_ago;
+use super::redact::redact;
+use super::toast::ToastLevel;
+
+/// Oldest notifications are dropped beyond this count
+const MAX_NOTIFICATIONS: usize = 100;
+
+/// Width of the notification panel in cells
+const PANEL_WIDTH: u16 = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub message: String,
+    pub level: ToastLevel,
+    pub time: DateTime<Local>,
+    pub read: bool,
+}
+
+#[derive(Default)]
+pub struct NotificationCenter {
+    pub entries: Vec<Notification>,
+    pub visible: bool,
+    state: ListState,
+    path: Option<PathBuf>,
+}
+
+impl NotificationCenter {
+    /// In-memory center that is not saved between sessions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Center backed by the shared notifications file in the data directory
+    pub fn persistent() -> Self {
+        let path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("notifications.json");
+            path
+        });
+        let entries = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            entries,
+            path,
+            ..Self::default()
+        }
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                let content = serde_json::to_string_pretty(&self.entries)?;
+                fs::write(path, content)
+            });
+        if let Err(e) = result {
+            log::error!("Failed to save notifications: {}", e);
+        }
+    }
+
+    pub fn record(&mut self, level: ToastLevel, message: &str) {
+        self.entries.push(Notification {
+            message: redact(message),
+            level,
+            time: Local::now(),
+            read: false,
+        });
+        if self.entries.len() > MAX_NOTIFICATIONS {
+            let excess = self.entries.len() - MAX_NOTIFICATIONS;
+            self.entries.drain(..excess);
+        }
+        self.save();
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.entries.iter().filter(|entry| !entry.read).count()
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+        if self.visible && self.state.selected().is_none() && !self.entries.is_empty() {
+            self.state.select(Some(0));
+        }
+    }
+
+    /// Index into `entries` of the selected row; the list shows newest first
+    fn selected_entry(&self) -> Option<usize> {
+        let row = self.state.selected()?;
+        (row < self.entries.len()).then(|| self.entries.len() - 1 - row)
+    }
+
+    pub fn mark_selected_read(&mut self) {
+        if let Some(index) = self.selected_entry() {
+            self.entries[index].read = true;
+            self.save();
+        }
+    }
+
+    pub fn mark_all_read(&mut self) {
+        self.entries.iter_mut().for_each(|entry| entry.read = true);
+        self.save();
+    }
+
+    pub fn clear_all(&mut self) {
+        self.entries.clear();
+        self.state.select(None);
+        self.save();
+    }
+
+    fn select_offset(&mut self, offset: isize) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let len = self.entries.len() as isize;
+        let current = self.state.selected().unwrap_or(0) as isize;
+        self.state.select(Some((current + offset).rem_euclid(len) as usize));
+    }
+
+    /// Handle keys while the panel is open; returns false when it is closed
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        if !self.visible {
+            return false;
+        }
+        match key {
+            KeyCode::Esc | KeyCode::Char('N') => self.visible = false,
+            KeyCode::Up => self.select_offset(-1),
+            KeyCode::Down => self.select_offset(1),
+            KeyCode::Enter | KeyCode::Char('m') => self.mark_selected_read(),
+            KeyCode::Char('a') => self.mark_all_read(),
+            KeyCode::Char('c') => self.clear_all(),
+            _ => {}
+        }
+        true
+    }
+
+    /// Render the panel along the right edge of `area`
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+        let width = PANEL_WIDTH.min(area.width);
+        let panel = Rect {
+            x: area.x + area.width - width,
+            width,
+            ..area
+        };
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .rev()
+            .map(|entry| {
+                let color = entry.level.color();
+                let text_style = if entry.read {
+                    Style::default().fg(Color::DarkGray)
+                } else {
+                    Style::default().add_modifier(Modifier::BOLD)
+                };
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(format!("{} ", entry.level.icon()), Style::default().fg(color)),
+                        Span::styled(
+                            format!("{} · {}", time_ago(&entry.time), entry.time.format("%Y-%m-%d %H:%M:%S")),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::raw(if entry.read { "" } else { "  ●" }),
+                    ]),
+                    Line::from(Span::styled(format!("  {}", entry.message), text_style)),
+                ])
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("🔔 Notifications ({} unread)", self.unread_count()))
+            .title_bottom("m: read | a: all read | c: clear | Esc: close")
+            .border_style(Style::default().fg(Color::Cyan));
+
+        frame.render_widget(Clear, panel);
+        if items.is_empty() {
+            let empty = Paragraph::new("No notifications").style(Style::default().fg(Color::DarkGray)).block(block);
+            frame.render_widget(empty, panel);
+        } else {
+            let list = List::new(items)
+                .block(block)
+                .highlight_style(Style::default().bg(Color::DarkGray));
+            frame.render_stateful_widget(list, panel, &mut self.state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_read_and_clear() {
+        let mut center = NotificationCenter::new();
+        center.record(ToastLevel::Info, "first");
+        center.record(ToastLevel::Error, "second");
+        assert_eq!(center.unread_count(), 2);
+
+        center.toggle();
+        center.mark_selected_read();
+        // Newest notification is listed first
+        assert!(center.entries[1].read);
+        assert_eq!(center.unread_count(), 1);
+
+        center.clear_all();
+        assert!(center.entries.is_empty());
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut center = NotificationCenter::new();
+        for i in 0..MAX_NOTIFICATIONS + 5 {
+            center.record(ToastLevel::Info, &i.to_string());
+        }
+        assert_eq!(center.entries.len(), MAX_NOTIFICATIONS);
+        assert_eq!(center.entries[0].message, "5");
+    }
+}
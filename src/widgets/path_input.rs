@@ -0,0 +1,286 @@
+//! Path input module
+//!
+//! Single-line path prompt with shell-style Tab completion of directory
+//! names, plus a persisted list of recently visited locations.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::line_edit::edit_at_end;
+
+/// Oldest recent locations are dropped beyond this count
+const MAX_RECENT: usize = 15;
+
+/// Completion candidates shown under the input
+const MAX_SHOWN_CANDIDATES: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathInputAction {
+    None,
+    Submit(PathBuf),
+    Cancel,
+}
+
+pub struct PathInput {
+    pub title: String,
+    pub value: String,
+    pub candidates: Vec<String>,
+    /// Index of the candidate shown while cycling with repeated Tab
+    cycle: Option<usize>,
+}
+
+impl PathInput {
+    /// Prompt pre-filled with `start` and a trailing separator
+    pub fn new(start: &Path) -> Self {
+        let mut value = start.display().to_string();
+        if !value.ends_with(std::path::MAIN_SEPARATOR) {
+            value.push(std::path::MAIN_SEPARATOR);
+        }
+        Self {
+            title: "Go to path".to_string(),
+            value,
+            candidates: Vec::new(),
+            cycle: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Complete the last path component, cycling through matches on repeat
+    pub fn complete(&mut self) {
+        if let Some(index) = self.cycle {
+            let next = (index + 1) % self.candidates.len();
+            self.value = self.candidates[next].clone();
+            self.cycle = Some(next);
+            return;
+        }
+
+        self.candidates = complete_dir(&self.value);
+        match self.candidates.len() {
+            0 => {}
+            1 => {
+                self.value = self.candidates[0].clone();
+                self.candidates.clear();
+            }
+            n => {
+                let prefix = common_prefix(&self.candidates);
+                if prefix.len() > self.value.len() {
+                    self.value = prefix;
+                } else {
+                    // Nothing more to extend; the next Tab starts cycling
+                    self.cycle = Some(n - 1);
+                }
+            }
+        }
+    }
+
+    fn reset_completion(&mut self) {
+        self.candidates.clear();
+        self.cycle = None;
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> PathInputAction {
+        self.handle_key_event(KeyEvent::new(key, KeyModifiers::NONE))
+    }
+
+    /// Like `handle_key`, with modifiers for the word editing shortcuts
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> PathInputAction {
+        match key.code {
+            KeyCode::Tab => {
+                self.complete();
+                return PathInputAction::None;
+            }
+            KeyCode::Enter => return PathInputAction::Submit(expand_tilde(self.value.trim())),
+            KeyCode::Esc => return PathInputAction::Cancel,
+            _ if edit_at_end(&mut self.value, key) => {}
+            _ => return PathInputAction::None,
+        }
+        self.reset_completion();
+        PathInputAction::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let shown = self.candidates.len().min(MAX_SHOWN_CANDIDATES) as u16;
+        let width = 70.min(area.width);
+        let height = (3 + shown).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + area.height / 4,
+            width,
+            height,
+        };
+
+        let mut lines = vec![Line::from(vec![
+            Span::raw(self.value.clone()),
+            Span::styled("_", Style::default().fg(Color::Yellow)),
+        ])];
+        lines.extend(self.candidates.iter().take(MAX_SHOWN_CANDIDATES).enumerate().map(|(i, candidate)| {
+            let style = if Some(i) == self.cycle {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            Line::styled(format!("  {}", candidate), style)
+        }));
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title))
+            .title_bottom(" Tab: complete | Enter: go | Esc: cancel ")
+            .border_style(Style::default().fg(Color::Cyan));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+}
+
+/// Expand a leading `~` to the home directory
+pub fn expand_tilde(input: &str) -> PathBuf {
+    if let Some(rest) = input.strip_prefix('~') {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(rest.trim_start_matches(['/', '\\']));
+        }
+    }
+    PathBuf::from(input)
+}
+
+/// Directories whose path starts with `input`, each with a trailing separator.
+/// Hidden directories are only offered when the typed name starts with a dot.
+pub fn complete_dir(input: &str) -> Vec<String> {
+    let split = input.rfind(['/', std::path::MAIN_SEPARATOR]).map_or(0, |i| i + 1);
+    let (dir_part, prefix) = input.split_at(split);
+    let dir = if dir_part.is_empty() { PathBuf::from(".") } else { expand_tilde(dir_part) };
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut matches: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter(|name| name.starts_with(prefix) && (prefix.starts_with('.') || !name.starts_with('.')))
+        .map(|name| format!("{}{}{}", dir_part, name, std::path::MAIN_SEPARATOR))
+        .collect();
+    matches.sort();
+    matches
+}
+
+fn common_prefix(values: &[String]) -> String {
+    let Some(first) = values.first() else {
+        return String::new();
+    };
+    let mut end = first.len();
+    for value in &values[1..] {
+        end = first
+            .char_indices()
+            .zip(value.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((i, a), _)| i + a.len_utf8())
+            .min(end);
+    }
+    first[..end].to_string()
+}
+
+/// Most-recently-visited directories, newest first
+#[derive(Default)]
+pub struct RecentLocations {
+    pub paths: Vec<PathBuf>,
+    path: Option<PathBuf>,
+}
+
+impl RecentLocations {
+    /// In-memory list that is not saved between sessions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// List backed by the shared recent-locations file in the data directory
+    pub fn persistent() -> Self {
+        let path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("recent_locations.json");
+            path
+        });
+        let paths = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self { paths, path }
+    }
+
+    /// Move `dir` to the front of the list
+    pub fn push(&mut self, dir: &Path) {
+        self.paths.retain(|existing| existing != dir);
+        self.paths.insert(0, dir.to_path_buf());
+        self.paths.truncate(MAX_RECENT);
+        self.save();
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| {
+                let content = serde_json::to_string_pretty(&self.paths)?;
+                fs::write(path, content)
+            });
+        if let Err(e) = result {
+            log::error!("Failed to save recent locations: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_dir_and_common_prefix() {
+        let root = std::env::temp_dir().join(format!("path_input_test_{}", std::process::id()));
+        for dir in ["alpha", "alps", "beta", ".alpine"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("alpaca.txt"), "").unwrap();
+
+        let input = format!("{}{}al", root.display(), std::path::MAIN_SEPARATOR);
+        let matches = complete_dir(&input);
+        assert_eq!(matches.len(), 2, "files and hidden directories are skipped");
+        assert!(matches[0].ends_with(&format!("alpha{}", std::path::MAIN_SEPARATOR)));
+
+        let mut prompt = PathInput::new(Path::new(""));
+        prompt.value = input.clone();
+        prompt.complete();
+        assert_eq!(prompt.value, format!("{}p", input));
+        prompt.complete();
+        prompt.complete();
+        assert_eq!(prompt.value, matches[0]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_recent_locations_dedupe() {
+        let mut recent = RecentLocations::new();
+        recent.push(Path::new("/a"));
+        recent.push(Path::new("/b"));
+        recent.push(Path::new("/a"));
+        assert_eq!(recent.paths, vec![PathBuf::from("/a"), PathBuf::from("/b")]);
+    }
+}
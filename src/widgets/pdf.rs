@@ -0,0 +1,47 @@
+//! PDF module
+//!
+//! Renders the first page of a PDF to an image with PDFium, for the image
+//! widget to show like any other picture. PDFium is loaded at runtime from
+//! the system library path or the working directory; without it the
+//! preview shows why instead of the page.
+
+use std::path::Path;
+
+use image::{DynamicImage, RgbaImage};
+use pdfium_render::prelude::*;
+
+/// Width the page is rendered at; the image widget scales it to fit
+const RENDER_WIDTH: Pixels = 800;
+
+pub fn is_pdf(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"))
+}
+
+/// The first page of the PDF at `path`; a `Decoder` for the image widget
+pub fn render_first_page(path: &Path) -> Result<DynamicImage, String> {
+    let bindings = Pdfium::bind_to_system_library()
+        .or_else(|_| Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path("./")))
+        .map_err(|e| format!("PDFium library not found: {}", e))?;
+    let pdfium = Pdfium::new(bindings);
+    let document = pdfium.load_pdf_from_file(path, None).map_err(|e| e.to_string())?;
+    let page = document.pages().first().map_err(|_| "The PDF has no pages".to_string())?;
+    let bitmap = page
+        .render_with_config(&PdfRenderConfig::new().set_target_width(RENDER_WIDTH))
+        .map_err(|e| e.to_string())?;
+    let (width, height) = (bitmap.width() as u32, bitmap.height() as u32);
+    RgbaImage::from_raw(width, height, bitmap.as_rgba_bytes())
+        .map(DynamicImage::ImageRgba8)
+        .ok_or_else(|| "PDFium returned a short bitmap".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_pdf_is_an_error() {
+        assert!(is_pdf(Path::new("Manual.PDF")));
+        assert!(!is_pdf(Path::new("notes.txt")));
+        assert!(render_first_page(Path::new("does-not-exist.pdf")).is_err());
+    }
+}
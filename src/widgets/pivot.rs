@@ -0,0 +1,225 @@
+//! Pivot view module
+//!
+//! Summary of `TableData` grouped by one column with another column
+//! aggregated as a count, sum or average. The result is drawn as a small
+//! table next to a bar chart.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Cell, Row, Table},
+    Frame,
+};
+
+use super::table::TableData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Count,
+    Sum,
+    Avg,
+}
+
+impl Aggregate {
+    pub fn name(self) -> &'static str {
+        match self {
+            Aggregate::Count => "count",
+            Aggregate::Sum => "sum",
+            Aggregate::Avg => "avg",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Aggregate::Count => Aggregate::Sum,
+            Aggregate::Sum => Aggregate::Avg,
+            Aggregate::Avg => Aggregate::Count,
+        }
+    }
+}
+
+/// One group of the pivot with its aggregated value
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotRow {
+    pub group: String,
+    pub value: f64,
+    /// Rows in the group, including ones without a numeric value
+    pub rows: usize,
+}
+
+/// Group `data` by `group_column` and aggregate `value_column`, in order of
+/// first appearance. Non-numeric values are skipped by sum and average.
+pub fn pivot(data: &TableData, group_column: usize, value_column: usize, aggregate: Aggregate) -> Vec<PivotRow> {
+    // (group, sum, numeric count, row count)
+    let mut groups: Vec<(String, f64, usize, usize)> = Vec::new();
+    for row in &data.rows {
+        let group = row.get(group_column).cloned().unwrap_or_default();
+        let number = row.get(value_column).and_then(|value| value.trim().parse::<f64>().ok());
+        let index = match groups.iter().position(|(existing, ..)| *existing == group) {
+            Some(index) => index,
+            None => {
+                groups.push((group, 0.0, 0, 0));
+                groups.len() - 1
+            }
+        };
+        let entry = &mut groups[index];
+        entry.3 += 1;
+        if let Some(number) = number {
+            entry.1 += number;
+            entry.2 += 1;
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(group, sum, numeric, rows)| PivotRow {
+            group,
+            value: match aggregate {
+                Aggregate::Count => rows as f64,
+                Aggregate::Sum => sum,
+                Aggregate::Avg if numeric > 0 => sum / numeric as f64,
+                Aggregate::Avg => 0.0,
+            },
+            rows,
+        })
+        .collect()
+}
+
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+/// Pivot settings for a table; `g`, `a` and `f` cycle them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PivotView {
+    pub group_column: usize,
+    pub value_column: usize,
+    pub aggregate: Aggregate,
+}
+
+impl PivotView {
+    pub fn new(group_column: usize, value_column: usize) -> Self {
+        Self {
+            group_column,
+            value_column,
+            aggregate: Aggregate::Count,
+        }
+    }
+
+    /// `g` next group column, `a` next aggregated column, `f` next function
+    pub fn handle_key(&mut self, c: char, columns: usize) -> bool {
+        let columns = columns.max(1);
+        match c {
+            'g' => self.group_column = (self.group_column + 1) % columns,
+            'a' => self.value_column = (self.value_column + 1) % columns,
+            'f' => self.aggregate = self.aggregate.next(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Heading such as `avg(Age) by Status`
+    pub fn title(&self, data: &TableData) -> String {
+        let header = |i: usize| data.headers.get(i).map_or("?", String::as_str);
+        match self.aggregate {
+            Aggregate::Count => format!("count by {}", header(self.group_column)),
+            aggregate => format!("{}({}) by {}", aggregate.name(), header(self.value_column), header(self.group_column)),
+        }
+    }
+
+    /// The pivot as table data, e.g. for export
+    pub fn to_table_data(&self, data: &TableData) -> TableData {
+        let rows = pivot(data, self.group_column, self.value_column, self.aggregate);
+        TableData {
+            headers: vec![
+                data.headers.get(self.group_column).cloned().unwrap_or_default(),
+                self.title(data),
+                "Rows".to_string(),
+            ],
+            rows: rows
+                .into_iter()
+                .map(|row| vec![row.group, format_value(row.value), row.rows.to_string()])
+                .collect(),
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, data: &TableData) {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(area);
+        let title = self.title(data);
+        let rows = pivot(data, self.group_column, self.value_column, self.aggregate);
+
+        let header_style = Style::default().bg(Color::Blue).fg(Color::White);
+        let table_rows: Vec<Row> = rows
+            .iter()
+            .map(|row| {
+                Row::new(vec![
+                    Cell::from(row.group.clone()),
+                    Cell::from(format_value(row.value)),
+                    Cell::from(row.rows.to_string()),
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            table_rows,
+            [Constraint::Percentage(45), Constraint::Percentage(35), Constraint::Percentage(20)],
+        )
+        .header(
+            Row::new(vec![
+                data.headers.get(self.group_column).cloned().unwrap_or_default(),
+                self.aggregate.name().to_string(),
+                "Rows".to_string(),
+            ])
+            .style(header_style),
+        )
+        .block(Block::default().borders(Borders::ALL).title(format!("Pivot: {}", title)));
+        frame.render_widget(table, chunks[0]);
+
+        let bars: Vec<Bar> = rows
+            .iter()
+            .map(|row| {
+                Bar::default()
+                    .label(Line::from(row.group.clone()))
+                    .value(row.value.max(0.0).round() as u64)
+                    .text_value(format_value(row.value))
+            })
+            .collect();
+        let chart = BarChart::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(7)
+            .bar_gap(2)
+            .bar_style(Style::default().fg(Color::Cyan))
+            .value_style(Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD));
+        frame.render_widget(chart, chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::table::create_demo_table;
+
+    #[test]
+    fn test_pivot_aggregates() {
+        let data = create_demo_table().data;
+        let count = pivot(&data, 3, 1, Aggregate::Count);
+        assert_eq!(count[0], PivotRow { group: "Active".to_string(), value: 4.0, rows: 4 });
+        assert_eq!(count[1].value, 2.0);
+
+        let avg = pivot(&data, 3, 1, Aggregate::Avg);
+        assert_eq!(avg[1].value, 32.0);
+        assert_eq!(pivot(&data, 3, 1, Aggregate::Sum)[0].value, 25.0 + 30.0 + 28.0 + 32.0);
+
+        let view = PivotView { aggregate: Aggregate::Avg, ..PivotView::new(3, 1) };
+        let summary = view.to_table_data(&data);
+        assert_eq!(summary.headers[1], "avg(Age) by Status");
+        assert_eq!(summary.rows[0], vec!["Active", "28.75", "4"]);
+    }
+}
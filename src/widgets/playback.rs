@@ -0,0 +1,309 @@
+//! Playback clock module
+//!
+//! Position and length of the media in a player, advanced with wall-clock
+//! time while playing. Seeking has a preview step: the arrow keys move a
+//! seek target, and hovering the seek bar points at one, so the player can
+//! show what is there before jumping. A target from the keys is committed
+//! once they have been quiet for `SEEK_COMMIT`, or at once with Enter.
+//!
+//! Playback can run at a speed from `SPEEDS`, wrap around at the end of
+//! the media, or repeat a range marked with A and B.
+
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    style::{Color, Style},
+    symbols,
+    widgets::LineGauge,
+};
+
+use super::humanize::format_clock;
+
+/// How far one arrow key press moves the seek target
+pub const SEEK_STEP: Duration = Duration::from_secs(5);
+
+/// Quiet time after the last seek key before the target is committed
+pub const SEEK_COMMIT: Duration = Duration::from_millis(800);
+
+/// Playback speeds, slowest first
+pub const SPEEDS: [f64; 7] = [0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Repeat points: A alone is set first, and the range loops once B is set
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Repeat {
+    #[default]
+    Off,
+    A(Duration),
+    AB(Duration, Duration),
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaybackClock {
+    pub position: Duration,
+    /// Length of the media, once known
+    pub duration: Option<Duration>,
+    pub playing: bool,
+    last_tick: Option<Instant>,
+    /// Position being previewed, from the seek keys or the mouse
+    pub seek_target: Option<Duration>,
+    /// When the seek keys last moved the target; `None` while hovering
+    scrubbed_at: Option<Instant>,
+    /// Multiple of real time the position moves at, one of `SPEEDS`
+    pub speed: f64,
+    /// Start over from the beginning at the end instead of stopping
+    pub loop_file: bool,
+    pub repeat: Repeat,
+}
+
+impl Default for PlaybackClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlaybackClock {
+    pub fn new() -> Self {
+        Self {
+            position: Duration::ZERO,
+            duration: None,
+            playing: false,
+            last_tick: None,
+            seek_target: None,
+            scrubbed_at: None,
+            speed: 1.0,
+            loop_file: false,
+            repeat: Repeat::Off,
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.playing = !self.playing;
+        self.last_tick = None;
+    }
+
+    pub fn stop(&mut self) {
+        self.playing = false;
+        self.last_tick = None;
+    }
+
+    /// `over` wrapped into a span of `length`; zero when the span is empty
+    fn wrap(over: Duration, length: Duration) -> Duration {
+        match length.as_nanos() {
+            0 => Duration::ZERO,
+            length => Duration::from_nanos((over.as_nanos() % length) as u64),
+        }
+    }
+
+    fn clamp(&self, at: Duration) -> Duration {
+        self.duration.map_or(at, |duration| at.min(duration))
+    }
+
+    /// Move the position on by the wall time since the last tick and commit
+    /// a seek target the keys have left alone; call once per tick
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        if self.playing {
+            let elapsed = self.last_tick.map_or(Duration::ZERO, |last| now - last);
+            self.advance(elapsed);
+        }
+        self.last_tick = self.playing.then_some(now);
+        if self.scrubbed_at.is_some_and(|at| at.elapsed() >= SEEK_COMMIT) {
+            self.commit_seek();
+        }
+    }
+
+    /// Play on for `elapsed` of wall time at the current speed, jumping
+    /// back to A at B and wrapping or stopping at the end
+    pub fn advance(&mut self, elapsed: Duration) {
+        let position = self.position + elapsed.mul_f64(self.speed);
+        if let Repeat::AB(a, b) = self.repeat {
+            if self.position <= b && position >= b {
+                self.position = a + Self::wrap(position - b, b - a);
+                return;
+            }
+        }
+        match self.duration {
+            Some(duration) if position >= duration && self.loop_file && !duration.is_zero() => {
+                self.position = Self::wrap(position - duration, duration);
+            }
+            Some(duration) if position >= duration => {
+                self.position = duration;
+                self.stop();
+            }
+            _ => self.position = position,
+        }
+    }
+
+    /// Step to the next speed up or down, staying within `SPEEDS`
+    pub fn change_speed(&mut self, faster: bool) {
+        let index = SPEEDS.iter().position(|&speed| speed == self.speed).unwrap_or(3);
+        let index = if faster { (index + 1).min(SPEEDS.len() - 1) } else { index.saturating_sub(1) };
+        self.speed = SPEEDS[index];
+    }
+
+    pub fn reset_speed(&mut self) {
+        self.speed = 1.0;
+    }
+
+    /// Audio cannot follow other speeds, so the player mutes it there
+    pub fn is_muted(&self) -> bool {
+        self.speed != 1.0
+    }
+
+    pub fn toggle_loop(&mut self) {
+        self.loop_file = !self.loop_file;
+    }
+
+    /// Mark A at the position, then B, then clear both. B before A swaps
+    /// them, and B on A clears the marks rather than loop nothing.
+    pub fn mark_repeat(&mut self) {
+        let at = self.position;
+        self.repeat = match self.repeat {
+            Repeat::Off => Repeat::A(at),
+            Repeat::A(a) if a == at => Repeat::Off,
+            Repeat::A(a) => Repeat::AB(a.min(at), a.max(at)),
+            Repeat::AB(..) => Repeat::Off,
+        };
+    }
+
+    /// Speed, loop and repeat indicators for a controls bar; empty at the
+    /// defaults
+    pub fn mode_label(&self) -> String {
+        let mut parts = Vec::new();
+        if self.speed != 1.0 {
+            parts.push(format!("{}x (muted)", self.speed));
+        }
+        if self.loop_file {
+            parts.push("Loop".to_string());
+        }
+        match self.repeat {
+            Repeat::Off => {}
+            Repeat::A(a) => parts.push(format!("A {} - B ?", format_clock(a))),
+            Repeat::AB(a, b) => parts.push(format!("A-B {} - {}", format_clock(a), format_clock(b))),
+        }
+        parts.join(" | ")
+    }
+
+    /// Move the seek target a step from where it is, or from the position
+    pub fn scrub(&mut self, forward: bool) {
+        let from = self.seek_target.unwrap_or(self.position);
+        let target = if forward { from + SEEK_STEP } else { from.saturating_sub(SEEK_STEP) };
+        self.seek_target = Some(self.clamp(target));
+        self.scrubbed_at = Some(Instant::now());
+    }
+
+    /// Point at a target with the mouse, or stop pointing with `None`
+    pub fn hover(&mut self, target: Option<Duration>) {
+        if self.scrubbed_at.is_none() {
+            self.seek_target = target.map(|target| self.clamp(target));
+        }
+    }
+
+    pub fn seek(&mut self, to: Duration) {
+        self.position = self.clamp(to);
+        self.seek_target = None;
+        self.scrubbed_at = None;
+    }
+
+    pub fn commit_seek(&mut self) {
+        if let Some(target) = self.seek_target {
+            self.seek(target);
+        }
+    }
+
+    pub fn cancel_seek(&mut self) {
+        self.seek_target = None;
+        self.scrubbed_at = None;
+    }
+
+    /// Position `ratio` of the way through the media, once its length is
+    /// known
+    pub fn at_ratio(&self, ratio: f64) -> Option<Duration> {
+        self.duration.map(|duration| duration.mul_f64(ratio.clamp(0.0, 1.0)))
+    }
+
+    /// How far through the media `at` is, from 0.0 to 1.0
+    pub fn ratio_of(&self, at: Duration) -> f64 {
+        match self.duration {
+            Some(duration) if !duration.is_zero() => (at.as_secs_f64() / duration.as_secs_f64()).min(1.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Seek bar at the position, labelled with it and the length
+    pub fn seek_bar(&self) -> LineGauge<'static> {
+        let total = self.duration.map_or_else(|| "--:--:--".to_string(), format_clock);
+        LineGauge::default()
+            .ratio(self.ratio_of(self.position))
+            .label(format!("{} / {} ", format_clock(self.position), total))
+            .filled_style(Style::default().fg(Color::Cyan))
+            .unfilled_style(Style::default().fg(Color::DarkGray))
+            .line_set(symbols::line::THICK)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_and_seek_target() {
+        let mut clock = PlaybackClock::new();
+        clock.duration = Some(Duration::from_secs(60));
+        clock.toggle();
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(clock.position, Duration::from_secs(10));
+
+        clock.scrub(true);
+        clock.scrub(true);
+        assert_eq!(clock.seek_target, Some(Duration::from_secs(20)));
+        assert_eq!(clock.position, Duration::from_secs(10));
+        // Hovering does not take over from the keys
+        clock.hover(Some(Duration::from_secs(50)));
+        clock.commit_seek();
+        assert_eq!(clock.position, Duration::from_secs(20));
+
+        clock.hover(clock.at_ratio(0.5));
+        assert_eq!(clock.seek_target, Some(Duration::from_secs(30)));
+        clock.hover(None);
+        assert_eq!(clock.seek_target, None);
+
+        clock.advance(Duration::from_secs(100));
+        assert_eq!(clock.position, Duration::from_secs(60));
+        assert!(!clock.playing);
+    }
+
+    #[test]
+    fn test_speed_loop_and_repeat() {
+        let mut clock = PlaybackClock::new();
+        clock.duration = Some(Duration::from_secs(60));
+        clock.toggle();
+        clock.change_speed(true);
+        clock.change_speed(true);
+        assert_eq!(clock.speed, 1.5);
+        assert!(clock.is_muted());
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(clock.position, Duration::from_secs(15));
+        for _ in 0..10 {
+            clock.change_speed(false);
+        }
+        assert_eq!(clock.speed, 0.25);
+        clock.reset_speed();
+
+        clock.mark_repeat();
+        clock.advance(Duration::from_secs(5));
+        clock.mark_repeat();
+        assert_eq!(clock.repeat, Repeat::AB(Duration::from_secs(15), Duration::from_secs(20)));
+        assert_eq!(clock.mode_label(), "A-B 00:00:15 - 00:00:20");
+        clock.advance(Duration::from_secs(7));
+        assert_eq!(clock.position, Duration::from_secs(17));
+        clock.mark_repeat();
+        assert_eq!(clock.repeat, Repeat::Off);
+
+        clock.toggle_loop();
+        clock.advance(Duration::from_secs(50));
+        assert_eq!(clock.position, Duration::from_secs(7));
+        assert!(clock.playing);
+        assert_eq!(clock.mode_label(), "Loop");
+    }
+}
@@ -0,0 +1,250 @@
+//! Pomodoro module
+//!
+//! Work and break cycles on top of a countdown `Timer`: every work session
+//! is followed by a short break, and every few sessions by a long one.
+//! Finished and skipped phases are kept as a session history in the data
+//! directory for the daily focus chart.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use super::timer::Timer;
+
+/// Phase lengths and cycle settings, stored in the app config
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PomodoroConfig {
+    pub work_minutes: u64,
+    pub short_break_minutes: u64,
+    pub long_break_minutes: u64,
+    /// Work sessions before a long break
+    pub sessions_before_long_break: u32,
+    /// Start the next phase without waiting for a key press
+    pub auto_start: bool,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            work_minutes: 25,
+            short_break_minutes: 5,
+            long_break_minutes: 15,
+            sessions_before_long_break: 4,
+            auto_start: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Phase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl Phase {
+    pub fn label(self) -> &'static str {
+        match self {
+            Phase::Work => "Focus",
+            Phase::ShortBreak => "Short break",
+            Phase::LongBreak => "Long break",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Phase::Work => Color::Red,
+            Phase::ShortBreak => Color::Green,
+            Phase::LongBreak => Color::Blue,
+        }
+    }
+}
+
+/// A phase that ended, either run to completion or skipped
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub phase: Phase,
+    pub ended: DateTime<Local>,
+    /// Seconds actually spent in the phase
+    pub seconds: u64,
+    pub completed: bool,
+}
+
+pub struct Pomodoro {
+    pub config: PomodoroConfig,
+    pub phase: Phase,
+    pub timer: Timer,
+    /// Work sessions completed in the current cycle
+    pub completed_work: u32,
+    pub history: Vec<Session>,
+    history_path: Option<PathBuf>,
+}
+
+impl Pomodoro {
+    /// Pomodoro with its history in `pomodoro.json` in the data directory
+    pub fn new(config: PomodoroConfig) -> Self {
+        let history_path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("pomodoro.json");
+            path
+        });
+        Self::with_history_path(config, history_path)
+    }
+
+    pub fn with_history_path(config: PomodoroConfig, history_path: Option<PathBuf>) -> Self {
+        let history = history_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        let timer = Timer::countdown(Phase::Work.label(), Self::length(&config, Phase::Work));
+        Self {
+            config,
+            phase: Phase::Work,
+            timer,
+            completed_work: 0,
+            history,
+            history_path,
+        }
+    }
+
+    fn length(config: &PomodoroConfig, phase: Phase) -> Duration {
+        let minutes = match phase {
+            Phase::Work => config.work_minutes,
+            Phase::ShortBreak => config.short_break_minutes,
+            Phase::LongBreak => config.long_break_minutes,
+        };
+        Duration::from_secs(minutes.max(1) * 60)
+    }
+
+    /// Apply new settings; the current phase restarts with its new length
+    pub fn set_config(&mut self, config: PomodoroConfig) {
+        self.config = config;
+        self.start_phase(self.phase, false);
+    }
+
+    /// Work sessions left before the next long break, including the current one
+    pub fn sessions_until_long_break(&self) -> u32 {
+        self.config.sessions_before_long_break.max(1) - self.completed_work % self.config.sessions_before_long_break.max(1)
+    }
+
+    fn start_phase(&mut self, phase: Phase, run: bool) {
+        self.phase = phase;
+        self.timer = Timer::countdown(phase.label(), Self::length(&self.config, phase));
+        if run {
+            self.timer.start();
+        }
+    }
+
+    /// Record the current phase and move to the one after it
+    fn advance(&mut self, completed: bool) -> Phase {
+        self.history.push(Session {
+            phase: self.phase,
+            ended: Local::now(),
+            seconds: self.timer.elapsed().as_secs(),
+            completed,
+        });
+        self.save_history();
+        let next = match self.phase {
+            Phase::Work => {
+                if completed {
+                    self.completed_work += 1;
+                }
+                if completed && self.completed_work.is_multiple_of(self.config.sessions_before_long_break.max(1)) {
+                    Phase::LongBreak
+                } else {
+                    Phase::ShortBreak
+                }
+            }
+            Phase::ShortBreak | Phase::LongBreak => Phase::Work,
+        };
+        self.start_phase(next, self.config.auto_start);
+        next
+    }
+
+    /// Advance the countdown; returns the new phase when one finished
+    pub fn tick(&mut self) -> Option<Phase> {
+        self.timer.tick().then(|| self.advance(true))
+    }
+
+    /// End the current phase early
+    pub fn skip(&mut self) -> Phase {
+        self.advance(false)
+    }
+
+    /// Restart the current phase from its full length
+    pub fn reset(&mut self) {
+        self.start_phase(self.phase, false);
+    }
+
+    /// Minutes of work per day for the `days` days ending `today`, oldest first
+    pub fn focus_minutes(&self, today: NaiveDate, days: u64) -> Vec<(NaiveDate, u64)> {
+        (0..days)
+            .rev()
+            .filter_map(|ago| today.checked_sub_days(chrono::Days::new(ago)))
+            .map(|day| {
+                let seconds: u64 = self
+                    .history
+                    .iter()
+                    .filter(|session| session.phase == Phase::Work && session.ended.date_naive() == day)
+                    .map(|session| session.seconds)
+                    .sum();
+                (day, seconds / 60)
+            })
+            .collect()
+    }
+
+    fn save_history(&self) {
+        let Some(path) = &self.history_path else {
+            return;
+        };
+        let result = serde_json::to_string(&self.history)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                path.parent().map_or(Ok(()), fs::create_dir_all).map_err(|e| e.to_string())?;
+                fs::write(path, content).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::error!("Failed to save pomodoro history: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_and_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pomodoro.json");
+        let config = PomodoroConfig {
+            sessions_before_long_break: 2,
+            ..PomodoroConfig::default()
+        };
+        let mut pomodoro = Pomodoro::with_history_path(config.clone(), Some(path.clone()));
+        assert_eq!(pomodoro.timer.remaining(), Some(Duration::from_secs(25 * 60)));
+
+        // A skipped work session does not count towards the long break
+        assert_eq!(pomodoro.skip(), Phase::ShortBreak);
+        assert_eq!(pomodoro.skip(), Phase::Work);
+        pomodoro.completed_work = 1;
+        assert_eq!(pomodoro.sessions_until_long_break(), 1);
+        assert_eq!(pomodoro.advance(true), Phase::LongBreak);
+        assert_eq!(pomodoro.timer.remaining(), Some(Duration::from_secs(15 * 60)));
+        assert!(!pomodoro.timer.is_running());
+
+        let reopened = Pomodoro::with_history_path(config, Some(path));
+        assert_eq!(reopened.history.len(), 3);
+        assert!(!reopened.history[0].completed);
+        let today = Local::now().date_naive();
+        let days = reopened.focus_minutes(today, 7);
+        assert_eq!(days.len(), 7);
+        assert_eq!(days[6].0, today);
+    }
+}
@@ -0,0 +1,144 @@
+//! Profiler module
+//!
+//! F12 overlay with frame rate, frame interval and how many input events
+//! were handled per frame. Event loops that batch queued keys before
+//! redrawing show more than one event per frame while the user types fast
+//! or pastes. Below that, each LRU cache's hit rate and memory use.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::event::{KeyCode, KeyEvent};
+
+use super::humanize::format_size;
+use super::lru;
+
+/// Frames kept for the averages
+const WINDOW: usize = 60;
+
+#[derive(Debug, Default)]
+pub struct Profiler {
+    pub visible: bool,
+    /// Times of the last `WINDOW` frames
+    frames: VecDeque<Instant>,
+    /// Events handled before each of the last `WINDOW` frames
+    batches: VecDeque<usize>,
+    /// Events handled since the last frame
+    pending: usize,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_shortcut(key: &KeyEvent) -> bool {
+        key.code == KeyCode::F(12)
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Count events handled before the next frame
+    pub fn record_events(&mut self, count: usize) {
+        self.pending += count;
+    }
+
+    /// Close the current frame's event count; call after each draw
+    pub fn record_frame(&mut self) {
+        self.frames.push_back(Instant::now());
+        self.batches.push_back(std::mem::take(&mut self.pending));
+        if self.frames.len() > WINDOW {
+            self.frames.pop_front();
+            self.batches.pop_front();
+        }
+    }
+
+    /// Average time between frames over the window
+    pub fn frame_interval(&self) -> Option<Duration> {
+        let (first, last) = (self.frames.front()?, self.frames.back()?);
+        let intervals = self.frames.len().checked_sub(1).filter(|&n| n > 0)?;
+        Some((*last - *first) / intervals as u32)
+    }
+
+    /// Events before the last frame and the most before any frame in the window
+    pub fn events_per_frame(&self) -> (usize, usize) {
+        let last = self.batches.back().copied().unwrap_or(0);
+        (last, self.batches.iter().copied().max().unwrap_or(0))
+    }
+
+    /// Small panel in the top-right corner; call last when drawing
+    pub fn render(&self, frame: &mut Frame) {
+        if !self.visible {
+            return;
+        }
+        let caches = lru::stats();
+        let area = frame.area();
+        let (width, height) = (36.min(area.width), (5 + caches.len() as u16).min(area.height));
+        let panel = Rect {
+            x: area.right() - width,
+            y: area.y,
+            width,
+            height,
+        };
+        let (fps, interval) = match self.frame_interval() {
+            Some(interval) if !interval.is_zero() => (1.0 / interval.as_secs_f64(), interval.as_secs_f64() * 1000.0),
+            _ => (0.0, 0.0),
+        };
+        let (last, max) = self.events_per_frame();
+        let mut lines = vec![
+            Line::raw(format!("fps        {:>6.1}", fps)),
+            Line::raw(format!("frame      {:>6.1} ms", interval)),
+            Line::raw(format!("events/frm {:>6} (max {})", last, max)),
+        ];
+        lines.extend(caches.iter().map(|(name, stats)| {
+            Line::raw(format!(
+                "{:<12} {:>3.0}% {:>9}",
+                name,
+                stats.hit_rate() * 100.0,
+                format_size(stats.bytes as u64)
+            ))
+        }));
+        frame.render_widget(Clear, panel);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .style(Style::default().fg(Color::Green))
+                .block(Block::default().borders(Borders::ALL).title("Profiler (F12)")),
+            panel,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_per_frame() {
+        let mut profiler = Profiler::new();
+        assert_eq!(profiler.frame_interval(), None);
+        profiler.record_events(1);
+        profiler.record_frame();
+        profiler.record_events(12);
+        profiler.record_events(3);
+        profiler.record_frame();
+        assert_eq!(profiler.events_per_frame(), (15, 15));
+        profiler.record_frame();
+        assert_eq!(profiler.events_per_frame(), (0, 15));
+        assert!(profiler.frame_interval().is_some());
+
+        for _ in 0..WINDOW {
+            profiler.record_frame();
+        }
+        assert_eq!(profiler.events_per_frame(), (0, 0));
+    }
+}
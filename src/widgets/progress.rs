@@ -0,0 +1,333 @@
+//! Background task progress module
+//!
+//! Runs named tasks on worker threads that report progress over a channel,
+//! and renders them as stacked progress bars with cancel support.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, Gauge, Paragraph},
+    Frame,
+};
+
+use crate::event::KeyCode;
+use crate::platform;
+
+/// Identifier of a task spawned by a `TaskManager`
+pub type TaskId = usize;
+
+/// Messages sent from worker threads to the manager
+enum TaskEvent<T> {
+    Progress { id: TaskId, ratio: f64, message: Option<String> },
+    Finished { id: TaskId, output: T },
+    Failed { id: TaskId, error: String },
+    Cancelled { id: TaskId },
+}
+
+/// Final result of a task, returned from `TaskManager::poll`
+#[derive(Debug)]
+pub enum TaskOutcome<T> {
+    Finished { id: TaskId, name: String, output: T },
+    Failed { id: TaskId, name: String, error: String },
+    Cancelled { id: TaskId, name: String },
+}
+
+/// Handle passed to a worker for reporting progress and checking cancellation
+pub struct TaskHandle<T> {
+    id: TaskId,
+    sender: Sender<TaskEvent<T>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<T> TaskHandle<T> {
+    /// Report progress as a ratio between 0.0 and 1.0
+    pub fn report(&self, ratio: f64) {
+        let _ = self.sender.send(TaskEvent::Progress {
+            id: self.id,
+            ratio: ratio.clamp(0.0, 1.0),
+            message: None,
+        });
+    }
+
+    /// Report progress together with a short status message
+    pub fn report_with(&self, ratio: f64, message: impl Into<String>) {
+        let _ = self.sender.send(TaskEvent::Progress {
+            id: self.id,
+            ratio: ratio.clamp(0.0, 1.0),
+            message: Some(message.into()),
+        });
+    }
+
+    /// Whether the user asked for this task to stop
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Display state of a running task
+pub struct TaskInfo {
+    pub id: TaskId,
+    pub name: String,
+    pub ratio: f64,
+    pub message: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TaskInfo {
+    pub fn is_cancelling(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Manager for concurrent background tasks producing outputs of type `T`
+pub struct TaskManager<T: Send + 'static = ()> {
+    pub tasks: Vec<TaskInfo>,
+    pub selected: usize,
+    next_id: TaskId,
+    sender: Sender<TaskEvent<T>>,
+    receiver: Receiver<TaskEvent<T>>,
+}
+
+impl<T: Send + 'static> Default for TaskManager<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Send + 'static> TaskManager<T> {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            tasks: Vec::new(),
+            selected: 0,
+            next_id: 0,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Run `work` on a new thread; it should check `is_cancelled` periodically
+    pub fn spawn<F>(&mut self, name: &str, work: F) -> TaskId
+    where
+        F: FnOnce(&TaskHandle<T>) -> Result<T, String> + Send + 'static,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.tasks.push(TaskInfo {
+            id,
+            name: name.to_string(),
+            ratio: 0.0,
+            message: String::new(),
+            cancelled: Arc::clone(&cancelled),
+        });
+
+        let handle = TaskHandle {
+            id,
+            sender: self.sender.clone(),
+            cancelled,
+        };
+        platform::current().background(Box::new(move || {
+            let event = match work(&handle) {
+                _ if handle.is_cancelled() => TaskEvent::Cancelled { id },
+                Ok(output) => TaskEvent::Finished { id, output },
+                Err(error) => TaskEvent::Failed { id, error },
+            };
+            let _ = handle.sender.send(event);
+        }));
+
+        id
+    }
+
+    /// Apply pending progress updates and return tasks that completed
+    pub fn poll(&mut self) -> Vec<TaskOutcome<T>> {
+        let mut outcomes = Vec::new();
+
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                TaskEvent::Progress { id, ratio, message } => {
+                    if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id) {
+                        task.ratio = ratio;
+                        if let Some(message) = message {
+                            task.message = message;
+                        }
+                    }
+                }
+                TaskEvent::Finished { id, output } => {
+                    let name = self.remove(id);
+                    outcomes.push(TaskOutcome::Finished { id, name, output });
+                }
+                TaskEvent::Failed { id, error } => {
+                    let name = self.remove(id);
+                    outcomes.push(TaskOutcome::Failed { id, name, error });
+                }
+                TaskEvent::Cancelled { id } => {
+                    let name = self.remove(id);
+                    outcomes.push(TaskOutcome::Cancelled { id, name });
+                }
+            }
+        }
+
+        outcomes
+    }
+
+    fn remove(&mut self, id: TaskId) -> String {
+        let name = match self.tasks.iter().position(|t| t.id == id) {
+            Some(index) => self.tasks.remove(index).name,
+            None => String::new(),
+        };
+        if self.selected >= self.tasks.len() {
+            self.selected = self.tasks.len().saturating_sub(1);
+        }
+        name
+    }
+
+    pub fn is_busy(&self) -> bool {
+        !self.tasks.is_empty()
+    }
+
+    pub fn is_running(&self, id: TaskId) -> bool {
+        self.tasks.iter().any(|t| t.id == id)
+    }
+
+    /// Request cancellation of the selected task
+    pub fn cancel_selected(&mut self) -> Option<&str> {
+        let task = self.tasks.get(self.selected)?;
+        task.cancelled.store(true, Ordering::Relaxed);
+        Some(task.name.as_str())
+    }
+
+    /// Request cancellation of every running task
+    pub fn cancel_all(&mut self) {
+        for task in &self.tasks {
+            task.cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub fn next(&mut self) {
+        if !self.tasks.is_empty() {
+            self.selected = (self.selected + 1) % self.tasks.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if !self.tasks.is_empty() {
+            self.selected = if self.selected == 0 { self.tasks.len() - 1 } else { self.selected - 1 };
+        }
+    }
+
+    /// Handle task panel keys (↑/↓ select, Esc cancel); returns true if consumed
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        if !self.is_busy() {
+            return false;
+        }
+        match key {
+            KeyCode::Up => self.previous(),
+            KeyCode::Down => self.next(),
+            KeyCode::Esc => {
+                self.cancel_selected();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Height needed to render every task bar plus the panel border
+    pub fn panel_height(&self) -> u16 {
+        self.tasks.len() as u16 * 3 + 2
+    }
+
+    /// Render stacked progress bars for all running tasks
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("Tasks ({}) - ↑↓ select, Esc cancel", self.tasks.len()));
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        if self.tasks.is_empty() {
+            let idle = Paragraph::new("No background tasks").style(Style::default().fg(Color::Gray));
+            frame.render_widget(idle, inner);
+            return;
+        }
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(3); self.tasks.len()])
+            .split(inner);
+
+        for (i, (task, row)) in self.tasks.iter().zip(rows.iter()).enumerate() {
+            let (color, status) = if task.is_cancelling() {
+                (Color::Red, "cancelling…")
+            } else {
+                (Color::Cyan, task.message.as_str())
+            };
+            let title_style = if i == self.selected {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            let gauge = Gauge::default()
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(task.name.as_str())
+                        .title_style(title_style),
+                )
+                .gauge_style(Style::default().fg(color))
+                .ratio(task.ratio)
+                .label(format!("{:.0}% {}", task.ratio * 100.0, status));
+            frame.render_widget(gauge, *row);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    fn wait_for<T: Send + 'static>(manager: &mut TaskManager<T>) -> Vec<TaskOutcome<T>> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut outcomes = Vec::new();
+        while manager.is_busy() && Instant::now() < deadline {
+            outcomes.extend(manager.poll());
+            thread::sleep(Duration::from_millis(5));
+        }
+        outcomes
+    }
+
+    #[test]
+    fn test_task_finishes_with_output() {
+        let mut manager: TaskManager<u32> = TaskManager::new();
+        manager.spawn("sum", |handle| {
+            handle.report(0.5);
+            Ok(42)
+        });
+        let outcomes = wait_for(&mut manager);
+        assert!(matches!(outcomes.as_slice(), [TaskOutcome::Finished { output: 42, .. }]));
+        assert!(!manager.is_busy());
+    }
+
+    #[test]
+    fn test_cancel_selected_task() {
+        let mut manager: TaskManager = TaskManager::new();
+        manager.spawn("loop", |handle| {
+            while !handle.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Ok(())
+        });
+        assert_eq!(manager.cancel_selected(), Some("loop"));
+        let outcomes = wait_for(&mut manager);
+        assert!(matches!(outcomes.as_slice(), [TaskOutcome::Cancelled { .. }]));
+    }
+}
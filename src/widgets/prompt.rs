@@ -0,0 +1,331 @@
+//! Prompt module
+//!
+//! Prompts for cooked-mode CLIs: text, password, confirm, select and
+//! multiselect. Answers are validated, then echoed in green so a finished
+//! session reads as a transcript in the scrollback. Confirm and the select
+//! prompts draw in an inline viewport and take the same keys as their TUI
+//! counterparts; select and multiselect are `SelectComponent`s.
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    style::{Color as TermColor, Print, ResetColor, SetForegroundColor},
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::error::DemoError;
+
+use super::component::Component;
+use super::inline;
+use super::table::{SelectComponent, SelectOption, SelectType};
+
+type Validator = Box<dyn Fn(&str) -> Result<(), String>>;
+
+/// Line prompt read in cooked mode, or with masked echo for passwords
+pub struct Text {
+    label: String,
+    default: Option<String>,
+    validators: Vec<Validator>,
+    masked: bool,
+}
+
+impl Text {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            default: None,
+            validators: Vec::new(),
+            masked: false,
+        }
+    }
+
+    /// Prompt that echoes `*` per character and never shows the answer
+    pub fn password(label: &str) -> Self {
+        Self {
+            masked: true,
+            ..Self::new(label)
+        }
+    }
+
+    /// Answer used when the input is left empty
+    pub fn default_value(mut self, value: &str) -> Self {
+        self.default = Some(value.to_string());
+        self
+    }
+
+    /// Reject answers for which `check` returns an error message
+    pub fn validate(mut self, check: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validators.push(Box::new(check));
+        self
+    }
+
+    /// Trimmed answer for `input`, or the message to show before asking again
+    pub fn check(&self, input: &str) -> Result<String, String> {
+        let mut answer = if self.masked { input.to_string() } else { input.trim().to_string() };
+        if answer.is_empty() {
+            answer = self
+                .default
+                .clone()
+                .ok_or_else(|| format!("{} cannot be empty. Please try again.", self.label))?;
+        }
+        for validator in &self.validators {
+            validator(&answer)?;
+        }
+        Ok(answer)
+    }
+
+    /// Ask until the answer passes validation
+    pub fn interact(&self) -> Result<String, DemoError> {
+        loop {
+            let prompt = match &self.default {
+                Some(default) if !self.masked => format!("{} [{}]: ", self.label, default),
+                _ => format!("{}: ", self.label),
+            };
+            print!("{}", prompt);
+            io::stdout().flush()?;
+
+            let input = if self.masked { read_masked()? } else { read_line()? };
+            match self.check(&input) {
+                Ok(answer) => {
+                    let shown = if self.masked { "*".repeat(answer.chars().count()) } else { answer.clone() };
+                    echo(&format!("{}: {}", self.label, shown))?;
+                    return Ok(answer);
+                }
+                Err(message) => error(&message)?,
+            }
+        }
+    }
+}
+
+fn read_line() -> Result<String, DemoError> {
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input)? == 0 {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "input closed").into());
+    }
+    Ok(input)
+}
+
+fn read_masked() -> Result<String, DemoError> {
+    enable_raw_mode()?;
+    let input = (|| -> Result<String, DemoError> {
+        let mut input = String::new();
+        loop {
+            let Event::Key(key) = event::read()? else { continue };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if is_cancel(key) {
+                return Err(cancelled());
+            }
+            match key.code {
+                KeyCode::Enter => return Ok(input),
+                KeyCode::Backspace if input.pop().is_some() => {
+                    execute!(io::stdout(), Print("\x08 \x08"))?;
+                }
+                KeyCode::Char(c) => {
+                    input.push(c);
+                    execute!(io::stdout(), Print("*"))?;
+                }
+                _ => {}
+            }
+        }
+    })();
+    disable_raw_mode()?;
+    // Enter did not move the cursor in raw mode; the echo replaces the line
+    print!("\r\x1B[2K");
+    input
+}
+
+fn is_cancel(key: KeyEvent) -> bool {
+    key.code == KeyCode::Esc || (key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL))
+}
+
+fn cancelled() -> DemoError {
+    io::Error::new(io::ErrorKind::Interrupted, "cancelled").into()
+}
+
+/// Print an accepted answer in green with a check mark
+pub fn echo(line: &str) -> Result<(), DemoError> {
+    execute!(io::stdout(), SetForegroundColor(TermColor::Green), Print(format!("{} ✓\n", line)), ResetColor)?;
+    Ok(())
+}
+
+/// Print a rejected answer's message in red
+pub fn error(message: &str) -> Result<(), DemoError> {
+    execute!(io::stdout(), SetForegroundColor(TermColor::Red), Print(format!("❌ {}\n", message)), ResetColor)?;
+    Ok(())
+}
+
+/// Draw `state` in a `height`-line inline viewport and feed it key presses
+/// until `on_key` returns an answer, then clear the viewport for the echo
+fn run_inline<S, T>(
+    height: u16,
+    state: &mut S,
+    draw: impl Fn(&mut S, &mut Frame),
+    mut on_key: impl FnMut(&mut S, KeyEvent) -> Option<T>,
+) -> Result<T, DemoError> {
+    let mut terminal = inline::terminal(height)?;
+    enable_raw_mode()?;
+    let answer = (|| -> Result<T, DemoError> {
+        loop {
+            terminal.draw(|frame| draw(state, frame))?;
+            if !event::poll(Duration::from_millis(250))? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else { continue };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if is_cancel(key) {
+                return Err(cancelled());
+            }
+            if let Some(answer) = on_key(state, key) {
+                return Ok(answer);
+            }
+        }
+    })();
+    disable_raw_mode()?;
+    inline::clear(terminal)?;
+    answer
+}
+
+fn confirm_line(question: &str, yes: bool) -> Line<'static> {
+    let option = |label: &str, chosen: bool| {
+        let style = if chosen {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        Span::styled(format!(" {} ", label), style)
+    };
+    Line::from(vec![
+        Span::styled(format!("{} ", question), Style::default().add_modifier(Modifier::BOLD)),
+        option("Yes", yes),
+        option("No", !yes),
+    ])
+}
+
+/// Yes/No toggle: ←/→ or Tab switch, `y`/`n` answer, Enter takes the
+/// highlighted one
+pub fn confirm(question: &str, default: bool) -> Result<bool, DemoError> {
+    let mut yes = default;
+    let answer = run_inline(
+        1,
+        &mut yes,
+        |yes, frame| {
+            let area = frame.area();
+            frame.render_widget(Paragraph::new(confirm_line(question, *yes)), area);
+        },
+        |yes, key| match key.code {
+            KeyCode::Left | KeyCode::Right | KeyCode::Tab => {
+                *yes = !*yes;
+                None
+            }
+            KeyCode::Char('y') | KeyCode::Char('Y') => Some(true),
+            KeyCode::Char('n') | KeyCode::Char('N') => Some(false),
+            KeyCode::Enter => Some(*yes),
+            _ => None,
+        },
+    )?;
+    echo(&format!("{} {}", question, if answer { "Yes" } else { "No" }))?;
+    Ok(answer)
+}
+
+fn choose(label: &str, options: Vec<SelectOption>, select_type: SelectType, cursor: usize) -> SelectComponent {
+    let mut select = SelectComponent::builder()
+        .title(label)
+        .options(options)
+        .select_type(select_type)
+        .build();
+    for _ in 0..cursor {
+        select.next();
+    }
+    select.set_focused(true);
+    select
+}
+
+/// Pick one option with ↑/↓ and Enter; returns its value
+pub fn select(label: &str, options: Vec<SelectOption>, default: usize) -> Result<String, DemoError> {
+    let height = options.len() as u16 + 2;
+    let mut select = choose(label, options, SelectType::RadioGroup, default);
+    let value = run_inline(
+        height,
+        &mut select,
+        |select, frame| {
+            let area = frame.area();
+            select.render(frame, area);
+        },
+        |select, key| {
+            if key.code != KeyCode::Enter {
+                select.handle_event(&Event::Key(key));
+                return None;
+            }
+            let value = select.options.get(select.selected_index).filter(|option| option.enabled)?.value.clone();
+            select.select_current();
+            Some(value)
+        },
+    )?;
+    echo(&format!("{}: {}", label, select.get_selected_labels().join(", ")))?;
+    Ok(value)
+}
+
+/// Toggle options with Space and finish with Enter; returns the chosen
+/// values in the order they were picked
+pub fn multiselect(label: &str, options: Vec<SelectOption>, selected: &[&str]) -> Result<Vec<String>, DemoError> {
+    let height = options.len() as u16 + 2;
+    let mut select = choose(label, options, SelectType::CheckboxGroup, 0);
+    select.selected_values = selected.iter().map(|value| value.to_string()).collect();
+    let values = run_inline(
+        height,
+        &mut select,
+        |select, frame| {
+            let area = frame.area();
+            select.render(frame, area);
+        },
+        |select, key| {
+            if key.code == KeyCode::Enter {
+                Some(select.selected_values.clone())
+            } else {
+                select.handle_event(&Event::Key(key));
+                None
+            }
+        },
+    )?;
+    let labels = select.get_selected_labels();
+    echo(&format!("{}: {}", label, if labels.is_empty() { "none".to_string() } else { labels.join(", ") }))?;
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_check() {
+        let name = Text::new("Name").validate(|name| {
+            if name.chars().count() < 2 {
+                Err("Name must be at least 2 characters long.".to_string())
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(name.check("  Ada \n"), Ok("Ada".to_string()));
+        assert_eq!(name.check(" \n"), Err("Name cannot be empty. Please try again.".to_string()));
+        assert_eq!(name.check("A"), Err("Name must be at least 2 characters long.".to_string()));
+
+        let city = Text::new("City").default_value("Lisbon");
+        assert_eq!(city.check(""), Ok("Lisbon".to_string()));
+
+        let password = Text::password("Password");
+        assert_eq!(password.check(" pass "), Ok(" pass ".to_string()));
+    }
+}
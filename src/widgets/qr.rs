@@ -0,0 +1,108 @@
+//! QR code module
+//!
+//! Renders a string as a scannable QR code with the `qrcode` crate, packing
+//! two module rows into each terminal row with half-block cells. Colors are
+//! fixed black on white so the code scans on dark themes too.
+
+use qrcode::{Color as Module, QrCode};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Light modules around the code, as required by scanners
+const QUIET_ZONE: usize = 4;
+
+pub struct QrCodeView {
+    pub title: String,
+    /// Dark modules, including the quiet zone
+    modules: Vec<Vec<bool>>,
+}
+
+impl QrCodeView {
+    pub fn new(data: &str) -> Result<Self, String> {
+        let code = QrCode::new(data.as_bytes()).map_err(|e| e.to_string())?;
+        let width = code.width();
+        let colors = code.to_colors();
+        let size = width + 2 * QUIET_ZONE;
+        let modules = (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        let inside = |v: usize| (QUIET_ZONE..QUIET_ZONE + width).contains(&v);
+                        inside(x) && inside(y) && colors[(y - QUIET_ZONE) * width + x - QUIET_ZONE] == Module::Dark
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Self {
+            title: data.to_string(),
+            modules,
+        })
+    }
+
+    /// Width and height in cells, without a border
+    pub fn size(&self) -> (u16, u16) {
+        let modules = self.modules.len() as u16;
+        (modules, modules.div_ceil(2))
+    }
+
+    /// `▀` cells with the upper module as foreground and the lower one as background
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let color = |dark: bool| if dark { Color::Black } else { Color::White };
+        self.modules
+            .chunks(2)
+            .map(|rows| {
+                let spans: Vec<Span> = (0..rows[0].len())
+                    .map(|x| {
+                        let lower = rows.get(1).is_some_and(|row| row[x]);
+                        Span::styled("▀", Style::default().fg(color(rows[0][x])).bg(color(lower)))
+                    })
+                    .collect();
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    /// Centered popup with the code, or a hint if `area` is too small for it
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let (width, height) = self.size();
+        let fits = width + 2 <= area.width && height + 2 <= area.height;
+        let (width, height) = if fits { (width + 2, height + 2) } else { (area.width.min(40), 3.min(area.height)) };
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        let block = Block::default().borders(Borders::ALL).title(self.title.clone());
+        frame.render_widget(Clear, popup);
+        if fits {
+            frame.render_widget(Paragraph::new(self.lines()).block(block), popup);
+        } else {
+            let hint = Paragraph::new("Enlarge the terminal to show the QR code").block(block);
+            frame.render_widget(hint, popup);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_half_block_layout() {
+        let view = QrCodeView::new("https://crates.io").unwrap();
+        let (width, height) = view.size();
+        assert_eq!(width as usize, view.modules.len());
+        assert_eq!(view.lines().len(), height as usize);
+
+        // Quiet zone is light, the finder pattern's corner is dark
+        let white = Style::default().fg(Color::White).bg(Color::White);
+        assert_eq!(view.lines()[0].spans[0].style, white);
+        assert!(view.modules[QUIET_ZONE][QUIET_ZONE]);
+    }
+}
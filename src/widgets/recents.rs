@@ -0,0 +1,121 @@
+//! Recent files module
+//!
+//! Images, videos, journals and CSV files opened anywhere in the app,
+//! newest first, for the main menu's quick-resume list. The list lives in
+//! the config, so every example adds to the same one; entries whose file
+//! has gone are pruned when the menu loads it.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::finder::FileKind;
+
+/// Entries kept; one per digit key in the menu
+pub const MAX_RECENT_FILES: usize = 9;
+
+/// What a recent file is, which decides the example that reopens it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecentKind {
+    Image,
+    Video,
+    /// Notes read in the text viewer: Markdown, plain text or Org files
+    Journal,
+    Csv,
+}
+
+impl RecentKind {
+    /// The kind of `path` by extension, if it is one worth remembering
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension().map(|ext| ext.to_string_lossy().to_lowercase()).unwrap_or_default();
+        match (ext.as_str(), FileKind::from_path(path)) {
+            ("csv", _) => Some(RecentKind::Csv),
+            ("md" | "txt" | "org", _) => Some(RecentKind::Journal),
+            (_, FileKind::Image) => Some(RecentKind::Image),
+            (_, FileKind::Video) => Some(RecentKind::Video),
+            _ => None,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            RecentKind::Image => "🖼️",
+            RecentKind::Video => "🎬",
+            RecentKind::Journal => "📓",
+            RecentKind::Csv => "📊",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentFile {
+    pub path: PathBuf,
+    pub kind: RecentKind,
+}
+
+/// Recently opened files, newest first
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RecentFiles {
+    pub files: Vec<RecentFile>,
+}
+
+impl RecentFiles {
+    /// Move `path` to the front when it is a kind worth remembering;
+    /// returns whether the list changed. Paths are stored absolute so any
+    /// example can reopen them.
+    pub fn push(&mut self, path: &Path) -> bool {
+        let Some(kind) = RecentKind::from_path(path) else {
+            return false;
+        };
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        self.files.retain(|file| file.path != path);
+        self.files.insert(0, RecentFile { path, kind });
+        self.files.truncate(MAX_RECENT_FILES);
+        true
+    }
+
+    /// Drop entries whose file no longer exists; returns how many went
+    pub fn prune(&mut self) -> usize {
+        let before = self.files.len();
+        self.files.retain(|file| file.path.is_file());
+        before - self.files.len()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&RecentFile> {
+        self.files.get(index)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_dedupes_and_prune_drops_missing() {
+        let dir = std::env::temp_dir().join(format!("recents_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["notes.md", "data.csv", "clip.mp4"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let mut recents = RecentFiles::default();
+        assert!(!recents.push(&dir.join("main.rs")));
+        assert!(recents.push(&dir.join("notes.md")));
+        assert!(recents.push(&dir.join("data.csv")));
+        assert!(recents.push(&dir.join("clip.mp4")));
+        assert!(recents.push(&dir.join("notes.md")));
+        let kinds: Vec<RecentKind> = recents.files.iter().map(|file| file.kind).collect();
+        assert_eq!(kinds, [RecentKind::Journal, RecentKind::Video, RecentKind::Csv]);
+
+        std::fs::remove_file(dir.join("clip.mp4")).unwrap();
+        assert_eq!(recents.prune(), 1);
+        assert_eq!(recents.get(1).map(|file| file.kind), Some(RecentKind::Csv));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,201 @@
+//! Reflow module
+//!
+//! Wraps plain text to a width before it goes into a `Paragraph`, instead
+//! of relying on `Wrap`. Words wrap at whitespace, or at any grapheme, and
+//! CJK text can break between its wide characters, which have no spaces
+//! between them. Tokens longer than a line are split; with hyphenation on,
+//! URLs and paths break after `/`, `?`, `&` and similar characters and long
+//! words get a trailing `-`, rather than being cut at an arbitrary column.
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Characters a URL or path may be broken after
+const URL_BREAKS: [char; 9] = ['/', '?', '&', '=', '.', ',', '_', '#', '-'];
+
+/// Where lines may break
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrapMode {
+    /// At whitespace; whitespace runs collapse to one space
+    #[default]
+    Word,
+    /// At whitespace and between wide (CJK) characters
+    Cjk,
+    /// At any grapheme, keeping whitespace as written
+    Grapheme,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reflow {
+    width: usize,
+    mode: WrapMode,
+    hyphenate: bool,
+}
+
+/// Terminal columns `text` occupies
+fn width_of(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+/// Byte length of the longest grapheme prefix of `text` that fits in
+/// `room` columns; at least one grapheme, so splitting always advances
+fn prefix_end(text: &str, room: usize) -> usize {
+    let mut used = 0;
+    for (i, grapheme) in text.grapheme_indices(true) {
+        used += width_of(grapheme);
+        if used > room {
+            return if i == 0 { grapheme.len() } else { i };
+        }
+    }
+    text.len()
+}
+
+fn is_url_like(token: &str) -> bool {
+    token.contains("://") || token.contains('/')
+}
+
+impl Reflow {
+    pub fn new(width: u16) -> Self {
+        Self { width: width.max(1) as usize, mode: WrapMode::default(), hyphenate: false }
+    }
+
+    pub fn mode(mut self, mode: WrapMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Split long tokens at URL separators or with a trailing `-`
+    pub fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.hyphenate = hyphenate;
+        self
+    }
+
+    /// `text` as wrapped lines; its own line breaks and blank lines are kept
+    pub fn wrap(&self, text: &str) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in text.lines() {
+            match self.mode {
+                WrapMode::Grapheme => lines.extend(self.split_long(paragraph)),
+                WrapMode::Word | WrapMode::Cjk => self.wrap_words(paragraph, &mut lines),
+            }
+        }
+        lines
+    }
+
+    /// Wrapped lines drawn in `style`, ready for a `Paragraph`
+    pub fn lines(&self, text: &str, style: Style) -> Vec<Line<'static>> {
+        self.wrap(text).into_iter().map(|line| Line::styled(line, style)).collect()
+    }
+
+    fn wrap_words(&self, paragraph: &str, lines: &mut Vec<String>) {
+        let mut current = String::new();
+        for (token, spaced) in self.tokens(paragraph) {
+            let gap = usize::from(spaced && !current.is_empty());
+            let token_width = width_of(token);
+            if width_of(&current) + gap + token_width <= self.width {
+                if gap == 1 {
+                    current.push(' ');
+                }
+                current.push_str(token);
+                continue;
+            }
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            let mut pieces = self.split_long(token);
+            current = pieces.pop().unwrap_or_default();
+            lines.extend(pieces);
+        }
+        lines.push(current);
+    }
+
+    /// Words of `paragraph`, each with whether a space comes before it. In
+    /// CJK mode every wide character is a token of its own, joined to its
+    /// neighbours without a space.
+    fn tokens<'a>(&self, paragraph: &'a str) -> Vec<(&'a str, bool)> {
+        let mut tokens = Vec::new();
+        for word in paragraph.split_whitespace() {
+            if self.mode != WrapMode::Cjk {
+                tokens.push((word, true));
+                continue;
+            }
+            let mut start = 0;
+            let mut spaced = true;
+            for (i, grapheme) in word.grapheme_indices(true) {
+                if width_of(grapheme) < 2 {
+                    continue;
+                }
+                if start < i {
+                    tokens.push((&word[start..i], spaced));
+                    spaced = false;
+                }
+                tokens.push((grapheme, spaced));
+                spaced = false;
+                start = i + grapheme.len();
+            }
+            if start < word.len() {
+                tokens.push((&word[start..], spaced));
+            }
+        }
+        tokens
+    }
+
+    /// `token` in pieces no wider than a line, where a grapheme allows
+    fn split_long(&self, token: &str) -> Vec<String> {
+        let mut pieces = Vec::new();
+        let mut rest = token;
+        while width_of(rest) > self.width {
+            let (piece, end) = self.break_point(rest);
+            pieces.push(piece);
+            rest = &rest[end..];
+        }
+        pieces.push(rest.to_string());
+        pieces
+    }
+
+    /// First line of an overlong `token` and the byte offset it ends at
+    fn break_point(&self, token: &str) -> (String, usize) {
+        let end = prefix_end(token, self.width);
+        if self.hyphenate && self.mode != WrapMode::Grapheme {
+            let url = is_url_like(token);
+            let breaks: &[char] = if url { &URL_BREAKS } else { &['-'] };
+            let natural = token[..end]
+                .char_indices()
+                .rev()
+                .find(|&(i, c)| i > 0 && breaks.contains(&c))
+                .map(|(i, c)| i + c.len_utf8());
+            if let Some(cut) = natural {
+                return (token[..cut].to_string(), cut);
+            }
+            let shorter = prefix_end(token, self.width.saturating_sub(1));
+            let wordlike = token[..shorter].chars().last().is_some_and(char::is_alphanumeric);
+            if !url && self.width >= 2 && wordlike && shorter < token.len() {
+                return (format!("{}-", &token[..shorter]), shorter);
+            }
+        }
+        (token[..end].to_string(), end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reflow_modes() {
+        let words = Reflow::new(10);
+        assert_eq!(words.wrap("the quick  brown fox\n\njumps"), ["the quick", "brown fox", "", "jumps"]);
+        assert_eq!(words.wrap("abcdefghijklmn"), ["abcdefghij", "klmn"]);
+
+        let hyphens = words.hyphenate(true);
+        assert_eq!(hyphens.wrap("see https://ratatui.rs/concepts/layout"), ["see", "https://", "ratatui.", "rs/", "concepts/", "layout"]);
+        assert_eq!(hyphens.wrap("extraordinarily"), ["extraordi-", "narily"]);
+
+        let cjk = Reflow::new(7).mode(WrapMode::Cjk);
+        assert_eq!(cjk.wrap("日本語のテキスト ok"), ["日本語", "のテキ", "スト ok"]);
+        assert_eq!(Reflow::new(4).mode(WrapMode::Grapheme).wrap("ab  cdef"), ["ab  ", "cdef"]);
+    }
+}
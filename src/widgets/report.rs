@@ -0,0 +1,399 @@
+//! Report module
+//!
+//! Turns a rendered frame buffer into a shareable report: plain text for
+//! terminals and email, or a standalone HTML page that keeps the colors and
+//! bold/italic/underline styling. Default colors come from the theme and are
+//! exposed as CSS variables. Screenshots add ANSI-colored text and SVG.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+    text::Span,
+};
+
+/// Colors of the configured theme as CSS values, e.g. `#268986`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportTheme {
+    pub primary: String,
+    pub secondary: String,
+    pub background: String,
+    pub text: String,
+}
+
+impl Default for ReportTheme {
+    fn default() -> Self {
+        Self {
+            primary: "#268bd2".to_string(),
+            secondary: "#b58900".to_string(),
+            background: "#1e1e1e".to_string(),
+            text: "#d4d4d4".to_string(),
+        }
+    }
+}
+
+/// Symbols of each buffer row, skipping the cells covered by wide characters
+fn rows(buffer: &Buffer) -> Vec<Vec<&ratatui::buffer::Cell>> {
+    let width = buffer.area.width as usize;
+    buffer
+        .content
+        .chunks(width.max(1))
+        .map(|row| {
+            let mut cells = Vec::new();
+            let mut skip = 0;
+            for cell in row {
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                skip = Span::raw(cell.symbol()).width().saturating_sub(1);
+                cells.push(cell);
+            }
+            cells
+        })
+        .collect()
+}
+
+/// Buffer as plain text with trailing spaces trimmed
+pub fn to_text(buffer: &Buffer) -> String {
+    rows(buffer)
+        .iter()
+        .map(|row| {
+            let line: String = row.iter().map(|cell| cell.symbol()).collect();
+            line.trim_end().to_string() + "\n"
+        })
+        .collect()
+}
+
+/// CSS for a terminal color; `None` keeps the theme default
+fn css_color(color: Color) -> Option<String> {
+    let named = match color {
+        Color::Reset => return None,
+        Color::Black => "#000000",
+        Color::Red => "#cd3131",
+        Color::Green => "#0dbc79",
+        Color::Yellow => "#e5e510",
+        Color::Blue => "var(--primary)",
+        Color::Magenta => "#bc3fbc",
+        Color::Cyan => "#11a8cd",
+        Color::Gray => "#a0a0a0",
+        Color::DarkGray => "#666666",
+        Color::LightRed => "#f14c4c",
+        Color::LightGreen => "#23d18b",
+        Color::LightYellow => "var(--secondary)",
+        Color::LightBlue => "#3b8eea",
+        Color::LightMagenta => "#d670d6",
+        Color::LightCyan => "#29b8db",
+        Color::White => "#ffffff",
+        Color::Rgb(r, g, b) => return Some(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+        Color::Indexed(index) => return Some(indexed_color(index)),
+    };
+    Some(named.to_string())
+}
+
+/// xterm 256-color palette entry
+fn indexed_color(index: u8) -> String {
+    match index {
+        0..=15 => {
+            const BASIC: [Color; 16] = [
+                Color::Black,
+                Color::Red,
+                Color::Green,
+                Color::Yellow,
+                Color::Blue,
+                Color::Magenta,
+                Color::Cyan,
+                Color::Gray,
+                Color::DarkGray,
+                Color::LightRed,
+                Color::LightGreen,
+                Color::LightYellow,
+                Color::LightBlue,
+                Color::LightMagenta,
+                Color::LightCyan,
+                Color::White,
+            ];
+            css_color(BASIC[index as usize]).unwrap_or_default()
+        }
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { value * 40 + 55 };
+            let index = index - 16;
+            format!("#{:02x}{:02x}{:02x}", level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        _ => {
+            let gray = (index - 232) * 10 + 8;
+            format!("#{:02x}{:02x}{:02x}", gray, gray, gray)
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn css_style(cell: &ratatui::buffer::Cell) -> String {
+    let reversed = cell.modifier.contains(Modifier::REVERSED);
+    let (fg, bg) = if reversed { (cell.bg, cell.fg) } else { (cell.fg, cell.bg) };
+    let mut style = Vec::new();
+    if let Some(fg) = css_color(fg) {
+        style.push(format!("color:{}", fg));
+    }
+    if let Some(bg) = css_color(bg) {
+        style.push(format!("background:{}", bg));
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        style.push("font-weight:bold".to_string());
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        style.push("font-style:italic".to_string());
+    }
+    if cell.modifier.contains(Modifier::UNDERLINED) {
+        style.push("text-decoration:underline".to_string());
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        style.push("opacity:0.6".to_string());
+    }
+    style.join(";")
+}
+
+/// Standalone HTML page for the buffer, with runs of equally styled cells
+/// merged into one `<span>`
+pub fn to_html(buffer: &Buffer, title: &str, theme: &ReportTheme) -> String {
+    let mut body = String::new();
+    for row in rows(buffer) {
+        let mut current: Option<(String, String)> = None;
+        for cell in row {
+            let style = css_style(cell);
+            match &mut current {
+                Some((run_style, text)) if *run_style == style => text.push_str(cell.symbol()),
+                _ => {
+                    if let Some((run_style, text)) = current.take() {
+                        body.push_str(&span_html(&run_style, &text));
+                    }
+                    current = Some((style, cell.symbol().to_string()));
+                }
+            }
+        }
+        if let Some((run_style, text)) = current {
+            body.push_str(&span_html(&run_style, text.trim_end()));
+        }
+        body.push('\n');
+    }
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n\
+         :root {{ --primary: {primary}; --secondary: {secondary}; --background: {background}; --text: {text}; }}\n\
+         body {{ background: var(--background); color: var(--text); }}\n\
+         pre {{ font-family: ui-monospace, Menlo, Consolas, monospace; line-height: 1.2; }}\n\
+         </style>\n</head>\n<body>\n<h1>{title}</h1>\n<pre>\n{body}</pre>\n</body>\n</html>\n",
+        title = escape_html(title),
+        primary = theme.primary,
+        secondary = theme.secondary,
+        background = theme.background,
+        text = theme.text,
+        body = body,
+    )
+}
+
+/// SGR parameters for a foreground color; background codes are offset by 10
+fn sgr_color(color: Color, background: bool) -> String {
+    let offset = if background { 10 } else { 0 };
+    let basic = |code: u8| (code + offset).to_string();
+    match color {
+        Color::Reset => basic(39),
+        Color::Black => basic(30),
+        Color::Red => basic(31),
+        Color::Green => basic(32),
+        Color::Yellow => basic(33),
+        Color::Blue => basic(34),
+        Color::Magenta => basic(35),
+        Color::Cyan => basic(36),
+        Color::Gray => basic(37),
+        Color::DarkGray => basic(90),
+        Color::LightRed => basic(91),
+        Color::LightGreen => basic(92),
+        Color::LightYellow => basic(93),
+        Color::LightBlue => basic(94),
+        Color::LightMagenta => basic(95),
+        Color::LightCyan => basic(96),
+        Color::White => basic(97),
+        Color::Indexed(index) => format!("{};5;{}", 38 + offset, index),
+        Color::Rgb(r, g, b) => format!("{};2;{};{};{}", 38 + offset, r, g, b),
+    }
+}
+
+/// SGR escape resetting the style and applying `cell`'s colors and modifiers
+pub(crate) fn sgr(cell: &ratatui::buffer::Cell) -> String {
+    let mut codes = vec!["0".to_string()];
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::REVERSED, "7"),
+    ] {
+        if cell.modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    if cell.fg != Color::Reset {
+        codes.push(sgr_color(cell.fg, false));
+    }
+    if cell.bg != Color::Reset {
+        codes.push(sgr_color(cell.bg, true));
+    }
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// Buffer as text with ANSI escape codes, e.g. for `cat` in a terminal
+pub fn to_ansi(buffer: &Buffer) -> String {
+    let plain = sgr(&ratatui::buffer::Cell::default());
+    let mut ansi = String::new();
+    for row in rows(buffer) {
+        let mut current = plain.clone();
+        for cell in row {
+            let codes = sgr(cell);
+            if codes != current {
+                ansi.push_str(&codes);
+                current = codes;
+            }
+            ansi.push_str(cell.symbol());
+        }
+        if current != plain {
+            ansi.push_str("\x1b[0m");
+        }
+        ansi.push('\n');
+    }
+    ansi
+}
+
+/// Size of one cell in SVG units
+const SVG_CELL: (usize, usize) = (9, 18);
+
+fn escape_xml(text: &str) -> String {
+    escape_html(text).replace('"', "&quot;")
+}
+
+/// SVG image of the buffer with a background rect and text per styled run
+pub fn to_svg(buffer: &Buffer, theme: &ReportTheme) -> String {
+    let (cell_width, cell_height) = SVG_CELL;
+    let mut body = String::new();
+    for (y, row) in rows(buffer).into_iter().enumerate() {
+        let mut x = 0;
+        for cell in row {
+            let width = Span::raw(cell.symbol()).width().max(1);
+            let reversed = cell.modifier.contains(Modifier::REVERSED);
+            let (fg, bg) = if reversed { (cell.bg, cell.fg) } else { (cell.fg, cell.bg) };
+            if let Some(bg) = css_color(bg) {
+                body.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" style=\"fill:{}\"/>\n",
+                    x * cell_width,
+                    y * cell_height,
+                    width * cell_width,
+                    cell_height,
+                    bg
+                ));
+            }
+            if !cell.symbol().trim().is_empty() {
+                let mut style = vec![format!("fill:{}", css_color(fg).unwrap_or_else(|| "var(--text)".to_string()))];
+                if cell.modifier.contains(Modifier::BOLD) {
+                    style.push("font-weight:bold".to_string());
+                }
+                if cell.modifier.contains(Modifier::ITALIC) {
+                    style.push("font-style:italic".to_string());
+                }
+                if cell.modifier.contains(Modifier::UNDERLINED) {
+                    style.push("text-decoration:underline".to_string());
+                }
+                body.push_str(&format!(
+                    "<text x=\"{}\" y=\"{}\" style=\"{}\">{}</text>\n",
+                    x * cell_width,
+                    y * cell_height + cell_height * 3 / 4,
+                    style.join(";"),
+                    escape_xml(cell.symbol())
+                ));
+            }
+            x += width;
+        }
+    }
+    let width = buffer.area.width as usize * cell_width;
+    let height = buffer.area.height as usize * cell_height;
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n\
+         <style>\n\
+         svg {{ --primary: {primary}; --secondary: {secondary}; --text: {text}; }}\n\
+         text {{ font-family: ui-monospace, Menlo, Consolas, monospace; font-size: 15px; white-space: pre; }}\n\
+         </style>\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{background}\"/>\n{body}</svg>\n",
+        width = width,
+        height = height,
+        primary = theme.primary,
+        secondary = theme.secondary,
+        text = theme.text,
+        background = theme.background,
+        body = body,
+    )
+}
+
+/// Write the buffer next to `stem` as `.txt`, `.ans`, `.svg` and `.html`,
+/// returning the written paths
+pub fn write_screenshot(buffer: &Buffer, stem: &Path, theme: &ReportTheme) -> io::Result<Vec<PathBuf>> {
+    let title = stem.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    let files = [
+        ("txt", to_text(buffer)),
+        ("ans", to_ansi(buffer)),
+        ("svg", to_svg(buffer, theme)),
+        ("html", to_html(buffer, &title, theme)),
+    ];
+    let mut written = Vec::new();
+    for (extension, content) in files {
+        let path = stem.with_extension(extension);
+        fs::write(&path, content)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn span_html(style: &str, text: &str) -> String {
+    if style.is_empty() {
+        escape_html(text)
+    } else {
+        format!("<span style=\"{}\">{}</span>", style, escape_html(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{layout::Rect, style::Style};
+
+    #[test]
+    fn test_text_and_html_reports() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        buffer.set_string(0, 0, "Hi <b>", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+        buffer.set_string(0, 1, "🦀ok", Style::default());
+
+        assert_eq!(to_text(&buffer), "Hi <b>\n🦀ok\n");
+
+        let html = to_html(&buffer, "Report", &ReportTheme::default());
+        assert!(html.contains("<span style=\"color:#cd3131;font-weight:bold\">Hi &lt;b&gt;</span>"));
+        assert!(html.contains("--primary: #268bd2"));
+        assert!(html.contains("🦀ok\n"));
+    }
+
+    #[test]
+    fn test_ansi_and_svg_screenshots() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_string(0, 0, "ab", Style::default().fg(Color::Indexed(200)).bg(Color::Blue));
+        buffer.set_string(2, 0, "c", Style::default());
+
+        assert_eq!(to_ansi(&buffer), "\x1b[0;38;5;200;44mab\x1b[0mc \n");
+
+        let svg = to_svg(&buffer, &ReportTheme::default());
+        assert!(svg.contains("width=\"36\" height=\"18\""));
+        assert!(svg.contains("<rect x=\"9\" y=\"0\" width=\"9\" height=\"18\" style=\"fill:var(--primary)\"/>"));
+        assert!(svg.contains(">c</text>"));
+    }
+}
@@ -0,0 +1,159 @@
+//! Rich text module
+//!
+//! URL and email detection for plain text, styling of the detected links and
+//! hit-testing of where they were drawn, so Paragraph-based views can open a
+//! link on click and highlight it on hover.
+
+use once_cell::sync::Lazy;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use regex::Regex;
+
+use crate::event::{MouseButton, MouseEvent, MouseEventKind};
+
+static URL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"https?://[^\s<>\x22']+").unwrap());
+
+static EMAIL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b").unwrap());
+
+/// A link found in a piece of text; `start` and `end` are byte offsets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    pub text: String,
+    pub url: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Find URLs and email addresses in `text`, ordered by position
+pub fn detect_links(text: &str) -> Vec<Link> {
+    let mut links: Vec<Link> = URL_REGEX
+        .find_iter(text)
+        .map(|m| {
+            // Sentence punctuation right after a URL is not part of it
+            let url = m.as_str().trim_end_matches(['.', ',', ';', ':', '!', '?', ')', ']']);
+            Link {
+                text: url.to_string(),
+                url: url.to_string(),
+                start: m.start(),
+                end: m.start() + url.len(),
+            }
+        })
+        .collect();
+
+    let emails: Vec<Link> = EMAIL_REGEX
+        .find_iter(text)
+        .filter(|m| !links.iter().any(|link| m.start() < link.end && link.start < m.end()))
+        .map(|m| Link {
+            text: m.as_str().to_string(),
+            url: format!("mailto:{}", m.as_str()),
+            start: m.start(),
+            end: m.end(),
+        })
+        .collect();
+    links.extend(emails);
+    links.sort_by_key(|link| link.start);
+    links
+}
+
+pub fn link_style(hovered: bool) -> Style {
+    let style = Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED);
+    if hovered {
+        style.fg(Color::Black).bg(Color::LightBlue)
+    } else {
+        style
+    }
+}
+
+/// Style `text` with its links highlighted; `hovered` is the URL under the mouse
+pub fn link_line(text: &str, links: &[Link], base: Style, hovered: Option<&str>) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for link in links {
+        if link.start > last {
+            spans.push(Span::styled(text[last..link.start].to_string(), base));
+        }
+        spans.push(Span::styled(link.text.clone(), base.patch(link_style(hovered == Some(link.url.as_str())))));
+        last = link.end;
+    }
+    if last < text.len() {
+        spans.push(Span::styled(text[last..].to_string(), base));
+    }
+    Line::from(spans)
+}
+
+/// Screen regions of rendered links, rebuilt on every frame
+#[derive(Debug, Default)]
+pub struct LinkRegions {
+    regions: Vec<(Rect, String)>,
+    /// URL of the link under the mouse pointer
+    pub hovered: Option<String>,
+}
+
+impl LinkRegions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the previous frame's regions; call before rendering
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    /// Record the links of `text` drawn unwrapped at column `x`, row `y`,
+    /// clipped to `bounds`
+    pub fn record(&mut self, x: u16, y: u16, text: &str, links: &[Link], bounds: Rect) {
+        if y < bounds.y || y >= bounds.bottom() {
+            return;
+        }
+        for link in links {
+            let start = x.saturating_add(Span::raw(&text[..link.start]).width() as u16);
+            let end = start.saturating_add(Span::raw(&link.text).width() as u16).min(bounds.right());
+            if start < end && start >= bounds.x {
+                self.regions.push((Rect::new(start, y, end - start, 1), link.url.clone()));
+            }
+        }
+    }
+
+    /// URL of the link drawn at a screen position
+    pub fn link_at(&self, column: u16, row: u16) -> Option<&str> {
+        self.regions
+            .iter()
+            .find(|(area, _)| area.contains((column, row).into()))
+            .map(|(_, url)| url.as_str())
+    }
+
+    /// Track hovering; returns the URL of a clicked link
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<String> {
+        match mouse.kind {
+            MouseEventKind::Moved => {
+                self.hovered = self.link_at(mouse.column, mouse.row).map(str::to_string);
+                None
+            }
+            MouseEventKind::Down(MouseButton::Left) => self.link_at(mouse.column, mouse.row).map(str::to_string),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_links() {
+        let links = detect_links("See https://example.com/a, or mail me@example.org.");
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].url, "https://example.com/a");
+        assert_eq!(links[1].url, "mailto:me@example.org");
+
+        let mut regions = LinkRegions::new();
+        let text = "go https://x.io";
+        regions.record(10, 2, text, &detect_links(text), Rect::new(0, 0, 80, 10));
+        assert_eq!(regions.link_at(13, 2), Some("https://x.io"));
+        assert_eq!(regions.link_at(12, 2), None);
+    }
+}
@@ -0,0 +1,248 @@
+//! Process runner module
+//!
+//! Runs an external command with its stdout and stderr piped into a
+//! scrollable log pane, with kill and restart. Used to build examples inside
+//! the TUI instead of letting cargo write over the screen.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::event::KeyCode;
+use crate::platform::{self, Process};
+
+use super::ansi::ansi_line;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::platform::native::run_built_binary;
+
+/// Oldest output lines are dropped beyond this count
+const MAX_LINES: usize = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone)]
+pub struct OutputLine {
+    pub stream: OutputStream,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunnerState {
+    Idle,
+    Running,
+    Exited(Option<i32>),
+    Killed,
+    Failed(String),
+}
+
+pub struct ProcessRunner {
+    pub title: String,
+    pub program: String,
+    pub args: Vec<String>,
+    pub lines: Vec<OutputLine>,
+    pub state: RunnerState,
+    /// Lines scrolled up from the bottom; 0 follows new output
+    pub scroll: usize,
+    child: Option<Box<dyn Process>>,
+    sender: Sender<OutputLine>,
+    receiver: Receiver<OutputLine>,
+    page_height: usize,
+}
+
+impl ProcessRunner {
+    pub fn new(title: &str, program: &str, args: &[&str]) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            title: title.to_string(),
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            lines: Vec::new(),
+            state: RunnerState::Idle,
+            scroll: 0,
+            child: None,
+            sender,
+            receiver,
+            page_height: 10,
+        }
+    }
+
+    /// Runner for a whitespace-separated command line such as `cargo check`
+    pub fn from_command_line(title: &str, command_line: &str) -> Option<Self> {
+        let mut parts = command_line.split_whitespace();
+        let program = parts.next()?;
+        let args: Vec<&str> = parts.collect();
+        Some(Self::new(title, program, &args))
+    }
+
+    /// Runner that builds one of this crate's binaries
+    pub fn cargo_build(binary_name: &str) -> Self {
+        Self::new(
+            &format!("Building {}", binary_name),
+            "cargo",
+            &["build", "--color", "always", "--bin", binary_name],
+        )
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.state == RunnerState::Running
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.state == RunnerState::Exited(Some(0))
+    }
+
+    /// Spawn the command, streaming both output pipes into the log
+    pub fn start(&mut self) {
+        if self.is_running() {
+            return;
+        }
+        self.lines.clear();
+        self.scroll = 0;
+
+        match platform::current().spawn(&self.program, &self.args, self.sender.clone()) {
+            Ok(child) => {
+                self.child = Some(child);
+                self.state = RunnerState::Running;
+            }
+            Err(e) => self.state = RunnerState::Failed(e),
+        }
+    }
+
+    pub fn kill(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            child.kill();
+            self.state = RunnerState::Killed;
+        }
+    }
+
+    pub fn restart(&mut self) {
+        self.kill();
+        self.start();
+    }
+
+    /// Collect new output and detect exit; call once per tick
+    pub fn poll(&mut self) {
+        while let Ok(line) = self.receiver.try_recv() {
+            self.lines.push(line);
+            if self.scroll > 0 {
+                self.scroll += 1;
+            }
+        }
+        if self.lines.len() > MAX_LINES {
+            let excess = self.lines.len() - MAX_LINES;
+            self.lines.drain(..excess);
+        }
+
+        if let Some(child) = self.child.as_mut() {
+            match child.try_wait() {
+                Ok(Some(code)) => {
+                    self.state = RunnerState::Exited(code);
+                    self.child = None;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.state = RunnerState::Failed(e);
+                    self.child = None;
+                }
+            }
+        }
+    }
+
+    fn scroll_by(&mut self, delta: isize) {
+        let max = self.lines.len().saturating_sub(self.page_height) as isize;
+        self.scroll = (self.scroll as isize + delta).clamp(0, max) as usize;
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        let page = self.page_height as isize;
+        match key {
+            KeyCode::Up => self.scroll_by(1),
+            KeyCode::Down => self.scroll_by(-1),
+            KeyCode::PageUp => self.scroll_by(page),
+            KeyCode::PageDown => self.scroll_by(-page),
+            KeyCode::End => self.scroll = 0,
+            KeyCode::Char('k') => self.kill(),
+            KeyCode::Char('r') => self.restart(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Short state description such as `exited 0` or `killed`
+    pub fn status_text(&self) -> String {
+        self.status().0
+    }
+
+    fn status(&self) -> (String, Color) {
+        match &self.state {
+            RunnerState::Idle => ("idle".to_string(), Color::Gray),
+            RunnerState::Running => ("running".to_string(), Color::Cyan),
+            RunnerState::Exited(Some(0)) => ("exited 0".to_string(), Color::Green),
+            RunnerState::Exited(Some(code)) => (format!("exited {}", code), Color::Red),
+            RunnerState::Exited(None) => ("terminated".to_string(), Color::Red),
+            RunnerState::Killed => ("killed".to_string(), Color::Yellow),
+            RunnerState::Failed(error) => (format!("failed: {}", error), Color::Red),
+        }
+    }
+
+    fn status_span(&self) -> Span<'static> {
+        let (text, color) = self.status();
+        Span::styled(format!(" [{}] ", text), Style::default().fg(color).add_modifier(Modifier::BOLD))
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Line::from(vec![Span::raw(format!(" {} ", self.title)), self.status_span()]))
+            .title_bottom("↑↓ PgUp/PgDn: scroll | End: follow | k: kill | r: restart | Esc: close")
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(area);
+        self.page_height = inner.height.max(1) as usize;
+
+        let end = self.lines.len().saturating_sub(self.scroll);
+        let start = end.saturating_sub(self.page_height);
+        let lines: Vec<Line> = self.lines[start..end]
+            .iter()
+            .map(|line| {
+                let style = match line.stream {
+                    OutputStream::Stdout => Style::default(),
+                    OutputStream::Stderr => Style::default().fg(Color::Gray),
+                };
+                ansi_line(&line.text, style)
+            })
+            .collect();
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+impl Drop for ProcessRunner {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_command_line() {
+        let runner = ProcessRunner::from_command_line("Check", "cargo check --all").unwrap();
+        assert_eq!(runner.program, "cargo");
+        assert_eq!(runner.args, vec!["check", "--all"]);
+        assert!(ProcessRunner::from_command_line("Empty", "  ").is_none());
+    }
+}
@@ -0,0 +1,294 @@
+//! Scatter plot widget module
+//!
+//! Points chart built on ratatui's `Chart`/`Dataset` with a zoomable,
+//! pannable axis window and a crosshair cursor that snaps a readout to the
+//! nearest data point.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType},
+    Frame,
+};
+
+use crate::event::KeyCode;
+
+/// Minimum span of an axis window, prevents zooming into a degenerate range
+const MIN_SPAN: f64 = 0.01;
+
+/// Factor applied to the axis window per zoom step
+const ZOOM_FACTOR: f64 = 0.8;
+
+/// Fraction of the visible window moved per pan step
+const PAN_FRACTION: f64 = 0.1;
+
+/// Number of cursor steps across the visible window
+const CURSOR_STEPS: f64 = 40.0;
+
+/// Scatter plot with zoom/pan and a crosshair cursor
+pub struct ScatterPlot {
+    pub title: String,
+    pub points: Vec<(f64, f64)>,
+    pub x_bounds: [f64; 2],
+    pub y_bounds: [f64; 2],
+    pub cursor: (f64, f64),
+    home_x: [f64; 2],
+    home_y: [f64; 2],
+}
+
+impl ScatterPlot {
+    pub fn new(title: &str, points: Vec<(f64, f64)>) -> Self {
+        let (x_bounds, y_bounds) = Self::fit_bounds(&points);
+        let cursor = (
+            (x_bounds[0] + x_bounds[1]) / 2.0,
+            (y_bounds[0] + y_bounds[1]) / 2.0,
+        );
+
+        Self {
+            title: title.to_string(),
+            points,
+            x_bounds,
+            y_bounds,
+            cursor,
+            home_x: x_bounds,
+            home_y: y_bounds,
+        }
+    }
+
+    /// Compute axis bounds enclosing all points with a small margin
+    fn fit_bounds(points: &[(f64, f64)]) -> ([f64; 2], [f64; 2]) {
+        if points.is_empty() {
+            return ([0.0, 1.0], [0.0, 1.0]);
+        }
+
+        let (mut x_min, mut x_max) = (f64::MAX, f64::MIN);
+        let (mut y_min, mut y_max) = (f64::MAX, f64::MIN);
+        for &(x, y) in points {
+            x_min = x_min.min(x);
+            x_max = x_max.max(x);
+            y_min = y_min.min(y);
+            y_max = y_max.max(y);
+        }
+
+        let x_pad = ((x_max - x_min) * 0.05).max(MIN_SPAN);
+        let y_pad = ((y_max - y_min) * 0.05).max(MIN_SPAN);
+        ([x_min - x_pad, x_max + x_pad], [y_min - y_pad, y_max + y_pad])
+    }
+
+    fn span(bounds: [f64; 2]) -> f64 {
+        bounds[1] - bounds[0]
+    }
+
+    /// Zoom the axis window around the cursor
+    pub fn zoom(&mut self, zoom_in: bool) {
+        let factor = if zoom_in { ZOOM_FACTOR } else { 1.0 / ZOOM_FACTOR };
+        self.x_bounds = Self::scale_around(self.x_bounds, self.cursor.0, factor);
+        self.y_bounds = Self::scale_around(self.y_bounds, self.cursor.1, factor);
+    }
+
+    fn scale_around(bounds: [f64; 2], center: f64, factor: f64) -> [f64; 2] {
+        let span = (Self::span(bounds) * factor).max(MIN_SPAN);
+        let ratio = (center - bounds[0]) / Self::span(bounds);
+        let start = center - span * ratio;
+        [start, start + span]
+    }
+
+    /// Pan the axis window by a fraction of its size
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        let x_step = Self::span(self.x_bounds) * PAN_FRACTION * dx;
+        let y_step = Self::span(self.y_bounds) * PAN_FRACTION * dy;
+        self.x_bounds = [self.x_bounds[0] + x_step, self.x_bounds[1] + x_step];
+        self.y_bounds = [self.y_bounds[0] + y_step, self.y_bounds[1] + y_step];
+        self.cursor = (self.cursor.0 + x_step, self.cursor.1 + y_step);
+    }
+
+    /// Move the crosshair cursor, keeping it inside the visible window
+    pub fn move_cursor(&mut self, dx: f64, dy: f64) {
+        let x = self.cursor.0 + Self::span(self.x_bounds) / CURSOR_STEPS * dx;
+        let y = self.cursor.1 + Self::span(self.y_bounds) / CURSOR_STEPS * dy;
+        self.cursor = (
+            x.clamp(self.x_bounds[0], self.x_bounds[1]),
+            y.clamp(self.y_bounds[0], self.y_bounds[1]),
+        );
+    }
+
+    /// Restore the initial axis window
+    pub fn reset_view(&mut self) {
+        self.x_bounds = self.home_x;
+        self.y_bounds = self.home_y;
+        self.cursor = (
+            (self.x_bounds[0] + self.x_bounds[1]) / 2.0,
+            (self.y_bounds[0] + self.y_bounds[1]) / 2.0,
+        );
+    }
+
+    /// Points inside the current window
+    fn visible_points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.points.iter().copied().filter(|&(x, y)| {
+            x >= self.x_bounds[0] && x <= self.x_bounds[1] && y >= self.y_bounds[0] && y <= self.y_bounds[1]
+        })
+    }
+
+    /// Find the visible data point closest to the cursor, normalised by the
+    /// window size
+    pub fn nearest_point(&self) -> Option<(f64, f64)> {
+        let x_span = Self::span(self.x_bounds);
+        let y_span = Self::span(self.y_bounds);
+
+        self.visible_points()
+            .map(|(x, y)| {
+                let dx = (x - self.cursor.0) / x_span;
+                let dy = (y - self.cursor.1) / y_span;
+                ((x, y), dx * dx + dy * dy)
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(point, _)| point)
+    }
+
+    /// Status line describing the cursor and nearest point
+    pub fn readout(&self) -> String {
+        match self.nearest_point() {
+            Some((x, y)) => format!(
+                "Cursor ({:.2}, {:.2}) | Nearest point ({:.2}, {:.2})",
+                self.cursor.0, self.cursor.1, x, y
+            ),
+            None => format!("Cursor ({:.2}, {:.2}) | No data", self.cursor.0, self.cursor.1),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Char('+') | KeyCode::Char('=') => self.zoom(true),
+            KeyCode::Char('-') => self.zoom(false),
+            KeyCode::Char('a') => self.pan(-1.0, 0.0),
+            KeyCode::Char('d') => self.pan(1.0, 0.0),
+            KeyCode::Char('w') => self.pan(0.0, 1.0),
+            KeyCode::Char('s') => self.pan(0.0, -1.0),
+            KeyCode::Left => self.move_cursor(-1.0, 0.0),
+            KeyCode::Right => self.move_cursor(1.0, 0.0),
+            KeyCode::Up => self.move_cursor(0.0, 1.0),
+            KeyCode::Down => self.move_cursor(0.0, -1.0),
+            KeyCode::Char('0') => self.reset_view(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn axis_labels(bounds: [f64; 2]) -> Vec<Span<'static>> {
+        let mid = (bounds[0] + bounds[1]) / 2.0;
+        vec![
+            Span::from(format!("{:.1}", bounds[0])),
+            Span::from(format!("{:.1}", mid)),
+            Span::from(format!("{:.1}", bounds[1])),
+        ]
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let visible: Vec<(f64, f64)> = self.visible_points().collect();
+        let h_line = [(self.x_bounds[0], self.cursor.1), (self.x_bounds[1], self.cursor.1)];
+        let v_line = [(self.cursor.0, self.y_bounds[0]), (self.cursor.0, self.y_bounds[1])];
+        let nearest: Vec<(f64, f64)> = self.nearest_point().into_iter().collect();
+
+        let crosshair_style = Style::default().fg(Color::DarkGray);
+        let datasets = vec![
+            Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(crosshair_style)
+                .data(&h_line),
+            Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(crosshair_style)
+                .data(&v_line),
+            Dataset::default()
+                .name("Points")
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Scatter)
+                .style(Style::default().fg(Color::Cyan))
+                .data(&visible),
+            Dataset::default()
+                .marker(symbols::Marker::Block)
+                .graph_type(GraphType::Scatter)
+                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                .data(&nearest),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(Block::default().borders(Borders::ALL).title(self.title.clone()))
+            .x_axis(
+                Axis::default()
+                    .title("X")
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds(self.x_bounds)
+                    .labels(Self::axis_labels(self.x_bounds)),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("Y")
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds(self.y_bounds)
+                    .labels(Self::axis_labels(self.y_bounds)),
+            );
+
+        frame.render_widget(chart, area);
+    }
+}
+
+/// Create a scatter plot with a deterministic sample cloud
+pub fn create_demo_scatter() -> ScatterPlot {
+    let points = (0..60)
+        .map(|i| {
+            let x = i as f64 * 0.25;
+            let y = (x * 0.8).sin() * 5.0 + ((i * 37) % 11) as f64 * 0.3;
+            (x, y)
+        })
+        .collect();
+
+    ScatterPlot::new("Scatter Plot", points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_shrinks_window() {
+        let mut plot = create_demo_scatter();
+        let before = ScatterPlot::span(plot.x_bounds);
+        plot.zoom(true);
+        assert!(ScatterPlot::span(plot.x_bounds) < before);
+        plot.reset_view();
+        assert_eq!(ScatterPlot::span(plot.x_bounds), before);
+    }
+
+    #[test]
+    fn test_nearest_point() {
+        let mut plot = ScatterPlot::new("Test", vec![(0.0, 0.0), (10.0, 10.0)]);
+        plot.cursor = (9.0, 9.5);
+        assert_eq!(plot.nearest_point(), Some((10.0, 10.0)));
+        plot.cursor = (1.0, 0.5);
+        assert_eq!(plot.nearest_point(), Some((0.0, 0.0)));
+
+        // With the origin outside the window, the drawn point wins even
+        // though the origin is closer
+        plot.x_bounds = [4.0, 12.0];
+        plot.y_bounds = [4.0, 12.0];
+        plot.cursor = (4.5, 4.5);
+        assert_eq!(plot.nearest_point(), Some((10.0, 10.0)));
+        plot.x_bounds = [20.0, 30.0];
+        assert_eq!(plot.nearest_point(), None);
+    }
+
+    #[test]
+    fn test_cursor_stays_in_window() {
+        let mut plot = ScatterPlot::new("Test", vec![(0.0, 0.0), (1.0, 1.0)]);
+        for _ in 0..100 {
+            plot.move_cursor(1.0, 1.0);
+        }
+        assert!(plot.cursor.0 <= plot.x_bounds[1]);
+        assert!(plot.cursor.1 <= plot.y_bounds[1]);
+    }
+}
@@ -0,0 +1,226 @@
+//! Schema form module
+//!
+//! Builds a `FormDialog` from a JSON Schema object so settings screens can be
+//! generated instead of hand-written. Strings, numbers and integers become
+//! text fields, booleans and enums become selects, and arrays of strings are
+//! edited as comma-separated text. `required`, `minimum`/`maximum`,
+//! `minLength`/`maxLength` and `pattern` map to form validation. Properties of
+//! other types are left out of the form and untouched when values are applied.
+//! Fields follow the schema's property order.
+
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use super::form::{FormDialog, FormField, Validator};
+
+/// How a form field's text converts back to JSON
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldKind {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    /// Allowed values, listed in the select by their display text
+    Enum(Vec<Value>),
+    /// Array of strings, edited as comma-separated text
+    StringList,
+}
+
+/// Property behind a generated form field
+#[derive(Debug, Clone)]
+pub struct SchemaField {
+    pub key: String,
+    pub label: String,
+    pub kind: FieldKind,
+}
+
+pub struct SchemaForm {
+    pub form: FormDialog,
+    pub fields: Vec<SchemaField>,
+}
+
+/// Text shown for a JSON value; strings without quotes
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items.iter().map(display).collect::<Vec<_>>().join(", "),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn kind_of(property: &Value) -> Option<FieldKind> {
+    if let Some(options) = property.get("enum").and_then(Value::as_array) {
+        return Some(FieldKind::Enum(options.clone()));
+    }
+    match property.get("type").and_then(Value::as_str)? {
+        "string" => Some(FieldKind::String),
+        "integer" => Some(FieldKind::Integer),
+        "number" => Some(FieldKind::Number),
+        "boolean" => Some(FieldKind::Boolean),
+        "array" if property.pointer("/items/type").and_then(Value::as_str) == Some("string") => {
+            Some(FieldKind::StringList)
+        }
+        _ => None,
+    }
+}
+
+/// Form field for one property with its constraints as validators
+fn field_for(label: &str, kind: &FieldKind, property: &Value, value: &str) -> Result<FormField, String> {
+    let mut field = match kind {
+        FieldKind::Boolean => FormField::select(label, &["true", "false"], value),
+        FieldKind::Enum(options) => {
+            let options: Vec<String> = options.iter().map(display).collect();
+            let options: Vec<&str> = options.iter().map(String::as_str).collect();
+            FormField::select(label, &options, value)
+        }
+        FieldKind::Integer => FormField::new(label, value).validator(Validator::Integer),
+        FieldKind::Number => FormField::new(label, value).validator(Validator::Number),
+        FieldKind::String | FieldKind::StringList => FormField::new(label, value),
+    };
+    if let Some(min) = property.get("minimum").and_then(Value::as_f64) {
+        field = field.validator(Validator::Min(min));
+    }
+    if let Some(max) = property.get("maximum").and_then(Value::as_f64) {
+        field = field.validator(Validator::Max(max));
+    }
+    if let Some(min) = property.get("minLength").and_then(Value::as_u64) {
+        field = field.validator(Validator::MinLength(min as usize));
+    }
+    if let Some(max) = property.get("maxLength").and_then(Value::as_u64) {
+        field = field.max_len(max as usize);
+    }
+    if let Some(pattern) = property.get("pattern").and_then(Value::as_str) {
+        let regex = Regex::new(pattern).map_err(|e| format!("Invalid pattern for {}: {}", label, e))?;
+        field = field.validator(Validator::Pattern(regex));
+    }
+    Ok(field)
+}
+
+impl SchemaForm {
+    /// Form for the object `schema`, filled from `values` or each property's
+    /// `default`. Properties are labelled with their `title` when present.
+    pub fn from_schema(title: &str, schema: &Value, values: &Value) -> Result<Self, String> {
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .ok_or("Schema has no properties")?;
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|keys| keys.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut form_fields = Vec::new();
+        let mut fields = Vec::new();
+        for (key, property) in properties {
+            let Some(kind) = kind_of(property) else {
+                continue;
+            };
+            let label = property.get("title").and_then(Value::as_str).unwrap_or(key).to_string();
+            let value = values.get(key).or_else(|| property.get("default")).map(display).unwrap_or_default();
+            let mut field = field_for(&label, &kind, property, &value)?;
+            if required.contains(&key.as_str()) && !field.is_select() {
+                field = field.required();
+            }
+            form_fields.push(field);
+            fields.push(SchemaField {
+                key: key.clone(),
+                label,
+                kind,
+            });
+        }
+        Ok(Self {
+            form: FormDialog::new(title, form_fields),
+            fields,
+        })
+    }
+
+    /// Typed values of the form; empty numbers are left out
+    pub fn values(&self) -> Result<Map<String, Value>, String> {
+        let mut values = Map::new();
+        for field in &self.fields {
+            let text = self.form.value(&field.label).unwrap_or_default().trim();
+            let invalid = || format!("{} has an invalid value", field.label);
+            let value = match &field.kind {
+                FieldKind::String => Value::String(text.to_string()),
+                FieldKind::StringList => Value::Array(
+                    text.split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(|item| Value::String(item.to_string()))
+                        .collect(),
+                ),
+                FieldKind::Integer | FieldKind::Number if text.is_empty() => continue,
+                FieldKind::Integer => Value::from(text.parse::<i64>().map_err(|_| invalid())?),
+                FieldKind::Number => Value::from(text.parse::<f64>().map_err(|_| invalid())?),
+                FieldKind::Boolean => Value::Bool(text == "true"),
+                FieldKind::Enum(options) => options
+                    .iter()
+                    .find(|option| display(option) == text)
+                    .cloned()
+                    .ok_or_else(invalid)?,
+            };
+            values.insert(field.key.clone(), value);
+        }
+        Ok(values)
+    }
+
+    /// Write the form's values into the object `target`, keeping other keys
+    pub fn apply(&self, target: &mut Value) -> Result<(), String> {
+        let object = target.as_object_mut().ok_or("Target is not an object")?;
+        object.extend(self.values()?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::form::FormAction;
+    use crate::event::KeyCode;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_maps_types_and_constraints() {
+        let schema = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string", "title": "Name", "maxLength": 10 },
+                "retries": { "type": "integer", "minimum": 0, "maximum": 5, "default": 3 },
+                "verbose": { "type": "boolean" },
+                "level": { "enum": ["low", "high"] },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "nested": { "type": "object" }
+            }
+        });
+        let mut form = SchemaForm::from_schema("Settings", &schema, &json!({ "name": "app", "verbose": true })).unwrap();
+        assert_eq!(form.fields.len(), 5);
+        assert!(form.form.fields.iter().any(|field| field.label == "Name" && field.required));
+
+        let retries = form.form.fields.iter().position(|field| field.label == "retries").unwrap();
+        form.form.fields[retries].value = "9".to_string();
+        assert_eq!(form.form.handle_key(KeyCode::Enter), FormAction::None);
+        assert!(form.form.error.as_deref().unwrap_or_default().contains("at most 5"));
+
+        form.form.fields[retries].value = "2".to_string();
+        let tags = form.form.fields.iter().position(|field| field.label == "tags").unwrap();
+        form.form.fields[tags].value = "a, b,".to_string();
+        assert_eq!(form.form.handle_key(KeyCode::Enter), FormAction::Submit);
+
+        let mut target = json!({ "nested": { "kept": 1 } });
+        form.apply(&mut target).unwrap();
+        assert_eq!(
+            target,
+            json!({
+                "name": "app",
+                "retries": 2,
+                "verbose": true,
+                "level": "low",
+                "tags": ["a", "b"],
+                "nested": { "kept": 1 }
+            })
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Screen search module
+//!
+//! Ctrl+F overlay (Alt+S with the emacs keymap, where Ctrl+F moves the
+//! cursor) that searches whatever the active screen has drawn:
+//! table cells, list items, help text and so on. Matches are found in the
+//! frame buffer itself, so no screen has to expose its content, and are
+//! highlighted in place with the current one picked out. Enter or ↓ moves
+//! to the next match, ↑ to the previous one.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+use regex::Regex;
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::keymap::KeymapProfile;
+use super::line_edit::edit_at_end;
+use super::search::build_pattern;
+
+/// A match on screen, in terminal cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenMatch {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+}
+
+/// Matches of `regex` in `area` of `buffer`, row by row, left to right
+pub fn find_in_buffer(buffer: &Buffer, area: Rect, regex: &Regex) -> Vec<ScreenMatch> {
+    let mut matches = Vec::new();
+    for y in area.top()..area.bottom() {
+        // Row text plus the column each byte of it came from
+        let mut text = String::new();
+        let mut columns = Vec::new();
+        for x in area.left()..area.right() {
+            let symbol = buffer[(x, y)].symbol();
+            text.push_str(symbol);
+            columns.extend(std::iter::repeat_n(x, symbol.len()));
+        }
+        columns.push(area.right());
+        for found in regex.find_iter(&text).filter(|found| !found.is_empty()) {
+            let (start, end) = (columns[found.start()], columns[found.end()]);
+            matches.push(ScreenMatch { x: start, y, width: end - start });
+        }
+    }
+    matches
+}
+
+#[derive(Debug, Default)]
+pub struct ScreenSearch {
+    pub visible: bool,
+    pub query: String,
+    /// Index of the current match
+    current: usize,
+    /// Matches found in the last rendered frame
+    match_count: usize,
+}
+
+impl ScreenSearch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Key that opens the search under `profile`, and its label
+    pub fn shortcut(profile: KeymapProfile) -> (KeyEvent, &'static str) {
+        match profile {
+            KeymapProfile::Emacs => (KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT), "Alt+S"),
+            KeymapProfile::Default | KeymapProfile::Vim => {
+                (KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL), "Ctrl+F")
+            }
+        }
+    }
+
+    pub fn is_shortcut(key: &KeyEvent, profile: KeymapProfile) -> bool {
+        let (shortcut, _) = Self::shortcut(profile);
+        key.code == shortcut.code && key.modifiers.contains(shortcut.modifiers)
+    }
+
+    /// Show the search bar, keeping the previous query
+    pub fn open(&mut self) {
+        self.visible = true;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    pub fn next(&mut self) {
+        if self.match_count > 0 {
+            self.current = (self.current + 1) % self.match_count;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if self.match_count > 0 {
+            self.current = (self.current + self.match_count - 1) % self.match_count;
+        }
+    }
+
+    /// Handle a key while the bar is open; the host closes it when the
+    /// shortcut is pressed again
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.close(),
+            KeyCode::Enter if key.modifiers.contains(KeyModifiers::SHIFT) => self.previous(),
+            KeyCode::Enter | KeyCode::Down => self.next(),
+            KeyCode::Up => self.previous(),
+            _ => {
+                if edit_at_end(&mut self.query, key) {
+                    self.current = 0;
+                }
+            }
+        }
+    }
+
+    /// Literal, smart-case pattern for the query
+    fn pattern(&self) -> Option<Regex> {
+        if self.query.is_empty() {
+            return None;
+        }
+        build_pattern(&regex::escape(&self.query)).ok()
+    }
+
+    /// Highlight matches in what the screen drew and show the search bar
+    /// on the bottom row; call last when drawing
+    pub fn render(&mut self, frame: &mut Frame) {
+        if !self.visible {
+            return;
+        }
+        let area = frame.area();
+        if area.height < 2 {
+            return;
+        }
+        let content = Rect { height: area.height - 1, ..area };
+        let bar = Rect { y: area.bottom() - 1, height: 1, ..area };
+
+        let matches = self
+            .pattern()
+            .map(|regex| find_in_buffer(frame.buffer_mut(), content, &regex))
+            .unwrap_or_default();
+        self.match_count = matches.len();
+        self.current = self.current.min(matches.len().saturating_sub(1));
+        let buffer = frame.buffer_mut();
+        for (index, found) in matches.iter().enumerate() {
+            let style = if index == self.current {
+                Style::default().fg(Color::Black).bg(Color::LightRed).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            };
+            buffer.set_style(Rect::new(found.x, found.y, found.width, 1), style);
+        }
+
+        let count = match (self.query.is_empty(), matches.len()) {
+            (true, _) => String::new(),
+            (false, 0) => "no matches".to_string(),
+            (false, total) => format!("{}/{}", self.current + 1, total),
+        };
+        let line = Line::from(vec![
+            Span::styled(" 🔍 ", Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{}▏", self.query), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("  {}", count), Style::default().fg(Color::Cyan)),
+            Span::styled("  Enter/↓: next | ↑: previous | Esc: close", Style::default().fg(Color::DarkGray)),
+        ]);
+        frame.render_widget(Clear, bar);
+        frame.render_widget(Paragraph::new(line).style(Style::default().bg(Color::Black)), bar);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_in_buffer_and_cycle() {
+        let buffer = Buffer::with_lines(["Name   Status", "alpha  Done", "Beta   done"]);
+        let regex = build_pattern("done").unwrap();
+        let matches = find_in_buffer(&buffer, buffer.area, &regex);
+        assert_eq!(
+            matches,
+            vec![ScreenMatch { x: 7, y: 1, width: 4 }, ScreenMatch { x: 7, y: 2, width: 4 }]
+        );
+
+        let mut search = ScreenSearch::new();
+        search.match_count = matches.len();
+        search.previous();
+        assert_eq!(search.current, 1);
+        search.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(search.current, 0);
+        search.handle_key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+        assert_eq!(search.query, "x");
+        search.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert!(!search.visible);
+    }
+}
@@ -0,0 +1,408 @@
+//! Project search module
+//!
+//! Overlay that greps a directory tree for a pattern on a background task.
+//! The walk honours `.gitignore` and hidden-file rules, and matches stream
+//! into a list grouped by file while the search is still running.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use regex::{Regex, RegexBuilder};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::line_edit::edit_at_end;
+use super::progress::{TaskHandle, TaskManager, TaskOutcome};
+use super::spinner::{Spinner, SpinnerStyle};
+
+/// Search stops once this many matching lines have been found
+const MAX_MATCHES: usize = 2000;
+
+/// Files larger than this are skipped
+const MAX_FILE_SIZE: u64 = 2 * 1024 * 1024;
+
+/// Matched lines are cut to this many characters in the list
+const MAX_LINE_CHARS: usize = 200;
+
+/// One matching line; `line` is zero-based
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineMatch {
+    pub line: usize,
+    pub text: String,
+    /// Byte ranges of the matches within `text`
+    pub ranges: Vec<(usize, usize)>,
+}
+
+/// All matches in one file, relative to the search root
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileMatches {
+    pub path: PathBuf,
+    pub matches: Vec<LineMatch>,
+}
+
+/// Result of a key press in the search overlay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchAction {
+    None,
+    /// Open the file at the given zero-based line
+    Open(PathBuf, usize),
+    Close,
+}
+
+/// Build a regex for `pattern`, case-insensitive unless it contains uppercase
+pub fn build_pattern(pattern: &str) -> Result<Regex, String> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(!pattern.chars().any(char::is_uppercase))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Matching lines of `content`, skipping content that looks binary
+pub fn find_matches(regex: &Regex, content: &str) -> Vec<LineMatch> {
+    if content.contains('\0') {
+        return Vec::new();
+    }
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(line, text)| {
+            let text = truncate(text, MAX_LINE_CHARS);
+            let ranges: Vec<(usize, usize)> = regex
+                .find_iter(text)
+                .filter(|m| !m.is_empty())
+                .map(|m| (m.start(), m.end()))
+                .collect();
+            (!ranges.is_empty()).then(|| LineMatch {
+                line,
+                text: text.to_string(),
+                ranges,
+            })
+        })
+        .collect()
+}
+
+fn truncate(text: &str, max_chars: usize) -> &str {
+    text.char_indices().nth(max_chars).map_or(text, |(end, _)| &text[..end])
+}
+
+/// Walk `root` and send each file's matches as soon as they are found.
+/// Returns the number of files searched.
+fn search_tree(
+    root: &Path,
+    regex: &Regex,
+    results: &Sender<FileMatches>,
+    handle: &TaskHandle<usize>,
+) -> Result<usize, String> {
+    let mut searched = 0;
+    let mut found = 0;
+
+    for entry in ignore::WalkBuilder::new(root).build().flatten() {
+        if handle.is_cancelled() {
+            return Err("cancelled".to_string());
+        }
+        if !entry.file_type().is_some_and(|kind| kind.is_file())
+            || entry.metadata().map_or(true, |meta| meta.len() > MAX_FILE_SIZE)
+        {
+            continue;
+        }
+        let Ok(bytes) = fs::read(entry.path()) else {
+            continue;
+        };
+        searched += 1;
+
+        let matches = find_matches(regex, &String::from_utf8_lossy(&bytes));
+        if !matches.is_empty() {
+            found += matches.len();
+            let path = entry.path().strip_prefix(root).unwrap_or(entry.path()).to_path_buf();
+            if results.send(FileMatches { path, matches }).is_err() {
+                // The overlay started another search and dropped our receiver
+                return Err("cancelled".to_string());
+            }
+        }
+        if searched % 50 == 0 {
+            handle.report_with(0.0, format!("{} files, {} matches", searched, found));
+        }
+        if found >= MAX_MATCHES {
+            break;
+        }
+    }
+    Ok(searched)
+}
+
+pub struct ProjectSearch {
+    pub visible: bool,
+    pub query: String,
+    root: PathBuf,
+    /// Query the current results belong to
+    searched: Option<String>,
+    results: Vec<FileMatches>,
+    /// Index into the flattened list of line matches
+    selected: usize,
+    receiver: Option<Receiver<FileMatches>>,
+    searcher: TaskManager<usize>,
+    files_searched: Option<usize>,
+    error: Option<String>,
+    spinner: Spinner,
+}
+
+impl ProjectSearch {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            visible: false,
+            query: String::new(),
+            root: root.into(),
+            searched: None,
+            results: Vec::new(),
+            selected: 0,
+            receiver: None,
+            searcher: TaskManager::new(),
+            files_searched: None,
+            error: None,
+            spinner: Spinner::new(SpinnerStyle::Braille, "Searching"),
+        }
+    }
+
+    /// Search rooted at the process's working directory
+    pub fn in_current_dir() -> Self {
+        Self::new(std::env::current_dir().unwrap_or_default())
+    }
+
+    /// Whether a key event is the Ctrl+G shortcut that opens the search
+    pub fn is_shortcut(key: &KeyEvent) -> bool {
+        key.code == KeyCode::Char('g') && key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Show the overlay, keeping the previous query and results
+    pub fn open(&mut self) {
+        self.visible = true;
+    }
+
+    pub fn is_searching(&self) -> bool {
+        self.searcher.is_busy()
+    }
+
+    pub fn match_count(&self) -> usize {
+        self.results.iter().map(|file| file.matches.len()).sum()
+    }
+
+    /// Start a new search for the current query, replacing any running one
+    pub fn search(&mut self) {
+        self.searcher.cancel_all();
+        self.results.clear();
+        self.selected = 0;
+        self.files_searched = None;
+        self.error = None;
+        self.searched = Some(self.query.clone());
+        if self.query.is_empty() {
+            self.receiver = None;
+            return;
+        }
+
+        let regex = match build_pattern(&self.query) {
+            Ok(regex) => regex,
+            Err(e) => {
+                self.receiver = None;
+                self.error = Some(e);
+                return;
+            }
+        };
+        let (sender, receiver) = mpsc::channel();
+        self.receiver = Some(receiver);
+        let root = self.root.clone();
+        self.searcher
+            .spawn("Searching", move |handle| search_tree(&root, &regex, &sender, handle));
+    }
+
+    /// Collect streamed matches and the final file count; call once per tick
+    pub fn poll(&mut self) {
+        if let Some(receiver) = &self.receiver {
+            self.results.extend(receiver.try_iter());
+        }
+        for outcome in self.searcher.poll() {
+            match outcome {
+                TaskOutcome::Finished { output, .. } => {
+                    if let Some(receiver) = &self.receiver {
+                        self.results.extend(receiver.try_iter());
+                    }
+                    self.files_searched = Some(output);
+                }
+                TaskOutcome::Failed { error, .. } => self.error = Some(error),
+                TaskOutcome::Cancelled { .. } => {}
+            }
+        }
+    }
+
+    /// File and line of the flattened match at `index`
+    fn match_at(&self, mut index: usize) -> Option<(&FileMatches, &LineMatch)> {
+        for file in &self.results {
+            if index < file.matches.len() {
+                return Some((file, &file.matches[index]));
+            }
+            index -= file.matches.len();
+        }
+        None
+    }
+
+    fn select_offset(&mut self, offset: isize) {
+        let len = self.match_count() as isize;
+        if len == 0 {
+            return;
+        }
+        self.selected = (self.selected as isize + offset).rem_euclid(len) as usize;
+    }
+
+    /// Jump to the first match of the next or previous file
+    fn select_file_offset(&mut self, forward: bool) {
+        let mut start = 0;
+        let starts: Vec<usize> = self
+            .results
+            .iter()
+            .map(|file| {
+                let first = start;
+                start += file.matches.len();
+                first
+            })
+            .collect();
+        let target = if forward {
+            starts.iter().find(|&&first| first > self.selected)
+        } else {
+            starts.iter().rev().find(|&&first| first < self.selected)
+        };
+        if let Some(&first) = target {
+            self.selected = first;
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SearchAction {
+        match key.code {
+            KeyCode::Esc => {
+                self.visible = false;
+                return SearchAction::Close;
+            }
+            KeyCode::Enter if self.searched.as_deref() != Some(self.query.as_str()) => self.search(),
+            KeyCode::Enter => {
+                if let Some((file, line)) = self.match_at(self.selected) {
+                    let action = SearchAction::Open(self.root.join(&file.path), line.line);
+                    self.visible = false;
+                    return action;
+                }
+            }
+            KeyCode::Up => self.select_offset(-1),
+            KeyCode::Down => self.select_offset(1),
+            KeyCode::PageUp => self.select_file_offset(false),
+            KeyCode::PageDown | KeyCode::Tab => self.select_file_offset(true),
+            _ => {
+                edit_at_end(&mut self.query, key);
+            }
+        }
+        SearchAction::None
+    }
+
+    /// Render as a centered popup over `area`
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+        let width = (area.width * 8 / 10).max(30).min(area.width);
+        let height = (area.height * 8 / 10).max(8).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let input_area = Rect { height: 3, ..popup };
+        let list_area = Rect {
+            y: popup.y + 3,
+            height: popup.height.saturating_sub(3),
+            ..popup
+        };
+
+        let input = Paragraph::new(format!("{}█", self.query)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🔎 Search in files (Ctrl+G)")
+                .title_bottom(" regex, case-insensitive unless the pattern has uppercase ")
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(input, input_area);
+
+        // File headers are plain rows; only match rows can be selected
+        let highlight = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        let mut items = Vec::new();
+        let mut selected_row = None;
+        let mut index = 0;
+        for file in &self.results {
+            items.push(ListItem::new(Line::styled(
+                format!("{} ({})", file.path.display(), file.matches.len()),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )));
+            for line in &file.matches {
+                if index == self.selected {
+                    selected_row = Some(items.len());
+                }
+                let mut spans = vec![Span::styled(
+                    format!("  {:>5}: ", line.line + 1),
+                    Style::default().fg(Color::DarkGray),
+                )];
+                let mut last = 0;
+                for &(start, end) in &line.ranges {
+                    spans.push(Span::raw(line.text[last..start].to_string()));
+                    spans.push(Span::styled(line.text[start..end].to_string(), highlight));
+                    last = end;
+                }
+                spans.push(Span::raw(line.text[last..].to_string()));
+                items.push(ListItem::new(Line::from(spans)));
+                index += 1;
+            }
+        }
+
+        let title = if let Some(error) = &self.error {
+            format!("⚠ {} ", error)
+        } else if self.searcher.is_busy() {
+            format!("{} {} ({} matches) ", self.spinner.current_frame(), self.spinner.label, self.match_count())
+        } else if let Some(files) = self.files_searched {
+            format!("{} matches in {} of {} files ", self.match_count(), self.results.len(), files)
+        } else {
+            "Type a pattern and press Enter ".to_string()
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .title_bottom("Enter: search/open | ↑↓: match | Tab/PgUp/PgDn: file | Esc: close"),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        let mut state = ListState::default().with_selected(selected_row);
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matches_smart_case() {
+        let content = "fn main() {\n    let Main = 1;\n}\n";
+        let lower = find_matches(&build_pattern("main").unwrap(), content);
+        assert_eq!(lower.iter().map(|m| m.line).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(lower[1].ranges, vec![(8, 12)]);
+
+        let upper = find_matches(&build_pattern("Main").unwrap(), content);
+        assert_eq!(upper.len(), 1);
+        assert!(find_matches(&build_pattern("main").unwrap(), "main\0binary").is_empty());
+        assert!(build_pattern("(").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+//! Seven-segment display module
+//!
+//! Large LED-style digits for clocks, countdowns and KPI numbers. The digit
+//! size is the largest that fits the area, and the segments are drawn with
+//! full blocks in a single style.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Widget},
+};
+
+/// Segments a to g as bits 0 to 6: top, upper right, lower right, bottom,
+/// lower left, upper left and middle
+fn segments(c: char) -> Option<u8> {
+    Some(match c {
+        '0' => 0x3F,
+        '1' => 0x06,
+        '2' => 0x5B,
+        '3' => 0x4F,
+        '4' => 0x66,
+        '5' => 0x6D,
+        '6' => 0x7D,
+        '7' => 0x07,
+        '8' => 0x7F,
+        '9' => 0x6F,
+        '-' => 0x40,
+        ' ' => 0x00,
+        _ => return None,
+    })
+}
+
+/// Digits, `-`, space, `:` and `.`; other characters are not drawn
+fn drawable(c: char) -> bool {
+    segments(c).is_some() || matches!(c, ':' | '.')
+}
+
+/// Width of vertical strokes; two columns look as thick as one row
+fn stroke(scale: u16) -> u16 {
+    scale.min(2)
+}
+
+/// Columns taken by `c` at `scale`, without the gap after it
+fn char_width(c: char, scale: u16) -> u16 {
+    match c {
+        ':' | '.' => stroke(scale),
+        _ => 3 * scale,
+    }
+}
+
+/// Lit cells of `text` at `scale`, as rows of `2 * scale + 1` booleans
+pub fn pixels(text: &str, scale: u16) -> Vec<Vec<bool>> {
+    let scale = scale.max(1);
+    let height = 2 * scale + 1;
+    let middle = scale;
+    let thick = stroke(scale);
+    let mut rows = vec![Vec::new(); height as usize];
+    let chars: Vec<char> = text.chars().filter(|&c| drawable(c)).collect();
+    for (i, &c) in chars.iter().enumerate() {
+        let width = char_width(c, scale);
+        for y in 0..height {
+            for x in 0..width {
+                let lit = match (c, segments(c)) {
+                    (':', _) => y == middle / 2 || y == middle + (height - middle) / 2,
+                    ('.', _) => y == height - 1,
+                    (_, Some(bits)) => {
+                        let on = |segment: u8| bits & (1 << segment) != 0;
+                        let left = x < thick;
+                        let right = x >= width - thick;
+                        let upper = y <= middle;
+                        let lower = y >= middle;
+                        (on(0) && y == 0)
+                            || (on(6) && y == middle)
+                            || (on(3) && y == height - 1)
+                            || (on(1) && right && upper)
+                            || (on(2) && right && lower)
+                            || (on(4) && left && lower)
+                            || (on(5) && left && upper)
+                    }
+                    _ => false,
+                };
+                rows[y as usize].push(lit);
+            }
+            if i + 1 < chars.len() {
+                rows[y as usize].push(false);
+            }
+        }
+    }
+    rows
+}
+
+/// Large digits centered in the area, e.g. `12:45` or `-3.5`
+pub struct SegmentDisplay<'a> {
+    text: String,
+    style: Style,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> SegmentDisplay<'a> {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: Style::default(),
+            block: None,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Columns needed for the text at `scale`
+    fn width_at(&self, scale: u16) -> u16 {
+        let widths: Vec<u16> = self
+            .text
+            .chars()
+            .filter(|&c| drawable(c))
+            .map(|c| char_width(c, scale))
+            .collect();
+        widths.iter().sum::<u16>() + widths.len().saturating_sub(1) as u16
+    }
+
+    /// Largest scale whose digits fit in `area`, if any
+    pub fn scale_for(&self, area: Rect) -> Option<u16> {
+        (1..=area.height.saturating_sub(1) / 2)
+            .rev()
+            .find(|&scale| self.width_at(scale) <= area.width)
+    }
+}
+
+impl Widget for SegmentDisplay<'_> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        let Some(scale) = self.scale_for(area) else {
+            // Too small for segments: plain text instead
+            buf.set_stringn(area.x, area.y, &self.text, area.width as usize, self.style);
+            return;
+        };
+        let rows = pixels(&self.text, scale);
+        let width = rows.first().map_or(0, Vec::len) as u16;
+        let x0 = area.x + (area.width - width) / 2;
+        let y0 = area.y + (area.height - rows.len() as u16) / 2;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &lit) in row.iter().enumerate() {
+                if lit {
+                    buf.set_string(x0 + x as u16, y0 + y as u16, "█", self.style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(rows: &[Vec<bool>]) -> Vec<String> {
+        rows.iter().map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect()).collect()
+    }
+
+    #[test]
+    fn test_digits_and_scaling() {
+        assert_eq!(draw(&pixels("1:7", 1)), vec!["..#.#.###", "..#.....#", "..#.#...#"]);
+        assert_eq!(draw(&pixels("8", 2)), vec!["######", "##..##", "######", "##..##", "######"]);
+
+        let display = SegmentDisplay::new("12:30");
+        // 4 digits of 3×scale, a colon of 2 and 4 gaps
+        assert_eq!(display.width_at(2), 4 * 6 + 2 + 4);
+        assert_eq!(display.scale_for(Rect::new(0, 0, 30, 20)), Some(2));
+        assert_eq!(display.scale_for(Rect::new(0, 0, 10, 20)), None);
+    }
+}
@@ -0,0 +1,223 @@
+//! Text selection module
+//!
+//! Mouse-drag selection over lines of text: maps screen cells to character
+//! positions using display widths, extracts the selected (possibly
+//! multi-line) text and highlights it in already-styled lines.
+
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use crate::event::{MouseButton, MouseEvent, MouseEventKind};
+
+/// Position in a text buffer; `col` counts characters, not bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TextPos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl TextPos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+pub fn selection_style() -> Style {
+    Style::default().fg(Color::Black).bg(Color::LightCyan)
+}
+
+/// Display width of a single character
+fn char_width(c: char) -> usize {
+    Span::raw(c.to_string()).width()
+}
+
+/// Character index under display column `column` of `text`, clamped to its end
+pub fn char_at_column(text: &str, column: usize) -> usize {
+    let mut width = 0;
+    for (i, c) in text.chars().enumerate() {
+        width += char_width(c);
+        if width > column {
+            return i;
+        }
+    }
+    text.chars().count()
+}
+
+/// Display column where character `col` of `text` starts
+pub fn column_of_char(text: &str, col: usize) -> usize {
+    text.chars().take(col).map(char_width).sum()
+}
+
+/// Byte offset of character `col` in `text`, clamped to its length
+pub fn byte_index(text: &str, col: usize) -> usize {
+    text.char_indices().nth(col).map_or(text.len(), |(i, _)| i)
+}
+
+/// Anchor and head of a selection; empty while they are equal
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    anchor: Option<TextPos>,
+    head: Option<TextPos>,
+    dragging: bool,
+}
+
+impl Selection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&mut self, pos: TextPos) {
+        self.anchor = Some(pos);
+        self.head = Some(pos);
+        self.dragging = true;
+    }
+
+    pub fn extend(&mut self, pos: TextPos) {
+        if self.anchor.is_some() {
+            self.head = Some(pos);
+        }
+    }
+
+    pub fn select(&mut self, from: TextPos, to: TextPos) {
+        self.anchor = Some(from);
+        self.head = Some(to);
+        self.dragging = false;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Ordered start and end (exclusive) of a non-empty selection
+    pub fn range(&self) -> Option<(TextPos, TextPos)> {
+        let (anchor, head) = (self.anchor?, self.head?);
+        match anchor.cmp(&head) {
+            std::cmp::Ordering::Less => Some((anchor, head)),
+            std::cmp::Ordering::Greater => Some((head, anchor)),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range().is_none()
+    }
+
+    /// Selected character range on `row`, for a line of `len` characters
+    pub fn columns_on(&self, row: usize, len: usize) -> Option<(usize, usize)> {
+        let (start, end) = self.range()?;
+        if row < start.row || row > end.row {
+            return None;
+        }
+        let from = if row == start.row { start.col.min(len) } else { 0 };
+        // Rows that continue past their end select the line break too; show it as one cell
+        let to = if row == end.row { end.col.min(len) } else { len + 1 };
+        (from < to).then_some((from, to))
+    }
+
+    /// Selected text of `lines`, joined with newlines
+    pub fn text(&self, lines: &[String]) -> Option<String> {
+        let (start, end) = self.range()?;
+        let mut parts = Vec::new();
+        for (row, line) in lines.iter().enumerate().skip(start.row).take(end.row + 1 - start.row) {
+            let from = if row == start.row { byte_index(line, start.col) } else { 0 };
+            let to = if row == end.row { byte_index(line, end.col) } else { line.len() };
+            parts.push(&line[from..to.max(from)]);
+        }
+        Some(parts.join("\n"))
+    }
+
+    /// Start, extend or finish a drag; `to_pos` maps a screen cell to a text
+    /// position, returning `None` outside the text. Returns whether the
+    /// event was used.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent, to_pos: impl Fn(u16, u16) -> Option<TextPos>) -> bool {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => match to_pos(mouse.column, mouse.row) {
+                Some(pos) => {
+                    self.start(pos);
+                    true
+                }
+                None => false,
+            },
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                if let Some(pos) = to_pos(mouse.column, mouse.row) {
+                    self.extend(pos);
+                }
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+                self.dragging = false;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Restyle characters `from..to` of `line` by patching `style` over them
+pub fn style_chars(line: Line<'static>, from: usize, to: usize, style: Style) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for span in line.spans {
+        let len = span.content.chars().count();
+        if pos + len <= from || pos >= to {
+            spans.push(span);
+        } else {
+            let chars: Vec<char> = span.content.chars().collect();
+            let start = from.saturating_sub(pos).min(len);
+            let end = (to - pos).min(len);
+            let piece = |a: usize, b: usize| chars[a..b].iter().collect::<String>();
+            if start > 0 {
+                spans.push(Span::styled(piece(0, start), span.style));
+            }
+            spans.push(Span::styled(piece(start, end), span.style.patch(style)));
+            if end < len {
+                spans.push(Span::styled(piece(end, len), span.style));
+            }
+        }
+        pos += len;
+    }
+    Line::from(spans).style(line.style)
+}
+
+/// Restyle characters `from..to` (counted after the first `skip` characters,
+/// e.g. a gutter) of `line` with the selection style
+pub fn highlight_line(line: Line<'static>, skip: usize, range: Option<(usize, usize)>) -> Line<'static> {
+    let Some((from, to)) = range else {
+        return line;
+    };
+    let (from, to) = (from + skip, to + skip);
+    let len: usize = line.spans.iter().map(|span| span.content.chars().count()).sum();
+    let mut line = style_chars(line, from, to, selection_style());
+    if to > len {
+        // Selected line break after the text
+        line.spans.push(Span::styled(" ", selection_style()));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_line_selection_text() {
+        let lines = vec!["héllo world".to_string(), "second".to_string(), "third line".to_string()];
+        let mut selection = Selection::new();
+        selection.start(TextPos::new(2, 5));
+        selection.extend(TextPos::new(0, 6));
+        assert_eq!(selection.text(&lines).as_deref(), Some("world\nsecond\nthird"));
+        assert_eq!(selection.columns_on(1, 6), Some((0, 7)));
+        assert_eq!(selection.columns_on(2, 10), Some((0, 5)));
+
+        selection.start(TextPos::new(1, 2));
+        assert!(selection.is_empty());
+        assert_eq!(char_at_column("a😀b", 2), 1);
+        assert_eq!(char_at_column("a😀b", 3), 2);
+    }
+}
@@ -0,0 +1,162 @@
+//! Snake game module
+//!
+//! The snake moves one cell per tick, grows when it eats and dies on walls
+//! or its own body. Turns are queued so two quick key presses between ticks
+//! are both applied.
+
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Heading {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Heading {
+    fn opposite(self) -> Self {
+        match self {
+            Heading::Up => Heading::Down,
+            Heading::Down => Heading::Up,
+            Heading::Left => Heading::Right,
+            Heading::Right => Heading::Left,
+        }
+    }
+}
+
+/// Points per food eaten
+const FOOD_POINTS: u32 = 10;
+
+pub struct Snake {
+    pub width: u16,
+    pub height: u16,
+    /// Head first
+    pub body: VecDeque<(u16, u16)>,
+    pub food: (u16, u16),
+    pub score: u32,
+    pub game_over: bool,
+    heading: Heading,
+    turns: VecDeque<Heading>,
+    rng: u64,
+}
+
+impl Snake {
+    pub fn new(width: u16, height: u16, seed: u64) -> Self {
+        let (x, y) = (width / 2, height / 2);
+        let mut snake = Self {
+            width,
+            height,
+            body: VecDeque::from([(x, y), (x - 1, y), (x - 2, y)]),
+            food: (0, 0),
+            score: 0,
+            game_over: false,
+            heading: Heading::Right,
+            turns: VecDeque::new(),
+            rng: seed.max(1),
+        };
+        snake.place_food();
+        snake
+    }
+
+    fn next_random(&mut self) -> u64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+
+    /// Put food on a random free cell
+    fn place_food(&mut self) {
+        let free: Vec<(u16, u16)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|cell| !self.body.contains(cell))
+            .collect();
+        if free.is_empty() {
+            self.game_over = true;
+            return;
+        }
+        let index = (self.next_random() % free.len() as u64) as usize;
+        self.food = free[index];
+    }
+
+    /// Queue a turn; reversing into the body is ignored
+    pub fn turn(&mut self, heading: Heading) {
+        let last = self.turns.back().copied().unwrap_or(self.heading);
+        if heading != last && heading != last.opposite() && self.turns.len() < 3 {
+            self.turns.push_back(heading);
+        }
+    }
+
+    /// Move one cell
+    pub fn step(&mut self) {
+        if self.game_over {
+            return;
+        }
+        if let Some(heading) = self.turns.pop_front() {
+            self.heading = heading;
+        }
+        let (x, y) = self.body[0];
+        let next = match self.heading {
+            Heading::Up => y.checked_sub(1).map(|y| (x, y)),
+            Heading::Down => (y + 1 < self.height).then_some((x, y + 1)),
+            Heading::Left => x.checked_sub(1).map(|x| (x, y)),
+            Heading::Right => (x + 1 < self.width).then_some((x + 1, y)),
+        };
+        let Some(head) = next else {
+            self.game_over = true;
+            return;
+        };
+        let eating = head == self.food;
+        if !eating {
+            self.body.pop_back();
+        }
+        if self.body.contains(&head) {
+            self.game_over = true;
+            return;
+        }
+        self.body.push_front(head);
+        if eating {
+            self.score += FOOD_POINTS;
+            self.place_food();
+        }
+    }
+
+    /// Ticks speed up as the snake grows
+    pub fn tick_millis(&self) -> u64 {
+        150u64.saturating_sub(self.body.len() as u64 * 2).max(60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eat_grow_and_collide() {
+        let mut snake = Snake::new(10, 10, 7);
+        snake.food = (6, 5);
+        snake.step();
+        assert_eq!(snake.body.len(), 4);
+        assert_eq!(snake.score, FOOD_POINTS);
+        assert!(!snake.body.contains(&snake.food));
+        snake.food = (0, 0);
+
+        // Reversing is ignored, other turns are queued in order
+        snake.turn(Heading::Left);
+        snake.turn(Heading::Down);
+        snake.turn(Heading::Left);
+        snake.step();
+        assert_eq!(snake.body[0], (6, 6));
+        snake.step();
+        assert_eq!(snake.body[0], (5, 6));
+
+        // Running into the top wall ends the game
+        snake.turn(Heading::Up);
+        for _ in 0..7 {
+            snake.step();
+        }
+        assert!(snake.game_over);
+        assert_eq!(snake.body[0], (5, 0));
+    }
+}
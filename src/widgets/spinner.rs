@@ -0,0 +1,262 @@
+//! Spinner and skeleton loading module
+//!
+//! Indeterminate spinners for TUI frames and plain terminal output, plus
+//! shimmering skeleton placeholders for lists and tables whose data is
+//! still loading.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::{self, Write};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crossterm::{
+    cursor, execute,
+    style::{Color as TermColor, Print, ResetColor, SetForegroundColor},
+    terminal::{Clear, ClearType},
+};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Animation styles for spinners
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpinnerStyle {
+    Braille,
+    Dots,
+    Bar,
+}
+
+impl SpinnerStyle {
+    pub fn frames(&self) -> &'static [&'static str] {
+        match self {
+            SpinnerStyle::Braille => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            SpinnerStyle::Dots => &[".  ", ".. ", "...", " ..", "  .", "   "],
+            SpinnerStyle::Bar => &["▏", "▎", "▍", "▌", "▋", "▊", "▉", "█", "▉", "▊", "▋", "▌", "▍", "▎"],
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        match self {
+            SpinnerStyle::Braille => Duration::from_millis(80),
+            SpinnerStyle::Dots => Duration::from_millis(200),
+            SpinnerStyle::Bar => Duration::from_millis(60),
+        }
+    }
+
+    /// Frame to show after `elapsed` time
+    pub fn frame_at(&self, elapsed: Duration) -> &'static str {
+        let frames = self.frames();
+        let index = (elapsed.as_millis() / self.interval().as_millis()) as usize % frames.len();
+        frames[index]
+    }
+}
+
+/// Spinner rendered inside a ratatui frame, animated from its start time
+pub struct Spinner {
+    pub style: SpinnerStyle,
+    pub label: String,
+    started: Instant,
+}
+
+impl Spinner {
+    pub fn new(style: SpinnerStyle, label: &str) -> Self {
+        Self {
+            style,
+            label: label.to_string(),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn current_frame(&self) -> &'static str {
+        self.style.frame_at(self.started.elapsed())
+    }
+
+    /// Spinner glyph followed by the label, for embedding in other widgets
+    pub fn line(&self) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(self.current_frame(), Style::default().fg(Color::Cyan)),
+            Span::raw(" "),
+            Span::raw(self.label.clone()),
+        ])
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(Paragraph::new(self.line()), area);
+    }
+}
+
+/// Shimmering placeholder shown in place of lists and tables while loading
+pub struct Skeleton {
+    started: Instant,
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Skeleton {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+        }
+    }
+
+    /// Column of the moving highlight band
+    fn shimmer_offset(&self, width: usize) -> usize {
+        let step = (self.started.elapsed().as_millis() / 50) as usize;
+        step % (width + 10).max(1)
+    }
+
+    fn bar(&self, width: usize, row: usize) -> Line<'static> {
+        let shimmer = self.shimmer_offset(width);
+        let spans = (0..width)
+            .map(|col| {
+                // Offset per row gives the band a slight diagonal
+                let distance = (col + row).abs_diff(shimmer);
+                let color = if distance < 3 { Color::Gray } else { Color::DarkGray };
+                Span::styled("░", Style::default().fg(color))
+            })
+            .collect::<Vec<_>>();
+        Line::from(spans)
+    }
+
+    /// Placeholder rows with varying lengths, like list items
+    pub fn render_list(&self, frame: &mut Frame, area: Rect, title: &str) {
+        let block = Block::default().borders(Borders::ALL).title(title.to_string());
+        let inner = block.inner(area);
+        let lines: Vec<Line> = (0..inner.height as usize)
+            .map(|row| {
+                let width = inner.width as usize * (60 + (row * 17) % 35) / 100;
+                self.bar(width, row)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Placeholder cells laid out in `columns` equal columns
+    pub fn render_table(&self, frame: &mut Frame, area: Rect, title: &str, columns: usize) {
+        let block = Block::default().borders(Borders::ALL).title(title.to_string());
+        let inner = block.inner(area);
+        let columns = columns.max(1);
+        let cell_width = (inner.width as usize / columns).saturating_sub(1);
+
+        let lines: Vec<Line> = (0..inner.height as usize)
+            .map(|row| {
+                let mut spans = Vec::new();
+                for col in 0..columns {
+                    let width = cell_width * (50 + ((row + col) * 23) % 45) / 100;
+                    spans.extend(self.bar(width, row + col * cell_width).spans);
+                    spans.push(Span::raw(" ".repeat(cell_width - width + 1)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// Spinner drawn on the current terminal line by a background thread,
+/// for cooked-mode CLIs that do real work on the main thread
+#[cfg(not(target_arch = "wasm32"))]
+pub struct InlineSpinner {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl InlineSpinner {
+    pub fn start(style: SpinnerStyle, message: &str) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&stop);
+        let message = message.to_string();
+
+        let thread = thread::spawn(move || {
+            let started = Instant::now();
+            let mut stdout = io::stdout();
+            let _ = execute!(stdout, cursor::Hide);
+            while !flag.load(Ordering::Relaxed) {
+                let _ = execute!(
+                    stdout,
+                    cursor::SavePosition,
+                    SetForegroundColor(TermColor::Cyan),
+                    Print(style.frame_at(started.elapsed())),
+                    SetForegroundColor(TermColor::White),
+                    Print(format!(" {}", message)),
+                    SetForegroundColor(TermColor::DarkGrey),
+                    Print("..."),
+                    ResetColor,
+                    cursor::RestorePosition
+                );
+                let _ = stdout.flush();
+                thread::sleep(style.interval());
+            }
+            let _ = execute!(stdout, Clear(ClearType::FromCursorDown), cursor::Show);
+        });
+
+        Self {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// Stop the animation and clear the spinner line
+    pub fn stop(mut self) {
+        self.halt();
+    }
+
+    fn halt(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for InlineSpinner {
+    fn drop(&mut self) {
+        self.halt();
+    }
+}
+
+/// Run `work` while an inline spinner animates, returning its result
+#[cfg(not(target_arch = "wasm32"))]
+pub fn with_spinner<T>(style: SpinnerStyle, message: &str, work: impl FnOnce() -> T) -> T {
+    let spinner = InlineSpinner::start(style, message);
+    let result = work();
+    spinner.stop();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_at_cycles() {
+        let style = SpinnerStyle::Braille;
+        let frames = style.frames();
+        assert_eq!(style.frame_at(Duration::ZERO), frames[0]);
+        assert_eq!(style.frame_at(style.interval()), frames[1]);
+        assert_eq!(style.frame_at(style.interval() * frames.len() as u32), frames[0]);
+    }
+
+    #[test]
+    fn test_all_styles_have_frames() {
+        for style in [SpinnerStyle::Braille, SpinnerStyle::Dots, SpinnerStyle::Bar] {
+            assert!(!style.frames().is_empty());
+        }
+    }
+}
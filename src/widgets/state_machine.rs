@@ -0,0 +1,165 @@
+//! State machine module
+//!
+//! Typed screen modes with explicit transitions. A screen's mode enum
+//! implements `State`, and the app holding a `StateMachine` implements
+//! `Transitions` to guard moves and run exit and entry hooks, so every mode
+//! change goes through one place. Overlay states such as Help remember the
+//! state they were opened from and `back` returns there.
+
+use std::fmt::Debug;
+
+/// A screen mode
+pub trait State: Copy + PartialEq + Debug {
+    /// Shown over another state; `back` returns to that state
+    fn is_overlay(self) -> bool {
+        false
+    }
+}
+
+/// Current state and the states overlays were opened from
+#[derive(Debug, Clone)]
+pub struct StateMachine<S: State> {
+    current: S,
+    /// States below the open overlays, innermost last
+    stack: Vec<S>,
+}
+
+impl<S: State> StateMachine<S> {
+    pub fn new(initial: S) -> Self {
+        Self {
+            current: initial,
+            stack: Vec::new(),
+        }
+    }
+
+    pub fn current(&self) -> S {
+        self.current
+    }
+
+    pub fn is(&self, state: S) -> bool {
+        self.current == state
+    }
+
+    /// State `back` would return to
+    pub fn previous(&self) -> Option<S> {
+        self.stack.last().copied()
+    }
+
+    fn enter(&mut self, to: S) {
+        if to.is_overlay() {
+            self.stack.push(self.current);
+        } else {
+            self.stack.clear();
+        }
+        self.current = to;
+    }
+}
+
+/// Guards and hooks of the app that owns a `StateMachine`
+pub trait Transitions<S: State> {
+    fn machine(&mut self) -> &mut StateMachine<S>;
+
+    /// Whether the move from `from` to `to` is allowed
+    fn guard(&self, _from: S, _to: S) -> bool {
+        true
+    }
+
+    /// Runs before leaving `from`
+    fn on_exit(&mut self, _from: S, _to: S) {}
+
+    /// Runs after entering `to`
+    fn on_enter(&mut self, _to: S, _from: S) {}
+
+    /// Move to `to` if it is a different state and the guard allows it;
+    /// returns whether the state changed
+    fn transition(&mut self, to: S) -> bool {
+        let from = self.machine().current;
+        if from == to || !self.guard(from, to) {
+            return false;
+        }
+        self.on_exit(from, to);
+        self.machine().enter(to);
+        self.on_enter(to, from);
+        true
+    }
+
+    /// Close the current overlay, returning to the state it was opened from
+    fn back(&mut self) -> bool {
+        let machine = self.machine();
+        if !machine.current.is_overlay() {
+            return false;
+        }
+        let Some(to) = machine.stack.pop() else { return false };
+        let from = machine.current;
+        self.on_exit(from, to);
+        self.machine().current = to;
+        self.on_enter(to, from);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Mode {
+        Browser,
+        Player,
+        Help,
+    }
+
+    impl State for Mode {
+        fn is_overlay(self) -> bool {
+            self == Mode::Help
+        }
+    }
+
+    struct App {
+        mode: StateMachine<Mode>,
+        has_video: bool,
+        log: Vec<String>,
+    }
+
+    impl Transitions<Mode> for App {
+        fn machine(&mut self) -> &mut StateMachine<Mode> {
+            &mut self.mode
+        }
+
+        fn guard(&self, _from: Mode, to: Mode) -> bool {
+            to != Mode::Player || self.has_video
+        }
+
+        fn on_exit(&mut self, from: Mode, _to: Mode) {
+            self.log.push(format!("exit {:?}", from));
+        }
+
+        fn on_enter(&mut self, to: Mode, _from: Mode) {
+            self.log.push(format!("enter {:?}", to));
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        let mut app = App {
+            mode: StateMachine::new(Mode::Browser),
+            has_video: false,
+            log: Vec::new(),
+        };
+        assert!(!app.transition(Mode::Player));
+        assert!(!app.transition(Mode::Browser));
+        assert!(!app.back());
+
+        app.has_video = true;
+        assert!(app.transition(Mode::Player));
+        assert!(app.transition(Mode::Help));
+        assert_eq!(app.mode.previous(), Some(Mode::Player));
+        assert!(app.back());
+        assert!(app.mode.is(Mode::Player));
+        assert_eq!(app.log, ["exit Browser", "enter Player", "exit Player", "enter Help", "exit Help", "enter Player"]);
+
+        app.transition(Mode::Help);
+        app.transition(Mode::Browser);
+        assert_eq!(app.mode.previous(), None);
+    }
+}
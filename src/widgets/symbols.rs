@@ -0,0 +1,451 @@
+//! Symbol picker module
+//!
+//! Overlay for inserting characters beyond emoji: box drawing, block
+//! elements, arrows, math, Greek letters, marks and Nerd Font glyphs. ←→
+//! switch between blocks and typing searches every block by name.
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs},
+    Frame,
+};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::line_edit::edit_at_end;
+
+/// A named group of symbols
+pub struct SymbolBlock {
+    pub name: &'static str,
+    pub symbols: &'static [(char, &'static str)],
+}
+
+pub const BLOCKS: &[SymbolBlock] = &[
+    SymbolBlock {
+        name: "Box Drawing",
+        symbols: &[
+            ('─', "light horizontal"),
+            ('│', "light vertical"),
+            ('┌', "light down and right"),
+            ('┐', "light down and left"),
+            ('└', "light up and right"),
+            ('┘', "light up and left"),
+            ('├', "light vertical and right"),
+            ('┤', "light vertical and left"),
+            ('┬', "light down and horizontal"),
+            ('┴', "light up and horizontal"),
+            ('┼', "light vertical and horizontal"),
+            ('━', "heavy horizontal"),
+            ('┃', "heavy vertical"),
+            ('┏', "heavy down and right"),
+            ('┓', "heavy down and left"),
+            ('┗', "heavy up and right"),
+            ('┛', "heavy up and left"),
+            ('═', "double horizontal"),
+            ('║', "double vertical"),
+            ('╔', "double down and right"),
+            ('╗', "double down and left"),
+            ('╚', "double up and right"),
+            ('╝', "double up and left"),
+            ('╠', "double vertical and right"),
+            ('╣', "double vertical and left"),
+            ('╬', "double vertical and horizontal"),
+            ('╭', "light arc down and right"),
+            ('╮', "light arc down and left"),
+            ('╯', "light arc up and left"),
+            ('╰', "light arc up and right"),
+            ('╱', "light diagonal upper right to lower left"),
+            ('╲', "light diagonal upper left to lower right"),
+            ('╳', "light diagonal cross"),
+        ],
+    },
+    SymbolBlock {
+        name: "Block Elements",
+        symbols: &[
+            ('█', "full block"),
+            ('▓', "dark shade"),
+            ('▒', "medium shade"),
+            ('░', "light shade"),
+            ('▀', "upper half block"),
+            ('▄', "lower half block"),
+            ('▌', "left half block"),
+            ('▐', "right half block"),
+            ('▁', "lower one eighth block"),
+            ('▂', "lower one quarter block"),
+            ('▃', "lower three eighths block"),
+            ('▅', "lower five eighths block"),
+            ('▆', "lower three quarters block"),
+            ('▇', "lower seven eighths block"),
+            ('▖', "quadrant lower left"),
+            ('▗', "quadrant lower right"),
+            ('▘', "quadrant upper left"),
+            ('▝', "quadrant upper right"),
+        ],
+    },
+    SymbolBlock {
+        name: "Arrows",
+        symbols: &[
+            ('←', "leftwards arrow"),
+            ('↑', "upwards arrow"),
+            ('→', "rightwards arrow"),
+            ('↓', "downwards arrow"),
+            ('↔', "left right arrow"),
+            ('↕', "up down arrow"),
+            ('↖', "north west arrow"),
+            ('↗', "north east arrow"),
+            ('↘', "south east arrow"),
+            ('↙', "south west arrow"),
+            ('⇐', "leftwards double arrow"),
+            ('⇑', "upwards double arrow"),
+            ('⇒', "rightwards double arrow"),
+            ('⇓', "downwards double arrow"),
+            ('⇔', "left right double arrow"),
+            ('↩', "leftwards arrow with hook"),
+            ('↪', "rightwards arrow with hook"),
+            ('↻', "clockwise open circle arrow"),
+            ('⟵', "long leftwards arrow"),
+            ('⟶', "long rightwards arrow"),
+            ('➜', "heavy round-tipped rightwards arrow"),
+            ('▶', "black right-pointing triangle"),
+            ('◀', "black left-pointing triangle"),
+        ],
+    },
+    SymbolBlock {
+        name: "Math",
+        symbols: &[
+            ('±', "plus-minus sign"),
+            ('×', "multiplication sign"),
+            ('÷', "division sign"),
+            ('≠', "not equal to"),
+            ('≈', "almost equal to"),
+            ('≡', "identical to"),
+            ('≤', "less-than or equal to"),
+            ('≥', "greater-than or equal to"),
+            ('∞', "infinity"),
+            ('√', "square root"),
+            ('∑', "n-ary summation"),
+            ('∏', "n-ary product"),
+            ('∫', "integral"),
+            ('∂', "partial differential"),
+            ('∆', "increment"),
+            ('∇', "nabla"),
+            ('∈', "element of"),
+            ('∉', "not an element of"),
+            ('⊂', "subset of"),
+            ('⊃', "superset of"),
+            ('∩', "intersection"),
+            ('∪', "union"),
+            ('∧', "logical and"),
+            ('∨', "logical or"),
+            ('¬', "not sign"),
+            ('∀', "for all"),
+            ('∃', "there exists"),
+            ('∅', "empty set"),
+            ('°', "degree sign"),
+            ('‰', "per mille sign"),
+            ('½', "vulgar fraction one half"),
+            ('²', "superscript two"),
+        ],
+    },
+    SymbolBlock {
+        name: "Greek",
+        symbols: &[
+            ('α', "greek small letter alpha"),
+            ('β', "greek small letter beta"),
+            ('γ', "greek small letter gamma"),
+            ('δ', "greek small letter delta"),
+            ('ε', "greek small letter epsilon"),
+            ('θ', "greek small letter theta"),
+            ('λ', "greek small letter lambda"),
+            ('μ', "greek small letter mu"),
+            ('π', "greek small letter pi"),
+            ('σ', "greek small letter sigma"),
+            ('φ', "greek small letter phi"),
+            ('ω', "greek small letter omega"),
+            ('Δ', "greek capital letter delta"),
+            ('Σ', "greek capital letter sigma"),
+            ('Ω', "greek capital letter omega"),
+        ],
+    },
+    SymbolBlock {
+        name: "Marks",
+        symbols: &[
+            ('•', "bullet"),
+            ('◦', "white bullet"),
+            ('●', "black circle"),
+            ('○', "white circle"),
+            ('■', "black square"),
+            ('□', "white square"),
+            ('▲', "black up-pointing triangle"),
+            ('▼', "black down-pointing triangle"),
+            ('◆', "black diamond"),
+            ('◇', "white diamond"),
+            ('★', "black star"),
+            ('☆', "white star"),
+            ('✓', "check mark"),
+            ('✗', "ballot x"),
+            ('…', "horizontal ellipsis"),
+            ('—', "em dash"),
+            ('§', "section sign"),
+            ('¶', "pilcrow sign"),
+            ('†', "dagger"),
+            ('©', "copyright sign"),
+            ('®', "registered sign"),
+            ('™', "trade mark sign"),
+            ('€', "euro sign"),
+            ('£', "pound sign"),
+        ],
+    },
+    SymbolBlock {
+        // Private-use code points; they need a patched Nerd Font to display
+        name: "Nerd Font",
+        symbols: &[
+            ('\u{e0a0}', "nf-pl-branch"),
+            ('\u{e0b0}', "nf-pl-left_hard_divider"),
+            ('\u{e0b2}', "nf-pl-right_hard_divider"),
+            ('\u{e702}', "nf-dev-git"),
+            ('\u{e7a8}', "nf-dev-rust"),
+            ('\u{e73c}', "nf-dev-python"),
+            ('\u{e74e}', "nf-dev-javascript"),
+            ('\u{e712}', "nf-dev-linux"),
+            ('\u{e711}', "nf-dev-apple"),
+            ('\u{e70f}', "nf-dev-windows"),
+            ('\u{f07b}', "nf-fa-folder"),
+            ('\u{f07c}', "nf-fa-folder_open"),
+            ('\u{f15b}', "nf-fa-file"),
+            ('\u{f09b}', "nf-fa-github"),
+            ('\u{f00c}', "nf-fa-check"),
+            ('\u{f00d}', "nf-fa-close"),
+            ('\u{f015}', "nf-fa-home"),
+            ('\u{f013}', "nf-fa-cog"),
+            ('\u{f002}', "nf-fa-search"),
+            ('\u{f005}', "nf-fa-star"),
+            ('\u{f004}', "nf-fa-heart"),
+            ('\u{f071}', "nf-fa-warning"),
+            ('\u{f05a}', "nf-fa-info_circle"),
+            ('\u{f120}', "nf-fa-terminal"),
+            ('\u{f121}', "nf-fa-code"),
+            ('\u{f023}', "nf-fa-lock"),
+            ('\u{f017}', "nf-fa-clock_o"),
+            ('\u{f073}', "nf-fa-calendar"),
+            ('\u{f0e0}', "nf-fa-envelope"),
+            ('\u{f188}', "nf-fa-bug"),
+        ],
+    },
+];
+
+/// Result of a key press in the symbol picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolAction {
+    None,
+    Insert(char),
+    Close,
+}
+
+/// A listed symbol with the block it belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolMatch {
+    pub symbol: char,
+    pub name: &'static str,
+    pub block: &'static str,
+}
+
+/// Symbols whose name (or block name) contains every word of `query`
+pub fn search(query: &str) -> Vec<SymbolMatch> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    BLOCKS
+        .iter()
+        .flat_map(|block| {
+            block.symbols.iter().map(move |&(symbol, name)| SymbolMatch {
+                symbol,
+                name,
+                block: block.name,
+            })
+        })
+        .filter(|found| {
+            let haystack = format!("{} {}", found.name, found.block.to_lowercase());
+            words.iter().all(|word| haystack.contains(word.as_str()))
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub struct SymbolPicker {
+    pub visible: bool,
+    pub query: String,
+    block: usize,
+    selected: usize,
+}
+
+impl SymbolPicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Alt+U opens the picker
+    pub fn is_shortcut(key: &KeyEvent) -> bool {
+        key.code == KeyCode::Char('u') && key.modifiers.contains(KeyModifiers::ALT)
+    }
+
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Symbols listed for the current query, or the current block without one
+    pub fn listed(&self) -> Vec<SymbolMatch> {
+        if self.query.trim().is_empty() {
+            let block = &BLOCKS[self.block];
+            block
+                .symbols
+                .iter()
+                .map(|&(symbol, name)| SymbolMatch {
+                    symbol,
+                    name,
+                    block: block.name,
+                })
+                .collect()
+        } else {
+            search(&self.query)
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SymbolAction {
+        let count = self.listed().len();
+        match key.code {
+            KeyCode::Esc => {
+                self.visible = false;
+                return SymbolAction::Close;
+            }
+            KeyCode::Enter => {
+                if let Some(found) = self.listed().get(self.selected) {
+                    self.visible = false;
+                    return SymbolAction::Insert(found.symbol);
+                }
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(count.saturating_sub(1)),
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(10),
+            KeyCode::PageDown => self.selected = (self.selected + 10).min(count.saturating_sub(1)),
+            KeyCode::Right | KeyCode::Tab => {
+                self.block = (self.block + 1) % BLOCKS.len();
+                self.query.clear();
+                self.selected = 0;
+            }
+            KeyCode::Left | KeyCode::BackTab => {
+                self.block = (self.block + BLOCKS.len() - 1) % BLOCKS.len();
+                self.query.clear();
+                self.selected = 0;
+            }
+            _ => {
+                if edit_at_end(&mut self.query, key) {
+                    self.selected = 0;
+                }
+            }
+        }
+        SymbolAction::None
+    }
+
+    /// Render as a centered popup over `area`
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        if !self.visible {
+            return;
+        }
+        let width = 64.min(area.width);
+        let height = (area.height * 7 / 10).max(10).min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        frame.render_widget(Clear, popup);
+
+        let input_area = Rect { height: 3, ..popup };
+        let tabs_area = Rect {
+            y: popup.y + 3,
+            height: 1,
+            ..popup
+        };
+        let list_area = Rect {
+            y: popup.y + 4,
+            height: popup.height.saturating_sub(4),
+            ..popup
+        };
+
+        let input = Paragraph::new(format!("{}█", self.query)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🔣 Insert symbol (Alt+U)")
+                .title_bottom(" type to search all blocks by name ")
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(input, input_area);
+
+        let searching = !self.query.trim().is_empty();
+        let tabs = Tabs::new(BLOCKS.iter().map(|block| block.name))
+            .select(self.block)
+            .style(Style::default().fg(if searching { Color::DarkGray } else { Color::Gray }))
+            .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        frame.render_widget(tabs, tabs_area);
+
+        let listed = self.listed();
+        let items: Vec<ListItem> = listed
+            .iter()
+            .map(|found| {
+                let mut spans = vec![
+                    Span::styled(format!(" {}  ", found.symbol), Style::default().fg(Color::Yellow)),
+                    Span::raw(found.name),
+                    Span::styled(format!("  U+{:04X}", found.symbol as u32), Style::default().fg(Color::DarkGray)),
+                ];
+                if searching {
+                    spans.push(Span::styled(format!("  {}", found.block), Style::default().fg(Color::Cyan)));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let title = if searching {
+            format!("{} matches ", listed.len())
+        } else {
+            format!("{} ", BLOCKS[self.block].name)
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .title_bottom("←→: block | ↑↓: select | Enter: insert | Esc: close"),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+        let selected = (!listed.is_empty()).then_some(self.selected.min(listed.len() - 1));
+        let mut state = ListState::default().with_selected(selected);
+        frame.render_stateful_widget(list, list_area, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_and_insert() {
+        let found = search("double vertical");
+        assert!(found.iter().any(|m| m.symbol == '║'));
+        assert!(found.iter().all(|m| m.block == "Box Drawing"));
+        assert_eq!(search("nf-dev rust").first().map(|m| m.symbol), Some('\u{e7a8}'));
+
+        let mut picker = SymbolPicker::new();
+        picker.open();
+        for c in "infinity".chars() {
+            picker.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(
+            picker.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            SymbolAction::Insert('∞')
+        );
+        assert!(!picker.visible);
+    }
+}
@@ -1,15 +1,27 @@
 //! Table widget module
 //! 
 //! Enhanced table widget with dynamic data and interactive features.
+//! Columns can draw their cells as progress bars, colored badges, ✓/✗ marks
+//! or icons through a `ColumnSpec`, and rows matching a predicate can be
+//! styled with `style_rows_where`. Columns can be hidden from a chooser
+//! popup opened with `v`.
 
 use std::collections::HashSet;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Table, TableState, Clear},
     Frame,
 };
-use crossterm::event::KeyCode;
+
+use crate::error::DemoError;
+use crate::event::{Event, KeyCode, KeyModifiers};
+
+use super::bus::{Message, Publisher};
+use super::component::{key_press, Component};
+use super::keymap::Action;
+use super::truncate::truncate_end;
 
 #[derive(Clone, Debug)]
 pub struct TableData {
@@ -17,18 +29,268 @@ pub struct TableData {
     pub rows: Vec<Vec<String>>,
 }
 
+/// Fields of one CSV record; double quotes group commas and `""` escapes a quote
+fn parse_csv_record(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+impl TableData {
+    /// Table from CSV text whose first record is the header. Short rows are
+    /// padded so every row has a cell per header.
+    pub fn from_csv(text: &str) -> Result<Self, String> {
+        let mut records = text.lines().filter(|line| !line.trim().is_empty()).map(parse_csv_record);
+        let headers = records.next().ok_or("CSV file is empty")?;
+        let rows = records
+            .map(|mut row| {
+                row.resize(headers.len(), String::new());
+                row
+            })
+            .collect();
+        Ok(Self { headers, rows })
+    }
+}
+
+/// Characters in a progress bar cell, excluding the value
+const PROGRESS_WIDTH: usize = 10;
+
+/// How a column draws its cell values
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellRenderer {
+    Text,
+    /// Numeric value as a bar filled up to `max`, followed by the value
+    Progress { max: f64 },
+    /// Value as a pill colored by the first matching entry
+    Badge(Vec<(String, Color)>),
+    /// `true`, `yes` or `1` as ✓, anything else as ✗
+    Check,
+    /// Value prefixed with the icon of the first matching entry
+    Icon(Vec<(String, String)>),
+}
+
+impl CellRenderer {
+    /// Cell for `value`, drawn over the row's `style`
+    pub fn cell(&self, value: &str, style: Style) -> Cell<'static> {
+        Cell::from(self.line(value)).style(style)
+    }
+
+    pub fn line(&self, value: &str) -> Line<'static> {
+        match self {
+            CellRenderer::Text => Line::raw(value.to_string()),
+            CellRenderer::Progress { max } => match value.trim().parse::<f64>() {
+                Ok(number) => {
+                    let ratio = if *max > 0.0 { (number / max).clamp(0.0, 1.0) } else { 0.0 };
+                    let filled = (ratio * PROGRESS_WIDTH as f64).round() as usize;
+                    let color = match ratio {
+                        r if r >= 0.75 => Color::Green,
+                        r if r >= 0.4 => Color::Yellow,
+                        _ => Color::Red,
+                    };
+                    Line::from(vec![
+                        Span::styled("█".repeat(filled), Style::default().fg(color)),
+                        Span::styled("░".repeat(PROGRESS_WIDTH - filled), Style::default().fg(Color::DarkGray)),
+                        Span::raw(format!(" {}", value)),
+                    ])
+                }
+                Err(_) => Line::raw(value.to_string()),
+            },
+            CellRenderer::Badge(colors) => {
+                let color = colors
+                    .iter()
+                    .find(|(badge, _)| badge == value)
+                    .map_or(Color::DarkGray, |(_, color)| *color);
+                Line::from(Span::styled(
+                    format!(" {} ", value),
+                    Style::default().bg(color).fg(Color::Black).add_modifier(Modifier::BOLD),
+                ))
+            }
+            CellRenderer::Check => {
+                if matches!(value.trim().to_lowercase().as_str(), "true" | "yes" | "1") {
+                    Line::from(Span::styled("✓", Style::default().fg(Color::Green)))
+                } else {
+                    Line::from(Span::styled("✗", Style::default().fg(Color::Red)))
+                }
+            }
+            CellRenderer::Icon(icons) => match icons.iter().find(|(matched, _)| matched == value) {
+                Some((_, icon)) => Line::raw(format!("{} {}", icon, value)),
+                None => Line::raw(value.to_string()),
+            },
+        }
+    }
+}
+
+/// Rendering options for one table column
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnSpec {
+    pub renderer: CellRenderer,
+    /// Overrides the evenly split default width
+    pub width: Option<Constraint>,
+}
+
+impl Default for ColumnSpec {
+    fn default() -> Self {
+        Self::text()
+    }
+}
+
+impl ColumnSpec {
+    pub fn text() -> Self {
+        Self {
+            renderer: CellRenderer::Text,
+            width: None,
+        }
+    }
+
+    pub fn progress(max: f64) -> Self {
+        Self {
+            renderer: CellRenderer::Progress { max },
+            ..Self::text()
+        }
+    }
+
+    pub fn badges(colors: &[(&str, Color)]) -> Self {
+        Self {
+            renderer: CellRenderer::Badge(colors.iter().map(|(value, color)| (value.to_string(), *color)).collect()),
+            ..Self::text()
+        }
+    }
+
+    pub fn check() -> Self {
+        Self {
+            renderer: CellRenderer::Check,
+            ..Self::text()
+        }
+    }
+
+    pub fn icons(icons: &[(&str, &str)]) -> Self {
+        Self {
+            renderer: CellRenderer::Icon(icons.iter().map(|(value, icon)| (value.to_string(), icon.to_string())).collect()),
+            ..Self::text()
+        }
+    }
+
+    pub fn width(mut self, width: Constraint) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+/// Predicate on a row's values with the style for matching rows
+type RowRule = (Box<dyn Fn(&[String]) -> bool>, Style);
+
+/// Required builder field that has not been given yet; `build` only exists
+/// once every required field is set, so a missing one fails to compile
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// Builder for `InteractiveTable`, from `InteractiveTable::builder()`.
+/// Headers are required; rows default to none and every column to text.
+pub struct InteractiveTableBuilder<H = Missing> {
+    headers: H,
+    rows: Vec<Vec<String>>,
+    columns: Vec<ColumnSpec>,
+    row_rules: Vec<RowRule>,
+    selectable: bool,
+}
+
+impl<H> InteractiveTableBuilder<H> {
+    pub fn headers<S: Into<String>>(self, headers: impl IntoIterator<Item = S>) -> InteractiveTableBuilder<Vec<String>> {
+        InteractiveTableBuilder {
+            headers: headers.into_iter().map(Into::into).collect(),
+            rows: self.rows,
+            columns: self.columns,
+            row_rules: self.row_rules,
+            selectable: self.selectable,
+        }
+    }
+
+    /// Rows of cell values; short rows are padded to the header count
+    pub fn rows<R, S>(mut self, rows: impl IntoIterator<Item = R>) -> Self
+    where
+        R: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rows = rows.into_iter().map(|row| row.into_iter().map(Into::into).collect()).collect();
+        self
+    }
+
+    /// Renderers in header order, as for `InteractiveTable::with_columns`
+    pub fn columns(mut self, specs: Vec<ColumnSpec>) -> Self {
+        self.columns = specs;
+        self
+    }
+
+    /// See `InteractiveTable::style_rows_where`
+    pub fn style_rows_where(mut self, predicate: impl Fn(&[String]) -> bool + 'static, style: Style) -> Self {
+        self.row_rules.push((Box::new(predicate), style));
+        self
+    }
+
+    /// Whether keys select rows and cells; on by default
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+}
+
+impl InteractiveTableBuilder<Vec<String>> {
+    pub fn build(self) -> InteractiveTable {
+        let width = self.headers.len();
+        let rows = self
+            .rows
+            .into_iter()
+            .map(|mut row| {
+                row.resize(width, String::new());
+                row
+            })
+            .collect();
+        let mut table = InteractiveTable::new(TableData { headers: self.headers, rows }).with_columns(self.columns);
+        table.row_rules = self.row_rules;
+        table.selectable = self.selectable;
+        table
+    }
+}
+
 pub struct InteractiveTable {
     pub data: TableData,
     pub state: TableState,
     pub selected_column: usize,
     pub multi_selection: HashSet<(usize, usize)>, // (row, col) pairs
     pub column_widths: Vec<Constraint>,
+    /// Row removed by `delete_row` or copied by `yank_row`, for `paste_row`
+    pub register: Option<Vec<String>>,
+    /// Per-column renderers, one per header
+    pub columns: Vec<ColumnSpec>,
+    row_rules: Vec<RowRule>,
+    /// Indexes of columns left out when rendering
+    pub hidden_columns: HashSet<usize>,
+    /// Checkbox list of columns while the `v` popup is open
+    column_chooser: Option<SelectComponent>,
+    /// Whether keys move and extend the row and cell selection
+    pub selectable: bool,
+    /// Where row changes are published as `SelectionChanged`
+    publisher: Option<Publisher>,
 }
 
 impl InteractiveTable {
     pub fn new(data: TableData) -> Self {
         let column_count = data.headers.len();
-        let column_widths = vec![Constraint::Percentage(100 / column_count as u16); column_count];
+        let column_widths = vec![Constraint::Percentage(100 / column_count.max(1) as u16); column_count];
         
         Self {
             data,
@@ -36,10 +298,84 @@ impl InteractiveTable {
             selected_column: 0,
             multi_selection: HashSet::new(),
             column_widths,
+            register: None,
+            columns: vec![ColumnSpec::default(); column_count],
+            row_rules: Vec::new(),
+            hidden_columns: HashSet::new(),
+            column_chooser: None,
+            selectable: true,
+            publisher: None,
         }
     }
 
+    /// Publish a `SelectionChanged` from "table" whenever the row changes
+    pub fn publish_to(&mut self, publisher: Publisher) {
+        self.publisher = Some(publisher);
+    }
+
+    fn select_row(&mut self, index: usize) {
+        if self.state.selected() == Some(index) {
+            return;
+        }
+        self.state.select(Some(index));
+        if let Some(publisher) = &self.publisher {
+            publisher.publish(Message::SelectionChanged { source: "table", index });
+        }
+    }
+
+    /// Start building a table; `headers` must be given before `build`
+    pub fn builder() -> InteractiveTableBuilder {
+        InteractiveTableBuilder {
+            headers: Missing,
+            rows: Vec::new(),
+            columns: Vec::new(),
+            row_rules: Vec::new(),
+            selectable: true,
+        }
+    }
+
+    /// Set how column `index` renders its cells
+    pub fn set_column(&mut self, index: usize, spec: ColumnSpec) {
+        if index >= self.columns.len() {
+            return;
+        }
+        if let Some(width) = spec.width {
+            self.column_widths[index] = width;
+        }
+        self.columns[index] = spec;
+    }
+
+    /// Style rows for which `predicate` holds, evaluated on every render.
+    /// Later rules patch earlier ones and selection highlighting wins.
+    pub fn style_rows_where(mut self, predicate: impl Fn(&[String]) -> bool + 'static, style: Style) -> Self {
+        self.row_rules.push((Box::new(predicate), style));
+        self
+    }
+
+    /// Combined style of the rules matching `row`
+    pub fn row_style(&self, row: &[String]) -> Style {
+        self.row_rules
+            .iter()
+            .filter(|(predicate, _)| predicate(row))
+            .fold(Style::default(), |style, (_, rule)| style.patch(*rule))
+    }
+
+    /// Index of the column with header `name`
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.data.headers.iter().position(|header| header == name)
+    }
+
+    pub fn with_columns(mut self, specs: Vec<ColumnSpec>) -> Self {
+        for (index, spec) in specs.into_iter().enumerate() {
+            self.set_column(index, spec);
+        }
+        self
+    }
+
     pub fn next_row(&mut self) {
+        if self.data.rows.is_empty() {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i >= self.data.rows.len() - 1 {
@@ -50,10 +386,13 @@ impl InteractiveTable {
             }
             None => 0,
         };
-        self.state.select(Some(i));
+        self.select_row(i);
     }
 
     pub fn previous_row(&mut self) {
+        if self.data.rows.is_empty() {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
@@ -64,22 +403,159 @@ impl InteractiveTable {
             }
             None => 0,
         };
-        self.state.select(Some(i));
+        self.select_row(i);
     }
 
-    pub fn next_column(&mut self) {
-        if self.selected_column >= self.data.headers.len() - 1 {
-            self.selected_column = 0;
-        } else {
-            self.selected_column += 1;
+    pub fn first_row(&mut self) {
+        if !self.data.rows.is_empty() {
+            self.select_row(0);
+        }
+    }
+
+    pub fn last_row(&mut self) {
+        if !self.data.rows.is_empty() {
+            self.select_row(self.data.rows.len() - 1);
+        }
+    }
+
+    /// Remove the selected row into the register
+    pub fn delete_row(&mut self) {
+        let Some(index) = self.state.selected().filter(|&i| i < self.data.rows.len()) else {
+            return;
+        };
+        self.register = Some(self.data.rows.remove(index));
+        // Selected cells are stored by row index, which just shifted
+        self.multi_selection.clear();
+        let selected = (!self.data.rows.is_empty()).then(|| index.min(self.data.rows.len() - 1));
+        self.state.select(selected);
+    }
+
+    pub fn yank_row(&mut self) {
+        if let Some(row) = self.state.selected().and_then(|i| self.data.rows.get(i)) {
+            self.register = Some(row.clone());
         }
     }
 
+    /// Insert the register below the selected row and select it
+    pub fn paste_row(&mut self) {
+        let Some(row) = self.register.clone() else {
+            return;
+        };
+        let index = self.state.selected().map_or(0, |i| (i + 1).min(self.data.rows.len()));
+        self.data.rows.insert(index, row);
+        self.multi_selection.clear();
+        self.state.select(Some(index));
+    }
+
+    /// Handle a keymap action; navigation maps to the default keys
+    pub fn handle_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::DeleteLine => self.delete_row(),
+            Action::YankLine => self.yank_row(),
+            Action::Paste => self.paste_row(),
+            _ => return action.key().is_some_and(|key| self.handle_key(key.code)),
+        }
+        true
+    }
+
+    pub fn next_column(&mut self) {
+        let visible = self.visible_columns();
+        let current = visible.iter().position(|&i| i == self.selected_column);
+        self.selected_column = match current {
+            Some(i) if i + 1 < visible.len() => visible[i + 1],
+            Some(_) => visible.first().copied().unwrap_or(0),
+            None => visible.iter().copied().find(|&i| i > self.selected_column).or(visible.first().copied()).unwrap_or(0),
+        };
+    }
+
     pub fn previous_column(&mut self) {
-        if self.selected_column == 0 {
-            self.selected_column = self.data.headers.len() - 1;
-        } else {
-            self.selected_column -= 1;
+        let visible = self.visible_columns();
+        let current = visible.iter().position(|&i| i == self.selected_column);
+        self.selected_column = match current {
+            Some(i) if i > 0 => visible[i - 1],
+            Some(_) => visible.last().copied().unwrap_or(0),
+            None => visible.iter().copied().rev().find(|&i| i < self.selected_column).or(visible.last().copied()).unwrap_or(0),
+        };
+    }
+
+    /// Indexes of the rendered columns, in order
+    pub fn visible_columns(&self) -> Vec<usize> {
+        (0..self.data.headers.len()).filter(|i| !self.hidden_columns.contains(i)).collect()
+    }
+
+    /// Headers of the hidden columns, for saving the choice
+    pub fn hidden_headers(&self) -> Vec<String> {
+        self.data
+            .headers
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.hidden_columns.contains(i))
+            .map(|(_, header)| header.clone())
+            .collect()
+    }
+
+    /// Hide the columns with these headers and show all others
+    pub fn set_hidden_headers(&mut self, headers: &[String]) {
+        self.hidden_columns = self
+            .data
+            .headers
+            .iter()
+            .enumerate()
+            .filter(|(_, header)| headers.contains(header))
+            .map(|(i, _)| i)
+            .collect();
+        if self.hidden_columns.len() >= self.data.headers.len() {
+            // Keep at least one column on screen
+            self.hidden_columns.remove(&0);
+        }
+        if self.hidden_columns.contains(&self.selected_column) {
+            self.next_column();
+        }
+    }
+
+    pub fn column_chooser_open(&self) -> bool {
+        self.column_chooser.is_some()
+    }
+
+    pub fn open_column_chooser(&mut self) {
+        let options = self
+            .data
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| SelectOption::new(header, &i.to_string()))
+            .collect();
+        let mut chooser = SelectComponent::new("Columns (Space: toggle, v: close)", options, SelectType::CheckboxGroup);
+        chooser.selected_values = self.visible_columns().iter().map(|i| i.to_string()).collect();
+        self.column_chooser = Some(chooser);
+    }
+
+    /// ↑↓ move, Space/Enter toggle the column, Esc or `v` close
+    fn handle_column_chooser_key(&mut self, key: KeyCode) {
+        let Some(chooser) = &mut self.column_chooser else {
+            return;
+        };
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => chooser.previous(),
+            KeyCode::Down | KeyCode::Char('j') => chooser.next(),
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                let unchecking_last = chooser.selected_values.len() == 1
+                    && chooser.selected_values[0] == chooser.selected_index.to_string();
+                if !unchecking_last {
+                    chooser.select_current();
+                }
+                let hidden: Vec<String> = self
+                    .data
+                    .headers
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| !chooser.selected_values.contains(&i.to_string()))
+                    .map(|(_, header)| header.clone())
+                    .collect();
+                self.set_hidden_headers(&hidden);
+            }
+            KeyCode::Esc | KeyCode::Char('v') => self.column_chooser = None,
+            _ => {}
         }
     }
 
@@ -88,17 +564,35 @@ impl InteractiveTable {
         let normal_style = Style::default().bg(Color::Reset).fg(Color::White);
         let header_style = Style::default().bg(Color::Blue).fg(Color::White);
 
+        let widths: Vec<Constraint> = self
+            .visible_columns()
+            .into_iter()
+            .map(|i| self.column_widths[i])
+            .collect();
+        let block = Block::default().borders(Borders::ALL).title("Interactive Table");
+        // Text longer than its column ends in an ellipsis instead of being cut
+        let column_areas = Layout::horizontal(widths.iter().copied()).flex(Flex::Start).spacing(1).split(block.inner(area));
+        let visible = self.visible_columns();
+        let column_width = |col_idx: usize| {
+            visible
+                .iter()
+                .position(|&i| i == col_idx)
+                .and_then(|position| column_areas.get(position))
+                .map_or(0, |column| column.width as usize)
+        };
+
         // Create header cells with column highlighting
         let header_cells: Vec<Cell> = self.data.headers
             .iter()
             .enumerate()
+            .filter(|(i, _)| !self.hidden_columns.contains(i))
             .map(|(i, h)| {
                 let style = if i == self.selected_column {
                     header_style.bg(Color::Cyan)
                 } else {
                     header_style
                 };
-                Cell::from(h.as_str()).style(style)
+                Cell::from(truncate_end(h, column_width(i)).into_owned()).style(style)
             })
             .collect();
 
@@ -107,37 +601,63 @@ impl InteractiveTable {
             .iter()
             .enumerate()
             .map(|(row_idx, item)| {
+                let row_style = self.row_style(item);
                 let cells: Vec<Cell> = item
                     .iter()
                     .enumerate()
+                    .filter(|(col_idx, _)| !self.hidden_columns.contains(col_idx))
                     .map(|(col_idx, c)| {
                         let style = if Some(row_idx) == self.state.selected() && col_idx == self.selected_column {
-                            selected_style
+                            row_style.patch(selected_style)
                         } else if Some(row_idx) == self.state.selected() {
-                            Style::default().bg(Color::DarkGray)
+                            row_style.patch(Style::default().bg(Color::DarkGray))
                         } else if col_idx == self.selected_column {
-                            Style::default().bg(Color::Gray)
+                            row_style.patch(Style::default().bg(Color::Gray))
                         } else if self.multi_selection.contains(&(row_idx, col_idx)) {
-                            Style::default().bg(Color::Magenta).fg(Color::White)
+                            row_style.patch(Style::default().bg(Color::Magenta).fg(Color::White))
                         } else {
-                            normal_style
+                            normal_style.patch(row_style)
                         };
-                        Cell::from(c.as_str()).style(style)
+                        let renderer = self.columns.get(col_idx).map_or(&CellRenderer::Text, |spec| &spec.renderer);
+                        match renderer {
+                            CellRenderer::Text => renderer.cell(&truncate_end(c, column_width(col_idx)), style),
+                            _ => renderer.cell(c, style),
+                        }
                     })
                     .collect();
                 Row::new(cells)
             })
             .collect();
 
-        let table = Table::new(rows, &[Constraint::Percentage(25); 4])
+        let table = Table::new(rows, widths)
             .header(Row::new(header_cells))
-            .block(Block::default().borders(Borders::ALL).title("Interactive Table"))
+            .block(block)
             .highlight_style(selected_style);
 
         f.render_stateful_widget(table, area, &mut self.state);
+
+        if let Some(chooser) = &mut self.column_chooser {
+            let width = 30.min(area.width);
+            let height = (chooser.options.len() as u16 + 2).min(area.height);
+            let popup = Rect {
+                x: area.x + (area.width - width) / 2,
+                y: area.y + (area.height - height) / 2,
+                width,
+                height,
+            };
+            f.render_widget(Clear, popup);
+            chooser.render(f, popup);
+        }
     }
 
     pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        if self.column_chooser.is_some() {
+            self.handle_column_chooser_key(key);
+            return true;
+        }
+        if !self.selectable && key != KeyCode::Char('v') {
+            return false;
+        }
         match key {
             KeyCode::Up => {
                 self.previous_row();
@@ -155,17 +675,29 @@ impl InteractiveTable {
                 self.next_column();
                 true
             }
+            KeyCode::Home => {
+                self.first_row();
+                true
+            }
+            KeyCode::End => {
+                self.last_row();
+                true
+            }
             KeyCode::Char('c') => {
                 // Clear multi-selection
                 self.multi_selection.clear();
                 true
             }
+            KeyCode::Char('v') => {
+                self.open_column_chooser();
+                true
+            }
             _ => false,
         }
     }
 
     pub fn handle_key_with_shift(&mut self, key: KeyCode, shift_pressed: bool) -> bool {
-        if shift_pressed {
+        if shift_pressed && self.selectable {
             // Handle multi-selection with Shift
             if let Some(current_row) = self.state.selected() {
                 match key {
@@ -203,28 +735,65 @@ impl InteractiveTable {
     pub fn get_selected_cells(&self) -> Vec<(usize, usize)> {
         self.multi_selection.iter().cloned().collect()
     }
+
+    /// Visible columns of the whole table, or with `selection_only` the
+    /// rows and columns holding multi-selected cells, for export
+    pub fn export_data(&self, selection_only: bool) -> TableData {
+        let mut columns = self.visible_columns();
+        let mut rows: Vec<usize> = (0..self.data.rows.len()).collect();
+        if selection_only && !self.multi_selection.is_empty() {
+            columns.retain(|col| self.multi_selection.iter().any(|(_, c)| c == col));
+            rows.retain(|row| self.multi_selection.iter().any(|(r, _)| r == row));
+        }
+        TableData {
+            headers: columns.iter().map(|&col| self.data.headers[col].clone()).collect(),
+            rows: rows
+                .iter()
+                .map(|&row| {
+                    columns
+                        .iter()
+                        .map(|&col| self.data.rows[row].get(col).cloned().unwrap_or_default())
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Component for InteractiveTable {
+    fn handle_event(&mut self, event: &Event) -> bool {
+        key_press(event).is_some_and(|key| self.handle_key_with_shift(key.code, key.modifiers.contains(KeyModifiers::SHIFT)))
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError> {
+        InteractiveTable::render(self, frame, area);
+        Ok(())
+    }
 }
 
 /// Create a demo table with sample data
 pub fn create_demo_table() -> InteractiveTable {
-    let data = TableData {
-        headers: vec![
-            "Name".to_string(),
-            "Age".to_string(),
-            "City".to_string(),
-            "Status".to_string(),
-        ],
-        rows: vec![
-            vec!["Alice".to_string(), "25".to_string(), "New York".to_string(), "Active".to_string()],
-            vec!["Bob".to_string(), "30".to_string(), "London".to_string(), "Active".to_string()],
-            vec!["Charlie".to_string(), "35".to_string(), "Tokyo".to_string(), "Inactive".to_string()],
-            vec!["Diana".to_string(), "28".to_string(), "Paris".to_string(), "Active".to_string()],
-            vec!["Eve".to_string(), "32".to_string(), "Berlin".to_string(), "Active".to_string()],
-            vec!["Frank".to_string(), "29".to_string(), "Sydney".to_string(), "Inactive".to_string()],
-        ],
-    };
-    
-    InteractiveTable::new(data)
+    InteractiveTable::builder()
+        .headers(["Name", "Age", "City", "Status"])
+        .rows([
+            ["Alice", "25", "New York", "Active"],
+            ["Bob", "30", "London", "Active"],
+            ["Charlie", "35", "Tokyo", "Inactive"],
+            ["Diana", "28", "Paris", "Active"],
+            ["Eve", "32", "Berlin", "Active"],
+            ["Frank", "29", "Sydney", "Inactive"],
+        ])
+        .columns(vec![
+            ColumnSpec::text(),
+            ColumnSpec::progress(40.0),
+            ColumnSpec::text(),
+            ColumnSpec::badges(&[("Active", Color::Green), ("Inactive", Color::Red)]),
+        ])
+        .style_rows_where(
+            |row| row.get(3).is_some_and(|status| status == "Inactive"),
+            Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+        )
+        .build()
 }
 
 // Select component code follows...
@@ -268,6 +837,7 @@ pub struct SelectComponent {
     pub select_type: SelectType,
     pub title: String,
     state: ListState,
+    focused: bool,
 }
 
 impl SelectComponent {
@@ -283,6 +853,17 @@ impl SelectComponent {
             select_type,
             title: title.to_string(),
             state,
+            focused: false,
+        }
+    }
+
+    /// Start building a select; `options` must be given before `build`
+    pub fn builder() -> SelectComponentBuilder {
+        SelectComponentBuilder {
+            title: String::new(),
+            options: Missing,
+            select_type: SelectType::Dropdown,
+            selected: Vec::new(),
         }
     }
 
@@ -357,9 +938,11 @@ impl SelectComponent {
     }
 
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let border = if self.focused { Color::Cyan } else { Color::Reset };
         let block = Block::default()
             .title(self.title.clone())
-            .borders(Borders::ALL);
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border));
 
         match self.select_type {
             SelectType::Dropdown => self.render_dropdown(frame, area, block),
@@ -498,6 +1081,88 @@ impl SelectComponent {
     }
 }
 
+/// Builder for `SelectComponent`, from `SelectComponent::builder()`.
+/// Options are required; it is an untitled dropdown with nothing selected
+/// unless told otherwise.
+pub struct SelectComponentBuilder<O = Missing> {
+    title: String,
+    options: O,
+    select_type: SelectType,
+    selected: Vec<String>,
+}
+
+impl<O> SelectComponentBuilder<O> {
+    pub fn options(self, options: impl IntoIterator<Item = SelectOption>) -> SelectComponentBuilder<Vec<SelectOption>> {
+        SelectComponentBuilder {
+            title: self.title,
+            options: options.into_iter().collect(),
+            select_type: self.select_type,
+            selected: self.selected,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn select_type(mut self, select_type: SelectType) -> Self {
+        self.select_type = select_type;
+        self
+    }
+
+    /// Values selected to begin with
+    pub fn selected<S: Into<String>>(mut self, values: impl IntoIterator<Item = S>) -> Self {
+        self.selected = values.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl SelectComponentBuilder<Vec<SelectOption>> {
+    pub fn build(self) -> SelectComponent {
+        let mut select = SelectComponent::new(&self.title, self.options, self.select_type);
+        select.selected_values = self.selected;
+        select
+    }
+}
+
+impl Component for SelectComponent {
+    /// ↑↓ move; Enter or Space opens a closed dropdown, otherwise selects;
+    /// Esc closes the dropdown
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let Some(key) = key_press(event) else {
+            return false;
+        };
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.next(),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if matches!(self.select_type, SelectType::Dropdown) && !self.is_open {
+                    self.toggle_open();
+                } else {
+                    self.select_current();
+                }
+            }
+            KeyCode::Esc if self.is_open => self.is_open = false,
+            _ => return false,
+        }
+        true
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) -> Result<(), DemoError> {
+        SelectComponent::render(self, frame, area);
+        Ok(())
+    }
+
+    fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -544,4 +1209,122 @@ mod tests {
         table.multi_selection.clear();
         assert_eq!(table.get_selection_count(), 0);
     }
+
+    #[test]
+    fn test_delete_yank_and_paste_rows() {
+        let mut table = create_demo_table();
+        table.state.select(Some(1));
+        table.handle_action(Action::DeleteLine);
+        assert_eq!(table.data.rows.len(), 5);
+        assert_eq!(table.data.rows[1][0], "Charlie");
+
+        table.handle_action(Action::Last);
+        table.handle_action(Action::Paste);
+        assert_eq!(table.state.selected(), Some(5));
+        assert_eq!(table.data.rows[5][0], "Bob");
+
+        table.handle_action(Action::First);
+        table.handle_action(Action::YankLine);
+        table.handle_action(Action::Paste);
+        assert_eq!(table.data.rows[1][0], "Alice");
+    }
+
+    #[test]
+    fn test_cell_renderers() {
+        let progress = CellRenderer::Progress { max: 40.0 };
+        assert_eq!(progress.line("20").to_string(), "█████░░░░░ 20");
+        assert_eq!(progress.line("99").to_string(), "██████████ 99");
+        assert_eq!(progress.line("n/a").to_string(), "n/a");
+        assert_eq!(CellRenderer::Check.line("Yes").to_string(), "✓");
+        assert_eq!(CellRenderer::Check.line("no").to_string(), "✗");
+
+        let badge = ColumnSpec::badges(&[("Active", Color::Green)]).renderer.line("Active");
+        assert_eq!(badge.spans[0].style.bg, Some(Color::Green));
+
+        let table = create_demo_table();
+        assert_eq!(table.columns[1].renderer, progress);
+    }
+
+    #[test]
+    fn test_row_rules_compose() {
+        let table = create_demo_table().style_rows_where(
+            |row| row[1].parse::<u32>().is_ok_and(|age| age > 30),
+            Style::default().fg(Color::Yellow),
+        );
+        assert_eq!(table.column_index("Status"), Some(3));
+        // Charlie is inactive and over 30: the later rule's color wins
+        let charlie = table.row_style(&table.data.rows[2]);
+        assert_eq!(charlie.fg, Some(Color::Yellow));
+        assert!(charlie.add_modifier.contains(Modifier::ITALIC));
+        assert_eq!(table.row_style(&table.data.rows[0]), Style::default());
+    }
+
+    #[test]
+    fn test_csv_parsing() {
+        let data = TableData::from_csv("Name,Note\nAda,\"Hello, \"\"world\"\"\"\n\nBob\n").unwrap();
+        assert_eq!(data.headers, vec!["Name", "Note"]);
+        assert_eq!(data.rows, vec![vec!["Ada", "Hello, \"world\""], vec!["Bob", ""]]);
+        assert!(TableData::from_csv("").is_err());
+    }
+
+    #[test]
+    fn test_column_chooser_hides_columns() {
+        let mut table = create_demo_table();
+        table.handle_key(KeyCode::Char('v'));
+        assert!(table.column_chooser_open());
+        // Uncheck "Age"
+        table.handle_key(KeyCode::Down);
+        table.handle_key(KeyCode::Char(' '));
+        table.handle_key(KeyCode::Esc);
+        assert!(!table.column_chooser_open());
+        assert_eq!(table.hidden_headers(), vec!["Age".to_string()]);
+
+        table.next_column();
+        assert_eq!(table.selected_column, 2);
+
+        // The last visible column cannot be hidden
+        table.set_hidden_headers(&table.data.headers.clone());
+        assert_eq!(table.visible_columns(), vec![0]);
+    }
+
+    #[test]
+    fn test_select_component_events() {
+        let press = |code| Event::Key(crate::event::KeyEvent::from(code));
+        let options = vec![SelectOption::new("One", "1"), SelectOption::new("Two", "2")];
+        let mut select = SelectComponent::new("Pick", options, SelectType::Dropdown);
+        assert!(select.handle_event(&press(KeyCode::Enter)));
+        assert!(select.is_open);
+        select.handle_event(&press(KeyCode::Down));
+        select.handle_event(&press(KeyCode::Enter));
+        assert_eq!(select.selected_values, vec!["2".to_string()]);
+        assert!(!select.is_open);
+        assert!(!select.handle_event(&press(KeyCode::Esc)));
+
+        select.set_focused(true);
+        assert!(select.is_focused());
+    }
+
+    #[test]
+    fn test_builders() {
+        let table = InteractiveTable::builder()
+            .headers(["Name", "Role"])
+            .rows([vec!["Ada", "Engineer"], vec!["Grace"]])
+            .columns(vec![ColumnSpec::text(), ColumnSpec::check()])
+            .selectable(false)
+            .build();
+        assert_eq!(table.data.rows[1], vec!["Grace".to_string(), String::new()]);
+        assert_eq!(table.columns[1], ColumnSpec::check());
+        let mut table = table;
+        assert!(!table.handle_key(KeyCode::Down));
+        assert_eq!(table.state.selected(), None);
+
+        let select = SelectComponent::builder()
+            .title("Size")
+            .options([SelectOption::new("Small", "s"), SelectOption::new("Large", "l")])
+            .select_type(SelectType::RadioGroup)
+            .selected(["l"])
+            .build();
+        assert_eq!(select.title, "Size");
+        assert_eq!(select.get_selected_labels(), vec!["Large".to_string()]);
+    }
 }
@@ -0,0 +1,256 @@
+//! Embedded terminal module
+//!
+//! Runs a shell on a pseudo-terminal and renders its screen inside a pane,
+//! using `portable-pty` for the pty and `vt100` to interpret the output.
+//! Only built with the `terminal` feature.
+
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::error::DemoError;
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Lines of scrollback kept by the parser
+const SCROLLBACK: usize = 1000;
+
+pub struct TerminalPane {
+    pub title: String,
+    parser: Arc<Mutex<vt100::Parser>>,
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+    exited: Arc<AtomicBool>,
+    size: (u16, u16),
+}
+
+impl TerminalPane {
+    /// Start the user's default shell in `cwd`
+    pub fn spawn_shell(cwd: &Path, rows: u16, cols: u16) -> Result<Self, DemoError> {
+        let rows = rows.max(2);
+        let cols = cols.max(2);
+        let pair = native_pty_system().openpty(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(terminal_error)?;
+
+        let mut command = CommandBuilder::new_default_prog();
+        command.cwd(cwd);
+        let child = pair.slave.spawn_command(command).map_err(terminal_error)?;
+        drop(pair.slave);
+
+        let parser = Arc::new(Mutex::new(vt100::Parser::new(rows, cols, SCROLLBACK)));
+        let exited = Arc::new(AtomicBool::new(false));
+        let mut reader = pair.master.try_clone_reader().map_err(terminal_error)?;
+        let writer = pair.master.take_writer().map_err(terminal_error)?;
+
+        // Feed pty output into the parser until the shell closes it
+        let output_parser = Arc::clone(&parser);
+        let output_exited = Arc::clone(&exited);
+        thread::spawn(move || {
+            let mut buffer = [0u8; 4096];
+            loop {
+                match reader.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        if let Ok(mut parser) = output_parser.lock() {
+                            parser.process(&buffer[..n]);
+                        }
+                    }
+                }
+            }
+            output_exited.store(true, Ordering::Relaxed);
+        });
+
+        Ok(Self {
+            title: format!("Terminal: {}", cwd.display()),
+            parser,
+            master: pair.master,
+            writer,
+            child,
+            exited,
+            size: (rows, cols),
+        })
+    }
+
+    pub fn is_alive(&self) -> bool {
+        !self.exited.load(Ordering::Relaxed)
+    }
+
+    /// Resize the pty and parser to fit `rows` x `cols`
+    pub fn resize(&mut self, rows: u16, cols: u16) {
+        let size = (rows.max(2), cols.max(2));
+        if size == self.size {
+            return;
+        }
+        self.size = size;
+        let _ = self.master.resize(PtySize {
+            rows: size.0,
+            cols: size.1,
+            pixel_width: 0,
+            pixel_height: 0,
+        });
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.set_size(size.0, size.1);
+        }
+    }
+
+    /// Forward a key press to the shell
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key_to_bytes(key) {
+            Some(bytes) => {
+                let _ = self.writer.write_all(&bytes);
+                let _ = self.writer.flush();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, focused: bool) {
+        let border = if focused { Color::Cyan } else { Color::DarkGray };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.title.clone())
+            .border_style(Style::default().fg(border));
+        let inner = block.inner(area);
+        self.resize(inner.height, inner.width);
+
+        let Ok(parser) = self.parser.lock() else {
+            return;
+        };
+        let screen = parser.screen();
+        let lines: Vec<Line> = (0..inner.height)
+            .map(|row| {
+                let spans: Vec<Span> = (0..inner.width)
+                    .map(|col| match screen.cell(row, col) {
+                        Some(cell) => {
+                            let contents = cell.contents();
+                            let text = if contents.is_empty() { " ".to_string() } else { contents };
+                            Span::styled(text, cell_style(cell))
+                        }
+                        None => Span::raw(" "),
+                    })
+                    .collect();
+                Line::from(spans)
+            })
+            .collect();
+
+        if focused && !screen.hide_cursor() {
+            let (row, col) = screen.cursor_position();
+            frame.set_cursor_position((inner.x + col, inner.y + row));
+        }
+        drop(parser);
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+impl Drop for TerminalPane {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+    }
+}
+
+fn terminal_error(err: anyhow::Error) -> DemoError {
+    DemoError::Terminal(err.to_string())
+}
+
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::default();
+    if let Some(color) = vt_color(cell.fgcolor()) {
+        style = style.fg(color);
+    }
+    if let Some(color) = vt_color(cell.bgcolor()) {
+        style = style.bg(color);
+    }
+    if cell.bold() {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if cell.italic() {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if cell.underline() {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if cell.inverse() {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+fn vt_color(color: vt100::Color) -> Option<Color> {
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(index) => Some(Color::Indexed(index)),
+        vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+    }
+}
+
+/// Encode a key press as the bytes a terminal would send
+pub fn key_to_bytes(key: KeyEvent) -> Option<Vec<u8>> {
+    let bytes = match key.code {
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            let c = c.to_ascii_lowercase();
+            if !c.is_ascii_lowercase() {
+                return None;
+            }
+            vec![c as u8 - b'a' + 1]
+        }
+        KeyCode::Char(c) => {
+            let mut buffer = [0u8; 4];
+            let mut bytes = c.encode_utf8(&mut buffer).as_bytes().to_vec();
+            if key.modifiers.contains(KeyModifiers::ALT) {
+                bytes.insert(0, 0x1b);
+            }
+            bytes
+        }
+        KeyCode::Enter => vec![b'\r'],
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Tab => vec![b'\t'],
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => b"\x1b[A".to_vec(),
+        KeyCode::Down => b"\x1b[B".to_vec(),
+        KeyCode::Right => b"\x1b[C".to_vec(),
+        KeyCode::Left => b"\x1b[D".to_vec(),
+        KeyCode::Home => b"\x1b[H".to_vec(),
+        KeyCode::End => b"\x1b[F".to_vec(),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        KeyCode::Insert => b"\x1b[2~".to_vec(),
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_encoding() {
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(key_to_bytes(ctrl_c), Some(vec![3]));
+        let alt_x = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT);
+        assert_eq!(key_to_bytes(alt_x), Some(vec![0x1b, b'x']));
+        let up = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(key_to_bytes(up), Some(b"\x1b[A".to_vec()));
+        assert_eq!(key_to_bytes(KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)), None);
+    }
+}
@@ -0,0 +1,268 @@
+//! Tetris game module
+//!
+//! A 10×20 well with the seven tetrominoes, rotation with simple wall kicks,
+//! line clears scored by level and gravity that speeds up every ten lines.
+//! Pieces come from a shuffled bag so every piece shows up once per seven.
+
+use ratatui::style::Color;
+
+pub const WIDTH: u16 = 10;
+pub const HEIGHT: u16 = 20;
+
+/// Points for clearing 1 to 4 lines at once, multiplied by level + 1
+const LINE_POINTS: [u32; 5] = [0, 100, 300, 500, 800];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    I,
+    O,
+    T,
+    S,
+    Z,
+    J,
+    L,
+}
+
+impl Kind {
+    const ALL: [Kind; 7] = [Kind::I, Kind::O, Kind::T, Kind::S, Kind::Z, Kind::J, Kind::L];
+
+    pub fn color(self) -> Color {
+        match self {
+            Kind::I => Color::Cyan,
+            Kind::O => Color::Yellow,
+            Kind::T => Color::Magenta,
+            Kind::S => Color::Green,
+            Kind::Z => Color::Red,
+            Kind::J => Color::Blue,
+            Kind::L => Color::LightRed,
+        }
+    }
+
+    /// Cells in spawn orientation and the size of the box they rotate in
+    fn shape(self) -> ([(i16, i16); 4], i16) {
+        match self {
+            Kind::I => ([(0, 1), (1, 1), (2, 1), (3, 1)], 4),
+            Kind::O => ([(0, 0), (1, 0), (0, 1), (1, 1)], 2),
+            Kind::T => ([(1, 0), (0, 1), (1, 1), (2, 1)], 3),
+            Kind::S => ([(1, 0), (2, 0), (0, 1), (1, 1)], 3),
+            Kind::Z => ([(0, 0), (1, 0), (1, 1), (2, 1)], 3),
+            Kind::J => ([(0, 0), (0, 1), (1, 1), (2, 1)], 3),
+            Kind::L => ([(2, 0), (0, 1), (1, 1), (2, 1)], 3),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Piece {
+    pub kind: Kind,
+    /// Quarter turns clockwise
+    pub rotation: u8,
+    pub x: i16,
+    pub y: i16,
+}
+
+impl Piece {
+    fn spawn(kind: Kind) -> Self {
+        let (_, size) = kind.shape();
+        Self {
+            kind,
+            rotation: 0,
+            x: (WIDTH as i16 - size) / 2,
+            y: 0,
+        }
+    }
+
+    /// Board cells covered by the piece
+    pub fn cells(&self) -> [(i16, i16); 4] {
+        let (mut cells, size) = self.kind.shape();
+        for _ in 0..self.rotation % 4 {
+            for cell in &mut cells {
+                *cell = (size - 1 - cell.1, cell.0);
+            }
+        }
+        cells.map(|(x, y)| (self.x + x, self.y + y))
+    }
+}
+
+pub struct Tetris {
+    /// Rows top to bottom of locked cells
+    pub board: Vec<[Option<Color>; WIDTH as usize]>,
+    pub piece: Piece,
+    pub next: Kind,
+    pub score: u32,
+    pub lines: u32,
+    pub game_over: bool,
+    bag: Vec<Kind>,
+    rng: u64,
+}
+
+impl Tetris {
+    pub fn new(seed: u64) -> Self {
+        let mut game = Self {
+            board: vec![[None; WIDTH as usize]; HEIGHT as usize],
+            piece: Piece::spawn(Kind::I),
+            next: Kind::I,
+            score: 0,
+            lines: 0,
+            game_over: false,
+            bag: Vec::new(),
+            rng: seed.max(1),
+        };
+        game.piece = Piece::spawn(game.take_from_bag());
+        game.next = game.take_from_bag();
+        game
+    }
+
+    pub fn level(&self) -> u32 {
+        self.lines / 10
+    }
+
+    /// Gravity interval for the current level
+    pub fn tick_millis(&self) -> u64 {
+        800u64.saturating_sub(self.level() as u64 * 70).max(100)
+    }
+
+    /// Next piece from a shuffled bag of all seven
+    fn take_from_bag(&mut self) -> Kind {
+        if self.bag.is_empty() {
+            self.bag = Kind::ALL.to_vec();
+            for i in (1..self.bag.len()).rev() {
+                self.rng ^= self.rng << 13;
+                self.rng ^= self.rng >> 7;
+                self.rng ^= self.rng << 17;
+                self.bag.swap(i, (self.rng % (i as u64 + 1)) as usize);
+            }
+        }
+        self.bag.pop().unwrap_or(Kind::T)
+    }
+
+    fn fits(&self, piece: &Piece) -> bool {
+        piece.cells().iter().all(|&(x, y)| {
+            (0..WIDTH as i16).contains(&x)
+                && (0..HEIGHT as i16).contains(&y)
+                && self.board[y as usize][x as usize].is_none()
+        })
+    }
+
+    /// Move the piece if it fits there
+    fn try_move(&mut self, piece: Piece) -> bool {
+        let fits = self.fits(&piece);
+        if fits {
+            self.piece = piece;
+        }
+        fits
+    }
+
+    pub fn shift(&mut self, dx: i16) -> bool {
+        !self.game_over && self.try_move(Piece { x: self.piece.x + dx, ..self.piece })
+    }
+
+    /// Rotate clockwise, nudging sideways off walls and blocks if needed
+    pub fn rotate(&mut self) -> bool {
+        if self.game_over {
+            return false;
+        }
+        let rotated = Piece { rotation: (self.piece.rotation + 1) % 4, ..self.piece };
+        [0, -1, 1, -2, 2].iter().any(|&kick| self.try_move(Piece { x: rotated.x + kick, ..rotated }))
+    }
+
+    /// Move down one row, locking the piece when it lands; returns whether it moved
+    pub fn tick(&mut self) -> bool {
+        if self.game_over {
+            return false;
+        }
+        if self.try_move(Piece { y: self.piece.y + 1, ..self.piece }) {
+            return true;
+        }
+        self.lock();
+        false
+    }
+
+    pub fn soft_drop(&mut self) {
+        if self.tick() {
+            self.score += 1;
+        }
+    }
+
+    pub fn hard_drop(&mut self) {
+        while self.try_move(Piece { y: self.piece.y + 1, ..self.piece }) {
+            self.score += 2;
+        }
+        self.tick();
+    }
+
+    /// Where the piece would land, for the drop shadow
+    pub fn ghost(&self) -> Piece {
+        let mut ghost = self.piece;
+        while self.fits(&Piece { y: ghost.y + 1, ..ghost }) {
+            ghost.y += 1;
+        }
+        ghost
+    }
+
+    fn lock(&mut self) {
+        for (x, y) in self.piece.cells() {
+            self.board[y as usize][x as usize] = Some(self.piece.kind.color());
+        }
+        let before = self.board.len();
+        self.board.retain(|row| row.iter().any(Option::is_none));
+        let cleared = before - self.board.len();
+        for _ in 0..cleared {
+            self.board.insert(0, [None; WIDTH as usize]);
+        }
+        self.score += LINE_POINTS[cleared] * (self.level() + 1);
+        self.lines += cleared as u32;
+
+        self.piece = Piece::spawn(self.next);
+        self.next = self.take_from_bag();
+        if !self.fits(&self.piece) {
+            self.game_over = true;
+        }
+    }
+
+    /// Locked cells grouped by color, as `(column, row)`
+    pub fn locked_cells(&self) -> Vec<(Vec<(u16, u16)>, Color)> {
+        let mut layers: Vec<(Vec<(u16, u16)>, Color)> = Vec::new();
+        for (y, row) in self.board.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let Some(color) = cell else {
+                    continue;
+                };
+                match layers.iter_mut().find(|(_, layer)| layer == color) {
+                    Some((cells, _)) => cells.push((x as u16, y as u16)),
+                    None => layers.push((vec![(x as u16, y as u16)], *color)),
+                }
+            }
+        }
+        layers
+    }
+}
+
+/// Cells of a piece as board coordinates
+pub fn piece_cells(piece: &Piece) -> Vec<(u16, u16)> {
+    piece.cells().iter().map(|&(x, y)| (x as u16, y as u16)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_and_line_clear() {
+        // A T turned clockwise points right
+        let t = Piece { kind: Kind::T, rotation: 1, x: 0, y: 0 };
+        assert_eq!(t.cells(), [(2, 1), (1, 0), (1, 1), (1, 2)]);
+
+        let mut game = Tetris::new(3);
+        for x in 4..WIDTH as usize {
+            game.board[HEIGHT as usize - 1][x] = Some(Color::Gray);
+        }
+        game.piece = Piece { kind: Kind::I, rotation: 0, x: 0, y: 0 };
+        assert_eq!(game.ghost().y, HEIGHT as i16 - 2);
+        game.hard_drop();
+        assert_eq!(game.lines, 1);
+        assert_eq!(game.score, 2 * 18 + 100);
+        assert!(game.board[HEIGHT as usize - 1].iter().all(Option::is_none));
+        assert!(!game.game_over);
+    }
+}
@@ -0,0 +1,425 @@
+//! Text area module
+//!
+//! Multi-line text editor with a cursor, vertical scrolling and mouse-drag
+//! selection. Typing over a selection replaces it, the readline word
+//! operations from `line_edit` work on the cursor's line and `:shortcode:`
+//! emoji expand as they are typed. Ctrl+R opens a find/replace bar that
+//! highlights every match and selects the current one.
+
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+
+use super::emoji::ShortcodeCompleter;
+use super::find_replace::{match_style, FindAction, FindReplace, TextMatch};
+use super::line_edit::handle_edit_key;
+use super::selection::{byte_index, char_at_column, column_of_char, highlight_line, style_chars, Selection, TextPos};
+
+pub struct TextArea {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub cursor: TextPos,
+    pub selection: Selection,
+    scroll: usize,
+    /// Text area of the last render, used to map mouse positions
+    inner: Rect,
+    emoji: ShortcodeCompleter,
+    pub find: FindReplace,
+}
+
+impl TextArea {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            lines: vec![String::new()],
+            cursor: TextPos::default(),
+            selection: Selection::new(),
+            scroll: 0,
+            inner: Rect::default(),
+            emoji: ShortcodeCompleter::new(),
+            find: FindReplace::new(),
+        }
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.lines = text.split('\n').map(str::to_string).collect();
+        self.cursor = TextPos::default();
+        self.selection.clear();
+        self.scroll = 0;
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection.text(&self.lines)
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        self.delete_selection();
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.insert_newline();
+            }
+            let line = &mut self.lines[self.cursor.row];
+            line.insert_str(byte_index(line, self.cursor.col), part);
+            self.cursor.col += part.chars().count();
+        }
+    }
+
+    fn insert_newline(&mut self) {
+        let line = &mut self.lines[self.cursor.row];
+        let rest = line.split_off(byte_index(line, self.cursor.col));
+        self.lines.insert(self.cursor.row + 1, rest);
+        self.cursor = TextPos::new(self.cursor.row + 1, 0);
+    }
+
+    /// Remove the selected text, returning whether there was any
+    pub fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.selection.range() else {
+            return false;
+        };
+        self.selection.clear();
+        let end_row = end.row.min(self.lines.len() - 1);
+        let tail = {
+            let line = &self.lines[end_row];
+            line[byte_index(line, end.col)..].to_string()
+        };
+        let line = &mut self.lines[start.row];
+        line.truncate(byte_index(line, start.col));
+        line.push_str(&tail);
+        self.lines.drain(start.row + 1..=end_row);
+        self.cursor = start;
+        true
+    }
+
+    fn backspace(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        if self.cursor.col > 0 {
+            let line = &mut self.lines[self.cursor.row];
+            line.remove(byte_index(line, self.cursor.col - 1));
+            self.cursor.col -= 1;
+        } else if self.cursor.row > 0 {
+            let line = self.lines.remove(self.cursor.row);
+            self.cursor.row -= 1;
+            self.cursor.col = self.line_len(self.cursor.row);
+            self.lines[self.cursor.row].push_str(&line);
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        if self.cursor.col < self.line_len(self.cursor.row) {
+            let line = &mut self.lines[self.cursor.row];
+            line.remove(byte_index(line, self.cursor.col));
+        } else if self.cursor.row + 1 < self.lines.len() {
+            let next = self.lines.remove(self.cursor.row + 1);
+            self.lines[self.cursor.row].push_str(&next);
+        }
+    }
+
+    fn move_cursor(&mut self, key: KeyCode) {
+        let TextPos { row, col } = self.cursor;
+        self.cursor = match key {
+            KeyCode::Left if col > 0 => TextPos::new(row, col - 1),
+            KeyCode::Left if row > 0 => TextPos::new(row - 1, self.line_len(row - 1)),
+            KeyCode::Right if col < self.line_len(row) => TextPos::new(row, col + 1),
+            KeyCode::Right if row + 1 < self.lines.len() => TextPos::new(row + 1, 0),
+            KeyCode::Up if row > 0 => TextPos::new(row - 1, col.min(self.line_len(row - 1))),
+            KeyCode::Down if row + 1 < self.lines.len() => TextPos::new(row + 1, col.min(self.line_len(row + 1))),
+            KeyCode::Home => TextPos::new(row, 0),
+            KeyCode::End => TextPos::new(row, self.line_len(row)),
+            _ => self.cursor,
+        };
+        self.selection.clear();
+    }
+
+    pub fn select_all(&mut self) {
+        let last = self.lines.len() - 1;
+        self.cursor = TextPos::new(last, self.line_len(last));
+        self.selection.select(TextPos::default(), self.cursor);
+    }
+
+    /// Word motion and kill keys, applied within the cursor's line
+    fn edit_line(&mut self, key: KeyEvent) {
+        self.selection.clear();
+        handle_edit_key(&mut self.lines[self.cursor.row], &mut self.cursor.col, key);
+    }
+
+    /// Character columns of `found` on its line
+    fn match_columns(&self, found: TextMatch) -> (usize, usize) {
+        let line = &self.lines[found.row];
+        (line[..found.start].chars().count(), line[..found.end].chars().count())
+    }
+
+    /// Make match `index` current: select it and move the cursor to its end
+    fn select_match(&mut self, matches: &[TextMatch], index: usize) {
+        let Some(&found) = matches.get(index) else { return };
+        self.find.current = index;
+        let (start, end) = self.match_columns(found);
+        self.cursor = TextPos::new(found.row, end);
+        self.selection.select(TextPos::new(found.row, start), self.cursor);
+    }
+
+    /// Go `step` matches from the current one; `0` goes to the first match
+    /// at or after the cursor
+    fn find_step(&mut self, step: isize) {
+        let matches = self.find.matches(&self.lines);
+        if matches.is_empty() {
+            self.find.current = 0;
+            return;
+        }
+        let index = if step == 0 {
+            let from = self.selection.range().map_or(self.cursor, |(start, _)| start);
+            matches
+                .iter()
+                .position(|&found| {
+                    let (start, _) = self.match_columns(found);
+                    TextPos::new(found.row, start) >= from
+                })
+                .unwrap_or(0)
+        } else {
+            (self.find.current as isize + step).rem_euclid(matches.len() as isize) as usize
+        };
+        self.select_match(&matches, index);
+    }
+
+    /// Replace the current match and select the one after it
+    fn replace_current(&mut self) {
+        let matches = self.find.matches(&self.lines);
+        let Some(&found) = matches.get(self.find.current) else { return };
+        let replacement = self.find.replacement_for(&self.lines[found.row], found);
+        self.lines[found.row].replace_range(found.start..found.end, &replacement);
+        self.selection.clear();
+        let (start, _) = self.match_columns(TextMatch { end: found.start, ..found });
+        self.cursor = TextPos::new(found.row, start + replacement.chars().count());
+        self.find_step(0);
+    }
+
+    /// Replace every match, returning how many lines changed
+    pub fn replace_all(&mut self) -> usize {
+        let mut changed = 0;
+        for line in &mut self.lines {
+            if let Some(replaced) = self.find.replace_all_in(line) {
+                *line = replaced;
+                changed += 1;
+            }
+        }
+        self.selection.clear();
+        self.cursor.col = self.cursor.col.min(self.line_len(self.cursor.row));
+        self.find.current = 0;
+        changed
+    }
+
+    fn handle_find_key(&mut self, key: KeyEvent) {
+        match self.find.handle_key(key) {
+            FindAction::Search => self.find_step(0),
+            FindAction::Next => self.find_step(1),
+            FindAction::Previous => self.find_step(-1),
+            FindAction::ReplaceOne => self.replace_current(),
+            FindAction::ReplaceAll => {
+                self.replace_all();
+            }
+            FindAction::Close | FindAction::None => {}
+        }
+    }
+
+    /// Returns whether the key was used. Ctrl+C is left to the host so it
+    /// can copy `selected_text` to its clipboard.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        if self.find.visible {
+            self.handle_find_key(key);
+            return true;
+        }
+        if FindReplace::is_shortcut(&key) {
+            // Start from the selected text when it is on one line
+            if let Some(text) = self.selected_text().filter(|text| !text.is_empty() && !text.contains('\n')) {
+                self.find.query = text;
+            }
+            self.find.open();
+            self.find_step(0);
+            return true;
+        }
+        let row = self.cursor.row;
+        if self.selection.is_empty() && self.emoji.handle_key(&mut self.lines[row], &mut self.cursor.col, key) {
+            return true;
+        }
+        match key.code {
+            KeyCode::Char('a') if ctrl => self.select_all(),
+            KeyCode::Left | KeyCode::Right if ctrl => self.edit_line(key),
+            KeyCode::Backspace if ctrl || alt => self.edit_line(key),
+            KeyCode::Char('w' | 'h' | 'u' | 'k') if ctrl => self.edit_line(key),
+            KeyCode::Char('b' | 'd' | 'f') if alt => self.edit_line(key),
+            KeyCode::Char(_) if ctrl || alt => return false,
+            KeyCode::Char(c) => {
+                self.insert_str(&c.to_string());
+                self.emoji.after_edit(&mut self.lines[self.cursor.row], &mut self.cursor.col);
+            }
+            KeyCode::Enter => {
+                self.delete_selection();
+                self.insert_newline();
+            }
+            KeyCode::Tab => self.insert_str("    "),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down | KeyCode::Home | KeyCode::End => {
+                self.move_cursor(key.code)
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Drag to select; a click also moves the cursor
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        let (lines, scroll, inner) = (&self.lines, self.scroll, self.inner);
+        let position_at = |column, row| position_in(lines, scroll, inner, column, row);
+        let used = self.selection.handle_mouse(mouse, position_at);
+        if used {
+            if let Some(pos) = position_at(mouse.column, mouse.row) {
+                self.cursor = pos;
+            }
+        }
+        used
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, focused: bool) {
+        let border = if focused { Color::Cyan } else { Color::Gray };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.title.clone())
+            .border_style(Style::default().fg(border));
+        let block = if self.find.visible {
+            block.title_bottom(" Enter/↓: next | ↑: previous | Tab: replace field | Enter: replace | Ctrl+A: all | Alt+X: regex | Esc ")
+        } else {
+            block
+        };
+        let mut inner = block.inner(area);
+        let matches = if self.find.visible { self.find.matches(&self.lines) } else { Vec::new() };
+        if self.find.visible && inner.height > 1 {
+            let bar = Rect { y: inner.bottom() - 1, height: 1, ..inner };
+            inner.height -= 1;
+            frame.render_widget(Paragraph::new(self.find.line(matches.len())), bar);
+        }
+        self.inner = inner;
+
+        let height = inner.height.max(1) as usize;
+        if self.cursor.row < self.scroll {
+            self.scroll = self.cursor.row;
+        } else if self.cursor.row >= self.scroll + height {
+            self.scroll = self.cursor.row + 1 - height;
+        }
+
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(height)
+            .map(|(row, text)| {
+                let line = matches
+                    .iter()
+                    .filter(|found| found.row == row)
+                    .fold(Line::raw(text.clone()), |line, &found| {
+                        let (start, end) = self.match_columns(found);
+                        style_chars(line, start, end, match_style())
+                    });
+                let range = self.selection.columns_on(row, text.chars().count());
+                highlight_line(line, 0, range)
+            })
+            .collect();
+        frame.render_widget(block, area);
+        frame.render_widget(Paragraph::new(lines), inner);
+
+        if focused && !self.find.visible {
+            let line = &self.lines[self.cursor.row];
+            let x = inner.x + column_of_char(line, self.cursor.col) as u16;
+            let y = inner.y + (self.cursor.row - self.scroll) as u16;
+            if x < inner.right() && y < inner.bottom() {
+                frame.set_cursor_position(Position::new(x, y));
+                let bounds = frame.area();
+                self.emoji.render(frame, line, self.cursor.col, Position::new(x, y), bounds);
+            }
+        }
+    }
+}
+
+/// Text position under a screen cell, given the rendered text area
+fn position_in(lines: &[String], scroll: usize, inner: Rect, column: u16, row: u16) -> Option<TextPos> {
+    if inner.width == 0 || row < inner.y || row >= inner.bottom() || column < inner.x {
+        return None;
+    }
+    let line_row = (scroll + (row - inner.y) as usize).min(lines.len() - 1);
+    let col = char_at_column(&lines[line_row], (column - inner.x) as usize);
+    Some(TextPos::new(line_row, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_edit_and_replace_selection() {
+        let mut area = TextArea::new("Notes").with_text("one\ntwo\nthree");
+        area.cursor = TextPos::new(1, 3);
+        area.handle_key(key(KeyCode::Enter));
+        area.insert_str("x");
+        assert_eq!(area.text(), "one\ntwo\nx\nthree");
+
+        area.selection.select(TextPos::new(0, 1), TextPos::new(2, 1));
+        assert_eq!(area.selected_text().as_deref(), Some("ne\ntwo\nx"));
+        area.handle_key(key(KeyCode::Char('!')));
+        assert_eq!(area.text(), "o!\nthree");
+
+        area.handle_key(key(KeyCode::Home));
+        area.handle_key(key(KeyCode::Backspace));
+        area.cursor = TextPos::new(1, 0);
+        area.handle_key(key(KeyCode::Backspace));
+        assert_eq!(area.text(), "o!three");
+    }
+
+    #[test]
+    fn test_find_and_replace() {
+        let mut area = TextArea::new("Journal").with_text("cat and dog\nanother cat");
+        area.handle_key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        for c in "cat".chars() {
+            area.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(area.selected_text().as_deref(), Some("cat"));
+        area.handle_key(key(KeyCode::Down));
+        assert_eq!((area.find.current, area.cursor), (1, TextPos::new(1, 11)));
+
+        area.handle_key(key(KeyCode::Tab));
+        area.handle_key(key(KeyCode::Char('x')));
+        area.handle_key(key(KeyCode::Enter));
+        assert_eq!(area.text(), "cat and dog\nanother x");
+        assert_eq!(area.replace_all(), 1);
+        assert_eq!(area.text(), "x and dog\nanother x");
+    }
+}
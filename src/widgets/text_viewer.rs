@@ -0,0 +1,350 @@
+//! Text viewer module
+//!
+//! Read-only, scrollable view of a text file with a line-number gutter.
+//! URLs in plain lines are underlined and open on click, and text can be
+//! selected by dragging with the mouse. In pager mode the keys follow
+//! `less`: `/` searches with the find bar, `n`/`N` step through the
+//! matches and the footer shows how far through the file the view is.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+
+use super::ansi::ansi_line;
+use super::find_replace::{match_style, FindAction, FindReplace, TextMatch};
+use super::rich_text::{detect_links, link_line, LinkRegions};
+use super::selection::{char_at_column, highlight_line, selection_style, style_chars, Selection, TextPos};
+
+pub struct TextViewer {
+    pub path: PathBuf,
+    pub lines: Vec<String>,
+    pub scroll: usize,
+    /// Zero-based line drawn highlighted, e.g. a search match
+    pub highlight: Option<usize>,
+    /// Rows visible at the last render, used for paging
+    page_height: usize,
+    links: LinkRegions,
+    pub selection: Selection,
+    /// Screen area of the text (right of the gutter) at the last render
+    text_area: Rect,
+    /// `less`-style keys and footer
+    pager: bool,
+    /// Search bar of the pager
+    pub find: FindReplace,
+}
+
+impl TextViewer {
+    pub fn new(path: &Path, content: &str) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            lines: content.lines().map(|line| line.replace('\t', "    ")).collect(),
+            scroll: 0,
+            highlight: None,
+            page_height: 20,
+            links: LinkRegions::new(),
+            selection: Selection::new(),
+            text_area: Rect::default(),
+            pager: false,
+            find: FindReplace::find_only(),
+        }
+    }
+
+    /// Use `less`-style keys and show the position as a percentage
+    pub fn pager(mut self) -> Self {
+        self.pager = true;
+        self
+    }
+
+    pub fn is_pager(&self) -> bool {
+        self.pager
+    }
+
+    /// Read a file, replacing invalid UTF-8 rather than failing
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let bytes = fs::read(path)?;
+        Ok(Self::new(path, &String::from_utf8_lossy(&bytes)))
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.page_height)
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) {
+        let target = self.scroll as isize + delta;
+        self.scroll = target.clamp(0, self.max_scroll() as isize) as usize;
+    }
+
+    /// Highlight a zero-based line and scroll it to the middle of the view
+    pub fn jump_to(&mut self, line: usize) {
+        let line = line.min(self.lines.len().saturating_sub(1));
+        self.highlight = Some(line);
+        self.scroll = line.saturating_sub(self.page_height / 2).min(self.max_scroll());
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        let page = self.page_height as isize;
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll_by(-page),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by(page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            KeyCode::End | KeyCode::Char('G') => self.scroll = self.max_scroll(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Pager keys: the search bar while it is open, then `less` bindings,
+    /// then the plain viewer keys
+    pub fn handle_pager_key(&mut self, key: KeyEvent) -> bool {
+        if self.find.visible {
+            if key.code == KeyCode::Enter {
+                // Like less, Enter runs the search and leaves the prompt
+                self.find.visible = false;
+                return true;
+            }
+            match self.find.handle_key(key) {
+                FindAction::Search => self.search_from(self.scroll),
+                FindAction::Next => self.step_match(1),
+                FindAction::Previous => self.step_match(-1),
+                FindAction::Close => self.find.query.clear(),
+                _ => {}
+            }
+            return true;
+        }
+        let half = (self.page_height / 2).max(1) as isize;
+        let page = self.page_height as isize;
+        match key.code {
+            KeyCode::Char('/') => {
+                self.find.query.clear();
+                self.find.open();
+            }
+            KeyCode::Char('n') => self.step_match(1),
+            KeyCode::Char('N') => self.step_match(-1),
+            KeyCode::Char('f') => self.scroll_by(page),
+            KeyCode::Char('b') => self.scroll_by(-page),
+            KeyCode::Char('d') => self.scroll_by(half),
+            KeyCode::Char('u') => self.scroll_by(-half),
+            code => return self.handle_key(code),
+        }
+        true
+    }
+
+    /// Make the first match at or below `row` current, wrapping to the top
+    fn search_from(&mut self, row: usize) {
+        let matches = self.find.matches(&self.lines);
+        let Some(index) = matches.iter().position(|found| found.row >= row).or((!matches.is_empty()).then_some(0)) else {
+            self.highlight = None;
+            return;
+        };
+        self.show_match(&matches, index);
+    }
+
+    /// Move `delta` matches from the current one, wrapping around
+    fn step_match(&mut self, delta: isize) {
+        let matches = self.find.matches(&self.lines);
+        if matches.is_empty() {
+            return;
+        }
+        let index = (self.find.current as isize + delta).rem_euclid(matches.len() as isize) as usize;
+        self.show_match(&matches, index);
+    }
+
+    fn show_match(&mut self, matches: &[TextMatch], index: usize) {
+        self.find.current = index;
+        self.jump_to(matches[index].row);
+    }
+
+    /// How far through the file the bottom of the view is
+    pub fn percent(&self) -> usize {
+        if self.lines.is_empty() {
+            return 100;
+        }
+        (self.scroll + self.page_height).min(self.lines.len()) * 100 / self.lines.len()
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection.text(&self.lines)
+    }
+
+    /// Text position under a screen cell of the last render
+    fn position_at(&self, column: u16, row: u16) -> Option<TextPos> {
+        let area = self.text_area;
+        if self.lines.is_empty() || row < area.y || row >= area.bottom() || column < area.x || column >= area.right() {
+            return None;
+        }
+        let line = (self.scroll + (row - area.y) as usize).min(self.lines.len() - 1);
+        Some(TextPos::new(line, char_at_column(&self.lines[line], (column - area.x) as usize)))
+    }
+
+    /// Drag to select text and track link hovering; a click without a drag
+    /// returns the URL of the link under the pointer
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<String> {
+        match mouse.kind {
+            MouseEventKind::Moved => {
+                self.links.handle_mouse(mouse);
+                None
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                let clicked = self.selection.is_dragging() && self.selection.is_empty();
+                self.selection.handle_mouse(mouse, |_, _| None);
+                if clicked {
+                    self.links.link_at(mouse.column, mouse.row).map(str::to_string)
+                } else {
+                    None
+                }
+            }
+            _ => {
+                let position = self.position_at(mouse.column, mouse.row);
+                if mouse.kind == MouseEventKind::Down(MouseButton::Left) && position.is_none() {
+                    self.selection.clear();
+                }
+                self.selection.handle_mouse(mouse, |_, _| position);
+                None
+            }
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let range = format!(
+            "{}-{} / {}",
+            self.scroll + 1,
+            (self.scroll + self.page_height).min(self.lines.len()),
+            self.lines.len()
+        );
+        let footer = if self.find.visible {
+            " Enter: search | ↓/↑: next/previous | Alt+X: regex | Esc: cancel ".to_string()
+        } else if self.pager {
+            let position = if self.scroll >= self.max_scroll() { "(END)".to_string() } else { format!("{}%", self.percent()) };
+            format!("{} {} | /: search | n/N: next/previous | Space/b: page | d/u: half page | g/G: top/end | e: edit | q: close", range, position)
+        } else {
+            format!("{} | ↑↓ PgUp/PgDn: scroll | drag: select | Ctrl+C: copy | e: edit | Esc: close", range)
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("📄 {}", self.path.display()))
+            .title_bottom(footer);
+        let mut inner = block.inner(area);
+        let matches = if self.pager { self.find.matches(&self.lines) } else { Vec::new() };
+        let bar = (self.find.visible && inner.height > 1).then(|| {
+            inner.height -= 1;
+            Rect { y: inner.bottom(), height: 1, ..inner }
+        });
+        self.page_height = inner.height.max(1) as usize;
+        self.scroll = self.scroll.min(self.max_scroll());
+
+        let gutter = self.lines.len().to_string().len();
+        let text_x = inner.x + gutter as u16 + 3;
+        self.text_area = Rect {
+            x: text_x.min(inner.right()),
+            width: inner.width.saturating_sub(gutter as u16 + 3),
+            ..inner
+        };
+        self.links.clear();
+        let links = &mut self.links;
+        let selection = &self.selection;
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(self.page_height)
+            .enumerate()
+            .map(|(row, (i, line))| {
+                let mut spans = vec![Span::styled(
+                    format!("{:>width$} │ ", i + 1, width = gutter),
+                    Style::default().fg(Color::DarkGray),
+                )];
+                if line.contains('\x1b') {
+                    // Log files often carry color codes; render them instead of raw escapes
+                    spans.extend(ansi_line(line, Style::default()).spans);
+                } else {
+                    let found = detect_links(line);
+                    links.record(text_x, inner.y + row as u16, line, &found, inner);
+                    spans.extend(link_line(line, &found, Style::default(), links.hovered.as_deref()).spans);
+                }
+                let mut styled = Line::from(spans);
+                if self.highlight == Some(i) {
+                    styled = styled.style(Style::default().bg(Color::DarkGray));
+                }
+                if !line.contains('\x1b') {
+                    for (index, found) in matches.iter().enumerate().filter(|(_, found)| found.row == i) {
+                        let start = gutter + 3 + line[..found.start].chars().count();
+                        let end = gutter + 3 + line[..found.end].chars().count();
+                        let style = if index == self.find.current { selection_style() } else { match_style() };
+                        styled = style_chars(styled, start, end, style);
+                    }
+                }
+                highlight_line(styled, gutter + 3, selection.columns_on(i, line.chars().count()))
+            })
+            .collect();
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+        if let Some(bar) = bar {
+            frame.render_widget(Paragraph::new(self.find.line(matches.len())), bar);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_is_clamped() {
+        let content = (1..=30).map(|i| i.to_string()).collect::<Vec<_>>().join("\n");
+        let mut viewer = TextViewer::new(Path::new("numbers.txt"), &content);
+        viewer.scroll_by(-5);
+        assert_eq!(viewer.scroll, 0);
+        viewer.handle_key(KeyCode::End);
+        assert_eq!(viewer.scroll, 10);
+        viewer.scroll_by(100);
+        assert_eq!(viewer.scroll, 10);
+    }
+
+    #[test]
+    fn test_pager_search() {
+        let content = (1..=100).map(|i| format!("line {}", i)).collect::<Vec<_>>().join("\n");
+        let mut viewer = TextViewer::new(Path::new("numbers.txt"), &content).pager();
+        viewer.handle_pager_key(KeyEvent::from(KeyCode::Char('/')));
+        for c in "line 5".chars() {
+            viewer.handle_pager_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        viewer.handle_pager_key(KeyEvent::from(KeyCode::Enter));
+        assert!(!viewer.find.visible);
+        assert_eq!(viewer.highlight, Some(4));
+        viewer.handle_pager_key(KeyEvent::from(KeyCode::Char('n')));
+        assert_eq!(viewer.highlight, Some(49));
+        viewer.handle_pager_key(KeyEvent::from(KeyCode::Char('N')));
+        viewer.handle_pager_key(KeyEvent::from(KeyCode::Char('N')));
+        assert_eq!(viewer.highlight, Some(58));
+
+        viewer.handle_pager_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(viewer.percent(), 100);
+        viewer.handle_pager_key(KeyEvent::from(KeyCode::Char('g')));
+        assert_eq!(viewer.percent(), 20);
+    }
+
+    #[test]
+    fn test_jump_to_centers_line() {
+        let content = (1..=100).map(|i| i.to_string()).collect::<Vec<_>>().join("\n");
+        let mut viewer = TextViewer::new(Path::new("numbers.txt"), &content);
+        viewer.jump_to(50);
+        assert_eq!(viewer.highlight, Some(50));
+        assert_eq!(viewer.scroll, 40);
+        viewer.jump_to(500);
+        assert_eq!(viewer.highlight, Some(99));
+        assert_eq!(viewer.scroll, 80);
+    }
+}
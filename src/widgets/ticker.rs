@@ -0,0 +1,338 @@
+//! Price ticker module
+//!
+//! Rolling OHLC series per symbol fed by a stream of quotes, threshold alerts
+//! that fire once when a price crosses them, and line and candlestick charts
+//! of a series. `SimulatedFeed` produces random-walk quotes for demos.
+
+use std::collections::VecDeque;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{self, Receiver};
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols::Marker,
+    text::Span,
+    widgets::{Axis, Block, Chart, Dataset, GraphType, Widget},
+};
+
+/// Candles kept per symbol
+const HISTORY: usize = 120;
+
+/// One open/high/low/close bar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Candle {
+    fn new(price: f64) -> Self {
+        Self {
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+        }
+    }
+
+    fn update(&mut self, price: f64) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// Rolling candles of one symbol; every `ticks_per_candle` quotes start a new candle
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceSeries {
+    pub symbol: String,
+    pub candles: VecDeque<Candle>,
+    ticks_per_candle: usize,
+    ticks: usize,
+}
+
+impl PriceSeries {
+    pub fn new(symbol: &str, ticks_per_candle: usize) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            candles: VecDeque::new(),
+            ticks_per_candle: ticks_per_candle.max(1),
+            ticks: 0,
+        }
+    }
+
+    pub fn push(&mut self, price: f64) {
+        match self.candles.back_mut() {
+            Some(candle) if self.ticks < self.ticks_per_candle => candle.update(price),
+            _ => {
+                self.candles.push_back(Candle::new(price));
+                self.ticks = 0;
+                if self.candles.len() > HISTORY {
+                    self.candles.pop_front();
+                }
+            }
+        }
+        self.ticks += 1;
+    }
+
+    pub fn last(&self) -> Option<f64> {
+        self.candles.back().map(|candle| candle.close)
+    }
+
+    /// Change since the first kept candle opened, in percent
+    pub fn change_percent(&self) -> Option<f64> {
+        let first = self.candles.front()?.open;
+        let last = self.last()?;
+        (first != 0.0).then(|| (last - first) / first * 100.0)
+    }
+
+    /// Lowest and highest price in the window
+    pub fn bounds(&self) -> Option<(f64, f64)> {
+        let low = self.candles.iter().map(|candle| candle.low).reduce(f64::min)?;
+        let high = self.candles.iter().map(|candle| candle.high).reduce(f64::max)?;
+        Some((low, high))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing {
+    Above,
+    Below,
+}
+
+impl Crossing {
+    pub const ALL: [&'static str; 2] = ["Above", "Below"];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Crossing::Above => "Above",
+            Crossing::Below => "Below",
+        }
+    }
+}
+
+/// Fires once when the price moves from one side of `price` to the other
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub symbol: String,
+    pub crossing: Crossing,
+    pub price: f64,
+    pub triggered: bool,
+}
+
+impl Alert {
+    pub fn new(symbol: &str, crossing: Crossing, price: f64) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            crossing,
+            price,
+            triggered: false,
+        }
+    }
+
+    /// Whether moving from `previous` to `current` crosses the threshold
+    fn crossed(&self, previous: f64, current: f64) -> bool {
+        match self.crossing {
+            Crossing::Above => previous < self.price && current >= self.price,
+            Crossing::Below => previous > self.price && current <= self.price,
+        }
+    }
+}
+
+/// Series for a set of symbols and the alerts on them
+pub struct Ticker {
+    pub series: Vec<PriceSeries>,
+    pub alerts: Vec<Alert>,
+}
+
+impl Ticker {
+    pub fn new(symbols: &[&str], ticks_per_candle: usize) -> Self {
+        Self {
+            series: symbols.iter().map(|symbol| PriceSeries::new(symbol, ticks_per_candle)).collect(),
+            alerts: Vec::new(),
+        }
+    }
+
+    /// Record a quote and return the alerts it triggered
+    pub fn quote(&mut self, symbol: &str, price: f64) -> Vec<Alert> {
+        let Some(series) = self.series.iter_mut().find(|series| series.symbol == symbol) else {
+            return Vec::new();
+        };
+        let previous = series.last();
+        series.push(price);
+        let Some(previous) = previous else {
+            return Vec::new();
+        };
+        self.alerts
+            .iter_mut()
+            .filter(|alert| !alert.triggered && alert.symbol == symbol && alert.crossed(previous, price))
+            .map(|alert| {
+                alert.triggered = true;
+                alert.clone()
+            })
+            .collect()
+    }
+}
+
+fn next_random(state: &mut u64) -> f64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    (*state >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Random-walk prices standing in for an exchange stream
+pub struct SimulatedFeed {
+    prices: Vec<(String, f64)>,
+    state: u64,
+}
+
+impl SimulatedFeed {
+    pub fn new(prices: &[(&str, f64)], seed: u64) -> Self {
+        Self {
+            prices: prices.iter().map(|(symbol, price)| (symbol.to_string(), *price)).collect(),
+            state: seed.max(1),
+        }
+    }
+
+    /// Move every price by up to ±1% and return the new quotes
+    pub fn next_quotes(&mut self) -> Vec<(String, f64)> {
+        for (_, price) in &mut self.prices {
+            let step = (next_random(&mut self.state) - 0.5) * 0.02;
+            *price = (*price * (1.0 + step) * 100.0).round() / 100.0;
+        }
+        self.prices.clone()
+    }
+
+    /// Stream quotes from a background thread every `interval`; the browser
+    /// has no threads, so there the caller drives `next_quotes` itself
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn(mut self, interval: Duration) -> Receiver<(String, f64)> {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || loop {
+            for quote in self.next_quotes() {
+                if sender.send(quote).is_err() {
+                    return;
+                }
+            }
+            thread::sleep(interval);
+        });
+        receiver
+    }
+}
+
+/// Closing prices as a line chart with price bounds on the y axis
+pub fn line_chart<'a>(series: &PriceSeries, data: &'a [(f64, f64)], block: Block<'a>) -> Chart<'a> {
+    let (low, high) = series.bounds().unwrap_or((0.0, 1.0));
+    let color = if series.change_percent().unwrap_or(0.0) >= 0.0 { Color::Green } else { Color::Red };
+    let dataset = Dataset::default()
+        .marker(Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(color))
+        .data(data);
+    Chart::new(vec![dataset])
+        .block(block)
+        .x_axis(Axis::default().bounds([0.0, data.len().max(2) as f64 - 1.0]))
+        .y_axis(
+            Axis::default()
+                .bounds([low, high])
+                .labels(vec![Span::raw(format!("{:.2}", low)), Span::raw(format!("{:.2}", high))]),
+        )
+}
+
+/// Chart points of the closing prices
+pub fn close_points(series: &PriceSeries) -> Vec<(f64, f64)> {
+    series.candles.iter().enumerate().map(|(i, candle)| (i as f64, candle.close)).collect()
+}
+
+/// One candle per column, newest on the right: `│` wicks and `█` bodies,
+/// green when the candle closed up and red when it closed down
+pub struct CandleChart<'a> {
+    series: &'a PriceSeries,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> CandleChart<'a> {
+    pub fn new(series: &'a PriceSeries) -> Self {
+        Self { series, block: None }
+    }
+
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl Widget for CandleChart<'_> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        let Some((low, high)) = self.series.bounds() else {
+            return;
+        };
+        if area.height == 0 {
+            return;
+        }
+        let span = (high - low).max(f64::EPSILON);
+        // Row of a price, 0 at the top
+        let row = |price: f64| ((high - price) / span * (area.height - 1) as f64).round() as u16;
+        let visible = self.series.candles.len().min(area.width as usize);
+        let skip = self.series.candles.len() - visible;
+        for (i, candle) in self.series.candles.iter().skip(skip).enumerate() {
+            let x = area.x + area.width - visible as u16 + i as u16;
+            let color = if candle.is_up() { Color::Green } else { Color::Red };
+            let (body_top, body_bottom) = (row(candle.open.max(candle.close)), row(candle.open.min(candle.close)));
+            for y in row(candle.high)..=row(candle.low) {
+                let symbol = if (body_top..=body_bottom).contains(&y) { "█" } else { "│" };
+                buf.set_string(x, area.y + y, symbol, Style::default().fg(color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candles_and_alerts() {
+        let mut ticker = Ticker::new(&["BTC"], 3);
+        ticker.alerts.push(Alert::new("BTC", Crossing::Above, 105.0));
+        ticker.alerts.push(Alert::new("BTC", Crossing::Below, 95.0));
+
+        for price in [100.0, 104.0, 98.0] {
+            assert!(ticker.quote("BTC", price).is_empty());
+        }
+        let fired = ticker.quote("BTC", 106.0);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].crossing, Crossing::Above);
+        // Already triggered alerts stay quiet
+        ticker.quote("BTC", 100.0);
+        assert!(ticker.quote("BTC", 110.0).is_empty());
+
+        let series = &ticker.series[0];
+        assert_eq!(series.candles.len(), 2);
+        assert_eq!(series.candles[0], Candle { open: 100.0, high: 104.0, low: 98.0, close: 98.0 });
+        assert_eq!(series.candles[1].close, 110.0);
+        assert_eq!(series.bounds(), Some((98.0, 110.0)));
+        assert_eq!(series.change_percent(), Some(10.0));
+    }
+}
@@ -0,0 +1,353 @@
+//! Timeline widget module
+//!
+//! Gantt-style view of scheduled steps along a horizontal time axis, with a
+//! "now" marker, horizontal scrolling and zoom. Used to visualise the send
+//! sequence stored in a campaign's `campaign.json`.
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use serde_json::Value;
+
+use crate::event::KeyCode;
+
+use super::truncate::truncate_end;
+
+/// Width reserved for the step labels on the left
+const LABEL_WIDTH: usize = 20;
+
+/// Available zoom levels, in hours per cell
+const SCALES: &[i64] = &[1, 2, 4, 6, 12, 24];
+
+/// Columns between two tick labels on the axis
+const TICK_SPACING: usize = 10;
+
+/// A single scheduled step on the timeline
+#[derive(Clone, Debug)]
+pub struct TimelineStep {
+    pub label: String,
+    pub start: DateTime<Utc>,
+    pub duration: Duration,
+}
+
+pub struct Timeline {
+    pub title: String,
+    pub steps: Vec<TimelineStep>,
+    pub origin: DateTime<Utc>,
+    pub selected: usize,
+    /// Horizontal scroll offset in cells
+    pub scroll: i64,
+    scale_index: usize,
+    now: Option<DateTime<Utc>>,
+}
+
+impl Timeline {
+    pub fn new(title: &str, origin: DateTime<Utc>, steps: Vec<TimelineStep>) -> Self {
+        Self {
+            title: title.to_string(),
+            steps,
+            origin,
+            selected: 0,
+            scroll: 0,
+            scale_index: 0,
+            now: None,
+        }
+    }
+
+    /// Build a timeline from a parsed `campaign.json`
+    ///
+    /// Reads the `sequence` array (`template` + `delay_hours`) relative to the
+    /// campaign's `created_at`. Older campaign files without a sequence list
+    /// their templates, which are shown as immediate sends.
+    pub fn from_campaign(json: &Value) -> Self {
+        let campaign = json.get("campaign").unwrap_or(json);
+        let title = campaign
+            .get("name")
+            .or_else(|| json.get("campaign_name"))
+            .and_then(Value::as_str)
+            .unwrap_or("Campaign");
+        let origin = campaign
+            .get("created_at")
+            .or_else(|| json.get("created_at"))
+            .and_then(Value::as_str)
+            .and_then(parse_timestamp)
+            .unwrap_or_else(Utc::now);
+
+        let mut offsets: Vec<(String, i64)> = match json.get("sequence").and_then(Value::as_array) {
+            Some(sequence) => sequence
+                .iter()
+                .map(|step| {
+                    let label = step.get("template").and_then(Value::as_str).unwrap_or("step");
+                    let delay = step.get("delay_hours").and_then(Value::as_i64).unwrap_or(0);
+                    (label.to_string(), delay)
+                })
+                .collect(),
+            None => json
+                .get("templates")
+                .and_then(Value::as_array)
+                .map(|templates| {
+                    templates
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(|name| (name.to_string(), 0))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+        offsets.sort_by_key(|(_, delay)| *delay);
+
+        // Each step lasts until the next one is sent; the last one gets a day
+        let steps = offsets
+            .iter()
+            .enumerate()
+            .map(|(i, (label, delay))| {
+                let next = offsets.get(i + 1).map(|(_, d)| *d).unwrap_or(delay + 24);
+                TimelineStep {
+                    label: label.clone(),
+                    start: origin + Duration::hours(*delay),
+                    duration: Duration::hours((next - delay).max(1)),
+                }
+            })
+            .collect();
+
+        Self::new(title, origin, steps)
+    }
+
+    /// Pin the "now" marker to a fixed time instead of the wall clock
+    pub fn with_now(mut self, now: DateTime<Utc>) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    pub fn hours_per_cell(&self) -> i64 {
+        SCALES[self.scale_index]
+    }
+
+    pub fn zoom_in(&mut self) {
+        self.scale_index = self.scale_index.saturating_sub(1);
+    }
+
+    pub fn zoom_out(&mut self) {
+        self.scale_index = (self.scale_index + 1).min(SCALES.len() - 1);
+    }
+
+    pub fn scroll_left(&mut self) {
+        self.scroll -= 1;
+    }
+
+    pub fn scroll_right(&mut self) {
+        self.scroll += 1;
+    }
+
+    pub fn next_step(&mut self) {
+        if !self.steps.is_empty() {
+            self.selected = (self.selected + 1) % self.steps.len();
+        }
+    }
+
+    pub fn previous_step(&mut self) {
+        if !self.steps.is_empty() {
+            self.selected = if self.selected == 0 { self.steps.len() - 1 } else { self.selected - 1 };
+        }
+    }
+
+    /// Scroll so that the "now" marker is visible
+    pub fn jump_to_now(&mut self) {
+        self.jump_to(self.current_time());
+    }
+
+    /// Scroll so that `time` sits near the left edge
+    pub fn jump_to(&mut self, time: DateTime<Utc>) {
+        self.scroll = self.column_for(time) - 2;
+    }
+
+    fn current_time(&self) -> DateTime<Utc> {
+        self.now.unwrap_or_else(Utc::now)
+    }
+
+    /// Absolute column (before scrolling) of a point in time
+    pub fn column_for(&self, time: DateTime<Utc>) -> i64 {
+        (time - self.origin).num_hours().div_euclid(self.hours_per_cell())
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Left => self.scroll_left(),
+            KeyCode::Right => self.scroll_right(),
+            KeyCode::Up => self.previous_step(),
+            KeyCode::Down => self.next_step(),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.zoom_in(),
+            KeyCode::Char('-') => self.zoom_out(),
+            KeyCode::Char('t') => self.jump_to_now(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn axis_line(&self, width: usize) -> Line<'static> {
+        let mut text: Vec<char> = vec![' '; width];
+        for col in 0..width {
+            let absolute = col as i64 + self.scroll;
+            if absolute.rem_euclid(TICK_SPACING as i64) != 0 {
+                continue;
+            }
+            let time = self.origin + Duration::hours(absolute * self.hours_per_cell());
+            for (i, c) in time.format("%m-%d %Hh").to_string().chars().enumerate() {
+                if col + i < width {
+                    text[col + i] = c;
+                }
+            }
+        }
+
+        Line::from(vec![
+            Span::raw(" ".repeat(LABEL_WIDTH)),
+            Span::styled(text.into_iter().collect::<String>(), Style::default().fg(Color::Gray)),
+        ])
+    }
+
+    fn tick_line(&self, width: usize, now_col: Option<usize>) -> Line<'static> {
+        let track: String = (0..width)
+            .map(|col| {
+                if Some(col) == now_col {
+                    '┃'
+                } else if (col as i64 + self.scroll).rem_euclid(TICK_SPACING as i64) == 0 {
+                    '┬'
+                } else {
+                    '─'
+                }
+            })
+            .collect();
+        Line::from(vec![
+            Span::raw(format!("{:<width$}", "Step", width = LABEL_WIDTH)),
+            Span::styled(track, Style::default().fg(Color::DarkGray)),
+        ])
+    }
+
+    fn step_line(&self, index: usize, step: &TimelineStep, width: usize, now_col: Option<usize>) -> Line<'static> {
+        let start = self.column_for(step.start) - self.scroll;
+        let end = self.column_for(step.start + step.duration) - self.scroll;
+        let selected = index == self.selected;
+
+        let bar_style = if selected {
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        let label_style = if selected {
+            Style::default().fg(Color::Black).bg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        let label = truncate_end(&step.label, LABEL_WIDTH - 1);
+        let label = format!("{}{}", label, " ".repeat(LABEL_WIDTH - Span::raw(label.as_ref()).width()));
+        let mut spans = vec![Span::styled(label, label_style)];
+
+        for col in 0..width {
+            let absolute = col as i64;
+            let (symbol, style) = if absolute == start {
+                ("◆", bar_style)
+            } else if absolute > start && absolute < end.max(start + 1) {
+                ("█", bar_style)
+            } else if Some(col) == now_col {
+                ("│", Style::default().fg(Color::Red))
+            } else {
+                ("·", Style::default().fg(Color::DarkGray))
+            };
+            spans.push(Span::styled(symbol, style));
+        }
+        Line::from(spans)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!("{} ({}h/cell)", self.title, self.hours_per_cell()));
+        let inner = block.inner(area);
+        let width = (inner.width as usize).saturating_sub(LABEL_WIDTH);
+
+        let now_col = self.column_for(self.current_time()) - self.scroll;
+        let now_col = (now_col >= 0 && (now_col as usize) < width).then_some(now_col as usize);
+
+        let mut lines = vec![self.axis_line(width), self.tick_line(width, now_col)];
+        if self.steps.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No scheduled steps",
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        for (i, step) in self.steps.iter().enumerate() {
+            lines.push(self.step_line(i, step, width, now_col));
+        }
+
+        if let Some(step) = self.steps.get(self.selected) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("Selected: ", Style::default().fg(Color::Cyan)),
+                Span::raw(format!(
+                    "{} at {} (+{}h)",
+                    step.label,
+                    step.start.format("%Y-%m-%d %H:%M UTC"),
+                    (step.start - self.origin).num_hours()
+                )),
+            ]));
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// Parse the timestamp formats written by the journal CLIs
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S UTC")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Value {
+        serde_json::json!({
+            "campaign": { "name": "Welcome", "created_at": "2025-08-01T00:00:00+00:00" },
+            "sequence": [
+                { "template": "getting_started", "delay_hours": 24 },
+                { "template": "welcome_email", "delay_hours": 0 }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_from_campaign_sorts_sequence() {
+        let timeline = Timeline::from_campaign(&sample());
+        assert_eq!(timeline.title, "Welcome");
+        assert_eq!(timeline.steps.len(), 2);
+        assert_eq!(timeline.steps[0].label, "welcome_email");
+        assert_eq!(timeline.steps[0].duration, Duration::hours(24));
+        assert_eq!((timeline.steps[1].start - timeline.origin).num_hours(), 24);
+    }
+
+    #[test]
+    fn test_legacy_timestamp_format() {
+        assert!(parse_timestamp("2025-08-01 09:27:18 UTC").is_some());
+        assert!(parse_timestamp("not a date").is_none());
+    }
+
+    #[test]
+    fn test_zoom_changes_columns() {
+        let mut timeline = Timeline::from_campaign(&sample());
+        let later = timeline.origin + Duration::hours(24);
+        assert_eq!(timeline.column_for(later), 24);
+        timeline.zoom_out();
+        assert_eq!(timeline.column_for(later), 12);
+    }
+}
@@ -0,0 +1,179 @@
+//! Stopwatch and countdown timer module
+//!
+//! Timers that accumulate time while running, with pause/resume, lap
+//! recording and completion detection for countdowns.
+
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerKind {
+    Stopwatch,
+    Countdown(Duration),
+}
+
+#[derive(Debug, Clone)]
+pub struct Timer {
+    pub name: String,
+    pub kind: TimerKind,
+    pub laps: Vec<Duration>,
+    accumulated: Duration,
+    running_since: Option<Instant>,
+    finished: bool,
+}
+
+impl Timer {
+    pub fn stopwatch(name: &str) -> Self {
+        Self::new(name, TimerKind::Stopwatch)
+    }
+
+    pub fn countdown(name: &str, duration: Duration) -> Self {
+        Self::new(name, TimerKind::Countdown(duration))
+    }
+
+    fn new(name: &str, kind: TimerKind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            laps: Vec::new(),
+            accumulated: Duration::ZERO,
+            running_since: None,
+            finished: false,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Total running time, excluding pauses
+    pub fn elapsed(&self) -> Duration {
+        self.accumulated + self.running_since.map(|since| since.elapsed()).unwrap_or_default()
+    }
+
+    /// Time left on a countdown; `None` for stopwatches
+    pub fn remaining(&self) -> Option<Duration> {
+        match self.kind {
+            TimerKind::Countdown(total) => Some(total.saturating_sub(self.elapsed())),
+            TimerKind::Stopwatch => None,
+        }
+    }
+
+    /// Value to show: elapsed for stopwatches, remaining for countdowns
+    pub fn display_time(&self) -> Duration {
+        self.remaining().unwrap_or_else(|| self.elapsed())
+    }
+
+    pub fn start(&mut self) {
+        if !self.finished && self.running_since.is_none() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    pub fn pause(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += since.elapsed();
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        if self.is_running() {
+            self.pause();
+        } else {
+            self.start();
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.running_since = None;
+        self.finished = false;
+        self.laps.clear();
+    }
+
+    /// Record the current elapsed time as a lap
+    pub fn lap(&mut self) {
+        if self.is_running() {
+            self.laps.push(self.elapsed());
+        }
+    }
+
+    /// Change a countdown's duration by `delta_secs`, clamped to at least one second
+    pub fn adjust(&mut self, delta_secs: i64) {
+        if let TimerKind::Countdown(total) = self.kind {
+            let secs = (total.as_secs() as i64 + delta_secs).max(1) as u64;
+            self.kind = TimerKind::Countdown(Duration::from_secs(secs));
+        }
+    }
+
+    /// Advance the timer; returns true exactly once when a countdown completes
+    pub fn tick(&mut self) -> bool {
+        if self.finished || !self.is_running() {
+            return false;
+        }
+        if let TimerKind::Countdown(total) = self.kind {
+            if self.elapsed() >= total {
+                self.pause();
+                self.accumulated = total;
+                self.finished = true;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Format a duration as `HH:MM:SS.t`, dropping the hours when zero
+pub fn format_duration(duration: Duration) -> String {
+    let total = duration.as_secs();
+    let tenths = duration.subsec_millis() / 100;
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{:02}:{:02}:{:02}.{}", hours, minutes, seconds, tenths)
+    } else {
+        format!("{:02}:{:02}.{}", minutes, seconds, tenths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_countdown_finishes_once() {
+        let mut timer = Timer::countdown("Tea", Duration::ZERO);
+        timer.adjust(-10);
+        assert_eq!(timer.kind, TimerKind::Countdown(Duration::from_secs(1)));
+
+        let mut timer = Timer::countdown("Now", Duration::from_millis(0));
+        timer.start();
+        assert!(timer.tick());
+        assert!(!timer.tick());
+        assert!(timer.is_finished());
+        assert_eq!(timer.remaining(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_pause_and_reset() {
+        let mut timer = Timer::stopwatch("Run");
+        timer.lap();
+        assert!(timer.laps.is_empty());
+        timer.start();
+        timer.lap();
+        timer.pause();
+        assert!(!timer.is_running());
+        assert_eq!(timer.laps.len(), 1);
+        timer.reset();
+        assert_eq!(timer.elapsed(), Duration::ZERO);
+        assert!(timer.laps.is_empty());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(65_300)), "01:05.3");
+        assert_eq!(format_duration(Duration::from_secs(3725)), "01:02:05.0");
+    }
+}
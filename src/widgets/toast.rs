@@ -0,0 +1,278 @@
+//! Toast notification module
+//!
+//! Short-lived notifications stacked in the top-right corner of the screen,
+//! expiring after a per-toast time to live. Every toast is also recorded in
+//! the manager's notification center.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::DemoError;
+
+use super::bus::{Message, Subscriber};
+use super::notifications::NotificationCenter;
+use super::redact::redact;
+
+/// Maximum number of toasts shown at once
+const MAX_VISIBLE: usize = 4;
+
+/// Default time a toast stays on screen
+const DEFAULT_TTL: Duration = Duration::from_secs(4);
+
+/// Width of a toast box in cells
+const TOAST_WIDTH: u16 = 40;
+
+/// Severity of a toast, controls its color and icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    pub fn color(&self) -> Color {
+        match self {
+            ToastLevel::Info => Color::Cyan,
+            ToastLevel::Success => Color::Green,
+            ToastLevel::Warning => Color::Yellow,
+            ToastLevel::Error => Color::Red,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ToastLevel::Info => "ℹ",
+            ToastLevel::Success => "✓",
+            ToastLevel::Warning => "⚠",
+            ToastLevel::Error => "✗",
+        }
+    }
+}
+
+/// Key the user can press while a toast is visible, e.g. `u` for Undo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToastAction {
+    pub key: char,
+    pub label: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub level: ToastLevel,
+    pub action: Option<ToastAction>,
+    created: Instant,
+    ttl: Duration,
+}
+
+impl Toast {
+    pub fn new(level: ToastLevel, message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            level,
+            action: None,
+            created: Instant::now(),
+            ttl: DEFAULT_TTL,
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Offer an action bound to `key` until the toast expires
+    pub fn with_action(mut self, key: char, label: &str) -> Self {
+        self.action = Some(ToastAction {
+            key,
+            label: label.to_string(),
+        });
+        self
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed() >= self.ttl
+    }
+}
+
+/// Queue of active toasts
+#[derive(Default)]
+pub struct ToastManager {
+    pub toasts: VecDeque<Toast>,
+    pub center: NotificationCenter,
+}
+
+impl ToastManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Manager whose notification history is kept across sessions
+    pub fn persistent() -> Self {
+        Self {
+            toasts: VecDeque::new(),
+            center: NotificationCenter::persistent(),
+        }
+    }
+
+    pub fn push(&mut self, mut toast: Toast) {
+        toast.message = redact(&toast.message);
+        self.center.record(toast.level, &toast.message);
+        self.toasts.push_back(toast);
+    }
+
+    pub fn info(&mut self, message: &str) {
+        self.push(Toast::new(ToastLevel::Info, message));
+    }
+
+    pub fn success(&mut self, message: &str) {
+        self.push(Toast::new(ToastLevel::Success, message));
+    }
+
+    pub fn warning(&mut self, message: &str) {
+        self.push(Toast::new(ToastLevel::Warning, message));
+    }
+
+    pub fn error(&mut self, message: &str) {
+        self.push(Toast::new(ToastLevel::Error, message));
+    }
+
+    /// Error toast with the failure's recovery hint. A failure repeated every
+    /// frame is shown once until its toast expires.
+    pub fn report(&mut self, error: &DemoError) {
+        let message = redact(&format!("{} ({})", error, error.hint()));
+        if self.toasts.iter().any(|toast| !toast.is_expired() && toast.message == message) {
+            return;
+        }
+        self.error(&message);
+    }
+
+    /// Drop expired toasts; call once per tick
+    pub fn prune(&mut self) {
+        self.toasts.retain(|toast| !toast.is_expired());
+    }
+
+    /// Remove and return the newest live toast whose action uses `key`
+    pub fn take_action(&mut self, key: char) -> Option<Toast> {
+        let index = self
+            .toasts
+            .iter()
+            .rposition(|toast| !toast.is_expired() && toast.action.as_ref().is_some_and(|action| action.key == key))?;
+        self.toasts.remove(index)
+    }
+
+    pub fn dismiss_all(&mut self) {
+        self.toasts.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Render the newest toasts stacked in the top-right corner of `area`
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = TOAST_WIDTH.min(area.width);
+        let mut y = area.y + 1;
+
+        for toast in self.toasts.iter().rev().take(MAX_VISIBLE) {
+            let inner_width = width.saturating_sub(4).max(1) as usize;
+            let action_text = toast
+                .action
+                .as_ref()
+                .map(|action| format!(" [{}] {}", action.key, action.label))
+                .unwrap_or_default();
+            let text_len = toast.message.chars().count() + action_text.chars().count();
+            let text_lines = (text_len / inner_width + 1) as u16;
+            let height = text_lines + 2;
+            if y + height > area.y + area.height {
+                break;
+            }
+
+            let toast_area = Rect {
+                x: area.x + area.width - width,
+                y,
+                width,
+                height,
+            };
+            let color = toast.level.color();
+            let paragraph = Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", toast.level.icon()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(toast.message.clone()),
+                Span::styled(action_text, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            ]))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(color)))
+            .wrap(Wrap { trim: true });
+
+            frame.render_widget(Clear, toast_area);
+            frame.render_widget(paragraph, toast_area);
+            y += height;
+        }
+    }
+}
+
+/// Copies, opened files and theme changes show as toasts; selections and
+/// status messages are left to the status line
+impl Subscriber for ToastManager {
+    fn on_message(&mut self, message: &Message) {
+        match message {
+            Message::Copied(text) => self.success(&format!("Copied {} characters", text.chars().count())),
+            Message::FileOpened(path) => {
+                let name = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().to_string());
+                self.info(&format!("Opened {}", name));
+            }
+            Message::ThemeChanged(name) => self.info(&format!("Theme: {}", name)),
+            Message::SelectionChanged { .. } | Message::Status(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_expired() {
+        let mut toasts = ToastManager::new();
+        toasts.push(Toast::new(ToastLevel::Info, "gone").with_ttl(Duration::ZERO));
+        toasts.success("kept");
+        toasts.prune();
+        assert_eq!(toasts.toasts.len(), 1);
+        assert_eq!(toasts.toasts[0].message, "kept");
+        assert_eq!(toasts.center.entries.len(), 2);
+    }
+
+    #[test]
+    fn test_take_action() {
+        let mut toasts = ToastManager::new();
+        toasts.push(Toast::new(ToastLevel::Info, "Deleted").with_action('u', "Undo"));
+        toasts.info("plain");
+        assert!(toasts.take_action('x').is_none());
+        assert_eq!(toasts.take_action('u').map(|toast| toast.message), Some("Deleted".to_string()));
+        assert!(toasts.take_action('u').is_none());
+    }
+
+    #[test]
+    fn test_report_shows_repeated_failure_once() {
+        let mut toasts = ToastManager::new();
+        let error = DemoError::Clipboard("no display".to_string());
+        toasts.report(&error);
+        toasts.report(&error);
+        assert_eq!(toasts.toasts.len(), 1);
+        assert_eq!(toasts.toasts[0].level, ToastLevel::Error);
+        assert!(toasts.toasts[0].message.starts_with("Clipboard error: no display ("));
+    }
+}
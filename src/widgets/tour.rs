@@ -0,0 +1,204 @@
+//! Guided tour module
+//!
+//! Walks the user through a screen one region at a time: the region is
+//! outlined, the rest of the screen dimmed, and a popup next to it explains
+//! what it is for. A screen describes its tour as a list of `TourStep`s and
+//! names the regions it draws with `Tour::mark`, so steps stay in sync with
+//! the layout however the terminal is sized.
+
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::reflow::Reflow;
+use crate::event::{KeyCode, KeyEvent};
+
+/// Width of the explanation popup in cells
+const POPUP_WIDTH: u16 = 48;
+
+/// One stop of a tour: the region to highlight and what to say about it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TourStep {
+    /// Name the screen gives the region with `Tour::mark`
+    pub region: &'static str,
+    pub title: &'static str,
+    pub text: &'static str,
+}
+
+impl TourStep {
+    pub const fn new(region: &'static str, title: &'static str, text: &'static str) -> Self {
+        Self { region, title, text }
+    }
+}
+
+/// Tour state: the steps, the current one, and where each region was drawn
+#[derive(Debug, Default)]
+pub struct Tour {
+    steps: Vec<TourStep>,
+    index: usize,
+    active: bool,
+    regions: HashMap<&'static str, Rect>,
+}
+
+impl Tour {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start at the first of `steps`
+    pub fn start(&mut self, steps: &[TourStep]) {
+        self.steps = steps.to_vec();
+        self.index = 0;
+        self.active = !self.steps.is_empty();
+    }
+
+    pub fn stop(&mut self) {
+        self.active = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn current(&self) -> Option<&TourStep> {
+        self.steps.get(self.index).filter(|_| self.active)
+    }
+
+    /// Record where `region` was drawn this frame
+    pub fn mark(&mut self, region: &'static str, area: Rect) {
+        self.regions.insert(region, area);
+    }
+
+    /// Enter or → advance, Backspace or ← go back, Esc ends the tour.
+    /// Returns whether the key was used; every key is while the tour runs.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.active {
+            return false;
+        }
+        match key.code {
+            KeyCode::Enter | KeyCode::Right => {
+                self.index += 1;
+                if self.index >= self.steps.len() {
+                    self.active = false;
+                }
+            }
+            KeyCode::Backspace | KeyCode::Left => self.index = self.index.saturating_sub(1),
+            KeyCode::Esc => self.active = false,
+            _ => {}
+        }
+        true
+    }
+
+    /// Draw the current step over `area`; call after the screen is drawn.
+    /// A step whose region was not drawn shows its popup centered.
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let Some(step) = self.current() else { return };
+        let region = self.regions.get(step.region).map(|region| region.intersection(area));
+
+        let buffer = frame.buffer_mut();
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if region.is_some_and(|region| region.contains((x, y).into())) {
+                    continue;
+                }
+                if let Some(cell) = buffer.cell_mut((x, y)) {
+                    cell.set_style(Style::default().fg(Color::DarkGray).add_modifier(Modifier::DIM));
+                }
+            }
+        }
+
+        let accent = Style::default().fg(Color::Yellow);
+        if let Some(region) = region.filter(|region| !region.is_empty()) {
+            let outline = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Thick)
+                .border_style(accent.add_modifier(Modifier::BOLD));
+            frame.render_widget(outline, region);
+        }
+
+        let popup = popup_area(area, region, step.text);
+        let footer = format!(
+            "{}/{}  Enter: Next  Backspace: Back  Esc: End",
+            self.index + 1,
+            self.steps.len()
+        );
+        let mut text = Reflow::new(popup.width.saturating_sub(2)).hyphenate(true).lines(step.text, Style::default());
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(footer, Style::default().fg(Color::DarkGray))));
+        let paragraph = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(accent)
+                    .title(Span::styled(format!(" {} ", step.title), accent.add_modifier(Modifier::BOLD))),
+            );
+        frame.render_widget(Clear, popup);
+        frame.render_widget(paragraph, popup);
+    }
+}
+
+/// Below the region if it fits, else above it, else centered in `area`
+fn popup_area(area: Rect, region: Option<Rect>, text: &str) -> Rect {
+    let width = POPUP_WIDTH.min(area.width);
+    let lines = Reflow::new(width.saturating_sub(2)).hyphenate(true).wrap(text).len();
+    // Text lines, a blank line, the footer and the borders
+    let height = (lines as u16 + 4).min(area.height);
+
+    let centered = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let Some(region) = region.filter(|region| !region.is_empty()) else {
+        return centered;
+    };
+    let x = region.x.clamp(area.x, area.right() - width);
+    if region.bottom() + height <= area.bottom() {
+        Rect { x, y: region.bottom(), width, height }
+    } else if region.y >= area.y + height {
+        Rect { x, y: region.y - height, width, height }
+    } else {
+        centered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tour_steps() {
+        const STEPS: &[TourStep] = &[
+            TourStep::new("tabs", "Tabs", "This is the tab bar — press Tab to switch"),
+            TourStep::new("gauges", "Gauges", "Press g to switch between bar and radial gauges"),
+        ];
+        let key = |code| KeyEvent::from(code);
+        let mut tour = Tour::new();
+        assert!(!tour.handle_key(key(KeyCode::Enter)));
+
+        tour.start(STEPS);
+        assert_eq!(tour.current().map(|step| step.region), Some("tabs"));
+        assert!(tour.handle_key(key(KeyCode::Char('q'))));
+        tour.handle_key(key(KeyCode::Enter));
+        assert_eq!(tour.current().map(|step| step.region), Some("gauges"));
+        tour.handle_key(key(KeyCode::Backspace));
+        tour.handle_key(key(KeyCode::Backspace));
+        assert_eq!(tour.current().map(|step| step.region), Some("tabs"));
+        tour.handle_key(key(KeyCode::Enter));
+        tour.handle_key(key(KeyCode::Enter));
+        assert!(!tour.is_active());
+
+        let area = Rect::new(0, 0, 80, 24);
+        let below = popup_area(area, Some(Rect::new(0, 0, 80, 3)), "short");
+        assert_eq!((below.y, below.width, below.height), (3, POPUP_WIDTH, 5));
+        let above = popup_area(area, Some(Rect::new(40, 20, 40, 4)), "short");
+        assert_eq!((above.x, above.y), (80 - POPUP_WIDTH, 15));
+    }
+}
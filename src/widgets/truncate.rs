@@ -0,0 +1,112 @@
+//! Truncation module
+//!
+//! Shortens text to a number of terminal columns with an ellipsis, instead
+//! of letting a widget cut it off. Text is measured in display width and cut
+//! between graphemes, so wide characters and emoji are never split. Long
+//! file names keep their extension with `elide_middle`, and paths keep their
+//! first directories and file name with `elide_path`, as in
+//! `/home/…/video.mp4`.
+
+use std::borrow::Cow;
+use std::path::is_separator;
+
+use ratatui::text::Span;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub const ELLIPSIS: char = '…';
+
+/// Terminal columns `text` occupies
+fn width_of(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+/// Byte length of the longest grapheme prefix of `text` within `width`
+fn head_end(text: &str, width: usize) -> usize {
+    let mut used = 0;
+    for (i, grapheme) in text.grapheme_indices(true) {
+        used += width_of(grapheme);
+        if used > width {
+            return i;
+        }
+    }
+    text.len()
+}
+
+/// Byte offset where the longest grapheme suffix of `text` within `width`
+/// starts
+fn tail_start(text: &str, width: usize) -> usize {
+    let mut used = 0;
+    for (i, grapheme) in text.grapheme_indices(true).rev() {
+        used += width_of(grapheme);
+        if used > width {
+            return i + grapheme.len();
+        }
+    }
+    0
+}
+
+/// `text` cut to `width` columns, ending in `…` when it was cut
+pub fn truncate_end(text: &str, width: usize) -> Cow<'_, str> {
+    if width_of(text) <= width {
+        return Cow::Borrowed(text);
+    }
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+    Cow::Owned(format!("{}{}", &text[..head_end(text, width - 1)], ELLIPSIS))
+}
+
+/// `text` cut to `width` columns by replacing its middle with `…`; the end,
+/// such as a file extension, gets the larger half
+pub fn elide_middle(text: &str, width: usize) -> Cow<'_, str> {
+    if width_of(text) <= width {
+        return Cow::Borrowed(text);
+    }
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+    let room = width - 1;
+    let head = &text[..head_end(text, room / 2)];
+    let tail = &text[tail_start(text, room - room / 2)..];
+    Cow::Owned(format!("{}{}{}", head, ELLIPSIS, tail))
+}
+
+/// `path` cut to `width` columns by eliding whole directories between its
+/// start and the file name. When the file name alone is too wide, it is
+/// elided in the middle instead.
+pub fn elide_path(path: &str, width: usize) -> Cow<'_, str> {
+    if width_of(path) <= width {
+        return Cow::Borrowed(path);
+    }
+    let Some(separator) = path.rfind(is_separator) else {
+        return elide_middle(path, width);
+    };
+    let name = &path[separator..];
+    let name_width = width_of(name);
+    if name_width + 1 > width {
+        return elide_middle(&path[separator + 1..], width);
+    }
+    let head = &path[..head_end(path, width - 1 - name_width)];
+    // Never show half a directory name
+    let head = head.rfind(is_separator).map_or("", |end| &head[..=end]);
+    Cow::Owned(format!("{}{}{}", head, ELLIPSIS, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_and_elide() {
+        assert_eq!(truncate_end("short", 10), "short");
+        assert_eq!(truncate_end("truncated text", 8), "truncat…");
+        // A wide character that would straddle the limit is left out
+        assert_eq!(truncate_end("日本語テキスト", 6), "日本…");
+        assert_eq!(truncate_end("anything", 0), "");
+
+        assert_eq!(elide_middle("holiday_video_2024.mp4", 12), "holid…24.mp4");
+        assert_eq!(elide_path("/home/user/videos/video.mp4", 17), "/home/…/video.mp4");
+        assert_eq!(elide_path("/home/user/videos/video.mp4", 12), "/…/video.mp4");
+        assert_eq!(elide_path("/home/user/a_very_long_name.mp4", 10), "a_ve…e.mp4");
+    }
+}
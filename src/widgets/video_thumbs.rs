@@ -0,0 +1,192 @@
+//! Video thumbnail module
+//!
+//! Single frames of a video, extracted with `ffmpeg` in the background
+//! and kept in an LRU cache, for the preview above a player's seek bar.
+//! Times snap to a grid of about a hundred slots per video, so scrubbing
+//! back and forth reuses frames. Only one extraction runs at a time; newer
+//! requests replace the queued one instead of piling up behind it.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use image::DynamicImage;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::platform;
+
+use super::humanize::format_clock;
+use super::image::{ImageQuality, ImageWidget};
+use super::lru::LruCache;
+
+/// Width ffmpeg scales the frames to; plenty for a text thumbnail
+const FRAME_WIDTH: u32 = 160;
+
+const CACHE_BYTES: usize = 8 * 1024 * 1024;
+
+/// Slots per video when its length is known
+const SLOTS: u32 = 100;
+
+/// Grid the request times snap to: a hundredth of the video, at least a
+/// second, or five seconds while the length is unknown
+pub fn grid_step(duration: Option<Duration>) -> Duration {
+    duration.map_or(Duration::from_secs(5), |duration| (duration / SLOTS).max(Duration::from_secs(1)))
+}
+
+/// Extract the frame of the video at `path` shown at `at` in the
+/// background and pass it to `done`
+pub fn extract_frame(path: &Path, at: Duration, done: impl FnOnce(Result<DynamicImage, String>) + Send + 'static) {
+    let mut args: Vec<String> = ["-v", "error", "-ss", &format!("{:.3}", at.as_secs_f64()), "-i"].map(String::from).into();
+    args.push(path.to_string_lossy().into_owned());
+    args.extend(
+        ["-frames:v", "1", "-vf", &format!("scale={}:-2", FRAME_WIDTH), "-f", "image2pipe", "-vcodec", "png", "-"]
+            .map(String::from),
+    );
+    platform::current().run_tool(
+        "ffmpeg",
+        &args,
+        Box::new(move |output| {
+            done(output.and_then(|stdout| {
+                if stdout.is_empty() {
+                    return Err("ffmpeg produced no frame".to_string());
+                }
+                image::load_from_memory(&stdout).map_err(|e| e.to_string())
+            }))
+        }),
+    );
+}
+
+pub struct VideoThumbnails {
+    path: Option<PathBuf>,
+    step: Duration,
+    cache: LruCache<(PathBuf, u32), DynamicImage>,
+    /// Slot being extracted and its result
+    job: Option<(u32, Receiver<Result<DynamicImage, String>>)>,
+    /// Latest slot asked for while a job was running
+    queued: Option<u32>,
+    /// Converts frames to text at the size of the popup
+    ascii: ImageWidget,
+}
+
+impl Default for VideoThumbnails {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VideoThumbnails {
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            step: grid_step(None),
+            cache: LruCache::new("video thumbnails", CACHE_BYTES),
+            job: None,
+            queued: None,
+            ascii: ImageWidget::with_quality(ImageQuality::Low),
+        }
+    }
+
+    /// Take thumbnails from `path` from now on; frames of other videos stay
+    /// cached
+    pub fn set_video(&mut self, path: &Path, duration: Option<Duration>) {
+        self.path = Some(path.to_path_buf());
+        self.step = grid_step(duration);
+        self.job = None;
+        self.queued = None;
+    }
+
+    fn slot(&self, at: Duration) -> u32 {
+        (at.as_secs_f64() / self.step.as_secs_f64()).round() as u32
+    }
+
+    /// Start extracting the frame for `at` unless it is cached
+    pub fn request(&mut self, at: Duration) {
+        let Some(path) = self.path.clone() else {
+            return;
+        };
+        let slot = self.slot(at);
+        if self.cache.get(&(path.clone(), slot)).is_some() || self.job.as_ref().is_some_and(|(running, _)| *running == slot) {
+            return;
+        }
+        if self.job.is_some() {
+            self.queued = Some(slot);
+            return;
+        }
+        let (sender, receiver) = mpsc::channel();
+        let at = self.step * slot;
+        extract_frame(&path, at, move |frame| {
+            let _ = sender.send(frame);
+        });
+        self.job = Some((slot, receiver));
+    }
+
+    /// Cache a finished frame and start the queued request; call once per
+    /// tick. A failed extraction is logged, as the time label still shows.
+    pub fn poll(&mut self) {
+        let Some((slot, Ok(result))) = self.job.as_ref().map(|(slot, receiver)| (*slot, receiver.try_recv())) else {
+            return;
+        };
+        self.job = None;
+        match (result, self.path.clone()) {
+            (Ok(frame), Some(path)) => self.cache.insert((path, slot), frame),
+            (Err(e), _) => log::warn!("No thumbnail at slot {} of {:?}: {}", slot, self.path, e),
+            _ => {}
+        }
+        if let Some(queued) = self.queued.take() {
+            self.request(self.step * queued);
+        }
+    }
+
+    /// Popup with the frame at `at` centred over `x`, its bottom edge at
+    /// `bottom`; the time label shows while the frame loads
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, x: u16, bottom: u16, at: Duration) {
+        let width = 34.min(area.width);
+        let height = 12.min(bottom.saturating_sub(area.y));
+        if height < 3 {
+            return;
+        }
+        let left = x.saturating_sub(width / 2).clamp(area.x, area.right() - width);
+        let popup = Rect { x: left, y: bottom - height, width, height };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", format_clock(at)))
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(popup);
+        let key = self.path.clone().map(|path| (path, self.slot(at)));
+        let lines: Vec<Line> = match key.and_then(|key| self.cache.get(&key)) {
+            Some(image) => self
+                .ascii
+                .image_to_ascii(image, inner.width as usize, inner.height as usize)
+                .into_iter()
+                .map(Line::raw)
+                .collect(),
+            None => vec![Line::styled("Loading frame…", Style::default().fg(Color::DarkGray))],
+        };
+        frame.render_widget(Clear, popup);
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requests_snap_to_the_grid() {
+        assert_eq!(grid_step(None), Duration::from_secs(5));
+        assert_eq!(grid_step(Some(Duration::from_secs(600))), Duration::from_secs(6));
+        assert_eq!(grid_step(Some(Duration::from_secs(30))), Duration::from_secs(1));
+
+        let mut thumbnails = VideoThumbnails::new();
+        thumbnails.set_video(Path::new("clip.mp4"), Some(Duration::from_secs(600)));
+        assert_eq!(thumbnails.slot(Duration::from_secs(13)), 2);
+        assert_eq!(thumbnails.slot(Duration::from_secs(14)), 2);
+        assert_eq!(thumbnails.slot(Duration::from_secs(16)), 3);
+    }
+}
@@ -0,0 +1,153 @@
+//! File watcher module
+//!
+//! Wraps a `notify` watcher whose events are collected on a channel and
+//! debounced, so views can refresh once after a burst of changes instead of
+//! on every write.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// Quiet period after the last event before changes are reported
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub struct FileWatcher {
+    watcher: Option<RecommendedWatcher>,
+    sender: Sender<PathBuf>,
+    receiver: Receiver<PathBuf>,
+    /// Paths being watched, kept so they can be re-added after re-enabling
+    watched: Vec<(PathBuf, bool)>,
+    pending: HashSet<PathBuf>,
+    last_event: Option<Instant>,
+    debounce: Duration,
+}
+
+impl FileWatcher {
+    pub fn new(enabled: bool) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = Self {
+            watcher: None,
+            sender,
+            receiver,
+            watched: Vec::new(),
+            pending: HashSet::new(),
+            last_event: None,
+            debounce: DEFAULT_DEBOUNCE,
+        };
+        watcher.set_enabled(enabled);
+        watcher
+    }
+
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.watcher.is_some()
+    }
+
+    /// Start or stop watching; stopping drops any pending changes
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if enabled == self.is_enabled() {
+            return;
+        }
+        if !enabled {
+            self.watcher = None;
+            while self.receiver.try_recv().is_ok() {}
+            self.pending.clear();
+            self.last_event = None;
+            return;
+        }
+
+        let sender = self.sender.clone();
+        let created = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+            if let Ok(event) = result {
+                if !matches!(event.kind, EventKind::Access(_)) {
+                    for path in event.paths {
+                        let _ = sender.send(path);
+                    }
+                }
+            }
+        });
+        match created {
+            Ok(mut watcher) => {
+                for (path, recursive) in &self.watched {
+                    let _ = watcher.watch(path, recursive_mode(*recursive));
+                }
+                self.watcher = Some(watcher);
+            }
+            Err(e) => log::error!("Failed to start file watcher: {}", e),
+        }
+    }
+
+    /// Watch `path`, including subdirectories when `recursive` is set
+    pub fn watch(&mut self, path: &Path, recursive: bool) {
+        if self.watched.iter().any(|(watched, _)| watched == path) {
+            return;
+        }
+        if let Some(watcher) = self.watcher.as_mut() {
+            if let Err(e) = watcher.watch(path, recursive_mode(recursive)) {
+                log::error!("Failed to watch {}: {}", path.display(), e);
+            }
+        }
+        self.watched.push((path.to_path_buf(), recursive));
+    }
+
+    pub fn unwatch_all(&mut self) {
+        if let Some(watcher) = self.watcher.as_mut() {
+            for (path, _) in &self.watched {
+                let _ = watcher.unwatch(path);
+            }
+        }
+        self.watched.clear();
+        self.pending.clear();
+        self.last_event = None;
+    }
+
+    /// Changed paths once events have been quiet for the debounce period;
+    /// call once per tick
+    pub fn poll(&mut self) -> Option<Vec<PathBuf>> {
+        while let Ok(path) = self.receiver.try_recv() {
+            self.pending.insert(path);
+            self.last_event = Some(Instant::now());
+        }
+
+        let quiet = self.last_event.is_some_and(|last| last.elapsed() >= self.debounce);
+        if !quiet || self.pending.is_empty() {
+            return None;
+        }
+        self.last_event = None;
+        let mut changed: Vec<PathBuf> = self.pending.drain().collect();
+        changed.sort();
+        Some(changed)
+    }
+}
+
+fn recursive_mode(recursive: bool) -> RecursiveMode {
+    if recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_are_debounced() {
+        let mut watcher = FileWatcher::new(false).with_debounce(Duration::from_millis(20));
+        watcher.sender.send(PathBuf::from("a")).unwrap();
+        watcher.sender.send(PathBuf::from("a")).unwrap();
+        assert!(watcher.poll().is_none(), "still inside the debounce period");
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(watcher.poll(), Some(vec![PathBuf::from("a")]));
+        assert!(watcher.poll().is_none());
+    }
+}
@@ -0,0 +1,118 @@
+//! Waveform module
+//!
+//! Minimum and maximum sample of an audio file per bucket, computed once
+//! from the whole file and small enough to cache. `render_waveform` draws
+//! the peaks on a `Canvas`, either as a thumbnail or as a seek bar with the
+//! played part highlighted.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use ratatui::{
+    layout::Rect,
+    style::Color,
+    symbols::Marker,
+    widgets::canvas::{Canvas, Line},
+    Frame,
+};
+use rodio::{Decoder, Source};
+
+use super::lru::ByteSize;
+use crate::error::DemoError;
+
+/// Buckets of a cached waveform, enough for a full-width preview pane
+pub const BUCKETS: usize = 256;
+
+/// Samples folded into one peak while decoding; the peaks are merged down
+/// to the bucket count at the end, so the length need not be known up front
+const CHUNK: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Waveform {
+    /// Lowest and highest sample per bucket, from -1.0 to 1.0
+    pub peaks: Vec<(f32, f32)>,
+}
+
+impl ByteSize for Waveform {
+    fn byte_size(&self) -> usize {
+        self.peaks.len() * std::mem::size_of::<(f32, f32)>()
+    }
+}
+
+fn merge(peaks: &[(f32, f32)]) -> (f32, f32) {
+    peaks.iter().fold((f32::MAX, f32::MIN), |(low, high), &(min, max)| (low.min(min), high.max(max)))
+}
+
+impl Waveform {
+    /// Peaks of `samples` in at most `buckets` buckets; channels can stay
+    /// interleaved
+    pub fn from_samples(samples: impl IntoIterator<Item = f32>, buckets: usize) -> Self {
+        let mut fine = Vec::new();
+        let mut current: Option<(f32, f32)> = None;
+        let mut count = 0;
+        for sample in samples {
+            let (low, high) = current.unwrap_or((sample, sample));
+            current = Some((low.min(sample), high.max(sample)));
+            count += 1;
+            if count == CHUNK {
+                fine.extend(current.take());
+                count = 0;
+            }
+        }
+        fine.extend(current);
+        if fine.len() <= buckets {
+            return Self { peaks: fine };
+        }
+        let peaks = (0..buckets)
+            .map(|i| {
+                let start = i * fine.len() / buckets;
+                let end = ((i + 1) * fine.len() / buckets).max(start + 1);
+                merge(&fine[start..end])
+            })
+            .collect();
+        Self { peaks }
+    }
+
+    /// Decode the whole file at `path`; slow for long files, so run it off
+    /// the UI thread
+    pub fn load(path: &Path, buckets: usize) -> Result<Self, DemoError> {
+        let decoder = Decoder::new(BufReader::new(File::open(path)?)).map_err(|e| DemoError::Audio(e.to_string()))?;
+        Ok(Self::from_samples(decoder.convert_samples::<f32>(), buckets))
+    }
+}
+
+/// Draw `waveform` in `area`. Buckets before `progress` (0.0 to 1.0) are
+/// highlighted, as on a seek bar.
+pub fn render_waveform(frame: &mut Frame, area: Rect, waveform: &Waveform, progress: Option<f64>) {
+    let buckets = waveform.peaks.len().max(1) as f64;
+    let played = progress.map_or(0.0, |progress| progress.clamp(0.0, 1.0) * buckets);
+    let canvas = Canvas::default()
+        .marker(Marker::Braille)
+        .x_bounds([0.0, buckets])
+        .y_bounds([-1.0, 1.0])
+        .paint(|ctx| {
+            for (i, &(low, high)) in waveform.peaks.iter().enumerate() {
+                let x = i as f64 + 0.5;
+                let color = if (i as f64) < played { Color::Cyan } else { Color::Gray };
+                ctx.draw(&Line { x1: x, y1: low as f64, x2: x, y2: high as f64, color });
+            }
+        });
+    frame.render_widget(canvas, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peaks_per_bucket() {
+        let samples = [vec![0.5; CHUNK], vec![-1.0; CHUNK], vec![0.25; CHUNK / 2]].concat();
+        let waveform = Waveform::from_samples(samples.iter().copied(), 3);
+        assert_eq!(waveform.peaks, [(0.5, 0.5), (-1.0, -1.0), (0.25, 0.25)]);
+
+        let merged = Waveform::from_samples(samples.iter().copied(), 1);
+        assert_eq!(merged.peaks, [(-1.0, 0.5)]);
+        assert!(Waveform::from_samples(std::iter::empty(), 8).peaks.is_empty());
+    }
+}
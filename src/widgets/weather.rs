@@ -0,0 +1,290 @@
+//! Weather tile module
+//!
+//! Current conditions and an hourly temperature sparkline from an
+//! Open-Meteo compatible API. Requests run on a background thread on a
+//! refresh interval, and the last response is cached in the data directory
+//! so the tile has something to show straight after startup or offline.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Sparkline},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::platform;
+
+/// Wait before trying again after a failed request
+const RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Where and how often to fetch the weather
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WeatherConfig {
+    /// Forecast endpoint taking Open-Meteo query parameters
+    pub endpoint: String,
+    /// Sent as `apikey` when not empty, for self-hosted or commercial instances
+    pub api_key: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub refresh_minutes: u64,
+}
+
+impl Default for WeatherConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "https://api.open-meteo.com/v1/forecast".to_string(),
+            api_key: String::new(),
+            latitude: 52.52,
+            longitude: 13.41,
+            refresh_minutes: 15,
+        }
+    }
+}
+
+impl WeatherConfig {
+    pub fn url(&self) -> String {
+        let mut url = format!(
+            "{}?latitude={}&longitude={}&current=temperature_2m,weather_code&hourly=temperature_2m&forecast_days=1",
+            self.endpoint, self.latitude, self.longitude
+        );
+        if !self.api_key.is_empty() {
+            url.push_str(&format!("&apikey={}", self.api_key));
+        }
+        url
+    }
+
+    fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.refresh_minutes.max(1) * 60)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Weather {
+    /// Degrees Celsius
+    pub temperature: f64,
+    /// WMO weather interpretation code
+    pub code: u32,
+    /// Hourly temperatures for today
+    pub hourly: Vec<f64>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Read an Open-Meteo forecast response
+pub fn parse_open_meteo(json: &Value, fetched_at: DateTime<Utc>) -> Result<Weather, String> {
+    let number = |pointer: &str| json.pointer(pointer).and_then(Value::as_f64).ok_or_else(|| format!("missing {}", pointer));
+    Ok(Weather {
+        temperature: number("/current/temperature_2m")?,
+        code: number("/current/weather_code")? as u32,
+        hourly: json
+            .pointer("/hourly/temperature_2m")
+            .and_then(Value::as_array)
+            .map(|values| values.iter().filter_map(Value::as_f64).collect())
+            .unwrap_or_default(),
+        fetched_at,
+    })
+}
+
+/// Glyph and description for a WMO weather code
+pub fn condition(code: u32) -> (&'static str, &'static str) {
+    match code {
+        0 => ("☀", "Clear"),
+        1 | 2 => ("⛅", "Partly cloudy"),
+        3 => ("☁", "Overcast"),
+        45 | 48 => ("🌫", "Fog"),
+        51..=57 => ("🌦", "Drizzle"),
+        61..=67 => ("🌧", "Rain"),
+        71..=77 => ("❄", "Snow"),
+        80..=82 => ("🌦", "Showers"),
+        85 | 86 => ("🌨", "Snow showers"),
+        95..=99 => ("⛈", "Thunderstorm"),
+        _ => ("?", "Unknown"),
+    }
+}
+
+#[cfg(feature = "http")]
+fn fetch(url: &str) -> Result<Weather, String> {
+    let json: Value = ureq::get(url)
+        .timeout(Duration::from_secs(10))
+        .call()
+        .map_err(|e| e.to_string())?
+        .into_json()
+        .map_err(|e| e.to_string())?;
+    parse_open_meteo(&json, Utc::now())
+}
+
+#[cfg(not(feature = "http"))]
+fn fetch(_url: &str) -> Result<Weather, String> {
+    Err("built without the http feature".to_string())
+}
+
+pub struct WeatherTile {
+    pub config: WeatherConfig,
+    pub weather: Option<Weather>,
+    pub error: Option<String>,
+    cache_path: Option<PathBuf>,
+    pending: Option<Receiver<Result<Weather, String>>>,
+    last_attempt: Option<Instant>,
+}
+
+impl WeatherTile {
+    /// Tile cached in `weather.json` in the data directory
+    pub fn new(config: WeatherConfig) -> Self {
+        let cache_path = dirs::data_dir().map(|mut path| {
+            path.push("ratatui-rust-example");
+            path.push("weather.json");
+            path
+        });
+        Self::with_cache(config, cache_path)
+    }
+
+    pub fn with_cache(config: WeatherConfig, cache_path: Option<PathBuf>) -> Self {
+        let weather = cache_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok());
+        Self {
+            config,
+            weather,
+            error: None,
+            cache_path,
+            pending: None,
+            last_attempt: None,
+        }
+    }
+
+    /// Whether the shown data is older than the refresh interval
+    fn is_stale(&self) -> bool {
+        let interval = chrono::Duration::from_std(self.config.refresh_interval()).unwrap_or_default();
+        self.weather.as_ref().is_none_or(|weather| Utc::now() - weather.fetched_at >= interval)
+    }
+
+    /// Start a request now unless one is running
+    pub fn refresh(&mut self) {
+        if self.pending.is_some() {
+            return;
+        }
+        let (sender, receiver) = mpsc::channel();
+        let url = self.config.url();
+        platform::current().background(Box::new(move || {
+            let _ = sender.send(fetch(&url));
+        }));
+        self.pending = Some(receiver);
+        self.last_attempt = Some(Instant::now());
+    }
+
+    /// Install a finished request and start one when the data is stale;
+    /// call once per tick
+    pub fn poll(&mut self) {
+        if let Some(receiver) = &self.pending {
+            if let Ok(result) = receiver.try_recv() {
+                self.pending = None;
+                match result {
+                    Ok(weather) => {
+                        self.save_cache(&weather);
+                        self.weather = Some(weather);
+                        self.error = None;
+                    }
+                    Err(e) => self.error = Some(e),
+                }
+            }
+        }
+        let retry_after = if self.error.is_some() { RETRY_AFTER } else { self.config.refresh_interval() };
+        let due = self.last_attempt.is_none_or(|last| last.elapsed() >= retry_after);
+        if self.pending.is_none() && self.is_stale() && due {
+            self.refresh();
+        }
+    }
+
+    fn save_cache(&self, weather: &Weather) {
+        let Some(path) = &self.cache_path else {
+            return;
+        };
+        let result = serde_json::to_string(weather)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                path.parent().map_or(Ok(()), fs::create_dir_all).map_err(|e| e.to_string())?;
+                fs::write(path, content).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::error!("Failed to cache weather: {}", e);
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let title = if self.pending.is_some() { "Weather ⟳" } else { "Weather" };
+        let block = Block::default().borders(Borders::ALL).title(title);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let Some(weather) = &self.weather else {
+            let message = match &self.error {
+                Some(error) => Line::styled(format!("Unavailable: {}", error), Style::default().fg(Color::Red)),
+                None => Line::styled("Loading weather...", Style::default().fg(Color::DarkGray)),
+            };
+            frame.render_widget(Paragraph::new(message), inner);
+            return;
+        };
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(inner);
+
+        let (glyph, description) = condition(weather.code);
+        let updated = weather.fetched_at.with_timezone(&Local).format("%H:%M");
+        let status = match &self.error {
+            Some(_) => Span::styled(format!("cached {} (offline)", updated), Style::default().fg(Color::Yellow)),
+            None => Span::styled(format!("updated {}", updated), Style::default().fg(Color::DarkGray)),
+        };
+        let lines = vec![
+            Line::from(vec![
+                Span::raw(format!("{} ", glyph)),
+                Span::styled(format!("{:.1}°C", weather.temperature), Style::default().add_modifier(Modifier::BOLD)),
+            ]),
+            Line::raw(description),
+            Line::from(status),
+        ];
+        frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+        // Sparkline bars start at the day's minimum so the shape is visible
+        let min = weather.hourly.iter().copied().fold(f64::INFINITY, f64::min);
+        let data: Vec<u64> = weather.hourly.iter().map(|t| ((t - min) * 10.0).round() as u64 + 1).collect();
+        let sparkline = Sparkline::default().data(&data).style(Style::default().fg(Color::Yellow));
+        frame.render_widget(sparkline, chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_cache() {
+        let json = serde_json::json!({
+            "current": { "temperature_2m": 21.5, "weather_code": 61 },
+            "hourly": { "temperature_2m": [15.0, 18.5, 21.5] }
+        });
+        let weather = parse_open_meteo(&json, Utc::now()).unwrap();
+        assert_eq!(weather.temperature, 21.5);
+        assert_eq!(condition(weather.code), ("🌧", "Rain"));
+        assert_eq!(weather.hourly, vec![15.0, 18.5, 21.5]);
+        assert!(parse_open_meteo(&serde_json::json!({}), Utc::now()).is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("weather.json");
+        let tile = WeatherTile::with_cache(WeatherConfig::default(), Some(path.clone()));
+        tile.save_cache(&weather);
+        let cached = WeatherTile::with_cache(WeatherConfig::default(), Some(path));
+        assert_eq!(cached.weather, Some(weather));
+        assert!(!cached.is_stale());
+    }
+}
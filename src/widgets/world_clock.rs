@@ -0,0 +1,130 @@
+//! World clock module
+//!
+//! Current time in a list of IANA timezones (`chrono-tz`), with a sun or
+//! moon for day and night and the UTC offset, sized for a dashboard tile.
+
+use chrono::{DateTime, Offset, Timelike, Utc};
+use chrono_tz::Tz;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Hours counted as daytime, local to each zone
+const DAY_HOURS: std::ops::Range<u32> = 6..18;
+
+/// Time in one zone at a given instant
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneTime {
+    /// City part of the zone name, e.g. `New York`
+    pub label: String,
+    pub time: DateTime<Tz>,
+}
+
+impl ZoneTime {
+    pub fn is_day(&self) -> bool {
+        DAY_HOURS.contains(&self.time.hour())
+    }
+
+    /// Offset from UTC such as `UTC+5:30` or `UTC-4`
+    pub fn offset(&self) -> String {
+        let seconds = self.time.offset().fix().local_minus_utc();
+        let sign = if seconds < 0 { '-' } else { '+' };
+        let (hours, minutes) = (seconds.abs() / 3600, seconds.abs() % 3600 / 60);
+        if minutes == 0 {
+            format!("UTC{}{}", sign, hours)
+        } else {
+            format!("UTC{}{}:{:02}", sign, hours, minutes)
+        }
+    }
+}
+
+/// `America/New_York` -> `New York`
+fn city(zone: &str) -> String {
+    zone.rsplit('/').next().unwrap_or(zone).replace('_', " ")
+}
+
+/// Configured zones, keeping names that did not parse for the error line
+pub struct WorldClock {
+    zones: Vec<Tz>,
+    pub invalid: Vec<String>,
+}
+
+impl WorldClock {
+    pub fn new(zones: &[String]) -> Self {
+        let mut clock = Self {
+            zones: Vec::new(),
+            invalid: Vec::new(),
+        };
+        for zone in zones {
+            match zone.trim().parse::<Tz>() {
+                Ok(tz) => clock.zones.push(tz),
+                Err(_) => clock.invalid.push(zone.clone()),
+            }
+        }
+        clock
+    }
+
+    pub fn times(&self, now: DateTime<Utc>) -> Vec<ZoneTime> {
+        self.zones
+            .iter()
+            .map(|tz| ZoneTime {
+                label: city(tz.name()),
+                time: now.with_timezone(tz),
+            })
+            .collect()
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, now: DateTime<Utc>) {
+        let label_width = self.zones.iter().map(|tz| city(tz.name()).chars().count()).max().unwrap_or(0);
+        let mut lines: Vec<Line> = self
+            .times(now)
+            .iter()
+            .map(|zone| {
+                let (icon, color) = if zone.is_day() { ("☀", Color::Yellow) } else { ("☾", Color::LightBlue) };
+                Line::from(vec![
+                    Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                    Span::raw(format!("{:width$} ", zone.label, width = label_width)),
+                    Span::styled(zone.time.format("%H:%M").to_string(), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::styled(
+                        format!(" {} {}", zone.time.format("%a"), zone.offset()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ])
+            })
+            .collect();
+        if !self.invalid.is_empty() {
+            lines.push(Line::styled(
+                format!("Unknown timezone: {}", self.invalid.join(", ")),
+                Style::default().fg(Color::Red),
+            ));
+        }
+        let clock = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("World Clock"));
+        frame.render_widget(clock, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_zone_times_and_day_night() {
+        let clock = WorldClock::new(&["Asia/Kolkata".to_string(), "America/New_York".to_string(), "Mars/Base".to_string()]);
+        assert_eq!(clock.invalid, vec!["Mars/Base"]);
+
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        let times = clock.times(now);
+        assert_eq!(times[0].time.format("%H:%M").to_string(), "17:30");
+        assert_eq!(times[0].offset(), "UTC+5:30");
+        assert!(times[0].is_day());
+        assert_eq!(times[1].label, "New York");
+        assert_eq!(times[1].offset(), "UTC-4");
+        assert!(times[1].is_day());
+        assert!(!clock.times(now + chrono::Duration::hours(12))[1].is_day());
+    }
+}
@@ -18,4 +18,26 @@ fn test_config_serialization() {
     assert_eq!(config.theme_index, deserialized.theme_index);
     assert_eq!(config.default_tab, deserialized.default_tab);
     assert_eq!(config.auto_save, deserialized.auto_save);
+    assert_eq!(config.week_start, deserialized.week_start);
+}
+
+#[test]
+fn test_week_start_defaults_when_missing() {
+    let config: Config = toml::from_str("theme_index = 0\ndefault_tab = 0\nauto_save = true\nthemes = []\n").unwrap();
+    assert_eq!(config.week_start, chrono::Weekday::Mon);
+}
+
+#[test]
+fn test_bookmarks_roundtrip() {
+    let mut config = Config::default();
+    config.bookmarks.push(std::path::PathBuf::from("/videos"));
+    let serialized = toml::to_string(&config).unwrap();
+    let deserialized: Config = toml::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.bookmarks, config.bookmarks);
+}
+
+#[test]
+fn test_watch_files_defaults_to_enabled() {
+    let config: Config = toml::from_str("theme_index = 0\ndefault_tab = 0\nauto_save = true\nthemes = []\n").unwrap();
+    assert!(config.watch_files);
 }